@@ -12,34 +12,53 @@
 //! # Handlers
 //! - `create_block_handler`: Creates a block handler function that processes new blocks from the
 //!   blockchain
+//! - `create_block_handler_with_registry`: Creates a block handler function that dispatches new
+//!   blocks to the processors of a `BlockProcessorRegistry`
 //! - `create_trigger_handler`: Creates a trigger handler function that processes trigger events
 //!   from the block processing pipeline
 
 use futures::future::BoxFuture;
-use std::{collections::HashMap, error::Error, sync::Arc};
+use std::{
+	collections::{HashMap, HashSet},
+	error::Error,
+	path::Path,
+	sync::Arc,
+};
 use tokio::sync::{watch, Mutex};
 
 use crate::{
 	models::{
-		BlockChainType, BlockType, ContractSpec, Monitor, MonitorMatch, Network, ProcessedBlock,
-		ScriptLanguage, TriggerConditions,
+		BlockChainType, BlockType, CombinedConfig, ContractSpec, Monitor, MonitorMatch, Network,
+		ProcessedBlock, ScriptLanguage, TriggerConditions,
 	},
 	repositories::{
-		MonitorRepositoryTrait, MonitorService, NetworkRepositoryTrait, NetworkService,
+		MonitorRepository, MonitorRepositoryTrait, MonitorService, NetworkRepository,
+		NetworkRepositoryTrait, NetworkService, RepositoryError, TriggerRepository,
 		TriggerRepositoryTrait, TriggerService,
 	},
 	services::{
-		blockchain::{BlockChainClient, BlockFilterFactory, ClientPoolTrait},
-		filter::{evm_helpers, handle_match, stellar_helpers, FilterService},
-		notification::NotificationService,
+		blockchain::{
+			BlockChainClient, BlockChainError, BlockFilterFactory, ClientPoolTrait, EvmClientTrait,
+		},
+		blockwatcher::{TriggerCompletion, TriggerQueue, TriggerQueueConfig},
+		filter::{evm_helpers, handle_match, network_address_normalizer, FilterService},
+		notification::{
+			CircuitBreakerConfig, DeadLetterStore, FileAuditLog, NotificationClientPool,
+			NotificationService,
+		},
 		trigger::{
-			ScriptError, ScriptExecutorFactory, TriggerError, TriggerExecutionService,
+			execute_with_retry, DispatchedMatchStore, MatchDeduplicator, ScriptError,
+			ScriptExecutorFactory, TriggerError, TriggerExecutionService,
 			TriggerExecutionServiceTrait,
 		},
 	},
-	utils::normalize_string,
+	utils::{metrics::MATCHES_DEDUPLICATED_TOTAL, normalize_string},
 };
 
+mod registry;
+
+pub use registry::{BlockProcessingContext, BlockProcessor, BlockProcessorRegistry};
+
 /// Type alias for handling ServiceResult
 pub type Result<T> = std::result::Result<T, Box<dyn Error>>;
 
@@ -53,6 +72,66 @@ type ServiceResult<M, N, T> = Result<(
 	Arc<Mutex<TriggerService<T>>>,
 )>;
 
+type ConfigFileServices = Result<(
+	MonitorService<
+		MonitorRepository<NetworkRepository, TriggerRepository>,
+		NetworkRepository,
+		TriggerRepository,
+	>,
+	NetworkService<NetworkRepository>,
+	TriggerService<TriggerRepository>,
+)>;
+
+/// Loads the monitor, network and trigger services from a combined configuration file.
+///
+/// The file is split into the respective repositories after each entry has been validated.
+/// Trigger tags are resolved and monitor references to networks and triggers are checked in
+/// the same way as when loading from the configuration directories.
+///
+/// # Arguments
+/// * `path` - Path to the combined configuration file
+///
+/// # Returns
+/// Returns the services to pass to [`initialize_services`]
+/// # Errors
+/// Returns an error if the file cannot be loaded or if any reference is invalid
+pub async fn load_services_from_config_file(path: &Path) -> ConfigFileServices {
+	let config = CombinedConfig::load_from_path(path).await.map_err(|e| {
+		RepositoryError::load_error(
+			"Failed to load combined configuration file",
+			Some(Box::new(e)),
+			Some(HashMap::from([(
+				"path".to_string(),
+				path.display().to_string(),
+			)])),
+		)
+	})?;
+
+	let mut monitors = config.monitors;
+	MonitorRepository::<NetworkRepository, TriggerRepository>::resolve_trigger_tags(
+		&mut monitors,
+		&config.triggers,
+	)?;
+	MonitorRepository::<NetworkRepository, TriggerRepository>::validate_monitor_references(
+		&monitors,
+		&config.triggers,
+		&config.networks,
+	)?;
+
+	let network_service = NetworkService::new_with_repository(NetworkRepository {
+		networks: config.networks,
+	})?;
+	let trigger_service = TriggerService::new_with_repository(TriggerRepository {
+		triggers: config.triggers,
+	})?;
+	let monitor_service = MonitorService::new_with_repository(MonitorRepository::<
+		NetworkRepository,
+		TriggerRepository,
+	>::new_with_monitors(monitors))?;
+
+	Ok((monitor_service, network_service, trigger_service))
+}
+
 /// Initializes all required services for the blockchain monitor.
 ///
 /// # Returns
@@ -105,16 +184,43 @@ where
 		}
 	};
 
-	let notification_service = NotificationService::new();
+	let circuit_breaker_config = CircuitBreakerConfig::from_env().map_err(|e| {
+		format!(
+			"Failed to read notification circuit breaker configuration: {}",
+			e
+		)
+	})?;
+	let mut notification_service = NotificationService::builder().client_pool(Arc::new(
+		NotificationClientPool::with_circuit_breaker_config(circuit_breaker_config),
+	));
+	if let Some(audit_log) = FileAuditLog::from_env() {
+		tracing::info!(
+			"Recording notifications in audit log {}",
+			audit_log.path().display()
+		);
+		notification_service = notification_service.audit_log(Arc::new(audit_log));
+	}
+	let notification_service = notification_service.build();
 
 	let filter_service = Arc::new(FilterService::new());
-	let trigger_execution_service = Arc::new(TriggerExecutionService::new(
-		trigger_service.clone(),
-		notification_service,
-	));
+	let mut trigger_execution_service =
+		TriggerExecutionService::new(trigger_service.clone(), notification_service);
+	if let Some(dead_letter_store) = DeadLetterStore::from_env() {
+		tracing::info!(
+			"Recording failed notifications in dead-letter store {}",
+			dead_letter_store.path().display()
+		);
+		trigger_execution_service =
+			trigger_execution_service.with_dead_letter_store(Arc::new(dead_letter_store));
+	}
+	let trigger_execution_service = Arc::new(trigger_execution_service);
 
-	let monitors = monitor_service.get_all();
-	let active_monitors = filter_active_monitors(monitors);
+	// Monitors paused until a later time are kept so that they resume once their window elapses
+	let active_monitors = monitor_service
+		.get_all()
+		.into_values()
+		.filter(|m| !m.paused)
+		.collect::<Vec<_>>();
 	let networks = network_service.get_all();
 
 	Ok((
@@ -130,6 +236,9 @@ where
 
 /// Creates a block handler function that processes new blocks from the blockchain.
 ///
+/// Blocks are processed by the default [`BlockProcessorRegistry`], see
+/// [`create_block_handler_with_registry`].
+///
 /// # Arguments
 /// * `shutdown_tx` - Watch channel for shutdown signals
 /// * `filter_service` - Service for filtering blockchain data
@@ -144,6 +253,38 @@ pub fn create_block_handler<P: ClientPoolTrait + 'static>(
 	active_monitors: Vec<Monitor>,
 	client_pools: Arc<P>,
 	contract_specs: Vec<(String, ContractSpec)>,
+) -> Arc<impl Fn(BlockType, Network) -> BoxFuture<'static, ProcessedBlock> + Send + Sync> {
+	create_block_handler_with_registry(
+		shutdown_tx,
+		filter_service,
+		active_monitors,
+		client_pools,
+		contract_specs,
+		Arc::new(BlockProcessorRegistry::default()),
+	)
+}
+
+/// Creates a block handler function dispatching new blocks to the processor registered for
+/// the type of their network.
+///
+/// Blocks of networks without a registered processor have no match.
+///
+/// # Arguments
+/// * `shutdown_tx` - Watch channel for shutdown signals
+/// * `filter_service` - Service for filtering blockchain data
+/// * `active_monitors` - List of active monitors
+/// * `client_pools` - Client pools for accessing blockchain clients
+/// * `registry` - Block processors indexed by blockchain type
+///
+/// # Returns
+/// Returns a function that handles incoming blocks
+pub fn create_block_handler_with_registry<P: ClientPoolTrait + 'static>(
+	shutdown_tx: watch::Sender<bool>,
+	filter_service: Arc<FilterService>,
+	active_monitors: Vec<Monitor>,
+	client_pools: Arc<P>,
+	contract_specs: Vec<(String, ContractSpec)>,
+	registry: Arc<BlockProcessorRegistry<P>>,
 ) -> Arc<impl Fn(BlockType, Network) -> BoxFuture<'static, ProcessedBlock> + Send + Sync> {
 	Arc::new(
 		move |block: BlockType, network: Network| -> BoxFuture<'static, ProcessedBlock> {
@@ -152,8 +293,12 @@ pub fn create_block_handler<P: ClientPoolTrait + 'static>(
 			let client_pools = client_pools.clone();
 			let shutdown_tx = shutdown_tx.clone();
 			let contract_specs = contract_specs.clone();
+			let registry = registry.clone();
 			Box::pin(async move {
-				let applicable_monitors = filter_network_monitors(&active_monitors, &network.slug);
+				let applicable_monitors = filter_active_monitors(filter_network_monitors(
+					&active_monitors,
+					&network.slug,
+				));
 
 				let mut processed_block = ProcessedBlock {
 					block_number: block.number().unwrap_or(0),
@@ -162,44 +307,17 @@ pub fn create_block_handler<P: ClientPoolTrait + 'static>(
 				};
 
 				if !applicable_monitors.is_empty() {
-					let mut shutdown_rx = shutdown_tx.subscribe();
-
-					let matches = match network.network_type {
-						BlockChainType::EVM => match client_pools.get_evm_client(&network).await {
-							Ok(client) => {
-								process_block(
-									client.as_ref(),
-									&network,
-									&block,
-									&applicable_monitors,
-									Some(&contract_specs),
-									&filter_service,
-									&mut shutdown_rx,
-								)
-								.await
-							}
-							Err(_) => None,
-						},
-						BlockChainType::Stellar => {
-							match client_pools.get_stellar_client(&network).await {
-								Ok(client) => {
-									process_block(
-										client.as_ref(),
-										&network,
-										&block,
-										&applicable_monitors,
-										Some(&contract_specs),
-										&filter_service,
-										&mut shutdown_rx,
-									)
-									.await
-								}
-								Err(_) => None,
-							}
-						}
-						BlockChainType::Midnight => None,
-						BlockChainType::Solana => None,
-					};
+					let matches = registry
+						.process(BlockProcessingContext {
+							client_pool: client_pools,
+							network,
+							block,
+							monitors: applicable_monitors,
+							contract_specs,
+							filter_service,
+							shutdown_rx: shutdown_tx.subscribe(),
+						})
+						.await;
 
 					processed_block.processing_results = matches.unwrap_or_default();
 				}
@@ -212,6 +330,9 @@ pub fn create_block_handler<P: ClientPoolTrait + 'static>(
 
 /// Processes a single block for all applicable monitors.
 ///
+/// Matches of monitors with `enrichment` settings are enriched with the results of their
+/// contract calls.
+///
 /// # Arguments
 /// * `client` - The client to use to process the block
 /// * `network` - The network the block belongs to
@@ -231,10 +352,20 @@ pub async fn process_block<T>(
 where
 	T: BlockChainClient + BlockFilterFactory<T>,
 {
+	let matches = async {
+		let matches = filter_service
+			.filter_block(client, network, block, applicable_monitors, contract_specs)
+			.await
+			.ok()?;
+		Some(
+			filter_service
+				.enrich_matches(client, network, matches)
+				.await,
+		)
+	};
+
 	tokio::select! {
-		result = filter_service.filter_block(client, network, block, applicable_monitors, contract_specs) => {
-			result.ok()
-		}
+		matches = matches => matches,
 		_ = shutdown_rx.changed() => {
 			tracing::info!("Shutting down block processing task");
 			None
@@ -242,6 +373,27 @@ where
 	}
 }
 
+/// Reports a network whose blockchain type has no network watcher implementation yet.
+///
+/// The error is logged when created, so callers can simply skip the network instead of
+/// aborting the whole service.
+///
+/// # Arguments
+/// * `network` - The network that cannot be watched
+///
+/// # Returns
+/// Returns the `BlockChainError::UnsupportedOperation` describing the skipped network
+pub fn unsupported_network_error(network: &Network) -> BlockChainError {
+	BlockChainError::unsupported_operation(
+		format!(
+			"Skipping network '{}': {:?} networks are not supported yet",
+			network.slug, network.network_type
+		),
+		None,
+		None,
+	)
+}
+
 /// Get contract specs for all applicable monitors
 ///
 /// # Arguments
@@ -277,7 +429,8 @@ pub async fn get_contract_specs<P: ClientPoolTrait + 'static>(
 							};
 
 							contract_specs.push((
-								stellar_helpers::normalize_address(&monitored_addr.address),
+								network_address_normalizer(network)
+									.normalize(&monitored_addr.address),
 								ContractSpec::Stellar(parsed_spec.clone()),
 							))
 						} else {
@@ -327,9 +480,10 @@ pub async fn get_contract_specs<P: ClientPoolTrait + 'static>(
 				}
 				BlockChainType::EVM => {
 					let mut contract_specs = Vec::new();
-					// First collect addresses that have contract specs configured in the monitor
+					// Collect addresses that have contract specs configured in the monitor, merged
+					// with any additional specs (e.g. proxy and implementation ABIs)
 					for monitored_addr in &monitor.addresses {
-						if let Some(spec) = &monitored_addr.contract_spec {
+						if let Some(spec) = &monitored_addr.merged_contract_spec() {
 							let parsed_spec = match spec {
 								ContractSpec::EVM(spec) => spec,
 								_ => {
@@ -344,7 +498,8 @@ pub async fn get_contract_specs<P: ClientPoolTrait + 'static>(
 							contract_specs.push((
 								format!(
 									"0x{}",
-									evm_helpers::normalize_address(&monitored_addr.address)
+									network_address_normalizer(network)
+										.normalize(&monitored_addr.address)
 								),
 								ContractSpec::EVM(parsed_spec.clone()),
 							))
@@ -362,12 +517,121 @@ pub async fn get_contract_specs<P: ClientPoolTrait + 'static>(
 	all_specs
 }
 
+/// Resolves the ENS names used as monitored addresses to their addresses
+///
+/// The names of a monitor are resolved on the first of its EVM networks with an ENS registry
+/// configured. Each name is only resolved once per network.
+///
+/// # Arguments
+/// * `client_pool` - The client pool to use to resolve the names
+/// * `networks` - The configured networks, by slug
+/// * `monitors` - The monitors to resolve the names of
+///
+/// # Returns
+/// Returns the monitors with their ENS names replaced by the resolved addresses, or an error if
+/// a name cannot be resolved
+pub async fn resolve_ens_names<P: ClientPoolTrait + 'static>(
+	client_pool: &Arc<P>,
+	networks: &HashMap<String, Network>,
+	monitors: Vec<Monitor>,
+) -> Result<Vec<Monitor>> {
+	let mut resolved_names: HashMap<(String, String), String> = HashMap::new();
+	let mut resolved_monitors = Vec::with_capacity(monitors.len());
+
+	for mut monitor in monitors {
+		if !monitor
+			.addresses
+			.iter()
+			.any(|address| evm_helpers::is_ens_name(&address.address))
+		{
+			resolved_monitors.push(monitor);
+			continue;
+		}
+
+		let (network, registry) = monitor
+			.networks
+			.iter()
+			.filter_map(|slug| networks.get(slug))
+			.filter(|network| network.network_type == BlockChainType::EVM)
+			.find_map(|network| Some((network, network.ens_registry.as_deref()?)))
+			.ok_or_else(|| {
+				anyhow::anyhow!(
+					"Monitor '{}' uses ENS names, but none of its networks has an ens_registry",
+					monitor.name
+				)
+			})?;
+		let client = client_pool.get_evm_client(network).await.map_err(|e| {
+			anyhow::anyhow!(
+				"Failed to get EVM client for network '{}': {}",
+				network.slug,
+				e
+			)
+		})?;
+
+		for address in &mut monitor.addresses {
+			if !evm_helpers::is_ens_name(&address.address) {
+				continue;
+			}
+
+			let name = address.address.trim().to_lowercase();
+			let key = (network.slug.clone(), name.clone());
+			let resolved = match resolved_names.get(&key) {
+				Some(resolved) => resolved.clone(),
+				None => {
+					let resolved = client
+						.resolve_ens_name(&name, registry)
+						.await
+						.map_err(|e| {
+							anyhow::anyhow!("Failed to resolve ENS name '{}': {}", name, e)
+						})?
+						.ok_or_else(|| {
+							anyhow::anyhow!(
+								"ENS name '{}' does not resolve to an address on network '{}'",
+								name,
+								network.slug
+							)
+						})?;
+					resolved_names.insert(key, resolved.clone());
+					resolved
+				}
+			};
+
+			tracing::info!(
+				monitor = %monitor.name,
+				"Resolved ENS name {} to {}",
+				name,
+				resolved
+			);
+			address.address = resolved;
+		}
+
+		resolved_monitors.push(monitor);
+	}
+
+	Ok(resolved_monitors)
+}
+
 /// Creates a trigger handler function that processes trigger events from the block processing
 /// pipeline.
 ///
+/// With a queue configuration, processed blocks are queued for a single task executing their
+/// triggers in order, and the handle returned for a block completes once the block is queued.
+/// Without one, each block's triggers are executed in their own task, and the returned handle
+/// completes once they have been executed.
+///
+/// In both cases the handle resolves to the block's `TriggerCompletion`, which only reports
+/// the triggers as executed once they have run. Blocks dropped from the queue or abandoned on
+/// shutdown are reported as not executed.
+///
+/// With a dispatched match store, the matches dispatched for each block are persisted, and a
+/// block processed again after an interruption only dispatches the remaining matches.
+///
 /// # Arguments
 /// * `shutdown_tx` - Watch channel for shutdown signals
 /// * `trigger_service` - Service for executing triggers
+/// * `active_monitors_trigger_scripts` - Trigger condition scripts of the active monitors
+/// * `queue_config` - Optional capacity and backpressure policy of the trigger queue
+/// * `dispatched_matches` - Optional store of the matches dispatched for each block
 ///
 /// # Returns
 /// Returns a function that handles trigger execution for matching monitors
@@ -375,34 +639,159 @@ pub fn create_trigger_handler<S: TriggerExecutionServiceTrait + Send + Sync + 's
 	shutdown_tx: watch::Sender<bool>,
 	trigger_service: Arc<S>,
 	active_monitors_trigger_scripts: HashMap<String, (ScriptLanguage, String)>,
-) -> Arc<impl Fn(&ProcessedBlock) -> tokio::task::JoinHandle<()> + Send + Sync> {
+	queue_config: Option<TriggerQueueConfig>,
+	dispatched_matches: Option<Arc<DispatchedMatchStore>>,
+) -> Arc<impl Fn(&ProcessedBlock) -> tokio::task::JoinHandle<TriggerCompletion> + Send + Sync> {
+	let deduplicator = Arc::new(MatchDeduplicator::new());
+	let queue = queue_config.map(|config| {
+		let queue = Arc::new(TriggerQueue::new(config));
+		let mut shutdown_rx = shutdown_tx.subscribe();
+		let trigger_service = trigger_service.clone();
+		let trigger_scripts = active_monitors_trigger_scripts.clone();
+		let deduplicator = deduplicator.clone();
+		let dispatched_matches = dispatched_matches.clone();
+		let consumer_queue = queue.clone();
+
+		tokio::spawn(async move {
+			tokio::select! {
+				_ = async {
+					loop {
+						let (block, signal) = consumer_queue.pop().await;
+						execute_block_triggers(&block, &*trigger_service, &trigger_scripts, &deduplicator, dispatched_matches.as_deref()).await;
+						signal.complete();
+					}
+				} => {}
+				_ = shutdown_rx.changed() => {
+					tracing::info!("Shutting down trigger queue task");
+				}
+			}
+		});
+		queue
+	});
+
 	Arc::new(move |block: &ProcessedBlock| {
 		let mut shutdown_rx = shutdown_tx.subscribe();
 		let trigger_service = trigger_service.clone();
 		let trigger_scripts = active_monitors_trigger_scripts.clone();
+		let deduplicator = deduplicator.clone();
+		let dispatched_matches = dispatched_matches.clone();
+		let queue = queue.clone();
 		let block = block.clone();
 
 		tokio::spawn(async move {
 			tokio::select! {
-				_ = async {
+				completion = async {
 					if block.processing_results.is_empty() {
-						return;
+						return TriggerCompletion::completed();
 					}
-					let filtered_matches = run_trigger_filters(&block.processing_results, &block.network_slug, &trigger_scripts).await;
-					for monitor_match in &filtered_matches {
-						if let Err(e) = handle_match(monitor_match.clone(), &*trigger_service, &trigger_scripts).await {
-							TriggerError::execution_error(e.to_string(), Some(e.into()), None);
+					match queue {
+						Some(queue) => queue.push(block).await,
+						None => {
+							execute_block_triggers(&block, &*trigger_service, &trigger_scripts, &deduplicator, dispatched_matches.as_deref()).await;
+							TriggerCompletion::completed()
 						}
 					}
-				} => {}
+				} => completion,
 				_ = shutdown_rx.changed() => {
 					tracing::info!("Shutting down trigger handling task");
+					TriggerCompletion::abandoned()
 				}
 			}
 		})
 	})
 }
 
+/// Executes the triggers of the matches of a processed block
+///
+/// Every match is recorded as the last match of its monitor. Matches duplicating a match
+/// notified in a recent block are suppressed, for the monitors with `match_dedup`. Matches
+/// already dispatched for the block before an interruption are skipped, and each match is
+/// recorded as dispatched once handled.
+///
+/// # Arguments
+/// * `block` - The processed block
+/// * `trigger_service` - Service for executing triggers
+/// * `trigger_scripts` - Trigger condition scripts of the active monitors
+/// * `deduplicator` - Tracker of the recently notified matches
+/// * `dispatched_matches` - Optional store of the matches dispatched for each block
+async fn execute_block_triggers<S: TriggerExecutionServiceTrait>(
+	block: &ProcessedBlock,
+	trigger_service: &S,
+	trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
+	deduplicator: &MatchDeduplicator,
+	dispatched_matches: Option<&DispatchedMatchStore>,
+) {
+	let filtered_matches = run_trigger_filters(
+		&block.processing_results,
+		&block.network_slug,
+		trigger_scripts,
+	)
+	.await;
+	let already_dispatched = match dispatched_matches {
+		Some(store) => store
+			.get_dispatched(&block.network_slug, block.block_number)
+			.await
+			.unwrap_or_else(|e| {
+				tracing::warn!(
+					block = block.block_number,
+					"Failed to read dispatched matches: {}",
+					e
+				);
+				HashSet::new()
+			}),
+		None => HashSet::new(),
+	};
+	for monitor_match in &filtered_matches {
+		let monitor_name = match monitor_match {
+			MonitorMatch::EVM(m) => &m.monitor.name,
+			MonitorMatch::Stellar(m) => &m.monitor.name,
+		};
+		trigger_service.record_match(monitor_name);
+
+		let match_id = monitor_match.match_id();
+		if already_dispatched.contains(&match_id) {
+			tracing::debug!(
+				match_id = %match_id,
+				block = block.block_number,
+				"Match already dispatched before an interruption, skipping"
+			);
+			continue;
+		}
+		if deduplicator.is_duplicate(monitor_match, block.block_number) {
+			tracing::debug!(
+				monitor = %monitor_name,
+				block = block.block_number,
+				"Duplicate match suppressed"
+			);
+			MATCHES_DEDUPLICATED_TOTAL
+				.with_label_values(&[monitor_name])
+				.inc();
+		} else if let Err(e) =
+			handle_match(monitor_match.clone(), trigger_service, trigger_scripts).await
+		{
+			TriggerError::execution_error(e.to_string(), Some(e.into()), None);
+		}
+		if let Some(store) = dispatched_matches {
+			if let Err(e) = store
+				.record_dispatched(&block.network_slug, block.block_number, &match_id)
+				.await
+			{
+				tracing::warn!(
+					match_id = %match_id,
+					block = block.block_number,
+					"Failed to record dispatched match: {}",
+					e
+				);
+			}
+		}
+	}
+	if let Some(store) = dispatched_matches {
+		if let Err(e) = store.prune(&block.network_slug, block.block_number).await {
+			tracing::warn!("Failed to prune dispatched matches: {}", e);
+		}
+	}
+}
+
 /// Checks if a network has any active monitors.
 ///
 /// # Arguments
@@ -414,20 +803,22 @@ pub fn create_trigger_handler<S: TriggerExecutionServiceTrait + Send + Sync + 's
 pub fn has_active_monitors(monitors: &[Monitor], network_slug: &String) -> bool {
 	monitors
 		.iter()
-		.any(|m| m.networks.contains(network_slug) && !m.paused)
+		.any(|m| m.networks.contains(network_slug) && !m.is_paused())
 }
 
 /// Filters out paused monitors from the provided collection.
 ///
+/// Monitors with a `paused_until` time in the future are treated as paused until that time.
+///
 /// # Arguments
-/// * `monitors` - HashMap of monitors to filter
+/// * `monitors` - List of monitors to filter
 ///
 /// # Returns
 /// Returns a vector containing only active (non-paused) monitors
-fn filter_active_monitors(monitors: HashMap<String, Monitor>) -> Vec<Monitor> {
+fn filter_active_monitors(monitors: Vec<Monitor>) -> Vec<Monitor> {
 	monitors
-		.into_values()
-		.filter(|m| !m.paused)
+		.into_iter()
+		.filter(|m| !m.is_paused())
 		.collect::<Vec<_>>()
 }
 
@@ -454,14 +845,15 @@ async fn execute_trigger_condition(
 ) -> bool {
 	let executor = ScriptExecutorFactory::create(&script_content.0, &script_content.1);
 
-	let result = executor
-		.execute(
-			monitor_match.clone(),
-			&trigger_condition.timeout_ms,
-			trigger_condition.arguments.as_deref(),
-			false,
-		)
-		.await;
+	let result = execute_with_retry(
+		executor.as_ref(),
+		monitor_match,
+		&trigger_condition.timeout_ms,
+		trigger_condition.arguments.as_deref(),
+		false,
+		trigger_condition.retry_policy.as_ref(),
+	)
+	.await;
 
 	match result {
 		Ok(true) => true,
@@ -527,14 +919,23 @@ mod tests {
 			Monitor, MonitorMatch, ScriptLanguage, StellarBlock, StellarMonitorMatch,
 			StellarTransaction, StellarTransactionInfo, TriggerConditions,
 		},
-		utils::tests::{builders::evm::monitor::MonitorBuilder, evm::receipt::ReceiptBuilder},
+		services::blockchain::MidnightClient,
+		utils::{
+			tests::{
+				builders::{evm::monitor::MonitorBuilder, network::NetworkBuilder},
+				evm::receipt::ReceiptBuilder,
+			},
+			JitterSetting, RetryConfig,
+		},
 	};
 	use alloy::{
 		consensus::{transaction::Recovered, Signed, TxEnvelope},
 		primitives::{Address, Bytes, TxKind, B256, U256},
 	};
+	use chrono::{Duration, Utc};
 	use std::io::Write;
 	use tempfile::NamedTempFile;
+	use tracing_test::traced_test;
 
 	// Helper function to create a temporary script file
 	fn create_temp_script(content: &str) -> NamedTempFile {
@@ -617,14 +1018,20 @@ mod tests {
 				monitor: create_test_monitor("test", vec![], false, script_path),
 				transaction: create_test_evm_transaction(),
 				receipt: Some(create_test_evm_transaction_receipt()),
+				revert_reason: None,
 				logs: Some(create_test_evm_logs()),
+				block_timestamp: None,
 				network_slug: "ethereum_mainnet".to_string(),
 				matched_on: MatchConditions {
 					functions: vec![],
 					events: vec![],
 					transactions: vec![],
+					operations: vec![],
+					aggregates: vec![],
+					percentage_changes: vec![],
 				},
 				matched_on_args: None,
+				enrichment: HashMap::new(),
 			})),
 			BlockChainType::Stellar => MonitorMatch::Stellar(Box::new(StellarMonitorMatch {
 				monitor: create_test_monitor("test", vec![], false, script_path),
@@ -635,8 +1042,12 @@ mod tests {
 					functions: vec![],
 					events: vec![],
 					transactions: vec![],
+					operations: vec![],
+					aggregates: vec![],
+					percentage_changes: vec![],
 				},
 				matched_on_args: None,
+				enrichment: HashMap::new(),
 			})),
 			BlockChainType::Midnight => unimplemented!(),
 			BlockChainType::Solana => unimplemented!(),
@@ -652,14 +1063,20 @@ mod tests {
 				monitor,
 				transaction: create_test_evm_transaction(),
 				receipt: Some(create_test_evm_transaction_receipt()),
+				revert_reason: None,
 				logs: Some(create_test_evm_logs()),
+				block_timestamp: None,
 				network_slug: "ethereum_mainnet".to_string(),
 				matched_on: MatchConditions {
 					functions: vec![],
 					events: vec![],
 					transactions: vec![],
+					operations: vec![],
+					aggregates: vec![],
+					percentage_changes: vec![],
 				},
 				matched_on_args: None,
+				enrichment: HashMap::new(),
 			})),
 			BlockChainType::Stellar => MonitorMatch::Stellar(Box::new(StellarMonitorMatch {
 				monitor,
@@ -670,8 +1087,12 @@ mod tests {
 					functions: vec![],
 					events: vec![],
 					transactions: vec![],
+					operations: vec![],
+					aggregates: vec![],
+					percentage_changes: vec![],
 				},
 				matched_on_args: None,
+				enrichment: HashMap::new(),
 			})),
 			BlockChainType::Midnight => unimplemented!(),
 			BlockChainType::Solana => unimplemented!(),
@@ -722,25 +1143,56 @@ mod tests {
 
 	#[test]
 	fn test_filter_active_monitors() {
-		let mut monitors = HashMap::new();
-		monitors.insert(
-			"1".to_string(),
+		let monitors = vec![
 			create_test_monitor("1", vec!["ethereum_mainnet"], false, None),
-		);
-		monitors.insert(
-			"2".to_string(),
 			create_test_monitor("2", vec!["stellar_mainnet"], true, None),
-		);
-		monitors.insert(
-			"3".to_string(),
 			create_test_monitor("3", vec!["ethereum_mainnet"], false, None),
-		);
+		];
 
 		let active_monitors = filter_active_monitors(monitors);
 		assert_eq!(active_monitors.len(), 2);
 		assert!(active_monitors.iter().all(|m| !m.paused));
 	}
 
+	#[test]
+	fn test_filter_active_monitors_paused_until() {
+		let future = (Utc::now() + Duration::hours(1)).to_rfc3339();
+		let past = (Utc::now() - Duration::hours(1)).to_rfc3339();
+		let monitors = vec![
+			MonitorBuilder::new()
+				.name("future")
+				.paused_until(&future)
+				.build(),
+			MonitorBuilder::new()
+				.name("past")
+				.paused_until(&past)
+				.build(),
+		];
+
+		let active_monitors = filter_active_monitors(monitors);
+		assert_eq!(active_monitors.len(), 1);
+		assert_eq!(active_monitors[0].name, "past");
+	}
+
+	#[test]
+	fn test_has_active_monitors_paused_until() {
+		let future = (Utc::now() + Duration::hours(1)).to_rfc3339();
+		let past = (Utc::now() - Duration::hours(1)).to_rfc3339();
+		let network = "ethereum_mainnet".to_string();
+
+		let paused = MonitorBuilder::new()
+			.networks(vec![network.clone()])
+			.paused_until(&future)
+			.build();
+		assert!(!has_active_monitors(&[paused], &network));
+
+		let resumed = MonitorBuilder::new()
+			.networks(vec![network.clone()])
+			.paused_until(&past)
+			.build();
+		assert!(has_active_monitors(&[resumed], &network));
+	}
+
 	#[test]
 	fn test_filter_network_monitors() {
 		let monitors = vec![
@@ -867,6 +1319,8 @@ print(result)
 			language: ScriptLanguage::Python,
 			script_path: temp_file.path().to_str().unwrap().to_string(),
 			timeout_ms: 1000,
+			script_checksum: None,
+			retry_policy: None,
 			arguments: None,
 		};
 		let match_item = create_mock_monitor_match_from_path(
@@ -888,6 +1342,8 @@ print(result)
 			language: ScriptLanguage::Python,
 			script_path: temp_file.path().to_str().unwrap().to_string(),
 			timeout_ms: 1000,
+			script_checksum: None,
+			retry_policy: None,
 			arguments: None,
 		};
 		let match_item = create_mock_monitor_match_from_path(
@@ -901,12 +1357,47 @@ print(result)
 		assert!(!result); // Should be false when script errors
 	}
 
+	#[tokio::test]
+	async fn test_execute_trigger_condition_retries_flaky_script() {
+		// Fails on the first run and succeeds once the marker file exists
+		let marker_dir = tempfile::tempdir().unwrap();
+		let marker = marker_dir.path().join("attempted");
+		let script_content = format!(
+			"cat > /dev/null; if [ -f '{0}' ]; then echo true; else touch '{0}'; exit 1; fi",
+			marker.display()
+		);
+		let mut trigger_condition = TriggerConditions {
+			language: ScriptLanguage::Bash,
+			script_path: "flaky.sh".to_string(),
+			timeout_ms: 1000,
+			script_checksum: None,
+			retry_policy: None,
+			arguments: None,
+		};
+		let match_item = create_mock_monitor_match_from_path(BlockChainType::EVM, None);
+		let script_content = (ScriptLanguage::Bash, script_content);
+
+		// Not retried without a retry policy
+		assert!(!execute_trigger_condition(&trigger_condition, &match_item, &script_content).await);
+
+		std::fs::remove_file(&marker).unwrap();
+		trigger_condition.retry_policy = Some(RetryConfig {
+			max_retries: 2,
+			initial_backoff: std::time::Duration::from_millis(10),
+			jitter: JitterSetting::None,
+			..Default::default()
+		});
+		assert!(execute_trigger_condition(&trigger_condition, &match_item, &script_content).await);
+	}
+
 	#[tokio::test]
 	async fn test_execute_trigger_condition_invalid_script() {
 		let trigger_condition = TriggerConditions {
 			language: ScriptLanguage::Python,
 			script_path: "non_existent_script.py".to_string(),
 			timeout_ms: 1000,
+			script_checksum: None,
+			retry_policy: None,
 			arguments: None,
 		};
 		let match_item = create_mock_monitor_match_from_path(
@@ -1193,4 +1684,50 @@ print(result)
 		let filtered = run_trigger_filters(&matches, "stellar_mainnet", &trigger_scripts).await;
 		assert_eq!(filtered.len(), 0); // Match should be filtered out because condition2 returns true
 	}
+
+	fn create_midnight_network() -> Network {
+		NetworkBuilder::new()
+			.name("Midnight Testnet")
+			.slug("midnight_testnet")
+			.network_type(BlockChainType::Midnight)
+			.build()
+	}
+
+	#[test]
+	#[traced_test]
+	fn test_unsupported_network_error_logs_midnight_network() {
+		let network = create_midnight_network();
+
+		let error = unsupported_network_error(&network);
+
+		assert!(matches!(error, BlockChainError::UnsupportedOperation(_)));
+		assert!(logs_contain(
+			"Skipping network 'midnight_testnet': Midnight networks are not supported yet"
+		));
+	}
+
+	#[tokio::test]
+	#[traced_test]
+	async fn test_process_block_midnight_network_does_not_panic() {
+		let network = create_midnight_network();
+		let monitor = create_test_monitor("test", vec!["midnight_testnet"], false, None);
+		let block = BlockType::Stellar(Box::new(create_test_stellar_block()));
+		let (_shutdown_tx, mut shutdown_rx) = watch::channel(false);
+
+		let matches = process_block(
+			&MidnightClient::new(&network),
+			&network,
+			&block,
+			&[monitor],
+			None,
+			&FilterService::new(),
+			&mut shutdown_rx,
+		)
+		.await;
+
+		assert!(matches.is_none());
+		assert!(logs_contain(
+			"Filtering Midnight blocks is not supported yet"
+		));
+	}
 }
@@ -0,0 +1,148 @@
+//! Registry of the block processors of each blockchain type.
+//!
+//! Block handlers dispatch every block to the processor registered for the type of its
+//! network, so that supporting a new chain only requires registering its processor.
+
+use futures::future::BoxFuture;
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::watch;
+
+use crate::{
+	bootstrap::process_block,
+	models::{BlockChainType, BlockType, ContractSpec, Monitor, MonitorMatch, Network},
+	services::{
+		blockchain::{ClientPoolTrait, MidnightClient},
+		filter::FilterService,
+	},
+};
+
+/// Everything needed to filter a block for the monitors of its network
+pub struct BlockProcessingContext<P> {
+	/// Pool to get the blockchain client of the network from
+	pub client_pool: Arc<P>,
+	/// The network the block belongs to
+	pub network: Network,
+	/// The block to process
+	pub block: BlockType,
+	/// Active monitors of the network
+	pub monitors: Vec<Monitor>,
+	/// Contract specs of the monitored contracts
+	pub contract_specs: Vec<(String, ContractSpec)>,
+	/// Service for filtering blockchain data
+	pub filter_service: Arc<FilterService>,
+	/// Receiver for shutdown signals
+	pub shutdown_rx: watch::Receiver<bool>,
+}
+
+/// Filters a block for the monitors of its network
+///
+/// Returns `None` if the block could not be processed, e.g. when the client of the network
+/// could not be created or on shutdown.
+pub type BlockProcessor<P> = Arc<
+	dyn Fn(BlockProcessingContext<P>) -> BoxFuture<'static, Option<Vec<MonitorMatch>>>
+		+ Send
+		+ Sync,
+>;
+
+/// Block processors indexed by blockchain type
+pub struct BlockProcessorRegistry<P> {
+	processors: HashMap<BlockChainType, BlockProcessor<P>>,
+}
+
+impl<P> BlockProcessorRegistry<P> {
+	/// Creates a registry without any processor
+	pub fn empty() -> Self {
+		Self {
+			processors: HashMap::new(),
+		}
+	}
+
+	/// Registers the processor of a blockchain type, replacing any previous one
+	pub fn register(&mut self, network_type: BlockChainType, processor: BlockProcessor<P>) {
+		self.processors.insert(network_type, processor);
+	}
+
+	/// Returns whether a processor is registered for a blockchain type
+	pub fn supports(&self, network_type: &BlockChainType) -> bool {
+		self.processors.contains_key(network_type)
+	}
+
+	/// Processes a block with the processor registered for the type of its network
+	///
+	/// # Returns
+	/// * `Option<Vec<MonitorMatch>>` - The matches of the block, or `None` if no processor
+	///   is registered for the network type or the block could not be processed
+	pub async fn process(&self, context: BlockProcessingContext<P>) -> Option<Vec<MonitorMatch>> {
+		let processor = self.processors.get(&context.network.network_type)?;
+		processor(context).await
+	}
+}
+
+impl<P: ClientPoolTrait + 'static> Default for BlockProcessorRegistry<P> {
+	/// Creates a registry with the processors of the EVM, Stellar and Midnight chains
+	fn default() -> Self {
+		let mut registry = Self::empty();
+		registry.register(
+			BlockChainType::EVM,
+			Arc::new(|mut context: BlockProcessingContext<P>| {
+				Box::pin(async move {
+					let client = context
+						.client_pool
+						.get_evm_client(&context.network)
+						.await
+						.ok()?;
+					process_block(
+						client.as_ref(),
+						&context.network,
+						&context.block,
+						&context.monitors,
+						Some(&context.contract_specs),
+						&context.filter_service,
+						&mut context.shutdown_rx,
+					)
+					.await
+				})
+			}),
+		);
+		registry.register(
+			BlockChainType::Stellar,
+			Arc::new(|mut context: BlockProcessingContext<P>| {
+				Box::pin(async move {
+					let client = context
+						.client_pool
+						.get_stellar_client(&context.network)
+						.await
+						.ok()?;
+					process_block(
+						client.as_ref(),
+						&context.network,
+						&context.block,
+						&context.monitors,
+						Some(&context.contract_specs),
+						&context.filter_service,
+						&mut context.shutdown_rx,
+					)
+					.await
+				})
+			}),
+		);
+		registry.register(
+			BlockChainType::Midnight,
+			Arc::new(|mut context: BlockProcessingContext<P>| {
+				Box::pin(async move {
+					process_block(
+						&MidnightClient::new(&context.network),
+						&context.network,
+						&context.block,
+						&context.monitors,
+						Some(&context.contract_specs),
+						&context.filter_service,
+						&mut context.shutdown_rx,
+					)
+					.await
+				})
+			}),
+		);
+		registry
+	}
+}
@@ -27,27 +27,44 @@ pub mod utils;
 use crate::{
 	bootstrap::{
 		create_block_handler, create_trigger_handler, get_contract_specs, has_active_monitors,
-		initialize_services, Result,
+		initialize_services, load_services_from_config_file, resolve_ens_names,
+		unsupported_network_error, Result,
 	},
-	models::{BlockChainType, Network, ScriptLanguage},
+	models::{BlockChainType, ConfigSchema, MonitorMatch, Network, ScriptLanguage, SecretString},
 	repositories::{
 		MonitorRepository, MonitorService, NetworkRepository, NetworkService, TriggerRepository,
+		TriggerService,
 	},
 	services::{
-		blockchain::{ClientPool, ClientPoolTrait},
-		blockwatcher::{BlockTracker, BlockTrackerTrait, BlockWatcherService, FileBlockStorage},
+		blockchain::{ClientPool, ClientPoolConfig, ClientPoolTrait},
+		blockwatcher::{
+			BlockTracker, BlockTrackerTrait, BlockWatcherService, FailFastConfig, FileBlockStorage,
+			FileBlockStorageConfig, NdjsonSink, NetworkFetchLimiter, TriggerQueueConfig,
+			WatcherFailureTracker, FAIL_FAST_ENV, FAIL_FAST_MAX_FAILURES_ENV,
+			MAX_CONCURRENT_NETWORK_FETCHES_ENV,
+		},
 		filter::FilterService,
-		trigger::{TriggerExecutionService, TriggerExecutionServiceTrait},
+		notification::{DeadLetterStore, NotificationService, DEAD_LETTER_PATH_ENV},
+		trigger::{DispatchedMatchStore, TriggerExecutionService, TriggerExecutionServiceTrait},
 	},
 	utils::{
 		constants::DOCUMENTATION_URL,
 		logging::setup_logging,
-		metrics::server::create_metrics_server,
+		metrics::server::{
+			create_metrics_server, run_metrics_only, AdminApiState, EvaluateApi,
+			METRICS_ONLY_REFRESH_INTERVAL,
+		},
 		monitor::{
-			execution::{execute_monitor, MonitorExecutionConfig},
+			execution::{
+				execute_monitor, write_matches_json, BlockSelector, MonitorExecutionConfig,
+				OutputFormat,
+			},
+			replay::{replay_blocks, ReplayConfig},
 			MonitorExecutionError,
 		},
 		parse_string_to_bytes_size,
+		selftest::{run_selftest, TELEGRAM_API_URL},
+		trigger::{drain_dead_letter, test_trigger},
 	},
 };
 
@@ -55,11 +72,15 @@ use clap::Parser;
 use dotenvy::dotenv_override;
 use std::collections::HashMap;
 use std::env::{set_var, var};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::sync::{watch, Mutex};
 use tokio_cron_scheduler::JobScheduler;
 use tracing::{error, info, instrument};
 
+/// Delay between attempts to start a failing network watcher in fail-fast mode
+const FAIL_FAST_STARTUP_RETRY_DELAY: tokio::time::Duration = tokio::time::Duration::from_secs(5);
+
 type MonitorServiceType = MonitorService<
 	MonitorRepository<NetworkRepository, TriggerRepository>,
 	NetworkRepository,
@@ -69,24 +90,26 @@ type MonitorServiceType = MonitorService<
 /// Fields:
 /// * `path` - Path to the monitor configuration file
 /// * `network_slug` - Optional network identifier to run the monitor against
-/// * `block_number` - Optional specific block number to test the monitor against
+/// * `block` - Optional block to test the monitor against
 /// * `monitor_service` - Service handling monitor operations
 /// * `network_service` - Service handling network operations
 /// * `filter_service` - Service handling filter operations
 /// * `trigger_execution_service` - Service handling trigger execution
 /// * `active_monitors_trigger_scripts` - Map of active monitors and their trigger scripts
 /// * `raw_output` - Whether to print the raw output of the monitor execution
+/// * `output` - Format in which the results of the monitor execution are printed
 /// * `client_pool` - Client pool of blockchain clients
 struct MonitorExecutionTestConfig {
 	pub path: String,
 	pub network_slug: Option<String>,
-	pub block_number: Option<u64>,
+	pub block: Option<BlockSelector>,
 	pub monitor_service: Arc<Mutex<MonitorServiceType>>,
 	pub network_service: Arc<Mutex<NetworkService<NetworkRepository>>>,
 	pub filter_service: Arc<FilterService>,
 	pub trigger_execution_service: Arc<TriggerExecutionService<TriggerRepository>>,
 	pub active_monitors_trigger_scripts: HashMap<String, (ScriptLanguage, String)>,
 	pub raw_output: bool,
+	pub output: OutputFormat,
 	pub client_pool: Arc<ClientPool>,
 }
 
@@ -113,14 +136,27 @@ struct Cli {
 	#[arg(long, value_name = "SIZE", value_parser = parse_string_to_bytes_size)]
 	log_max_size: Option<u64>,
 
-	/// Address to start the metrics server on (default: 127.0.0.1:8081)
-	#[arg(long, value_name = "HOST:PORT")]
+	/// Number of days to keep rolled log files before deleting them
+	#[arg(long, value_name = "DAYS")]
+	log_retention_days: Option<u64>,
+
+	/// Address to start the metrics server on, either HOST:PORT, [IPV6]:PORT or unix:PATH for
+	/// a Unix domain socket (default: 127.0.0.1:8081). [::] listens on IPv4 and IPv6
+	#[arg(long, value_name = "HOST:PORT|unix:PATH")]
 	metrics_address: Option<String>,
 
 	/// Enable metrics server
 	#[arg(long)]
 	metrics: bool,
 
+	/// Enable the authenticated /evaluate endpoint on the metrics server
+	#[arg(long)]
+	evaluate_api: bool,
+
+	/// Enable the authenticated /admin endpoints pausing notifications on the metrics server
+	#[arg(long)]
+	admin_api: bool,
+
 	/// Path to the monitor to execute
 	#[arg(long, value_name = "MONITOR_PATH")]
 	monitor_path: Option<String>,
@@ -129,13 +165,95 @@ struct Cli {
 	#[arg(long, value_name = "NETWORK_SLUG")]
 	network: Option<String>,
 
-	/// Block number to execute the monitor for
-	#[arg(long, value_name = "BLOCK_NUMBER")]
-	block: Option<u64>,
+	/// Block to execute the monitor for: a block number, latest, latest-N or finalized
+	#[arg(long, value_name = "BLOCK")]
+	block: Option<BlockSelector>,
+
+	/// Format of the monitor execution results: text (default) or json. With json, the matches
+	/// are printed to stdout as a single JSON document and the logs are written to stderr
+	#[arg(long, value_name = "FORMAT", requires = "monitor_path")]
+	output: Option<OutputFormat>,
+
+	/// Path to a single file containing the networks, monitors and triggers to load instead
+	/// of the configuration directories
+	#[arg(long, value_name = "PATH")]
+	config_file: Option<String>,
+
+	/// Serve the metrics of the loaded configuration without watching any network or making
+	/// any RPC call
+	#[arg(long, conflicts_with_all = ["check", "test_trigger", "monitor_path", "replay", "drain_dead_letter"])]
+	metrics_only: bool,
 
 	/// Validate configuration files without starting the service
 	#[arg(long)]
 	check: bool,
+
+	/// Verify the connectivity of every network and trigger, print a pass/fail report and exit
+	/// with an error if any check fails. No notification is sent
+	#[arg(long, conflicts_with_all = ["check", "test_trigger", "monitor_path", "replay", "metrics_only", "drain_dead_letter"])]
+	selftest: bool,
+
+	/// Print the JSON Schema of a configuration file kind (monitor, network or trigger)
+	#[arg(long, value_name = "KIND")]
+	print_schema: Option<ConfigSchema>,
+
+	/// Send a sample notification through a trigger to verify its configuration
+	#[arg(long, value_name = "TRIGGER_NAME")]
+	test_trigger: Option<String>,
+
+	/// Path of the dead-letter store recording the notifications that could not be delivered
+	#[arg(long, value_name = "PATH")]
+	dead_letter_path: Option<String>,
+
+	/// Re-send the failed notifications of the dead-letter store, removing the ones sent
+	/// successfully, and exit
+	#[arg(long, conflicts_with_all = ["check", "test_trigger", "monitor_path", "replay"])]
+	drain_dead_letter: bool,
+
+	/// Maximum number of dead-letter notifications to re-send
+	#[arg(long, value_name = "COUNT", requires = "drain_dead_letter")]
+	max: Option<usize>,
+
+	/// Only re-send the dead-letter notifications of this trigger
+	#[arg(long, value_name = "TRIGGER_NAME", requires = "drain_dead_letter")]
+	trigger: Option<String>,
+
+	/// Exit with an error once a network watcher fails too many consecutive times, instead of
+	/// retrying indefinitely
+	#[arg(long)]
+	fail_fast: bool,
+
+	/// Number of consecutive failures of a network watcher that is fatal with --fail-fast
+	/// (default: 3)
+	#[arg(long, value_name = "COUNT")]
+	fail_fast_max_failures: Option<u32>,
+
+	/// Maximum number of networks polled concurrently across all network watchers
+	/// (default: unlimited)
+	#[arg(long, value_name = "COUNT")]
+	max_concurrent_network_fetches: Option<usize>,
+
+	/// Replay a block range of --network against the current monitors and print the matches,
+	/// without affecting the stored last processed block
+	#[arg(long, requires_all = ["network", "from", "to"])]
+	replay: bool,
+
+	/// First block to replay
+	#[arg(long, value_name = "BLOCK_NUMBER", requires = "replay")]
+	from: Option<u64>,
+
+	/// Last block to replay (inclusive)
+	#[arg(long, value_name = "BLOCK_NUMBER", requires = "replay")]
+	to: Option<u64>,
+
+	/// Send the notifications of the replayed matches through the monitors' triggers
+	#[arg(long, requires = "replay")]
+	fire_triggers: bool,
+
+	/// Write every match to stdout as a line of JSON (NDJSON) as it is produced, in addition
+	/// to the notifications. Logs are written to stderr unless written to file
+	#[arg(long, conflicts_with_all = ["check", "test_trigger", "monitor_path", "replay", "metrics_only", "selftest"])]
+	emit_stdout: bool,
 }
 
 impl Cli {
@@ -150,6 +268,13 @@ impl Cli {
 			set_var("LOG_MODE", "file");
 		}
 
+		// JSON output is printed to stdout, so logs are moved to stderr unless written to file
+		if (self.output == Some(OutputFormat::Json) || self.emit_stdout)
+			&& !var("LOG_MODE").is_ok_and(|mode| mode.eq_ignore_ascii_case("file"))
+		{
+			set_var("LOG_MODE", "stderr");
+		}
+
 		// Set log level from RUST_LOG if it exists
 		if let Ok(level) = var("RUST_LOG") {
 			set_var("LOG_LEVEL", level);
@@ -171,15 +296,54 @@ impl Cli {
 			set_var("LOG_MAX_SIZE", max_size.to_string());
 		}
 
+		// Log retention - override if CLI flag is set
+		if let Some(days) = &self.log_retention_days {
+			set_var("LOG_RETENTION_DAYS", days.to_string());
+		}
+
 		// Metrics server - override if CLI flag is set
 		if self.metrics {
 			set_var("METRICS_ENABLED", "true");
 		}
 
+		// Evaluate endpoint - override if CLI flag is set
+		if self.evaluate_api {
+			set_var("EVALUATE_API_ENABLED", "true");
+		}
+
+		// Admin endpoints - override if CLI flag is set
+		if self.admin_api {
+			set_var("ADMIN_API_ENABLED", "true");
+		}
+
+		// Fail-fast mode - override if CLI flag is set
+		if self.fail_fast {
+			set_var(FAIL_FAST_ENV, "true");
+		}
+
+		// Fail-fast threshold - override if CLI flag is set
+		if let Some(max_failures) = &self.fail_fast_max_failures {
+			set_var(FAIL_FAST_MAX_FAILURES_ENV, max_failures.to_string());
+		}
+
+		// Dead-letter store - override if CLI flag is set
+		if let Some(path) = &self.dead_letter_path {
+			set_var(DEAD_LETTER_PATH_ENV, path);
+		}
+
+		// Concurrent network fetches - override if CLI flag is set
+		if let Some(max_fetches) = &self.max_concurrent_network_fetches {
+			set_var(MAX_CONCURRENT_NETWORK_FETCHES_ENV, max_fetches.to_string());
+		}
+
 		// Metrics address - override if CLI flag is set
 		if let Some(address) = &self.metrics_address {
 			// Extract port from address if it's in HOST:PORT format
-			if let Some(port) = address.split(':').nth(1) {
+			if let Some(port) = address
+				.rsplit_once(':')
+				.map(|(_, port)| port)
+				.filter(|_| !address.starts_with("unix:"))
+			{
 				set_var("METRICS_PORT", port);
 			}
 		}
@@ -194,6 +358,12 @@ impl Cli {
 async fn main() -> Result<()> {
 	let cli = Cli::parse();
 
+	// If --print-schema is provided, only print the schema and exit
+	if let Some(schema) = cli.print_schema {
+		println!("{}", serde_json::to_string_pretty(&schema.json_schema())?);
+		return Ok(());
+	}
+
 	// Apply CLI options to environment
 	cli.apply_to_env();
 
@@ -204,10 +374,15 @@ async fn main() -> Result<()> {
 
 	// If --check flag is provided, only validate configuration and exit
 	if cli.check {
-		validate_configuration().await;
+		validate_configuration(cli.config_file.as_deref()).await;
 		return Ok(());
 	}
 
+	let (config_monitor_service, config_network_service, config_trigger_service) =
+		load_config_file_services(cli.config_file.as_deref())
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to initialize services: {}. Please refer to the documentation quickstart ({}) on how to configure the service.", e, DOCUMENTATION_URL))?;
+
 	let (
 		filter_service,
 		trigger_execution_service,
@@ -220,10 +395,48 @@ async fn main() -> Result<()> {
 		MonitorRepository<NetworkRepository, TriggerRepository>,
 		NetworkRepository,
 		TriggerRepository,
-	>(None, None, None)
+	>(
+		config_monitor_service,
+		config_network_service,
+		config_trigger_service,
+	)
 	.await
 	.map_err(|e| anyhow::anyhow!("Failed to initialize services: {}. Please refer to the documentation quickstart ({}) on how to configure the service.", e, DOCUMENTATION_URL))?;
 
+	// If a trigger name is provided, only send a sample notification through it and exit
+	if let Some(trigger_name) = &cli.test_trigger {
+		return test_trigger_execution(trigger_name, trigger_service).await;
+	}
+
+	// If --drain-dead-letter is provided, only re-send the failed notifications and exit
+	if cli.drain_dead_letter {
+		return drain_dead_letter_execution(cli.trigger.as_deref(), cli.max, trigger_service).await;
+	}
+
+	// In metrics-only mode, only serve the metrics of the loaded configuration, without
+	// creating any blockchain client or watching any network
+	if cli.metrics_only {
+		let metrics_address = metrics_bind_address(cli.metrics_address.as_deref());
+		info!(
+			"Metrics-only mode enabled, serving metrics on {}",
+			metrics_address
+		);
+		run_metrics_only(
+			metrics_address,
+			monitor_service,
+			network_service,
+			trigger_service,
+			METRICS_ONLY_REFRESH_INTERVAL,
+			async {
+				let _ = tokio::signal::ctrl_c().await;
+				info!("Shutdown signal received, stopping metrics server...");
+			},
+		)
+		.await
+		.map_err(|e| anyhow::anyhow!("Metrics server failed: {}", e))?;
+		return Ok(());
+	}
+
 	// Pre-load all trigger scripts into memory at startup to reduce file I/O operations.
 	// This prevents repeated file descriptor usage during script execution and improves performance
 	// by keeping scripts readily available in memory.
@@ -233,9 +446,51 @@ async fn main() -> Result<()> {
 	// Read CLI arguments to determine if we should test monitor execution
 	let monitor_path = cli.monitor_path.clone();
 	let network_slug = cli.network.clone();
-	let block_number = cli.block;
+	let block = cli.block;
+
+	let client_pool_config = ClientPoolConfig::from_env()
+		.map_err(|e| anyhow::anyhow!("Failed to read client pool configuration: {}", e))?;
+	let client_pool = Arc::new(ClientPool::with_config(client_pool_config));
+
+	// If --selftest is provided, only verify the connectivity of networks and triggers and exit
+	if cli.selftest {
+		return run_selftest_report(
+			&networks.values().cloned().collect::<Vec<_>>(),
+			trigger_service,
+			&client_pool,
+		)
+		.await;
+	}
 
-	let client_pool = Arc::new(ClientPool::new());
+	// Monitored addresses may be given as ENS names, which are resolved once at startup
+	let active_monitors = resolve_ens_names(&client_pool, &networks, active_monitors)
+		.await
+		.map_err(|e| anyhow::anyhow!("Failed to resolve ENS names: {}", e))?;
+
+	// If a block range is replayed, only print (or notify) its matches and exit
+	if cli.replay {
+		let (Some(network_slug), Some(from_block), Some(to_block)) =
+			(network_slug, cli.from, cli.to)
+		else {
+			return Err(anyhow::anyhow!("--replay requires --network, --from and --to").into());
+		};
+		let network = networks
+			.get(&network_slug)
+			.cloned()
+			.ok_or(anyhow::anyhow!("Network '{}' not found", network_slug))?;
+		return replay_block_range(ReplayConfig {
+			network,
+			from_block,
+			to_block,
+			monitors: active_monitors,
+			filter_service,
+			trigger_execution_service,
+			active_monitors_trigger_scripts,
+			fire_triggers: cli.fire_triggers,
+			client_pool,
+		})
+		.await;
+	}
 
 	let should_test_monitor_execution = monitor_path.is_some();
 	// If monitor path is provided, test monitor execution else start the service
@@ -246,13 +501,14 @@ async fn main() -> Result<()> {
 		return test_monitor_execution(MonitorExecutionTestConfig {
 			path: monitor_path,
 			network_slug,
-			block_number,
+			block,
 			monitor_service: monitor_service.clone(),
 			network_service: network_service.clone(),
 			filter_service: filter_service.clone(),
 			trigger_execution_service: trigger_execution_service.clone(),
 			active_monitors_trigger_scripts,
 			raw_output: false,
+			output: cli.output.unwrap_or_default(),
 			client_pool,
 		})
 		.await;
@@ -262,17 +518,55 @@ async fn main() -> Result<()> {
 	let metrics_enabled =
 		cli.metrics || var("METRICS_ENABLED").map(|v| v == "true").unwrap_or(false);
 
-	// Extract metrics address as a String to avoid borrowing issues
-	let metrics_address = if var("IN_DOCKER").unwrap_or_default() == "true" {
-		// For Docker, use METRICS_PORT env var if available
-		var("METRICS_PORT")
-			.map(|port| format!("0.0.0.0:{}", port))
-			.unwrap_or_else(|_| "0.0.0.0:8081".to_string())
+	let metrics_address = metrics_bind_address(cli.metrics_address.as_deref());
+
+	// The evaluate endpoint is served by the metrics server and requires a bearer token
+	let evaluate_api_enabled = var("EVALUATE_API_ENABLED")
+		.map(|v| v == "true")
+		.unwrap_or(false);
+	let evaluate_api = if !evaluate_api_enabled {
+		None
+	} else if !metrics_enabled {
+		error!("Evaluate endpoint requires the metrics server. Use --metrics or METRICS_ENABLED=true to enable it");
+		None
 	} else {
-		// For CLI, use the command line arg or default
-		cli.metrics_address
-			.map(|s| s.to_string())
-			.unwrap_or_else(|| "127.0.0.1:8081".to_string())
+		match var("EVALUATE_API_TOKEN") {
+			Ok(token) if !token.trim().is_empty() => Some(EvaluateApi {
+				token: SecretString::new(token.trim().to_string()),
+				monitor_service: monitor_service.clone(),
+				network_service: network_service.clone(),
+				filter_service: filter_service.clone(),
+				client_pool: client_pool.clone(),
+			}),
+			_ => {
+				error!("Evaluate endpoint disabled: EVALUATE_API_TOKEN must be set to a non-empty token");
+				None
+			}
+		}
+	};
+
+	// The admin endpoints are served by the metrics server and require a bearer token
+	let admin_api_enabled = var("ADMIN_API_ENABLED")
+		.map(|v| v == "true")
+		.unwrap_or(false);
+	let admin_api = if !admin_api_enabled {
+		None
+	} else if !metrics_enabled {
+		error!("Admin endpoints require the metrics server. Use --metrics or METRICS_ENABLED=true to enable them");
+		None
+	} else {
+		match var("ADMIN_API_TOKEN") {
+			Ok(token) if !token.trim().is_empty() => Some(AdminApiState {
+				token: SecretString::new(token.trim().to_string()),
+				pause: trigger_execution_service.notification_pause(),
+			}),
+			_ => {
+				error!(
+					"Admin endpoints disabled: ADMIN_API_TOKEN must be set to a non-empty token"
+				);
+				None
+			}
+		}
 	};
 
 	// Start the metrics server if successful
@@ -285,6 +579,8 @@ async fn main() -> Result<()> {
 			monitor_service.clone(),
 			network_service.clone(),
 			trigger_service.clone(),
+			evaluate_api,
+			admin_api,
 		) {
 			Ok(server) => Some(server),
 			Err(e) => {
@@ -297,9 +593,15 @@ async fn main() -> Result<()> {
 		None
 	};
 
+	// Networks are watched even if their monitors are temporarily paused, so that those monitors
+	// resume without a restart once their `paused_until` time has passed
 	let networks_with_monitors: Vec<Network> = networks
 		.values()
-		.filter(|network| has_active_monitors(&active_monitors.clone(), &network.slug))
+		.filter(|network| {
+			active_monitors
+				.iter()
+				.any(|m| m.networks.contains(&network.slug))
+		})
 		.cloned()
 		.collect();
 
@@ -327,6 +629,8 @@ async fn main() -> Result<()> {
 	let contract_specs = get_contract_specs(&client_pool, &network_monitors).await;
 
 	let (shutdown_tx, _) = watch::channel(false);
+	let staleness_checker = trigger_execution_service
+		.start_staleness_checker(active_monitors.clone(), shutdown_tx.subscribe());
 	let block_handler = create_block_handler(
 		shutdown_tx.clone(),
 		filter_service,
@@ -334,55 +638,117 @@ async fn main() -> Result<()> {
 		client_pool.clone(),
 		contract_specs,
 	);
+	let digest_flusher = trigger_execution_service.start_digest_flusher(shutdown_tx.subscribe());
+	let trigger_queue_config = TriggerQueueConfig::from_env()
+		.map_err(|e| anyhow::anyhow!("Failed to read trigger queue configuration: {}", e))?;
 	let trigger_handler = create_trigger_handler(
 		shutdown_tx.clone(),
 		trigger_execution_service,
 		active_monitors_trigger_scripts,
+		Some(trigger_queue_config),
+		// Matches dispatched before an interruption are not dispatched again on restart
+		Some(Arc::new(DispatchedMatchStore::new(PathBuf::from("data")))),
 	);
 
-	let file_block_storage = Arc::new(FileBlockStorage::default());
-	let block_watcher = BlockWatcherService::<FileBlockStorage, _, _, JobScheduler>::new(
+	let block_storage_config = FileBlockStorageConfig::from_env()
+		.map_err(|e| anyhow::anyhow!("Failed to read block storage configuration: {}", e))?;
+	let file_block_storage = Arc::new(FileBlockStorage::with_config(
+		PathBuf::from("data"),
+		block_storage_config,
+	));
+	let mut block_watcher = BlockWatcherService::<FileBlockStorage, _, _, JobScheduler>::new(
 		file_block_storage.clone(),
 		block_handler,
 		trigger_handler,
 		Arc::new(BlockTracker::new(1000, Some(file_block_storage.clone()))),
 	)
 	.await?;
+	if cli.emit_stdout {
+		block_watcher.add_match_sink(Arc::new(NdjsonSink::stdout()));
+	}
+
+	// Bound the networks polled at the same time, so that many chains do not all hit their
+	// RPC endpoints on the same tick
+	if let Some(fetch_limiter) = NetworkFetchLimiter::from_env()
+		.map_err(|e| anyhow::anyhow!("Failed to read network fetch limit configuration: {}", e))?
+	{
+		info!(
+			"Polling at most {} networks concurrently",
+			fetch_limiter.limit()
+		);
+		block_watcher.set_fetch_limiter(fetch_limiter);
+	}
+
+	// With fail-fast, a network watcher failing too many consecutive times stops the service
+	let (failure_tracker, mut fatal_failures) = match FailFastConfig::from_env()
+		.map_err(|e| anyhow::anyhow!("Failed to read fail-fast configuration: {}", e))?
+	{
+		Some(config) => {
+			info!(
+				"Fail-fast enabled, exiting after {} consecutive network watcher failures",
+				config.max_consecutive_failures
+			);
+			let (failure_tracker, fatal_failures) = WatcherFailureTracker::new(config);
+			block_watcher.set_failure_tracker(failure_tracker.clone());
+			(Some(failure_tracker), Some(fatal_failures))
+		}
+		None => (None, None),
+	};
 
 	for network in networks_with_monitors {
-		match network.network_type {
-			BlockChainType::EVM => {
-				if let Ok(client) = client_pool.get_evm_client(&network).await {
-					let _ = block_watcher
+		loop {
+			let started = match network.network_type {
+				BlockChainType::EVM => match client_pool.get_evm_client(&network).await {
+					Ok(client) => block_watcher
 						.start_network_watcher(&network, (*client).clone())
 						.await
-						.inspect_err(|e| {
-							error!("Failed to start EVM network watcher: {}", e);
-						});
-				} else {
-					error!("Failed to get EVM client for network: {}", network.slug);
-				}
-			}
-			BlockChainType::Stellar => {
-				if let Ok(client) = client_pool.get_stellar_client(&network).await {
-					let _ = block_watcher
+						.map_err(|e| format!("Failed to start EVM network watcher: {}", e)),
+					Err(_) => Err(format!(
+						"Failed to get EVM client for network: {}",
+						network.slug
+					)),
+				},
+				BlockChainType::Stellar => match client_pool.get_stellar_client(&network).await {
+					Ok(client) => block_watcher
 						.start_network_watcher(&network, (*client).clone())
 						.await
-						.inspect_err(|e| {
-							error!("Failed to start Stellar network watcher: {}", e);
-						});
-				} else {
-					error!("Failed to get Stellar client for network: {}", network.slug);
+						.map_err(|e| format!("Failed to start Stellar network watcher: {}", e)),
+					Err(_) => Err(format!(
+						"Failed to get Stellar client for network: {}",
+						network.slug
+					)),
+				},
+				BlockChainType::Midnight | BlockChainType::Solana => {
+					let _ = unsupported_network_error(&network);
+					break;
+				}
+			};
+
+			let Err(e) = started else {
+				break;
+			};
+			error!("{}", e);
+
+			// Without fail-fast, a watcher that fails to start is not retried
+			match &failure_tracker {
+				Some(failure_tracker) if !failure_tracker.record_failure(&network.slug, &e) => {
+					tokio::time::sleep(FAIL_FAST_STARTUP_RETRY_DELAY).await;
 				}
+				_ => break,
 			}
-			BlockChainType::Midnight => unimplemented!("Midnight not implemented"),
-			BlockChainType::Solana => unimplemented!("Solana not implemented"),
 		}
 	}
 
 	info!("Service started. Press Ctrl+C to shutdown");
 
 	let ctrl_c = tokio::signal::ctrl_c();
+	let fatal_failure = async {
+		match fatal_failures.as_mut() {
+			Some(fatal_failures) => fatal_failures.recv().await,
+			None => std::future::pending().await,
+		}
+	};
+	let mut fatal_error = None;
 
 	if let Some(metrics_future) = metrics_server {
 		tokio::select! {
@@ -398,10 +764,23 @@ async fn main() -> Result<()> {
 			}
 			info!("Metrics server stopped, shutting down services...");
 		  }
+		  failure = fatal_failure => {
+			fatal_error = failure;
+		  }
 		}
 	} else {
-		let _ = ctrl_c.await;
-		info!("Shutdown signal received, stopping services...");
+		tokio::select! {
+			_ = ctrl_c => {
+				info!("Shutdown signal received, stopping services...");
+			}
+			failure = fatal_failure => {
+				fatal_error = failure;
+			}
+		}
+	}
+
+	if let Some(failure) = &fatal_error {
+		error!("{}. Fail-fast enabled, stopping services...", failure);
 	}
 
 	// Common shutdown logic
@@ -418,12 +797,244 @@ async fn main() -> Result<()> {
 		}
 	}
 
+	// Send the pending digests before exiting
+	if let Some(digest_flusher) = digest_flusher {
+		if let Err(e) = digest_flusher.await {
+			error!("Digest flusher error: {}", e);
+		}
+	}
+
+	if let Some(staleness_checker) = staleness_checker {
+		if let Err(e) = staleness_checker.await {
+			error!("Staleness checker error: {}", e);
+		}
+	}
+
 	tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
 
+	if let Some(failure) = fatal_error {
+		return Err(anyhow::anyhow!("{}", failure).into());
+	}
+
 	info!("Shutdown complete");
 	Ok(())
 }
 
+/// Returns the address the metrics server binds to
+///
+/// # Arguments
+/// * `cli_address` - Address given on the command line, if any
+fn metrics_bind_address(cli_address: Option<&str>) -> String {
+	if let Some(address) = cli_address.filter(|address| address.starts_with("unix:")) {
+		// Unix domain sockets are used as is, also inside Docker
+		address.to_string()
+	} else if var("IN_DOCKER").unwrap_or_default() == "true" {
+		// For Docker, use METRICS_PORT env var if available and listen on all interfaces of
+		// the IP version of the command line address
+		let host = if cli_address.is_some_and(|address| address.starts_with('[')) {
+			"[::]"
+		} else {
+			"0.0.0.0"
+		};
+		var("METRICS_PORT")
+			.map(|port| format!("{}:{}", host, port))
+			.unwrap_or_else(|_| format!("{}:8081", host))
+	} else {
+		// For CLI, use the command line arg or default
+		cli_address
+			.map(|s| s.to_string())
+			.unwrap_or_else(|| "127.0.0.1:8081".to_string())
+	}
+}
+
+/// Sends a sample notification through a trigger and reports the outcome
+///
+/// # Arguments
+/// * `trigger_name` - Name of the trigger to test
+/// * `trigger_service` - Service to load the trigger from
+///
+/// # Returns
+/// * `Result<()>` - Success if the notification was sent, error otherwise
+async fn test_trigger_execution(
+	trigger_name: &str,
+	trigger_service: Arc<Mutex<TriggerService<TriggerRepository>>>,
+) -> Result<()> {
+	info!(trigger = trigger_name, "Sending test notification");
+
+	let notification_service = NotificationService::new();
+	match test_trigger(
+		trigger_name,
+		&*trigger_service.lock().await,
+		&notification_service,
+	)
+	.await
+	{
+		Ok(()) => {
+			info!(
+				trigger = trigger_name,
+				"Test notification sent successfully"
+			);
+			Ok(())
+		}
+		Err(e) => {
+			error!(
+				trigger = trigger_name,
+				"Failed to send test notification: {}", e
+			);
+			Err(Box::new(e))
+		}
+	}
+}
+
+/// Re-sends the failed notifications of the dead-letter store and reports a summary
+///
+/// # Arguments
+/// * `trigger_name` - Only re-send the notifications of this trigger, if set
+/// * `max` - Maximum number of notifications to re-send, if set
+/// * `trigger_service` - Service to load the triggers from
+///
+/// # Returns
+/// * `Result<()>` - Success if the store was drained, even if some notifications failed again
+async fn drain_dead_letter_execution(
+	trigger_name: Option<&str>,
+	max: Option<usize>,
+	trigger_service: Arc<Mutex<TriggerService<TriggerRepository>>>,
+) -> Result<()> {
+	let store = DeadLetterStore::from_env().ok_or_else(|| {
+		anyhow::anyhow!(
+			"No dead-letter store configured: set {} or --dead-letter-path",
+			DEAD_LETTER_PATH_ENV
+		)
+	})?;
+	info!(
+		path = %store.path().display(),
+		"Draining dead-letter store"
+	);
+
+	let notification_service = NotificationService::new();
+	let summary = drain_dead_letter(
+		&store,
+		&*trigger_service.lock().await,
+		&notification_service,
+		trigger_name,
+		max,
+	)
+	.await?;
+
+	info!(
+		delivered = summary.delivered,
+		failed = summary.failed,
+		skipped = summary.skipped,
+		remaining = summary.remaining,
+		"Dead-letter store drained"
+	);
+	println!(
+		"Delivered: {}, failed: {}, skipped (unknown trigger): {}, remaining: {}",
+		summary.delivered, summary.failed, summary.skipped, summary.remaining
+	);
+	Ok(())
+}
+
+/// Verifies the connectivity of every network and trigger and prints the report
+///
+/// # Arguments
+/// * `networks` - Networks to verify
+/// * `trigger_service` - Service to load the triggers to verify from
+/// * `client_pool` - Pool to get the blockchain clients from
+///
+/// # Returns
+/// * `Result<()>` - Success if every check passed, error otherwise
+async fn run_selftest_report(
+	networks: &[Network],
+	trigger_service: Arc<Mutex<TriggerService<TriggerRepository>>>,
+	client_pool: &ClientPool,
+) -> Result<()> {
+	let triggers = trigger_service
+		.lock()
+		.await
+		.get_all()
+		.into_values()
+		.collect::<Vec<_>>();
+	info!(
+		networks = networks.len(),
+		triggers = triggers.len(),
+		"Running self-test"
+	);
+
+	let report = run_selftest(networks, &triggers, client_pool, TELEGRAM_API_URL).await;
+	println!("{}", report);
+	if !report.passed() {
+		return Err(anyhow::anyhow!(
+			"Self-test failed: {} check(s) failed",
+			report.failures().count()
+		)
+		.into());
+	}
+	info!("Self-test passed");
+	Ok(())
+}
+
+/// Replays a block range against the current monitors and prints the matches
+///
+/// # Arguments
+/// * `config` - Configuration for the replay
+///
+/// # Returns
+/// * `Result<()>` - Ok(()) if the replay succeeds, or an error if it fails
+#[instrument(skip_all)]
+async fn replay_block_range(
+	config: ReplayConfig<TriggerExecutionService<TriggerRepository>, ClientPool>,
+) -> Result<()> {
+	let network_slug = config.network.slug.clone();
+	let fire_triggers = config.fire_triggers;
+	info!(
+		message = "Starting replay",
+		network = network_slug,
+		from = config.from_block,
+		to = config.to_block,
+		fire_triggers,
+	);
+
+	let processed_blocks = replay_blocks(config).await?;
+	let total = processed_blocks
+		.iter()
+		.map(|block| block.processing_results.len())
+		.sum::<usize>();
+	if total == 0 {
+		info!("No matches found");
+		return Ok(());
+	}
+
+	info!("=========== Replay Results ===========");
+	info!(total, "Found matches");
+	for block in &processed_blocks {
+		for monitor_match in &block.processing_results {
+			let (monitor_name, transaction_hash) = match monitor_match {
+				MonitorMatch::EVM(evm_match) => (
+					&evm_match.monitor.name,
+					format!("{:#x}", evm_match.transaction.hash()),
+				),
+				MonitorMatch::Stellar(stellar_match) => (
+					&stellar_match.monitor.name,
+					stellar_match.transaction.hash().to_string(),
+				),
+			};
+			info!(
+				block = block.block_number,
+				monitor = monitor_name,
+				transaction = transaction_hash,
+				match_id = monitor_match.match_id(),
+				"Match"
+			);
+		}
+	}
+
+	if fire_triggers {
+		info!("Executed the triggers of {} match(es)", total);
+	}
+	Ok(())
+}
+
 /// Tests the execution of a blockchain monitor configuration file.
 ///
 /// This function loads and executes a monitor configuration from the specified path,
@@ -442,7 +1053,8 @@ async fn main() -> Result<()> {
 #[instrument(skip_all)]
 async fn test_monitor_execution(config: MonitorExecutionTestConfig) -> Result<()> {
 	// Validate inputs first
-	if config.block_number.is_some() && config.network_slug.is_none() {
+	// Relative selectors are resolved on each network, but a block number is specific to one
+	if matches!(config.block, Some(BlockSelector::Number(_))) && config.network_slug.is_none() {
 		return Err(Box::new(MonitorExecutionError::execution_error(
 			"Network name is required when executing a monitor for a specific block",
 			None,
@@ -454,13 +1066,13 @@ async fn test_monitor_execution(config: MonitorExecutionTestConfig) -> Result<()
 		message = "Starting monitor execution",
 		path = config.path,
 		network = config.network_slug,
-		block = config.block_number,
+		block = config.block.map(|block| block.to_string()),
 	);
 
 	let result = execute_monitor(MonitorExecutionConfig {
 		path: config.path.clone(),
 		network_slug: config.network_slug.clone(),
-		block_number: config.block_number,
+		block: config.block,
 		monitor_service: config.monitor_service.clone(),
 		network_service: config.network_service.clone(),
 		filter_service: config.filter_service.clone(),
@@ -474,6 +1086,11 @@ async fn test_monitor_execution(config: MonitorExecutionTestConfig) -> Result<()
 		Ok(matches) => {
 			info!("Monitor execution completed successfully");
 
+			if config.output == OutputFormat::Json {
+				write_matches_json(&mut std::io::stdout().lock(), &matches)?;
+				return Ok(());
+			}
+
 			if matches.is_empty() {
 				info!("No matches found");
 				return Ok(());
@@ -670,10 +1287,7 @@ async fn test_monitor_execution(config: MonitorExecutionTestConfig) -> Result<()
 					),
 					(
 						"block".to_string(),
-						config
-							.block_number
-							.map(|b| b.to_string())
-							.unwrap_or_default(),
+						config.block.map(|b| b.to_string()).unwrap_or_default(),
 					),
 				])),
 			)
@@ -682,16 +1296,49 @@ async fn test_monitor_execution(config: MonitorExecutionTestConfig) -> Result<()
 	}
 }
 
+/// Loads the services from the combined configuration file, if one was provided
+///
+/// Returns `None` for each service otherwise, so that they are loaded from the configuration
+/// directories.
+async fn load_config_file_services(
+	config_file: Option<&str>,
+) -> Result<(
+	Option<MonitorServiceType>,
+	Option<NetworkService<NetworkRepository>>,
+	Option<TriggerService<TriggerRepository>>,
+)> {
+	match config_file {
+		Some(path) => {
+			let (monitor_service, network_service, trigger_service) =
+				load_services_from_config_file(Path::new(path)).await?;
+			Ok((
+				Some(monitor_service),
+				Some(network_service),
+				Some(trigger_service),
+			))
+		}
+		None => Ok((None, None, None)),
+	}
+}
+
 /// Validates configuration files and their structure
-async fn validate_configuration() {
+async fn validate_configuration(config_file: Option<&str>) {
 	info!("Validating configuration files...");
 
+	let services = match load_config_file_services(config_file).await {
+		Ok(services) => services,
+		Err(e) => {
+			error!("{}.\nPlease refer to the documentation quickstart ({}) for proper configuration setup.", e, DOCUMENTATION_URL);
+			return;
+		}
+	};
+
 	// Initialize services in validation mode to check configurations
 	match initialize_services::<
 		MonitorRepository<NetworkRepository, TriggerRepository>,
 		NetworkRepository,
 		TriggerRepository,
-	>(None, None, None)
+	>(services.0, services.1, services.2)
 	.await
 	{
 		Ok((_, _, active_monitors, networks, _, _, _)) => {
@@ -744,19 +1391,20 @@ mod tests {
 			.unwrap();
 
 		let path = "test_monitor.json".to_string();
-		let block_number = Some(12345);
+		let block = Some(BlockSelector::Number(12345));
 		let client_pool = Arc::new(ClientPool::new());
 		// Execute test
 		let result = test_monitor_execution(MonitorExecutionTestConfig {
 			path,
 			network_slug: None,
-			block_number,
+			block,
 			monitor_service: monitor_service.clone(),
 			network_service: network_service.clone(),
 			filter_service: filter_service.clone(),
 			trigger_execution_service: trigger_execution_service.clone(),
 			active_monitors_trigger_scripts: HashMap::new(),
 			raw_output: false,
+			output: OutputFormat::Text,
 			client_pool: client_pool.clone(),
 		})
 		.await;
@@ -785,20 +1433,21 @@ mod tests {
 		// Test parameters
 		let path = "nonexistent_monitor.json".to_string();
 		let network_slug = Some("test_network".to_string());
-		let block_number = Some(12345);
+		let block = Some(BlockSelector::Number(12345));
 
 		let client_pool = Arc::new(ClientPool::new());
 		// Execute test
 		let result = test_monitor_execution(MonitorExecutionTestConfig {
 			path,
 			network_slug,
-			block_number,
+			block,
 			monitor_service: monitor_service.clone(),
 			network_service: network_service.clone(),
 			filter_service: filter_service.clone(),
 			trigger_execution_service: trigger_execution_service.clone(),
 			active_monitors_trigger_scripts: HashMap::new(),
 			raw_output: false,
+			output: OutputFormat::Text,
 			client_pool: client_pool.clone(),
 		})
 		.await;
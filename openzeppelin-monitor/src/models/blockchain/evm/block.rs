@@ -91,6 +91,14 @@ impl Block {
 	pub fn number(&self) -> Option<u64> {
 		self.0.number.map(|n| n.to())
 	}
+
+	/// Get the block timestamp
+	///
+	/// Returns the timestamp in seconds since the Unix epoch, or `None` if it does not fit in
+	/// a `u64`.
+	pub fn timestamp(&self) -> Option<u64> {
+		u64::try_from(self.0.timestamp).ok()
+	}
 }
 
 impl From<BaseBlock<EVMTransaction>> for Block {
@@ -2,6 +2,7 @@ use crate::models::{
 	EVMReceiptLog, EVMTransaction, EVMTransactionReceipt, MatchConditions, Monitor,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Result of a successful monitor match on an EVM chain
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -15,9 +16,17 @@ pub struct EVMMonitorMatch {
 	/// Transaction receipt with execution results
 	pub receipt: Option<EVMTransactionReceipt>,
 
+	/// Decoded revert reason of a failed transaction, if decoding is enabled for the network
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub revert_reason: Option<String>,
+
 	/// Transaction logs
 	pub logs: Option<Vec<EVMReceiptLog>>,
 
+	/// Timestamp (Unix seconds) of the block containing the transaction
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub block_timestamp: Option<u64>,
+
 	/// Network slug that the transaction was sent from
 	pub network_slug: String,
 
@@ -26,6 +35,10 @@ pub struct EVMMonitorMatch {
 
 	/// Decoded arguments from the matched conditions
 	pub matched_on_args: Option<MatchArguments>,
+
+	/// Results of the monitor's enrichment calls, keyed by call name
+	#[serde(default, skip_serializing_if = "HashMap::is_empty")]
+	pub enrichment: HashMap<String, String>,
 }
 
 /// Collection of decoded parameters from matched conditions
@@ -125,6 +138,48 @@ impl std::ops::Deref for ContractSpec {
 	}
 }
 
+impl ContractSpec {
+	/// Merges the functions, events and errors of another spec into this one
+	///
+	/// Items whose selector is already present are skipped, so this spec takes
+	/// precedence over the merged one.
+	///
+	/// # Arguments
+	/// * `other` - The spec to merge into this one
+	pub fn merge(&mut self, other: &ContractSpec) {
+		for function in other.functions() {
+			if !self
+				.functions()
+				.any(|f| f.selector() == function.selector())
+			{
+				self.0
+					.functions
+					.entry(function.name.clone())
+					.or_default()
+					.push(function.clone());
+			}
+		}
+		for event in other.events() {
+			if !self.events().any(|e| e.selector() == event.selector()) {
+				self.0
+					.events
+					.entry(event.name.clone())
+					.or_default()
+					.push(event.clone());
+			}
+		}
+		for error in other.errors() {
+			if !self.errors().any(|e| e.selector() == error.selector()) {
+				self.0
+					.errors
+					.entry(error.name.clone())
+					.or_default()
+					.push(error.clone());
+			}
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::{
@@ -186,7 +241,9 @@ mod tests {
 			monitor: monitor.clone(),
 			transaction: transaction.clone(),
 			receipt: Some(receipt.clone()),
+			revert_reason: None,
 			logs: Some(receipt.logs.clone()),
+			block_timestamp: None,
 			network_slug: "ethereum_mainnet".to_string(),
 			matched_on: MatchConditions {
 				functions: vec![FunctionCondition {
@@ -195,11 +252,15 @@ mod tests {
 				}],
 				events: vec![],
 				transactions: vec![],
+				operations: vec![],
+				aggregates: vec![],
+				percentage_changes: vec![],
 			},
 			matched_on_args: Some(MatchArguments {
 				functions: Some(vec![match_params]),
 				events: None,
 			}),
+			enrichment: HashMap::new(),
 		};
 
 		assert_eq!(monitor_match.monitor.name, "TestMonitor");
@@ -439,4 +500,62 @@ mod tests {
 		let converted_spec = ContractSpec::from(models_spec);
 		assert!(converted_spec.is_empty());
 	}
+
+	#[test]
+	fn test_contract_spec_merge() {
+		let mut proxy_spec = ContractSpec::from(serde_json::json!([
+			{
+				"type": "function",
+				"name": "transfer",
+				"inputs": [
+					{ "name": "to", "type": "address", "internalType": "address" },
+					{ "name": "amount", "type": "uint256", "internalType": "uint256" }
+				],
+				"outputs": [],
+				"stateMutability": "nonpayable"
+			},
+			{
+				"type": "event",
+				"name": "Upgraded",
+				"inputs": [
+					{ "name": "implementation", "type": "address", "indexed": true }
+				],
+				"anonymous": false
+			}
+		]));
+		let implementation_spec = ContractSpec::from(serde_json::json!([
+			{
+				"type": "function",
+				"name": "transfer",
+				"inputs": [
+					{ "name": "recipient", "type": "address", "internalType": "address" },
+					{ "name": "value", "type": "uint256", "internalType": "uint256" }
+				],
+				"outputs": [],
+				"stateMutability": "nonpayable"
+			},
+			{
+				"type": "event",
+				"name": "Transfer",
+				"inputs": [
+					{ "name": "from", "type": "address", "indexed": true },
+					{ "name": "to", "type": "address", "indexed": true },
+					{ "name": "value", "type": "uint256", "indexed": false }
+				],
+				"anonymous": false
+			}
+		]));
+
+		proxy_spec.merge(&implementation_spec);
+
+		// Functions with the same selector keep the definition of the merged-into spec
+		let functions: Vec<_> = proxy_spec.functions().collect();
+		assert_eq!(functions.len(), 1);
+		assert_eq!(functions[0].inputs[0].name, "to");
+
+		// Events only defined in the merged spec are added
+		let mut events: Vec<_> = proxy_spec.events().map(|e| e.name.clone()).collect();
+		events.sort();
+		assert_eq!(events, vec!["Transfer", "Upgraded"]);
+	}
 }
@@ -152,6 +152,15 @@ impl Transaction {
 	pub fn hash(&self) -> &B256 {
 		&self.0.hash
 	}
+
+	/// Get the addresses declared in the access list (empty for Legacy transactions)
+	pub fn access_list_addresses(&self) -> Vec<&Address> {
+		self.0
+			.access_list
+			.iter()
+			.flat_map(|access_list| access_list.iter().map(|item| &item.address))
+			.collect()
+	}
 }
 
 impl From<BaseTransaction> for Transaction {
@@ -256,6 +265,21 @@ mod tests {
 		assert_eq!(*tx.nonce(), nonce);
 	}
 
+	#[test]
+	fn test_access_list_addresses() {
+		let default_tx = TransactionBuilder::new().build();
+		assert!(default_tx.access_list_addresses().is_empty());
+
+		let addresses = vec![Address::with_last_byte(7), Address::with_last_byte(8)];
+		let tx = TransactionBuilder::new()
+			.access_list(addresses.clone())
+			.build();
+		assert_eq!(
+			tx.access_list_addresses(),
+			addresses.iter().collect::<Vec<_>>()
+		);
+	}
+
 	#[test]
 	fn test_hash() {
 		let hash = B256::with_last_byte(1);
@@ -4,13 +4,15 @@
 //! blockchain platforms (EVM, Stellar, etc). Each submodule implements the
 //! platform-specific logic for blocks, transactions, and event monitoring.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 pub mod evm;
 pub mod stellar;
 
 /// Supported blockchain platform types
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub enum BlockChainType {
 	/// Ethereum Virtual Machine based chains
@@ -45,6 +47,14 @@ impl BlockType {
 			BlockType::Stellar(b) => b.number(),
 		}
 	}
+
+	/// Returns the block timestamp in seconds since the Unix epoch, if known
+	pub fn timestamp(&self) -> Option<u64> {
+		match self {
+			BlockType::EVM(b) => b.timestamp(),
+			BlockType::Stellar(b) => b.timestamp(),
+		}
+	}
 }
 
 /// Transaction data from different blockchain platforms
@@ -81,6 +91,73 @@ pub enum MonitorMatch {
 	Stellar(Box<stellar::StellarMonitorMatch>),
 }
 
+impl MonitorMatch {
+	/// Returns a deterministic identifier for this match.
+	///
+	/// The identifier is the hex encoded SHA-256 hash of the network slug, transaction
+	/// hash, monitor name and matched conditions, so re-processing the same transaction
+	/// yields the same id. It can be used as a deduplication key by downstream systems.
+	pub fn match_id(&self) -> String {
+		let (network_slug, transaction_hash, monitor_name, matched_on) = match self {
+			MonitorMatch::EVM(m) => (
+				&m.network_slug,
+				format!("{:#x}", m.transaction.hash()),
+				&m.monitor.name,
+				&m.matched_on,
+			),
+			MonitorMatch::Stellar(m) => (
+				&m.network_slug,
+				m.transaction.hash().to_lowercase(),
+				&m.monitor.name,
+				&m.matched_on,
+			),
+		};
+		let conditions = serde_json::to_string(matched_on).unwrap_or_default();
+
+		hash_fields(&[network_slug, &transaction_hash, monitor_name, &conditions])
+	}
+
+	/// Returns a key identifying the content of this match, regardless of its transaction.
+	///
+	/// The key is the hex encoded SHA-256 hash of the network slug, monitor name, matched
+	/// conditions and decoded arguments, so identical events emitted by different
+	/// transactions, e.g. in consecutive blocks, share the same key.
+	pub fn content_key(&self) -> String {
+		let (network_slug, monitor_name, conditions, arguments) = match self {
+			MonitorMatch::EVM(m) => (
+				&m.network_slug,
+				&m.monitor.name,
+				serde_json::to_string(&m.matched_on),
+				serde_json::to_string(&m.matched_on_args),
+			),
+			MonitorMatch::Stellar(m) => (
+				&m.network_slug,
+				&m.monitor.name,
+				serde_json::to_string(&m.matched_on),
+				serde_json::to_string(&m.matched_on_args),
+			),
+		};
+
+		hash_fields(&[
+			network_slug,
+			monitor_name,
+			&conditions.unwrap_or_default(),
+			&arguments.unwrap_or_default(),
+		])
+	}
+}
+
+/// Hashes fields with SHA-256 into a hex encoded string
+fn hash_fields(fields: &[&str]) -> String {
+	let mut hasher = Sha256::new();
+	// Length-prefix each field so that different field boundaries cannot collide
+	for field in fields {
+		hasher.update((field.len() as u64).to_be_bytes());
+		hasher.update(field.as_bytes());
+	}
+	hex::encode(hasher.finalize())
+}
+
 /// Structure to hold block processing results
 ///
 /// This is used to pass the results of block processing to the trigger handler
@@ -90,3 +167,153 @@ pub struct ProcessedBlock {
 	pub network_slug: String,
 	pub processing_results: Vec<MonitorMatch>,
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{
+			EVMMatchArguments, EVMMatchParamEntry, EVMMatchParamsMap, EVMMonitorMatch,
+			EventCondition, MatchConditions, StellarBlock, StellarMonitorMatch, StellarTransaction,
+			StellarTransactionInfo,
+		},
+		utils::tests::builders::{
+			evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+			stellar::monitor::MonitorBuilder as StellarMonitorBuilder,
+		},
+	};
+	use alloy::primitives::B256;
+	use std::collections::HashMap;
+
+	fn create_evm_match(network_slug: &str, tx_hash: B256, monitor_name: &str) -> MonitorMatch {
+		MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new().name(monitor_name).build(),
+			transaction: TransactionBuilder::new().hash(tx_hash).build(),
+			receipt: None,
+			revert_reason: None,
+			logs: None,
+			block_timestamp: None,
+			network_slug: network_slug.to_string(),
+			matched_on: MatchConditions {
+				events: vec![EventCondition {
+					signature: "Transfer(address,address,uint256)".to_string(),
+					expression: None,
+				}],
+				..Default::default()
+			},
+			matched_on_args: None,
+			enrichment: HashMap::new(),
+		}))
+	}
+
+	fn create_stellar_match(tx_hash: &str, matched_on: MatchConditions) -> MonitorMatch {
+		MonitorMatch::Stellar(Box::new(StellarMonitorMatch {
+			monitor: StellarMonitorBuilder::new().name("Stellar Monitor").build(),
+			transaction: StellarTransaction::from(StellarTransactionInfo {
+				transaction_hash: tx_hash.to_string(),
+				..Default::default()
+			}),
+			ledger: StellarBlock::default(),
+			network_slug: "stellar_mainnet".to_string(),
+			matched_on,
+			matched_on_args: None,
+			enrichment: HashMap::new(),
+		}))
+	}
+
+	#[test]
+	fn test_match_id_is_deterministic() {
+		let first = create_evm_match("ethereum_mainnet", B256::with_last_byte(1), "Monitor");
+		let second = create_evm_match("ethereum_mainnet", B256::with_last_byte(1), "Monitor");
+		assert_eq!(first.match_id(), second.match_id());
+		assert_eq!(first.match_id().len(), 64);
+
+		let stellar = create_stellar_match("abc123", MatchConditions::default());
+		assert_eq!(
+			stellar.match_id(),
+			create_stellar_match("abc123", MatchConditions::default()).match_id()
+		);
+	}
+
+	#[test]
+	fn test_match_id_differs_between_matches() {
+		let base = create_evm_match("ethereum_mainnet", B256::with_last_byte(1), "Monitor");
+		let ids = [
+			create_evm_match("ethereum_sepolia", B256::with_last_byte(1), "Monitor").match_id(),
+			create_evm_match("ethereum_mainnet", B256::with_last_byte(2), "Monitor").match_id(),
+			create_evm_match("ethereum_mainnet", B256::with_last_byte(1), "Other").match_id(),
+		];
+		for id in &ids {
+			assert_ne!(&base.match_id(), id);
+		}
+
+		let without_events = create_stellar_match("abc123", MatchConditions::default());
+		let with_events = create_stellar_match(
+			"abc123",
+			MatchConditions {
+				events: vec![EventCondition {
+					signature: "transfer(Address,Address,i128)".to_string(),
+					expression: None,
+				}],
+				..Default::default()
+			},
+		);
+		assert_ne!(without_events.match_id(), with_events.match_id());
+		assert_ne!(
+			without_events.match_id(),
+			create_stellar_match("abc124", MatchConditions::default()).match_id()
+		);
+	}
+
+	#[test]
+	fn test_content_key_ignores_transaction() {
+		let first = create_evm_match("ethereum_mainnet", B256::with_last_byte(1), "Monitor");
+		let second = create_evm_match("ethereum_mainnet", B256::with_last_byte(2), "Monitor");
+		assert_ne!(first.match_id(), second.match_id());
+		assert_eq!(first.content_key(), second.content_key());
+		assert_ne!(
+			first.content_key(),
+			create_evm_match("ethereum_mainnet", B256::with_last_byte(1), "Other").content_key()
+		);
+		assert_ne!(
+			create_stellar_match("abc123", MatchConditions::default()).content_key(),
+			create_stellar_match(
+				"abc124",
+				MatchConditions {
+					events: vec![EventCondition {
+						signature: "transfer(Address,Address,i128)".to_string(),
+						expression: None,
+					}],
+					..Default::default()
+				},
+			)
+			.content_key()
+		);
+	}
+
+	#[test]
+	fn test_content_key_differs_by_arguments() {
+		let with_value = |value: &str| {
+			let mut monitor_match =
+				create_evm_match("ethereum_mainnet", B256::with_last_byte(1), "Monitor");
+			if let MonitorMatch::EVM(m) = &mut monitor_match {
+				m.matched_on_args = Some(EVMMatchArguments {
+					functions: None,
+					events: Some(vec![EVMMatchParamsMap {
+						signature: "Transfer(address,address,uint256)".to_string(),
+						args: Some(vec![EVMMatchParamEntry {
+							name: "value".to_string(),
+							value: value.to_string(),
+							indexed: false,
+							kind: "uint256".to_string(),
+						}]),
+						hex_signature: None,
+					}]),
+				});
+			}
+			monitor_match
+		};
+		assert_eq!(with_value("1").content_key(), with_value("1").content_key());
+		assert_ne!(with_value("1").content_key(), with_value("2").content_key());
+	}
+}
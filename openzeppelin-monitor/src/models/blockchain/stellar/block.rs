@@ -57,6 +57,18 @@ impl Block {
 	pub fn number(&self) -> Option<u64> {
 		Some(self.0.sequence as u64)
 	}
+
+	/// Get the ledger close time in seconds since the Unix epoch
+	///
+	/// The close time is reported either as a Unix timestamp or as an RFC 3339 date.
+	pub fn timestamp(&self) -> Option<u64> {
+		let close_time = self.0.ledger_close_time.trim();
+		close_time.parse::<u64>().ok().or_else(|| {
+			chrono::DateTime::parse_from_rfc3339(close_time)
+				.ok()
+				.and_then(|date| u64::try_from(date.timestamp()).ok())
+		})
+	}
 }
 
 impl From<LedgerInfo> for Block {
@@ -95,6 +107,9 @@ mod tests {
 		// Test number() method
 		assert_eq!(block.number(), Some(12345u64));
 
+		// Test timestamp() method
+		assert_eq!(block.timestamp(), Some(1710928800));
+
 		// Test Deref implementation
 		assert_eq!(block.hash, "abc123");
 		assert_eq!(block.sequence, 12345);
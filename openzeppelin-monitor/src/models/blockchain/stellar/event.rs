@@ -57,3 +57,25 @@ pub struct Event {
 	#[serde(rename = "valueJson", skip_serializing_if = "Option::is_none")]
 	pub value_json: Option<serde_json::Value>,
 }
+
+/// Filter applied by the Stellar RPC to the contract events it returns
+///
+/// Empty fields do not restrict the returned events.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventFilter {
+	/// Contract addresses (`C...`) whose events are returned
+	pub contract_ids: Vec<String>,
+
+	/// Topic filters, each a list of base64-encoded ScVal segments matching the event topics
+	/// in order. A `*` segment matches any single topic and a trailing `**` segment matches
+	/// any remaining topics.
+	pub topics: Vec<Vec<String>>,
+}
+
+impl EventFilter {
+	/// Maximum number of filters in a single `getEvents` request
+	pub const MAX_FILTERS: usize = 5;
+
+	/// Maximum number of contract ids, and of topic filters, in a single filter
+	pub const MAX_ENTRIES: usize = 5;
+}
@@ -10,7 +10,7 @@ mod monitor;
 mod transaction;
 
 pub use block::{Block as StellarBlock, LedgerInfo as StellarLedgerInfo};
-pub use event::Event as StellarEvent;
+pub use event::{Event as StellarEvent, EventFilter as StellarEventFilter};
 pub use monitor::{
 	ContractFunction as StellarContractFunction, ContractInput as StellarContractInput,
 	ContractSpec as StellarContractSpec, DecodedParamEntry as StellarDecodedParamEntry,
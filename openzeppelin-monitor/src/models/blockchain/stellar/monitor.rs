@@ -2,6 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
 use stellar_xdr::curr::ScSpecEntry;
 
 use crate::{
@@ -31,6 +32,10 @@ pub struct MonitorMatch {
 
 	/// Decoded arguments from the matched conditions
 	pub matched_on_args: Option<MatchArguments>,
+
+	/// Results of the monitor's enrichment calls, keyed by call name
+	#[serde(default, skip_serializing_if = "HashMap::is_empty")]
+	pub enrichment: HashMap<String, String>,
 }
 
 /// Collection of decoded parameters from matched conditions
@@ -490,11 +495,15 @@ mod tests {
 				}],
 				events: vec![],
 				transactions: vec![],
+				operations: vec![],
+				aggregates: vec![],
+				percentage_changes: vec![],
 			},
 			matched_on_args: Some(MatchArguments {
 				functions: Some(vec![match_params]),
 				events: None,
 			}),
+			enrichment: HashMap::new(),
 		};
 
 		assert_eq!(monitor_match.monitor.name, "TestMonitor");
@@ -0,0 +1,263 @@
+//! Combined configuration file loading.
+//!
+//! This module loads networks, monitors and triggers from a single JSON file, as an
+//! alternative to the `config/networks`, `config/monitors` and `config/triggers`
+//! directories. Every entry goes through the same secret resolution and validation as
+//! when it is loaded from its own file.
+
+use serde::Deserialize;
+use std::{collections::HashMap, path::Path};
+
+use crate::models::{
+	config::{error::ConfigError, monitor_config::load_contract_spec_files},
+	ConfigLoader, Monitor, Network, Trigger,
+};
+
+/// File structure for combined configuration files
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CombinedConfigFile {
+	/// Network configurations
+	#[serde(default)]
+	networks: Vec<Network>,
+
+	/// Monitor configurations
+	#[serde(default)]
+	monitors: Vec<Monitor>,
+
+	/// Trigger configurations
+	#[serde(default)]
+	triggers: Vec<Trigger>,
+}
+
+/// Networks, monitors and triggers loaded from a single configuration file
+#[derive(Debug, Clone, Default)]
+pub struct CombinedConfig {
+	/// Networks indexed by slug
+	pub networks: HashMap<String, Network>,
+
+	/// Monitors indexed by name
+	pub monitors: HashMap<String, Monitor>,
+
+	/// Triggers indexed by name
+	pub triggers: HashMap<String, Trigger>,
+}
+
+impl CombinedConfig {
+	/// Load a combined configuration file
+	///
+	/// Reads a JSON file of the form `{ "networks": [...], "monitors": [...], "triggers": [...] }`,
+	/// resolves secrets and validates every entry. Contract spec paths of monitors are resolved
+	/// relative to the directory containing the file.
+	///
+	/// References between monitors, networks and triggers are not checked here, as they are
+	/// validated by the monitor repository.
+	pub async fn load_from_path(path: &Path) -> Result<Self, ConfigError> {
+		let path_metadata = || {
+			Some(HashMap::from([(
+				"path".to_string(),
+				path.display().to_string(),
+			)]))
+		};
+		let file_path = path.display().to_string();
+
+		let content = std::fs::read_to_string(path).map_err(|e| {
+			ConfigError::file_error(
+				format!("failed to read config file: {}", e),
+				Some(Box::new(e)),
+				path_metadata(),
+			)
+		})?;
+		let file: CombinedConfigFile = serde_json::from_str(&content).map_err(|e| {
+			ConfigError::parse_error(
+				format!("failed to parse config file: {}", e),
+				Some(Box::new(e)),
+				path_metadata(),
+			)
+		})?;
+
+		let mut networks: Vec<Network> = Vec::new();
+		for mut network in file.networks {
+			network.apply_default_rpc_urls();
+			let network = network.resolve_secrets().await?;
+			network.validate()?;
+
+			let existing_networks: Vec<&Network> = networks.iter().collect();
+			Network::validate_uniqueness(&existing_networks, &network, &file_path)?;
+			networks.push(network);
+		}
+
+		let mut triggers: Vec<Trigger> = Vec::new();
+		for trigger in file.triggers {
+			let trigger = trigger.resolve_secrets().await?;
+			if let Err(validation_error) = trigger.validate() {
+				return Err(ConfigError::validation_error(
+					format!(
+						"Validation failed for trigger '{}': {}",
+						trigger.name, validation_error
+					),
+					Some(Box::new(validation_error)),
+					Some(HashMap::from([
+						("path".to_string(), file_path.clone()),
+						("trigger_name".to_string(), trigger.name.clone()),
+					])),
+				));
+			}
+
+			let existing_triggers: Vec<&Trigger> = triggers.iter().collect();
+			Trigger::validate_uniqueness(&existing_triggers, &trigger, &file_path)?;
+			triggers.push(trigger);
+		}
+
+		let mut monitors: Vec<Monitor> = Vec::new();
+		for mut monitor in file.monitors {
+			load_contract_spec_files(&mut monitor, path)?;
+			let monitor = monitor.resolve_secrets().await?;
+			monitor.validate().map_err(|e| {
+				ConfigError::validation_error(
+					format!("monitor validation failed: {}", e),
+					Some(Box::new(e)),
+					Some(HashMap::from([
+						("path".to_string(), file_path.clone()),
+						("monitor_name".to_string(), monitor.name.clone()),
+					])),
+				)
+			})?;
+
+			let existing_monitors: Vec<&Monitor> = monitors.iter().collect();
+			Monitor::validate_uniqueness(&existing_monitors, &monitor, &file_path)?;
+			monitors.push(monitor);
+		}
+
+		Ok(Self {
+			networks: networks
+				.into_iter()
+				.map(|network| (network.slug.clone(), network))
+				.collect(),
+			monitors: monitors
+				.into_iter()
+				.map(|monitor| (monitor.name.clone(), monitor))
+				.collect(),
+			triggers: triggers
+				.into_iter()
+				.map(|trigger| (trigger.name.clone(), trigger))
+				.collect(),
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::BlockChainType,
+		utils::tests::builders::{
+			evm::monitor::MonitorBuilder, network::NetworkBuilder, trigger::TriggerBuilder,
+		},
+	};
+	use tempfile::TempDir;
+
+	fn write_config(dir: &TempDir, config: serde_json::Value) -> std::path::PathBuf {
+		let path = dir.path().join("config.json");
+		std::fs::write(&path, config.to_string()).unwrap();
+		path
+	}
+
+	fn create_test_network(slug: &str) -> Network {
+		NetworkBuilder::new()
+			.name(slug)
+			.slug(slug)
+			.network_type(BlockChainType::EVM)
+			.chain_id(1)
+			.rpc_url("https://eth.drpc.org")
+			.block_time_ms(12000)
+			.confirmation_blocks(1)
+			.cron_schedule("0 */1 * * * *")
+			.max_past_blocks(10)
+			.build()
+	}
+
+	fn create_test_trigger(name: &str) -> Trigger {
+		TriggerBuilder::new()
+			.name(name)
+			.webhook("https://example.com/webhook")
+			.message("Alert", "Test message")
+			.build()
+	}
+
+	#[tokio::test]
+	async fn test_load_combined_config() {
+		let dir = TempDir::new().unwrap();
+		let monitor = MonitorBuilder::new()
+			.name("test_monitor")
+			.networks(vec!["ethereum_mainnet".to_string()])
+			.triggers(vec!["test_trigger".to_string()])
+			.build();
+		let path = write_config(
+			&dir,
+			serde_json::json!({
+				"networks": [create_test_network("ethereum_mainnet")],
+				"monitors": [monitor],
+				"triggers": [create_test_trigger("test_trigger")],
+			}),
+		);
+
+		let config = CombinedConfig::load_from_path(&path).await.unwrap();
+
+		assert!(config.networks.contains_key("ethereum_mainnet"));
+		assert!(config.monitors.contains_key("test_monitor"));
+		assert!(config.triggers.contains_key("test_trigger"));
+	}
+
+	#[tokio::test]
+	async fn test_load_combined_config_missing_sections() {
+		let dir = TempDir::new().unwrap();
+		let path = write_config(
+			&dir,
+			serde_json::json!({ "networks": [create_test_network("ethereum_mainnet")] }),
+		);
+
+		let config = CombinedConfig::load_from_path(&path).await.unwrap();
+
+		assert_eq!(config.networks.len(), 1);
+		assert!(config.monitors.is_empty());
+		assert!(config.triggers.is_empty());
+	}
+
+	#[tokio::test]
+	async fn test_load_combined_config_errors() {
+		let dir = TempDir::new().unwrap();
+
+		// Unknown sections are rejected
+		let path = write_config(&dir, serde_json::json!({ "network": [] }));
+		let err = CombinedConfig::load_from_path(&path).await.unwrap_err();
+		assert!(matches!(err, ConfigError::ParseError(_)));
+
+		// Entries are validated
+		let mut invalid_network = create_test_network("ethereum_mainnet");
+		invalid_network.slug = "Invalid Slug".to_string();
+		let path = write_config(&dir, serde_json::json!({ "networks": [invalid_network] }));
+		let err = CombinedConfig::load_from_path(&path).await.unwrap_err();
+		assert!(err
+			.to_string()
+			.contains("Slug must contain only lowercase letters"));
+
+		// Names must be unique
+		let path = write_config(
+			&dir,
+			serde_json::json!({
+				"triggers": [create_test_trigger("test_trigger"), create_test_trigger("test_trigger")],
+			}),
+		);
+		let err = CombinedConfig::load_from_path(&path).await.unwrap_err();
+		assert!(err
+			.to_string()
+			.contains("Duplicate trigger name found: 'test_trigger'"));
+
+		// Missing file
+		let err = CombinedConfig::load_from_path(&dir.path().join("missing.json"))
+			.await
+			.unwrap_err();
+		assert!(matches!(err, ConfigError::FileError(_)));
+	}
+}
@@ -1,19 +1,26 @@
 //! Configuration loading and validation.
 //!
 //! This module provides traits and implementations for loading and validating
-//! configuration files for networks, monitors, and triggers.
+//! configuration files for networks, monitors, and triggers, either from their own
+//! directories or from a single combined file.
 
 #![allow(clippy::result_large_err)]
 
 use async_trait::async_trait;
-use std::path::Path;
+use std::{collections::HashMap, path::Path};
 
+use crate::utils::metrics::{is_valid_label_name, NOTIFICATION_METRIC_LABELS};
+
+mod combined_config;
 mod error;
 mod monitor_config;
 mod network_config;
+mod schema;
 mod trigger_config;
 
+pub use combined_config::CombinedConfig;
 pub use error::ConfigError;
+pub use schema::ConfigSchema;
 
 /// Common interface for loading configuration files
 #[async_trait]
@@ -61,3 +68,31 @@ pub trait ConfigLoader: Sized {
 		file_path: &str,
 	) -> Result<(), ConfigError>;
 }
+
+/// Validate the labels of a monitor or trigger
+///
+/// Label names must be valid Prometheus label names and cannot replace the labels set by the
+/// monitor on notification metrics.
+fn validate_labels(labels: &HashMap<String, String>) -> Result<(), ConfigError> {
+	for name in labels.keys() {
+		if !is_valid_label_name(name) {
+			return Err(ConfigError::validation_error(
+				format!(
+					"Invalid label name '{}': must start with a letter or underscore and contain \
+					 only letters, digits and underscores",
+					name
+				),
+				None,
+				None,
+			));
+		}
+		if NOTIFICATION_METRIC_LABELS.contains(&name.as_str()) {
+			return Err(ConfigError::validation_error(
+				format!("Label name '{}' is reserved", name),
+				None,
+				None,
+			));
+		}
+	}
+	Ok(())
+}
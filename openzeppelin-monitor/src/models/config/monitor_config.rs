@@ -3,15 +3,206 @@
 //! This module implements the ConfigLoader trait for Monitor configurations,
 //! allowing monitors to be loaded from JSON files.
 
+use alloy::dyn_abi::DynSolType;
 use async_trait::async_trait;
 use std::{collections::HashMap, fs, path::Path};
 
 use crate::{
-	models::{config::error::ConfigError, ConfigLoader, Monitor},
-	services::trigger::validate_script_config,
+	models::{
+		config::{error::ConfigError, validate_labels},
+		ConfigLoader, ContractSpec, Monitor, MonitorTrigger,
+	},
+	services::{
+		filter::{
+			evm_helpers::{
+				are_same_signature, normalize_signature, parse_event_topic, parse_function_selector,
+			},
+			stellar_helpers::is_operation_type,
+		},
+		trigger::validate_script_config,
+	},
 	utils::normalize_string,
 };
 
+/// Load contract specs referenced by `contract_spec_path` into the monitor addresses
+///
+/// Paths are resolved relative to the directory containing the monitor config file.
+pub(super) fn load_contract_spec_files(
+	monitor: &mut Monitor,
+	config_path: &Path,
+) -> Result<(), ConfigError> {
+	let base_dir = config_path.parent().unwrap_or(Path::new("."));
+
+	for address in monitor.addresses.iter_mut() {
+		let Some(spec_path) = &address.contract_spec_path else {
+			continue;
+		};
+
+		if address.contract_spec.is_some() {
+			return Err(ConfigError::validation_error(
+				format!(
+					"Address '{}' cannot specify both contract_spec and contract_spec_path",
+					address.address
+				),
+				None,
+				Some(HashMap::from([(
+					"path".to_string(),
+					config_path.display().to_string(),
+				)])),
+			));
+		}
+
+		let resolved_path = base_dir.join(spec_path);
+		let metadata = Some(HashMap::from([
+			("path".to_string(), config_path.display().to_string()),
+			(
+				"contract_spec_path".to_string(),
+				resolved_path.display().to_string(),
+			),
+		]));
+
+		let content = fs::read_to_string(&resolved_path).map_err(|e| {
+			ConfigError::file_error(
+				format!(
+					"failed to read contract spec file '{}' for address '{}': {}",
+					resolved_path.display(),
+					address.address,
+					e
+				),
+				Some(Box::new(e)),
+				metadata.clone(),
+			)
+		})?;
+
+		let contract_spec: ContractSpec = serde_json::from_str(&content).map_err(|e| {
+			ConfigError::parse_error(
+				format!(
+					"failed to parse contract spec file '{}' for address '{}': {}",
+					resolved_path.display(),
+					address.address,
+					e
+				),
+				Some(Box::new(e)),
+				metadata.clone(),
+			)
+		})?;
+
+		address.contract_spec = Some(contract_spec);
+	}
+
+	Ok(())
+}
+
+/// Checks that the event and function signatures of a monitor's conditions exist in the EVM
+/// contract specs of its addresses
+///
+/// The check is skipped unless every address has an EVM contract spec, since missing specs
+/// are fetched from the network at startup. Raw selectors and topics are not checked.
+fn validate_evm_signatures(monitor: &Monitor) -> Result<(), ConfigError> {
+	let Some(specs) = monitor
+		.addresses
+		.iter()
+		.map(|address| match address.merged_contract_spec() {
+			Some(ContractSpec::EVM(spec)) => Some(spec),
+			_ => None,
+		})
+		.collect::<Option<Vec<_>>>()
+	else {
+		return Ok(());
+	};
+	if specs.is_empty() {
+		return Ok(());
+	}
+
+	let conditions = &monitor.match_conditions;
+	let events = conditions
+		.events
+		.iter()
+		.map(|condition| &condition.signature)
+		.filter(|signature| parse_event_topic(signature).is_none())
+		.chain(
+			conditions
+				.aggregates
+				.iter()
+				.map(|condition| &condition.signature),
+		)
+		.chain(
+			conditions
+				.percentage_changes
+				.iter()
+				.flat_map(|condition| [&condition.from.signature, &condition.to.signature]),
+		);
+	let event_signatures: Vec<String> = specs
+		.iter()
+		.flat_map(|spec| spec.events().map(|event| event.signature()))
+		.chain(
+			monitor
+				.addresses
+				.iter()
+				.flat_map(|address| address.event_signatures.values().cloned()),
+		)
+		.collect();
+	for signature in events {
+		check_signature_exists("Event", signature, &event_signatures)?;
+	}
+
+	let function_signatures: Vec<String> = specs
+		.iter()
+		.flat_map(|spec| spec.functions().map(|function| function.signature()))
+		.collect();
+	for condition in &conditions.functions {
+		if parse_function_selector(&condition.signature).is_none() {
+			check_signature_exists("Function", &condition.signature, &function_signatures)?;
+		}
+	}
+
+	Ok(())
+}
+
+/// Returns an error suggesting the closest known signature if a signature is not known
+fn check_signature_exists(
+	kind: &str,
+	signature: &str,
+	known_signatures: &[String],
+) -> Result<(), ConfigError> {
+	if known_signatures
+		.iter()
+		.any(|known| are_same_signature(signature, known))
+	{
+		return Ok(());
+	}
+
+	let normalized = normalize_signature(signature);
+	let suggestion = known_signatures
+		.iter()
+		.min_by_key(|known| edit_distance(&normalized, &normalize_signature(known)))
+		.map(|known| format!(". Did you mean '{}'?", known))
+		.unwrap_or_default();
+	Err(ConfigError::validation_error(
+		format!(
+			"{} signature '{}' not found in the contract spec of the monitored addresses{}",
+			kind, signature, suggestion
+		),
+		None,
+		None,
+	))
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+	let b: Vec<char> = b.chars().collect();
+	let mut previous: Vec<usize> = (0..=b.len()).collect();
+	for (i, ca) in a.chars().enumerate() {
+		let mut current = vec![i + 1; b.len() + 1];
+		for (j, cb) in b.iter().enumerate() {
+			let substitution = previous[j] + usize::from(ca != *cb);
+			current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+		}
+		previous = current;
+	}
+	previous[b.len()]
+}
+
 #[async_trait]
 impl ConfigLoader for Monitor {
 	/// Resolve all secrets in the monitor configuration
@@ -112,6 +303,9 @@ impl ConfigLoader for Monitor {
 			)
 		})?;
 
+		// Load contract specs stored in separate files
+		load_contract_spec_files(&mut config, path)?;
+
 		// Resolve secrets before validating
 		config = config.resolve_secrets().await?;
 
@@ -150,9 +344,204 @@ impl ConfigLoader for Monitor {
 			));
 		}
 
-		// Validate function signatures
+		// Validate trigger tags
+		if self.trigger_tags.iter().any(|tag| tag.trim().is_empty()) {
+			return Err(ConfigError::validation_error(
+				"Trigger tags cannot be empty",
+				None,
+				None,
+			));
+		}
+
+		// Validate conditional triggers
+		for trigger in &self.triggers {
+			if let MonitorTrigger::Conditional { name, when } = trigger {
+				if when.is_empty() {
+					return Err(ConfigError::validation_error(
+						format!(
+							"Trigger '{}' must list at least one condition type in `when`",
+							name
+						),
+						None,
+						None,
+					));
+				}
+			}
+		}
+
+		// Validate trigger concurrency
+		if let Some(execution) = &self.trigger_execution {
+			if execution.trigger_concurrency == Some(0) {
+				return Err(ConfigError::validation_error(
+					"trigger_execution trigger_concurrency must be greater than 0",
+					None,
+					None,
+				));
+			}
+		}
+
+		// Validate notification throttle
+		if let Some(throttle) = &self.notification_throttle {
+			if throttle.max_notifications_per_window == 0 || throttle.window_ms == 0 {
+				return Err(ConfigError::validation_error(
+					"notification_throttle max_notifications_per_window and window_ms must be greater than 0",
+					None,
+					None,
+				));
+			}
+		}
+
+		// Validate match deduplication
+		if let Some(match_dedup) = &self.match_dedup {
+			if match_dedup.window_blocks == 0 {
+				return Err(ConfigError::validation_error(
+					"match_dedup window_blocks must be greater than 0",
+					None,
+					None,
+				));
+			}
+		}
+
+		// Validate enrichment calls
+		if let Some(enrichment) = &self.enrichment {
+			if enrichment.timeout_ms == 0 {
+				return Err(ConfigError::validation_error(
+					"enrichment timeout_ms must be greater than 0",
+					None,
+					None,
+				));
+			}
+			for (index, call) in enrichment.calls.iter().enumerate() {
+				if call.name.is_empty() || call.function.is_empty() {
+					return Err(ConfigError::validation_error(
+						"Enrichment call name and function cannot be empty",
+						None,
+						None,
+					));
+				}
+				if enrichment.calls[..index]
+					.iter()
+					.any(|other| other.name == call.name)
+				{
+					return Err(ConfigError::validation_error(
+						format!("Duplicate enrichment call name '{}'", call.name),
+						None,
+						None,
+					));
+				}
+				if call.address.is_none() && self.addresses.is_empty() {
+					return Err(ConfigError::validation_error(
+						format!(
+							"Enrichment call '{}' requires an address, as the monitor has no \
+							 addresses",
+							call.name
+						),
+						None,
+						None,
+					));
+				}
+				if call.cache_ttl_secs == Some(0) {
+					return Err(ConfigError::validation_error(
+						format!(
+							"cache_ttl_secs of enrichment call '{}' must be greater than 0",
+							call.name
+						),
+						None,
+						None,
+					));
+				}
+				if let Some(output) = &call.output {
+					if DynSolType::parse(output).is_err() {
+						return Err(ConfigError::validation_error(
+							format!(
+								"Invalid output type '{}' of enrichment call '{}'",
+								output, call.name
+							),
+							None,
+							None,
+						));
+					}
+				}
+			}
+		}
+
+		// Validate labels
+		validate_labels(&self.labels)?;
+
+		// Validate sample rate
+		if let Some(sample_rate) = self.sample_rate {
+			if !(0.0..=1.0).contains(&sample_rate) {
+				return Err(ConfigError::validation_error(
+					format!(
+						"sample_rate must be between 0.0 and 1.0, got {}",
+						sample_rate
+					),
+					None,
+					None,
+				));
+			}
+		}
+
+		// Validate expected match window
+		if self.expected_match_window_secs == Some(0) {
+			return Err(ConfigError::validation_error(
+				"expected_match_window_secs must be greater than 0",
+				None,
+				None,
+			));
+		}
+
+		// Validate severity rules
+		for rule in &self.severity_rules {
+			if rule.severity.trim().is_empty() {
+				return Err(ConfigError::validation_error(
+					"Severity rule severity cannot be empty",
+					None,
+					None,
+				));
+			}
+			if rule
+				.expression
+				.as_deref()
+				.is_some_and(|expression| expression.trim().is_empty())
+			{
+				return Err(ConfigError::validation_error(
+					format!("Severity rule '{}' has an empty expression", rule.severity),
+					None,
+					None,
+				));
+			}
+		}
+
+		// Validate pause window
+		if let Some(paused_until) = &self.paused_until {
+			if let Err(e) = chrono::DateTime::parse_from_rfc3339(paused_until) {
+				return Err(ConfigError::validation_error(
+					format!(
+						"Invalid paused_until timestamp '{}', expected RFC3339 format",
+						paused_until
+					),
+					Some(e.into()),
+					None,
+				));
+			}
+		}
+
+		// Validate function signatures, which may also be raw 4-byte selectors
 		for func in &self.match_conditions.functions {
-			if !func.signature.contains('(') || !func.signature.contains(')') {
+			if parse_function_selector(&func.signature).is_some() {
+				if func.expression.is_some() {
+					return Err(ConfigError::validation_error(
+						format!(
+							"Function selector {} cannot have an expression, arguments are not \
+							 decoded for raw selectors",
+							func.signature
+						),
+						None,
+						None,
+					));
+				}
+			} else if !func.signature.contains('(') || !func.signature.contains(')') {
 				return Err(ConfigError::validation_error(
 					format!("Invalid function signature format: {}", func.signature),
 					None,
@@ -161,9 +550,21 @@ impl ConfigLoader for Monitor {
 			}
 		}
 
-		// Validate event signatures
+		// Validate event signatures, which may also be raw topic0 hashes
 		for event in &self.match_conditions.events {
-			if !event.signature.contains('(') || !event.signature.contains(')') {
+			if parse_event_topic(&event.signature).is_some() {
+				if event.expression.is_some() {
+					return Err(ConfigError::validation_error(
+						format!(
+							"Event topic {} cannot have an expression, arguments are not decoded \
+							 for raw topics",
+							event.signature
+						),
+						None,
+						None,
+					));
+				}
+			} else if !event.signature.contains('(') || !event.signature.contains(')') {
 				return Err(ConfigError::validation_error(
 					format!("Invalid event signature format: {}", event.signature),
 					None,
@@ -172,12 +573,115 @@ impl ConfigLoader for Monitor {
 			}
 		}
 
+		// Validate the event signatures labeling logs by their topic0 hash
+		for address in &self.addresses {
+			for (topic, signature) in &address.event_signatures {
+				if parse_event_topic(topic).is_none() {
+					return Err(ConfigError::validation_error(
+						format!(
+							"Invalid event topic '{}' in event_signatures of address {}, expected a \
+							 0x-prefixed 32 bytes hash",
+							topic, address.address
+						),
+						None,
+						None,
+					));
+				}
+				if !signature.contains('(') || !signature.contains(')') {
+					return Err(ConfigError::validation_error(
+						format!("Invalid event signature format: {}", signature),
+						None,
+						None,
+					));
+				}
+			}
+		}
+
+		// Validate aggregate conditions
+		for aggregate in &self.match_conditions.aggregates {
+			if !aggregate.signature.contains('(') || !aggregate.signature.contains(')') {
+				return Err(ConfigError::validation_error(
+					format!(
+						"Invalid aggregate event signature format: {}",
+						aggregate.signature
+					),
+					None,
+					None,
+				));
+			}
+			if aggregate.field.trim().is_empty() || aggregate.expression.trim().is_empty() {
+				return Err(ConfigError::validation_error(
+					format!(
+						"Aggregate condition for {} requires a field and an expression",
+						aggregate.signature
+					),
+					None,
+					None,
+				));
+			}
+		}
+
+		// Validate percentage change conditions
+		for condition in &self.match_conditions.percentage_changes {
+			for event in [&condition.from, &condition.to] {
+				if !event.signature.contains('(') || !event.signature.contains(')') {
+					return Err(ConfigError::validation_error(
+						format!(
+							"Invalid percentage change event signature format: {}",
+							event.signature
+						),
+						None,
+						None,
+					));
+				}
+				if event.field.trim().is_empty() {
+					return Err(ConfigError::validation_error(
+						format!(
+							"Percentage change condition for {} requires a field",
+							event.signature
+						),
+						None,
+						None,
+					));
+				}
+			}
+			if condition.expression.trim().is_empty() {
+				return Err(ConfigError::validation_error(
+					format!(
+						"Percentage change condition for {} requires an expression",
+						condition.from.signature
+					),
+					None,
+					None,
+				));
+			}
+		}
+
+		// Validate that signatures exist in the provided ABIs
+		validate_evm_signatures(self)?;
+
+		// Validate operation types
+		for operation in &self.match_conditions.operations {
+			if !is_operation_type(&operation.operation_type) {
+				return Err(ConfigError::validation_error(
+					format!(
+						"Invalid operation type: {}. Expected a Stellar operation type such as \
+						 'payment', 'change_trust' or 'invoke_host_function'",
+						operation.operation_type
+					),
+					None,
+					None,
+				));
+			}
+		}
+
 		// Validate trigger conditions (focus on script path, timeout, and language)
 		for trigger_condition in &self.trigger_conditions {
 			validate_script_config(
 				&trigger_condition.script_path,
 				&trigger_condition.language,
 				&trigger_condition.timeout_ms,
+				trigger_condition.script_checksum.as_deref(),
 			)?;
 		}
 
@@ -239,8 +743,13 @@ impl ConfigLoader for Monitor {
 mod tests {
 	use super::*;
 	use crate::{
-		models::core::{ScriptLanguage, TransactionStatus},
-		utils::tests::builders::evm::monitor::MonitorBuilder,
+		models::core::{
+			EventCondition, FunctionCondition, MatchConditionType, MatchConditions, ScriptLanguage,
+			ThrottleOverflow, TransactionStatus,
+		},
+		utils::tests::builders::{
+			evm::monitor::MonitorBuilder, stellar::monitor::MonitorBuilder as StellarMonitorBuilder,
+		},
 	};
 	use std::collections::HashMap;
 	use tempfile::TempDir;
@@ -288,6 +797,237 @@ mod tests {
 		assert_eq!(monitor.name, "TestMonitor");
 	}
 
+	#[tokio::test]
+	async fn test_load_monitor_with_conditional_triggers() {
+		let temp_dir = TempDir::new().unwrap();
+		let file_path = temp_dir.path().join("conditional_triggers_monitor.json");
+
+		let config = r#"{
+			"name": "TestMonitor",
+			"networks": ["ethereum_mainnet"],
+			"paused": false,
+			"addresses": [],
+			"match_conditions": {
+				"functions": [],
+				"events": [],
+				"transactions": []
+			},
+			"trigger_conditions": [],
+			"triggers": [
+				"slack_alerts",
+				{"name": "pagerduty", "when": ["functions"]}
+			]
+		}"#;
+
+		fs::write(&file_path, config).unwrap();
+
+		let monitor = Monitor::load_from_path(&file_path).await.unwrap();
+		assert_eq!(
+			monitor.triggers,
+			vec![
+				MonitorTrigger::Name("slack_alerts".to_string()),
+				MonitorTrigger::Conditional {
+					name: "pagerduty".to_string(),
+					when: vec![MatchConditionType::Functions],
+				},
+			]
+		);
+
+		let event_match = MatchConditions {
+			events: vec![EventCondition {
+				signature: "Transfer(address,address,uint256)".to_string(),
+				expression: None,
+			}],
+			..Default::default()
+		};
+		assert!(monitor.triggers[0].applies_to(&event_match));
+		assert!(!monitor.triggers[1].applies_to(&event_match));
+
+		let function_match = MatchConditions {
+			functions: vec![FunctionCondition {
+				signature: "transfer(address,uint256)".to_string(),
+				expression: None,
+			}],
+			..Default::default()
+		};
+		assert!(monitor.triggers[1].applies_to(&function_match));
+
+		// Plain trigger names are serialized back as strings
+		let serialized = serde_json::to_value(&monitor.triggers).unwrap();
+		assert_eq!(
+			serialized,
+			serde_json::json!(["slack_alerts", {"name": "pagerduty", "when": ["functions"]}])
+		);
+	}
+
+	fn monitor_config_with_spec_path(address: &str, spec_path: &str) -> String {
+		format!(
+			r#"{{
+				"name": "TestMonitor",
+				"networks": ["ethereum_mainnet"],
+				"paused": false,
+				"addresses": [
+					{{
+						"address": "{}",
+						"contract_spec_path": "{}"
+					}}
+				],
+				"match_conditions": {{
+					"functions": [],
+					"events": [],
+					"transactions": []
+				}},
+				"trigger_conditions": [],
+				"triggers": []
+			}}"#,
+			address, spec_path
+		)
+	}
+
+	#[tokio::test]
+	async fn test_load_monitor_with_evm_contract_spec_path() {
+		let temp_dir = TempDir::new().unwrap();
+		fs::create_dir(temp_dir.path().join("abis")).unwrap();
+		fs::write(
+			temp_dir.path().join("abis/erc20.json"),
+			r#"[{
+				"type": "function",
+				"name": "transfer",
+				"inputs": [
+					{"name": "to", "type": "address", "internalType": "address"},
+					{"name": "amount", "type": "uint256", "internalType": "uint256"}
+				],
+				"outputs": [{"name": "", "type": "bool", "internalType": "bool"}],
+				"stateMutability": "nonpayable"
+			}]"#,
+		)
+		.unwrap();
+
+		let file_path = temp_dir.path().join("monitor.json");
+		fs::write(
+			&file_path,
+			monitor_config_with_spec_path(
+				"0x0000000000000000000000000000000000000000",
+				"abis/erc20.json",
+			),
+		)
+		.unwrap();
+
+		let monitor = Monitor::load_from_path(&file_path).await.unwrap();
+		let address = &monitor.addresses[0];
+		assert_eq!(
+			address.contract_spec_path.as_deref(),
+			Some("abis/erc20.json")
+		);
+		match &address.contract_spec {
+			Some(ContractSpec::EVM(spec)) => {
+				assert!(spec.functions.contains_key("transfer"));
+			}
+			other => panic!("Expected EVM contract spec, got {:?}", other),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_load_monitor_with_stellar_contract_spec_path() {
+		let temp_dir = TempDir::new().unwrap();
+		fs::write(
+			temp_dir.path().join("spec.json"),
+			r#"[{
+				"function_v0": {
+					"doc": "",
+					"name": "transfer",
+					"inputs": [
+						{"doc": "", "name": "from", "type_": "address"},
+						{"doc": "", "name": "to", "type_": "address"},
+						{"doc": "", "name": "amount", "type_": "i128"}
+					],
+					"outputs": []
+				}
+			}]"#,
+		)
+		.unwrap();
+
+		let file_path = temp_dir.path().join("monitor.json");
+		fs::write(
+			&file_path,
+			monitor_config_with_spec_path(
+				"CBIELTK6YBZJU5UP2WWQEUCYKLPU6AUNZ2BQ4WWFEIE3USCIHMXQDAMA",
+				"spec.json",
+			),
+		)
+		.unwrap();
+
+		let monitor = Monitor::load_from_path(&file_path).await.unwrap();
+		match &monitor.addresses[0].contract_spec {
+			Some(ContractSpec::Stellar(spec)) => {
+				assert_eq!(spec.len(), 1);
+			}
+			other => panic!("Expected Stellar contract spec, got {:?}", other),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_load_monitor_with_missing_contract_spec_path() {
+		let temp_dir = TempDir::new().unwrap();
+		let file_path = temp_dir.path().join("monitor.json");
+		fs::write(
+			&file_path,
+			monitor_config_with_spec_path(
+				"0x0000000000000000000000000000000000000000",
+				"missing.json",
+			),
+		)
+		.unwrap();
+
+		let result = Monitor::load_from_path(&file_path).await;
+		assert!(matches!(result, Err(ConfigError::FileError(_))));
+		assert!(result
+			.unwrap_err()
+			.to_string()
+			.contains("failed to read contract spec file"));
+	}
+
+	#[tokio::test]
+	async fn test_load_monitor_with_malformed_contract_spec_path() {
+		let temp_dir = TempDir::new().unwrap();
+		fs::write(temp_dir.path().join("spec.json"), r#"{"not": "a spec"}"#).unwrap();
+		let file_path = temp_dir.path().join("monitor.json");
+		fs::write(
+			&file_path,
+			monitor_config_with_spec_path(
+				"0x0000000000000000000000000000000000000000",
+				"spec.json",
+			),
+		)
+		.unwrap();
+
+		let result = Monitor::load_from_path(&file_path).await;
+		assert!(matches!(result, Err(ConfigError::ParseError(_))));
+		assert!(result
+			.unwrap_err()
+			.to_string()
+			.contains("failed to parse contract spec file"));
+	}
+
+	#[tokio::test]
+	async fn test_load_monitor_with_inline_and_file_contract_spec() {
+		let temp_dir = TempDir::new().unwrap();
+		fs::write(temp_dir.path().join("spec.json"), "[]").unwrap();
+		let file_path = temp_dir.path().join("monitor.json");
+		let config = monitor_config_with_spec_path(
+			"0x0000000000000000000000000000000000000000",
+			"spec.json",
+		)
+		.replace(
+			r#""contract_spec_path""#,
+			r#""contract_spec": [], "contract_spec_path""#,
+		);
+		fs::write(&file_path, config).unwrap();
+
+		let result = Monitor::load_from_path(&file_path).await;
+		assert!(matches!(result, Err(ConfigError::ValidationError(_))));
+	}
+
 	#[tokio::test]
 	async fn test_load_invalid_monitor() {
 		let temp_dir = TempDir::new().unwrap();
@@ -402,6 +1142,553 @@ mod tests {
 		assert!(invalid_monitor.validate().is_err());
 	}
 
+	#[test]
+	fn test_validate_monitor_trigger_tags() {
+		let valid_monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.trigger_tags(vec!["critical".to_string()])
+			.build();
+		assert!(valid_monitor.validate().is_ok());
+
+		let invalid_monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.trigger_tags(vec!["critical".to_string(), " ".to_string()])
+			.build();
+		assert!(invalid_monitor
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("Trigger tags cannot be empty"));
+	}
+
+	#[test]
+	fn test_validate_monitor_conditional_triggers() {
+		let valid_monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.conditional_trigger("pagerduty", vec![MatchConditionType::Functions])
+			.build();
+		assert!(valid_monitor.validate().is_ok());
+
+		let invalid_monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.conditional_trigger("pagerduty", vec![])
+			.build();
+		assert!(invalid_monitor
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("Trigger 'pagerduty' must list at least one condition type in `when`"));
+	}
+
+	#[test]
+	fn test_validate_monitor_notification_throttle() {
+		let valid_monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.notification_throttle(10, 60000, ThrottleOverflow::Drop)
+			.build();
+		assert!(valid_monitor.validate().is_ok());
+
+		for (max_notifications_per_window, window_ms) in [(0, 60000), (10, 0)] {
+			let invalid_monitor = MonitorBuilder::new()
+				.name("TestMonitor")
+				.notification_throttle(
+					max_notifications_per_window,
+					window_ms,
+					ThrottleOverflow::Summary,
+				)
+				.build();
+			assert!(invalid_monitor
+				.validate()
+				.unwrap_err()
+				.to_string()
+				.contains("notification_throttle"));
+		}
+	}
+
+	#[test]
+	fn test_validate_monitor_match_dedup() {
+		let valid_monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.match_dedup(10)
+			.build();
+		assert!(valid_monitor.validate().is_ok());
+
+		let invalid_monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.match_dedup(0)
+			.build();
+		assert!(invalid_monitor
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("match_dedup"));
+	}
+
+	#[test]
+	fn test_validate_monitor_trigger_concurrency() {
+		let valid_monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.trigger_concurrency(4)
+			.build();
+		assert!(valid_monitor.validate().is_ok());
+
+		let invalid_monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.trigger_concurrency(0)
+			.build();
+		assert!(invalid_monitor
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("trigger_concurrency"));
+	}
+
+	#[test]
+	fn test_validate_monitor_labels() {
+		let valid_monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.labels(vec![("team", "payments"), ("env", "prod")])
+			.build();
+		assert!(valid_monitor.validate().is_ok());
+
+		let invalid_name = MonitorBuilder::new()
+			.name("TestMonitor")
+			.labels(vec![("team-name", "payments")])
+			.build();
+		assert!(invalid_name
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("Invalid label name 'team-name'"));
+
+		let reserved_name = MonitorBuilder::new()
+			.name("TestMonitor")
+			.labels(vec![("monitor", "payments")])
+			.build();
+		assert!(reserved_name
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("Label name 'monitor' is reserved"));
+	}
+
+	#[test]
+	fn test_validate_monitor_enrichment() {
+		let valid_monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.enrichment_call("symbol", "symbol()", Some("string"), None)
+			.enrichment_call("decimals", "decimals()", Some("uint8"), None)
+			.build();
+		assert!(valid_monitor.validate().is_ok());
+
+		let duplicate_name = MonitorBuilder::new()
+			.name("TestMonitor")
+			.enrichment_call("symbol", "symbol()", Some("string"), None)
+			.enrichment_call("symbol", "name()", Some("string"), None)
+			.build();
+		assert!(duplicate_name
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("Duplicate enrichment call name"));
+
+		let missing_address = MonitorBuilder::new()
+			.name("TestMonitor")
+			.addresses(vec![])
+			.enrichment_call("symbol", "symbol()", Some("string"), None)
+			.build();
+		assert!(missing_address.validate().is_err());
+
+		let zero_cache_ttl = MonitorBuilder::new()
+			.name("TestMonitor")
+			.enrichment_call("symbol", "symbol()", Some("string"), None)
+			.enrichment_cache_ttl_secs(0)
+			.build();
+		assert!(zero_cache_ttl
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("cache_ttl_secs of enrichment call 'symbol' must be greater than 0"));
+
+		let invalid_output = MonitorBuilder::new()
+			.name("TestMonitor")
+			.enrichment_call("symbol", "symbol()", Some("strin"), None)
+			.build();
+		assert!(invalid_output
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("Invalid output type"));
+
+		let zero_timeout = MonitorBuilder::new()
+			.name("TestMonitor")
+			.enrichment_call("symbol", "symbol()", Some("string"), None)
+			.enrichment_timeout_ms(0)
+			.build();
+		assert!(zero_timeout.validate().is_err());
+	}
+
+	#[test]
+	fn test_validate_monitor_expected_match_window() {
+		let monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.expected_match_window_secs(3600)
+			.build();
+		assert!(monitor.validate().is_ok());
+
+		let monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.expected_match_window_secs(0)
+			.build();
+		assert!(monitor
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("expected_match_window_secs must be greater than 0"));
+	}
+
+	#[test]
+	fn test_validate_monitor_severity_rules() {
+		let monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.severity_rule("critical", None, Some("value > 1000"))
+			.severity_rule("info", None, None)
+			.build();
+		assert!(monitor.validate().is_ok());
+
+		let monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.severity_rule(" ", None, None)
+			.build();
+		assert!(monitor
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("Severity rule severity cannot be empty"));
+
+		let monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.severity_rule("critical", None, Some(""))
+			.build();
+		assert!(monitor
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("Severity rule 'critical' has an empty expression"));
+	}
+
+	#[test]
+	fn test_validate_monitor_sample_rate() {
+		for sample_rate in [0.0, 0.25, 1.0] {
+			let monitor = MonitorBuilder::new()
+				.name("TestMonitor")
+				.sample_rate(sample_rate)
+				.build();
+			assert!(monitor.validate().is_ok());
+		}
+
+		for sample_rate in [-0.1, 1.5, f64::NAN] {
+			let monitor = MonitorBuilder::new()
+				.name("TestMonitor")
+				.sample_rate(sample_rate)
+				.build();
+			assert!(monitor
+				.validate()
+				.unwrap_err()
+				.to_string()
+				.contains("sample_rate must be between 0.0 and 1.0"));
+		}
+	}
+
+	#[test]
+	fn test_validate_monitor_raw_selectors() {
+		let transfer_topic = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+		let valid_monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.function("0xa9059cbb", None)
+			.event(transfer_topic, None)
+			.build();
+		assert!(valid_monitor.validate().is_ok());
+
+		let function_with_expression = MonitorBuilder::new()
+			.name("TestMonitor")
+			.function("0xa9059cbb", Some("amount > 0".to_string()))
+			.build();
+		assert!(function_with_expression
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("cannot have an expression"));
+
+		let event_with_expression = MonitorBuilder::new()
+			.name("TestMonitor")
+			.event(transfer_topic, Some("value > 0".to_string()))
+			.build();
+		assert!(event_with_expression
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("cannot have an expression"));
+
+		let malformed_selector = MonitorBuilder::new()
+			.name("TestMonitor")
+			.function("0xa9059c", None)
+			.build();
+		assert!(malformed_selector
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("Invalid function signature format"));
+	}
+
+	#[test]
+	fn test_validate_monitor_signatures_in_abi() {
+		let spec = ContractSpec::EVM(crate::models::EVMContractSpec::from(serde_json::json!([
+			{
+				"type": "event",
+				"name": "Transfer",
+				"inputs": [
+					{"name": "from", "type": "address", "indexed": true},
+					{"name": "to", "type": "address", "indexed": true},
+					{"name": "value", "type": "uint256", "indexed": false}
+				],
+				"anonymous": false
+			},
+			{
+				"type": "function",
+				"name": "transfer",
+				"inputs": [
+					{"name": "to", "type": "address"},
+					{"name": "amount", "type": "uint256"}
+				],
+				"outputs": [{"name": "", "type": "bool"}],
+				"stateMutability": "nonpayable"
+			}
+		])));
+
+		let matching = MonitorBuilder::new()
+			.name("TestMonitor")
+			.address_with_spec(
+				"0x0000000000000000000000000000000000004321",
+				Some(spec.clone()),
+			)
+			.event("Transfer(address, address, uint256)", None)
+			.function("transfer(address,uint256)", None)
+			.build();
+		assert!(matching.validate().is_ok());
+
+		let event_typo = MonitorBuilder::new()
+			.name("TestMonitor")
+			.address_with_spec(
+				"0x0000000000000000000000000000000000004321",
+				Some(spec.clone()),
+			)
+			.event("Transfer(address,address,uint)", None)
+			.build();
+		let error = event_typo.validate().unwrap_err().to_string();
+		assert!(error.contains("Event signature 'Transfer(address,address,uint)' not found"));
+		assert!(error.contains("Did you mean 'Transfer(address,address,uint256)'?"));
+
+		let function_typo = MonitorBuilder::new()
+			.name("TestMonitor")
+			.address_with_spec("0x0000000000000000000000000000000000004321", Some(spec))
+			.function("transfr(address,uint256)", None)
+			.build();
+		let error = function_typo.validate().unwrap_err().to_string();
+		assert!(error.contains("Function signature 'transfr(address,uint256)' not found"));
+		assert!(error.contains("Did you mean 'transfer(address,uint256)'?"));
+
+		// Specs missing from the configuration are fetched at startup, so no check is done
+		let spec_less = MonitorBuilder::new()
+			.name("TestMonitor")
+			.address("0x0000000000000000000000000000000000004321")
+			.event("Transfer(address,address,uint)", None)
+			.build();
+		assert!(spec_less.validate().is_ok());
+	}
+
+	#[test]
+	fn test_validate_monitor_event_signatures() {
+		let transfer_topic = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+
+		let labeled = MonitorBuilder::new()
+			.name("TestMonitor")
+			.address("0x0000000000000000000000000000000000004321")
+			.event_signature(transfer_topic, "Transfer(address,address,uint256)")
+			.event("Transfer(address,address,uint256)", None)
+			.build();
+		assert!(labeled.validate().is_ok());
+
+		let invalid_topic = MonitorBuilder::new()
+			.name("TestMonitor")
+			.address("0x0000000000000000000000000000000000004321")
+			.event_signature("0xddf252ad", "Transfer(address,address,uint256)")
+			.build();
+		assert!(invalid_topic
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("Invalid event topic '0xddf252ad'"));
+
+		let invalid_signature = MonitorBuilder::new()
+			.name("TestMonitor")
+			.address("0x0000000000000000000000000000000000004321")
+			.event_signature(transfer_topic, "Transfer")
+			.build();
+		assert!(invalid_signature
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("Invalid event signature format: Transfer"));
+
+		// Labeled signatures are known even when the contract spec lacks the event
+		let spec = ContractSpec::EVM(crate::models::EVMContractSpec::from(serde_json::json!([])));
+		let labeled_with_spec = MonitorBuilder::new()
+			.name("TestMonitor")
+			.address_with_spec("0x0000000000000000000000000000000000004321", Some(spec))
+			.event_signature(transfer_topic, "Transfer(address,address,uint256)")
+			.event("Transfer(address,address,uint256)", None)
+			.build();
+		assert!(labeled_with_spec.validate().is_ok());
+	}
+
+	#[test]
+	fn test_validate_monitor_aggregates() {
+		let valid_monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.aggregate(
+				"Transfer(address,address,uint256)",
+				"value",
+				None,
+				"sum > 1000000",
+			)
+			.build();
+		assert!(valid_monitor.validate().is_ok());
+
+		let invalid_signature = MonitorBuilder::new()
+			.name("TestMonitor")
+			.aggregate("Transfer", "value", None, "sum > 1000000")
+			.build();
+		assert!(invalid_signature
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("Invalid aggregate event signature format"));
+
+		let missing_expression = MonitorBuilder::new()
+			.name("TestMonitor")
+			.aggregate("Transfer(address,address,uint256)", "value", None, " ")
+			.build();
+		assert!(missing_expression
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("requires a field and an expression"));
+	}
+
+	#[test]
+	fn test_validate_monitor_percentage_changes() {
+		let valid_monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.percentage_change(
+				("Sync(uint112,uint112)", "reserve0"),
+				("Sync(uint112,uint112)", "reserve0"),
+				"abs_change > 5",
+			)
+			.build();
+		assert!(valid_monitor.validate().is_ok());
+
+		let invalid_signature = MonitorBuilder::new()
+			.name("TestMonitor")
+			.percentage_change(
+				("Sync(uint112,uint112)", "reserve0"),
+				("Sync", "reserve0"),
+				"abs_change > 5",
+			)
+			.build();
+		assert!(invalid_signature
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("Invalid percentage change event signature format: Sync"));
+
+		let missing_field = MonitorBuilder::new()
+			.name("TestMonitor")
+			.percentage_change(
+				("Sync(uint112,uint112)", " "),
+				("Sync(uint112,uint112)", "reserve0"),
+				"abs_change > 5",
+			)
+			.build();
+		assert!(missing_field
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("requires a field"));
+
+		let missing_expression = MonitorBuilder::new()
+			.name("TestMonitor")
+			.percentage_change(
+				("Sync(uint112,uint112)", "reserve0"),
+				("Sync(uint112,uint112)", "reserve0"),
+				"",
+			)
+			.build();
+		assert!(missing_expression
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("requires an expression"));
+	}
+
+	#[test]
+	fn test_validate_monitor_operation_types() {
+		let valid_monitor = StellarMonitorBuilder::new()
+			.name("TestMonitor")
+			.operation("payment", Some("amount > 1000".to_string()))
+			.operation("change_trust", None)
+			.operation("INVOKE_HOST_FUNCTION", None)
+			.build();
+		assert!(valid_monitor.validate().is_ok());
+
+		let invalid_monitor = StellarMonitorBuilder::new()
+			.name("TestMonitor")
+			.operation("transfer", None)
+			.build();
+		let result = invalid_monitor.validate();
+		assert!(result.is_err());
+		assert!(result
+			.unwrap_err()
+			.to_string()
+			.contains("Invalid operation type: transfer"));
+	}
+
+	#[test]
+	fn test_validate_monitor_paused_until() {
+		let valid_monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.paused_until("2030-01-01T00:00:00Z")
+			.build();
+		assert!(valid_monitor.validate().is_ok());
+
+		let valid_offset_monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.paused_until("2030-01-01T02:00:00+02:00")
+			.build();
+		assert!(valid_offset_monitor.validate().is_ok());
+
+		let invalid_monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.paused_until("2030-01-01 00:00")
+			.build();
+		let result = invalid_monitor.validate();
+		assert!(result.is_err());
+		assert!(result
+			.unwrap_err()
+			.to_string()
+			.contains("Invalid paused_until timestamp"));
+	}
+
 	#[test]
 	fn test_validate_monitor_with_trigger_conditions() {
 		// Create a temporary directory and script file
@@ -587,19 +1874,35 @@ mod tests {
 			name: "TestMonitor".to_string(),
 			networks: vec!["ethereum_mainnet".to_string()],
 			paused: false,
+			paused_until: None,
 			addresses: vec![],
 			match_conditions: MatchConditions {
 				functions: vec![],
 				events: vec![],
 				transactions: vec![],
+				operations: vec![],
+				aggregates: vec![],
+				percentage_changes: vec![],
 			},
 			trigger_conditions: vec![TriggerConditions {
 				script_path: script_path.to_str().unwrap().to_string(),
 				timeout_ms: 1000,
+				script_checksum: None,
+				retry_policy: None,
 				arguments: None,
 				language: ScriptLanguage::Bash,
 			}],
 			triggers: vec![],
+			trigger_tags: vec![],
+			trigger_execution: None,
+			notification_throttle: None,
+			notification_retry: None,
+			match_dedup: None,
+			enrichment: None,
+			labels: HashMap::new(),
+			sample_rate: None,
+			expected_match_window_secs: None,
+			severity_rules: vec![],
 		};
 
 		monitor.validate_protocol();
@@ -3,34 +3,116 @@
 //! This module implements the ConfigLoader trait for Network configurations,
 //! allowing network definitions to be loaded from JSON files.
 
+use alloy::primitives::Address;
 use async_trait::async_trait;
-use std::{collections::HashMap, path::Path, str::FromStr};
+use reqwest::header::{HeaderName, HeaderValue};
+use std::{collections::HashMap, path::Path};
 
 use crate::{
-	models::{config::error::ConfigError, BlockChainType, ConfigLoader, Network, SecretValue},
-	utils::{get_cron_interval_ms, normalize_string},
+	models::{
+		config::error::ConfigError, BlockChainType, ConfigLoader, Network, RpcUrl, SecretValue,
+		DEFAULT_MAX_LOGS_PER_TX, DEFAULT_MAX_PAST_BLOCKS_LIMIT,
+	},
+	utils::{
+		get_cron_interval_ms, is_adaptive_schedule, normalize_string, validate_schedule,
+		ADAPTIVE_MAX_BLOCK_TIMES,
+	},
 };
 
+/// Public RPC endpoints of the well-known Stellar networks, used when a Stellar network
+/// configures no RPC URL
+const DEFAULT_STELLAR_RPC_URLS: [(&str, &str); 3] = [
+	("mainnet", "https://mainnet.sorobanrpc.com"),
+	("testnet", "https://soroban-testnet.stellar.org"),
+	("futurenet", "https://rpc-futurenet.stellar.org"),
+];
+
 impl Network {
+	/// Defaults the RPC URLs of a Stellar network configuring none to the public RPC endpoint
+	/// of the well-known network matching its passphrase
+	///
+	/// Networks with RPC URLs, other network types and unknown passphrases are left untouched.
+	pub fn apply_default_rpc_urls(&mut self) {
+		if self.network_type != BlockChainType::Stellar || !self.rpc_urls.is_empty() {
+			return;
+		}
+		let Some(default_url) = self.stellar_network_name().and_then(|name| {
+			DEFAULT_STELLAR_RPC_URLS
+				.iter()
+				.find(|(known, _)| *known == name)
+				.map(|(_, url)| *url)
+		}) else {
+			return;
+		};
+
+		tracing::info!(
+			"Network '{}' configures no RPC URL, defaulting to the public endpoint {}",
+			self.slug,
+			default_url
+		);
+		self.rpc_urls.push(RpcUrl {
+			type_: "rpc".to_string(),
+			url: SecretValue::Plain(default_url.to_string().into()),
+			weight: 100,
+			headers: HashMap::new(),
+		});
+	}
+
 	/// Calculates the recommended minimum number of past blocks to maintain for this network.
 	///
 	/// This function computes a safe minimum value based on three factors:
 	/// 1. The number of blocks that occur during one cron interval (`blocks_per_cron`)
-	/// 2. The required confirmation blocks for the network
+	/// 2. The confirmations required by the network's finality
 	/// 3. An additional buffer block (+1)
 	///
-	/// The formula used is: `(cron_interval_ms / block_time_ms) + confirmation_blocks + 1`
+	/// The formula used is: `(cron_interval_ms / block_time_ms) + confirmations + 1`
 	///
 	/// # Returns
 	/// * `u64` - The recommended minimum number of past blocks to maintain
 	///
 	/// # Note
 	/// If the cron schedule parsing fails, the blocks_per_cron component will be 0,
-	/// resulting in a minimum recommendation of `confirmation_blocks + 1`
+	/// resulting in a minimum recommendation of `confirmations + 1`. The `@adaptive`
+	/// schedule uses its longest poll interval, `ADAPTIVE_MAX_BLOCK_TIMES` block times.
 	pub fn get_recommended_past_blocks(&self) -> u64 {
-		let cron_interval_ms = get_cron_interval_ms(&self.cron_schedule).unwrap_or(0) as u64;
+		let cron_interval_ms = if is_adaptive_schedule(&self.cron_schedule) {
+			self.block_time_ms * u64::from(ADAPTIVE_MAX_BLOCK_TIMES)
+		} else {
+			get_cron_interval_ms(&self.cron_schedule).unwrap_or(0) as u64
+		};
 		let blocks_per_cron = cron_interval_ms / self.block_time_ms;
-		blocks_per_cron + self.confirmation_blocks + 1
+		blocks_per_cron + self.confirmations() + 1
+	}
+
+	/// Returns the maximum number of past blocks processed when catching up
+	///
+	/// This is `max_past_blocks`, or the recommended number of past blocks when it is not set,
+	/// capped to `max_past_blocks_limit` so that a misconfigured value cannot trigger an
+	/// enormous backfill.
+	///
+	/// # Returns
+	/// * `u64` - The maximum number of past blocks to process
+	pub fn get_max_past_blocks(&self) -> u64 {
+		self.max_past_blocks
+			.unwrap_or_else(|| self.get_recommended_past_blocks())
+			.min(self.get_max_past_blocks_limit())
+	}
+
+	/// Returns the upper bound of the past blocks processed when catching up
+	///
+	/// # Returns
+	/// * `u64` - `max_past_blocks_limit`, or `DEFAULT_MAX_PAST_BLOCKS_LIMIT` when not set
+	pub fn get_max_past_blocks_limit(&self) -> u64 {
+		self.max_past_blocks_limit
+			.unwrap_or(DEFAULT_MAX_PAST_BLOCKS_LIMIT)
+	}
+
+	/// Returns the maximum number of logs of a single transaction matched against the monitors
+	///
+	/// # Returns
+	/// * `usize` - `max_logs_per_tx`, or `DEFAULT_MAX_LOGS_PER_TX` when not set
+	pub fn get_max_logs_per_tx(&self) -> usize {
+		self.max_logs_per_tx.unwrap_or(DEFAULT_MAX_LOGS_PER_TX)
 	}
 }
 
@@ -50,6 +132,17 @@ impl ConfigLoader for Network {
 				)
 			})?;
 			rpc_url.url = SecretValue::Plain(resolved_url);
+
+			for (name, value) in &mut rpc_url.headers {
+				let resolved_value = value.resolve().await.map_err(|e| {
+					ConfigError::parse_error(
+						format!("failed to resolve RPC header '{}': {}", name, e),
+						Some(Box::new(e)),
+						None,
+					)
+				})?;
+				*value = SecretValue::Plain(resolved_value);
+			}
 		}
 		Ok(network)
 	}
@@ -146,6 +239,8 @@ impl ConfigLoader for Network {
 			)
 		})?;
 
+		config.apply_default_rpc_urls();
+
 		// Resolve secrets before validating
 		config = config.resolve_secrets().await?;
 
@@ -198,7 +293,7 @@ impl ConfigLoader for Network {
 		}
 
 		// Validate RPC URL types
-		let supported_types = ["rpc"];
+		let supported_types = ["rpc", "graphql"];
 		if !self
 			.rpc_urls
 			.iter()
@@ -214,6 +309,20 @@ impl ConfigLoader for Network {
 			));
 		}
 
+		// GraphQL indexers are only supported for Stellar networks
+		if self.network_type != BlockChainType::Stellar
+			&& self
+				.rpc_urls
+				.iter()
+				.any(|rpc_url| rpc_url.type_ == "graphql")
+		{
+			return Err(ConfigError::validation_error(
+				"RPC URL type 'graphql' is only supported for Stellar networks",
+				None,
+				None,
+			));
+		}
+
 		// Validate RPC URLs format
 		if !self.rpc_urls.iter().all(|rpc_url| {
 			rpc_url.url.starts_with("http://") || rpc_url.url.starts_with("https://")
@@ -234,6 +343,27 @@ impl ConfigLoader for Network {
 			));
 		}
 
+		// Validate RPC URL headers, whose values are checked once their secrets are resolved
+		for (name, value) in self.rpc_urls.iter().flat_map(|rpc_url| &rpc_url.headers) {
+			if HeaderName::from_bytes(name.as_bytes()).is_err() {
+				return Err(ConfigError::validation_error(
+					format!("Invalid RPC header name '{}'", name),
+					None,
+					None,
+				));
+			}
+			if matches!(value, SecretValue::Plain(_))
+				&& HeaderValue::from_str(value.as_str()).is_err()
+			{
+				// The value is not included, as it may be a secret
+				return Err(ConfigError::validation_error(
+					format!("Invalid value for RPC header '{}'", name),
+					None,
+					None,
+				));
+			}
+		}
+
 		// Validate block time
 		if self.block_time_ms < 100 {
 			return Err(ConfigError::validation_error(
@@ -261,9 +391,13 @@ impl ConfigLoader for Network {
 			));
 		}
 
-		// Add cron schedule format validation
-		if let Err(e) = cron::Schedule::from_str(&self.cron_schedule) {
-			return Err(ConfigError::validation_error(e.to_string(), None, None));
+		// Validate cron schedule format (or `@interval:<duration>` form)
+		if let Err(e) = validate_schedule(&self.cron_schedule) {
+			return Err(ConfigError::validation_error(
+				format!("Network '{}': {}", self.slug, e),
+				None,
+				None,
+			));
 		}
 
 		// Validate max_past_blocks
@@ -289,6 +423,144 @@ impl ConfigLoader for Network {
 			}
 		}
 
+		// Validate max_past_blocks_limit
+		if self.max_past_blocks_limit == Some(0) {
+			return Err(ConfigError::validation_error(
+				"max_past_blocks_limit must be greater than 0",
+				None,
+				None,
+			));
+		}
+
+		// Validate backfill_concurrency
+		if self.backfill_concurrency == Some(0) {
+			return Err(ConfigError::validation_error(
+				"backfill_concurrency must be greater than 0",
+				None,
+				None,
+			));
+		}
+
+		// Validate block processing watchdog
+		if self.max_block_processing_ms == Some(0) {
+			return Err(ConfigError::validation_error(
+				"max_block_processing_ms must be greater than 0",
+				None,
+				None,
+			));
+		}
+		if self.block_processing_retries.is_some() && self.max_block_processing_ms.is_none() {
+			return Err(ConfigError::validation_error(
+				"block_processing_retries requires max_block_processing_ms to be set",
+				None,
+				None,
+			));
+		}
+
+		// Revert reasons can only be decoded on EVM networks
+		if self.decode_revert_reasons == Some(true) && self.network_type != BlockChainType::EVM {
+			return Err(ConfigError::validation_error(
+				"decode_revert_reasons is only supported on EVM networks",
+				None,
+				None,
+			));
+		}
+
+		// Address checksums only exist on EVM networks
+		if self.strict_address_checksum == Some(true) && self.network_type != BlockChainType::EVM {
+			return Err(ConfigError::validation_error(
+				"strict_address_checksum is only supported on EVM networks",
+				None,
+				None,
+			));
+		}
+
+		// Block tags only exist on EVM networks
+		if self.finality.is_some() && self.network_type != BlockChainType::EVM {
+			return Err(ConfigError::validation_error(
+				"finality is only supported on EVM networks",
+				None,
+				None,
+			));
+		}
+
+		// Validate tx_chunk_size
+		if let Some(tx_chunk_size) = self.tx_chunk_size {
+			if tx_chunk_size == 0 {
+				return Err(ConfigError::validation_error(
+					"tx_chunk_size must be greater than 0",
+					None,
+					None,
+				));
+			}
+			if self.network_type != BlockChainType::EVM {
+				return Err(ConfigError::validation_error(
+					"tx_chunk_size is only supported on EVM networks",
+					None,
+					None,
+				));
+			}
+		}
+
+		// Validate max_logs_per_tx
+		if let Some(max_logs_per_tx) = self.max_logs_per_tx {
+			if max_logs_per_tx == 0 {
+				return Err(ConfigError::validation_error(
+					"max_logs_per_tx must be greater than 0",
+					None,
+					None,
+				));
+			}
+			if self.network_type != BlockChainType::EVM {
+				return Err(ConfigError::validation_error(
+					"max_logs_per_tx is only supported on EVM networks",
+					None,
+					None,
+				));
+			}
+		}
+
+		// Validate ens_registry
+		if let Some(ens_registry) = &self.ens_registry {
+			if self.network_type != BlockChainType::EVM {
+				return Err(ConfigError::validation_error(
+					"ens_registry is only supported on EVM networks",
+					None,
+					None,
+				));
+			}
+			if ens_registry.parse::<Address>().is_err() {
+				return Err(ConfigError::validation_error(
+					format!("Invalid ens_registry address: {}", ens_registry),
+					None,
+					None,
+				));
+			}
+		}
+
+		// Validate HTTP connection pool
+		if self.pool_idle_timeout_ms == Some(0) {
+			return Err(ConfigError::validation_error(
+				"pool_idle_timeout_ms must be greater than 0",
+				None,
+				None,
+			));
+		}
+
+		// Validate RPC method aliases
+		if let Some(aliases) = &self.rpc_method_aliases {
+			if aliases
+				.iter()
+				.any(|(method, alias)| method.trim().is_empty() || alias.trim().is_empty())
+			{
+				return Err(ConfigError::validation_error(
+					"rpc_method_aliases cannot contain empty method names",
+					None,
+					None,
+				));
+			}
+		}
+
 		// Log a warning if the network uses an insecure protocol
 		self.validate_protocol();
 
@@ -354,6 +626,7 @@ impl ConfigLoader for Network {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::models::{Finality, SecretString};
 	use crate::utils::tests::builders::network::NetworkBuilder;
 	use std::fs;
 	use tempfile::TempDir;
@@ -391,6 +664,15 @@ mod tests {
 			network.get_recommended_past_blocks(),
 			recommended_past_blocks
 		);
+
+		// Numeric finality replaces the confirmation blocks
+		let network = NetworkBuilder::new()
+			.block_time_ms(1000)
+			.confirmation_blocks(2)
+			.finality(Finality::Confirmations(10))
+			.cron_schedule("0 */5 * * * *")
+			.build();
+		assert_eq!(network.get_recommended_past_blocks(), 300 + 10 + 1);
 	}
 
 	#[test]
@@ -427,6 +709,28 @@ mod tests {
 		));
 	}
 
+	#[test]
+	fn test_validate_graphql_rpc_url_type() {
+		let network = NetworkBuilder::new()
+			.network_type(BlockChainType::Stellar)
+			.network_passphrase("Test SDF Network ; September 2015")
+			.clear_rpc_urls()
+			.add_rpc_url("https://indexer.network/graphql", "graphql", 100)
+			.build();
+		assert!(network.validate().is_ok());
+
+		let network = NetworkBuilder::new()
+			.network_type(BlockChainType::EVM)
+			.chain_id(1)
+			.clear_rpc_urls()
+			.add_rpc_url("https://indexer.network/graphql", "graphql", 100)
+			.build();
+		let err = network.validate().unwrap_err();
+		assert!(err
+			.to_string()
+			.contains("only supported for Stellar networks"));
+	}
+
 	#[test]
 	fn test_validate_invalid_rpc_url_format() {
 		let network = NetworkBuilder::new().rpc_url("invalid-url").build();
@@ -446,6 +750,81 @@ mod tests {
 		));
 	}
 
+	#[test]
+	fn test_validate_rpc_url_headers() {
+		let network = NetworkBuilder::new()
+			.rpc_url("https://test.network")
+			.add_rpc_url_header(
+				"x-api-key",
+				SecretValue::Environment("TEST_RPC_HEADER_KEY".to_string()),
+			)
+			.build();
+		assert!(network.validate().is_ok());
+
+		let network = NetworkBuilder::new()
+			.rpc_url("https://test.network")
+			.add_rpc_url_header(
+				"invalid header",
+				SecretValue::Plain(SecretString::new("value".to_string())),
+			)
+			.build();
+		let result = network.validate();
+		assert!(matches!(result, Err(ConfigError::ValidationError(_))));
+		assert!(result
+			.unwrap_err()
+			.to_string()
+			.contains("Invalid RPC header name 'invalid header'"));
+
+		let network = NetworkBuilder::new()
+			.rpc_url("https://test.network")
+			.add_rpc_url_header(
+				"x-api-key",
+				SecretValue::Plain(SecretString::new("secret\nvalue".to_string())),
+			)
+			.build();
+		let result = network.validate();
+		assert!(matches!(result, Err(ConfigError::ValidationError(_))));
+		let message = result.unwrap_err().to_string();
+		assert!(message.contains("Invalid value for RPC header 'x-api-key'"));
+		assert!(!message.contains("secret"));
+	}
+
+	#[tokio::test]
+	async fn test_resolve_secrets_rpc_url_headers() {
+		std::env::set_var("TEST_RPC_HEADER_KEY", "header-secret");
+		let network = NetworkBuilder::new()
+			.rpc_url("https://test.network")
+			.add_rpc_url_header(
+				"x-api-key",
+				SecretValue::Environment("TEST_RPC_HEADER_KEY".to_string()),
+			)
+			.build();
+
+		let resolved = network.resolve_secrets().await.unwrap();
+		let value = &resolved.rpc_urls[0].headers["x-api-key"];
+		assert!(matches!(value, SecretValue::Plain(_)));
+		assert_eq!(value.as_str(), "header-secret");
+		std::env::remove_var("TEST_RPC_HEADER_KEY");
+	}
+
+	#[tokio::test]
+	async fn test_resolve_secrets_rpc_url_headers_env_error() {
+		let network = NetworkBuilder::new()
+			.rpc_url("https://test.network")
+			.add_rpc_url_header(
+				"x-api-key",
+				SecretValue::Environment("NON_EXISTENT_ENV_VAR".to_string()),
+			)
+			.build();
+
+		let result = network.resolve_secrets().await;
+		assert!(result.is_err());
+		assert!(result
+			.unwrap_err()
+			.to_string()
+			.contains("failed to resolve RPC header 'x-api-key'"));
+	}
+
 	#[test]
 	fn test_validate_invalid_block_time() {
 		let network = NetworkBuilder::new().block_time_ms(50).build();
@@ -473,6 +852,286 @@ mod tests {
 		));
 	}
 
+	#[test]
+	fn test_validate_valid_cron_schedule() {
+		let network = NetworkBuilder::new()
+			.cron_schedule("*/15 * * * * *")
+			.build();
+		assert!(network.validate().is_ok());
+	}
+
+	#[test]
+	fn test_validate_invalid_cron_schedule_error_message() {
+		let network = NetworkBuilder::new()
+			.slug("test_network")
+			.cron_schedule("*/15 * * *")
+			.build();
+		match network.validate() {
+			Err(ConfigError::ValidationError(err)) => {
+				assert!(err.message.contains("test_network"));
+				assert!(err.message.contains("Invalid cron schedule '*/15 * * *'"));
+			}
+			other => panic!("Expected validation error, got {:?}", other),
+		}
+	}
+
+	#[test]
+	fn test_validate_interval_schedule() {
+		let network = NetworkBuilder::new()
+			.cron_schedule("@interval:500ms")
+			.max_past_blocks(50)
+			.build();
+		assert!(network.validate().is_ok());
+		assert_eq!(
+			network.get_recommended_past_blocks(),
+			(500 / network.block_time_ms) + network.confirmation_blocks + 1
+		);
+	}
+
+	#[test]
+	fn test_validate_adaptive_schedule() {
+		let network = NetworkBuilder::new()
+			.cron_schedule("@adaptive")
+			.block_time_ms(12000)
+			.confirmation_blocks(12)
+			.build();
+		assert!(network.validate().is_ok());
+		// Up to eight blocks are produced between two adaptive polls
+		assert_eq!(network.get_recommended_past_blocks(), 8 + 12 + 1);
+	}
+
+	#[test]
+	fn test_validate_invalid_interval_schedule() {
+		let network = NetworkBuilder::new().cron_schedule("@interval:0ms").build();
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+	}
+
+	#[test]
+	fn test_validate_zero_backfill_concurrency() {
+		let network = NetworkBuilder::new().backfill_concurrency(0).build();
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+
+		let network = NetworkBuilder::new().backfill_concurrency(4).build();
+		assert!(network.validate().is_ok());
+	}
+
+	#[test]
+	fn test_validate_block_processing_watchdog() {
+		let network = NetworkBuilder::new().max_block_processing_ms(0).build();
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+
+		let network = NetworkBuilder::new().block_processing_retries(1).build();
+		assert!(network
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("block_processing_retries requires max_block_processing_ms"));
+
+		let network = NetworkBuilder::new()
+			.max_block_processing_ms(5000)
+			.block_processing_retries(1)
+			.build();
+		assert!(network.validate().is_ok());
+	}
+
+	#[test]
+	fn test_validate_decode_revert_reasons() {
+		let network = NetworkBuilder::new()
+			.network_type(BlockChainType::EVM)
+			.decode_revert_reasons(true)
+			.build();
+		assert!(network.validate().is_ok());
+
+		let network = NetworkBuilder::new()
+			.network_type(BlockChainType::Stellar)
+			.network_passphrase("Test SDF Network ; September 2015")
+			.decode_revert_reasons(true)
+			.build();
+		assert!(network
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("decode_revert_reasons is only supported on EVM networks"));
+	}
+
+	#[test]
+	fn test_validate_strict_address_checksum() {
+		let network = NetworkBuilder::new()
+			.network_type(BlockChainType::EVM)
+			.strict_address_checksum(true)
+			.build();
+		assert!(network.validate().is_ok());
+
+		let network = NetworkBuilder::new()
+			.network_type(BlockChainType::Stellar)
+			.network_passphrase("Test SDF Network ; September 2015")
+			.strict_address_checksum(true)
+			.build();
+		assert!(network
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("strict_address_checksum is only supported on EVM networks"));
+	}
+
+	#[test]
+	fn test_validate_finality() {
+		let network = NetworkBuilder::new()
+			.network_type(BlockChainType::EVM)
+			.finality(Finality::Finalized)
+			.build();
+		assert!(network.validate().is_ok());
+
+		let network = NetworkBuilder::new()
+			.network_type(BlockChainType::Stellar)
+			.network_passphrase("Test SDF Network ; September 2015")
+			.finality(Finality::Confirmations(2))
+			.build();
+		assert!(network
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("finality is only supported on EVM networks"));
+	}
+
+	#[test]
+	fn test_validate_tx_chunk_size() {
+		let network = NetworkBuilder::new()
+			.network_type(BlockChainType::EVM)
+			.tx_chunk_size(500)
+			.build();
+		assert!(network.validate().is_ok());
+
+		let network = NetworkBuilder::new()
+			.network_type(BlockChainType::EVM)
+			.tx_chunk_size(0)
+			.build();
+		assert!(network
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("tx_chunk_size must be greater than 0"));
+
+		let network = NetworkBuilder::new()
+			.network_type(BlockChainType::Stellar)
+			.network_passphrase("Test SDF Network ; September 2015")
+			.tx_chunk_size(500)
+			.build();
+		assert!(network
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("tx_chunk_size is only supported on EVM networks"));
+	}
+
+	#[test]
+	fn test_validate_max_logs_per_tx() {
+		let network = NetworkBuilder::new()
+			.network_type(BlockChainType::EVM)
+			.max_logs_per_tx(100)
+			.build();
+		assert!(network.validate().is_ok());
+		assert_eq!(network.get_max_logs_per_tx(), 100);
+		assert_eq!(
+			NetworkBuilder::new().build().get_max_logs_per_tx(),
+			DEFAULT_MAX_LOGS_PER_TX
+		);
+
+		let network = NetworkBuilder::new()
+			.network_type(BlockChainType::EVM)
+			.max_logs_per_tx(0)
+			.build();
+		assert!(network
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("max_logs_per_tx must be greater than 0"));
+
+		let network = NetworkBuilder::new()
+			.network_type(BlockChainType::Stellar)
+			.network_passphrase("Test SDF Network ; September 2015")
+			.max_logs_per_tx(100)
+			.build();
+		assert!(network
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("max_logs_per_tx is only supported on EVM networks"));
+	}
+
+	#[test]
+	fn test_validate_pool_idle_timeout_ms() {
+		let network = NetworkBuilder::new()
+			.pool_idle_timeout_ms(30000)
+			.pool_max_idle_per_host(0)
+			.http2_prior_knowledge(true)
+			.build();
+		assert!(network.validate().is_ok());
+
+		let network = NetworkBuilder::new().pool_idle_timeout_ms(0).build();
+		assert!(network
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("pool_idle_timeout_ms must be greater than 0"));
+	}
+
+	#[test]
+	fn test_validate_rpc_method_aliases() {
+		let network = NetworkBuilder::new()
+			.rpc_method_alias("eth_getBlockByNumber", "eth_getBlockByNumberV2")
+			.build();
+		assert!(network.validate().is_ok());
+
+		let network = NetworkBuilder::new()
+			.rpc_method_alias("eth_getBlockByNumber", "")
+			.build();
+		assert!(network
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("rpc_method_aliases cannot contain empty method names"));
+	}
+
+	#[test]
+	fn test_validate_ens_registry() {
+		let network = NetworkBuilder::new()
+			.network_type(BlockChainType::EVM)
+			.ens_registry("0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e")
+			.build();
+		assert!(network.validate().is_ok());
+
+		let network = NetworkBuilder::new()
+			.network_type(BlockChainType::EVM)
+			.ens_registry("registry.eth")
+			.build();
+		assert!(network
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("Invalid ens_registry address"));
+
+		let network = NetworkBuilder::new()
+			.network_type(BlockChainType::Stellar)
+			.network_passphrase("Test SDF Network ; September 2015")
+			.ens_registry("0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e")
+			.build();
+		assert!(network
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("ens_registry is only supported on EVM networks"));
+	}
+
 	#[test]
 	fn test_validate_zero_max_past_blocks() {
 		let network = NetworkBuilder::new().max_past_blocks(0).build();
@@ -482,6 +1141,36 @@ mod tests {
 		));
 	}
 
+	#[test]
+	fn test_validate_zero_max_past_blocks_limit() {
+		let network = NetworkBuilder::new().max_past_blocks_limit(0).build();
+		assert!(network
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("max_past_blocks_limit must be greater than 0"));
+	}
+
+	#[test]
+	fn test_get_max_past_blocks() {
+		// Configured values within the limit are used as-is
+		let network = NetworkBuilder::new().max_past_blocks(50).build();
+		assert_eq!(network.get_max_past_blocks(), 50);
+
+		// Configured values above the limit are capped
+		let network = NetworkBuilder::new()
+			.max_past_blocks(DEFAULT_MAX_PAST_BLOCKS_LIMIT * 100)
+			.build();
+		assert_eq!(network.get_max_past_blocks(), DEFAULT_MAX_PAST_BLOCKS_LIMIT);
+
+		let network = NetworkBuilder::new()
+			.max_past_blocks(50)
+			.max_past_blocks_limit(20)
+			.build();
+		assert_eq!(network.get_max_past_blocks(), 20);
+		assert_eq!(network.get_max_past_blocks_limit(), 20);
+	}
+
 	#[test]
 	fn test_validate_empty_cron_schedule() {
 		let network = NetworkBuilder::new().cron_schedule("").build();
@@ -595,6 +1284,100 @@ mod tests {
 		assert!(!logs_contain("wss://secure.ws.network"));
 	}
 
+	#[test]
+	#[traced_test]
+	fn test_apply_default_rpc_urls_known_passphrases() {
+		for (passphrase, expected_url) in [
+			(
+				"Public Global Stellar Network ; September 2015",
+				"https://mainnet.sorobanrpc.com",
+			),
+			(
+				"Test SDF Network ; September 2015",
+				"https://soroban-testnet.stellar.org",
+			),
+		] {
+			let mut network = NetworkBuilder::new()
+				.slug("stellar_network")
+				.network_type(BlockChainType::Stellar)
+				.network_passphrase(passphrase)
+				.clear_rpc_urls()
+				.build();
+
+			network.apply_default_rpc_urls();
+			assert_eq!(network.rpc_urls.len(), 1);
+			assert_eq!(network.rpc_urls[0].type_, "rpc");
+			assert_eq!(network.rpc_urls[0].url.as_str(), expected_url);
+			assert_eq!(network.rpc_urls[0].weight, 100);
+			assert!(network.validate().is_ok());
+		}
+		assert!(logs_contain(
+			"Network 'stellar_network' configures no RPC URL, defaulting to the public endpoint"
+		));
+	}
+
+	#[test]
+	fn test_apply_default_rpc_urls_untouched() {
+		// Explicit RPC URLs are kept
+		let mut network = NetworkBuilder::new()
+			.network_type(BlockChainType::Stellar)
+			.network_passphrase("Test SDF Network ; September 2015")
+			.rpc_url("https://stellar.custom.network")
+			.build();
+		network.apply_default_rpc_urls();
+		assert_eq!(network.rpc_urls.len(), 1);
+		assert_eq!(
+			network.rpc_urls[0].url.as_str(),
+			"https://stellar.custom.network"
+		);
+
+		// Unknown passphrases have no public endpoint
+		let mut network = NetworkBuilder::new()
+			.network_type(BlockChainType::Stellar)
+			.network_passphrase("Private Stellar Network")
+			.clear_rpc_urls()
+			.build();
+		network.apply_default_rpc_urls();
+		assert!(network.rpc_urls.is_empty());
+
+		// Only Stellar networks are defaulted
+		let mut network = NetworkBuilder::new()
+			.network_type(BlockChainType::EVM)
+			.chain_id(1)
+			.clear_rpc_urls()
+			.build();
+		network.apply_default_rpc_urls();
+		assert!(network.rpc_urls.is_empty());
+	}
+
+	#[tokio::test]
+	async fn test_load_from_path_default_stellar_rpc_url() {
+		let temp_dir = TempDir::new().unwrap();
+		let file_path = temp_dir.path().join("stellar_testnet.json");
+		fs::write(
+			&file_path,
+			r#"{
+				"name": "Stellar Testnet",
+				"slug": "stellar_testnet",
+				"network_type": "Stellar",
+				"network_passphrase": "Test SDF Network ; September 2015",
+				"block_time_ms": 5000,
+				"confirmation_blocks": 1,
+				"cron_schedule": "0 */1 * * * *",
+				"max_past_blocks": 20,
+				"store_blocks": false
+			}"#,
+		)
+		.unwrap();
+
+		let network = Network::load_from_path(&file_path).await.unwrap();
+		assert_eq!(network.rpc_urls.len(), 1);
+		assert_eq!(
+			network.rpc_urls[0].url.as_str(),
+			"https://soroban-testnet.stellar.org"
+		);
+	}
+
 	#[tokio::test]
 	async fn test_load_all_duplicate_network_name() {
 		let temp_dir = TempDir::new().unwrap();
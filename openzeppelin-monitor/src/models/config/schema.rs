@@ -0,0 +1,103 @@
+//! JSON Schema export for configuration files.
+//!
+//! The schemas are generated from the serde models, so they can be used for editor
+//! autocompletion and for validating configuration files in CI.
+
+use schemars::schema_for;
+use std::str::FromStr;
+
+use crate::models::{Monitor, Network, Trigger};
+
+/// Kind of configuration file a schema can be generated for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigSchema {
+	/// Monitor configuration files
+	Monitor,
+	/// Network configuration files
+	Network,
+	/// Trigger configuration files, which map trigger names to triggers
+	Trigger,
+}
+
+impl FromStr for ConfigSchema {
+	type Err = String;
+
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		match value.to_lowercase().as_str() {
+			"monitor" => Ok(Self::Monitor),
+			"network" => Ok(Self::Network),
+			"trigger" => Ok(Self::Trigger),
+			_ => Err(format!(
+				"Unknown configuration kind '{}': expected monitor, network or trigger",
+				value
+			)),
+		}
+	}
+}
+
+impl ConfigSchema {
+	/// Generates the JSON Schema for this kind of configuration file
+	pub fn json_schema(&self) -> serde_json::Value {
+		let schema = match self {
+			Self::Monitor => schema_for!(Monitor),
+			Self::Network => schema_for!(Network),
+			// Trigger files hold several triggers keyed by name
+			Self::Trigger => schema_for!(std::collections::HashMap<String, Trigger>),
+		};
+		schema.to_value()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_config_schema_from_str() {
+		assert_eq!(
+			"monitor".parse::<ConfigSchema>().unwrap(),
+			ConfigSchema::Monitor
+		);
+		assert_eq!(
+			"Network".parse::<ConfigSchema>().unwrap(),
+			ConfigSchema::Network
+		);
+		assert_eq!(
+			"trigger".parse::<ConfigSchema>().unwrap(),
+			ConfigSchema::Trigger
+		);
+		assert!("block".parse::<ConfigSchema>().is_err());
+	}
+
+	#[test]
+	fn test_trigger_schema_contains_trigger_types() {
+		let schema = ConfigSchema::Trigger.json_schema();
+		let trigger_type = &schema["$defs"]["TriggerType"];
+
+		// Documented unit variants are emitted as `oneOf` entries holding a `const`
+		let variants: Vec<&str> = trigger_type["oneOf"]
+			.as_array()
+			.expect("TriggerType should list its variants")
+			.iter()
+			.filter_map(|variant| variant["const"].as_str())
+			.collect();
+		assert_eq!(
+			variants,
+			vec![
+				"slack", "email", "webhook", "telegram", "discord", "script", "kafka", "nats",
+				"grpc"
+			]
+		);
+	}
+
+	#[test]
+	fn test_monitor_and_network_schemas() {
+		let monitor = ConfigSchema::Monitor.json_schema();
+		assert_eq!(monitor["title"], "Monitor");
+		assert!(monitor["properties"]["match_conditions"].is_object());
+
+		let network = ConfigSchema::Network.json_schema();
+		assert_eq!(network["title"], "Network");
+		assert!(network["properties"]["network_passphrase"].is_object());
+	}
+}
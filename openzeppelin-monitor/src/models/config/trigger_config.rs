@@ -6,19 +6,26 @@
 use async_trait::async_trait;
 use email_address::EmailAddress;
 use serde::Deserialize;
-use std::{collections::HashMap, fs, path::Path};
+use std::{collections::HashMap, fs, path::Path, str::FromStr};
+use tonic::metadata::{AsciiMetadataKey, AsciiMetadataValue};
 
 use crate::{
 	models::{
-		config::error::ConfigError, ConfigLoader, SecretValue, Trigger, TriggerType,
-		TriggerTypeConfig,
+		config::{error::ConfigError, validate_labels},
+		ConfigLoader, SecretValue, Trigger, TriggerType, TriggerTypeConfig,
 	},
 	services::trigger::validate_script_config,
-	utils::normalize_string,
+	utils::{normalize_string, timestamp::parse_timezone},
 };
 
 const TELEGRAM_MAX_BODY_LENGTH: usize = 4096;
 const DISCORD_MAX_BODY_LENGTH: usize = 2000;
+const DISCORD_EMBED_MAX_BODY_LENGTH: usize = 4096;
+const DISCORD_EMBED_MAX_FIELDS: usize = 25;
+const KAFKA_MAX_TOPIC_LENGTH: usize = 249;
+
+/// URL schemes supported by the NATS client
+const NATS_URL_SCHEMES: [&str; 4] = ["nats://", "tls://", "ws://", "wss://"];
 
 /// File structure for trigger configuration files
 #[derive(Debug, Deserialize)]
@@ -242,7 +249,142 @@ impl ConfigLoader for Trigger {
 	/// - Required configuration fields for the trigger type are present
 	/// - URLs are valid for webhook and Slack triggers
 	/// - Script paths exist for script triggers
+	/// - Message templates are valid messages for the trigger type
 	fn validate(&self) -> Result<(), ConfigError> {
+		self.validate_settings()?;
+
+		// Validate message templates, each replacing the message of the trigger
+		for (name, template) in &self.templates {
+			let mut trigger = self.clone();
+			trigger.templates.clear();
+			if !trigger.config.set_message(template.clone()) {
+				return Err(ConfigError::validation_error(
+					format!(
+						"Invalid template '{}': trigger type does not support message templates",
+						name
+					),
+					None,
+					None,
+				));
+			}
+			trigger.validate_settings().map_err(|e| {
+				ConfigError::validation_error(
+					format!("Invalid template '{}': {}", name, e),
+					None,
+					None,
+				)
+			})?;
+		}
+
+		// Log a warning if the trigger uses an insecure protocol
+		self.validate_protocol();
+
+		Ok(())
+	}
+
+	/// Validate the safety of the protocols used in the trigger
+	///
+	/// Returns if safe, or logs a warning message if unsafe.
+	fn validate_protocol(&self) {
+		match &self.config {
+			TriggerTypeConfig::Slack { slack_url, .. } => {
+				if !slack_url.starts_with("https://") {
+					tracing::warn!("Slack URL uses an insecure protocol: {}", slack_url);
+				}
+			}
+			TriggerTypeConfig::Discord { discord_url, .. } => {
+				if !discord_url.starts_with("https://") {
+					tracing::warn!("Discord URL uses an insecure protocol: {}", discord_url);
+				}
+			}
+			TriggerTypeConfig::Telegram { .. } => {}
+			TriggerTypeConfig::Kafka { .. } => {}
+			TriggerTypeConfig::Custom { .. } => {}
+			TriggerTypeConfig::Nats { server_url, .. } => {
+				if server_url.starts_with("nats://") || server_url.starts_with("ws://") {
+					tracing::warn!("NATS server URL uses an insecure protocol: {}", server_url);
+				}
+			}
+			TriggerTypeConfig::Grpc { endpoint, .. } => {
+				if !endpoint.starts_with("https://") {
+					tracing::warn!("gRPC endpoint uses an insecure protocol: {}", endpoint);
+				}
+			}
+			TriggerTypeConfig::Script { script_path, .. } => {
+				// Check script file permissions on Unix systems
+				#[cfg(unix)]
+				{
+					use std::os::unix::fs::PermissionsExt;
+					if let Ok(metadata) = std::fs::metadata(script_path) {
+						let permissions = metadata.permissions();
+						let mode = permissions.mode();
+						if mode & 0o022 != 0 {
+							tracing::warn!(
+								"Script file has overly permissive write permissions: {}.The recommended permissions are `644` (`rw-r--r--`)",
+								script_path
+							);
+						}
+					}
+				}
+			}
+			TriggerTypeConfig::Email { port, .. } => {
+				let secure_ports = [993, 587, 465];
+				if let Some(port) = port {
+					if !secure_ports.contains(port) {
+						tracing::warn!("Email port is not using a secure protocol: {}", port);
+					}
+				}
+			}
+			TriggerTypeConfig::Webhook { url, headers, .. } => {
+				if !url.starts_with("https://") {
+					tracing::warn!("Webhook URL uses an insecure protocol: {}", url);
+				}
+				// Check for security headers
+				match headers {
+					Some(headers) => {
+						if !headers.contains_key("X-API-Key")
+							&& !headers.contains_key("Authorization")
+						{
+							tracing::warn!("Webhook lacks authentication headers");
+						}
+					}
+					None => {
+						tracing::warn!("Webhook lacks authentication headers");
+					}
+				}
+			}
+		};
+	}
+
+	fn validate_uniqueness(
+		instances: &[&Self],
+		current_instance: &Self,
+		file_path: &str,
+	) -> Result<(), ConfigError> {
+		// Check trigger name uniqueness before pushing
+		if instances.iter().any(|existing_trigger| {
+			normalize_string(&existing_trigger.name) == normalize_string(&current_instance.name)
+		}) {
+			Err(ConfigError::validation_error(
+				format!("Duplicate trigger name found: '{}'", current_instance.name),
+				None,
+				Some(HashMap::from([
+					(
+						"trigger_name".to_string(),
+						current_instance.name.to_string(),
+					),
+					("path".to_string(), file_path.to_string()),
+				])),
+			))
+		} else {
+			Ok(())
+		}
+	}
+}
+
+impl Trigger {
+	/// Validate the settings of the trigger and the message of its type
+	fn validate_settings(&self) -> Result<(), ConfigError> {
 		// Validate trigger name
 		if self.name.is_empty() {
 			return Err(ConfigError::validation_error(
@@ -252,12 +394,69 @@ impl ConfigLoader for Trigger {
 			));
 		}
 
+		// Validate tags
+		if self.tags.iter().any(|tag| tag.trim().is_empty()) {
+			return Err(ConfigError::validation_error(
+				"Trigger tags cannot be empty",
+				None,
+				None,
+			));
+		}
+
+		// Validate labels
+		validate_labels(&self.labels)?;
+
+		// Validate digest
+		if let Some(digest) = &self.digest {
+			if digest.digest_interval_ms == 0 || digest.max_matches == 0 {
+				return Err(ConfigError::validation_error(
+					"Digest digest_interval_ms and max_matches must be greater than 0",
+					None,
+					None,
+				));
+			}
+		}
+
+		// Validate timezone
+		if let Some(timezone) = &self.timezone {
+			if parse_timezone(timezone).is_none() {
+				return Err(ConfigError::validation_error(
+					format!(
+						"Invalid timezone '{}', expected UTC or an offset such as +02:00",
+						timezone
+					),
+					None,
+					None,
+				));
+			}
+		}
+
+		// Validate notification concurrency limit
+		if self.config.get_max_concurrent_notifications() == Some(0) {
+			return Err(ConfigError::validation_error(
+				"max_concurrent_notifications must be greater than 0",
+				None,
+				None,
+			));
+		}
+
+		// Validate message length limit
+		if self.config.get_max_message_length() == Some(0) {
+			return Err(ConfigError::validation_error(
+				"max_message_length must be greater than 0",
+				None,
+				None,
+			));
+		}
+
 		match &self.trigger_type {
 			TriggerType::Slack => {
 				if let TriggerTypeConfig::Slack {
 					slack_url,
 					message,
 					retry_policy: _,
+					max_concurrent_notifications: _,
+					max_message_length: _,
 				} = &self.config
 				{
 					// Validate webhook URL
@@ -295,6 +494,11 @@ impl ConfigLoader for Trigger {
 					message,
 					sender,
 					recipients,
+					cc,
+					bcc,
+					reply_to,
+					content_type: _,
+					attachments,
 					retry_policy: _,
 				} = &self.config
 				{
@@ -426,6 +630,46 @@ impl ConfigLoader for Trigger {
 							));
 						}
 					}
+
+					// Validate CC and BCC recipients
+					for (field, address) in cc
+						.iter()
+						.map(|address| ("cc", address))
+						.chain(bcc.iter().map(|address| ("bcc", address)))
+					{
+						if !EmailAddress::is_valid(address.as_str()) {
+							return Err(ConfigError::validation_error(
+								format!("Invalid {} email address: {}", field, address),
+								None,
+								None,
+							));
+						}
+					}
+
+					// Validate reply-to
+					if let Some(reply_to) = reply_to {
+						if !EmailAddress::is_valid(reply_to.as_str()) {
+							return Err(ConfigError::validation_error(
+								format!("Invalid reply-to email address: {}", reply_to),
+								None,
+								None,
+							));
+						}
+					}
+
+					// Validate attachments exist
+					for attachment in attachments.iter().flatten() {
+						if !Path::new(attachment).is_file() {
+							return Err(ConfigError::validation_error(
+								format!("Email attachment not found: {}", attachment),
+								None,
+								Some(HashMap::from([(
+									"path".to_string(),
+									attachment.to_string(),
+								)])),
+							));
+						}
+					}
 				}
 			}
 			TriggerType::Webhook => {
@@ -447,7 +691,7 @@ impl ConfigLoader for Trigger {
 					// Validate HTTP method
 					if let Some(method) = method {
 						match method.to_uppercase().as_str() {
-							"GET" | "POST" | "PUT" | "DELETE" => {}
+							"GET" | "POST" | "PUT" | "PATCH" | "DELETE" => {}
 							_ => {
 								return Err(ConfigError::validation_error(
 									"Invalid HTTP method",
@@ -552,6 +796,7 @@ impl ConfigLoader for Trigger {
 				if let TriggerTypeConfig::Discord {
 					discord_url,
 					message,
+					embed,
 					..
 				} = &self.config
 				{
@@ -578,17 +823,42 @@ impl ConfigLoader for Trigger {
 							None,
 						));
 					}
-					// Validate template max length
-					if message.body.len() > DISCORD_MAX_BODY_LENGTH {
+					// Validate template max length, embed descriptions allowing longer bodies
+					let max_body_length = if embed.is_some() {
+						DISCORD_EMBED_MAX_BODY_LENGTH
+					} else {
+						DISCORD_MAX_BODY_LENGTH
+					};
+					if message.body.len() > max_body_length {
 						return Err(ConfigError::validation_error(
 							format!(
 								"Message body should not exceed {} characters",
-								DISCORD_MAX_BODY_LENGTH
+								max_body_length
 							),
 							None,
 							None,
 						));
 					}
+					// Validate embed fields
+					if let Some(embed) = embed {
+						if embed.fields.len() > DISCORD_EMBED_MAX_FIELDS {
+							return Err(ConfigError::validation_error(
+								format!(
+									"Discord embeds cannot have more than {} fields",
+									DISCORD_EMBED_MAX_FIELDS
+								),
+								None,
+								None,
+							));
+						}
+						if embed.fields.iter().any(|field| field.trim().is_empty()) {
+							return Err(ConfigError::validation_error(
+								"Discord embed field names cannot be empty",
+								None,
+								None,
+							));
+						}
+					}
 				}
 			}
 			TriggerType::Script => {
@@ -596,113 +866,258 @@ impl ConfigLoader for Trigger {
 					script_path,
 					language,
 					timeout_ms,
+					script_checksum,
 					..
 				} = &self.config
 				{
-					validate_script_config(script_path, language, timeout_ms)?;
-				}
-			}
-		}
-
-		// Log a warning if the trigger uses an insecure protocol
-		self.validate_protocol();
-
-		Ok(())
-	}
-
-	/// Validate the safety of the protocols used in the trigger
-	///
-	/// Returns if safe, or logs a warning message if unsafe.
-	fn validate_protocol(&self) {
-		match &self.config {
-			TriggerTypeConfig::Slack { slack_url, .. } => {
-				if !slack_url.starts_with("https://") {
-					tracing::warn!("Slack URL uses an insecure protocol: {}", slack_url);
-				}
-			}
-			TriggerTypeConfig::Discord { discord_url, .. } => {
-				if !discord_url.starts_with("https://") {
-					tracing::warn!("Discord URL uses an insecure protocol: {}", discord_url);
+					validate_script_config(
+						script_path,
+						language,
+						timeout_ms,
+						script_checksum.as_deref(),
+					)?;
 				}
 			}
-			TriggerTypeConfig::Telegram { .. } => {}
-			TriggerTypeConfig::Script { script_path, .. } => {
-				// Check script file permissions on Unix systems
-				#[cfg(unix)]
+			TriggerType::Kafka => {
+				if let TriggerTypeConfig::Kafka {
+					brokers,
+					topic,
+					key_template,
+					message,
+					..
+				} = &self.config
 				{
-					use std::os::unix::fs::PermissionsExt;
-					if let Ok(metadata) = std::fs::metadata(script_path) {
-						let permissions = metadata.permissions();
-						let mode = permissions.mode();
-						if mode & 0o022 != 0 {
-							tracing::warn!(
-								"Script file has overly permissive write permissions: {}.The recommended permissions are `644` (`rw-r--r--`)",
-								script_path
-							);
+					// Validate brokers
+					if brokers.is_empty() {
+						return Err(ConfigError::validation_error(
+							"Brokers cannot be empty",
+							None,
+							None,
+						));
+					}
+					for broker in brokers {
+						if broker.trim().is_empty() || broker.chars().any(char::is_whitespace) {
+							return Err(ConfigError::validation_error(
+								format!("Invalid Kafka broker address: '{}'", broker),
+								None,
+								None,
+							));
+						}
+					}
+					// Validate topic name (Kafka allows [a-zA-Z0-9._-], max 249 characters)
+					if topic.is_empty()
+						|| topic.len() > KAFKA_MAX_TOPIC_LENGTH
+						|| !topic
+							.chars()
+							.all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))
+					{
+						return Err(ConfigError::validation_error(
+							format!("Invalid Kafka topic name: '{}'", topic),
+							None,
+							None,
+						));
+					}
+					// Validate key template
+					if let Some(key_template) = key_template {
+						if key_template.trim().is_empty() {
+							return Err(ConfigError::validation_error(
+								"Key template cannot be empty",
+								None,
+								None,
+							));
+						}
+					}
+					// Validate message
+					if let Some(message) = message {
+						if message.title.trim().is_empty() {
+							return Err(ConfigError::validation_error(
+								"Title cannot be empty",
+								None,
+								None,
+							));
+						}
+						if message.body.trim().is_empty() {
+							return Err(ConfigError::validation_error(
+								"Body cannot be empty",
+								None,
+								None,
+							));
 						}
 					}
 				}
 			}
-			TriggerTypeConfig::Email { port, .. } => {
-				let secure_ports = [993, 587, 465];
-				if let Some(port) = port {
-					if !secure_ports.contains(port) {
-						tracing::warn!("Email port is not using a secure protocol: {}", port);
+			TriggerType::Nats => {
+				if let TriggerTypeConfig::Nats {
+					server_url,
+					subject,
+					stream,
+					message,
+					..
+				} = &self.config
+				{
+					// Validate server URL
+					if server_url.trim().is_empty()
+						|| server_url.chars().any(char::is_whitespace)
+						|| (server_url.contains("://")
+							&& !NATS_URL_SCHEMES
+								.iter()
+								.any(|scheme| server_url.starts_with(scheme)))
+					{
+						return Err(ConfigError::validation_error(
+							format!("Invalid NATS server URL: '{}'", server_url),
+							None,
+							None,
+						));
+					}
+					// Validate subject (dot-separated tokens, no wildcards when publishing)
+					if subject.split('.').any(|token| {
+						token.is_empty()
+							|| token == "*" || token == ">"
+							|| token.chars().any(char::is_whitespace)
+					}) {
+						return Err(ConfigError::validation_error(
+							format!("Invalid NATS subject: '{}'", subject),
+							None,
+							None,
+						));
+					}
+					// Validate stream name
+					if let Some(stream) = stream {
+						if stream.is_empty()
+							|| stream.chars().any(|c| {
+								c.is_whitespace() || matches!(c, '.' | '*' | '>' | '/' | '\\')
+							}) {
+							return Err(ConfigError::validation_error(
+								format!("Invalid NATS stream name: '{}'", stream),
+								None,
+								None,
+							));
+						}
+					}
+					// Validate message
+					if let Some(message) = message {
+						if message.title.trim().is_empty() {
+							return Err(ConfigError::validation_error(
+								"Title cannot be empty",
+								None,
+								None,
+							));
+						}
+						if message.body.trim().is_empty() {
+							return Err(ConfigError::validation_error(
+								"Body cannot be empty",
+								None,
+								None,
+							));
+						}
 					}
 				}
 			}
-			TriggerTypeConfig::Webhook { url, headers, .. } => {
-				if !url.starts_with("https://") {
-					tracing::warn!("Webhook URL uses an insecure protocol: {}", url);
-				}
-				// Check for security headers
-				match headers {
-					Some(headers) => {
-						if !headers.contains_key("X-API-Key")
-							&& !headers.contains_key("Authorization")
+			TriggerType::Grpc => {
+				if let TriggerTypeConfig::Grpc {
+					endpoint,
+					metadata,
+					message,
+					timeout_ms,
+					..
+				} = &self.config
+				{
+					// Validate endpoint URL
+					let is_valid_endpoint = url::Url::parse(endpoint).is_ok_and(|url| {
+						matches!(url.scheme(), "http" | "https") && url.has_host()
+					});
+					if !is_valid_endpoint {
+						return Err(ConfigError::validation_error(
+							format!(
+								"Invalid gRPC endpoint: '{}', expected an http:// or https:// URL",
+								endpoint
+							),
+							None,
+							None,
+						));
+					}
+					// Validate metadata
+					for (key, value) in metadata {
+						if AsciiMetadataKey::from_str(key).is_err()
+							|| AsciiMetadataValue::from_str(value).is_err()
 						{
-							tracing::warn!("Webhook lacks authentication headers");
+							return Err(ConfigError::validation_error(
+								format!("Invalid gRPC metadata entry: '{}'", key),
+								None,
+								None,
+							));
 						}
 					}
-					None => {
-						tracing::warn!("Webhook lacks authentication headers");
+					// Validate timeout
+					if *timeout_ms == 0 {
+						return Err(ConfigError::validation_error(
+							"gRPC timeout_ms must be greater than 0",
+							None,
+							None,
+						));
 					}
+					// Validate message
+					if let Some(message) = message {
+						if message.title.trim().is_empty() {
+							return Err(ConfigError::validation_error(
+								"Title cannot be empty",
+								None,
+								None,
+							));
+						}
+						if message.body.trim().is_empty() {
+							return Err(ConfigError::validation_error(
+								"Body cannot be empty",
+								None,
+								None,
+							));
+						}
+					}
+				}
+			}
+			TriggerType::Custom(notifier) => {
+				// Validate notifier name
+				if notifier.trim().is_empty() {
+					return Err(ConfigError::validation_error(
+						"Custom notifier name cannot be empty",
+						None,
+						None,
+					));
+				}
+				let TriggerTypeConfig::Custom { message, .. } = &self.config else {
+					return Err(ConfigError::validation_error(
+						"Custom trigger requires a custom configuration",
+						None,
+						None,
+					));
+				};
+				// Validate message
+				if message.title.trim().is_empty() {
+					return Err(ConfigError::validation_error(
+						"Title cannot be empty",
+						None,
+						None,
+					));
+				}
+				if message.body.trim().is_empty() {
+					return Err(ConfigError::validation_error(
+						"Body cannot be empty",
+						None,
+						None,
+					));
 				}
 			}
-		};
-	}
-
-	fn validate_uniqueness(
-		instances: &[&Self],
-		current_instance: &Self,
-		file_path: &str,
-	) -> Result<(), ConfigError> {
-		// Check trigger name uniqueness before pushing
-		if instances.iter().any(|existing_trigger| {
-			normalize_string(&existing_trigger.name) == normalize_string(&current_instance.name)
-		}) {
-			Err(ConfigError::validation_error(
-				format!("Duplicate trigger name found: '{}'", current_instance.name),
-				None,
-				Some(HashMap::from([
-					(
-						"trigger_name".to_string(),
-						current_instance.name.to_string(),
-					),
-					("path".to_string(), file_path.to_string()),
-				])),
-			))
-		} else {
-			Ok(())
 		}
+
+		Ok(())
 	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::models::NotificationMessage;
 	use crate::models::{core::Trigger, ScriptLanguage, SecretString};
+	use crate::models::{DiscordEmbedConfig, EmailContentType, NotificationMessage};
 	use crate::utils::tests::builders::trigger::TriggerBuilder;
 	use crate::utils::RetryConfig;
 	use std::{fs::File, io::Write, os::unix::fs::PermissionsExt};
@@ -906,6 +1321,93 @@ mod tests {
 		assert!(control_chars_body.validate().is_err());
 	}
 
+	#[test]
+	fn test_email_trigger_cc_bcc_reply_to_validation() {
+		let email_trigger = || {
+			TriggerBuilder::new().name("test_email").email(
+				"smtp.example.com",
+				"user",
+				"pass",
+				"sender@example.com",
+				vec!["recipient@example.com"],
+			)
+		};
+
+		let valid_trigger = email_trigger()
+			.email_cc(vec!["team@example.com"])
+			.email_bcc(vec!["audit@example.com"])
+			.email_reply_to("oncall@example.com")
+			.build();
+		assert!(valid_trigger.validate().is_ok());
+
+		let invalid_cc = email_trigger().email_cc(vec!["not-an-email"]).build();
+		assert!(invalid_cc
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("Invalid cc email address"));
+
+		let invalid_bcc = email_trigger().email_bcc(vec!["@example.com"]).build();
+		assert!(invalid_bcc
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("Invalid bcc email address"));
+
+		let invalid_reply_to = email_trigger().email_reply_to("oncall").build();
+		assert!(invalid_reply_to
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("Invalid reply-to email address"));
+	}
+
+	#[test]
+	fn test_email_trigger_attachment_validation() {
+		let temp_dir = TempDir::new().unwrap();
+		let attachment_path = temp_dir.path().join("report.pdf");
+		File::create(&attachment_path).unwrap();
+
+		let valid_trigger = TriggerBuilder::new()
+			.name("test_email")
+			.email(
+				"smtp.example.com",
+				"user",
+				"pass",
+				"sender@example.com",
+				vec!["recipient@example.com"],
+			)
+			.email_content_type(EmailContentType::Html)
+			.email_attachments(vec![attachment_path.to_str().unwrap()])
+			.build();
+		assert!(valid_trigger.validate().is_ok());
+
+		let missing_attachment = TriggerBuilder::new()
+			.name("test_email")
+			.email(
+				"smtp.example.com",
+				"user",
+				"pass",
+				"sender@example.com",
+				vec!["recipient@example.com"],
+			)
+			.email_attachments(vec!["/non/existent/report.pdf"])
+			.build();
+		let result = missing_attachment.validate();
+		assert!(result.is_err());
+		assert!(result
+			.unwrap_err()
+			.to_string()
+			.contains("Email attachment not found"));
+	}
+
+	#[test]
+	fn test_email_content_type_deserialization() {
+		let content_type: EmailContentType = serde_json::from_str("\"html\"").unwrap();
+		assert_eq!(content_type, EmailContentType::Html);
+		assert_eq!(EmailContentType::default(), EmailContentType::Markdown);
+	}
+
 	#[test]
 	fn test_webhook_trigger_validation() {
 		// Valid trigger
@@ -938,6 +1440,46 @@ mod tests {
 			.message("Alert", "")
 			.build();
 		assert!(invalid_body.validate().is_err());
+
+		// Valid concurrency limit
+		let valid_limit = TriggerBuilder::new()
+			.name("test_webhook")
+			.webhook("https://api.example.com/webhook")
+			.max_concurrent_notifications(1)
+			.build();
+		assert!(valid_limit.validate().is_ok());
+
+		// Zero concurrency limit
+		let zero_limit = TriggerBuilder::new()
+			.name("test_webhook")
+			.webhook("https://api.example.com/webhook")
+			.max_concurrent_notifications(0)
+			.build();
+		assert!(zero_limit.validate().is_err());
+
+		// Zero message length limit
+		let zero_length = TriggerBuilder::new()
+			.name("test_slack")
+			.slack("https://hooks.slack.com/services/xxx")
+			.max_message_length(0)
+			.build();
+		assert!(zero_length.validate().is_err());
+
+		// Templated URL with a REST method
+		let templated_url = TriggerBuilder::new()
+			.name("test_webhook")
+			.webhook("https://api.example.com/monitors/${monitor.name}/alerts")
+			.webhook_method("PATCH")
+			.build();
+		assert!(templated_url.validate().is_ok());
+
+		// Invalid method
+		let invalid_method = TriggerBuilder::new()
+			.name("test_webhook")
+			.webhook("https://api.example.com/webhook")
+			.webhook_method("CONNECT")
+			.build();
+		assert!(invalid_method.validate().is_err());
 	}
 
 	#[test]
@@ -1028,6 +1570,468 @@ mod tests {
 		assert!(invalid_body_message.validate().is_err());
 	}
 
+	#[test]
+	fn test_kafka_trigger_validation() {
+		let valid_trigger = TriggerBuilder::new()
+			.name("test_kafka")
+			.kafka(vec!["localhost:9092"], "monitor.alerts")
+			.kafka_key_template("${monitor.name}")
+			.build();
+		assert!(valid_trigger.validate().is_ok());
+
+		// Valid with message template
+		let valid_message = TriggerBuilder::new()
+			.name("test_kafka")
+			.kafka(vec!["localhost:9092"], "monitor.alerts")
+			.message("Alert", "Value ${value}")
+			.build();
+		assert!(valid_message.validate().is_ok());
+
+		// Empty brokers
+		let empty_brokers = TriggerBuilder::new()
+			.name("test_kafka")
+			.kafka(vec![], "monitor.alerts")
+			.build();
+		assert!(empty_brokers.validate().is_err());
+
+		// Invalid broker
+		let invalid_broker = TriggerBuilder::new()
+			.name("test_kafka")
+			.kafka(vec!["local host:9092"], "monitor.alerts")
+			.build();
+		assert!(invalid_broker.validate().is_err());
+
+		// Invalid topic
+		let invalid_topic = TriggerBuilder::new()
+			.name("test_kafka")
+			.kafka(vec!["localhost:9092"], "monitor/alerts")
+			.build();
+		assert!(invalid_topic.validate().is_err());
+
+		// Topic too long
+		let long_topic = TriggerBuilder::new()
+			.name("test_kafka")
+			.kafka(
+				vec!["localhost:9092"],
+				&"a".repeat(KAFKA_MAX_TOPIC_LENGTH + 1),
+			)
+			.build();
+		assert!(long_topic.validate().is_err());
+
+		// Empty key template
+		let empty_key = TriggerBuilder::new()
+			.name("test_kafka")
+			.kafka(vec!["localhost:9092"], "monitor.alerts")
+			.kafka_key_template(" ")
+			.build();
+		assert!(empty_key.validate().is_err());
+
+		// Empty message body
+		let empty_body = TriggerBuilder::new()
+			.name("test_kafka")
+			.kafka(vec!["localhost:9092"], "monitor.alerts")
+			.message("Alert", "")
+			.build();
+		assert!(empty_body.validate().is_err());
+	}
+
+	#[test]
+	fn test_kafka_trigger_deserialization() {
+		let json = r#"{
+			"name": "kafka_trigger",
+			"trigger_type": "kafka",
+			"config": {
+				"brokers": ["localhost:9092"],
+				"topic": "monitor.alerts",
+				"key_template": "${monitor.name}"
+			}
+		}"#;
+
+		let trigger: Trigger = serde_json::from_str(json).unwrap();
+		assert_eq!(trigger.trigger_type, TriggerType::Kafka);
+		match trigger.config {
+			TriggerTypeConfig::Kafka {
+				brokers,
+				topic,
+				key_template,
+				message,
+				..
+			} => {
+				assert_eq!(brokers, vec!["localhost:9092".to_string()]);
+				assert_eq!(topic, "monitor.alerts");
+				assert_eq!(key_template, Some("${monitor.name}".to_string()));
+				assert!(message.is_none());
+			}
+			_ => panic!("Expected Kafka config"),
+		}
+	}
+
+	#[test]
+	fn test_nats_trigger_validation() {
+		let valid_trigger = TriggerBuilder::new()
+			.name("test_nats")
+			.nats("nats://localhost:4222", "monitor.alerts")
+			.nats_stream("ALERTS")
+			.build();
+		assert!(valid_trigger.validate().is_ok());
+
+		let host_only = TriggerBuilder::new()
+			.name("test_nats")
+			.nats("localhost:4222", "monitor.alerts")
+			.build();
+		assert!(host_only.validate().is_ok());
+
+		let invalid_scheme = TriggerBuilder::new()
+			.name("test_nats")
+			.nats("http://localhost:4222", "monitor.alerts")
+			.build();
+		assert!(invalid_scheme.validate().is_err());
+
+		let empty_url = TriggerBuilder::new()
+			.name("test_nats")
+			.nats("", "monitor.alerts")
+			.build();
+		assert!(empty_url.validate().is_err());
+
+		let wildcard_subject = TriggerBuilder::new()
+			.name("test_nats")
+			.nats("nats://localhost:4222", "monitor.*")
+			.build();
+		assert!(wildcard_subject.validate().is_err());
+
+		let empty_token_subject = TriggerBuilder::new()
+			.name("test_nats")
+			.nats("nats://localhost:4222", "monitor..alerts")
+			.build();
+		assert!(empty_token_subject.validate().is_err());
+
+		let invalid_stream = TriggerBuilder::new()
+			.name("test_nats")
+			.nats("nats://localhost:4222", "monitor.alerts")
+			.nats_stream("alerts.stream")
+			.build();
+		assert!(invalid_stream.validate().is_err());
+
+		let empty_body = TriggerBuilder::new()
+			.name("test_nats")
+			.nats("nats://localhost:4222", "monitor.alerts")
+			.message("Alert", "")
+			.build();
+		assert!(empty_body.validate().is_err());
+	}
+
+	#[test]
+	fn test_nats_trigger_deserialization() {
+		let json = r#"{
+			"name": "nats_trigger",
+			"trigger_type": "nats",
+			"config": {
+				"server_url": "nats://localhost:4222",
+				"subject": "monitor.alerts",
+				"stream": "ALERTS"
+			}
+		}"#;
+
+		let trigger: Trigger = serde_json::from_str(json).unwrap();
+		assert_eq!(trigger.trigger_type, TriggerType::Nats);
+		match trigger.config {
+			TriggerTypeConfig::Nats {
+				server_url,
+				subject,
+				stream,
+				message,
+				..
+			} => {
+				assert_eq!(server_url, "nats://localhost:4222");
+				assert_eq!(subject, "monitor.alerts");
+				assert_eq!(stream, Some("ALERTS".to_string()));
+				assert!(message.is_none());
+			}
+			_ => panic!("Expected Nats config"),
+		}
+	}
+
+	#[test]
+	fn test_grpc_trigger_validation() {
+		let valid_trigger = TriggerBuilder::new()
+			.name("test_grpc")
+			.grpc("https://alerts.example.com:443")
+			.grpc_metadata("authorization", "Bearer token")
+			.message("Alert", "Test message")
+			.build();
+		assert!(valid_trigger.validate().is_ok());
+
+		let insecure_trigger = TriggerBuilder::new()
+			.name("test_grpc")
+			.grpc("http://localhost:50051")
+			.build();
+		assert!(insecure_trigger.validate().is_ok());
+
+		for endpoint in ["localhost:50051", "grpc://localhost:50051", "http://", ""] {
+			let invalid_endpoint = TriggerBuilder::new()
+				.name("test_grpc")
+				.grpc(endpoint)
+				.build();
+			assert!(invalid_endpoint
+				.validate()
+				.unwrap_err()
+				.to_string()
+				.contains("Invalid gRPC endpoint"));
+		}
+
+		let invalid_metadata = TriggerBuilder::new()
+			.name("test_grpc")
+			.grpc("https://alerts.example.com")
+			.grpc_metadata("Invalid Key", "value")
+			.build();
+		assert!(invalid_metadata
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("Invalid gRPC metadata entry"));
+
+		let zero_timeout = TriggerBuilder::new()
+			.name("test_grpc")
+			.grpc("https://alerts.example.com")
+			.grpc_timeout_ms(0)
+			.build();
+		assert!(zero_timeout.validate().is_err());
+
+		let empty_body = TriggerBuilder::new()
+			.name("test_grpc")
+			.grpc("https://alerts.example.com")
+			.message("Alert", "")
+			.build();
+		assert!(empty_body.validate().is_err());
+	}
+
+	#[test]
+	fn test_grpc_trigger_deserialization() {
+		let json = r#"{
+			"name": "grpc_trigger",
+			"trigger_type": "grpc",
+			"config": {
+				"endpoint": "https://alerts.example.com",
+				"metadata": {"authorization": "Bearer token"}
+			}
+		}"#;
+
+		let trigger: Trigger = serde_json::from_str(json).unwrap();
+		assert_eq!(trigger.trigger_type, TriggerType::Grpc);
+		match trigger.config {
+			TriggerTypeConfig::Grpc {
+				endpoint,
+				metadata,
+				message,
+				timeout_ms,
+				..
+			} => {
+				assert_eq!(endpoint, "https://alerts.example.com");
+				assert_eq!(metadata["authorization"], "Bearer token");
+				assert!(message.is_none());
+				assert_eq!(timeout_ms, 5000);
+			}
+			_ => panic!("Expected Grpc config"),
+		}
+	}
+
+	#[test]
+	fn test_custom_trigger_validation() {
+		let valid_trigger = TriggerBuilder::new()
+			.name("test_custom")
+			.custom("pagerduty")
+			.build();
+		assert!(valid_trigger.validate().is_ok());
+
+		let empty_notifier = TriggerBuilder::new()
+			.name("test_custom")
+			.custom(" ")
+			.build();
+		assert!(empty_notifier.validate().is_err());
+
+		let empty_title = TriggerBuilder::new()
+			.name("test_custom")
+			.custom("pagerduty")
+			.message("", "Test message")
+			.build();
+		assert!(empty_title.validate().is_err());
+
+		let mismatched_config = TriggerBuilder::new()
+			.name("test_custom")
+			.nats("nats://localhost:4222", "monitor.alerts")
+			.trigger_type(TriggerType::Custom("pagerduty".to_string()))
+			.build();
+		assert!(mismatched_config.validate().is_err());
+	}
+
+	#[test]
+	fn test_custom_trigger_deserialization() {
+		let json = r#"{
+			"name": "custom_trigger",
+			"trigger_type": {"custom": "pagerduty"},
+			"config": {
+				"message": {"title": "Alert", "body": "Test message"},
+				"settings": {"routing_key": "abc"}
+			}
+		}"#;
+
+		let trigger: Trigger = serde_json::from_str(json).unwrap();
+		assert_eq!(
+			trigger.trigger_type,
+			TriggerType::Custom("pagerduty".to_string())
+		);
+		match trigger.config {
+			TriggerTypeConfig::Custom { message, settings } => {
+				assert_eq!(message.title, "Alert");
+				assert_eq!(settings["routing_key"], "abc");
+			}
+			_ => panic!("Expected Custom config"),
+		}
+	}
+
+	#[test]
+	fn test_trigger_tags() {
+		let json = r#"{
+			"name": "tagged_trigger",
+			"trigger_type": "webhook",
+			"tags": ["critical", "oncall"],
+			"config": {
+				"url": {"type": "plain", "value": "https://api.example.com/webhook"},
+				"method": "POST",
+				"message": {"title": "Alert", "body": "Test message"}
+			}
+		}"#;
+		let trigger: Trigger = serde_json::from_str(json).unwrap();
+		assert_eq!(trigger.tags, vec!["critical", "oncall"]);
+		assert!(trigger.validate().is_ok());
+
+		let invalid_tags = TriggerBuilder::new()
+			.name("test_webhook")
+			.tags(vec!["critical", ""])
+			.build();
+		assert!(invalid_tags
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("Trigger tags cannot be empty"));
+	}
+
+	#[test]
+	fn test_trigger_timezone() {
+		for timezone in ["UTC", "+02:00", "-05:30"] {
+			let trigger = TriggerBuilder::new()
+				.name("test_webhook")
+				.timezone(timezone)
+				.build();
+			assert!(trigger.validate().is_ok());
+		}
+
+		for timezone in ["Europe/Paris", "+25:00", ""] {
+			let trigger = TriggerBuilder::new()
+				.name("test_webhook")
+				.timezone(timezone)
+				.build();
+			assert!(trigger
+				.validate()
+				.unwrap_err()
+				.to_string()
+				.contains("Invalid timezone"));
+		}
+	}
+
+	#[test]
+	fn test_trigger_templates() {
+		let trigger = TriggerBuilder::new()
+			.name("test_webhook")
+			.template(
+				"critical",
+				"Critical alert",
+				"Large transfer of ${events.0.args.value}",
+			)
+			.build();
+		assert!(trigger.validate().is_ok());
+
+		let trigger = TriggerBuilder::new()
+			.name("test_webhook")
+			.template("critical", "Critical alert", " ")
+			.build();
+		let error = trigger.validate().unwrap_err().to_string();
+		assert!(error.contains("Invalid template 'critical'"));
+		assert!(error.contains("Body cannot be empty"));
+
+		let temp_dir = TempDir::new().unwrap();
+		let script_path = temp_dir.path().join("test_script.sh");
+		std::fs::write(&script_path, "#!/bin/bash\necho 'true'").unwrap();
+		let trigger = TriggerBuilder::new()
+			.name("test_script")
+			.script(script_path.to_str().unwrap(), ScriptLanguage::Bash)
+			.template("critical", "Critical alert", "Large transfer")
+			.build();
+		assert!(trigger
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("trigger type does not support message templates"));
+	}
+
+	#[test]
+	fn test_trigger_digest() {
+		let json = r#"{
+			"name": "digest_trigger",
+			"trigger_type": "webhook",
+			"digest": {"digest_interval_ms": 3600000},
+			"config": {
+				"url": {"type": "plain", "value": "https://api.example.com/webhook"},
+				"method": "POST",
+				"message": {"title": "Digest", "body": "${digest.count} matches"}
+			}
+		}"#;
+		let trigger: Trigger = serde_json::from_str(json).unwrap();
+		let digest = trigger.digest.as_ref().unwrap();
+		assert_eq!(digest.digest_interval_ms, 3600000);
+		assert_eq!(digest.max_matches, 10);
+		assert!(trigger.validate().is_ok());
+
+		for (digest_interval_ms, max_matches) in [(0, 10), (1000, 0)] {
+			let invalid_digest = TriggerBuilder::new()
+				.name("test_webhook")
+				.digest(digest_interval_ms, max_matches)
+				.build();
+			assert!(invalid_digest
+				.validate()
+				.unwrap_err()
+				.to_string()
+				.contains("Digest digest_interval_ms and max_matches must be greater than 0"));
+		}
+	}
+
+	#[test]
+	fn test_trigger_labels() {
+		let json = r#"{
+			"name": "labelled_trigger",
+			"trigger_type": "webhook",
+			"labels": {"team": "payments"},
+			"config": {
+				"url": {"type": "plain", "value": "https://api.example.com/webhook"},
+				"method": "POST",
+				"message": {"title": "Alert", "body": "Test message"}
+			}
+		}"#;
+		let trigger: Trigger = serde_json::from_str(json).unwrap();
+		assert_eq!(trigger.labels.get("team"), Some(&"payments".to_string()));
+		assert!(trigger.validate().is_ok());
+
+		let invalid_labels = TriggerBuilder::new()
+			.name("test_webhook")
+			.labels(vec![("status", "active")])
+			.build();
+		assert!(invalid_labels
+			.validate()
+			.unwrap_err()
+			.to_string()
+			.contains("Label name 'status' is reserved"));
+	}
+
 	#[test]
 	fn test_script_trigger_validation() {
 		let temp_dir = std::env::temp_dir();
@@ -1448,7 +2452,14 @@ mod tests {
 					body: "x".repeat(TELEGRAM_MAX_BODY_LENGTH + 1), // Exceeds max length
 				},
 				retry_policy: RetryConfig::default(),
+				max_concurrent_notifications: None,
+				max_message_length: None,
 			},
+			tags: vec![],
+			labels: HashMap::new(),
+			digest: None,
+			timezone: None,
+			templates: HashMap::new(),
 		};
 		assert!(max_body_length.validate().is_err());
 	}
@@ -1467,11 +2478,50 @@ mod tests {
 					body: "z".repeat(DISCORD_MAX_BODY_LENGTH + 1), // Exceeds max length
 				},
 				retry_policy: RetryConfig::default(),
+				max_concurrent_notifications: None,
+				max_message_length: None,
+				embed: None,
 			},
+			tags: vec![],
+			labels: HashMap::new(),
+			digest: None,
+			timezone: None,
+			templates: HashMap::new(),
 		};
 		assert!(max_body_length.validate().is_err());
 	}
 
+	#[test]
+	fn test_discord_embed_validation() {
+		let embed_trigger = |fields: Vec<String>| {
+			TriggerBuilder::new()
+				.name("test_discord")
+				.discord("https://discord.com/api/webhooks/xxx")
+				.message("Alert", &"z".repeat(DISCORD_MAX_BODY_LENGTH + 1))
+				.discord_embed(DiscordEmbedConfig {
+					fields,
+					..Default::default()
+				})
+				.build()
+		};
+
+		// Embed descriptions allow bodies longer than plain content
+		assert!(embed_trigger(vec!["transaction.hash".to_string()])
+			.validate()
+			.is_ok());
+
+		// Too many fields
+		let too_many_fields = embed_trigger(
+			(0..=DISCORD_EMBED_MAX_FIELDS)
+				.map(|i| i.to_string())
+				.collect(),
+		);
+		assert!(too_many_fields.validate().is_err());
+
+		// Empty field name
+		assert!(embed_trigger(vec![" ".to_string()]).validate().is_err());
+	}
+
 	#[tokio::test]
 	async fn test_load_all_duplicate_trigger_name() {
 		let temp_dir = TempDir::new().unwrap();
@@ -10,8 +10,17 @@ mod network;
 mod trigger;
 
 pub use monitor::{
-	AddressWithSpec, EventCondition, FunctionCondition, MatchConditions, Monitor, ScriptLanguage,
-	TransactionCondition, TransactionStatus, TriggerConditions,
+	AddressWithSpec, AggregateCondition, EnrichmentCall, EnrichmentConfig, EventCondition,
+	EventField, FunctionCondition, MatchConditionType, MatchConditions, MatchDedupConfig, Monitor,
+	MonitorTrigger, NotificationThrottleConfig, OperationCondition, PercentageChangeCondition,
+	ScriptLanguage, SeverityRule, ThrottleOverflow, TransactionCondition, TransactionStatus,
+	TriggerConditions, TriggerExecutionConfig,
+};
+pub use network::{
+	EmitMatchesAfter, Finality, Network, RpcUrl, DEFAULT_MAX_LOGS_PER_TX,
+	DEFAULT_MAX_PAST_BLOCKS_LIMIT,
+};
+pub use trigger::{
+	DigestConfig, DiscordEmbedConfig, DiscordEmbedSeverity, EmailContentType, NotificationMessage,
+	Trigger, TriggerType, TriggerTypeConfig, WebhookPayloadFields,
 };
-pub use network::{Network, RpcUrl};
-pub use trigger::{NotificationMessage, Trigger, TriggerType, TriggerTypeConfig};
@@ -1,6 +1,13 @@
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
 
-use crate::models::blockchain::ContractSpec;
+use crate::{
+	models::{blockchain::ContractSpec, EVMContractSpec},
+	utils::RetryConfig,
+};
 
 /// Configuration for monitoring specific blockchain activity.
 ///
@@ -11,7 +18,7 @@ use crate::models::blockchain::ContractSpec;
 /// - Triggers conditions refers to a custom filter script that being executed apply extra filters
 ///   to the matched transactions before triggering the notifications
 /// - Triggers to execute when conditions are met
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Monitor {
 	/// Unique name identifying this monitor
@@ -23,6 +30,10 @@ pub struct Monitor {
 	/// Whether this monitor is currently paused
 	pub paused: bool,
 
+	/// Optional RFC3339 timestamp until which this monitor is paused
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub paused_until: Option<String>,
+
 	/// Contract addresses to monitor, optionally with their contract specs
 	pub addresses: Vec<AddressWithSpec>,
 
@@ -32,23 +43,389 @@ pub struct Monitor {
 	/// Conditions that should be met prior to triggering notifications
 	pub trigger_conditions: Vec<TriggerConditions>,
 
-	/// IDs of triggers to execute when conditions match
-	pub triggers: Vec<String>,
+	/// Triggers to execute when conditions match, given by ID or with the kinds of matched
+	/// conditions they are executed for
+	pub triggers: Vec<MonitorTrigger>,
+
+	/// Tags selecting additional triggers to execute; resolved into `triggers` at load time
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub trigger_tags: Vec<String>,
+
+	/// Optional settings controlling how triggers are executed
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub trigger_execution: Option<TriggerExecutionConfig>,
+
+	/// Optional cap on the number of notifications sent for this monitor within a time window
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub notification_throttle: Option<NotificationThrottleConfig>,
+
+	/// Optional retry policy of this monitor's notifications, taking precedence over the
+	/// `retry_policy` of its triggers
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub notification_retry: Option<RetryConfig>,
+
+	/// Optional suppression of matches identical to the ones notified in recent blocks
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub match_dedup: Option<MatchDedupConfig>,
+
+	/// Optional read-only contract calls whose results are exposed to notification templates
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub enrichment: Option<EnrichmentConfig>,
+
+	/// Arbitrary key/value labels exposed to notification templates and metrics
+	#[serde(default, skip_serializing_if = "HashMap::is_empty")]
+	pub labels: HashMap<String, String>,
+
+	/// Optional fraction (0.0 to 1.0) of transactions evaluated by this monitor. Transactions
+	/// are sampled deterministically by their hash
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub sample_rate: Option<f64>,
+
+	/// Optional window, in seconds, within which this monitor is expected to match. A warning
+	/// is logged while the monitor has not matched for longer
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub expected_match_window_secs: Option<u64>,
+
+	/// Optional rules computing the severity of each match, exposed to notification templates
+	/// as `severity` and selecting the matching message template of the triggers
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub severity_rules: Vec<SeverityRule>,
+}
+
+impl Monitor {
+	/// Checks whether the monitor is paused at the given time
+	///
+	/// A monitor is paused if the `paused` flag is set or if `paused_until` is later than `now`.
+	/// An unparseable `paused_until` is ignored, as it is rejected during config validation.
+	///
+	/// # Arguments
+	/// * `now` - The time to check against
+	///
+	/// # Returns
+	/// `true` if the monitor is paused, `false` otherwise
+	pub fn is_paused_at(&self, now: DateTime<Utc>) -> bool {
+		self.paused
+			|| self
+				.paused_until
+				.as_deref()
+				.and_then(|until| DateTime::parse_from_rfc3339(until).ok())
+				.is_some_and(|until| until > now)
+	}
+
+	/// Checks whether the monitor is currently paused
+	///
+	/// # Returns
+	/// `true` if the monitor is paused, `false` otherwise
+	pub fn is_paused(&self) -> bool {
+		self.is_paused_at(Utc::now())
+	}
+
+	/// Checks whether a transaction is part of the sample evaluated by the monitor
+	///
+	/// The transaction hash is hashed with SHA-256 and mapped to `[0, 1)`, so that the same
+	/// transactions are sampled on every run. All transactions are sampled when no
+	/// `sample_rate` is set.
+	///
+	/// # Arguments
+	/// * `transaction_hash` - Hash of the transaction
+	///
+	/// # Returns
+	/// `true` if the transaction should be evaluated, `false` otherwise
+	pub fn samples_transaction(&self, transaction_hash: &str) -> bool {
+		let Some(sample_rate) = self.sample_rate else {
+			return true;
+		};
+
+		let digest = Sha256::digest(transaction_hash.to_lowercase().as_bytes());
+		let mut bytes = [0u8; 8];
+		bytes.copy_from_slice(&digest[..8]);
+		// The top 53 bits are exactly representable as an f64 fraction
+		let position = (u64::from_be_bytes(bytes) >> 11) as f64 / (1u64 << 53) as f64;
+		position < sample_rate
+	}
+}
+
+/// Settings controlling how a monitor's triggers are executed
+///
+/// By default all triggers run concurrently. `trigger_concurrency` bounds the number of triggers
+/// running at the same time. When `ordered` is set, triggers run one at a time in the order they
+/// are declared.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TriggerExecutionConfig {
+	/// Whether triggers are executed sequentially in declared order
+	#[serde(default)]
+	pub ordered: bool,
+
+	/// Whether remaining triggers still run after one fails (only applies when ordered)
+	#[serde(default)]
+	pub continue_on_error: bool,
+
+	/// Maximum number of triggers running at the same time (only applies when not ordered)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub trigger_concurrency: Option<usize>,
+}
+
+/// Cap on the number of notifications sent for a monitor within a time window
+///
+/// Notifications are limited with a token bucket holding `max_notifications_per_window` tokens,
+/// refilled over `window_ms`. Each match sending notifications consumes one token, regardless
+/// of the number of triggers it executes. This bounds the alert volume during storms of
+/// distinct matches.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct NotificationThrottleConfig {
+	/// Maximum number of matches notified within a window
+	pub max_notifications_per_window: u32,
+
+	/// Length of the window in milliseconds
+	pub window_ms: u64,
+
+	/// What happens to the matches exceeding the limit
+	#[serde(default)]
+	pub overflow: ThrottleOverflow,
+}
+
+/// Handling of the matches exceeding a monitor's notification throttle
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ThrottleOverflow {
+	/// Throttled matches are dropped
+	#[default]
+	Drop,
+	/// Throttled matches are dropped and their count is reported by the next notification
+	/// sent for the monitor, through the `throttle.suppressed_count` variable
+	Summary,
+}
+
+/// Suppression of matches identical to the ones notified in recent blocks
+///
+/// Matches are identical when they have the same content key (network, monitor, matched
+/// conditions and decoded arguments), regardless of their transaction. A match is suppressed
+/// when an identical match was notified in another block within the last `window_blocks`
+/// blocks, so that an event emitted every block is notified once per window. Unlike the
+/// time-based `notification_throttle`, distinct matches are never suppressed.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MatchDedupConfig {
+	/// Number of blocks during which identical matches are suppressed
+	pub window_blocks: u64,
+}
+
+/// Rule assigning a severity to the matches of a monitor
+///
+/// Rules are checked in order and the first one applying to a matched function or event sets
+/// the severity of the match. A rule applies when the signature, if any, equals the signature
+/// of the matched function or event and the expression, if any, evaluates to true against its
+/// arguments. A rule without signature nor expression applies to every match.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SeverityRule {
+	/// Severity assigned to the matches the rule applies to, e.g. `critical`
+	pub severity: String,
+
+	/// Optional signature of the matched function or event
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub signature: Option<String>,
+
+	/// Optional expression evaluated against the arguments of the matched function or event
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub expression: Option<String>,
+}
+
+/// Default maximum time in milliseconds of an enrichment call
+const fn default_enrichment_timeout_ms() -> u64 {
+	5000
+}
+
+/// Read-only contract calls performed for each match before notifying
+///
+/// The decoded result of each call is available to notification templates as
+/// `enrichment.<name>`. Results are cached per network, contract and function, so the calls are
+/// meant for values that do not change, such as token metadata.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EnrichmentConfig {
+	/// Calls to perform
+	pub calls: Vec<EnrichmentCall>,
+
+	/// Maximum time in milliseconds of a call, after which its variable is left unset
+	/// (default 5000)
+	#[serde(default = "default_enrichment_timeout_ms")]
+	pub timeout_ms: u64,
+}
+
+/// Read-only call of a contract function taking no arguments
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EnrichmentCall {
+	/// Name of the template variable holding the result, as `enrichment.<name>`
+	pub name: String,
+
+	/// Function to call, given by its signature on EVM (e.g. `symbol()`) and by its name on
+	/// Stellar (e.g. `symbol`)
+	pub function: String,
+
+	/// Solidity type returned by the function, used to decode the result (EVM only)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub output: Option<String>,
+
+	/// Contract to call, defaulting to the first address of the monitor
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub address: Option<String>,
+
+	/// Number of seconds the result is reused by later matches (not cached by default)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub cache_ttl_secs: Option<u64>,
+}
+
+/// Kind of match condition that a trigger can be restricted to
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum MatchConditionType {
+	/// Function call conditions
+	Functions,
+	/// Event conditions
+	Events,
+	/// Transaction conditions
+	Transactions,
+	/// Operation conditions (Stellar only)
+	Operations,
+}
+
+/// Trigger executed by a monitor
+///
+/// Given either by its ID, in which case it is executed for every match, or as an object with
+/// a `when` list restricting it to matches on the listed kinds of conditions.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+#[serde(untagged)]
+pub enum MonitorTrigger {
+	/// Trigger executed for every match
+	Name(String),
+	/// Trigger executed only for matches on the listed kinds of conditions
+	Conditional {
+		/// ID of the trigger
+		name: String,
+		/// Kinds of matched conditions the trigger is executed for
+		when: Vec<MatchConditionType>,
+	},
+}
+
+impl MonitorTrigger {
+	/// Returns the ID of the trigger
+	pub fn name(&self) -> &str {
+		match self {
+			Self::Name(name) | Self::Conditional { name, .. } => name,
+		}
+	}
+
+	/// Checks whether the trigger is executed for a match
+	///
+	/// A trigger without a `when` list is executed for every match. Otherwise, at least one of
+	/// the listed kinds of conditions must have matched.
+	///
+	/// # Arguments
+	/// * `matched_on` - The conditions the match was found on
+	///
+	/// # Returns
+	/// `true` if the trigger should be executed, `false` otherwise
+	pub fn applies_to(&self, matched_on: &MatchConditions) -> bool {
+		let Self::Conditional { when, .. } = self else {
+			return true;
+		};
+
+		when.iter().any(|condition_type| match condition_type {
+			MatchConditionType::Functions => !matched_on.functions.is_empty(),
+			MatchConditionType::Events => !matched_on.events.is_empty(),
+			MatchConditionType::Transactions => !matched_on.transactions.is_empty(),
+			MatchConditionType::Operations => !matched_on.operations.is_empty(),
+		})
+	}
+}
+
+impl From<String> for MonitorTrigger {
+	fn from(name: String) -> Self {
+		Self::Name(name)
+	}
+}
+
+impl From<&str> for MonitorTrigger {
+	fn from(name: &str) -> Self {
+		Self::Name(name.to_string())
+	}
+}
+
+impl PartialEq<str> for MonitorTrigger {
+	fn eq(&self, other: &str) -> bool {
+		self.name() == other
+	}
+}
+
+impl PartialEq<&str> for MonitorTrigger {
+	fn eq(&self, other: &&str) -> bool {
+		self.name() == *other
+	}
 }
 
 /// Contract address with optional ABI for decoding transactions and events
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct AddressWithSpec {
 	/// Contract address in the network's native format
 	pub address: String,
 
 	/// Optional contract spec for decoding contract interactions
+	#[schemars(with = "Option<serde_json::Value>")]
 	pub contract_spec: Option<ContractSpec>,
+
+	/// Optional path to a file containing the contract spec, resolved relative to the
+	/// monitor config file and loaded into `contract_spec` during config loading
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub contract_spec_path: Option<String>,
+
+	/// Additional contract specs for the same address, e.g. the implementation ABI of a
+	/// proxy contract. Items of `contract_spec` take precedence, followed by these specs
+	/// in order
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	#[schemars(with = "Vec<serde_json::Value>")]
+	pub additional_specs: Vec<ContractSpec>,
+
+	/// Human-readable event signatures indexed by their topic0 hash (EVM only), e.g.
+	/// `"0xddf2...b3ef": "Transfer(address,address,uint256)"`. Labels the logs that cannot
+	/// be decoded with the contract spec, whose arguments are then exposed as raw hex
+	#[serde(default, skip_serializing_if = "HashMap::is_empty")]
+	pub event_signatures: HashMap<String, String>,
+}
+
+impl AddressWithSpec {
+	/// Returns the contract spec of the address merged with its additional specs
+	///
+	/// Additional specs are only merged for EVM, where functions, events and errors of
+	/// `contract_spec` take precedence over those of the additional specs, in order.
+	///
+	/// # Returns
+	/// The merged contract spec, or `None` if the address has no spec
+	pub fn merged_contract_spec(&self) -> Option<ContractSpec> {
+		if self.additional_specs.is_empty() {
+			return self.contract_spec.clone();
+		}
+
+		let mut merged = match &self.contract_spec {
+			Some(ContractSpec::EVM(spec)) => spec.clone(),
+			Some(spec) => return Some(spec.clone()),
+			None => EVMContractSpec::default(),
+		};
+		for spec in &self.additional_specs {
+			if let ContractSpec::EVM(spec) = spec {
+				merged.merge(spec);
+			}
+		}
+		Some(ContractSpec::EVM(merged))
+	}
 }
 
 /// Collection of conditions that can trigger a monitor
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct MatchConditions {
 	/// Function calls to match
@@ -59,10 +436,22 @@ pub struct MatchConditions {
 
 	/// Transaction states to match
 	pub transactions: Vec<TransactionCondition>,
+
+	/// Operations to match by type (Stellar only, e.g. "payment" or "change_trust")
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub operations: Vec<OperationCondition>,
+
+	/// Totals of an event parameter across a transaction to match (EVM only)
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub aggregates: Vec<AggregateCondition>,
+
+	/// Percentage changes between two event parameters in a block to match (EVM only)
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub percentage_changes: Vec<PercentageChangeCondition>,
 }
 
 /// Condition for matching contract function calls
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct FunctionCondition {
 	/// Function signature (e.g., "transfer(address,uint256)")
@@ -73,7 +462,7 @@ pub struct FunctionCondition {
 }
 
 /// Condition for matching contract events
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct EventCondition {
 	/// Event signature (e.g., "Transfer(address,address,uint256)")
@@ -83,8 +472,76 @@ pub struct EventCondition {
 	pub expression: Option<String>,
 }
 
+/// Condition for matching the total of an event parameter across a transaction
+///
+/// Sums `field` over every event in the transaction that matches `signature` (and `filter`,
+/// if set), then evaluates `expression` against the totals, which are exposed as `sum`
+/// and `count`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AggregateCondition {
+	/// Event signature to aggregate (e.g., "Transfer(address,address,uint256)")
+	pub signature: String,
+
+	/// Unsigned integer event parameter to sum (e.g., "value")
+	pub field: String,
+
+	/// Optional expression selecting which events are included (e.g., "to == 0x...")
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub filter: Option<String>,
+
+	/// Expression evaluated against the totals (e.g., "sum > 1000000")
+	pub expression: String,
+}
+
+/// Condition for matching the percentage change between two event parameters in a block
+///
+/// Compares `field` of the first event in the block matching `from` with `field` of the
+/// last event matching `to` that follows it, then evaluates `expression` against the
+/// change, which is exposed as `change` (signed, in percent), `abs_change`, `first` and
+/// `second`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PercentageChangeCondition {
+	/// Event parameter holding the reference value
+	pub from: EventField,
+
+	/// Event parameter holding the changed value
+	pub to: EventField,
+
+	/// Expression evaluated against the change (e.g., "abs_change > 5")
+	pub expression: String,
+}
+
+/// Numeric event parameter referenced by a percentage change condition
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EventField {
+	/// Event signature (e.g., "Sync(uint112,uint112)")
+	pub signature: String,
+
+	/// Numeric event parameter (e.g., "reserve0")
+	pub field: String,
+
+	/// Optional expression selecting which events are considered (e.g., "reserve1 > 0")
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub filter: Option<String>,
+}
+
+/// Condition for matching transaction operations by type
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct OperationCondition {
+	/// Operation type in snake_case (e.g., "payment", "invoke_host_function")
+	#[serde(rename = "type")]
+	pub operation_type: String,
+
+	/// Optional expression to filter operation fields (e.g., "amount > 1000")
+	pub expression: Option<String>,
+}
+
 /// Condition for matching transaction states
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct TransactionCondition {
 	/// Required transaction status
@@ -95,7 +552,7 @@ pub struct TransactionCondition {
 }
 
 /// Possible transaction execution states
-#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub enum TransactionStatus {
 	/// Match any transaction status
@@ -107,10 +564,10 @@ pub enum TransactionStatus {
 }
 
 /// Conditions that should be met prior to triggering notifications
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct TriggerConditions {
-	/// The path to the script
+	/// The path to the script, or http(s) URL the script is fetched from at startup
 	pub script_path: String,
 
 	/// The arguments of the script
@@ -122,9 +579,17 @@ pub struct TriggerConditions {
 
 	/// The timeout of the script
 	pub timeout_ms: u32,
+
+	/// The SHA-256 checksum of the script, required for scripts fetched from a URL
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub script_checksum: Option<String>,
+
+	/// The retry policy of transient script failures, the script is not retried when unset
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub retry_policy: Option<RetryConfig>,
 }
 /// The possible languages of the script
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Hash, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Hash, Eq, JsonSchema)]
 pub enum ScriptLanguage {
 	JavaScript,
 	Python,
@@ -1,12 +1,41 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{collections::HashMap, fmt};
+use stellar_strkey::{ed25519::PublicKey as StrkeyPublicKey, Contract};
+use stellar_xdr::curr::{
+	Asset, ContractIdPreimage, Hash, HashIdPreimage, HashIdPreimageContractId, Limits, WriteXdr,
+};
 
 use crate::models::{BlockChainType, SecretValue};
 
+/// Network passphrase of the Stellar public network
+pub const STELLAR_MAINNET_PASSPHRASE: &str = "Public Global Stellar Network ; September 2015";
+
+/// Network passphrase of the Stellar test network
+pub const STELLAR_TESTNET_PASSPHRASE: &str = "Test SDF Network ; September 2015";
+
+/// Network passphrase of the Stellar future network
+pub const STELLAR_FUTURENET_PASSPHRASE: &str = "Test SDF Future Network ; October 2022";
+
+/// Default upper bound of the past blocks processed when catching up
+pub const DEFAULT_MAX_PAST_BLOCKS_LIMIT: u64 = 10_000;
+
+/// Default maximum number of logs of a single transaction matched against the monitors
+pub const DEFAULT_MAX_LOGS_PER_TX: usize = 10_000;
+
+/// Well-known Stellar networks and their passphrases
+const KNOWN_STELLAR_NETWORKS: [(&str, &str); 3] = [
+	("mainnet", STELLAR_MAINNET_PASSPHRASE),
+	("testnet", STELLAR_TESTNET_PASSPHRASE),
+	("futurenet", STELLAR_FUTURENET_PASSPHRASE),
+];
+
 /// Configuration for connecting to and interacting with a blockchain network.
 ///
 /// Defines connection details and operational parameters for a specific blockchain network,
 /// supporting both EVM and Stellar-based chains.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Network {
 	/// Type of blockchain (EVM, Stellar, etc)
@@ -19,6 +48,9 @@ pub struct Network {
 	pub name: String,
 
 	/// List of RPC endpoints with their weights for load balancing
+	///
+	/// Stellar networks with a well-known passphrase default to its public RPC endpoint
+	#[serde(default)]
 	pub rpc_urls: Vec<RpcUrl>,
 
 	/// Chain ID for EVM networks
@@ -33,18 +65,138 @@ pub struct Network {
 	/// Number of blocks needed for confirmation
 	pub confirmation_blocks: u64,
 
+	/// How the last block safe to process is determined (EVM only, defaults to
+	/// `confirmation_blocks` confirmations)
+	pub finality: Option<Finality>,
+
 	/// Cron expression for how often to check for new blocks
 	pub cron_schedule: String,
 
 	/// Maximum number of past blocks to process
 	pub max_past_blocks: Option<u64>,
 
+	/// Upper bound of the past blocks processed when catching up, which caps `max_past_blocks`
+	/// (defaults to `DEFAULT_MAX_PAST_BLOCKS_LIMIT`)
+	pub max_past_blocks_limit: Option<u64>,
+
 	/// Whether to store processed blocks
 	pub store_blocks: Option<bool>,
+
+	/// Maximum number of blocks fetched concurrently when catching up on past blocks
+	pub backfill_concurrency: Option<usize>,
+
+	/// Maximum time in milliseconds to process a single block before it is cancelled
+	pub max_block_processing_ms: Option<u64>,
+
+	/// Number of times a block is processed again after timing out before it is skipped
+	pub block_processing_retries: Option<u32>,
+
+	/// Whether to decode the revert reason of failed transactions (EVM only)
+	pub decode_revert_reasons: Option<bool>,
+
+	/// Whether mixed-case monitored addresses must carry a valid EIP-55 checksum (EVM only)
+	pub strict_address_checksum: Option<bool>,
+
+	/// Maximum number of transactions of a block fetched and processed at once (EVM only)
+	pub tx_chunk_size: Option<usize>,
+
+	/// Maximum number of logs of a single transaction matched against the monitors, the
+	/// remaining logs being skipped (EVM only, defaults to `DEFAULT_MAX_LOGS_PER_TX`)
+	pub max_logs_per_tx: Option<usize>,
+
+	/// Address of the ENS registry used to resolve ENS names in monitor addresses (EVM only)
+	pub ens_registry: Option<String>,
+
+	/// Whether to connect to the HTTP RPC endpoints with HTTP/2 without negotiating it first
+	pub http2_prior_knowledge: Option<bool>,
+
+	/// Time in milliseconds an idle connection to an HTTP RPC endpoint is kept alive for reuse
+	pub pool_idle_timeout_ms: Option<u64>,
+
+	/// Maximum number of idle connections kept alive per HTTP RPC endpoint host
+	pub pool_max_idle_per_host: Option<usize>,
+
+	/// JSON-RPC method names sent to the RPC endpoints instead of the standard ones, keyed by
+	/// standard method name
+	pub rpc_method_aliases: Option<HashMap<String, String>>,
+
+	/// Block or timestamp before which matches are processed without firing triggers
+	pub emit_matches_after: Option<EmitMatchesAfter>,
+}
+
+/// How the last block safe to process is determined on an EVM network
+///
+/// The `finalized` and `safe` block tags give actual finality on proof-of-stake chains. Networks
+/// whose RPC endpoints do not support the tags fall back to `confirmation_blocks` confirmations.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Finality {
+	/// Latest block tagged `finalized` by the network
+	Finalized,
+	/// Latest block tagged `safe` by the network
+	Safe,
+	/// Latest block, without waiting for confirmations
+	Latest,
+	/// Latest block with the given number of blocks on top of it
+	Confirmations(u64),
+}
+
+impl Finality {
+	/// Returns the block tag identifying the last final block, if any
+	pub fn block_tag(&self) -> Option<&'static str> {
+		match self {
+			Finality::Finalized => Some("finalized"),
+			Finality::Safe => Some("safe"),
+			Finality::Latest | Finality::Confirmations(_) => None,
+		}
+	}
+}
+
+impl fmt::Display for Finality {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Finality::Finalized => write!(f, "finalized"),
+			Finality::Safe => write!(f, "safe"),
+			Finality::Latest => write!(f, "latest"),
+			Finality::Confirmations(confirmations) => write!(f, "{} confirmations", confirmations),
+		}
+	}
+}
+
+/// Point from which the matches of a network fire triggers
+///
+/// Blocks before this point are still processed and advance the last processed block, but
+/// their matches are dropped, which prevents alerts for historical matches from firing again
+/// after the last processed block is lost or reset.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EmitMatchesAfter {
+	/// First block number whose matches fire triggers
+	Block(u64),
+	/// First block timestamp, in seconds since the Unix epoch, whose matches fire triggers
+	Timestamp(u64),
+}
+
+impl EmitMatchesAfter {
+	/// Returns whether the matches of a block fire triggers
+	///
+	/// Blocks without a known timestamp fire their matches when the point is a timestamp.
+	///
+	/// # Arguments
+	/// * `block_number` - Number of the block
+	/// * `timestamp` - Timestamp of the block in seconds since the Unix epoch, if known
+	pub fn emits(&self, block_number: u64, timestamp: Option<u64>) -> bool {
+		match self {
+			EmitMatchesAfter::Block(first_block) => block_number >= *first_block,
+			EmitMatchesAfter::Timestamp(first_timestamp) => {
+				timestamp.is_none_or(|timestamp| timestamp >= *first_timestamp)
+			}
+		}
+	}
 }
 
 /// RPC endpoint configuration with load balancing weight
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct RpcUrl {
 	/// Type of RPC endpoint (e.g. "rpc")
@@ -55,4 +207,220 @@ pub struct RpcUrl {
 
 	/// Weight for load balancing (0-100)
 	pub weight: u32,
+
+	/// Additional HTTP headers sent with every request to the endpoint (values can be secret
+	/// values)
+	#[serde(default, skip_serializing_if = "HashMap::is_empty")]
+	pub headers: HashMap<String, SecretValue>,
+}
+
+impl Network {
+	/// Returns how the last block safe to process is determined
+	pub fn finality(&self) -> Finality {
+		self.finality
+			.unwrap_or(Finality::Confirmations(self.confirmation_blocks))
+	}
+
+	/// Returns the number of blocks required on top of the latest block for it to be processed
+	///
+	/// Networks using a block tag require `confirmation_blocks` confirmations, which apply
+	/// when their RPC endpoints do not support the tag.
+	pub fn confirmations(&self) -> u64 {
+		match self.finality() {
+			Finality::Latest => 0,
+			Finality::Confirmations(confirmations) => confirmations,
+			Finality::Finalized | Finality::Safe => self.confirmation_blocks,
+		}
+	}
+
+	/// Returns the name of the well-known Stellar network (`mainnet`, `testnet` or
+	/// `futurenet`) matching the configured passphrase, if any
+	pub fn stellar_network_name(&self) -> Option<&'static str> {
+		let passphrase = self.network_passphrase.as_deref()?;
+		KNOWN_STELLAR_NETWORKS
+			.iter()
+			.find(|(_, known)| *known == passphrase)
+			.map(|(name, _)| *name)
+	}
+
+	/// Validates that an address can be monitored on this Stellar network
+	///
+	/// Stellar addresses do not encode the network they belong to, so beyond checking
+	/// that the address is a valid account or contract strkey, only the native asset
+	/// contract of another well-known network can be detected as a mismatch.
+	///
+	/// # Returns
+	/// * `Ok(())` - If the address is valid for this network
+	/// * `Err(String)` - A description of why the address is invalid
+	pub fn validate_stellar_address(&self, address: &str) -> Result<(), String> {
+		if StrkeyPublicKey::from_string(address).is_err() && Contract::from_string(address).is_err()
+		{
+			return Err(format!(
+				"'{}' is not a valid Stellar account or contract address",
+				address
+			));
+		}
+
+		let passphrase = self.network_passphrase.as_deref();
+		for (name, known) in KNOWN_STELLAR_NETWORKS {
+			if Some(known) != passphrase && stellar_native_asset_contract_id(known) == address {
+				return Err(format!(
+					"'{}' is the native asset contract of the Stellar {}, which does not \
+					 match the passphrase of network '{}'",
+					address, name, self.slug
+				));
+			}
+		}
+
+		Ok(())
+	}
+}
+
+/// Returns the ID of the native asset (XLM) contract on the network with the given passphrase
+pub fn stellar_native_asset_contract_id(network_passphrase: &str) -> String {
+	let preimage = HashIdPreimage::ContractId(HashIdPreimageContractId {
+		network_id: Hash(Sha256::digest(network_passphrase.as_bytes()).into()),
+		contract_id_preimage: ContractIdPreimage::Asset(Asset::Native),
+	});
+	let preimage = preimage
+		.to_xdr(Limits::none())
+		.expect("contract id preimage is always serializable");
+	Contract(Sha256::digest(preimage).into()).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::utils::tests::builders::network::NetworkBuilder;
+
+	fn create_stellar_network(passphrase: &str) -> Network {
+		NetworkBuilder::new()
+			.slug("stellar_testnet")
+			.network_type(BlockChainType::Stellar)
+			.network_passphrase(passphrase)
+			.build()
+	}
+
+	#[test]
+	fn test_stellar_network_name() {
+		assert_eq!(
+			create_stellar_network(STELLAR_MAINNET_PASSPHRASE).stellar_network_name(),
+			Some("mainnet")
+		);
+		assert_eq!(
+			create_stellar_network(STELLAR_TESTNET_PASSPHRASE).stellar_network_name(),
+			Some("testnet")
+		);
+		assert_eq!(
+			create_stellar_network("Standalone Network ; February 2017").stellar_network_name(),
+			None
+		);
+	}
+
+	#[test]
+	fn test_stellar_native_asset_contract_id() {
+		assert_eq!(
+			stellar_native_asset_contract_id(STELLAR_MAINNET_PASSPHRASE),
+			"CAS3J7GYLGXMF6TDJBBYYSE3HQ6BBSMLNUQ34T6TZMYMW2EVH34XOWMA"
+		);
+		assert_eq!(
+			stellar_native_asset_contract_id(STELLAR_TESTNET_PASSPHRASE),
+			"CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC"
+		);
+	}
+
+	#[test]
+	fn test_validate_stellar_address() {
+		let network = create_stellar_network(STELLAR_TESTNET_PASSPHRASE);
+
+		assert!(network
+			.validate_stellar_address("GCXKG6RN4ONIEPCMNFB732A436Z5PNDSRLGWK7GBLCMQLIFO4S7EYWVU")
+			.is_ok());
+		assert!(network
+			.validate_stellar_address(&stellar_native_asset_contract_id(
+				STELLAR_TESTNET_PASSPHRASE
+			))
+			.is_ok());
+
+		let err = network
+			.validate_stellar_address("0x1234567890123456789012345678901234567890")
+			.unwrap_err();
+		assert!(err.contains("not a valid Stellar account or contract address"));
+
+		let err = network
+			.validate_stellar_address(&stellar_native_asset_contract_id(
+				STELLAR_MAINNET_PASSPHRASE,
+			))
+			.unwrap_err();
+		assert!(err.contains("native asset contract of the Stellar mainnet"));
+		assert!(err.contains("'stellar_testnet'"));
+	}
+
+	#[test]
+	fn test_finality() {
+		let network = NetworkBuilder::new().confirmation_blocks(12).build();
+		assert_eq!(network.finality(), Finality::Confirmations(12));
+		assert_eq!(network.confirmations(), 12);
+
+		let network = NetworkBuilder::new()
+			.confirmation_blocks(12)
+			.finality(Finality::Finalized)
+			.build();
+		assert_eq!(network.finality().block_tag(), Some("finalized"));
+		assert_eq!(network.confirmations(), 12);
+
+		let network = NetworkBuilder::new().finality(Finality::Latest).build();
+		assert_eq!(network.finality().block_tag(), None);
+		assert_eq!(network.confirmations(), 0);
+
+		let network = NetworkBuilder::new()
+			.confirmation_blocks(12)
+			.finality(Finality::Confirmations(3))
+			.build();
+		assert_eq!(network.confirmations(), 3);
+	}
+
+	#[test]
+	fn test_finality_deserialization() {
+		assert_eq!(
+			serde_json::from_str::<Finality>(r#""finalized""#).unwrap(),
+			Finality::Finalized
+		);
+		assert_eq!(
+			serde_json::from_str::<Finality>(r#""safe""#).unwrap(),
+			Finality::Safe
+		);
+		assert_eq!(
+			serde_json::from_str::<Finality>(r#"{"confirmations": 6}"#).unwrap(),
+			Finality::Confirmations(6)
+		);
+		assert!(serde_json::from_str::<Finality>(r#""pending""#).is_err());
+	}
+
+	#[test]
+	fn test_emit_matches_after_emits() {
+		let cutoff = EmitMatchesAfter::Block(100);
+		assert!(!cutoff.emits(99, Some(2_000)));
+		assert!(cutoff.emits(100, None));
+		assert!(cutoff.emits(101, Some(0)));
+
+		let cutoff = EmitMatchesAfter::Timestamp(1_700_000_000);
+		assert!(!cutoff.emits(100, Some(1_699_999_999)));
+		assert!(cutoff.emits(0, Some(1_700_000_000)));
+		// Blocks without a known timestamp are never silenced
+		assert!(cutoff.emits(0, None));
+	}
+
+	#[test]
+	fn test_emit_matches_after_deserialization() {
+		assert_eq!(
+			serde_json::from_str::<EmitMatchesAfter>(r#"{"block": 19000000}"#).unwrap(),
+			EmitMatchesAfter::Block(19_000_000)
+		);
+		assert_eq!(
+			serde_json::from_str::<EmitMatchesAfter>(r#"{"timestamp": 1700000000}"#).unwrap(),
+			EmitMatchesAfter::Timestamp(1_700_000_000)
+		);
+		assert!(serde_json::from_str::<EmitMatchesAfter>(r#"{"slot": 1}"#).is_err());
+	}
 }
@@ -3,24 +3,72 @@ use crate::{
 	utils::RetryConfig,
 };
 use email_address::EmailAddress;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Configuration for actions to take when monitored conditions are met.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Trigger {
 	/// Unique name identifying this trigger
 	pub name: String,
 
-	/// Type of trigger (Email, Slack, Webhook, Telegram, Discord, Script)
+	/// Type of trigger (Email, Slack, Webhook, Telegram, Discord, Script, Kafka, Nats, Grpc, Custom)
 	pub trigger_type: TriggerType,
 
 	/// Configuration specific to the trigger type
 	pub config: TriggerTypeConfig,
+
+	/// Tags that monitors can use to reference this trigger via `trigger_tags`
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub tags: Vec<String>,
+
+	/// Arbitrary key/value labels exposed to notification templates and metrics
+	#[serde(default, skip_serializing_if = "HashMap::is_empty")]
+	pub labels: HashMap<String, String>,
+
+	/// Optional digest settings. When set, matches are accumulated and a single summary
+	/// notification is sent every `digest_interval_ms` instead of one notification per match
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub digest: Option<DigestConfig>,
+
+	/// Timezone of the `block.timestamp_iso` notification variable, as `UTC` or a fixed offset
+	/// such as `+02:00`. Defaults to the `NOTIFICATION_TIMEZONE` environment variable
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub timezone: Option<String>,
+
+	/// Optional named message templates replacing the trigger's message when the severity of
+	/// a match, computed by the monitor's `severity_rules`, equals their name
+	#[serde(default, skip_serializing_if = "HashMap::is_empty")]
+	pub templates: HashMap<String, NotificationMessage>,
+}
+
+/// Default number of matches detailed in a digest notification
+const fn default_digest_max_matches() -> usize {
+	10
+}
+
+/// Settings of a trigger sending periodic digests of its matches
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DigestConfig {
+	/// Interval in milliseconds between two digest notifications
+	pub digest_interval_ms: u64,
+
+	/// Maximum number of matches detailed in a digest, the first matches of the window being
+	/// kept. All matches are counted (default 10)
+	#[serde(default = "default_digest_max_matches")]
+	pub max_matches: usize,
+}
+
+/// Default timeout in milliseconds of a gRPC notification request
+const fn default_grpc_timeout_ms() -> u64 {
+	5000
 }
 
 /// Supported trigger action types
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 #[serde(deny_unknown_fields)]
 pub enum TriggerType {
@@ -36,10 +84,18 @@ pub enum TriggerType {
 	Discord,
 	/// Execute local script
 	Script,
+	/// Publish record to a Kafka topic
+	Kafka,
+	/// Publish message to a NATS subject
+	Nats,
+	/// Send notification to a gRPC service
+	Grpc,
+	/// Send notification through the custom notifier registered under the given name
+	Custom(String),
 }
 
 /// Notification message fields
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct NotificationMessage {
 	/// Notification title or subject
@@ -48,8 +104,87 @@ pub struct NotificationMessage {
 	pub body: String,
 }
 
+/// Template variables added to a generic webhook payload
+///
+/// Entries match a variable name exactly or any variable nested under it, so `events`
+/// selects `events.0.signature` and `events.0.args.value`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookPayloadFields {
+	/// Variables to include. All variables are included when empty.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub include: Vec<String>,
+	/// Variables to leave out, applied after `include`
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub exclude: Vec<String>,
+}
+
+impl WebhookPayloadFields {
+	/// Returns whether the variable with the given name belongs in the payload
+	pub fn allows(&self, name: &str) -> bool {
+		let matches = |field: &String| {
+			name == field
+				|| name
+					.strip_prefix(field.as_str())
+					.is_some_and(|rest| rest.starts_with('.'))
+		};
+		(self.include.is_empty() || self.include.iter().any(matches))
+			&& !self.exclude.iter().any(matches)
+	}
+}
+
+/// Severity of a Discord embed, setting the color of its side bar
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DiscordEmbedSeverity {
+	/// Blue side bar
+	#[default]
+	Info,
+	/// Yellow side bar
+	Warning,
+	/// Red side bar
+	Critical,
+}
+
+impl DiscordEmbedSeverity {
+	/// Returns the RGB color of the embed side bar
+	pub fn color(&self) -> u32 {
+		match self {
+			Self::Info => 0x3498DB,
+			Self::Warning => 0xF1C40F,
+			Self::Critical => 0xE74C3C,
+		}
+	}
+}
+
+/// Settings of a Discord notification sent as an embed instead of plain content
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DiscordEmbedConfig {
+	/// Severity setting the color of the embed (default info)
+	#[serde(default)]
+	pub severity: DiscordEmbedSeverity,
+	/// Template variables shown as key/value fields of the embed, in order. Variables
+	/// missing from a match are left out
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub fields: Vec<String>,
+}
+
+/// Content type of the email body
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum EmailContentType {
+	/// Markdown template rendered to HTML
+	#[default]
+	Markdown,
+	/// Raw HTML template
+	Html,
+	/// Plain text template
+	Text,
+}
+
 /// Type-specific configuration for triggers
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema)]
 #[serde(deny_unknown_fields)]
 #[serde(untagged)]
 pub enum TriggerTypeConfig {
@@ -62,6 +197,13 @@ pub enum TriggerTypeConfig {
 		/// Retry policy for HTTP requests
 		#[serde(default)]
 		retry_policy: RetryConfig,
+		/// Maximum number of notifications sent to the endpoint concurrently
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		max_concurrent_notifications: Option<usize>,
+		/// Maximum length of the message in characters, longer messages are truncated
+		/// (defaults to the limit of the channel)
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		max_message_length: Option<usize>,
 	},
 	/// Email notification configuration
 	Email {
@@ -76,9 +218,29 @@ pub enum TriggerTypeConfig {
 		/// Notification message
 		message: NotificationMessage,
 		/// Email sender
+		#[schemars(with = "String")]
 		sender: EmailAddress,
 		/// Email recipients
+		#[schemars(with = "Vec<String>")]
 		recipients: Vec<EmailAddress>,
+		/// Email addresses copied on the email
+		#[serde(default, skip_serializing_if = "Vec::is_empty")]
+		#[schemars(with = "Vec<String>")]
+		cc: Vec<EmailAddress>,
+		/// Email addresses blind copied on the email
+		#[serde(default, skip_serializing_if = "Vec::is_empty")]
+		#[schemars(with = "Vec<String>")]
+		bcc: Vec<EmailAddress>,
+		/// Address replies are sent to (defaults to the sender)
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		#[schemars(with = "Option<String>")]
+		reply_to: Option<EmailAddress>,
+		/// Content type of the email body (default markdown)
+		#[serde(default)]
+		content_type: EmailContentType,
+		/// Paths of static files to attach to the email
+		#[serde(default)]
+		attachments: Option<Vec<String>>,
 		/// Retry policy for SMTP requests
 		#[serde(default)]
 		retry_policy: RetryConfig,
@@ -92,12 +254,18 @@ pub enum TriggerTypeConfig {
 		/// Secret
 		secret: Option<SecretValue>,
 		/// Optional HTTP headers
-		headers: Option<std::collections::HashMap<String, String>>,
+		headers: Option<HashMap<String, String>>,
 		/// Notification message
 		message: NotificationMessage,
 		/// Retry policy for HTTP requests
 		#[serde(default)]
 		retry_policy: RetryConfig,
+		/// Maximum number of notifications sent to the endpoint concurrently
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		max_concurrent_notifications: Option<usize>,
+		/// Template variables to add to the payload alongside the title and body
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		payload_fields: Option<WebhookPayloadFields>,
 	},
 	/// Telegram notification configuration
 	Telegram {
@@ -112,6 +280,13 @@ pub enum TriggerTypeConfig {
 		/// Retry policy for HTTP requests
 		#[serde(default)]
 		retry_policy: RetryConfig,
+		/// Maximum number of notifications sent to the endpoint concurrently
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		max_concurrent_notifications: Option<usize>,
+		/// Maximum length of the message in characters, longer messages are truncated
+		/// (defaults to the limit of the channel)
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		max_message_length: Option<usize>,
 	},
 	/// Discord notification configuration
 	Discord {
@@ -122,18 +297,91 @@ pub enum TriggerTypeConfig {
 		/// Retry policy for HTTP requests
 		#[serde(default)]
 		retry_policy: RetryConfig,
+		/// Maximum number of notifications sent to the endpoint concurrently
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		max_concurrent_notifications: Option<usize>,
+		/// Maximum length of the message in characters, longer messages are truncated
+		/// (defaults to the limit of the channel)
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		max_message_length: Option<usize>,
+		/// Sends the message as an embed instead of plain content when set
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		embed: Option<DiscordEmbedConfig>,
+	},
+	/// Kafka notification configuration
+	Kafka {
+		/// Kafka bootstrap brokers (host:port)
+		brokers: Vec<String>,
+		/// Topic to publish records to
+		topic: String,
+		/// Optional record key template
+		#[serde(default)]
+		key_template: Option<String>,
+		/// Optional notification message. The serialized monitor match is published when omitted
+		#[serde(default)]
+		message: Option<NotificationMessage>,
+		/// Retry policy for record delivery
+		#[serde(default)]
+		retry_policy: RetryConfig,
+	},
+	/// NATS notification configuration
+	Nats {
+		/// NATS server URL (e.g. nats://localhost:4222)
+		server_url: String,
+		/// Subject to publish messages to
+		subject: String,
+		/// Optional JetStream stream expected to store the messages
+		#[serde(default)]
+		stream: Option<String>,
+		/// Optional notification message. The serialized monitor match is published when omitted
+		#[serde(default)]
+		message: Option<NotificationMessage>,
+		/// Retry policy for message delivery
+		#[serde(default)]
+		retry_policy: RetryConfig,
+	},
+	/// gRPC notification configuration
+	Grpc {
+		/// gRPC service URL (e.g. https://alerts.example.com:443)
+		endpoint: String,
+		/// Optional metadata sent with each request, such as an authorization token
+		#[serde(default, skip_serializing_if = "HashMap::is_empty")]
+		metadata: HashMap<String, String>,
+		/// Optional notification message. Only the serialized monitor match is sent when omitted
+		#[serde(default)]
+		message: Option<NotificationMessage>,
+		/// Timeout of each request in milliseconds
+		#[serde(default = "default_grpc_timeout_ms")]
+		timeout_ms: u64,
+		/// Retry policy for notification delivery
+		#[serde(default)]
+		retry_policy: RetryConfig,
 	},
 	/// Script execution configuration
 	Script {
 		/// Language of the script
 		language: ScriptLanguage,
-		/// Path to script file
+		/// Path to script file, or http(s) URL the script is fetched from at startup
 		script_path: String,
 		/// Command line arguments
 		#[serde(default)]
 		arguments: Option<Vec<String>>,
 		/// Timeout in milliseconds
 		timeout_ms: u32,
+		/// SHA-256 checksum of the script, required for scripts fetched from a URL
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		script_checksum: Option<String>,
+		/// Retry policy of transient script failures, the script is not retried when unset
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		retry_policy: Option<RetryConfig>,
+	},
+	/// Custom notifier configuration
+	Custom {
+		/// Notification message
+		message: NotificationMessage,
+		/// Settings passed as-is to the custom notifier
+		#[serde(default, skip_serializing_if = "HashMap::is_empty")]
+		settings: HashMap<String, String>,
 	},
 }
 
@@ -145,6 +393,90 @@ impl TriggerTypeConfig {
 			Self::Discord { retry_policy, .. } => Some(retry_policy.clone()),
 			Self::Webhook { retry_policy, .. } => Some(retry_policy.clone()),
 			Self::Telegram { retry_policy, .. } => Some(retry_policy.clone()),
+			Self::Kafka { retry_policy, .. } => Some(retry_policy.clone()),
+			Self::Nats { retry_policy, .. } => Some(retry_policy.clone()),
+			Self::Grpc { retry_policy, .. } => Some(retry_policy.clone()),
+			_ => None,
+		}
+	}
+
+	/// Replace the retry policy of the trigger type, if applicable.
+	pub fn set_retry_policy(&mut self, policy: RetryConfig) {
+		match self {
+			Self::Slack { retry_policy, .. }
+			| Self::Email { retry_policy, .. }
+			| Self::Discord { retry_policy, .. }
+			| Self::Webhook { retry_policy, .. }
+			| Self::Telegram { retry_policy, .. }
+			| Self::Kafka { retry_policy, .. }
+			| Self::Nats { retry_policy, .. }
+			| Self::Grpc { retry_policy, .. } => *retry_policy = policy,
+			_ => {}
+		}
+	}
+
+	/// Replace the message of the trigger type, if applicable.
+	///
+	/// Returns `false` for trigger types without a message, such as scripts.
+	pub fn set_message(&mut self, new_message: NotificationMessage) -> bool {
+		match self {
+			Self::Slack { message, .. }
+			| Self::Email { message, .. }
+			| Self::Webhook { message, .. }
+			| Self::Telegram { message, .. }
+			| Self::Discord { message, .. }
+			| Self::Custom { message, .. } => *message = new_message,
+			Self::Kafka { message, .. }
+			| Self::Nats { message, .. }
+			| Self::Grpc { message, .. } => *message = Some(new_message),
+			Self::Script { .. } => return false,
+		}
+		true
+	}
+
+	/// Get the maximum number of concurrent notifications for the trigger type, if configured.
+	pub fn get_max_concurrent_notifications(&self) -> Option<usize> {
+		match self {
+			Self::Slack {
+				max_concurrent_notifications,
+				..
+			}
+			| Self::Discord {
+				max_concurrent_notifications,
+				..
+			}
+			| Self::Webhook {
+				max_concurrent_notifications,
+				..
+			}
+			| Self::Telegram {
+				max_concurrent_notifications,
+				..
+			} => *max_concurrent_notifications,
+			_ => None,
+		}
+	}
+
+	/// Get the maximum message length for the trigger type, if configured.
+	pub fn get_max_message_length(&self) -> Option<usize> {
+		match self {
+			Self::Slack {
+				max_message_length, ..
+			}
+			| Self::Discord {
+				max_message_length, ..
+			}
+			| Self::Telegram {
+				max_message_length, ..
+			} => *max_message_length,
+			_ => None,
+		}
+	}
+
+	/// Get the embed settings of a Discord trigger, if configured.
+	pub fn get_discord_embed(&self) -> Option<&DiscordEmbedConfig> {
+		match self {
+			Self::Discord { embed, .. } => embed.as_ref(),
 			_ => None,
 		}
 	}
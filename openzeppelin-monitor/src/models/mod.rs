@@ -25,7 +25,7 @@ pub use blockchain::evm::{
 
 pub use blockchain::stellar::{
 	StellarBlock, StellarContractFunction, StellarContractInput, StellarContractSpec,
-	StellarDecodedParamEntry, StellarDecodedTransaction, StellarEvent,
+	StellarDecodedParamEntry, StellarDecodedTransaction, StellarEvent, StellarEventFilter,
 	StellarFormattedContractSpec, StellarLedgerInfo, StellarMatchArguments, StellarMatchParamEntry,
 	StellarMatchParamsMap, StellarMonitorMatch, StellarParsedOperationResult, StellarTransaction,
 	StellarTransactionInfo,
@@ -33,13 +33,18 @@ pub use blockchain::stellar::{
 
 // Re-export core types
 pub use core::{
-	AddressWithSpec, EventCondition, FunctionCondition, MatchConditions, Monitor, Network,
-	NotificationMessage, RpcUrl, ScriptLanguage, TransactionCondition, TransactionStatus, Trigger,
-	TriggerConditions, TriggerType, TriggerTypeConfig,
+	AddressWithSpec, AggregateCondition, DigestConfig, DiscordEmbedConfig, DiscordEmbedSeverity,
+	EmailContentType, EmitMatchesAfter, EnrichmentCall, EnrichmentConfig, EventCondition,
+	EventField, Finality, FunctionCondition, MatchConditionType, MatchConditions, MatchDedupConfig,
+	Monitor, MonitorTrigger, Network, NotificationMessage, NotificationThrottleConfig,
+	OperationCondition, PercentageChangeCondition, RpcUrl, ScriptLanguage, SeverityRule,
+	ThrottleOverflow, TransactionCondition, TransactionStatus, Trigger, TriggerConditions,
+	TriggerExecutionConfig, TriggerType, TriggerTypeConfig, WebhookPayloadFields,
+	DEFAULT_MAX_LOGS_PER_TX, DEFAULT_MAX_PAST_BLOCKS_LIMIT,
 };
 
 // Re-export config types
-pub use config::{ConfigError, ConfigLoader};
+pub use config::{CombinedConfig, ConfigError, ConfigLoader, ConfigSchema};
 
 // Re-export security types
 pub use security::{SecretString, SecretValue, SecurityError};
@@ -11,8 +11,9 @@
 //! - Serde support for configuration files
 
 use oz_keystore::HashicorpCloudClient;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
 use serde::{Deserialize, Serialize};
-use std::{env, fmt, sync::Arc};
+use std::{borrow::Cow, env, fmt, sync::Arc};
 use tokio::sync::OnceCell;
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
@@ -133,6 +134,33 @@ impl_case_insensitive_enum!(SecretValue, {
 	"hashicorpcloudvault" => HashicorpCloudVault,
 });
 
+/// The secret type is matched case-insensitively on deserialization, so the schema
+/// accepts any casing instead of listing the variant names.
+impl JsonSchema for SecretValue {
+	fn schema_name() -> Cow<'static, str> {
+		"SecretValue".into()
+	}
+
+	fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+		json_schema!({
+			"type": "object",
+			"description": "A secret given in plain text or referenced from its source",
+			"properties": {
+				"type": {
+					"type": "string",
+					"description": "Source of the secret: Plain, Environment or HashicorpCloudVault (case-insensitive)"
+				},
+				"value": {
+					"type": "string",
+					"description": "The secret, environment variable name or vault secret name"
+				}
+			},
+			"required": ["type", "value"],
+			"additionalProperties": false
+		})
+	}
+}
+
 impl PartialEq for SecretValue {
 	fn eq(&self, other: &Self) -> bool {
 		match (self, other) {
@@ -11,12 +11,16 @@ use std::{collections::HashMap, marker::PhantomData, path::Path};
 use async_trait::async_trait;
 
 use crate::{
-	models::{ConfigLoader, Monitor, Network, ScriptLanguage, Trigger},
+	models::{BlockChainType, ConfigLoader, Monitor, Network, ScriptLanguage, Trigger},
 	repositories::{
 		error::RepositoryError,
 		network::{NetworkRepository, NetworkRepositoryTrait, NetworkService},
 		trigger::{TriggerRepository, TriggerRepositoryTrait, TriggerService},
 	},
+	services::{
+		filter::network_address_normalizer,
+		trigger::{is_remote_script, script_extension},
+	},
 };
 
 /// Static mapping of script languages to their file extensions
@@ -69,6 +73,66 @@ impl<
 		}
 	}
 
+	/// Resolves each monitor's `trigger_tags` into trigger names.
+	///
+	/// Every trigger carrying one of a monitor's tags is appended to its `triggers`, in name
+	/// order and without duplicates. Returns an error if a tag matches no trigger.
+	pub fn resolve_trigger_tags(
+		monitors: &mut HashMap<String, Monitor>,
+		triggers: &HashMap<String, Trigger>,
+	) -> Result<(), RepositoryError> {
+		let mut validation_errors = Vec::new();
+		let mut metadata = HashMap::new();
+
+		let mut trigger_names: Vec<&String> = triggers.keys().collect();
+		trigger_names.sort();
+
+		for (monitor_name, monitor) in monitors.iter_mut() {
+			for tag in &monitor.trigger_tags {
+				let tagged: Vec<&String> = trigger_names
+					.iter()
+					.copied()
+					.filter(|name| triggers[*name].tags.contains(tag))
+					.collect();
+
+				if tagged.is_empty() {
+					validation_errors.push(format!(
+						"Monitor '{}' references trigger tag '{}' that matches no trigger",
+						monitor_name, tag
+					));
+					metadata.insert(
+						format!("monitor_{}_invalid_trigger_tag", monitor_name),
+						tag.clone(),
+					);
+					continue;
+				}
+
+				for name in tagged {
+					if !monitor
+						.triggers
+						.iter()
+						.any(|trigger| trigger.name() == name)
+					{
+						monitor.triggers.push(name.clone().into());
+					}
+				}
+			}
+		}
+
+		if !validation_errors.is_empty() {
+			return Err(RepositoryError::validation_error(
+				format!(
+					"Configuration validation failed:\n{}",
+					validation_errors.join("\n"),
+				),
+				None,
+				Some(metadata),
+			));
+		}
+
+		Ok(())
+	}
+
 	/// Returns an error if any monitor references a non-existent network or trigger.
 	pub fn validate_monitor_references(
 		monitors: &HashMap<String, Monitor>,
@@ -80,7 +144,7 @@ impl<
 
 		for (monitor_name, monitor) in monitors {
 			// Validate trigger references
-			for trigger_id in &monitor.triggers {
+			for trigger_id in monitor.triggers.iter().map(|trigger| trigger.name()) {
 				if !triggers.contains_key(trigger_id) {
 					validation_errors.push(format!(
 						"Monitor '{}' references non-existent trigger '{}'",
@@ -88,14 +152,14 @@ impl<
 					));
 					metadata.insert(
 						format!("monitor_{}_invalid_trigger", monitor_name),
-						trigger_id.clone(),
+						trigger_id.to_string(),
 					);
 				}
 			}
 
 			// Validate network references
 			for network_slug in &monitor.networks {
-				if !networks.contains_key(network_slug) {
+				let Some(network) = networks.get(network_slug) else {
 					validation_errors.push(format!(
 						"Monitor '{}' references non-existent network '{}'",
 						monitor_name, network_slug
@@ -104,13 +168,47 @@ impl<
 						format!("monitor_{}_invalid_network", monitor_name),
 						network_slug.clone(),
 					);
+					continue;
+				};
+
+				// Validate monitored addresses against Stellar networks
+				if network.network_type == BlockChainType::Stellar {
+					for address in &monitor.addresses {
+						if let Err(e) = network.validate_stellar_address(&address.address) {
+							validation_errors.push(format!(
+								"Monitor '{}' has an invalid address for network '{}': {}",
+								monitor_name, network_slug, e
+							));
+							metadata.insert(
+								format!("monitor_{}_invalid_address", monitor_name),
+								address.address.clone(),
+							);
+						}
+					}
+				}
+
+				// Validate address checksums on networks requiring them
+				let normalizer = network_address_normalizer(network);
+				for address in &monitor.addresses {
+					if !normalizer.is_valid(&address.address) {
+						validation_errors.push(format!(
+							"Monitor '{}' has an address with an invalid checksum for network \
+							 '{}': {}",
+							monitor_name, network_slug, address.address
+						));
+						metadata.insert(
+							format!("monitor_{}_invalid_address", monitor_name),
+							address.address.clone(),
+						);
+					}
 				}
 			}
 
 			// Validate custom trigger conditions
 			for condition in &monitor.trigger_conditions {
-				let script_path = Path::new(&condition.script_path);
-				if !script_path.exists() {
+				if !is_remote_script(&condition.script_path)
+					&& !Path::new(&condition.script_path).exists()
+				{
 					validation_errors.push(format!(
 						"Monitor '{}' has a custom filter script that does not exist: {}",
 						monitor_name, condition.script_path
@@ -133,7 +231,7 @@ impl<
 					}
 				};
 
-				match script_path.extension().and_then(|ext| ext.to_str()) {
+				match script_extension(&condition.script_path) {
 					Some(ext) if ext == expected_extension => (), // Valid extension
 					_ => validation_errors.push(format!(
 						"Monitor '{}' has a custom filter script with invalid extension - must be \
@@ -236,7 +334,7 @@ impl<
 		network_service: Option<NetworkService<N>>,
 		trigger_service: Option<TriggerService<T>>,
 	) -> Result<HashMap<String, Monitor>, RepositoryError> {
-		let monitors = Monitor::load_all(path).await.map_err(|e| {
+		let mut monitors = Monitor::load_all(path).await.map_err(|e| {
 			RepositoryError::load_error(
 				"Failed to load monitors",
 				Some(Box::new(e)),
@@ -279,6 +377,7 @@ impl<
 			}
 		};
 
+		Self::resolve_trigger_tags(&mut monitors, &triggers)?;
 		Self::validate_monitor_references(&monitors, &triggers, &networks)?;
 		Ok(monitors)
 	}
@@ -314,7 +413,8 @@ impl<
 					Some(service) => service.get_all(),
 					None => TriggerRepository::new(None).await?.triggers,
 				};
-				let monitors = HashMap::from([(monitor.name.clone(), monitor)]);
+				let mut monitors = HashMap::from([(monitor.name.clone(), monitor)]);
+				Self::resolve_trigger_tags(&mut monitors, &triggers)?;
 				Self::validate_monitor_references(&monitors, &triggers, &networks)?;
 				match monitors.values().next() {
 					Some(monitor) => Ok(monitor.clone()),
@@ -434,7 +534,12 @@ impl<
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::{models::ScriptLanguage, utils::tests::builders::evm::monitor::MonitorBuilder};
+	use crate::{
+		models::ScriptLanguage,
+		utils::tests::builders::{
+			evm::monitor::MonitorBuilder, network::NetworkBuilder, trigger::TriggerBuilder,
+		},
+	};
 	use std::fs;
 	use tempfile::TempDir;
 
@@ -577,6 +682,154 @@ mod tests {
 		assert!(err.to_string().contains("references non-existent network"));
 	}
 
+	#[test]
+	fn test_stellar_address_validation() {
+		let mut networks = HashMap::new();
+		networks.insert(
+			"stellar_testnet".to_string(),
+			NetworkBuilder::new()
+				.slug("stellar_testnet")
+				.network_type(BlockChainType::Stellar)
+				.network_passphrase("Test SDF Network ; September 2015")
+				.build(),
+		);
+		let triggers = HashMap::new();
+
+		let valid_monitor = MonitorBuilder::new()
+			.name("valid_monitor")
+			.networks(vec!["stellar_testnet".to_string()])
+			.addresses(vec![
+				"CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC".to_string(),
+			])
+			.build();
+		let monitors = HashMap::from([("valid_monitor".to_string(), valid_monitor)]);
+		assert!(
+			MonitorRepository::<NetworkRepository, TriggerRepository>::validate_monitor_references(
+				&monitors, &triggers, &networks,
+			)
+			.is_ok()
+		);
+
+		let invalid_monitor = MonitorBuilder::new()
+			.name("invalid_monitor")
+			.networks(vec!["stellar_testnet".to_string()])
+			.addresses(vec![
+				"0x0000000000000000000000000000000000001234".to_string(),
+				// Native asset contract on mainnet
+				"CAS3J7GYLGXMF6TDJBBYYSE3HQ6BBSMLNUQ34T6TZMYMW2EVH34XOWMA".to_string(),
+			])
+			.build();
+		let monitors = HashMap::from([("invalid_monitor".to_string(), invalid_monitor)]);
+		let err =
+			MonitorRepository::<NetworkRepository, TriggerRepository>::validate_monitor_references(
+				&monitors, &triggers, &networks,
+			)
+			.unwrap_err()
+			.to_string();
+		assert!(err.contains("not a valid Stellar account or contract address"));
+		assert!(err.contains("native asset contract of the Stellar mainnet"));
+	}
+
+	#[test]
+	fn test_strict_address_checksum_validation() {
+		let mut networks = HashMap::new();
+		networks.insert(
+			"ethereum_mainnet".to_string(),
+			NetworkBuilder::new()
+				.slug("ethereum_mainnet")
+				.network_type(BlockChainType::EVM)
+				.strict_address_checksum(true)
+				.build(),
+		);
+		let triggers = HashMap::new();
+
+		let valid_monitor = MonitorBuilder::new()
+			.name("valid_monitor")
+			.networks(vec!["ethereum_mainnet".to_string()])
+			.addresses(vec![
+				"0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+				"0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359".to_string(),
+			])
+			.build();
+		let monitors = HashMap::from([("valid_monitor".to_string(), valid_monitor)]);
+		assert!(
+			MonitorRepository::<NetworkRepository, TriggerRepository>::validate_monitor_references(
+				&monitors, &triggers, &networks,
+			)
+			.is_ok()
+		);
+
+		let invalid_monitor = MonitorBuilder::new()
+			.name("invalid_monitor")
+			.networks(vec!["ethereum_mainnet".to_string()])
+			.addresses(vec![
+				"0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD".to_string()
+			])
+			.build();
+		let monitors = HashMap::from([("invalid_monitor".to_string(), invalid_monitor)]);
+		let err =
+			MonitorRepository::<NetworkRepository, TriggerRepository>::validate_monitor_references(
+				&monitors, &triggers, &networks,
+			)
+			.unwrap_err()
+			.to_string();
+		assert!(err.contains("has an address with an invalid checksum"));
+	}
+
+	#[tokio::test]
+	async fn test_load_all_rejects_monitor_with_only_invalid_addresses() {
+		let temp_dir = TempDir::new().unwrap();
+		let addresses = [
+			"0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD",
+			"0xFB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+		];
+		let monitor = MonitorBuilder::new()
+			.name("mistyped_monitor")
+			.networks(vec!["ethereum_mainnet".to_string()])
+			.addresses(addresses.map(String::from).to_vec())
+			.build();
+		fs::write(
+			temp_dir.path().join("mistyped_monitor.json"),
+			serde_json::to_string(&monitor).unwrap(),
+		)
+		.unwrap();
+
+		let network_service = NetworkService::new_with_repository(NetworkRepository {
+			networks: HashMap::from([(
+				"ethereum_mainnet".to_string(),
+				NetworkBuilder::new()
+					.slug("ethereum_mainnet")
+					.network_type(BlockChainType::EVM)
+					.strict_address_checksum(true)
+					.build(),
+			)]),
+		})
+		.unwrap();
+		let trigger_service = TriggerService::new_with_repository(TriggerRepository {
+			triggers: HashMap::new(),
+		})
+		.unwrap();
+
+		// The monitor is rejected rather than loaded without any address
+		let Err(err) = MonitorRepository::<NetworkRepository, TriggerRepository>::new(
+			Some(temp_dir.path()),
+			Some(network_service),
+			Some(trigger_service),
+		)
+		.await
+		else {
+			panic!("Expected the monitor to be rejected");
+		};
+		let err = err.to_string();
+		for address in addresses {
+			assert!(err.contains(&format!(
+				"Monitor 'mistyped_monitor' has an address with an invalid checksum for network \
+				 'ethereum_mainnet': {}",
+				address
+			)));
+		}
+	}
+
 	#[test]
 	fn test_trigger_validation_error() {
 		// Create a monitor with a reference to a non-existent trigger
@@ -602,6 +855,118 @@ mod tests {
 		assert!(err.to_string().contains("references non-existent trigger"));
 	}
 
+	fn create_tagged_triggers() -> HashMap<String, Trigger> {
+		HashMap::from([
+			(
+				"pagerduty".to_string(),
+				TriggerBuilder::new()
+					.name("pagerduty")
+					.tags(vec!["critical"])
+					.build(),
+			),
+			(
+				"slack_alerts".to_string(),
+				TriggerBuilder::new()
+					.name("slack_alerts")
+					.tags(vec!["critical", "info"])
+					.build(),
+			),
+			(
+				"audit_log".to_string(),
+				TriggerBuilder::new().name("audit_log").build(),
+			),
+		])
+	}
+
+	#[test]
+	fn test_resolve_trigger_tags() {
+		let triggers = create_tagged_triggers();
+		let mut monitors = HashMap::from([(
+			"test_monitor".to_string(),
+			MonitorBuilder::new()
+				.name("test_monitor")
+				.triggers(vec!["slack_alerts".to_string()])
+				.trigger_tags(vec!["critical".to_string()])
+				.build(),
+		)]);
+
+		MonitorRepository::<NetworkRepository, TriggerRepository>::resolve_trigger_tags(
+			&mut monitors,
+			&triggers,
+		)
+		.unwrap();
+
+		// Explicit triggers are kept and tagged triggers are added once, in name order
+		assert_eq!(
+			monitors["test_monitor"].triggers,
+			vec!["slack_alerts", "pagerduty"]
+		);
+	}
+
+	#[test]
+	fn test_resolve_trigger_tags_without_match() {
+		let triggers = create_tagged_triggers();
+		let mut monitors = HashMap::from([(
+			"test_monitor".to_string(),
+			MonitorBuilder::new()
+				.name("test_monitor")
+				.trigger_tags(vec!["unknown".to_string()])
+				.build(),
+		)]);
+
+		let err = MonitorRepository::<NetworkRepository, TriggerRepository>::resolve_trigger_tags(
+			&mut monitors,
+			&triggers,
+		)
+		.unwrap_err();
+		assert!(err
+			.to_string()
+			.contains("references trigger tag 'unknown' that matches no trigger"));
+	}
+
+	#[tokio::test]
+	async fn test_load_all_resolves_shared_trigger_tags() {
+		let temp_dir = TempDir::new().unwrap();
+		for name in ["monitor_a", "monitor_b"] {
+			let monitor = MonitorBuilder::new()
+				.name(name)
+				.trigger_tags(vec!["critical".to_string()])
+				.build();
+			fs::write(
+				temp_dir.path().join(format!("{}.json", name)),
+				serde_json::to_string(&monitor).unwrap(),
+			)
+			.unwrap();
+		}
+
+		let network_service = NetworkService::new_with_repository(NetworkRepository {
+			networks: HashMap::from([(
+				"ethereum_mainnet".to_string(),
+				NetworkBuilder::new().slug("ethereum_mainnet").build(),
+			)]),
+		})
+		.unwrap();
+		let trigger_service = TriggerService::new_with_repository(TriggerRepository {
+			triggers: create_tagged_triggers(),
+		})
+		.unwrap();
+
+		let repository = MonitorRepository::<NetworkRepository, TriggerRepository>::new(
+			Some(temp_dir.path()),
+			Some(network_service),
+			Some(trigger_service),
+		)
+		.await
+		.unwrap();
+
+		for name in ["monitor_a", "monitor_b"] {
+			assert_eq!(
+				repository.monitors[name].triggers,
+				vec!["pagerduty", "slack_alerts"]
+			);
+		}
+	}
+
 	#[tokio::test]
 	async fn test_load_from_path_error_handling() {
 		// Create a temporary directory for testing
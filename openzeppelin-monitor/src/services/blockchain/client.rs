@@ -22,6 +22,18 @@ pub trait BlockChainClient: Send + Sync + Clone {
 	/// * `Result<u64, anyhow::Error>` - The latest block number or an error
 	async fn get_latest_block_number(&self) -> Result<u64, anyhow::Error>;
 
+	/// Retrieves the number of the latest block with the given block tag (e.g. `finalized`)
+	///
+	/// # Arguments
+	/// * `tag` - The block tag to look up
+	///
+	/// # Returns
+	/// * `Result<Option<u64>, anyhow::Error>` - The block number, `None` if the network does
+	///   not support the tag, or an error
+	async fn get_block_number_by_tag(&self, _tag: &str) -> Result<Option<u64>, anyhow::Error> {
+		Ok(None)
+	}
+
 	/// Retrieves a range of blocks from the blockchain
 	///
 	/// # Arguments
@@ -50,13 +62,33 @@ pub trait BlockChainClient: Send + Sync + Clone {
 	async fn get_contract_spec(&self, _contract_id: &str) -> Result<ContractSpec, anyhow::Error> {
 		Err(anyhow::anyhow!("get_contract_spec not implemented"))
 	}
+
+	/// Performs a read-only call of a contract function taking no arguments
+	///
+	/// # Arguments
+	/// * `contract_id` - The ID of the contract to call
+	/// * `function` - The function to call, given by its signature on EVM (e.g. `symbol()`) and
+	///   by its name on Stellar (e.g. `symbol`)
+	/// * `output` - The type returned by the function, required to decode EVM results
+	///
+	/// # Returns
+	/// * `Result<String, anyhow::Error>` - The decoded result or an error
+	async fn call_contract_function(
+		&self,
+		_contract_id: &str,
+		_function: &str,
+		_output: Option<&str>,
+	) -> Result<String, anyhow::Error> {
+		Err(anyhow::anyhow!("call_contract_function not implemented"))
+	}
 }
 
 /// Defines the factory interface for creating block filters
 ///
 /// This trait must be implemented by all blockchain-specific clients to provide
-/// a way to create block filters.
+/// a way to create block filters. Filters created by the same client may share state
+/// owned by the client, such as cached contract specs.
 pub trait BlockFilterFactory<T> {
 	type Filter: BlockFilter<Client = T> + Send;
-	fn filter() -> Self::Filter;
+	fn filter(&self) -> Self::Filter;
 }
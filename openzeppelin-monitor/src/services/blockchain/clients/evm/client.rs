@@ -6,6 +6,10 @@
 
 use std::marker::PhantomData;
 
+use alloy::{
+	dyn_abi::DynSolType,
+	primitives::{keccak256, Address, B256},
+};
 use anyhow::Context;
 use async_trait::async_trait;
 use futures;
@@ -13,14 +17,20 @@ use serde_json::json;
 use tracing::instrument;
 
 use crate::{
-	models::{BlockType, EVMBlock, EVMReceiptLog, EVMTransactionReceipt, Network},
+	models::{
+		BlockType, EVMBaseTransaction, EVMBlock, EVMReceiptLog, EVMTransaction,
+		EVMTransactionReceipt, Network,
+	},
 	services::{
 		blockchain::{
 			client::BlockChainClient,
 			transports::{BlockchainTransport, EVMTransportClient},
 			BlockFilterFactory,
 		},
-		filter::{evm_helpers::string_to_h256, EVMBlockFilter},
+		filter::{
+			evm_helpers::{decode_revert_reason, ens_namehash, format_token_value, string_to_h256},
+			EVMBlockFilter,
+		},
 	},
 };
 
@@ -31,12 +41,74 @@ use crate::{
 pub struct EvmClient<T: Send + Sync + Clone> {
 	/// The underlying HTTP transport client for RPC communication
 	http_client: T,
+
+	/// Maximum number of transactions fetched at once per block, if blocks are fetched
+	/// without their transactions
+	tx_chunk_size: Option<usize>,
 }
 
 impl<T: Send + Sync + Clone> EvmClient<T> {
 	/// Creates a new EVM client instance with a specific transport client
 	pub fn new_with_transport(http_client: T) -> Self {
-		Self { http_client }
+		Self {
+			http_client,
+			tx_chunk_size: None,
+		}
+	}
+
+	/// Sets the maximum number of transactions fetched at once per block
+	///
+	/// When set, blocks are fetched with transaction hashes only, and the transactions are
+	/// fetched in batches of this size while the block is filtered.
+	///
+	/// # Arguments
+	/// * `tx_chunk_size` - Maximum number of transactions per batch, or `None` to fetch
+	///   blocks with all their transactions
+	pub fn with_tx_chunk_size(mut self, tx_chunk_size: Option<usize>) -> Self {
+		self.tx_chunk_size = tx_chunk_size;
+		self
+	}
+}
+
+impl<T: Send + Sync + Clone + BlockchainTransport> EvmClient<T> {
+	/// Calls a method taking a transaction hash for each of the given hashes in a single
+	/// batch request, parsing the result of each call
+	///
+	/// # Arguments
+	/// * `method` - The JSON-RPC method to call
+	/// * `transaction_hashes` - The hash passed to each call
+	///
+	/// # Returns
+	/// * `Result<Vec<R>, anyhow::Error>` - The results in the order of the hashes or error
+	async fn get_batch_results<R: serde::de::DeserializeOwned>(
+		&self,
+		method: &str,
+		transaction_hashes: Vec<String>,
+	) -> Result<Vec<R>, anyhow::Error> {
+		let params = transaction_hashes
+			.iter()
+			.map(|transaction_hash| {
+				let hash = string_to_h256(transaction_hash)
+					.map_err(|e| anyhow::anyhow!("Invalid transaction hash: {}", e))?;
+				Ok(json!([format!("0x{:x}", hash)]))
+			})
+			.collect::<Result<Vec<_>, anyhow::Error>>()?;
+
+		let responses = self.http_client.send_batch_request(method, params).await?;
+		responses
+			.into_iter()
+			.zip(&transaction_hashes)
+			.map(|(response, transaction_hash)| {
+				let result = response
+					.get("result")
+					.with_context(|| format!("Missing 'result' field for {}", transaction_hash))?;
+				if result.is_null() {
+					return Err(anyhow::anyhow!("Not found: {}", transaction_hash));
+				}
+				serde_json::from_value(result.clone())
+					.with_context(|| format!("Failed to parse result for {}", transaction_hash))
+			})
+			.collect()
 	}
 }
 
@@ -50,13 +122,13 @@ impl EvmClient<EVMTransportClient> {
 	/// * `Result<Self, anyhow::Error>` - New client instance or connection error
 	pub async fn new(network: &Network) -> Result<Self, anyhow::Error> {
 		let client = EVMTransportClient::new(network).await?;
-		Ok(Self::new_with_transport(client))
+		Ok(Self::new_with_transport(client).with_tx_chunk_size(network.tx_chunk_size))
 	}
 }
 
 impl<T: Send + Sync + Clone + BlockchainTransport> BlockFilterFactory<Self> for EvmClient<T> {
 	type Filter = EVMBlockFilter<Self>;
-	fn filter() -> Self::Filter {
+	fn filter(&self) -> Self::Filter {
 		EVMBlockFilter {
 			_client: PhantomData,
 		}
@@ -78,12 +150,39 @@ pub trait EvmClientTrait {
 		transaction_hash: String,
 	) -> Result<EVMTransactionReceipt, anyhow::Error>;
 
+	/// Retrieves the receipts of transactions in a single batch request
+	///
+	/// # Arguments
+	/// * `transaction_hashes` - The hashes of the transactions to look up
+	///
+	/// # Returns
+	/// * `Result<Vec<EVMTransactionReceipt>, anyhow::Error>` - Receipts in the order of the
+	///   given hashes or error
+	async fn get_transaction_receipts(
+		&self,
+		transaction_hashes: Vec<String>,
+	) -> Result<Vec<EVMTransactionReceipt>, anyhow::Error>;
+
+	/// Retrieves transactions by their hashes in a single batch request
+	///
+	/// # Arguments
+	/// * `transaction_hashes` - The hashes of the transactions to look up
+	///
+	/// # Returns
+	/// * `Result<Vec<EVMTransaction>, anyhow::Error>` - Transactions in the order of the
+	///   given hashes or error
+	async fn get_transactions(
+		&self,
+		transaction_hashes: Vec<String>,
+	) -> Result<Vec<EVMTransaction>, anyhow::Error>;
+
 	/// Retrieves logs for a range of blocks
 	///
 	/// # Arguments
 	/// * `from_block` - Starting block number
 	/// * `to_block` - Ending block number
 	/// * `addresses` - Optional list of addresses to filter logs by
+	/// * `topics` - Optional list of event topic0 hashes to filter logs by
 	/// # Returns
 	/// * `Result<Vec<Log>, anyhow::Error>` - Collection of matching logs or error
 	async fn get_logs_for_blocks(
@@ -91,7 +190,94 @@ pub trait EvmClientTrait {
 		from_block: u64,
 		to_block: u64,
 		addresses: Option<Vec<String>>,
+		topics: Option<Vec<String>>,
 	) -> Result<Vec<EVMReceiptLog>, anyhow::Error>;
+
+	/// Retrieves the revert reason of a failed transaction
+	///
+	/// # Arguments
+	/// * `transaction` - The failed transaction to replay
+	///
+	/// # Returns
+	/// * `Result<Option<String>, anyhow::Error>` - Decoded revert reason, if any, or error
+	async fn get_revert_reason(
+		&self,
+		transaction: &EVMTransaction,
+	) -> Result<Option<String>, anyhow::Error>;
+
+	/// Resolves an ENS name to the address it points to
+	///
+	/// # Arguments
+	/// * `name` - The ENS name to resolve (e.g. `vitalik.eth`)
+	/// * `registry` - Address of the ENS registry
+	///
+	/// # Returns
+	/// * `Result<Option<String>, anyhow::Error>` - The resolved address, `None` if the name
+	///   has no resolver or address set, or error
+	async fn resolve_ens_name(
+		&self,
+		name: &str,
+		registry: &str,
+	) -> Result<Option<String>, anyhow::Error>;
+}
+
+/// Selector of the ENS registry's `resolver(bytes32)` function
+const ENS_RESOLVER_SELECTOR: [u8; 4] = [0x01, 0x78, 0xb8, 0xbf];
+
+/// Selector of the ENS resolver's `addr(bytes32)` function
+const ENS_ADDR_SELECTOR: [u8; 4] = [0x3b, 0x3b, 0x57, 0xde];
+
+impl<T: Send + Sync + Clone + BlockchainTransport> EvmClient<T> {
+	/// Performs an `eth_call` against the latest block
+	///
+	/// # Arguments
+	/// * `to` - Address of the contract to call
+	/// * `data` - Calldata of the call
+	///
+	/// # Returns
+	/// * `Result<Vec<u8>, anyhow::Error>` - The returned data or error
+	async fn eth_call(&self, to: &str, data: &[u8]) -> Result<Vec<u8>, anyhow::Error> {
+		let params = vec![
+			json!({
+				"to": to,
+				"data": format!("0x{}", hex::encode(data)),
+			}),
+			json!("latest"),
+		];
+
+		let response = self
+			.http_client
+			.send_raw_request("eth_call", Some(params))
+			.await?;
+
+		let result = response
+			.get("result")
+			.and_then(|result| result.as_str())
+			.with_context(|| "Missing 'result' field")?;
+		hex::decode(result.trim_start_matches("0x")).with_context(|| "Failed to decode call result")
+	}
+
+	/// Calls an ENS function taking a node and returning an address
+	///
+	/// # Returns
+	/// * `Result<Option<String>, anyhow::Error>` - The returned address, `None` if it is the
+	///   zero address, or error
+	async fn call_ens_address(
+		&self,
+		to: &str,
+		selector: [u8; 4],
+		node: B256,
+	) -> Result<Option<String>, anyhow::Error> {
+		let data = self
+			.eth_call(to, &[&selector[..], &node[..]].concat())
+			.await?;
+		if data.len() < 32 {
+			return Ok(None);
+		}
+
+		let address = Address::from_slice(&data[12..32]);
+		Ok((!address.is_zero()).then(|| format!("0x{:x}", address)))
+	}
 }
 
 #[async_trait]
@@ -133,30 +319,56 @@ impl<T: Send + Sync + Clone + BlockchainTransport> EvmClientTrait for EvmClient<
 			.with_context(|| "Failed to parse transaction receipt")?)
 	}
 
+	/// Retrieves the receipts of transactions with a single `eth_getTransactionReceipt` batch
+	#[instrument(skip_all, fields(transactions = transaction_hashes.len()))]
+	async fn get_transaction_receipts(
+		&self,
+		transaction_hashes: Vec<String>,
+	) -> Result<Vec<EVMTransactionReceipt>, anyhow::Error> {
+		self.get_batch_results("eth_getTransactionReceipt", transaction_hashes)
+			.await
+			.with_context(|| "Failed to get transaction receipts")
+	}
+
+	/// Retrieves transactions with a single `eth_getTransactionByHash` batch
+	#[instrument(skip_all, fields(transactions = transaction_hashes.len()))]
+	async fn get_transactions(
+		&self,
+		transaction_hashes: Vec<String>,
+	) -> Result<Vec<EVMTransaction>, anyhow::Error> {
+		self.get_batch_results("eth_getTransactionByHash", transaction_hashes)
+			.await
+			.with_context(|| "Failed to get transactions")
+	}
+
 	/// Retrieves logs within the specified block range
 	///
 	/// # Arguments
 	/// * `from_block` - Starting block number
 	/// * `to_block` - Ending block number
 	/// * `addresses` - Optional list of addresses to filter logs by
+	/// * `topics` - Optional list of event topic0 hashes to filter logs by. A log matches
+	///   if its first topic is any of them.
 	/// # Returns
 	/// * `Result<Vec<EVMReceiptLog>, anyhow::Error>` - Collection of matching logs or error
-	#[instrument(skip(self), fields(from_block, to_block))]
+	#[instrument(skip(self, addresses, topics), fields(from_block, to_block))]
 	async fn get_logs_for_blocks(
 		&self,
 		from_block: u64,
 		to_block: u64,
 		addresses: Option<Vec<String>>,
+		topics: Option<Vec<String>>,
 	) -> Result<Vec<EVMReceiptLog>, anyhow::Error> {
 		// Convert parameters to JSON-RPC format
-		let params = json!([{
+		let mut filter = json!({
 			"fromBlock": format!("0x{:x}", from_block),
 			"toBlock": format!("0x{:x}", to_block),
 			"address": addresses
-		}])
-		.as_array()
-		.with_context(|| "Failed to create JSON-RPC params array")?
-		.to_vec();
+		});
+		if let Some(topics) = topics {
+			filter["topics"] = json!([topics]);
+		}
+		let params = vec![filter];
 
 		let response = self
 			.http_client
@@ -177,6 +389,96 @@ impl<T: Send + Sync + Clone + BlockchainTransport> EvmClientTrait for EvmClient<
 		// Parse the response into the expected type
 		Ok(serde_json::from_value(logs_data.clone()).with_context(|| "Failed to parse logs")?)
 	}
+
+	/// Retrieves the revert reason of a failed transaction
+	///
+	/// The transaction is replayed with `eth_call` against the state of its parent block,
+	/// and the returned revert data is decoded as an `Error(string)` or `Panic(uint256)`.
+	/// If the node does not return revert data, the reason is taken from the
+	/// `execution reverted: <reason>` error message when present.
+	///
+	/// # Arguments
+	/// * `transaction` - The failed transaction to replay
+	/// # Returns
+	/// * `Result<Option<String>, anyhow::Error>` - Decoded revert reason, if any, or error
+	#[instrument(skip_all, fields(transaction_hash = %transaction.hash))]
+	async fn get_revert_reason(
+		&self,
+		transaction: &EVMTransaction,
+	) -> Result<Option<String>, anyhow::Error> {
+		let mut call = json!({
+			"data": format!("0x{}", hex::encode(&transaction.input)),
+			"value": format!("0x{:x}", transaction.value),
+			"gas": format!("0x{:x}", transaction.gas),
+		});
+		if let Some(from) = transaction.from {
+			call["from"] = json!(format!("0x{:x}", from));
+		}
+		if let Some(to) = transaction.to {
+			call["to"] = json!(format!("0x{:x}", to));
+		}
+		let block = transaction
+			.block_number
+			.map(|number| number.to::<u64>().saturating_sub(1))
+			.map_or("latest".to_string(), |number| format!("0x{:x}", number));
+		let params = vec![call, json!(block)];
+
+		let response = self
+			.http_client
+			.send_raw_request("eth_call", Some(params))
+			.await
+			.with_context(|| format!("Failed to replay transaction: 0x{:x}", transaction.hash))?;
+
+		let decode_hex = |value: &serde_json::Value| {
+			value
+				.as_str()
+				.and_then(|data| hex::decode(data.trim_start_matches("0x")).ok())
+				.and_then(|data| decode_revert_reason(&data))
+		};
+
+		// Some nodes return the revert data as the call result
+		if let Some(result) = response.get("result") {
+			return Ok(decode_hex(result));
+		}
+
+		let error = response
+			.get("error")
+			.with_context(|| "Missing 'result' and 'error' fields")?;
+		if let Some(reason) = error.get("data").and_then(decode_hex) {
+			return Ok(Some(reason));
+		}
+
+		Ok(error
+			.get("message")
+			.and_then(|message| message.as_str())
+			.and_then(|message| message.strip_prefix("execution reverted: "))
+			.map(|reason| reason.to_string()))
+	}
+
+	/// Resolves an ENS name by looking up its resolver in the registry, then its address in
+	/// the resolver
+	#[instrument(skip(self))]
+	async fn resolve_ens_name(
+		&self,
+		name: &str,
+		registry: &str,
+	) -> Result<Option<String>, anyhow::Error> {
+		let node = ens_namehash(name);
+
+		// Calls `resolver(bytes32)` on the registry
+		let resolver = self
+			.call_ens_address(registry, ENS_RESOLVER_SELECTOR, node)
+			.await
+			.with_context(|| format!("Failed to get ENS resolver of {}", name))?;
+		let Some(resolver) = resolver else {
+			return Ok(None);
+		};
+
+		// Calls `addr(bytes32)` on the resolver
+		self.call_ens_address(&resolver, ENS_ADDR_SELECTOR, node)
+			.await
+			.with_context(|| format!("Failed to get ENS address of {}", name))
+	}
 }
 
 #[async_trait]
@@ -201,6 +503,64 @@ impl<T: Send + Sync + Clone + BlockchainTransport> BlockChainClient for EvmClien
 			.map_err(|e| anyhow::anyhow!("Failed to parse block number: {}", e))
 	}
 
+	/// Retrieves the number of the latest block with the given tag with `eth_getBlockByNumber`
+	///
+	/// Nodes that do not support the tag reply with a JSON-RPC error or a null block, which are
+	/// both reported as an unsupported tag.
+	#[instrument(skip(self))]
+	async fn get_block_number_by_tag(&self, tag: &str) -> Result<Option<u64>, anyhow::Error> {
+		let params = json!([tag, false]);
+		let response = self
+			.http_client
+			.send_raw_request("eth_getBlockByNumber", Some(params))
+			.await
+			.with_context(|| format!("Failed to get {} block", tag))?;
+
+		if let Some(error) = response.get("error") {
+			tracing::debug!("Block tag '{}' is not supported: {}", tag, error);
+			return Ok(None);
+		}
+		let block_data = response
+			.get("result")
+			.with_context(|| "Missing 'result' field")?;
+		if block_data.is_null() {
+			return Ok(None);
+		}
+
+		let hex_str = block_data
+			.get("number")
+			.and_then(|number| number.as_str())
+			.with_context(|| format!("Missing number of {} block", tag))?;
+		u64::from_str_radix(hex_str.trim_start_matches("0x"), 16)
+			.map(Some)
+			.map_err(|e| anyhow::anyhow!("Failed to parse block number: {}", e))
+	}
+
+	/// Calls a contract function taking no arguments with `eth_call`, decoding the result as
+	/// the given Solidity type
+	#[instrument(skip(self))]
+	async fn call_contract_function(
+		&self,
+		contract_id: &str,
+		function: &str,
+		output: Option<&str>,
+	) -> Result<String, anyhow::Error> {
+		let output = output.with_context(|| format!("Missing output type of {}", function))?;
+		let output_type = DynSolType::parse(output)
+			.with_context(|| format!("Invalid output type of {}: {}", function, output))?;
+
+		let selector = keccak256(function.as_bytes());
+		let data = self
+			.eth_call(contract_id, &selector[..4])
+			.await
+			.with_context(|| format!("Failed to call {} on {}", function, contract_id))?;
+
+		let value = output_type
+			.abi_decode(&data)
+			.with_context(|| format!("Failed to decode result of {}", function))?;
+		Ok(format_token_value(&value))
+	}
+
 	/// Retrieves blocks within the specified range with retry functionality
 	///
 	/// # Note
@@ -211,12 +571,12 @@ impl<T: Send + Sync + Clone + BlockchainTransport> BlockChainClient for EvmClien
 		start_block: u64,
 		end_block: Option<u64>,
 	) -> Result<Vec<BlockType>, anyhow::Error> {
+		// Without a chunk size, blocks include their full transaction objects. Otherwise only
+		// the transaction hashes are included, and the filter fetches the transactions in chunks
+		let full_transactions = self.tx_chunk_size.is_none();
 		let block_futures: Vec<_> = (start_block..=end_block.unwrap_or(start_block))
 			.map(|block_number| {
-				let params = json!([
-					format!("0x{:x}", block_number),
-					true // include full transaction objects
-				]);
+				let params = json!([format!("0x{:x}", block_number), full_transactions]);
 				let client = self.http_client.clone();
 
 				async move {
@@ -233,8 +593,12 @@ impl<T: Send + Sync + Clone + BlockchainTransport> BlockChainClient for EvmClien
 						return Err(anyhow::anyhow!("Block not found"));
 					}
 
-					let block: EVMBlock = serde_json::from_value(block_data.clone())
-						.map_err(|e| anyhow::anyhow!("Failed to parse block: {}", e))?;
+					let block = if full_transactions {
+						serde_json::from_value(block_data.clone())
+							.map_err(|e| anyhow::anyhow!("Failed to parse block: {}", e))?
+					} else {
+						parse_block_with_hashes(block_data.clone())?
+					};
 
 					Ok(BlockType::EVM(Box::new(block)))
 				}
@@ -247,3 +611,32 @@ impl<T: Send + Sync + Clone + BlockchainTransport> BlockChainClient for EvmClien
 			.collect::<Result<Vec<_>, _>>()
 	}
 }
+
+/// Parses a block fetched with transaction hashes only
+///
+/// The transactions of the returned block only have their hash set, and are fetched with
+/// [`EvmClientTrait::get_transactions`] when the block is filtered.
+///
+/// # Arguments
+/// * `block_data` - The `eth_getBlockByNumber` result without full transaction objects
+///
+/// # Returns
+/// * `Result<EVMBlock, anyhow::Error>` - The parsed block or error
+fn parse_block_with_hashes(mut block_data: serde_json::Value) -> Result<EVMBlock, anyhow::Error> {
+	let hashes: Vec<B256> = serde_json::from_value(block_data["transactions"].take())
+		.map_err(|e| anyhow::anyhow!("Failed to parse block transaction hashes: {}", e))?;
+	block_data["transactions"] = json!([]);
+
+	let mut block: EVMBlock = serde_json::from_value(block_data)
+		.map_err(|e| anyhow::anyhow!("Failed to parse block: {}", e))?;
+	block.0.transactions = hashes
+		.into_iter()
+		.map(|hash| {
+			EVMTransaction::from(EVMBaseTransaction {
+				hash,
+				..Default::default()
+			})
+		})
+		.collect();
+	Ok(block)
+}
@@ -0,0 +1,143 @@
+//! Midnight blockchain client implementation.
+//!
+//! Midnight support is not implemented yet. This client provides the scaffolding
+//! for it and reports every operation as unsupported instead of panicking, so a
+//! Midnight network configured by mistake cannot bring the service down.
+
+use async_trait::async_trait;
+use std::{collections::HashMap, marker::PhantomData};
+
+use crate::{
+	models::{BlockType, ContractSpec, Network},
+	services::{
+		blockchain::{
+			client::{BlockChainClient, BlockFilterFactory},
+			BlockChainError,
+		},
+		filter::MidnightBlockFilter,
+	},
+};
+
+/// Client implementation for the Midnight blockchain
+///
+/// Every operation currently fails with `BlockChainError::UnsupportedOperation`.
+#[derive(Clone)]
+pub struct MidnightClient {
+	/// Slug of the network this client was created for
+	network_slug: String,
+}
+
+impl MidnightClient {
+	/// Creates a new Midnight client instance
+	///
+	/// # Arguments
+	/// * `network` - Network configuration the client is created for
+	pub fn new(network: &Network) -> Self {
+		Self {
+			network_slug: network.slug.clone(),
+		}
+	}
+
+	/// Builds the error returned by every operation of this client
+	fn unsupported(&self, operation: &str) -> BlockChainError {
+		BlockChainError::unsupported_operation(
+			format!("Midnight networks are not supported yet ({})", operation),
+			None,
+			Some(HashMap::from([(
+				"network".to_string(),
+				self.network_slug.clone(),
+			)])),
+		)
+	}
+}
+
+/// Extended functionality specific to the Midnight blockchain
+#[async_trait]
+pub trait MidnightClientTrait {
+	/// Retrieves the raw transactions contained in a range of blocks
+	///
+	/// # Arguments
+	/// * `start_block` - The starting block number
+	/// * `end_block` - Optional ending block number
+	///
+	/// # Returns
+	/// * `Result<Vec<serde_json::Value>, anyhow::Error>` - Collection of transactions or error
+	async fn get_transactions(
+		&self,
+		start_block: u64,
+		end_block: Option<u64>,
+	) -> Result<Vec<serde_json::Value>, anyhow::Error>;
+}
+
+#[async_trait]
+impl MidnightClientTrait for MidnightClient {
+	async fn get_transactions(
+		&self,
+		_start_block: u64,
+		_end_block: Option<u64>,
+	) -> Result<Vec<serde_json::Value>, anyhow::Error> {
+		Err(self.unsupported("get_transactions").into())
+	}
+}
+
+impl BlockFilterFactory<Self> for MidnightClient {
+	type Filter = MidnightBlockFilter<Self>;
+
+	fn filter(&self) -> Self::Filter {
+		MidnightBlockFilter {
+			_client: PhantomData {},
+		}
+	}
+}
+
+#[async_trait]
+impl BlockChainClient for MidnightClient {
+	async fn get_latest_block_number(&self) -> Result<u64, anyhow::Error> {
+		Err(self.unsupported("get_latest_block_number").into())
+	}
+
+	async fn get_blocks(
+		&self,
+		_start_block: u64,
+		_end_block: Option<u64>,
+	) -> Result<Vec<BlockType>, anyhow::Error> {
+		Err(self.unsupported("get_blocks").into())
+	}
+
+	async fn get_contract_spec(&self, _contract_id: &str) -> Result<ContractSpec, anyhow::Error> {
+		Err(self.unsupported("get_contract_spec").into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{models::BlockChainType, utils::tests::builders::network::NetworkBuilder};
+
+	fn create_client() -> MidnightClient {
+		let network = NetworkBuilder::new()
+			.name("Midnight")
+			.slug("midnight_testnet")
+			.network_type(BlockChainType::Midnight)
+			.build();
+		MidnightClient::new(&network)
+	}
+
+	fn assert_unsupported(error: anyhow::Error) {
+		let error = error
+			.downcast::<BlockChainError>()
+			.expect("expected a BlockChainError");
+		assert!(matches!(error, BlockChainError::UnsupportedOperation(_)));
+		assert!(error.to_string().contains("network=midnight_testnet"));
+	}
+
+	#[tokio::test]
+	async fn test_operations_return_unsupported_operation() {
+		let client = create_client();
+
+		assert_unsupported(client.get_latest_block_number().await.unwrap_err());
+		assert_unsupported(client.get_blocks(1, Some(10)).await.unwrap_err());
+		assert_unsupported(client.get_contract_spec("contract").await.unwrap_err());
+		assert_unsupported(client.get_transactions(1, None).await.unwrap_err());
+	}
+}
@@ -3,15 +3,20 @@
 //! Contains specific implementations for different blockchain types:
 //! - EVM client for Ethereum-compatible chains
 //! - Stellar client for Stellar network
+//! - Midnight client scaffolding (operations are not supported yet)
 
 mod evm {
 	pub mod client;
 }
+mod midnight {
+	pub mod client;
+}
 mod stellar {
 	pub mod client;
 	pub mod error;
 }
 
 pub use evm::client::{EvmClient, EvmClientTrait};
+pub use midnight::client::{MidnightClient, MidnightClientTrait};
 pub use stellar::client::{StellarClient, StellarClientTrait};
 pub use stellar::error::StellarClientError;
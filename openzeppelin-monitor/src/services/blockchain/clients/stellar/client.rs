@@ -5,15 +5,15 @@
 
 use anyhow::Context;
 use async_trait::async_trait;
+use base64::Engine;
 use serde_json::json;
-use std::marker::PhantomData;
 use stellar_xdr::curr::{Limits, WriteXdr};
 use tracing::instrument;
 
 use crate::{
 	models::{
 		BlockType, ContractSpec, Network, StellarBlock, StellarContractSpec, StellarEvent,
-		StellarTransaction, StellarTransactionInfo,
+		StellarEventFilter, StellarTransaction, StellarTransactionInfo,
 	},
 	services::{
 		blockchain::{
@@ -23,10 +23,12 @@ use crate::{
 		},
 		filter::{
 			stellar_helpers::{
-				get_contract_code_ledger_key, get_contract_instance_ledger_key, get_contract_spec,
+				get_contract_call_transaction_envelope, get_contract_code_ledger_key,
+				get_contract_instance_ledger_key, get_contract_spec,
 				get_wasm_code_from_ledger_entry_data, get_wasm_hash_from_ledger_entry_data,
+				parse_xdr_value,
 			},
-			StellarBlockFilter,
+			ContractSpecCache, StellarBlockFilter,
 		},
 	},
 };
@@ -39,6 +41,10 @@ const RPC_METHOD_GET_EVENTS: &str = "getEvents";
 const RPC_METHOD_GET_LATEST_LEDGER: &str = "getLatestLedger";
 const RPC_METHOD_GET_LEDGERS: &str = "getLedgers";
 const RPC_METHOD_GET_LEDGER_ENTRIES: &str = "getLedgerEntries";
+const RPC_METHOD_SIMULATE_TRANSACTION: &str = "simulateTransaction";
+
+/// Maximum number of ledgers the RPC serves in a single `getEvents` request
+const MAX_EVENTS_LEDGER_RANGE: u32 = 10_000;
 
 const RETENTION_MESSAGES: [&str; 2] = [
 	"must be within the ledger range",
@@ -52,12 +58,26 @@ const RETENTION_MESSAGES: [&str; 2] = [
 pub struct StellarClient<T: Send + Sync + Clone> {
 	/// The underlying Stellar transport client for RPC communication
 	http_client: T,
+	/// Contract specs refreshed from chain, shared by the filters created by the client
+	contract_spec_cache: ContractSpecCache,
 }
 
 impl<T: Send + Sync + Clone> StellarClient<T> {
 	/// Creates a new Stellar client instance with a specific transport client
 	pub fn new_with_transport(http_client: T) -> Self {
-		Self { http_client }
+		Self {
+			http_client,
+			contract_spec_cache: ContractSpecCache::default(),
+		}
+	}
+
+	/// Replaces the cache of contract specs refreshed from chain
+	///
+	/// # Arguments
+	/// * `contract_spec_cache` - Cache shared by the filters created by the client
+	pub fn with_contract_spec_cache(mut self, contract_spec_cache: ContractSpecCache) -> Self {
+		self.contract_spec_cache = contract_spec_cache;
+		self
 	}
 
 	/// Checks a JSON-RPC response for error information and converts it into a `StellarClientError` if present.
@@ -162,6 +182,8 @@ pub trait StellarClientTrait {
 	/// # Arguments
 	/// * `start_sequence` - Starting sequence number
 	/// * `end_sequence` - Optional ending sequence number. If None, only fetches start_sequence
+	/// * `filters` - Contract id and topic filters applied by the RPC. If empty, all contract
+	///   events are returned
 	///
 	/// # Returns
 	/// * `Result<Vec<StellarEvent>, anyhow::Error>` - Collection of events or error
@@ -169,6 +191,7 @@ pub trait StellarClientTrait {
 		&self,
 		start_sequence: u32,
 		end_sequence: Option<u32>,
+		filters: &[StellarEventFilter],
 	) -> Result<Vec<StellarEvent>, anyhow::Error>;
 }
 
@@ -315,14 +338,20 @@ impl<T: Send + Sync + Clone + BlockchainTransport> StellarClientTrait for Stella
 
 	/// Retrieves events within a sequence range with pagination
 	///
+	/// The range is split into windows of at most 10,000 ledgers, the
+	/// maximum range the RPC serves in a single `getEvents` request, and each window is paged
+	/// through with its cursor.
+	///
 	/// # Errors
 	/// - Returns `anyhow::Error` if start_sequence > end_sequence
+	/// - Returns `anyhow::Error` if the filters exceed the RPC limits
 	/// - Returns `anyhow::Error` if event parsing fails
-	#[instrument(skip(self), fields(start_sequence, end_sequence))]
+	#[instrument(skip(self, filters), fields(start_sequence, end_sequence))]
 	async fn get_events(
 		&self,
 		start_sequence: u32,
 		end_sequence: Option<u32>,
+		filters: &[StellarEventFilter],
 	) -> Result<Vec<StellarEvent>, anyhow::Error> {
 		// Validate input parameters
 		if let Some(end_sequence) = end_sequence {
@@ -336,132 +365,207 @@ impl<T: Send + Sync + Clone + BlockchainTransport> StellarClientTrait for Stella
 					.context("Invalid input parameters for Stellar RPC");
 			}
 		}
+		if let Some(message) = validate_event_filters(filters) {
+			let input_error = StellarClientError::invalid_input(message, None, None);
+			return Err(anyhow::anyhow!(input_error))
+				.context("Invalid input parameters for Stellar RPC");
+		}
 
 		// max limit for the RPC endpoint is 200
 		const PAGE_LIMIT: u32 = 200;
 		let mut events = Vec::new();
 		let target_sequence = end_sequence.unwrap_or(start_sequence);
-		let mut cursor: Option<String> = None;
-		let mut current_iteration = 0;
-
-		while cursor.is_some() || current_iteration <= 0 {
-			let params = if current_iteration == 0 {
-				// First iteration, we need to fetch the events from the start sequence without a cursor
-				json!({
-					"startLedger": start_sequence,
-					"filters": [{
-						"type": "contract",
-					}],
-					"pagination": {
-						"limit": PAGE_LIMIT
-					}
-				})
-			} else {
-				// Subsequent iterations, we need to fetch the events from the cursor
-				json!({
-					"filters": [{
-						"type": "contract",
-					}],
-					"pagination": {
-						"cursor": cursor,
-						"limit": PAGE_LIMIT
-					}
-				})
-			};
-
-			let http_response = self
-				.http_client
-				.send_raw_request(RPC_METHOD_GET_EVENTS, Some(params))
-				.await;
-
-			match http_response {
-				Ok(response_body) => {
-					// Check for RPC errors in the response
-					if let Err(rpc_error) = self.check_and_handle_rpc_error(
-						&response_body,
-						start_sequence,
-						target_sequence,
-						RPC_METHOD_GET_EVENTS,
-					) {
-						// A terminal JSON-RPC error was found, convert and return
-						return Err(anyhow::anyhow!(rpc_error).context(format!(
-							"Soroban RPC reported an error during {}",
-							RPC_METHOD_GET_EVENTS
-						)));
-					}
-
-					// Extract the events from the response
-					let raw_events = response_body
-						.get("result")
-						.and_then(|r| r.get("events"))
-						.ok_or_else(|| {
-							let message = format!(
-								"Unexpected response structure for method '{}'",
+		let rpc_filters = event_filters_to_rpc(filters);
+		let mut window_start = start_sequence;
+
+		loop {
+			let window_end =
+				target_sequence.min(window_start.saturating_add(MAX_EVENTS_LEDGER_RANGE - 1));
+			let mut cursor: Option<String> = None;
+			let mut current_iteration = 0;
+
+			while cursor.is_some() || current_iteration <= 0 {
+				let params = if current_iteration == 0 {
+					// First iteration, we need to fetch the events from the start of the window
+					// without a cursor
+					json!({
+						"startLedger": window_start,
+						"endLedger": window_end.saturating_add(1),
+						"filters": rpc_filters,
+						"pagination": {
+							"limit": PAGE_LIMIT
+						}
+					})
+				} else {
+					// Subsequent iterations, we need to fetch the events from the cursor
+					json!({
+						"endLedger": window_end.saturating_add(1),
+						"filters": rpc_filters,
+						"pagination": {
+							"cursor": cursor,
+							"limit": PAGE_LIMIT
+						}
+					})
+				};
+
+				let http_response = self
+					.http_client
+					.send_raw_request(RPC_METHOD_GET_EVENTS, Some(params))
+					.await;
+
+				match http_response {
+					Ok(response_body) => {
+						// Check for RPC errors in the response
+						if let Err(rpc_error) = self.check_and_handle_rpc_error(
+							&response_body,
+							start_sequence,
+							target_sequence,
+							RPC_METHOD_GET_EVENTS,
+						) {
+							// A terminal JSON-RPC error was found, convert and return
+							return Err(anyhow::anyhow!(rpc_error).context(format!(
+								"Soroban RPC reported an error during {}",
 								RPC_METHOD_GET_EVENTS
-							);
-							StellarClientError::unexpected_response_structure(message, None, None)
-						})
-						.map_err(|client_parse_error| {
-							anyhow::anyhow!(client_parse_error)
-								.context("Failed to parse event response")
-						})?;
+							)));
+						}
 
-					let ledger_events: Vec<StellarEvent> =
-						serde_json::from_value(raw_events.clone()).map_err(|e| {
-							let message = format!(
-								"Failed to parse events from response for method '{}': {}",
-								RPC_METHOD_GET_EVENTS, e
-							);
-							let sce_parse_error = StellarClientError::response_parse_error(
-								message,
-								Some(e.into()),
-								None,
-							);
-							anyhow::anyhow!(sce_parse_error)
-								.context("Failed to parse event response")
-						})?;
+						// Extract the events from the response
+						let raw_events = response_body
+							.get("result")
+							.and_then(|r| r.get("events"))
+							.ok_or_else(|| {
+								let message = format!(
+									"Unexpected response structure for method '{}'",
+									RPC_METHOD_GET_EVENTS
+								);
+								StellarClientError::unexpected_response_structure(
+									message, None, None,
+								)
+							})
+							.map_err(|client_parse_error| {
+								anyhow::anyhow!(client_parse_error)
+									.context("Failed to parse event response")
+							})?;
+
+						let ledger_events: Vec<StellarEvent> =
+							serde_json::from_value(raw_events.clone()).map_err(|e| {
+								let message = format!(
+									"Failed to parse events from response for method '{}': {}",
+									RPC_METHOD_GET_EVENTS, e
+								);
+								let sce_parse_error = StellarClientError::response_parse_error(
+									message,
+									Some(e.into()),
+									None,
+								);
+								anyhow::anyhow!(sce_parse_error)
+									.context("Failed to parse event response")
+							})?;
+
+						let mut window_exhausted = false;
+						for event in ledger_events {
+							if event.ledger > window_end {
+								window_exhausted = true;
+								break;
+							}
+							events.push(event);
+						}
+						if window_exhausted {
+							break;
+						}
 
-					for event in ledger_events {
-						if event.ledger > target_sequence {
-							return Ok(events);
+						// Increment the number of iterations to ensure we break the loop in case there is no cursor
+						current_iteration += 1;
+						cursor = response_body["result"]["cursor"]
+							.as_str()
+							.map(|s| s.to_string());
+						if cursor.is_none() {
+							break;
 						}
-						events.push(event);
 					}
-
-					// Increment the number of iterations to ensure we break the loop in case there is no cursor
-					current_iteration += 1;
-					cursor = response_body["result"]["cursor"]
-						.as_str()
-						.map(|s| s.to_string());
-					if cursor.is_none() {
-						break;
+					Err(transport_err) => {
+						// Ledger info for logging
+						let ledger_info = format!(
+							"start_sequence: {}, end_sequence: {:?}",
+							start_sequence, end_sequence
+						);
+
+						return Err(anyhow::anyhow!(transport_err)).context(format!(
+							"Failed to {} from Stellar RPC for ledger: {}",
+							RPC_METHOD_GET_EVENTS, ledger_info,
+						));
 					}
 				}
-				Err(transport_err) => {
-					// Ledger info for logging
-					let ledger_info = format!(
-						"start_sequence: {}, end_sequence: {:?}",
-						start_sequence, end_sequence
-					);
+			}
 
-					return Err(anyhow::anyhow!(transport_err)).context(format!(
-						"Failed to {} from Stellar RPC for ledger: {}",
-						RPC_METHOD_GET_EVENTS, ledger_info,
-					));
-				}
+			if window_end >= target_sequence {
+				break;
 			}
+			window_start = window_end + 1;
 		}
 		Ok(events)
 	}
 }
 
+/// Checks event filters against the limits of the `getEvents` RPC method
+///
+/// # Returns
+/// * `Option<String>` - A message describing the first exceeded limit, if any
+fn validate_event_filters(filters: &[StellarEventFilter]) -> Option<String> {
+	if filters.len() > StellarEventFilter::MAX_FILTERS {
+		return Some(format!(
+			"At most {} event filters are supported, got {}",
+			StellarEventFilter::MAX_FILTERS,
+			filters.len()
+		));
+	}
+	filters.iter().find_map(|filter| {
+		if filter.contract_ids.len() > StellarEventFilter::MAX_ENTRIES {
+			Some(format!(
+				"At most {} contract ids are supported per event filter, got {}",
+				StellarEventFilter::MAX_ENTRIES,
+				filter.contract_ids.len()
+			))
+		} else if filter.topics.len() > StellarEventFilter::MAX_ENTRIES {
+			Some(format!(
+				"At most {} topic filters are supported per event filter, got {}",
+				StellarEventFilter::MAX_ENTRIES,
+				filter.topics.len()
+			))
+		} else {
+			None
+		}
+	})
+}
+
+/// Converts event filters to the `filters` parameter of the `getEvents` RPC method
+///
+/// Without any filter, all contract events are requested.
+fn event_filters_to_rpc(filters: &[StellarEventFilter]) -> serde_json::Value {
+	if filters.is_empty() {
+		return json!([{ "type": "contract" }]);
+	}
+
+	filters
+		.iter()
+		.map(|filter| {
+			let mut rpc_filter = json!({ "type": "contract" });
+			if !filter.contract_ids.is_empty() {
+				rpc_filter["contractIds"] = json!(filter.contract_ids);
+			}
+			if !filter.topics.is_empty() {
+				rpc_filter["topics"] = json!(filter.topics);
+			}
+			rpc_filter
+		})
+		.collect()
+}
+
 impl<T: Send + Sync + Clone + BlockchainTransport> BlockFilterFactory<Self> for StellarClient<T> {
 	type Filter = StellarBlockFilter<Self>;
 
-	fn filter() -> Self::Filter {
-		StellarBlockFilter {
-			_client: PhantomData {},
-		}
+	fn filter(&self) -> Self::Filter {
+		StellarBlockFilter::with_contract_spec_cache(self.contract_spec_cache.clone())
 	}
 }
 
@@ -713,4 +817,56 @@ impl<T: Send + Sync + Clone + BlockchainTransport> BlockChainClient for StellarC
 			contract_spec,
 		)))
 	}
+
+	/// Calls a contract function taking no arguments by simulating its invocation
+	///
+	/// # Arguments
+	/// * `contract_id` - The ID of the contract to call
+	/// * `function` - The name of the function to call
+	/// * `_output` - Unused, as Stellar results are self-describing
+	///
+	/// # Returns
+	/// * `Result<String, anyhow::Error>` - The decoded result or error
+	#[instrument(skip(self))]
+	async fn call_contract_function(
+		&self,
+		contract_id: &str,
+		function: &str,
+		_output: Option<&str>,
+	) -> Result<String, anyhow::Error> {
+		let transaction_xdr = get_contract_call_transaction_envelope(contract_id, function)?
+			.to_xdr_base64(Limits::none())
+			.map_err(|e| anyhow::anyhow!("Failed to convert transaction to XDR: {}", e))?;
+
+		let params = json!({
+			"transaction": transaction_xdr,
+			"xdrFormat": "base64"
+		});
+
+		let response = self
+			.http_client
+			.send_raw_request(RPC_METHOD_SIMULATE_TRANSACTION, Some(params))
+			.await
+			.with_context(|| format!("Failed to call {} on {}", function, contract_id))?;
+
+		if let Some(error) = response["result"]["error"].as_str() {
+			return Err(anyhow::anyhow!(
+				"Failed to simulate {} on {}: {}",
+				function,
+				contract_id,
+				error
+			));
+		}
+
+		let result_xdr = response["result"]["results"][0]["xdr"]
+			.as_str()
+			.ok_or_else(|| anyhow::anyhow!("Failed to get simulation result XDR"))?;
+		let result_bytes = base64::engine::general_purpose::STANDARD
+			.decode(result_xdr)
+			.map_err(|e| anyhow::anyhow!("Failed to decode simulation result: {}", e))?;
+
+		parse_xdr_value(&result_bytes, false)
+			.map(|entry| entry.value)
+			.ok_or_else(|| anyhow::anyhow!("Failed to parse result of {}", function))
+	}
 }
@@ -35,6 +35,10 @@ pub enum BlockChainError {
 	#[error("Client pool error: {0}")]
 	ClientPoolError(ErrorContext),
 
+	/// Operations that are not supported for the blockchain type
+	#[error("Unsupported operation: {0}")]
+	UnsupportedOperation(ErrorContext),
+
 	/// Other errors that don't fit into the categories above
 	#[error(transparent)]
 	Other(#[from] anyhow::Error),
@@ -94,6 +98,15 @@ impl BlockChainError {
 	) -> Self {
 		Self::ClientPoolError(ErrorContext::new_with_log(msg, source, metadata))
 	}
+
+	// Unsupported operation
+	pub fn unsupported_operation(
+		msg: impl Into<String>,
+		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+		metadata: Option<HashMap<String, String>>,
+	) -> Self {
+		Self::UnsupportedOperation(ErrorContext::new_with_log(msg, source, metadata))
+	}
 }
 
 impl TraceableError for BlockChainError {
@@ -105,6 +118,7 @@ impl TraceableError for BlockChainError {
 			Self::TransactionError(ctx) => ctx.trace_id.clone(),
 			Self::InternalError(ctx) => ctx.trace_id.clone(),
 			Self::ClientPoolError(ctx) => ctx.trace_id.clone(),
+			Self::UnsupportedOperation(ctx) => ctx.trace_id.clone(),
 			Self::Other(_) => Uuid::new_v4().to_string(),
 		}
 	}
@@ -211,6 +225,26 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_unsupported_operation_formatting() {
+		let error = BlockChainError::unsupported_operation("test error", None, None);
+		assert_eq!(error.to_string(), "Unsupported operation: test error");
+
+		let error = BlockChainError::unsupported_operation(
+			"test error",
+			None,
+			Some(HashMap::from([(
+				"network".to_string(),
+				"midnight".to_string(),
+			)])),
+		);
+		assert_eq!(
+			error.to_string(),
+			"Unsupported operation: test error [network=midnight]"
+		);
+		assert!(!error.trace_id().is_empty());
+	}
+
 	#[test]
 	fn test_from_anyhow_error() {
 		let anyhow_error = anyhow::anyhow!("test anyhow error");
@@ -17,11 +17,13 @@ mod transports;
 
 pub use client::{BlockChainClient, BlockFilterFactory};
 pub use clients::{
-	EvmClient, EvmClientTrait, StellarClient, StellarClientError, StellarClientTrait,
+	EvmClient, EvmClientTrait, MidnightClient, MidnightClientTrait, StellarClient,
+	StellarClientError, StellarClientTrait,
 };
 pub use error::BlockChainError;
-pub use pool::{ClientPool, ClientPoolTrait};
+pub use pool::{ClientPool, ClientPoolConfig, ClientPoolTrait};
 pub use transports::{
 	BlockchainTransport, EVMTransportClient, EndpointManager, HttpTransportClient,
-	RotatingTransport, StellarTransportClient, TransientErrorRetryStrategy, TransportError,
+	RotatingTransport, StellarGraphQLTransport, StellarTransportClient,
+	TransientErrorRetryStrategy, TransportError, GRAPHQL_URL_TYPE,
 };
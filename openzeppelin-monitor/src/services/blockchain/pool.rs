@@ -6,6 +6,7 @@
 //! - Handles both EVM and Stellar clients
 //! - Provides type-safe access to clients
 //! - Manages client lifecycles automatically
+//! - Optionally bounds the number of cached clients and evicts idle ones
 //!
 //! The pool uses a fast path for existing clients and a slow path for
 //! creating new ones, optimizing performance while maintaining safety.
@@ -21,7 +22,60 @@ use crate::{
 use anyhow::Context;
 use async_trait::async_trait;
 use futures::future::BoxFuture;
-use std::{any::Any, collections::HashMap, sync::Arc};
+use std::{
+	any::Any,
+	collections::HashMap,
+	env,
+	sync::{Arc, Mutex},
+	time::{Duration, Instant},
+};
+
+/// Environment variable limiting the number of cached clients per blockchain type
+pub const CLIENT_POOL_MAX_CLIENTS_ENV: &str = "CLIENT_POOL_MAX_CLIENTS";
+
+/// Environment variable setting how long an unused client stays cached, in seconds
+pub const CLIENT_POOL_IDLE_TIMEOUT_ENV: &str = "CLIENT_POOL_IDLE_TIMEOUT_SECS";
+
+/// Limits applied to the clients cached by a [`ClientPool`].
+///
+/// The default configuration caches clients indefinitely and without bound.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClientPoolConfig {
+	/// Maximum number of clients cached per blockchain type. When the limit is
+	/// reached, the least recently used client is evicted to make room.
+	pub max_clients: Option<usize>,
+	/// Time after which a client that has not been requested is evicted.
+	/// Evicted clients are recreated on their next access.
+	pub idle_ttl: Option<Duration>,
+}
+
+impl ClientPoolConfig {
+	/// Reads the pool configuration from the environment.
+	///
+	/// Uses `CLIENT_POOL_MAX_CLIENTS` and `CLIENT_POOL_IDLE_TIMEOUT_SECS`. Unset
+	/// variables leave the corresponding limit disabled.
+	pub fn from_env() -> Result<Self, anyhow::Error> {
+		Ok(Self {
+			max_clients: parse_positive_env(CLIENT_POOL_MAX_CLIENTS_ENV)?
+				.map(|value| value as usize),
+			idle_ttl: parse_positive_env(CLIENT_POOL_IDLE_TIMEOUT_ENV)?.map(Duration::from_secs),
+		})
+	}
+}
+
+fn parse_positive_env(name: &str) -> Result<Option<u64>, anyhow::Error> {
+	let Ok(value) = env::var(name) else {
+		return Ok(None);
+	};
+	match value.trim().parse::<u64>() {
+		Ok(parsed) if parsed > 0 => Ok(Some(parsed)),
+		_ => Err(anyhow::anyhow!(
+			"Invalid {} value '{}': expected a positive integer",
+			name,
+			value
+		)),
+	}
+}
 
 /// Trait for the client pool.
 #[async_trait]
@@ -49,6 +103,10 @@ pub trait ClientPoolTrait: Send + Sync {
 pub struct ClientPool {
 	/// Map of client storages indexed by client type
 	pub storages: HashMap<BlockChainType, Box<dyn Any + Send + Sync>>,
+	/// Limits applied to the cached clients
+	config: ClientPoolConfig,
+	/// Last time each cached client was handed out, keyed by client type and network slug
+	last_used: Mutex<HashMap<(BlockChainType, String), Instant>>,
 }
 
 impl ClientPool {
@@ -56,8 +114,15 @@ impl ClientPool {
 	///
 	/// Initializes empty hashmaps for both EVM and Stellar clients.
 	pub fn new() -> Self {
+		Self::with_config(ClientPoolConfig::default())
+	}
+
+	/// Creates a new empty client pool with the given limits.
+	pub fn with_config(config: ClientPoolConfig) -> Self {
 		let mut pool = Self {
 			storages: HashMap::new(),
+			config,
+			last_used: Mutex::new(HashMap::new()),
 		};
 
 		// Register client types
@@ -67,11 +132,87 @@ impl ClientPool {
 		pool
 	}
 
+	/// Returns the limits applied to this pool.
+	pub fn config(&self) -> ClientPoolConfig {
+		self.config
+	}
+
 	fn register_client_type<T: 'static + Send + Sync>(&mut self, client_type: BlockChainType) {
 		self.storages
 			.insert(client_type, Box::new(ClientStorage::<T>::new()));
 	}
 
+	fn touch(&self, client_type: &BlockChainType, slug: &str) {
+		self.last_used
+			.lock()
+			.unwrap_or_else(|e| e.into_inner())
+			.insert((client_type.clone(), slug.to_string()), Instant::now());
+	}
+
+	/// Returns the slugs of clients of the given type that have been idle longer than the TTL.
+	fn expired_slugs(&self, client_type: &BlockChainType) -> Vec<String> {
+		let Some(ttl) = self.config.idle_ttl else {
+			return Vec::new();
+		};
+		let now = Instant::now();
+		self.last_used
+			.lock()
+			.unwrap_or_else(|e| e.into_inner())
+			.iter()
+			.filter(|((ty, _), used)| ty == client_type && now.duration_since(**used) >= ttl)
+			.map(|((_, slug), _)| slug.clone())
+			.collect()
+	}
+
+	/// Removes clients of the given type that have been idle longer than the TTL.
+	///
+	/// Must be called while holding the storage write lock so that no concurrent
+	/// request can refresh a client between the expiry check and its removal.
+	fn evict_expired<T>(
+		&self,
+		client_type: &BlockChainType,
+		clients: &mut HashMap<String, Arc<T>>,
+	) {
+		let expired = self.expired_slugs(client_type);
+		if expired.is_empty() {
+			return;
+		}
+		let mut last_used = self.last_used.lock().unwrap_or_else(|e| e.into_inner());
+		for slug in expired {
+			clients.remove(&slug);
+			last_used.remove(&(client_type.clone(), slug.clone()));
+			tracing::debug!("Evicted idle {:?} client for network {}", client_type, slug);
+		}
+	}
+
+	/// Removes least recently used clients until there is room for one more client.
+	fn evict_least_recently_used<T>(
+		&self,
+		client_type: &BlockChainType,
+		clients: &mut HashMap<String, Arc<T>>,
+	) {
+		let Some(max_clients) = self.config.max_clients else {
+			return;
+		};
+		let mut last_used = self.last_used.lock().unwrap_or_else(|e| e.into_inner());
+		while !clients.is_empty() && clients.len() >= max_clients {
+			let Some(slug) = clients
+				.keys()
+				.min_by_key(|slug| last_used.get(&(client_type.clone(), (*slug).clone())))
+				.cloned()
+			else {
+				break;
+			};
+			clients.remove(&slug);
+			last_used.remove(&(client_type.clone(), slug.clone()));
+			tracing::debug!(
+				"Evicted least recently used {:?} client for network {}",
+				client_type,
+				slug
+			);
+		}
+	}
+
 	/// Internal helper method to get or create a client of any type.
 	///
 	/// Uses a double-checked locking pattern:
@@ -79,7 +220,9 @@ impl ClientPool {
 	/// 2. Slow path with write lock to create new client if needed
 	///
 	/// This ensures thread-safety while maintaining good performance
-	/// for the common case of accessing existing clients.
+	/// for the common case of accessing existing clients. Idle and surplus
+	/// clients are only evicted under the write lock, so a client handed out
+	/// on the fast path stays valid for its holder even if it is evicted later.
 	async fn get_or_create_client<T: BlockChainClient + 'static>(
 		&self,
 		client_type: BlockChainType,
@@ -92,15 +235,27 @@ impl ClientPool {
 			.and_then(|s| s.downcast_ref::<ClientStorage<T>>())
 			.with_context(|| "Invalid client type")?;
 
-		// Fast path: check if client exists
-		if let Some(client) = storage.clients.read().await.get(&network.slug) {
-			return Ok(client.clone());
+		// Fast path: check if a live client exists
+		if self.expired_slugs(&client_type).is_empty() {
+			let clients = storage.clients.read().await;
+			if let Some(client) = clients.get(&network.slug) {
+				self.touch(&client_type, &network.slug);
+				return Ok(client.clone());
+			}
 		}
 
-		// Slow path: create new client
+		// Slow path: evict idle clients, then reuse or create
 		let mut clients = storage.clients.write().await;
+		self.evict_expired(&client_type, &mut clients);
+		if let Some(client) = clients.get(&network.slug) {
+			self.touch(&client_type, &network.slug);
+			return Ok(client.clone());
+		}
+
 		let client = Arc::new(create_fn(network).await?);
+		self.evict_least_recently_used(&client_type, &mut clients);
 		clients.insert(network.slug.clone(), client.clone());
+		self.touch(&client_type, &network.slug);
 		Ok(client)
 	}
 
@@ -2,14 +2,22 @@
 //!
 //! Provides methods for rotating between multiple URLs and sending requests to the active endpoint
 //! with automatic fallback to other URLs on failure.
+use reqwest::header::HeaderMap;
 use reqwest_middleware::ClientWithMiddleware;
 use serde::Serialize;
-use serde_json::Value;
-use std::sync::Arc;
+use serde_json::{json, Value};
+use std::{
+	collections::HashMap,
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+	},
+};
 use tokio::sync::RwLock;
 
-use crate::services::blockchain::transports::{
-	RotatingTransport, TransportError, ROTATE_ON_ERROR_CODES,
+use crate::{
+	services::blockchain::transports::{RotatingTransport, TransportError, ROTATE_ON_ERROR_CODES},
+	utils::http::MalformedResponseError,
 };
 
 /// Manages the rotation of blockchain RPC endpoints
@@ -22,12 +30,18 @@ use crate::services::blockchain::transports::{
 /// * `fallback_urls` - A list of fallback URLs to rotate to
 /// * `client` - The client to use for the endpoint manager
 /// * `rotation_lock` - A lock for managing the rotation process
+/// * `headers` - Additional headers sent to each URL, keyed by URL without trailing slash
+/// * `method_aliases` - Method names sent instead of the called ones, keyed by called name
+/// * `next_request_id` - The id of the next JSON-RPC request, shared by clones
 #[derive(Clone, Debug)]
 pub struct EndpointManager {
 	pub active_url: Arc<RwLock<String>>,
 	pub fallback_urls: Arc<RwLock<Vec<String>>>,
 	client: ClientWithMiddleware,
 	rotation_lock: Arc<tokio::sync::Mutex<()>>,
+	headers: Arc<HashMap<String, HeaderMap>>,
+	method_aliases: Arc<HashMap<String, String>>,
+	next_request_id: Arc<AtomicU64>,
 }
 
 /// Represents the outcome of a `EndpointManager::attempt_request_on_url` method call
@@ -39,6 +53,8 @@ enum SingleRequestAttemptOutcome {
 	Success(reqwest::Response),
 	/// Error during send (e.g., connection, timeout)
 	NetworkError(reqwest_middleware::Error),
+	/// Successful responses that kept failing to parse as JSON through the client's retries
+	MalformedResponse(TransportError),
 	/// Error serializing the request body
 	SerializationError(TransportError),
 }
@@ -58,9 +74,55 @@ impl EndpointManager {
 			fallback_urls: Arc::new(RwLock::new(fallback_urls)),
 			rotation_lock: Arc::new(tokio::sync::Mutex::new(())),
 			client,
+			headers: Arc::new(HashMap::new()),
+			method_aliases: Arc::new(HashMap::new()),
+			next_request_id: Arc::new(AtomicU64::new(1)),
 		}
 	}
 
+	/// Sets the additional headers sent to each URL
+	///
+	/// # Arguments
+	/// * `headers` - Headers to send, keyed by URL
+	///
+	/// # Returns
+	/// * `Self` - The endpoint manager with the headers set
+	pub fn with_headers(mut self, headers: HashMap<String, HeaderMap>) -> Self {
+		self.headers = Arc::new(
+			headers
+				.into_iter()
+				.map(|(url, headers)| (url.trim_end_matches('/').to_string(), headers))
+				.collect(),
+		);
+		self
+	}
+
+	/// Returns the additional headers sent to a URL
+	///
+	/// # Arguments
+	/// * `url` - The URL to get the headers of
+	///
+	/// # Returns
+	/// * `HeaderMap` - The headers of the URL, empty if none are configured
+	pub fn headers_for(&self, url: &str) -> HeaderMap {
+		self.headers
+			.get(url.trim_end_matches('/'))
+			.cloned()
+			.unwrap_or_default()
+	}
+
+	/// Sets the method names sent instead of the called ones
+	///
+	/// # Arguments
+	/// * `method_aliases` - Method names to send, keyed by called method name
+	///
+	/// # Returns
+	/// * `Self` - The endpoint manager with the method aliases set
+	pub fn with_method_aliases(mut self, method_aliases: HashMap<String, String>) -> Self {
+		self.method_aliases = Arc::new(method_aliases);
+		self
+	}
+
 	/// Updates the client with a new client
 	///
 	/// Useful for updating the client with a new retry policy or strategy
@@ -172,30 +234,55 @@ impl EndpointManager {
 		Ok(new_url)
 	}
 
-	/// Attempts to send a request to the specified URL
+	/// Builds the JSON-RPC request body of a method call
+	///
 	/// # Arguments
-	/// * `url` - The URL to send the request to
 	/// * `transport` - The transport client implementing the RotatingTransport trait
-	/// * `method` - The HTTP method to use for the request (e.g., "POST")
+	/// * `method` - The RPC method name to call
 	/// * `params` - Optional parameters for the request, serialized to JSON
 	///
 	/// # Returns
-	/// * `SingleRequestAttemptOutcome` - The outcome of the request attempt
-	async fn try_request_on_url<P>(
+	/// * `Value` - The request body, with a unique id
+	async fn request_body<P>(
 		&self,
-		url: &str,
 		transport: &impl RotatingTransport,
 		method: &str,
 		params: Option<P>,
-	) -> SingleRequestAttemptOutcome
+	) -> Value
 	where
 		P: Into<Value> + Send + Clone + Serialize,
 	{
+		let method = self
+			.method_aliases
+			.get(method)
+			.map(String::as_str)
+			.unwrap_or(method);
+
 		// Create the request body using the transport's customization method
-		let request_body = transport.customize_request(method, params).await;
+		let mut request_body = transport.customize_request(method, params).await;
+
+		// Give JSON-RPC requests unique ids, as some providers mix up the responses of
+		// concurrent requests sharing an id
+		if let Some(id) = request_body.get_mut("id") {
+			*id = json!(self.next_request_id.fetch_add(1, Ordering::Relaxed));
+		}
+		request_body
+	}
 
+	/// Attempts to send a request to the specified URL
+	/// # Arguments
+	/// * `url` - The URL to send the request to
+	/// * `request_body` - The request body, a single request or a batch of requests
+	///
+	/// # Returns
+	/// * `SingleRequestAttemptOutcome` - The outcome of the request attempt
+	async fn try_request_on_url(
+		&self,
+		url: &str,
+		request_body: &Value,
+	) -> SingleRequestAttemptOutcome {
 		// Serialize the request body to JSON
-		let request_body_str = match serde_json::to_string(&request_body) {
+		let request_body_str = match serde_json::to_string(request_body) {
 			Ok(body) => body,
 			Err(e) => {
 				tracing::error!("Failed to serialize request body: {}", e);
@@ -213,6 +300,7 @@ impl EndpointManager {
 		let response_result = self
 			.client
 			.post(url)
+			.headers(self.headers_for(url))
 			.header("Content-Type", "application/json")
 			.body(request_body_str)
 			.send()
@@ -221,6 +309,13 @@ impl EndpointManager {
 		// Handle the response
 		match response_result {
 			Ok(response) => SingleRequestAttemptOutcome::Success(response),
+			Err(error) if MalformedResponseError::is_cause_of(&error) => {
+				SingleRequestAttemptOutcome::MalformedResponse(TransportError::response_parse(
+					"Failed to parse JSON response".to_string(),
+					Some(error.into()),
+					None,
+				))
+			}
 			Err(network_error) => {
 				tracing::warn!("Network error while sending request: {}", network_error);
 				SingleRequestAttemptOutcome::NetworkError(network_error)
@@ -242,6 +337,9 @@ impl EndpointManager {
 	/// - Automatically rotates to fallback URLs if the request fails with specific status codes
 	///   (e.g., 429)
 	/// - Retries the request with the new URL after rotation
+	/// - Rotates to fallback URLs if successful responses keep failing to parse as JSON (e.g.
+	///   truncated bodies or HTML error pages) once the client's retries are exhausted. JSON-RPC
+	///   error objects are valid responses and are returned as is
 	/// - Returns the first successful response or an error if all attempts fail
 	pub async fn send_raw_request<
 		T: RotatingTransport,
@@ -251,6 +349,78 @@ impl EndpointManager {
 		transport: &T,
 		method: &str,
 		params: Option<P>,
+	) -> Result<Value, TransportError> {
+		let request_body = self.request_body(transport, method, params).await;
+		self.send_request_body(transport, &request_body).await
+	}
+
+	/// Sends a batch of calls to the same JSON-RPC method in a single request, with the same
+	/// URL rotation and retries as [`EndpointManager::send_raw_request`]
+	///
+	/// # Arguments
+	/// * `transport` - The transport client implementing the RotatingTransport trait
+	/// * `method` - The RPC method name to call
+	/// * `params` - The parameters of each call
+	///
+	/// # Returns
+	/// * `Result<Vec<Value>, TransportError>` - The JSON-RPC response of each call, in the order
+	///   of the given parameters, or an error
+	pub async fn send_batch_request<T: RotatingTransport>(
+		&self,
+		transport: &T,
+		method: &str,
+		params: Vec<Value>,
+	) -> Result<Vec<Value>, TransportError> {
+		if params.is_empty() {
+			return Ok(Vec::new());
+		}
+
+		let mut requests = Vec::with_capacity(params.len());
+		for params in params {
+			requests.push(self.request_body(transport, method, Some(params)).await);
+		}
+		let ids = requests
+			.iter()
+			.map(|request| request.get("id").cloned().unwrap_or_default())
+			.collect::<Vec<_>>();
+
+		let response = self
+			.send_request_body(transport, &Value::Array(requests))
+			.await?;
+
+		// Responses of a batch may come in any order, they are matched to the calls by id
+		let mut responses = match response {
+			Value::Array(responses) => responses,
+			response => {
+				return Err(TransportError::response_parse(
+					format!("Expected a batch response, got: {}", response),
+					None,
+					None,
+				))
+			}
+		};
+		ids.iter()
+			.map(|id| {
+				let index = responses
+					.iter()
+					.position(|response| response.get("id") == Some(id))
+					.ok_or_else(|| {
+						TransportError::response_parse(
+							format!("Missing batch response for request {}", id),
+							None,
+							None,
+						)
+					})?;
+				Ok(responses.swap_remove(index))
+			})
+			.collect()
+	}
+
+	/// Sends a request body to the active URL, rotating URLs and retrying on failure
+	async fn send_request_body<T: RotatingTransport>(
+		&self,
+		transport: &T,
+		request_body: &Value,
 	) -> Result<Value, TransportError> {
 		loop {
 			let current_url_snapshot = self.active_url.read().await.clone();
@@ -262,7 +432,7 @@ impl EndpointManager {
 
 			// Attempt to send the request to the current active URL
 			let attempt_result = self
-				.try_request_on_url(&current_url_snapshot, transport, method, params.clone())
+				.try_request_on_url(&current_url_snapshot, request_body)
 				.await;
 
 			match attempt_result {
@@ -271,13 +441,20 @@ impl EndpointManager {
 					let status = response.status();
 					if status.is_success() {
 						// Successful response, parse JSON
-						return response.json().await.map_err(|e| {
-							TransportError::response_parse(
-								"Failed to parse JSON response".to_string(),
+						return match response.bytes().await {
+							Ok(body) => serde_json::from_slice::<Value>(&body).map_err(|e| {
+								TransportError::response_parse(
+									"Failed to parse JSON response".to_string(),
+									Some(Box::new(e)),
+									None,
+								)
+							}),
+							Err(e) => Err(TransportError::response_parse(
+								"Failed to read response body".to_string(),
 								Some(Box::new(e)),
 								None,
-							)
-						});
+							)),
+						};
 					} else {
 						// HTTP error
 						let error_body = response.text().await.unwrap_or_default();
@@ -355,6 +532,28 @@ impl EndpointManager {
 						}
 					}
 				}
+				// Malformed responses persisting through the client's retries, try rotation
+				SingleRequestAttemptOutcome::MalformedResponse(parse_error) => {
+					match self.try_rotate_url(transport).await {
+						Ok(new_url) => {
+							tracing::debug!(
+								"Rotation successful after malformed responses, retrying request \
+								 on new URL: '{}'",
+								new_url
+							);
+							continue; // Retry on the new active URL
+						}
+						Err(rotation_error) => {
+							tracing::debug!(
+								"send_raw_request: no rotation after malformed responses on \
+								 '{}': {}",
+								current_url_snapshot,
+								rotation_error
+							);
+							return Err(parse_error);
+						}
+					}
+				}
 				// Non-retryable serialization error
 				SingleRequestAttemptOutcome::SerializationError(serialization_error) => {
 					return Err(serialization_error);
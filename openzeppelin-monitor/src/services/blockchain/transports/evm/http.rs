@@ -72,6 +72,22 @@ impl BlockchainTransport for EVMTransportClient {
 		self.http_client.send_raw_request(method, params).await
 	}
 
+	/// Sends a batch of JSON-RPC calls to the same method in a single request
+	///
+	/// # Arguments
+	/// * `method` - The JSON-RPC method of every call
+	/// * `params` - The parameters of each call
+	///
+	/// # Returns
+	/// * `Result<Vec<Value>, TransportError>` - The response of each call in order, or error
+	async fn send_batch_request(
+		&self,
+		method: &str,
+		params: Vec<Value>,
+	) -> Result<Vec<Value>, TransportError> {
+		self.http_client.send_batch_request(method, params).await
+	}
+
 	/// Update endpoint manager with a new client
 	///
 	/// # Arguments
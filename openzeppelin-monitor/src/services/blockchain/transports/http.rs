@@ -5,24 +5,28 @@
 //! - Multiple RPC endpoints with automatic failover
 //! - Configurable retry policies
 //! - Authentication via bearer tokens
+//! - Custom headers per endpoint
+//! - Unique JSON-RPC request ids and per-network method name aliases
+//! - HTTP/2 and connection pool tuning per network
 //! - Connection health checks
 //! - Endpoint rotation for high availability
 
 use anyhow::Context;
 use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use reqwest_middleware::ClientWithMiddleware;
 use serde::Serialize;
 use serde_json::{json, Value};
-use std::{sync::Arc, time::Duration};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 use url::Url;
 
 use crate::{
-	models::Network,
+	models::{Network, RpcUrl},
 	services::blockchain::transports::{
 		BlockchainTransport, EndpointManager, RotatingTransport, TransientErrorRetryStrategy,
 		TransportError,
 	},
-	utils::http::{create_retryable_http_client, RetryConfig},
+	utils::http::{create_retryable_json_http_client, user_agent, RetryConfig},
 };
 
 /// Basic HTTP transport client for blockchain interactions
@@ -60,26 +64,44 @@ impl HttpTransportClient {
 	pub async fn new(
 		network: &Network,
 		test_connection_payload: Option<String>,
+	) -> Result<Self, anyhow::Error> {
+		Self::new_with_url_type(network, "rpc", test_connection_payload).await
+	}
+
+	/// Creates a new HTTP transport client using only the network URLs of the given type
+	///
+	/// Behaves like [`HttpTransportClient::new`], but selects the configured URLs whose
+	/// `type_` matches `url_type` (e.g. `graphql`) instead of the default `rpc` URLs.
+	///
+	/// # Arguments
+	/// * `network` - Network configuration containing RPC URLs, weights, and other details
+	/// * `url_type` - The URL type to connect to
+	/// * `test_connection_payload` - Optional JSON payload to test the connection (default is net_version)
+	///
+	/// # Returns
+	/// * `Result<Self, anyhow::Error>` - New client instance or connection error
+	pub async fn new_with_url_type(
+		network: &Network,
+		url_type: &str,
+		test_connection_payload: Option<String>,
 	) -> Result<Self, anyhow::Error> {
 		let mut rpc_urls: Vec<_> = network
 			.rpc_urls
 			.iter()
-			.filter(|rpc_url| rpc_url.type_ == "rpc" && rpc_url.weight > 0)
+			.filter(|rpc_url| rpc_url.type_ == url_type && rpc_url.weight > 0)
 			.collect();
 
 		rpc_urls.sort_by(|a, b| b.weight.cmp(&a.weight));
 
+		let url_headers = rpc_url_headers(&rpc_urls)?;
+
 		// Create a retry policy with default settings
 		// Shared config for endpoint manager and test connection
 		let http_retry_config = RetryConfig::default();
 
 		// Create the base HTTP client
 		let base_http_client = Arc::new(
-			reqwest::ClientBuilder::new()
-				.pool_idle_timeout(Duration::from_secs(90))
-				.pool_max_idle_per_host(32)
-				.timeout(Duration::from_secs(30))
-				.connect_timeout(Duration::from_secs(20))
+			base_http_client_builder(network)
 				.build()
 				.context("Failed to create base HTTP client")?,
 		);
@@ -88,7 +110,7 @@ impl HttpTransportClient {
 		// Shared across:
 		// - EndpointManager for handling endpoint rotation
 		// - Connection testing for verifying endpoint availability
-		let retryable_client = create_retryable_http_client(
+		let retryable_client = create_retryable_json_http_client(
 			&http_retry_config,
 			(*base_http_client).clone(),
 			Some(TransientErrorRetryStrategy),
@@ -115,6 +137,12 @@ impl HttpTransportClient {
 			// Attempt to connect to the endpoint
 			let request_result = retryable_client
 				.post(url.clone())
+				.headers(
+					url_headers
+						.get(rpc_url.url.as_str())
+						.cloned()
+						.unwrap_or_default(),
+				)
 				.json(&test_request)
 				.send()
 				.await;
@@ -141,7 +169,9 @@ impl HttpTransportClient {
 							retryable_client,
 							rpc_url.url.as_ref(),
 							fallback_urls,
-						),
+						)
+						.with_headers(url_headers)
+						.with_method_aliases(method_aliases(network, url_type)),
 						test_connection_payload,
 					});
 				}
@@ -154,6 +184,124 @@ impl HttpTransportClient {
 
 		Err(anyhow::anyhow!("All RPC URLs failed to connect"))
 	}
+
+	/// Sends a request through the endpoint manager on behalf of a wrapping transport
+	///
+	/// The request body is built by `transport.customize_request`, which lets transports
+	/// that speak a protocol other than JSON-RPC reuse endpoint rotation and retries.
+	///
+	/// # Arguments
+	/// * `transport` - The transport used to build the request body and rotate URLs
+	/// * `method` - The method name to call
+	/// * `params` - Optional parameters for the method call
+	///
+	/// # Returns
+	/// * `Result<Value, TransportError>` - JSON response or error with context
+	pub async fn send_raw_request_via<T, P>(
+		&self,
+		transport: &T,
+		method: &str,
+		params: Option<P>,
+	) -> Result<Value, TransportError>
+	where
+		T: RotatingTransport,
+		P: Into<Value> + Send + Clone + Serialize,
+	{
+		self.endpoint_manager
+			.send_raw_request(transport, method, params)
+			.await
+	}
+}
+
+/// Default time in milliseconds an idle pooled connection is kept alive
+const DEFAULT_POOL_IDLE_TIMEOUT_MS: u64 = 90_000;
+
+/// Default maximum number of idle pooled connections per host
+const DEFAULT_POOL_MAX_IDLE_PER_HOST: usize = 32;
+
+/// Creates the builder of the base HTTP client used to reach the network's RPC URLs
+///
+/// Requests carry the configured `User-Agent`, see [`user_agent`]. Connection pooling follows
+/// the network's `pool_idle_timeout_ms` and
+/// `pool_max_idle_per_host` settings, falling back to conservative defaults. HTTP/2 is only
+/// used without negotiation when `http2_prior_knowledge` is enabled, as endpoints that do not
+/// support it would reject every request.
+///
+/// # Arguments
+/// * `network` - Network configuration containing the connection settings
+///
+/// # Returns
+/// * `reqwest::ClientBuilder` - Builder configured with the network's connection settings
+fn base_http_client_builder(network: &Network) -> reqwest::ClientBuilder {
+	let builder = reqwest::ClientBuilder::new()
+		.user_agent(user_agent())
+		.pool_idle_timeout(Duration::from_millis(
+			network
+				.pool_idle_timeout_ms
+				.unwrap_or(DEFAULT_POOL_IDLE_TIMEOUT_MS),
+		))
+		.pool_max_idle_per_host(
+			network
+				.pool_max_idle_per_host
+				.unwrap_or(DEFAULT_POOL_MAX_IDLE_PER_HOST),
+		)
+		.timeout(Duration::from_secs(30))
+		.connect_timeout(Duration::from_secs(20));
+
+	if network.http2_prior_knowledge == Some(true) {
+		builder.http2_prior_knowledge()
+	} else {
+		builder
+	}
+}
+
+/// Returns the method names sent to the network's URLs of the given type instead of the
+/// called ones
+///
+/// Aliases only apply to the JSON-RPC `rpc` URLs, as other transports map the called methods
+/// to their own protocol.
+///
+/// # Arguments
+/// * `network` - Network configuration containing the method aliases
+/// * `url_type` - The type of the URLs the requests are sent to
+///
+/// # Returns
+/// * `HashMap<String, String>` - Method names to send, keyed by called method name
+fn method_aliases(network: &Network, url_type: &str) -> HashMap<String, String> {
+	match &network.rpc_method_aliases {
+		Some(aliases) if url_type == "rpc" => aliases.clone(),
+		_ => HashMap::new(),
+	}
+}
+
+/// Builds the additional headers sent to each RPC URL
+///
+/// Header values are marked as sensitive, so that they are never shown in debug output.
+///
+/// # Arguments
+/// * `rpc_urls` - The RPC URLs with their configured headers
+///
+/// # Returns
+/// * `Result<HashMap<String, HeaderMap>, anyhow::Error>` - Headers keyed by URL or error
+fn rpc_url_headers(rpc_urls: &[&RpcUrl]) -> Result<HashMap<String, HeaderMap>, anyhow::Error> {
+	let mut url_headers = HashMap::new();
+	for rpc_url in rpc_urls
+		.iter()
+		.filter(|rpc_url| !rpc_url.headers.is_empty())
+	{
+		let mut headers = HeaderMap::new();
+		for (name, value) in &rpc_url.headers {
+			let name = HeaderName::from_bytes(name.as_bytes())
+				.with_context(|| format!("Invalid RPC header name '{}'", name))?;
+			// The value is not included in the error, as it may be a secret
+			let mut value = HeaderValue::from_str(value.as_str())
+				.map_err(|_| anyhow::anyhow!("Invalid value for RPC header '{}'", name))?;
+			value.set_sensitive(true);
+			headers.insert(name, value);
+		}
+		url_headers.insert(rpc_url.url.as_str().to_string(), headers);
+	}
+	Ok(url_headers)
 }
 
 #[async_trait]
@@ -202,6 +350,24 @@ impl BlockchainTransport for HttpTransportClient {
 		Ok(response)
 	}
 
+	/// Sends a batch of calls to the same method as a single JSON-RPC batch request
+	///
+	/// # Arguments
+	/// * `method` - The method of every call
+	/// * `params` - The parameters of each call
+	///
+	/// # Returns
+	/// * `Result<Vec<Value>, TransportError>` - The response of each call in order, or error
+	async fn send_batch_request(
+		&self,
+		method: &str,
+		params: Vec<Value>,
+	) -> Result<Vec<Value>, TransportError> {
+		self.endpoint_manager
+			.send_batch_request(self, method, params)
+			.await
+	}
+
 	/// Update endpoint manager with a new client
 	///
 	/// # Arguments
@@ -241,7 +407,11 @@ impl RotatingTransport for HttpTransportClient {
 			})
 		};
 
-		let request = self.client.post(url.clone()).json(&test_request);
+		let request = self
+			.client
+			.post(url.clone())
+			.headers(self.endpoint_manager.headers_for(url.as_str()))
+			.json(&test_request);
 
 		match request.send().await {
 			Ok(response) => {
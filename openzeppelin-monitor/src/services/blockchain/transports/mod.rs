@@ -3,11 +3,13 @@
 //! Provides concrete implementations for different blockchain network protocols:
 //!
 //! - Generic HTTP transport for all chains
+//! - GraphQL indexer transport for Stellar
 
 mod evm {
 	pub mod http;
 }
 mod stellar {
+	pub mod graphql;
 	pub mod http;
 }
 
@@ -19,8 +21,12 @@ pub use endpoint_manager::EndpointManager;
 pub use error::TransportError;
 pub use evm::http::EVMTransportClient;
 pub use http::HttpTransportClient;
-pub use stellar::http::StellarTransportClient;
+pub use stellar::{
+	graphql::{StellarGraphQLTransport, GRAPHQL_URL_TYPE},
+	http::StellarTransportClient,
+};
 
+use crate::utils::http::MalformedResponseError;
 use reqwest_middleware::ClientWithMiddleware;
 use reqwest_retry::{
 	default_on_request_failure, default_on_request_success, Retryable, RetryableStrategy,
@@ -47,6 +53,22 @@ pub trait BlockchainTransport: Send + Sync {
 	where
 		P: Into<Value> + Send + Clone + Serialize;
 
+	/// Sends a batch of calls to the same method
+	///
+	/// Returns the response of each call in the order of the given parameters. Transports
+	/// without JSON-RPC batch support send the calls one at a time.
+	async fn send_batch_request(
+		&self,
+		method: &str,
+		params: Vec<Value>,
+	) -> Result<Vec<Value>, TransportError> {
+		let mut responses = Vec::with_capacity(params.len());
+		for params in params {
+			responses.push(self.send_raw_request(method, Some(params)).await?);
+		}
+		Ok(responses)
+	}
+
 	/// Customizes the request for specific blockchain requirements
 	async fn customize_request<P>(&self, method: &str, params: Option<P>) -> Value
 	where
@@ -80,6 +102,11 @@ pub trait RotatingTransport: BlockchainTransport {
 
 /// A default retry strategy that retries on requests based on the status code
 /// This can be used to customise the retry strategy
+///
+/// Successful responses failing to parse as JSON, as reported by the clients created with
+/// [`create_retryable_json_http_client`](crate::utils::http::create_retryable_json_http_client),
+/// are transient as well: some providers occasionally
+/// return truncated bodies or HTML error pages with a success status.
 pub struct TransientErrorRetryStrategy;
 impl RetryableStrategy for TransientErrorRetryStrategy {
 	fn handle(
@@ -88,6 +115,7 @@ impl RetryableStrategy for TransientErrorRetryStrategy {
 	) -> Option<Retryable> {
 		match res {
 			Ok(success) => default_on_request_success(success),
+			Err(error) if MalformedResponseError::is_cause_of(error) => Some(Retryable::Transient),
 			Err(error) => default_on_request_failure(error),
 		}
 	}
@@ -0,0 +1,267 @@
+//! Stellar GraphQL transport implementation for blockchain interactions.
+//!
+//! This module provides a transport that fetches Stellar data from a GraphQL indexer
+//! instead of a JSON-RPC node. Requests issued with Stellar RPC method names are
+//! translated into GraphQL queries, and the query results are returned in the same
+//! shape as the equivalent JSON-RPC responses so that `StellarClient` can parse them
+//! into the existing `StellarBlock`, `StellarTransaction` and `StellarEvent` models.
+//!
+//! The indexer is expected to expose the following root query fields, mirroring the
+//! Stellar RPC result objects:
+//! - `network` - Network passphrase and protocol information (`getNetwork`)
+//! - `latestLedger` - The latest ingested ledger (`getLatestLedger`)
+//! - `ledgers(startLedger, cursor, limit)` - A page of ledgers (`getLedgers`)
+//! - `transactions(startLedger, cursor, limit)` - A page of transactions (`getTransactions`)
+//! - `events(startLedger, cursor, limit, type)` - A page of events (`getEvents`)
+//! - `ledgerEntries(keys)` - Ledger entries by key (`getLedgerEntries`)
+
+use reqwest_middleware::ClientWithMiddleware;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::{
+	models::Network,
+	services::blockchain::transports::{
+		BlockchainTransport, HttpTransportClient, RotatingTransport, TransportError,
+	},
+};
+
+/// The RPC URL type that identifies a GraphQL indexer endpoint
+pub const GRAPHQL_URL_TYPE: &str = "graphql";
+
+/// Error code reported for GraphQL errors that do not carry a numeric code
+const GRAPHQL_ERROR_CODE: i64 = -32000;
+
+const NETWORK_QUERY: &str = "query { network { friendbotUrl passphrase protocolVersion } }";
+
+const LATEST_LEDGER_QUERY: &str = "query { latestLedger { id protocolVersion sequence } }";
+
+const LEDGERS_QUERY: &str = "query Ledgers($startLedger: Int, $cursor: String, $limit: Int) { \
+	ledgers(startLedger: $startLedger, cursor: $cursor, limit: $limit) { \
+	cursor ledgers { hash sequence ledgerCloseTime headerXdr metadataXdr } } }";
+
+const TRANSACTIONS_QUERY: &str =
+	"query Transactions($startLedger: Int, $cursor: String, $limit: Int) { \
+	transactions(startLedger: $startLedger, cursor: $cursor, limit: $limit) { \
+	cursor transactions { status txHash applicationOrder feeBump envelopeXdr resultXdr \
+	resultMetaXdr diagnosticEventsXdr ledger createdAt } } }";
+
+const EVENTS_QUERY: &str =
+	"query Events($startLedger: Int, $cursor: String, $limit: Int, $type: String) { \
+	events(startLedger: $startLedger, cursor: $cursor, limit: $limit, type: $type) { \
+	cursor events { type ledger ledgerClosedAt contractId id pagingToken \
+	inSuccessfulContractCall txHash topic value } } }";
+
+const LEDGER_ENTRIES_QUERY: &str = "query LedgerEntries($keys: [String!]!) { \
+	ledgerEntries(keys: $keys) { latestLedger entries { key xdr lastModifiedLedgerSeq \
+	liveUntilLedgerSeq } } }";
+
+/// Returns the GraphQL root field and query used to serve a Stellar RPC method
+fn graphql_operation(method: &str) -> Option<(&'static str, &'static str)> {
+	match method {
+		"getNetwork" => Some(("network", NETWORK_QUERY)),
+		"getLatestLedger" => Some(("latestLedger", LATEST_LEDGER_QUERY)),
+		"getLedgers" => Some(("ledgers", LEDGERS_QUERY)),
+		"getTransactions" => Some(("transactions", TRANSACTIONS_QUERY)),
+		"getEvents" => Some(("events", EVENTS_QUERY)),
+		"getLedgerEntries" => Some(("ledgerEntries", LEDGER_ENTRIES_QUERY)),
+		_ => None,
+	}
+}
+
+/// Builds the GraphQL variables for a Stellar RPC method from its JSON-RPC parameters
+fn graphql_variables(method: &str, params: &Value) -> Value {
+	let pagination = &params["pagination"];
+	match method {
+		"getLedgers" | "getTransactions" => json!({
+			"startLedger": params.get("startLedger"),
+			"cursor": pagination.get("cursor"),
+			"limit": pagination.get("limit"),
+		}),
+		"getEvents" => json!({
+			"startLedger": params.get("startLedger"),
+			"cursor": pagination.get("cursor"),
+			"limit": pagination.get("limit"),
+			"type": params["filters"][0].get("type"),
+		}),
+		"getLedgerEntries" => json!({ "keys": params.get("keys") }),
+		_ => json!({}),
+	}
+}
+
+/// Converts a GraphQL response into the equivalent JSON-RPC response
+///
+/// The first GraphQL error is reported as a JSON-RPC `error` object, using the numeric
+/// `extensions.code` when the indexer provides one. Otherwise the data under `root_field`
+/// becomes the JSON-RPC `result`. Returns `None` if the response holds neither.
+fn into_json_rpc_response(root_field: &str, response: Value) -> Option<Value> {
+	if let Some(error) = response
+		.get("errors")
+		.and_then(|errors| errors.as_array())
+		.and_then(|errors| errors.first())
+	{
+		let code = error["extensions"]["code"]
+			.as_i64()
+			.unwrap_or(GRAPHQL_ERROR_CODE);
+		let message = error["message"]
+			.as_str()
+			.unwrap_or("Unknown GraphQL error")
+			.to_string();
+		return Some(json!({
+			"jsonrpc": "2.0",
+			"id": 1,
+			"error": { "code": code, "message": message }
+		}));
+	}
+
+	response
+		.get("data")
+		.and_then(|data| data.get(root_field))
+		.filter(|result| !result.is_null())
+		.map(|result| {
+			json!({
+				"jsonrpc": "2.0",
+				"id": 1,
+				"result": result
+			})
+		})
+}
+
+/// A client for fetching Stellar data from a GraphQL indexer
+///
+/// This implementation wraps the HttpTransportClient, so it shares the same endpoint
+/// rotation and retry behaviour as the JSON-RPC transports. Only network URLs of type
+/// `graphql` are used.
+#[derive(Clone, Debug)]
+pub struct StellarGraphQLTransport {
+	/// The underlying HTTP transport client that sends the GraphQL requests
+	http_client: HttpTransportClient,
+}
+
+impl StellarGraphQLTransport {
+	/// Creates a new Stellar GraphQL transport connected to the network's `graphql` URLs
+	///
+	/// # Arguments
+	/// * `network` - Network configuration containing GraphQL URLs and other network details
+	///
+	/// # Returns
+	/// * `Result<Self, anyhow::Error>` - A new transport instance or connection error
+	pub async fn new(network: &Network) -> Result<Self, anyhow::Error> {
+		let test_connection_payload = Some(r#"{"query":"{ __typename }"}"#.to_string());
+		let http_client = HttpTransportClient::new_with_url_type(
+			network,
+			GRAPHQL_URL_TYPE,
+			test_connection_payload,
+		)
+		.await?;
+		Ok(Self { http_client })
+	}
+}
+
+#[async_trait::async_trait]
+impl BlockchainTransport for StellarGraphQLTransport {
+	/// Gets the current active GraphQL URL
+	///
+	/// # Returns
+	/// * `String` - The currently active GraphQL endpoint URL
+	async fn get_current_url(&self) -> String {
+		self.http_client.get_current_url().await
+	}
+
+	/// Sends a Stellar RPC method to the indexer as a GraphQL query
+	///
+	/// # Arguments
+	/// * `method` - The Stellar RPC method to serve
+	/// * `params` - Optional JSON-RPC parameters for the method
+	///
+	/// # Returns
+	/// * `Result<Value, TransportError>` - The response in JSON-RPC shape or error
+	async fn send_raw_request<P>(
+		&self,
+		method: &str,
+		params: Option<P>,
+	) -> Result<Value, TransportError>
+	where
+		P: Into<Value> + Send + Clone + Serialize,
+	{
+		let (root_field, _) = graphql_operation(method).ok_or_else(|| {
+			TransportError::request_serialization(
+				format!(
+					"Method '{}' is not supported by the GraphQL transport",
+					method
+				),
+				None,
+				None,
+			)
+		})?;
+
+		let response = self
+			.http_client
+			.send_raw_request_via(self, method, params)
+			.await?;
+
+		into_json_rpc_response(root_field, response).ok_or_else(|| {
+			TransportError::response_parse(
+				format!("GraphQL response is missing data for '{}'", root_field),
+				None,
+				None,
+			)
+		})
+	}
+
+	/// Builds the GraphQL request body for a Stellar RPC method
+	///
+	/// # Arguments
+	/// * `method` - The Stellar RPC method to serve
+	/// * `params` - Optional JSON-RPC parameters for the method
+	///
+	/// # Returns
+	/// * `Value` - The GraphQL request body containing the query and its variables
+	async fn customize_request<P>(&self, method: &str, params: Option<P>) -> Value
+	where
+		P: Into<Value> + Send + Clone + Serialize,
+	{
+		let params = params.map(|p| p.into()).unwrap_or(Value::Null);
+		let query = graphql_operation(method).map(|(_, query)| query);
+		json!({
+			"query": query,
+			"variables": graphql_variables(method, &params)
+		})
+	}
+
+	/// Update endpoint manager with a new client
+	///
+	/// # Arguments
+	/// * `client` - The new client to use for the endpoint manager
+	fn update_endpoint_manager_client(
+		&mut self,
+		client: ClientWithMiddleware,
+	) -> Result<(), anyhow::Error> {
+		self.http_client.update_endpoint_manager_client(client)
+	}
+}
+
+#[async_trait::async_trait]
+impl RotatingTransport for StellarGraphQLTransport {
+	/// Tests connection to a specific URL
+	///
+	/// # Arguments
+	/// * `url` - The URL to test connection with
+	///
+	/// # Returns
+	/// * `Result<(), anyhow::Error>` - Success or error status
+	async fn try_connect(&self, url: &str) -> Result<(), anyhow::Error> {
+		self.http_client.try_connect(url).await
+	}
+
+	/// Updates the client to use a new URL
+	///
+	/// # Arguments
+	/// * `url` - The new URL to use for subsequent requests
+	///
+	/// # Returns
+	/// * `Result<(), anyhow::Error>` - Success or error status
+	async fn update_client(&self, url: &str) -> Result<(), anyhow::Error> {
+		self.http_client.update_client(url).await
+	}
+}
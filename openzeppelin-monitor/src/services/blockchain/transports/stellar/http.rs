@@ -3,6 +3,9 @@
 //! This module provides a client implementation for interacting with Stellar-compatible nodes
 //! by wrapping the HttpTransportClient. This allows for consistent behavior with other
 //! transport implementations while providing specific Stellar-focused functionality.
+//!
+//! Networks configured only with `graphql` URLs are served by the
+//! [`StellarGraphQLTransport`] instead of JSON-RPC.
 
 use reqwest_middleware::ClientWithMiddleware;
 use serde::Serialize;
@@ -11,10 +14,20 @@ use serde_json::Value;
 use crate::{
 	models::Network,
 	services::blockchain::transports::{
-		BlockchainTransport, HttpTransportClient, RotatingTransport, TransportError,
+		stellar::graphql::GRAPHQL_URL_TYPE, BlockchainTransport, HttpTransportClient,
+		RotatingTransport, StellarGraphQLTransport, TransportError,
 	},
 };
 
+/// The protocol used to reach a Stellar network
+#[derive(Clone, Debug)]
+enum StellarTransport {
+	/// Stellar JSON-RPC node
+	JsonRpc(HttpTransportClient),
+	/// GraphQL indexer
+	GraphQL(StellarGraphQLTransport),
+}
+
 /// A client for interacting with Stellar-compatible blockchain nodes
 ///
 /// This implementation wraps the HttpTransportClient to provide consistent
@@ -23,23 +36,46 @@ use crate::{
 /// endpoint rotation for Stellar-based networks.
 #[derive(Clone, Debug)]
 pub struct StellarTransportClient {
-	/// The underlying HTTP transport client that handles actual RPC communications
-	http_client: HttpTransportClient,
+	/// The underlying transport that handles actual RPC communications
+	transport: StellarTransport,
 }
 
 impl StellarTransportClient {
 	/// Creates a new Stellar transport client by initializing an HTTP transport client
 	///
+	/// JSON-RPC is used whenever the network has `rpc` URLs. A network configured only
+	/// with `graphql` URLs is served by a GraphQL indexer transport instead.
+	///
 	/// # Arguments
 	/// * `network` - Network configuration containing RPC URLs and other network details
 	///
 	/// # Returns
 	/// * `Result<Self, anyhow::Error>` - A new client instance or connection error
 	pub async fn new(network: &Network) -> Result<Self, anyhow::Error> {
+		let has_rpc_urls = network.rpc_urls.iter().any(|url| url.type_ == "rpc");
+		let has_graphql_urls = network
+			.rpc_urls
+			.iter()
+			.any(|url| url.type_ == GRAPHQL_URL_TYPE);
+
+		if !has_rpc_urls && has_graphql_urls {
+			let graphql_transport = StellarGraphQLTransport::new(network).await?;
+			return Ok(Self {
+				transport: StellarTransport::GraphQL(graphql_transport),
+			});
+		}
+
 		let test_connection_payload =
 			Some(r#"{"id":1,"jsonrpc":"2.0","method":"getNetwork","params":[]}"#.to_string());
 		let http_client = HttpTransportClient::new(network, test_connection_payload).await?;
-		Ok(Self { http_client })
+		Ok(Self {
+			transport: StellarTransport::JsonRpc(http_client),
+		})
+	}
+
+	/// Returns whether requests are served by a GraphQL indexer rather than JSON-RPC
+	pub fn is_graphql(&self) -> bool {
+		matches!(self.transport, StellarTransport::GraphQL(_))
 	}
 }
 
@@ -50,7 +86,10 @@ impl BlockchainTransport for StellarTransportClient {
 	/// # Returns
 	/// * `String` - The currently active RPC endpoint URL
 	async fn get_current_url(&self) -> String {
-		self.http_client.get_current_url().await
+		match &self.transport {
+			StellarTransport::JsonRpc(client) => client.get_current_url().await,
+			StellarTransport::GraphQL(client) => client.get_current_url().await,
+		}
 	}
 
 	/// Sends a raw JSON-RPC request to the Stellar node
@@ -69,7 +108,10 @@ impl BlockchainTransport for StellarTransportClient {
 	where
 		P: Into<Value> + Send + Clone + Serialize,
 	{
-		self.http_client.send_raw_request(method, params).await
+		match &self.transport {
+			StellarTransport::JsonRpc(client) => client.send_raw_request(method, params).await,
+			StellarTransport::GraphQL(client) => client.send_raw_request(method, params).await,
+		}
 	}
 
 	/// Update endpoint manager with a new client
@@ -80,7 +122,14 @@ impl BlockchainTransport for StellarTransportClient {
 		&mut self,
 		client: ClientWithMiddleware,
 	) -> Result<(), anyhow::Error> {
-		self.http_client.update_endpoint_manager_client(client)
+		match &mut self.transport {
+			StellarTransport::JsonRpc(transport) => {
+				transport.update_endpoint_manager_client(client)
+			}
+			StellarTransport::GraphQL(transport) => {
+				transport.update_endpoint_manager_client(client)
+			}
+		}
 	}
 }
 
@@ -94,7 +143,10 @@ impl RotatingTransport for StellarTransportClient {
 	/// # Returns
 	/// * `Result<(), anyhow::Error>` - Success or error status
 	async fn try_connect(&self, url: &str) -> Result<(), anyhow::Error> {
-		self.http_client.try_connect(url).await
+		match &self.transport {
+			StellarTransport::JsonRpc(client) => client.try_connect(url).await,
+			StellarTransport::GraphQL(client) => client.try_connect(url).await,
+		}
 	}
 
 	/// Updates the client to use a new URL
@@ -105,6 +157,9 @@ impl RotatingTransport for StellarTransportClient {
 	/// # Returns
 	/// * `Result<(), anyhow::Error>` - Success or error status
 	async fn update_client(&self, url: &str) -> Result<(), anyhow::Error> {
-		self.http_client.update_client(url).await
+		match &self.transport {
+			StellarTransport::JsonRpc(client) => client.update_client(url).await,
+			StellarTransport::GraphQL(client) => client.update_client(url).await,
+		}
 	}
 }
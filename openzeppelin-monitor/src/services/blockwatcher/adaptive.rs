@@ -0,0 +1,213 @@
+//! Adaptive polling for networks using the `@adaptive` schedule.
+//!
+//! Instead of polling on a fixed schedule, the watcher estimates the network's block cadence
+//! from the blocks it observes and schedules its next poll near the expected time of the next
+//! block. Polls that find no new block back off, and the estimate is bounded around the
+//! network's configured `block_time_ms`.
+
+use std::time::{Duration, Instant};
+
+use crate::utils::ADAPTIVE_MAX_BLOCK_TIMES;
+
+/// Shortest interval between two polls
+pub const MIN_ADAPTIVE_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Weight of a new block time sample in the estimated block time
+const SAMPLE_WEIGHT: f64 = 0.25;
+
+/// Estimates the block cadence of a network and computes the interval until its next poll
+///
+/// The estimate stays between `block_time_ms / ADAPTIVE_MAX_BLOCK_TIMES` and
+/// `block_time_ms * ADAPTIVE_MAX_BLOCK_TIMES`, and polls are never further apart than
+/// `block_time_ms * ADAPTIVE_MAX_BLOCK_TIMES`.
+#[derive(Debug, Clone)]
+pub struct AdaptivePollingState {
+	/// Block time configured for the network
+	block_time: Duration,
+	/// Moving average of the observed block times
+	estimated_block_time: Duration,
+	/// Last observed block number and when it was observed
+	last_block: Option<(u64, Instant)>,
+	/// Consecutive polls that observed no new block
+	empty_polls: u32,
+}
+
+impl AdaptivePollingState {
+	/// Creates a state estimating the configured block time
+	///
+	/// # Arguments
+	/// * `block_time_ms` - Block time configured for the network
+	pub fn new(block_time_ms: u64) -> Self {
+		let block_time = Duration::from_millis(block_time_ms).max(MIN_ADAPTIVE_POLL_INTERVAL);
+		Self {
+			block_time,
+			estimated_block_time: block_time,
+			last_block: None,
+			empty_polls: 0,
+		}
+	}
+
+	/// Returns the current estimate of the network's block time
+	pub fn estimated_block_time(&self) -> Duration {
+		self.estimated_block_time
+	}
+
+	/// Records the outcome of a poll and returns the interval until the next one
+	///
+	/// When new blocks were observed, the time elapsed since the previous new block is
+	/// averaged into the estimated block time and the next poll is scheduled one estimated
+	/// block time later. Otherwise the next block is late: the first empty poll waits half
+	/// an estimated block time, and each further one doubles the wait.
+	///
+	/// # Arguments
+	/// * `block_number` - Latest block number known after the poll, `None` if unknown
+	/// * `now` - When the poll completed
+	///
+	/// # Returns
+	/// The interval until the next poll
+	pub fn observe(&mut self, block_number: Option<u64>, now: Instant) -> Duration {
+		match (block_number, self.last_block) {
+			(Some(number), Some((last_number, last_seen))) if number > last_number => {
+				let blocks = u32::try_from(number - last_number).unwrap_or(u32::MAX);
+				let sample = now.saturating_duration_since(last_seen) / blocks;
+				self.estimated_block_time = self.estimated_block_time.mul_f64(1.0 - SAMPLE_WEIGHT)
+					+ sample.mul_f64(SAMPLE_WEIGHT);
+				self.estimated_block_time = self.estimated_block_time.clamp(
+					self.block_time / ADAPTIVE_MAX_BLOCK_TIMES,
+					self.block_time * ADAPTIVE_MAX_BLOCK_TIMES,
+				);
+				self.last_block = Some((number, now));
+				self.empty_polls = 0;
+				self.bounded(self.estimated_block_time)
+			}
+			(Some(number), None) => {
+				self.last_block = Some((number, now));
+				self.empty_polls = 0;
+				self.bounded(self.estimated_block_time)
+			}
+			_ => {
+				self.empty_polls = self.empty_polls.saturating_add(1);
+				let backoff = 2u32.saturating_pow(self.empty_polls - 1);
+				self.bounded((self.estimated_block_time / 2).saturating_mul(backoff))
+			}
+		}
+	}
+
+	/// Bounds a poll interval to the allowed range
+	fn bounded(&self, interval: Duration) -> Duration {
+		interval.clamp(
+			MIN_ADAPTIVE_POLL_INTERVAL,
+			self.block_time * ADAPTIVE_MAX_BLOCK_TIMES,
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Observes `count` blocks produced every `cadence`, returning the last poll interval
+	fn observe_cadence(
+		state: &mut AdaptivePollingState,
+		start: Instant,
+		first_block: u64,
+		count: u64,
+		cadence: Duration,
+	) -> Duration {
+		let mut interval = Duration::ZERO;
+		for i in 0..count {
+			interval = state.observe(Some(first_block + i), start + cadence * i as u32);
+		}
+		interval
+	}
+
+	#[test]
+	fn test_observe_first_block_uses_configured_block_time() {
+		let mut state = AdaptivePollingState::new(12_000);
+		assert_eq!(
+			state.observe(Some(100), Instant::now()),
+			Duration::from_secs(12)
+		);
+	}
+
+	#[test]
+	fn test_observe_adapts_to_faster_blocks() {
+		let mut state = AdaptivePollingState::new(12_000);
+		let interval = observe_cadence(&mut state, Instant::now(), 100, 30, Duration::from_secs(6));
+
+		assert!(interval < Duration::from_millis(6_100));
+		assert!(interval >= Duration::from_secs(6));
+		assert_eq!(interval, state.estimated_block_time());
+	}
+
+	#[test]
+	fn test_observe_adapts_to_slower_blocks() {
+		let mut state = AdaptivePollingState::new(2_000);
+		let interval = observe_cadence(&mut state, Instant::now(), 100, 30, Duration::from_secs(5));
+
+		assert!(interval > Duration::from_millis(4_900));
+		assert!(interval <= Duration::from_secs(5));
+	}
+
+	#[test]
+	fn test_observe_averages_skipped_blocks() {
+		let mut state = AdaptivePollingState::new(1_000);
+		let start = Instant::now();
+		state.observe(Some(100), start);
+
+		// Four blocks in two seconds is a 500ms cadence
+		let interval = state.observe(Some(104), start + Duration::from_secs(2));
+		assert_eq!(interval, Duration::from_millis(875));
+	}
+
+	#[test]
+	fn test_observe_backs_off_on_empty_polls() {
+		let mut state = AdaptivePollingState::new(1_000);
+		let start = Instant::now();
+		state.observe(Some(100), start);
+
+		let intervals: Vec<_> = (1..=6)
+			.map(|i| state.observe(Some(100), start + Duration::from_secs(i)))
+			.collect();
+		assert_eq!(
+			intervals,
+			vec![
+				Duration::from_millis(500),
+				Duration::from_secs(1),
+				Duration::from_secs(2),
+				Duration::from_secs(4),
+				Duration::from_secs(8),
+				// Capped at eight block times
+				Duration::from_secs(8),
+			]
+		);
+
+		// A new block resets the backoff
+		assert!(state.observe(Some(101), start + Duration::from_secs(7)) <= Duration::from_secs(8));
+		assert_eq!(
+			state.observe(Some(101), start + Duration::from_secs(8)),
+			state.estimated_block_time() / 2
+		);
+	}
+
+	#[test]
+	fn test_observe_unknown_block_counts_as_empty_poll() {
+		let mut state = AdaptivePollingState::new(1_000);
+		assert_eq!(
+			state.observe(None, Instant::now()),
+			Duration::from_millis(500)
+		);
+	}
+
+	#[test]
+	fn test_estimate_is_bounded() {
+		let mut state = AdaptivePollingState::new(1_000);
+		observe_cadence(&mut state, Instant::now(), 100, 50, Duration::from_secs(60));
+		assert_eq!(state.estimated_block_time(), Duration::from_secs(8));
+
+		let mut state = AdaptivePollingState::new(8_000);
+		let interval = observe_cadence(&mut state, Instant::now(), 100, 50, Duration::ZERO);
+		assert_eq!(state.estimated_block_time(), Duration::from_secs(1));
+		assert_eq!(interval, Duration::from_secs(1));
+	}
+}
@@ -0,0 +1,116 @@
+//! Completion tracking of the triggers executed for a processed block.
+//!
+//! A trigger handler returns a `TriggerCompletion` for each block, resolved once the block's
+//! triggers have been executed. The block watcher only marks a block complete in storage once
+//! its completion resolves successfully, so a block whose triggers are still queued, were
+//! dropped, or were abandoned on shutdown is processed again after a restart.
+
+use std::{
+	collections::BTreeSet,
+	sync::{Arc, Mutex},
+};
+use tokio::sync::oneshot;
+
+/// Sending half of a block's trigger completion, held by whoever executes the triggers
+///
+/// Dropping the signal without calling `complete` resolves the completion as not executed.
+#[derive(Debug)]
+pub struct CompletionSignal(oneshot::Sender<()>);
+
+impl CompletionSignal {
+	/// Signals that the block's triggers have been executed
+	pub fn complete(self) {
+		let _ = self.0.send(());
+	}
+}
+
+#[derive(Debug)]
+enum CompletionState {
+	Completed,
+	Abandoned,
+	Pending(oneshot::Receiver<()>),
+}
+
+/// Receiving half of a block's trigger completion, returned by the trigger handler
+#[derive(Debug)]
+pub struct TriggerCompletion(CompletionState);
+
+impl TriggerCompletion {
+	/// Creates a pending completion, resolved through the returned signal
+	pub fn pending() -> (CompletionSignal, Self) {
+		let (tx, rx) = oneshot::channel();
+		(CompletionSignal(tx), Self(CompletionState::Pending(rx)))
+	}
+
+	/// Creates a completion for a block whose triggers have already been executed
+	pub fn completed() -> Self {
+		Self(CompletionState::Completed)
+	}
+
+	/// Creates a completion for a block whose triggers will not be executed
+	pub fn abandoned() -> Self {
+		Self(CompletionState::Abandoned)
+	}
+
+	/// Waits for the block's triggers to be executed
+	///
+	/// # Returns
+	/// `true` if the triggers were executed, `false` if they were dropped or abandoned
+	pub async fn wait(self) -> bool {
+		match self.0 {
+			CompletionState::Completed => true,
+			CompletionState::Abandoned => false,
+			CompletionState::Pending(rx) => rx.await.is_ok(),
+		}
+	}
+}
+
+/// Blocks a running watcher marked in progress that have not been marked complete yet
+///
+/// Their checkpoints belong to the running watcher, e.g. blocks whose triggers are still
+/// queued, rather than to an interrupted run, so the watcher does not process them again.
+/// Blocks whose triggers were not executed stay pending until the watcher is restarted.
+#[derive(Debug, Clone, Default)]
+pub struct PendingBlocks(Arc<Mutex<BTreeSet<u64>>>);
+
+impl PendingBlocks {
+	/// Records a block marked in progress
+	pub fn insert(&self, block_number: u64) {
+		self.0.lock().unwrap().insert(block_number);
+	}
+
+	/// Forgets a block marked complete
+	pub fn remove(&self, block_number: u64) {
+		self.0.lock().unwrap().remove(&block_number);
+	}
+
+	/// Returns whether a block is pending
+	pub fn contains(&self, block_number: u64) -> bool {
+		self.0.lock().unwrap().contains(&block_number)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[tokio::test]
+	async fn test_completion_resolves_on_signal() {
+		let (signal, completion) = TriggerCompletion::pending();
+		signal.complete();
+		assert!(completion.wait().await);
+	}
+
+	#[tokio::test]
+	async fn test_completion_dropped_signal_is_not_executed() {
+		let (signal, completion) = TriggerCompletion::pending();
+		drop(signal);
+		assert!(!completion.wait().await);
+	}
+
+	#[tokio::test]
+	async fn test_completion_immediate_states() {
+		assert!(TriggerCompletion::completed().wait().await);
+		assert!(!TriggerCompletion::abandoned().wait().await);
+	}
+}
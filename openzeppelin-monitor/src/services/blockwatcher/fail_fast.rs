@@ -0,0 +1,219 @@
+//! Fail-fast handling of repeated network watcher failures.
+//!
+//! By default, a network watcher that fails to start or to process blocks only logs the error
+//! and tries again on its next run. When fail-fast is enabled, consecutive failures are counted
+//! per network and a fatal failure is signaled once a network reaches the configured threshold,
+//! so that the process can exit and be restarted by its orchestrator.
+
+use std::{
+	collections::HashMap,
+	env, fmt,
+	sync::{Arc, Mutex},
+};
+
+use tokio::sync::mpsc;
+
+/// Environment variable enabling fail-fast mode
+pub const FAIL_FAST_ENV: &str = "FAIL_FAST";
+
+/// Environment variable setting the number of consecutive failures that is fatal
+pub const FAIL_FAST_MAX_FAILURES_ENV: &str = "FAIL_FAST_MAX_FAILURES";
+
+/// Number of consecutive failures that is fatal when not configured
+pub const DEFAULT_FAIL_FAST_MAX_FAILURES: u32 = 3;
+
+/// Fail-fast configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FailFastConfig {
+	/// Number of consecutive failures of a network watcher after which the failure is fatal
+	pub max_consecutive_failures: u32,
+}
+
+impl Default for FailFastConfig {
+	fn default() -> Self {
+		Self {
+			max_consecutive_failures: DEFAULT_FAIL_FAST_MAX_FAILURES,
+		}
+	}
+}
+
+impl FailFastConfig {
+	/// Reads the fail-fast configuration from the environment.
+	///
+	/// Uses `FAIL_FAST` and `FAIL_FAST_MAX_FAILURES`. Returns `None` unless `FAIL_FAST` is
+	/// `true`, which preserves the default behavior of retrying indefinitely.
+	pub fn from_env() -> Result<Option<Self>, anyhow::Error> {
+		if !env::var(FAIL_FAST_ENV).is_ok_and(|value| value == "true") {
+			return Ok(None);
+		}
+
+		let max_consecutive_failures = match env::var(FAIL_FAST_MAX_FAILURES_ENV) {
+			Ok(value) => match value.trim().parse::<u32>() {
+				Ok(parsed) if parsed > 0 => parsed,
+				_ => {
+					return Err(anyhow::anyhow!(
+						"Invalid {} value '{}': expected a positive integer",
+						FAIL_FAST_MAX_FAILURES_ENV,
+						value
+					))
+				}
+			},
+			Err(_) => DEFAULT_FAIL_FAST_MAX_FAILURES,
+		};
+
+		Ok(Some(Self {
+			max_consecutive_failures,
+		}))
+	}
+}
+
+/// Fatal failure of a network watcher, signaled once its failure threshold is reached
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatcherFailure {
+	/// Slug of the failing network
+	pub network_slug: String,
+	/// Number of consecutive failures of the network watcher
+	pub consecutive_failures: u32,
+	/// The last error reported by the network watcher
+	pub error: String,
+}
+
+impl fmt::Display for WatcherFailure {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"Network watcher for '{}' failed {} consecutive times: {}",
+			self.network_slug, self.consecutive_failures, self.error
+		)
+	}
+}
+
+/// Counts consecutive failures of network watchers
+///
+/// Cloned trackers share their counters and their fatal failure channel.
+#[derive(Debug, Clone)]
+pub struct WatcherFailureTracker {
+	config: FailFastConfig,
+	failures: Arc<Mutex<HashMap<String, u32>>>,
+	fatal_tx: mpsc::UnboundedSender<WatcherFailure>,
+}
+
+impl WatcherFailureTracker {
+	/// Creates a new tracker
+	///
+	/// # Arguments
+	/// * `config` - Fail-fast configuration
+	///
+	/// # Returns
+	/// The tracker and the receiver of the fatal failures it signals
+	pub fn new(config: FailFastConfig) -> (Self, mpsc::UnboundedReceiver<WatcherFailure>) {
+		let (fatal_tx, fatal_rx) = mpsc::unbounded_channel();
+		(
+			Self {
+				config,
+				failures: Arc::new(Mutex::new(HashMap::new())),
+				fatal_tx,
+			},
+			fatal_rx,
+		)
+	}
+
+	/// Resets the consecutive failure count of a network
+	///
+	/// # Arguments
+	/// * `network_slug` - Slug of the network whose watcher succeeded
+	pub fn record_success(&self, network_slug: &str) {
+		self.failures.lock().unwrap().remove(network_slug);
+	}
+
+	/// Records a failure of a network watcher
+	///
+	/// A fatal failure is signaled when the network reaches the configured number of
+	/// consecutive failures.
+	///
+	/// # Arguments
+	/// * `network_slug` - Slug of the failing network
+	/// * `error` - The reported error
+	///
+	/// # Returns
+	/// `true` if the failure is fatal
+	pub fn record_failure(&self, network_slug: &str, error: &str) -> bool {
+		let consecutive_failures = {
+			let mut failures = self.failures.lock().unwrap();
+			let count = failures.entry(network_slug.to_string()).or_insert(0);
+			*count += 1;
+			*count
+		};
+
+		if consecutive_failures < self.config.max_consecutive_failures {
+			tracing::warn!(
+				network = network_slug,
+				"Network watcher failed {} of {} allowed consecutive times",
+				consecutive_failures,
+				self.config.max_consecutive_failures
+			);
+			return false;
+		}
+
+		// The receiver is only dropped once the process is already shutting down
+		let _ = self.fatal_tx.send(WatcherFailure {
+			network_slug: network_slug.to_string(),
+			consecutive_failures,
+			error: error.to_string(),
+		});
+		true
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_record_failure_signals_at_threshold() {
+		let (tracker, mut fatal_rx) = WatcherFailureTracker::new(FailFastConfig {
+			max_consecutive_failures: 2,
+		});
+
+		assert!(!tracker.record_failure("ethereum_mainnet", "first"));
+		assert!(fatal_rx.try_recv().is_err());
+
+		assert!(tracker.record_failure("ethereum_mainnet", "second"));
+		assert_eq!(
+			fatal_rx.try_recv().unwrap(),
+			WatcherFailure {
+				network_slug: "ethereum_mainnet".to_string(),
+				consecutive_failures: 2,
+				error: "second".to_string(),
+			}
+		);
+	}
+
+	#[test]
+	fn test_record_success_resets_failures() {
+		let (tracker, mut fatal_rx) = WatcherFailureTracker::new(FailFastConfig {
+			max_consecutive_failures: 2,
+		});
+
+		assert!(!tracker.record_failure("ethereum_mainnet", "first"));
+		tracker.record_success("ethereum_mainnet");
+		assert!(!tracker.record_failure("ethereum_mainnet", "second"));
+
+		// Failures are counted per network
+		assert!(!tracker.record_failure("stellar_mainnet", "first"));
+		assert!(fatal_rx.try_recv().is_err());
+	}
+
+	#[test]
+	fn test_watcher_failure_display() {
+		let failure = WatcherFailure {
+			network_slug: "ethereum_mainnet".to_string(),
+			consecutive_failures: 3,
+			error: "connection refused".to_string(),
+		};
+		assert_eq!(
+			failure.to_string(),
+			"Network watcher for 'ethereum_mainnet' failed 3 consecutive times: connection refused"
+		);
+	}
+}
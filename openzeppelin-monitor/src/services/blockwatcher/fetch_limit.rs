@@ -0,0 +1,99 @@
+//! Global limit of the networks polled concurrently.
+//!
+//! Every network watcher polls on its own schedule, so a deployment watching many chains may
+//! poll all of them at once. The limiter is shared by the watchers of a block watcher service
+//! and bounds the number of polls, i.e. fetching the new blocks of a network and processing
+//! them, in flight at the same time. Polls over the limit wait for a slot, so each network
+//! keeps its own schedule.
+
+use std::{env, sync::Arc};
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Environment variable setting the number of networks polled concurrently
+pub const MAX_CONCURRENT_NETWORK_FETCHES_ENV: &str = "MAX_CONCURRENT_NETWORK_FETCHES";
+
+/// Limiter of the networks polled concurrently across all block watchers
+///
+/// Clones share the same slots.
+#[derive(Debug, Clone)]
+pub struct NetworkFetchLimiter {
+	semaphore: Arc<Semaphore>,
+	limit: usize,
+}
+
+impl NetworkFetchLimiter {
+	/// Creates a limiter allowing `limit` networks to be polled concurrently
+	///
+	/// # Arguments
+	/// * `limit` - Number of concurrent polls, at least 1
+	pub fn new(limit: usize) -> Self {
+		let limit = limit.max(1);
+		Self {
+			semaphore: Arc::new(Semaphore::new(limit)),
+			limit,
+		}
+	}
+
+	/// Reads the limit from `MAX_CONCURRENT_NETWORK_FETCHES`.
+	///
+	/// Returns `None` when the variable is unset, which leaves polls unlimited.
+	pub fn from_env() -> Result<Option<Self>, anyhow::Error> {
+		let Ok(value) = env::var(MAX_CONCURRENT_NETWORK_FETCHES_ENV) else {
+			return Ok(None);
+		};
+		match value.trim().parse::<usize>() {
+			Ok(limit) if limit > 0 => Ok(Some(Self::new(limit))),
+			_ => Err(anyhow::anyhow!(
+				"Invalid {} value '{}': expected a positive integer",
+				MAX_CONCURRENT_NETWORK_FETCHES_ENV,
+				value
+			)),
+		}
+	}
+
+	/// Returns the number of networks that can be polled concurrently
+	pub fn limit(&self) -> usize {
+		self.limit
+	}
+
+	/// Waits for a free slot, held until the returned permit is dropped
+	pub async fn acquire(&self) -> OwnedSemaphorePermit {
+		self.semaphore
+			.clone()
+			.acquire_owned()
+			.await
+			.expect("network fetch semaphore is never closed")
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[tokio::test]
+	async fn test_limiter_bounds_permits() {
+		let limiter = NetworkFetchLimiter::new(2);
+		let shared = limiter.clone();
+
+		let first = limiter.acquire().await;
+		let _second = shared.acquire().await;
+		assert!(
+			tokio::time::timeout(std::time::Duration::from_millis(20), limiter.acquire())
+				.await
+				.is_err()
+		);
+
+		drop(first);
+		assert!(
+			tokio::time::timeout(std::time::Duration::from_millis(20), limiter.acquire())
+				.await
+				.is_ok()
+		);
+	}
+
+	#[test]
+	fn test_limiter_has_at_least_one_slot() {
+		assert_eq!(NetworkFetchLimiter::new(0).limit(), 1);
+	}
+}
@@ -3,17 +3,46 @@
 //! This module provides functionality to watch and process blockchain blocks across
 //! different networks. It includes:
 //! - Block watching service for multiple networks
+//! - Adaptive polling on the observed block cadence of a network
 //! - Block storage implementations
+//! - Match sinks for forwarding matches to custom handlers
+//! - Fail-fast tracking of repeated network watcher failures
+//! - Global limit of the networks polled concurrently
+//! - Bounded queue between block processing and trigger execution
+//! - Completion tracking of the triggers executed for each block
 //! - Error handling specific to block watching operations
 
+mod adaptive;
+mod completion;
 mod error;
+mod fail_fast;
+mod fetch_limit;
 mod service;
+mod sink;
 mod storage;
 mod tracker;
+mod trigger_queue;
 
+pub use adaptive::{AdaptivePollingState, MIN_ADAPTIVE_POLL_INTERVAL};
+pub use completion::{CompletionSignal, PendingBlocks, TriggerCompletion};
 pub use error::BlockWatcherError;
+pub use fail_fast::{
+	FailFastConfig, WatcherFailure, WatcherFailureTracker, DEFAULT_FAIL_FAST_MAX_FAILURES,
+	FAIL_FAST_ENV, FAIL_FAST_MAX_FAILURES_ENV,
+};
+pub use fetch_limit::{NetworkFetchLimiter, MAX_CONCURRENT_NETWORK_FETCHES_ENV};
 pub use service::{
-	process_new_blocks, BlockWatcherService, JobSchedulerTrait, NetworkBlockWatcher,
+	get_latest_confirmed_block, process_new_blocks, process_new_blocks_with_sinks,
+	BlockWatcherService, JobSchedulerTrait, NetworkBlockWatcher,
+};
+pub use sink::{MatchSink, NdjsonSink};
+pub use storage::{
+	BlockCheckpoints, BlockStorage, FileBlockStorage, FileBlockStorageConfig,
+	BLOCK_STORAGE_COMPRESSION_ENV, BLOCK_STORAGE_MAX_AGE_SECS_ENV, BLOCK_STORAGE_MAX_FILES_ENV,
+	BLOCK_STORAGE_MAX_MISSED_BLOCKS_ENV,
 };
-pub use storage::{BlockStorage, FileBlockStorage};
 pub use tracker::{BlockTracker, BlockTrackerTrait};
+pub use trigger_queue::{
+	BackpressurePolicy, TriggerQueue, TriggerQueueConfig, DEFAULT_TRIGGER_QUEUE_CAPACITY,
+	TRIGGER_QUEUE_CAPACITY_ENV, TRIGGER_QUEUE_POLICY_ENV,
+};
@@ -4,10 +4,17 @@
 //! managing individual watchers for each network and coordinating block processing.
 
 use anyhow::Context;
-use futures::{channel::mpsc, future::BoxFuture, stream::StreamExt, SinkExt};
+use futures::{
+	channel::mpsc,
+	future::BoxFuture,
+	stream::{StreamExt, TryStreamExt},
+	SinkExt,
+};
 use std::{
-	collections::{BTreeMap, HashMap},
+	collections::{BTreeMap, HashMap, HashSet, VecDeque},
 	sync::Arc,
+	task::Poll,
+	time::{Duration, Instant},
 };
 use tokio::sync::RwLock;
 use tokio_cron_scheduler::{Job, JobScheduler};
@@ -18,11 +25,21 @@ use crate::{
 	services::{
 		blockchain::BlockChainClient,
 		blockwatcher::{
+			adaptive::AdaptivePollingState,
+			completion::{PendingBlocks, TriggerCompletion},
 			error::BlockWatcherError,
+			fail_fast::WatcherFailureTracker,
+			fetch_limit::NetworkFetchLimiter,
+			sink::{forward_to_sinks, MatchSink},
 			storage::BlockStorage,
 			tracker::{BlockTracker, BlockTrackerTrait},
 		},
 	},
+	utils::{
+		is_adaptive_schedule,
+		metrics::{BACKFILL_BLOCKS_TOTAL, BLOCK_PROCESSING_TIMEOUTS_TOTAL},
+		parse_interval_schedule,
+	},
 };
 
 /// Trait for job scheduler
@@ -78,6 +95,14 @@ where
 	pub trigger_handler: Arc<T>,
 	pub scheduler: J,
 	pub block_tracker: Arc<BlockTracker<S>>,
+	/// Sinks receiving every match emitted for this network
+	pub match_sinks: Vec<Arc<dyn MatchSink>>,
+	/// Tracker of consecutive processing failures, set when fail-fast is enabled
+	pub failure_tracker: Option<WatcherFailureTracker>,
+	/// Limiter shared with the other watchers, set when concurrent polls are limited
+	pub fetch_limiter: Option<NetworkFetchLimiter>,
+	/// Polling task used instead of the scheduler for `@interval:` and `@adaptive` schedules
+	interval_task: Option<tokio::task::JoinHandle<()>>,
 }
 
 /// Map of active block watchers
@@ -102,13 +127,19 @@ where
 	pub trigger_handler: Arc<T>,
 	pub active_watchers: Arc<RwLock<BlockWatchersMap<S, H, T, J>>>,
 	pub block_tracker: Arc<BlockTracker<S>>,
+	/// Sinks receiving every match emitted by the watchers started by this service
+	pub match_sinks: Vec<Arc<dyn MatchSink>>,
+	/// Tracker of consecutive watcher failures, set when fail-fast is enabled
+	pub failure_tracker: Option<WatcherFailureTracker>,
+	/// Limiter of the networks polled concurrently, set when concurrent polls are limited
+	pub fetch_limiter: Option<NetworkFetchLimiter>,
 }
 
 impl<S, H, T, J> NetworkBlockWatcher<S, H, T, J>
 where
 	S: BlockStorage + Send + Sync + 'static,
 	H: Fn(BlockType, Network) -> BoxFuture<'static, ProcessedBlock> + Send + Sync + 'static,
-	T: Fn(&ProcessedBlock) -> tokio::task::JoinHandle<()> + Send + Sync + 'static,
+	T: Fn(&ProcessedBlock) -> tokio::task::JoinHandle<TriggerCompletion> + Send + Sync + 'static,
 	J: JobSchedulerTrait,
 {
 	/// Creates a new network watcher instance
@@ -144,13 +175,21 @@ where
 			trigger_handler,
 			scheduler,
 			block_tracker,
+			match_sinks: Vec::new(),
+			failure_tracker: None,
+			fetch_limiter: None,
+			interval_task: None,
 		})
 	}
 
 	/// Starts the network watcher
 	///
 	/// Initializes the scheduler and begins watching for new blocks according
-	/// to the network's cron schedule.
+	/// to the network's cron schedule. Schedules of the form `@interval:<duration>`
+	/// bypass the cron scheduler and poll on a plain tokio interval instead, which
+	/// allows sub-second polling. The `@adaptive` schedule also bypasses the cron
+	/// scheduler, polling near the expected time of the next block based on the block
+	/// cadence observed through the last processed block.
 	pub async fn start<C: BlockChainClient + Clone + Send + 'static>(
 		&mut self,
 		rpc_client: C,
@@ -160,22 +199,38 @@ where
 		let block_handler = self.block_handler.clone();
 		let trigger_handler = self.trigger_handler.clone();
 		let block_tracker = self.block_tracker.clone();
+		let match_sinks: Arc<[Arc<dyn MatchSink>]> = self.match_sinks.clone().into();
+		let failure_tracker = self.failure_tracker.clone();
+		let fetch_limiter = self.fetch_limiter.clone();
+		let pending_blocks = PendingBlocks::default();
 
-		let job = Job::new_async(self.network.cron_schedule.as_str(), move |_uuid, _l| {
+		let run_once = move || {
 			let network = network.clone();
 			let block_storage = block_storage.clone();
 			let block_handler = block_handler.clone();
 			let block_tracker = block_tracker.clone();
 			let rpc_client = rpc_client.clone();
 			let trigger_handler = trigger_handler.clone();
+			let match_sinks = match_sinks.clone();
+			let failure_tracker = failure_tracker.clone();
+			let fetch_limiter = fetch_limiter.clone();
+			let pending_blocks = pending_blocks.clone();
 			Box::pin(async move {
-				let _ = process_new_blocks(
+				// Wait for a slot shared with the other networks, held for the whole poll
+				let _permit = match &fetch_limiter {
+					Some(fetch_limiter) => Some(fetch_limiter.acquire().await),
+					None => None,
+				};
+
+				let result = process_new_blocks_with_sinks(
 					&network,
 					&rpc_client,
 					block_storage,
 					block_handler,
 					trigger_handler,
 					block_tracker,
+					match_sinks,
+					pending_blocks,
 				)
 				.await
 				.map_err(|e| {
@@ -188,7 +243,82 @@ where
 						)])),
 					)
 				});
-			})
+
+				if let Some(failure_tracker) = failure_tracker {
+					match result {
+						Ok(()) => failure_tracker.record_success(&network.slug),
+						Err(e) => {
+							failure_tracker.record_failure(&network.slug, &e.to_string());
+						}
+					}
+				}
+			}) as BoxFuture<'static, ()>
+		};
+
+		if is_adaptive_schedule(&self.network.cron_schedule) {
+			let block_storage = self.block_storage.clone();
+			let network_slug = self.network.slug.clone();
+			let mut state = AdaptivePollingState::new(self.network.block_time_ms);
+			// Runs are sequential; the next poll is scheduled once the current run completes
+			self.interval_task = Some(tokio::spawn(async move {
+				loop {
+					run_once().await;
+					let last_processed_block = block_storage
+						.get_last_processed_block(&network_slug)
+						.await
+						.ok()
+						.flatten();
+					let next_poll = state.observe(last_processed_block, Instant::now());
+					tracing::debug!(
+						network = %network_slug,
+						"Next adaptive poll in {:?} (estimated block time: {:?})",
+						next_poll,
+						state.estimated_block_time()
+					);
+					tokio::time::sleep(next_poll).await;
+				}
+			}));
+
+			tracing::info!(
+				"Started block watcher for network: {} (adaptive polling)",
+				self.network.slug
+			);
+			return Ok(());
+		}
+
+		let interval = parse_interval_schedule(&self.network.cron_schedule).map_err(|e| {
+			BlockWatcherError::scheduler_error(
+				e,
+				None,
+				Some(HashMap::from([(
+					"network".to_string(),
+					self.network.slug.clone(),
+				)])),
+			)
+		})?;
+
+		if let Some(interval) = interval {
+			// Blocks are processed sequentially; ticks missed while a run is still in
+			// progress are skipped rather than queued up.
+			self.interval_task = Some(tokio::spawn(async move {
+				let mut ticker = tokio::time::interval(interval);
+				ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+				loop {
+					ticker.tick().await;
+					run_once().await;
+				}
+			}));
+
+			tracing::info!(
+				"Started block watcher for network: {} (interval: {:?})",
+				self.network.slug,
+				interval
+			);
+			return Ok(());
+		}
+
+		let job = Job::new_async(self.network.cron_schedule.as_str(), move |_uuid, _l| {
+			run_once()
 		})
 		.with_context(|| "Failed to create job")?;
 
@@ -220,8 +350,14 @@ where
 
 	/// Stops the network watcher
 	///
-	/// Shuts down the scheduler and stops watching for new blocks.
+	/// Shuts down the scheduler (or interval polling task) and stops watching for new blocks.
 	pub async fn stop(&mut self) -> Result<(), BlockWatcherError> {
+		if let Some(task) = self.interval_task.take() {
+			task.abort();
+			tracing::info!("Stopped block watcher for network: {}", self.network.slug);
+			return Ok(());
+		}
+
 		self.scheduler.shutdown().await.map_err(|e| {
 			BlockWatcherError::scheduler_error(
 				e.to_string(),
@@ -242,7 +378,7 @@ impl<S, H, T, J> BlockWatcherService<S, H, T, J>
 where
 	S: BlockStorage + Send + Sync + 'static,
 	H: Fn(BlockType, Network) -> BoxFuture<'static, ProcessedBlock> + Send + Sync + 'static,
-	T: Fn(&ProcessedBlock) -> tokio::task::JoinHandle<()> + Send + Sync + 'static,
+	T: Fn(&ProcessedBlock) -> tokio::task::JoinHandle<TriggerCompletion> + Send + Sync + 'static,
 	J: JobSchedulerTrait,
 {
 	/// Creates a new block watcher service
@@ -263,9 +399,46 @@ where
 			trigger_handler,
 			active_watchers: Arc::new(RwLock::new(HashMap::new())),
 			block_tracker,
+			match_sinks: Vec::new(),
+			failure_tracker: None,
+			fetch_limiter: None,
 		})
 	}
 
+	/// Registers a sink receiving every match emitted by the block watchers
+	///
+	/// Sinks only apply to network watchers started after they are registered.
+	///
+	/// # Arguments
+	/// * `sink` - The sink to forward matches to
+	pub fn add_match_sink(&mut self, sink: Arc<dyn MatchSink>) {
+		self.match_sinks.push(sink);
+	}
+
+	/// Enables fail-fast tracking of the block watchers' failures
+	///
+	/// Failures of the network watchers to process blocks are recorded on the tracker, while
+	/// failures to start a watcher are left to the caller. The tracker only applies to network
+	/// watchers started after it is set.
+	///
+	/// # Arguments
+	/// * `failure_tracker` - The tracker to record failures on
+	pub fn set_failure_tracker(&mut self, failure_tracker: WatcherFailureTracker) {
+		self.failure_tracker = Some(failure_tracker);
+	}
+
+	/// Limits the number of networks polled concurrently across the block watchers
+	///
+	/// Polls over the limit wait for another network's poll to complete, so each network
+	/// keeps its own schedule. The limiter only applies to network watchers started after it
+	/// is set.
+	///
+	/// # Arguments
+	/// * `fetch_limiter` - The limiter shared by the network watchers
+	pub fn set_fetch_limiter(&mut self, fetch_limiter: NetworkFetchLimiter) {
+		self.fetch_limiter = Some(fetch_limiter);
+	}
+
 	/// Starts a watcher for a specific network
 	///
 	/// # Arguments
@@ -293,6 +466,9 @@ where
 			self.block_tracker.clone(),
 		)
 		.await?;
+		watcher.match_sinks = self.match_sinks.clone();
+		watcher.failure_tracker = self.failure_tracker.clone();
+		watcher.fetch_limiter = self.fetch_limiter.clone();
 
 		watcher.start(rpc_client).await?;
 		watchers.insert(network.slug.clone(), watcher);
@@ -317,6 +493,11 @@ where
 
 /// Processes new blocks for a network
 ///
+/// Each block is marked in progress in storage before it is filtered and marked complete
+/// once its triggers have been executed. Blocks left in progress by an interrupted run
+/// are processed again, even when the last processed block has moved past them, while
+/// blocks that already completed are skipped.
+///
 /// # Arguments
 /// * `network` - Network configuration
 /// * `rpc_client` - RPC client for the network
@@ -327,12 +508,11 @@ where
 ///
 /// # Returns
 /// * `Result<(), BlockWatcherError>` - Success or error
-#[instrument(skip_all, fields(network = network.slug))]
 pub async fn process_new_blocks<
-	S: BlockStorage,
+	S: BlockStorage + 'static,
 	C: BlockChainClient + Send + Clone + 'static,
 	H: Fn(BlockType, Network) -> BoxFuture<'static, ProcessedBlock> + Send + Sync + 'static,
-	T: Fn(&ProcessedBlock) -> tokio::task::JoinHandle<()> + Send + Sync + 'static,
+	T: Fn(&ProcessedBlock) -> tokio::task::JoinHandle<TriggerCompletion> + Send + Sync + 'static,
 	TR: BlockTrackerTrait<S>,
 >(
 	network: &Network,
@@ -341,6 +521,58 @@ pub async fn process_new_blocks<
 	block_handler: Arc<H>,
 	trigger_handler: Arc<T>,
 	block_tracker: Arc<TR>,
+) -> Result<(), BlockWatcherError> {
+	process_new_blocks_with_sinks(
+		network,
+		rpc_client,
+		block_storage,
+		block_handler,
+		trigger_handler,
+		block_tracker,
+		Arc::from([]),
+		PendingBlocks::default(),
+	)
+	.await
+}
+
+/// Processes new blocks for a network, forwarding every emitted match to the given sinks
+///
+/// Behaves like [`process_new_blocks`]. Matches are forwarded in block order, right after the
+/// block's triggers have been dispatched. Sink errors are logged and do not abort processing.
+///
+/// Blocks in `pending_blocks` were started by an earlier call of the same run, e.g. blocks
+/// whose triggers are still queued, and are not processed again. Blocks are added when they
+/// are marked in progress and removed once they are marked complete.
+///
+/// # Arguments
+/// * `network` - Network configuration
+/// * `rpc_client` - RPC client for the network
+/// * `block_storage` - Storage implementation for blocks
+/// * `block_handler` - Handler function for processed blocks
+/// * `trigger_handler` - Handler function for processed blocks
+/// * `block_tracker` - Tracker implementation for block processing
+/// * `match_sinks` - Sinks receiving every emitted match
+/// * `pending_blocks` - Blocks started by the running watcher that have not completed
+///
+/// # Returns
+/// * `Result<(), BlockWatcherError>` - Success or error
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all, fields(network = network.slug))]
+pub async fn process_new_blocks_with_sinks<
+	S: BlockStorage + 'static,
+	C: BlockChainClient + Send + Clone + 'static,
+	H: Fn(BlockType, Network) -> BoxFuture<'static, ProcessedBlock> + Send + Sync + 'static,
+	T: Fn(&ProcessedBlock) -> tokio::task::JoinHandle<TriggerCompletion> + Send + Sync + 'static,
+	TR: BlockTrackerTrait<S>,
+>(
+	network: &Network,
+	rpc_client: &C,
+	block_storage: Arc<S>,
+	block_handler: Arc<H>,
+	trigger_handler: Arc<T>,
+	block_tracker: Arc<TR>,
+	match_sinks: Arc<[Arc<dyn MatchSink>]>,
+	pending_blocks: PendingBlocks,
 ) -> Result<(), BlockWatcherError> {
 	let start_time = std::time::Instant::now();
 
@@ -350,26 +582,15 @@ pub async fn process_new_blocks<
 		.with_context(|| "Failed to get last processed block")?
 		.unwrap_or(0);
 
-	let latest_block = rpc_client
-		.get_latest_block_number()
-		.await
-		.with_context(|| "Failed to get latest block number")?;
+	let latest_confirmed_block = get_latest_confirmed_block(network, rpc_client).await?;
 
-	let latest_confirmed_block = latest_block.saturating_sub(network.confirmation_blocks);
+	let max_past_blocks = network.get_max_past_blocks();
 
-	let recommended_past_blocks = network.get_recommended_past_blocks();
-
-	let max_past_blocks = network.max_past_blocks.unwrap_or(recommended_past_blocks);
-
-	// Calculate the start block number, using the default if max_past_blocks is not set
-	let start_block = std::cmp::max(
-		last_processed_block + 1,
-		latest_confirmed_block.saturating_sub(max_past_blocks),
-	);
+	let start_block = backfill_start_block(network, last_processed_block, latest_confirmed_block);
 
 	tracing::info!(
 		"Processing blocks:\n\tLast processed block: {}\n\tLatest confirmed block: {}\n\tStart \
-		 block: {}{}\n\tConfirmations required: {}\n\tMax past blocks: {}",
+		 block: {}{}\n\tFinality: {}\n\tMax past blocks: {}",
 		last_processed_block,
 		latest_confirmed_block,
 		start_block,
@@ -381,7 +602,7 @@ pub async fn process_new_blocks<
 		} else {
 			String::new()
 		},
-		network.confirmation_blocks,
+		network.finality(),
 		max_past_blocks
 	);
 
@@ -392,16 +613,58 @@ pub async fn process_new_blocks<
 			.await
 			.with_context(|| format!("Failed to get block {}", latest_confirmed_block))?;
 	} else if last_processed_block < latest_confirmed_block {
-		blocks = rpc_client
-			.get_blocks(start_block, Some(latest_confirmed_block))
+		blocks = fetch_block_range(
+			rpc_client,
+			start_block,
+			latest_confirmed_block,
+			network.backfill_concurrency,
+		)
+		.await?;
+		BACKFILL_BLOCKS_TOTAL
+			.with_label_values(&[&network.slug])
+			.inc_by(latest_confirmed_block.saturating_sub(start_block) + 1);
+	}
+
+	// Resume from checkpoints left by an interrupted run: blocks that started but never
+	// completed are processed again, even below the last processed block, and blocks whose
+	// triggers were executed are skipped. Blocks still pending in this run are left alone
+	let checkpoints = block_storage
+		.get_checkpoints(&network.slug)
+		.await
+		.with_context(|| "Failed to get block checkpoints")?;
+
+	blocks.retain(|block| {
+		let number = block.number().unwrap_or(0);
+		!checkpoints.completed.contains(&number) && !pending_blocks.contains(number)
+	});
+
+	let interrupted_blocks = checkpoints
+		.in_progress
+		.iter()
+		.copied()
+		.filter(|&number| number <= latest_confirmed_block && !pending_blocks.contains(number))
+		.collect::<Vec<_>>();
+	if !interrupted_blocks.is_empty() {
+		tracing::warn!(
+			"Re-processing {} block(s) interrupted before completion: {:?}",
+			interrupted_blocks.len(),
+			interrupted_blocks
+		);
+	}
+	for block_number in interrupted_blocks {
+		if blocks
+			.iter()
+			.any(|block| block.number() == Some(block_number))
+		{
+			continue;
+		}
+		let interrupted = rpc_client
+			.get_blocks(block_number, None)
 			.await
-			.with_context(|| {
-				format!(
-					"Failed to get blocks from {} to {}",
-					start_block, latest_confirmed_block
-				)
-			})?;
+			.with_context(|| format!("Failed to get block {}", block_number))?;
+		blocks.extend(interrupted);
 	}
+	blocks.sort_by_key(|block| block.number().unwrap_or(0));
 
 	// Create channels for our pipeline
 	let (process_tx, process_rx) = mpsc::channel::<(BlockType, u64)>(blocks.len() * 2);
@@ -411,6 +674,7 @@ pub async fn process_new_blocks<
 	let process_handle = tokio::spawn({
 		let network = network.clone();
 		let block_handler = block_handler.clone();
+		let block_storage = block_storage.clone();
 		let mut trigger_tx = trigger_tx.clone();
 
 		async move {
@@ -419,7 +683,16 @@ pub async fn process_new_blocks<
 				.map(|(block, _)| {
 					let network = network.clone();
 					let block_handler = block_handler.clone();
-					async move { (block_handler)(block, network).await }
+					let block_storage = block_storage.clone();
+					async move {
+						handle_block_with_watchdog(
+							block_handler.as_ref(),
+							block_storage,
+							block,
+							network,
+						)
+						.await
+					}
 				})
 				.buffer_unordered(32);
 
@@ -435,14 +708,100 @@ pub async fn process_new_blocks<
 		}
 	});
 
+	// Blocks before the network's `emit_matches_after` cutoff are processed without firing
+	// triggers, so the cursor still advances past historical matches
+	let silenced_blocks = network
+		.emit_matches_after
+		.map(|cutoff| {
+			blocks
+				.iter()
+				.filter(|block| !cutoff.emits(block.number().unwrap_or(0), block.timestamp()))
+				.map(|block| block.number().unwrap_or(0))
+				.collect::<HashSet<_>>()
+		})
+		.unwrap_or_default();
+
 	// Stage 2: Trigger Pipeline
 	let trigger_handle = tokio::spawn({
 		let trigger_handler = trigger_handler.clone();
+		let block_storage = block_storage.clone();
+		let pending_blocks = pending_blocks.clone();
+		let network_slug = network.slug.clone();
+		let mut expected_blocks = blocks
+			.iter()
+			.map(|block| block.number().unwrap_or(0))
+			.collect::<VecDeque<_>>();
 
 		async move {
+			let dispatch = |block: ProcessedBlock| {
+				let trigger_handler = trigger_handler.clone();
+				let block_storage = block_storage.clone();
+				let network_slug = network_slug.clone();
+				let match_sinks = match_sinks.clone();
+				let pending_blocks = pending_blocks.clone();
+				let silenced = silenced_blocks.contains(&block.block_number);
+				async move {
+					let completion = if silenced {
+						if !block.processing_results.is_empty() {
+							tracing::debug!(
+								"Suppressing {} match(es) in block {} before emit_matches_after",
+								block.processing_results.len(),
+								block.block_number
+							);
+						}
+						TriggerCompletion::completed()
+					} else {
+						// Waiting for the handler propagates the trigger queue's backpressure
+						let completion = match (trigger_handler)(&block).await {
+							Ok(completion) => completion,
+							Err(e) => {
+								tracing::error!(
+									"Trigger handler failed for block {}: {}",
+									block.block_number,
+									e
+								);
+								TriggerCompletion::abandoned()
+							}
+						};
+						forward_to_sinks(&match_sinks, &block).await;
+						completion
+					};
+
+					// A block is only marked complete once its triggers have been executed. Blocks
+					// still waiting in the trigger queue are marked complete by a separate task, so
+					// the queue keeps decoupling block processing from trigger execution
+					let block_number = block.block_number;
+					let mut executed = Box::pin(completion.wait());
+					match futures::poll!(&mut executed) {
+						Poll::Ready(executed) => {
+							mark_block_complete(
+								&*block_storage,
+								&pending_blocks,
+								&network_slug,
+								block_number,
+								executed,
+							)
+							.await
+						}
+						Poll::Pending => {
+							tokio::spawn(async move {
+								let executed = executed.await;
+								mark_block_complete(
+									&*block_storage,
+									&pending_blocks,
+									&network_slug,
+									block_number,
+									executed,
+								)
+								.await
+							});
+						}
+					}
+				}
+			};
+
 			let mut trigger_rx = trigger_rx;
 			let mut pending_blocks = BTreeMap::new();
-			let mut next_block_number = Some(start_block);
 
 			// Process all incoming blocks
 			while let Some(processed_block) = trigger_rx.next().await {
@@ -450,10 +809,10 @@ pub async fn process_new_blocks<
 				pending_blocks.insert(block_number, processed_block);
 
 				// Process blocks in order as long as we have the next expected block
-				while let Some(expected) = next_block_number {
-					if let Some(block) = pending_blocks.remove(&expected) {
-						(trigger_handler)(&block);
-						next_block_number = Some(expected + 1);
+				while let Some(expected) = expected_blocks.front() {
+					if let Some(block) = pending_blocks.remove(expected) {
+						expected_blocks.pop_front();
+						dispatch(block).await;
 					} else {
 						break;
 					}
@@ -463,7 +822,7 @@ pub async fn process_new_blocks<
 			// Process any remaining blocks in order after the channel is closed
 			while let Some(min_block) = pending_blocks.keys().next().copied() {
 				if let Some(block) = pending_blocks.remove(&min_block) {
-					(trigger_handler)(&block);
+					dispatch(block).await;
 				}
 			}
 			Ok::<(), BlockWatcherError>(())
@@ -474,6 +833,8 @@ pub async fn process_new_blocks<
 	futures::future::join_all(blocks.iter().map(|block| {
 		let network = network.clone();
 		let block_tracker = block_tracker.clone();
+		let block_storage = block_storage.clone();
+		let pending_blocks = pending_blocks.clone();
 		let mut process_tx = process_tx.clone();
 		async move {
 			let block_number = block.number().unwrap_or(0);
@@ -481,6 +842,13 @@ pub async fn process_new_blocks<
 			// Record block in tracker
 			block_tracker.record_block(&network, block_number).await?;
 
+			// Checkpoint the block before it is filtered
+			block_storage
+				.mark_in_progress(&network.slug, block_number)
+				.await
+				.with_context(|| format!("Failed to mark block {} as in progress", block_number))?;
+			pending_blocks.insert(block_number);
+
 			// Send block to processing pipeline
 			process_tx
 				.send((block.clone(), block_number))
@@ -528,3 +896,286 @@ pub async fn process_new_blocks<
 
 	Ok(())
 }
+
+/// Marks a block complete once its triggers have been executed
+///
+/// Blocks whose triggers were dropped from the trigger queue or abandoned on shutdown are left
+/// in progress, and pending, so that they are processed again once the watcher restarts.
+///
+/// # Arguments
+/// * `block_storage` - Storage implementation for blocks
+/// * `pending_blocks` - Blocks started by the running watcher that have not completed
+/// * `network_slug` - Network the block belongs to
+/// * `block_number` - Number of the block
+/// * `executed` - Whether the block's triggers have been executed
+async fn mark_block_complete<S: BlockStorage>(
+	block_storage: &S,
+	pending_blocks: &PendingBlocks,
+	network_slug: &str,
+	block_number: u64,
+	executed: bool,
+) {
+	if !executed {
+		tracing::warn!(
+			"Triggers of block {} were not executed, leaving the block in progress",
+			block_number
+		);
+		return;
+	}
+	match block_storage
+		.mark_complete(network_slug, block_number)
+		.await
+	{
+		Ok(()) => pending_blocks.remove(block_number),
+		Err(e) => tracing::error!("Failed to mark block {} as complete: {}", block_number, e),
+	}
+}
+
+/// Runs the block handler under the network's block processing watchdog
+///
+/// When `max_block_processing_ms` is set, processing that exceeds it is cancelled and retried
+/// up to `block_processing_retries` times. A block that still times out is skipped: it is
+/// recorded as missed and an empty processed block is returned, so that the watcher keeps
+/// processing the following blocks.
+///
+/// # Arguments
+/// * `block_handler` - Handler function for processed blocks
+/// * `block_storage` - Storage implementation for blocks
+/// * `block` - Block to process
+/// * `network` - Network the block belongs to
+///
+/// # Returns
+/// * `ProcessedBlock` - The processed block, without matches if it was skipped
+async fn handle_block_with_watchdog<S, H>(
+	block_handler: &H,
+	block_storage: Arc<S>,
+	block: BlockType,
+	network: Network,
+) -> ProcessedBlock
+where
+	S: BlockStorage,
+	H: Fn(BlockType, Network) -> BoxFuture<'static, ProcessedBlock>,
+{
+	let Some(max_block_processing_ms) = network.max_block_processing_ms else {
+		return (block_handler)(block, network).await;
+	};
+
+	let block_number = block.number().unwrap_or(0);
+	let timeout = Duration::from_millis(max_block_processing_ms);
+	let attempts = network.block_processing_retries.unwrap_or(0) + 1;
+
+	for attempt in 1..=attempts {
+		match tokio::time::timeout(timeout, (block_handler)(block.clone(), network.clone())).await {
+			Ok(processed_block) => return processed_block,
+			Err(_) => {
+				BLOCK_PROCESSING_TIMEOUTS_TOTAL
+					.with_label_values(&[network.slug.as_str()])
+					.inc();
+				tracing::warn!(
+					"Processing of block {} exceeded {}ms (attempt {} of {})",
+					block_number,
+					max_block_processing_ms,
+					attempt,
+					attempts
+				);
+			}
+		}
+	}
+
+	BlockWatcherError::processing_error(
+		format!(
+			"Skipping block {} after its processing timed out {} time(s)",
+			block_number, attempts
+		),
+		None,
+		None,
+	);
+	if network.store_blocks.unwrap_or(false)
+		&& block_storage
+			.save_missed_block(&network.slug, block_number)
+			.await
+			.is_err()
+	{
+		BlockWatcherError::storage_error(
+			format!("Failed to store missed block {}", block_number),
+			None,
+			None,
+		);
+	}
+
+	ProcessedBlock {
+		block_number,
+		network_slug: network.slug,
+		processing_results: Vec::new(),
+	}
+}
+
+/// Returns the latest block that is final according to the network's finality
+///
+/// With the `finalized` or `safe` finality, the block with that tag is fetched and bounded by
+/// the latest block. Networks that do not support the tag fall back to `confirmation_blocks`
+/// confirmations on top of the latest block.
+///
+/// # Arguments
+/// * `network` - Network configuration
+/// * `rpc_client` - RPC client for the network
+///
+/// # Returns
+/// * `Result<u64, BlockWatcherError>` - Latest final block or error
+pub async fn get_latest_confirmed_block<C: BlockChainClient>(
+	network: &Network,
+	rpc_client: &C,
+) -> Result<u64, BlockWatcherError> {
+	let latest_block = rpc_client
+		.get_latest_block_number()
+		.await
+		.with_context(|| "Failed to get latest block number")?;
+
+	if let Some(tag) = network.finality().block_tag() {
+		let tagged_block = rpc_client
+			.get_block_number_by_tag(tag)
+			.await
+			.with_context(|| format!("Failed to get {} block number", tag))?;
+		match tagged_block {
+			Some(tagged_block) => return Ok(tagged_block.min(latest_block)),
+			None => tracing::warn!(
+				"Network '{}' does not support the '{}' block tag, falling back to {} \
+				 confirmation blocks",
+				network.slug,
+				tag,
+				network.confirmations()
+			),
+		}
+	}
+
+	Ok(latest_block.saturating_sub(network.confirmations()))
+}
+
+/// Returns the first block to process when catching up
+///
+/// At most `max_past_blocks` blocks before the latest confirmed block are processed, capped
+/// to the network's `max_past_blocks_limit`. A warning is logged when the cap shortens the
+/// backfill of a misconfigured `max_past_blocks`.
+///
+/// # Arguments
+/// * `network` - Network configuration
+/// * `last_processed_block` - Last processed block, `0` when no block was processed yet
+/// * `latest_confirmed_block` - Latest block with enough confirmations
+///
+/// # Returns
+/// * `u64` - First block to process
+fn backfill_start_block(
+	network: &Network,
+	last_processed_block: u64,
+	latest_confirmed_block: u64,
+) -> u64 {
+	let start_block = std::cmp::max(
+		last_processed_block + 1,
+		latest_confirmed_block.saturating_sub(network.get_max_past_blocks()),
+	);
+
+	if let Some(configured_past_blocks) = network.max_past_blocks {
+		let unclamped_start_block = std::cmp::max(
+			last_processed_block + 1,
+			latest_confirmed_block.saturating_sub(configured_past_blocks),
+		);
+		if last_processed_block > 0 && unclamped_start_block < start_block {
+			tracing::warn!(
+				"Network '{}' max_past_blocks ({}) exceeds max_past_blocks_limit ({}), \
+				 processing blocks from {} instead of {}",
+				network.slug,
+				configured_past_blocks,
+				network.get_max_past_blocks_limit(),
+				start_block,
+				unclamped_start_block
+			);
+		}
+	}
+
+	start_block
+}
+
+/// Fetches all blocks from `start_block` to `end_block` (inclusive) in block order
+///
+/// Without a backfill concurrency the range is requested with a single call. Otherwise each
+/// block is requested on its own with at most `concurrency` requests in flight. Requests go
+/// through the client's transport, so rate-limited endpoints are rotated and retried by its
+/// endpoint manager.
+///
+/// # Arguments
+/// * `rpc_client` - RPC client for the network
+/// * `start_block` - First block to fetch
+/// * `end_block` - Last block to fetch
+/// * `concurrency` - Maximum number of concurrent block requests
+///
+/// # Returns
+/// * `Result<Vec<BlockType>, BlockWatcherError>` - Blocks ordered by number or error
+async fn fetch_block_range<C: BlockChainClient>(
+	rpc_client: &C,
+	start_block: u64,
+	end_block: u64,
+	concurrency: Option<usize>,
+) -> Result<Vec<BlockType>, BlockWatcherError> {
+	match concurrency {
+		Some(concurrency) if concurrency > 1 && end_block > start_block => {
+			let blocks = futures::stream::iter(start_block..=end_block)
+				.map(|block_number| async move {
+					rpc_client
+						.get_blocks(block_number, None)
+						.await
+						.with_context(|| format!("Failed to get block {}", block_number))
+				})
+				.buffered(concurrency)
+				.try_collect::<Vec<_>>()
+				.await?;
+			Ok(blocks.into_iter().flatten().collect())
+		}
+		_ => Ok(rpc_client
+			.get_blocks(start_block, Some(end_block))
+			.await
+			.with_context(|| {
+				format!("Failed to get blocks from {} to {}", start_block, end_block)
+			})?),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::utils::tests::network::NetworkBuilder;
+	use tracing_test::traced_test;
+
+	#[test]
+	#[traced_test]
+	fn test_backfill_start_block_clamps_max_past_blocks() {
+		let network = NetworkBuilder::new()
+			.slug("test_network")
+			.max_past_blocks(1_000_000)
+			.max_past_blocks_limit(100)
+			.build();
+
+		// The backfill window is capped to the limit
+		assert_eq!(backfill_start_block(&network, 10, 10_000), 9_900);
+		assert!(logs_contain(
+			"Network 'test_network' max_past_blocks (1000000) exceeds max_past_blocks_limit (100)"
+		));
+	}
+
+	#[test]
+	#[traced_test]
+	fn test_backfill_start_block_within_limit() {
+		let network = NetworkBuilder::new()
+			.max_past_blocks(50)
+			.max_past_blocks_limit(100)
+			.build();
+		assert_eq!(backfill_start_block(&network, 10, 10_000), 9_950);
+
+		// Caught up networks are not affected by the limit
+		let network = NetworkBuilder::new()
+			.max_past_blocks(1_000_000)
+			.max_past_blocks_limit(100)
+			.build();
+		assert_eq!(backfill_start_block(&network, 9_990, 10_000), 9_991);
+		assert!(!logs_contain("exceeds max_past_blocks_limit"));
+	}
+}
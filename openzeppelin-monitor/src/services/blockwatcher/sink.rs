@@ -0,0 +1,209 @@
+//! Match sink extension point.
+//!
+//! Match sinks receive every match emitted by the block processing pipeline, in addition to
+//! the configured triggers. They allow library users to forward matches to their own code
+//! path, for example to persist them to a database. The built-in [`NdjsonSink`] writes
+//! them as newline-delimited JSON, e.g. to stdout for piping into other tools.
+
+use std::{
+	io::Write,
+	sync::{Arc, Mutex},
+};
+
+use crate::models::{MonitorMatch, ProcessedBlock};
+
+/// Receiver for matches emitted by the block watcher
+///
+/// Sinks are called in block order, once for every match found in a block and before the
+/// monitor's trigger conditions are evaluated. Errors returned by a sink are logged and
+/// never abort block processing.
+#[async_trait::async_trait]
+pub trait MatchSink: Send + Sync {
+	/// Called for each match emitted by the block processing pipeline
+	///
+	/// # Arguments
+	/// * `monitor_match` - The emitted match
+	async fn on_match(
+		&self,
+		monitor_match: &MonitorMatch,
+	) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+}
+
+/// Sink writing each match as a single line of JSON (NDJSON)
+///
+/// Lines are flushed as soon as they are written, so that readers of a pipe receive each
+/// match as it is produced.
+pub struct NdjsonSink {
+	writer: Mutex<Box<dyn Write + Send>>,
+}
+
+impl NdjsonSink {
+	/// Creates a sink writing to the given writer
+	pub fn new(writer: impl Write + Send + 'static) -> Self {
+		Self {
+			writer: Mutex::new(Box::new(writer)),
+		}
+	}
+
+	/// Creates a sink writing to stdout
+	pub fn stdout() -> Self {
+		Self::new(std::io::stdout())
+	}
+}
+
+#[async_trait::async_trait]
+impl MatchSink for NdjsonSink {
+	async fn on_match(
+		&self,
+		monitor_match: &MonitorMatch,
+	) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+		let line = serde_json::to_string(monitor_match)?;
+		let mut writer = self.writer.lock().unwrap_or_else(|e| e.into_inner());
+		writeln!(writer, "{}", line)?;
+		writer.flush()?;
+		Ok(())
+	}
+}
+
+/// Forwards every match of a processed block to the given sinks
+///
+/// # Arguments
+/// * `sinks` - Sinks to forward the matches to
+/// * `block` - The processed block whose matches are forwarded
+pub(crate) async fn forward_to_sinks(sinks: &[Arc<dyn MatchSink>], block: &ProcessedBlock) {
+	for monitor_match in &block.processing_results {
+		for sink in sinks {
+			if let Err(e) = sink.on_match(monitor_match).await {
+				tracing::error!(
+					"Match sink failed for block {} on network {}: {}",
+					block.block_number,
+					block.network_slug,
+					e
+				);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{EVMMonitorMatch, EVMTransaction, MatchConditions},
+		utils::tests::builders::evm::monitor::MonitorBuilder,
+	};
+	use std::{
+		collections::HashMap,
+		sync::atomic::{AtomicUsize, Ordering},
+	};
+	use tracing_test::traced_test;
+
+	#[derive(Default)]
+	struct CountingSink {
+		count: AtomicUsize,
+	}
+
+	#[async_trait::async_trait]
+	impl MatchSink for CountingSink {
+		async fn on_match(
+			&self,
+			_monitor_match: &MonitorMatch,
+		) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+			self.count.fetch_add(1, Ordering::SeqCst);
+			Ok(())
+		}
+	}
+
+	struct FailingSink;
+
+	#[async_trait::async_trait]
+	impl MatchSink for FailingSink {
+		async fn on_match(
+			&self,
+			_monitor_match: &MonitorMatch,
+		) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+			Err("database unavailable".into())
+		}
+	}
+
+	fn create_processed_block(matches: usize) -> ProcessedBlock {
+		let monitor_match = MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new().name("test").build(),
+			transaction: EVMTransaction::default(),
+			receipt: None,
+			revert_reason: None,
+			logs: None,
+			block_timestamp: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: None,
+			enrichment: HashMap::new(),
+		}));
+		ProcessedBlock {
+			block_number: 100,
+			network_slug: "ethereum_mainnet".to_string(),
+			processing_results: vec![monitor_match; matches],
+		}
+	}
+
+	#[tokio::test]
+	#[traced_test]
+	async fn test_forward_to_sinks_continues_after_sink_error() {
+		let counting_sink = Arc::new(CountingSink::default());
+		let sinks: Vec<Arc<dyn MatchSink>> = vec![Arc::new(FailingSink), counting_sink.clone()];
+
+		forward_to_sinks(&sinks, &create_processed_block(3)).await;
+
+		assert_eq!(counting_sink.count.load(Ordering::SeqCst), 3);
+		assert!(logs_contain(
+			"Match sink failed for block 100 on network ethereum_mainnet: database unavailable"
+		));
+	}
+
+	/// Writer sharing its buffer, to read what a sink wrote
+	#[derive(Clone, Default)]
+	struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+	impl Write for SharedBuffer {
+		fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+			self.0.lock().unwrap().write(buf)
+		}
+
+		fn flush(&mut self) -> std::io::Result<()> {
+			Ok(())
+		}
+	}
+
+	#[tokio::test]
+	async fn test_ndjson_sink_writes_one_line_per_match() {
+		let buffer = SharedBuffer::default();
+		let sinks: Vec<Arc<dyn MatchSink>> = vec![Arc::new(NdjsonSink::new(buffer.clone()))];
+
+		forward_to_sinks(&sinks, &create_processed_block(3)).await;
+
+		let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+		assert!(output.ends_with('\n'));
+		let lines = output.lines().collect::<Vec<_>>();
+		assert_eq!(lines.len(), 3);
+		for line in lines {
+			let monitor_match: MonitorMatch = serde_json::from_str(line).unwrap();
+			match monitor_match {
+				MonitorMatch::EVM(evm_match) => {
+					assert_eq!(evm_match.monitor.name, "test");
+					assert_eq!(evm_match.network_slug, "ethereum_mainnet");
+				}
+				_ => panic!("Expected an EVM match"),
+			}
+		}
+	}
+
+	#[tokio::test]
+	async fn test_forward_to_sinks_without_matches() {
+		let counting_sink = Arc::new(CountingSink::default());
+		let sinks: Vec<Arc<dyn MatchSink>> = vec![counting_sink.clone()];
+
+		forward_to_sinks(&sinks, &create_processed_block(0)).await;
+
+		assert_eq!(counting_sink.count.load(Ordering::SeqCst), 0);
+	}
+}
@@ -2,16 +2,111 @@
 //!
 //! This module provides storage interfaces and implementations for persisting
 //! blockchain blocks and tracking processing state. Currently supports:
-//! - File-based storage with JSON serialization
+//! - File-based storage with JSON serialization, optionally gzip compressed
 //! - Last processed block tracking
-//! - Block deletion for cleanup
+//! - Two-phase block checkpoints for resuming interrupted processing
+//! - Block deletion for cleanup, and retention caps bounding the disk usage
 
 use async_trait::async_trait;
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
 use glob::glob;
-use std::path::PathBuf;
+use std::{
+	collections::BTreeSet,
+	env,
+	io::{Read, Write},
+	path::{Path, PathBuf},
+	sync::Arc,
+	time::Duration,
+};
+use tokio::sync::Mutex;
 
 use crate::models::BlockType;
 
+/// Environment variable enabling gzip compression of stored block files
+pub const BLOCK_STORAGE_COMPRESSION_ENV: &str = "BLOCK_STORAGE_COMPRESSION";
+
+/// Environment variable setting the number of block files kept per network
+pub const BLOCK_STORAGE_MAX_FILES_ENV: &str = "BLOCK_STORAGE_MAX_FILES";
+
+/// Environment variable setting the age in seconds after which block files are deleted
+pub const BLOCK_STORAGE_MAX_AGE_SECS_ENV: &str = "BLOCK_STORAGE_MAX_AGE_SECS";
+
+/// Environment variable setting the number of missed blocks kept per network
+pub const BLOCK_STORAGE_MAX_MISSED_BLOCKS_ENV: &str = "BLOCK_STORAGE_MAX_MISSED_BLOCKS";
+
+/// Storage options of `FileBlockStorage`
+///
+/// Without retention caps, stored block files and missed block lists are kept indefinitely.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FileBlockStorageConfig {
+	/// Whether block files are gzip compressed
+	pub compression: bool,
+	/// Number of block files kept per network, the oldest being deleted first
+	pub max_block_files: Option<usize>,
+	/// Age after which block files are deleted
+	pub max_age: Option<Duration>,
+	/// Number of missed blocks kept per network, the oldest being dropped first
+	pub max_missed_blocks: Option<usize>,
+}
+
+impl FileBlockStorageConfig {
+	/// Reads the storage options from the environment.
+	///
+	/// Uses `BLOCK_STORAGE_COMPRESSION`, `BLOCK_STORAGE_MAX_FILES`,
+	/// `BLOCK_STORAGE_MAX_AGE_SECS` and `BLOCK_STORAGE_MAX_MISSED_BLOCKS`, falling back to
+	/// the defaults for unset variables.
+	pub fn from_env() -> Result<Self, anyhow::Error> {
+		let compression = match env::var(BLOCK_STORAGE_COMPRESSION_ENV) {
+			Ok(value) => value.trim().parse::<bool>().map_err(|_| {
+				anyhow::anyhow!(
+					"Invalid {} value '{}': expected 'true' or 'false'",
+					BLOCK_STORAGE_COMPRESSION_ENV,
+					value
+				)
+			})?,
+			Err(_) => false,
+		};
+
+		Ok(Self {
+			compression,
+			max_block_files: positive_env_var(BLOCK_STORAGE_MAX_FILES_ENV)?
+				.map(|value| value as usize),
+			max_age: positive_env_var(BLOCK_STORAGE_MAX_AGE_SECS_ENV)?.map(Duration::from_secs),
+			max_missed_blocks: positive_env_var(BLOCK_STORAGE_MAX_MISSED_BLOCKS_ENV)?
+				.map(|value| value as usize),
+		})
+	}
+}
+
+/// Reads an optional positive integer from an environment variable
+fn positive_env_var(name: &str) -> Result<Option<u64>, anyhow::Error> {
+	match env::var(name) {
+		Ok(value) => match value.trim().parse::<u64>() {
+			Ok(parsed) if parsed > 0 => Ok(Some(parsed)),
+			_ => Err(anyhow::anyhow!(
+				"Invalid {} value '{}': expected a positive integer",
+				name,
+				value
+			)),
+		},
+		Err(_) => Ok(None),
+	}
+}
+
+/// Checkpoint state of the blocks that are not yet covered by the last processed block
+///
+/// Blocks are marked in progress before they are filtered and complete once their
+/// triggers have been executed. A block that is in progress but not complete was either
+/// interrupted (e.g. by a crash) and must be processed again, or is still waiting for its
+/// triggers, even when the last processed block has moved past it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlockCheckpoints {
+	/// Blocks marked in progress that were never marked complete
+	pub in_progress: BTreeSet<u64>,
+	/// Blocks whose triggers have been executed
+	pub completed: BTreeSet<u64>,
+}
+
 /// Interface for block storage implementations
 ///
 /// Defines the required functionality for storing and retrieving blocks
@@ -69,6 +164,9 @@ pub trait BlockStorage: Clone + Send + Sync {
 
 	/// Saves a missed block for a network
 	///
+	/// # Note
+	/// Only the most recent `max_missed_blocks` entries are kept when that cap is set
+	///
 	/// # Arguments
 	/// * `network_id` - Unique identifier for the network
 	/// * `block` - Block number to save
@@ -76,6 +174,35 @@ pub trait BlockStorage: Clone + Send + Sync {
 	/// # Returns
 	/// * `Result<(), anyhow::Error>` - Success or error
 	async fn save_missed_block(&self, network_id: &str, block: u64) -> Result<(), anyhow::Error>;
+
+	/// Records that a block is about to be processed
+	///
+	/// # Arguments
+	/// * `network_id` - Unique identifier for the network
+	/// * `block` - Block number being processed
+	///
+	/// # Returns
+	/// * `Result<(), anyhow::Error>` - Success or error
+	async fn mark_in_progress(&self, network_id: &str, block: u64) -> Result<(), anyhow::Error>;
+
+	/// Records that a block has been processed and its triggers executed
+	///
+	/// # Arguments
+	/// * `network_id` - Unique identifier for the network
+	/// * `block` - Block number that completed
+	///
+	/// # Returns
+	/// * `Result<(), anyhow::Error>` - Success or error
+	async fn mark_complete(&self, network_id: &str, block: u64) -> Result<(), anyhow::Error>;
+
+	/// Retrieves the block checkpoints not yet covered by the last processed block
+	///
+	/// # Arguments
+	/// * `network_id` - Unique identifier for the network
+	///
+	/// # Returns
+	/// * `Result<BlockCheckpoints, anyhow::Error>` - Recorded checkpoints or error
+	async fn get_checkpoints(&self, network_id: &str) -> Result<BlockCheckpoints, anyhow::Error>;
 }
 
 /// File-based implementation of block storage
@@ -86,6 +213,10 @@ pub trait BlockStorage: Clone + Send + Sync {
 pub struct FileBlockStorage {
 	/// Base path for all storage files
 	storage_path: PathBuf,
+	/// Compression and retention options
+	config: FileBlockStorageConfig,
+	/// Serializes appends to the checkpoints files with their rewrites
+	checkpoints_lock: Arc<Mutex<()>>,
 }
 
 impl FileBlockStorage {
@@ -93,10 +224,255 @@ impl FileBlockStorage {
 	///
 	/// Initializes storage with the provided path
 	pub fn new(storage_path: PathBuf) -> Self {
-		FileBlockStorage { storage_path }
+		Self::with_config(storage_path, FileBlockStorageConfig::default())
+	}
+
+	/// Creates a new file-based block storage instance with storage options
+	///
+	/// # Arguments
+	/// * `storage_path` - Base path for all storage files
+	/// * `config` - Compression and retention options
+	pub fn with_config(storage_path: PathBuf, config: FileBlockStorageConfig) -> Self {
+		FileBlockStorage {
+			storage_path,
+			config,
+			checkpoints_lock: Arc::new(Mutex::new(())),
+		}
+	}
+
+	/// Retrieves the blocks stored for a network, oldest file first
+	///
+	/// Compressed block files are decompressed transparently.
+	///
+	/// # Arguments
+	/// * `network_id` - Unique identifier for the network
+	///
+	/// # Returns
+	/// * `Result<Vec<BlockType>, anyhow::Error>` - Stored blocks or error
+	pub async fn get_blocks(&self, network_id: &str) -> Result<Vec<BlockType>, anyhow::Error> {
+		let mut blocks = Vec::new();
+		for (_, path) in self.block_files(network_id)? {
+			let content = tokio::fs::read(&path)
+				.await
+				.map_err(|e| anyhow::anyhow!("Failed to read blocks: {}", e))?;
+			let json = if is_compressed(&path) {
+				let mut json = Vec::new();
+				GzDecoder::new(content.as_slice())
+					.read_to_end(&mut json)
+					.map_err(|e| anyhow::anyhow!("Failed to decompress blocks: {}", e))?;
+				json
+			} else {
+				content
+			};
+			let file_blocks: Vec<BlockType> = serde_json::from_slice(&json)
+				.map_err(|e| anyhow::anyhow!("Failed to parse blocks: {}", e))?;
+			blocks.extend(file_blocks);
+		}
+		Ok(blocks)
+	}
+
+	/// Lists the block files of a network with their timestamps, oldest first
+	///
+	/// Matches "{network_id}_blocks_{timestamp}.json" and "{network_id}_blocks_{timestamp}.json.gz"
+	fn block_files(&self, network_id: &str) -> Result<Vec<(i64, PathBuf)>, anyhow::Error> {
+		let prefix = format!("{}_blocks_", network_id);
+		let pattern = self
+			.storage_path
+			.join(format!("{}*.json*", prefix))
+			.to_string_lossy()
+			.to_string();
+
+		let mut files = glob(&pattern)
+			.map_err(|e| anyhow::anyhow!("Failed to parse blocks: {}", e))?
+			.flatten()
+			.filter_map(|path| {
+				let name = path.file_name()?.to_str()?;
+				let timestamp = name
+					.strip_prefix(&prefix)?
+					.strip_suffix(".json.gz")
+					.or_else(|| name.strip_prefix(&prefix)?.strip_suffix(".json"))?
+					.parse::<i64>()
+					.ok()?;
+				Some((timestamp, path))
+			})
+			.collect::<Vec<_>>();
+		files.sort();
+		Ok(files)
+	}
+
+	/// Deletes the block files of a network beyond the retention caps
+	///
+	/// Files older than `max_age` are deleted first, then the oldest files until at most
+	/// `max_block_files` remain.
+	async fn prune_blocks(&self, network_id: &str) -> Result<(), anyhow::Error> {
+		if self.config.max_age.is_none() && self.config.max_block_files.is_none() {
+			return Ok(());
+		}
+
+		let mut files = self.block_files(network_id)?;
+		if let Some(max_age) = self.config.max_age {
+			let cutoff = chrono::Utc::now().timestamp() - max_age.as_secs() as i64;
+			let expired = files
+				.iter()
+				.take_while(|(timestamp, _)| *timestamp < cutoff);
+			let expired_count = expired.count();
+			for (_, path) in files.drain(..expired_count) {
+				remove_block_file(&path).await?;
+			}
+		}
+		if let Some(max_block_files) = self.config.max_block_files {
+			let excess = files.len().saturating_sub(max_block_files);
+			for (_, path) in files.drain(..excess) {
+				remove_block_file(&path).await?;
+			}
+		}
+		Ok(())
+	}
+
+	/// Keeps only the most recent `max_missed_blocks` entries of the missed blocks file
+	async fn prune_missed_blocks(&self, file_path: &Path) -> Result<(), anyhow::Error> {
+		let Some(max_missed_blocks) = self.config.max_missed_blocks else {
+			return Ok(());
+		};
+
+		let content = tokio::fs::read_to_string(file_path)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to read missed blocks: {}", e))?;
+		let lines = content.lines().collect::<Vec<_>>();
+		if lines.len() <= max_missed_blocks {
+			return Ok(());
+		}
+
+		let retained = lines[lines.len() - max_missed_blocks..]
+			.iter()
+			.map(|line| format!("{}\n", line))
+			.collect::<String>();
+		tokio::fs::write(file_path, retained)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to prune missed blocks: {}", e))?;
+		Ok(())
+	}
+
+	fn checkpoints_path(&self, network_id: &str) -> PathBuf {
+		self.storage_path
+			.join(format!("{}_checkpoints.txt", network_id))
+	}
+
+	/// Appends a checkpoint entry of the form "{state} {block}" to the checkpoints file
+	async fn append_checkpoint(
+		&self,
+		network_id: &str,
+		state: &str,
+		block: u64,
+	) -> Result<(), anyhow::Error> {
+		let _guard = self.checkpoints_lock.lock().await;
+		let mut file = tokio::fs::OpenOptions::new()
+			.create(true)
+			.append(true)
+			.open(self.checkpoints_path(network_id))
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to open checkpoints file: {}", e))?;
+
+		tokio::io::AsyncWriteExt::write_all(&mut file, format!("{} {}\n", state, block).as_bytes())
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to save checkpoint: {}", e))?;
+
+		Ok(())
+	}
+
+	/// Reads the checkpoints file of a network, the caller holding the checkpoints lock
+	async fn read_checkpoints(&self, network_id: &str) -> Result<BlockCheckpoints, anyhow::Error> {
+		let file_path = self.checkpoints_path(network_id);
+		if !file_path.exists() {
+			return Ok(BlockCheckpoints::default());
+		}
+
+		let content = tokio::fs::read_to_string(file_path)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to read checkpoints: {}", e))?;
+
+		let mut checkpoints = BlockCheckpoints::default();
+		for line in content.lines() {
+			let Some((state, block)) = line.trim().split_once(' ') else {
+				continue;
+			};
+			let Ok(block) = block.parse::<u64>() else {
+				continue;
+			};
+			match state {
+				"in_progress" => {
+					checkpoints.in_progress.insert(block);
+				}
+				"complete" => {
+					checkpoints.completed.insert(block);
+				}
+				_ => {}
+			}
+		}
+		checkpoints.in_progress = checkpoints
+			.in_progress
+			.difference(&checkpoints.completed)
+			.copied()
+			.collect();
+
+		Ok(checkpoints)
+	}
+
+	/// Drops the checkpoints covered by a newly saved last processed block
+	///
+	/// Completed blocks at or below the saved block are dropped, while blocks that never
+	/// completed are kept whatever their number, so that they are still processed again
+	/// after a restart. The file is rewritten through a temporary file, under the same lock
+	/// as the appends, so that no concurrent checkpoint is lost.
+	async fn prune_checkpoints(&self, network_id: &str, block: u64) -> Result<(), anyhow::Error> {
+		let _guard = self.checkpoints_lock.lock().await;
+		let checkpoints = self.read_checkpoints(network_id).await?;
+		let remaining = checkpoints
+			.in_progress
+			.iter()
+			.map(|number| format!("in_progress {}\n", number))
+			.chain(
+				checkpoints
+					.completed
+					.iter()
+					.filter(|&&number| number > block)
+					.map(|number| format!("complete {}\n", number)),
+			)
+			.collect::<String>();
+
+		let checkpoints_path = self.checkpoints_path(network_id);
+		if remaining.is_empty() {
+			if checkpoints_path.exists() {
+				tokio::fs::remove_file(checkpoints_path)
+					.await
+					.map_err(|e| anyhow::anyhow!("Failed to clear checkpoints: {}", e))?;
+			}
+			return Ok(());
+		}
+
+		let tmp_path = checkpoints_path.with_extension("txt.tmp");
+		tokio::fs::write(&tmp_path, remaining)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to prune checkpoints: {}", e))?;
+		tokio::fs::rename(&tmp_path, checkpoints_path)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to prune checkpoints: {}", e))?;
+		Ok(())
 	}
 }
 
+/// Returns whether a block file is gzip compressed
+fn is_compressed(path: &Path) -> bool {
+	path.extension().is_some_and(|extension| extension == "gz")
+}
+
+/// Deletes a block file
+async fn remove_block_file(path: &Path) -> Result<(), anyhow::Error> {
+	tokio::fs::remove_file(path)
+		.await
+		.map_err(|e| anyhow::anyhow!("Failed to delete blocks: {}", e))
+}
+
 impl Default for FileBlockStorage {
 	/// Default implementation for FileBlockStorage
 	///
@@ -136,7 +512,8 @@ impl BlockStorage for FileBlockStorage {
 	/// Saves the last processed block to a network-specific file
 	///
 	/// # Note
-	/// Overwrites any existing last block file for the network
+	/// Overwrites any existing last block file for the network. Checkpoints of the completed
+	/// blocks it covers are dropped, those of blocks that never completed are kept.
 	async fn save_last_processed_block(
 		&self,
 		network_id: &str,
@@ -148,51 +525,57 @@ impl BlockStorage for FileBlockStorage {
 		tokio::fs::write(file_path, block.to_string())
 			.await
 			.map_err(|e| anyhow::anyhow!("Failed to save last processed block: {}", e))?;
-		Ok(())
+
+		self.prune_checkpoints(network_id, block).await
 	}
 
 	/// Saves blocks to a timestamped JSON file
 	///
 	/// # Note
 	/// Creates a new file for each save operation, named:
-	/// "{network_id}_blocks_{timestamp}.json"
+	/// "{network_id}_blocks_{timestamp}.json", with a ".gz" suffix when compression is
+	/// enabled. Block files beyond the retention caps are deleted afterwards.
 	async fn save_blocks(
 		&self,
 		network_slug: &str,
 		blocks: &[BlockType],
 	) -> Result<(), anyhow::Error> {
+		let extension = if self.config.compression {
+			"json.gz"
+		} else {
+			"json"
+		};
 		let file_path = self.storage_path.join(format!(
-			"{}_blocks_{}.json",
+			"{}_blocks_{}.{}",
 			network_slug,
-			chrono::Utc::now().timestamp()
+			chrono::Utc::now().timestamp(),
+			extension
 		));
-		let json = serde_json::to_string(blocks)
+		let json = serde_json::to_vec(blocks)
 			.map_err(|e| anyhow::anyhow!("Failed to serialize blocks: {}", e))?;
-		tokio::fs::write(file_path, json)
+		let content = if self.config.compression {
+			let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+			encoder
+				.write_all(&json)
+				.and_then(|_| encoder.finish())
+				.map_err(|e| anyhow::anyhow!("Failed to compress blocks: {}", e))?
+		} else {
+			json
+		};
+		tokio::fs::write(file_path, content)
 			.await
 			.map_err(|e| anyhow::anyhow!("Failed to save blocks: {}", e))?;
-		Ok(())
+
+		self.prune_blocks(network_slug).await
 	}
 
 	/// Deletes all block files for a network
 	///
 	/// # Note
-	/// Uses glob pattern matching to find and delete all files matching:
-	/// "{network_id}_blocks_*.json"
+	/// Deletes all files matching "{network_id}_blocks_{timestamp}.json", compressed or not
 	async fn delete_blocks(&self, network_slug: &str) -> Result<(), anyhow::Error> {
-		let pattern = self
-			.storage_path
-			.join(format!("{}_blocks_*.json", network_slug))
-			.to_string_lossy()
-			.to_string();
-
-		for entry in glob(&pattern)
-			.map_err(|e| anyhow::anyhow!("Failed to parse blocks: {}", e))?
-			.flatten()
-		{
-			tokio::fs::remove_file(entry)
-				.await
-				.map_err(|e| anyhow::anyhow!("Failed to delete blocks: {}", e))?;
+		for (_, path) in self.block_files(network_slug)? {
+			remove_block_file(&path).await?;
 		}
 		Ok(())
 	}
@@ -214,7 +597,7 @@ impl BlockStorage for FileBlockStorage {
 		let mut file = tokio::fs::OpenOptions::new()
 			.create(true)
 			.append(true)
-			.open(file_path)
+			.open(&file_path)
 			.await
 			.map_err(|e| anyhow::anyhow!("Failed to create missed block file: {}", e))?;
 
@@ -223,7 +606,27 @@ impl BlockStorage for FileBlockStorage {
 			.await
 			.map_err(|e| anyhow::anyhow!("Failed to save missed block: {}", e))?;
 
-		Ok(())
+		self.prune_missed_blocks(&file_path).await
+	}
+
+	/// Appends an "in_progress" entry to "{network_id}_checkpoints.txt"
+	async fn mark_in_progress(&self, network_id: &str, block: u64) -> Result<(), anyhow::Error> {
+		self.append_checkpoint(network_id, "in_progress", block)
+			.await
+	}
+
+	/// Appends a "complete" entry to "{network_id}_checkpoints.txt"
+	async fn mark_complete(&self, network_id: &str, block: u64) -> Result<(), anyhow::Error> {
+		self.append_checkpoint(network_id, "complete", block).await
+	}
+
+	/// Reads the checkpoints recorded in "{network_id}_checkpoints.txt"
+	///
+	/// # Note
+	/// A trailing partial line (e.g. from a crash mid-write) is ignored
+	async fn get_checkpoints(&self, network_id: &str) -> Result<BlockCheckpoints, anyhow::Error> {
+		let _guard = self.checkpoints_lock.lock().await;
+		self.read_checkpoints(network_id).await
 	}
 }
 
@@ -414,4 +817,262 @@ mod tests {
 			assert!(err.to_string().contains("Permission denied"));
 		}
 	}
+
+	#[tokio::test]
+	async fn test_checkpoints_detect_interrupted_block() {
+		let temp_dir = tempfile::tempdir().unwrap();
+		let storage = FileBlockStorage::new(temp_dir.path().to_path_buf());
+
+		// No checkpoints recorded yet
+		assert_eq!(
+			storage.get_checkpoints("test").await.unwrap(),
+			BlockCheckpoints::default()
+		);
+
+		// Block 101 completes, block 102 is interrupted before completing
+		storage.mark_in_progress("test", 101).await.unwrap();
+		storage.mark_in_progress("test", 102).await.unwrap();
+		storage.mark_complete("test", 101).await.unwrap();
+
+		let checkpoints = storage.get_checkpoints("test").await.unwrap();
+		assert_eq!(checkpoints.in_progress, BTreeSet::from([102]));
+		assert_eq!(checkpoints.completed, BTreeSet::from([101]));
+	}
+
+	#[tokio::test]
+	async fn test_checkpoints_ignore_malformed_lines() {
+		let temp_dir = tempfile::tempdir().unwrap();
+		let storage = FileBlockStorage::new(temp_dir.path().to_path_buf());
+
+		tokio::fs::write(
+			temp_dir.path().join("test_checkpoints.txt"),
+			"in_progress 5\ncomplete 4\nunknown 6\ncomplete\nin_progress 7",
+		)
+		.await
+		.unwrap();
+
+		let checkpoints = storage.get_checkpoints("test").await.unwrap();
+		assert_eq!(checkpoints.in_progress, BTreeSet::from([5, 7]));
+		assert_eq!(checkpoints.completed, BTreeSet::from([4]));
+	}
+
+	#[tokio::test]
+	async fn test_save_last_processed_block_prunes_checkpoints() {
+		let temp_dir = tempfile::tempdir().unwrap();
+		let storage = FileBlockStorage::new(temp_dir.path().to_path_buf());
+
+		for block in [100, 101, 102, 103] {
+			storage.mark_in_progress("test", block).await.unwrap();
+		}
+		storage.mark_complete("test", 100).await.unwrap();
+		storage.mark_complete("test", 102).await.unwrap();
+
+		// Completed blocks at or below the saved block are dropped, unfinished blocks survive
+		// whatever their number
+		storage
+			.save_last_processed_block("test", 102)
+			.await
+			.unwrap();
+		let checkpoints = storage.get_checkpoints("test").await.unwrap();
+		assert_eq!(checkpoints.in_progress, BTreeSet::from([101, 103]));
+		assert!(checkpoints.completed.is_empty());
+
+		// Completed blocks above the saved block are kept
+		storage.mark_in_progress("test", 104).await.unwrap();
+		storage.mark_complete("test", 104).await.unwrap();
+		storage
+			.save_last_processed_block("test", 103)
+			.await
+			.unwrap();
+		let checkpoints = storage.get_checkpoints("test").await.unwrap();
+		assert_eq!(checkpoints.in_progress, BTreeSet::from([101, 103]));
+		assert_eq!(checkpoints.completed, BTreeSet::from([104]));
+
+		// The file is removed once every block completed and is covered
+		storage.mark_complete("test", 101).await.unwrap();
+		storage.mark_complete("test", 103).await.unwrap();
+		storage
+			.save_last_processed_block("test", 104)
+			.await
+			.unwrap();
+		assert!(!temp_dir.path().join("test_checkpoints.txt").exists());
+	}
+
+	#[tokio::test]
+	async fn test_checkpoints_are_not_lost_during_prune() {
+		let temp_dir = tempfile::tempdir().unwrap();
+		let storage = FileBlockStorage::new(temp_dir.path().to_path_buf());
+
+		// Blocks are checkpointed while the last processed block is saved concurrently
+		let appends = (1..=200).map(|block| {
+			let storage = storage.clone();
+			tokio::spawn(async move { storage.mark_in_progress("test", block).await })
+		});
+		let saves = (0..20).map(|_| {
+			let storage = storage.clone();
+			tokio::spawn(async move { storage.save_last_processed_block("test", 200).await })
+		});
+		for handle in appends.chain(saves).collect::<Vec<_>>() {
+			handle.await.unwrap().unwrap();
+		}
+
+		let checkpoints = storage.get_checkpoints("test").await.unwrap();
+		assert_eq!(checkpoints.in_progress, (1..=200).collect::<BTreeSet<_>>());
+	}
+
+	fn create_test_block(block_number: u64) -> BlockType {
+		BlockType::EVM(Box::new(crate::models::EVMBlock::from(
+			alloy::rpc::types::Block {
+				header: alloy::rpc::types::Header {
+					inner: alloy::consensus::Header {
+						number: block_number,
+						..Default::default()
+					},
+					..Default::default()
+				},
+				transactions: alloy::rpc::types::BlockTransactions::Full(vec![]),
+				uncles: vec![],
+				withdrawals: None,
+			},
+		)))
+	}
+
+	#[tokio::test]
+	async fn test_save_blocks_compressed() {
+		let temp_dir = tempfile::tempdir().unwrap();
+		let storage = FileBlockStorage::with_config(
+			temp_dir.path().to_path_buf(),
+			FileBlockStorageConfig {
+				compression: true,
+				..Default::default()
+			},
+		);
+
+		let blocks = vec![create_test_block(100), create_test_block(101)];
+		storage.save_blocks("test", &blocks).await.unwrap();
+
+		// The block file is written as gzip
+		let files = storage.block_files("test").unwrap();
+		assert_eq!(files.len(), 1);
+		let path = &files[0].1;
+		assert!(path.to_string_lossy().ends_with(".json.gz"));
+		let content = tokio::fs::read(path).await.unwrap();
+		assert_eq!(&content[..2], &[0x1f, 0x8b]);
+
+		// Reads decompress it transparently, along with uncompressed files
+		tokio::fs::write(
+			temp_dir.path().join("test_blocks_1.json"),
+			serde_json::to_string(&[create_test_block(99)]).unwrap(),
+		)
+		.await
+		.unwrap();
+		let numbers = storage
+			.get_blocks("test")
+			.await
+			.unwrap()
+			.iter()
+			.map(|block| block.number())
+			.collect::<Vec<_>>();
+		assert_eq!(numbers, vec![Some(99), Some(100), Some(101)]);
+
+		// Deleting blocks removes compressed files as well
+		storage.delete_blocks("test").await.unwrap();
+		assert!(storage.get_blocks("test").await.unwrap().is_empty());
+	}
+
+	#[tokio::test]
+	async fn test_save_blocks_prunes_beyond_max_files() {
+		let temp_dir = tempfile::tempdir().unwrap();
+		let storage = FileBlockStorage::with_config(
+			temp_dir.path().to_path_buf(),
+			FileBlockStorageConfig {
+				compression: true,
+				max_block_files: Some(2),
+				..Default::default()
+			},
+		);
+
+		let now = chrono::Utc::now().timestamp();
+		for (offset, number) in [(3, 1), (2, 2), (1, 3)] {
+			tokio::fs::write(
+				temp_dir
+					.path()
+					.join(format!("test_blocks_{}.json", now - offset)),
+				serde_json::to_string(&[create_test_block(number)]).unwrap(),
+			)
+			.await
+			.unwrap();
+		}
+		// Files of other networks are not pruned
+		tokio::fs::write(temp_dir.path().join("other_blocks_1.json"), "[]")
+			.await
+			.unwrap();
+
+		storage
+			.save_blocks("test", &[create_test_block(4)])
+			.await
+			.unwrap();
+
+		let numbers = storage
+			.get_blocks("test")
+			.await
+			.unwrap()
+			.iter()
+			.map(|block| block.number())
+			.collect::<Vec<_>>();
+		assert_eq!(numbers, vec![Some(3), Some(4)]);
+		assert!(temp_dir.path().join("other_blocks_1.json").exists());
+	}
+
+	#[tokio::test]
+	async fn test_save_blocks_prunes_beyond_max_age() {
+		let temp_dir = tempfile::tempdir().unwrap();
+		let storage = FileBlockStorage::with_config(
+			temp_dir.path().to_path_buf(),
+			FileBlockStorageConfig {
+				max_age: Some(Duration::from_secs(3600)),
+				..Default::default()
+			},
+		);
+
+		let now = chrono::Utc::now().timestamp();
+		let expired = temp_dir
+			.path()
+			.join(format!("test_blocks_{}.json", now - 7200));
+		let recent = temp_dir
+			.path()
+			.join(format!("test_blocks_{}.json", now - 60));
+		tokio::fs::write(&expired, "[]").await.unwrap();
+		tokio::fs::write(&recent, "[]").await.unwrap();
+
+		storage
+			.save_blocks("test", &[create_test_block(1)])
+			.await
+			.unwrap();
+
+		assert!(!expired.exists());
+		assert!(recent.exists());
+		assert_eq!(storage.block_files("test").unwrap().len(), 2);
+	}
+
+	#[tokio::test]
+	async fn test_save_missed_block_prunes_beyond_max_missed_blocks() {
+		let temp_dir = tempfile::tempdir().unwrap();
+		let storage = FileBlockStorage::with_config(
+			temp_dir.path().to_path_buf(),
+			FileBlockStorageConfig {
+				max_missed_blocks: Some(3),
+				..Default::default()
+			},
+		);
+
+		for block in 100..105 {
+			storage.save_missed_block("test", block).await.unwrap();
+		}
+
+		let content = tokio::fs::read_to_string(temp_dir.path().join("test_missed_blocks.txt"))
+			.await
+			.unwrap();
+		assert_eq!(content, "102\n103\n104\n");
+	}
 }
@@ -165,7 +165,10 @@ impl<S: BlockStorage> BlockTrackerTrait<S> for BlockTracker<S> {
 
 #[cfg(test)]
 mod tests {
-	use crate::{models::BlockType, utils::tests::network::NetworkBuilder};
+	use crate::{
+		models::BlockType, services::blockwatcher::storage::BlockCheckpoints,
+		utils::tests::network::NetworkBuilder,
+	};
 
 	use super::*;
 	use mockall::mock;
@@ -180,6 +183,9 @@ mod tests {
 			async fn get_last_processed_block(&self, network_slug: &str) -> Result<Option<u64>, anyhow::Error>;
 			async fn save_blocks(&self, network_slug: &str, blocks: &[BlockType]) -> Result<(), anyhow::Error>;
 			async fn delete_blocks(&self, network_slug: &str) -> Result<(), anyhow::Error>;
+			async fn mark_in_progress(&self, network_slug: &str, block_number: u64) -> Result<(), anyhow::Error>;
+			async fn mark_complete(&self, network_slug: &str, block_number: u64) -> Result<(), anyhow::Error>;
+			async fn get_checkpoints(&self, network_slug: &str) -> Result<BlockCheckpoints, anyhow::Error>;
 		}
 
 		impl Clone for BlockStorage {
@@ -0,0 +1,325 @@
+//! Bounded queue between block processing and trigger execution.
+//!
+//! Processed blocks are queued for a single trigger execution task instead of spawning a task
+//! per block, which bounds the memory used during bursts of matches. When the queue is full,
+//! the backpressure policy either waits for a free slot, slowing down block processing, or
+//! drops the oldest queued block.
+
+use std::{collections::VecDeque, env, str::FromStr, sync::Mutex};
+
+use tokio::sync::Notify;
+
+use crate::{
+	models::ProcessedBlock,
+	services::blockwatcher::completion::{CompletionSignal, TriggerCompletion},
+	utils::metrics::TRIGGER_QUEUE_DROPPED_BLOCKS_TOTAL,
+};
+
+/// Environment variable setting the number of blocks the trigger queue can hold
+pub const TRIGGER_QUEUE_CAPACITY_ENV: &str = "TRIGGER_QUEUE_CAPACITY";
+
+/// Environment variable setting the backpressure policy of the trigger queue
+pub const TRIGGER_QUEUE_POLICY_ENV: &str = "TRIGGER_QUEUE_POLICY";
+
+/// Number of blocks the trigger queue can hold when not configured
+pub const DEFAULT_TRIGGER_QUEUE_CAPACITY: usize = 100;
+
+/// Behavior of the trigger queue when it is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BackpressurePolicy {
+	/// Wait for a free slot, which slows down block processing
+	#[default]
+	Block,
+	/// Drop the oldest queued block to make room for the new one
+	DropOldest,
+}
+
+impl FromStr for BackpressurePolicy {
+	type Err = anyhow::Error;
+
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		match value.trim() {
+			"block" => Ok(Self::Block),
+			"drop_oldest" => Ok(Self::DropOldest),
+			_ => Err(anyhow::anyhow!(
+				"Invalid {} value '{}': expected 'block' or 'drop_oldest'",
+				TRIGGER_QUEUE_POLICY_ENV,
+				value
+			)),
+		}
+	}
+}
+
+/// Trigger queue configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TriggerQueueConfig {
+	/// Number of processed blocks the queue can hold
+	pub capacity: usize,
+	/// Behavior when the queue is full
+	pub policy: BackpressurePolicy,
+}
+
+impl Default for TriggerQueueConfig {
+	fn default() -> Self {
+		Self {
+			capacity: DEFAULT_TRIGGER_QUEUE_CAPACITY,
+			policy: BackpressurePolicy::default(),
+		}
+	}
+}
+
+impl TriggerQueueConfig {
+	/// Reads the trigger queue configuration from the environment.
+	///
+	/// Uses `TRIGGER_QUEUE_CAPACITY` and `TRIGGER_QUEUE_POLICY`, falling back to the defaults
+	/// for unset variables.
+	pub fn from_env() -> Result<Self, anyhow::Error> {
+		let capacity = match env::var(TRIGGER_QUEUE_CAPACITY_ENV) {
+			Ok(value) => match value.trim().parse::<usize>() {
+				Ok(parsed) if parsed > 0 => parsed,
+				_ => {
+					return Err(anyhow::anyhow!(
+						"Invalid {} value '{}': expected a positive integer",
+						TRIGGER_QUEUE_CAPACITY_ENV,
+						value
+					))
+				}
+			},
+			Err(_) => DEFAULT_TRIGGER_QUEUE_CAPACITY,
+		};
+
+		let policy = match env::var(TRIGGER_QUEUE_POLICY_ENV) {
+			Ok(value) => value.parse()?,
+			Err(_) => BackpressurePolicy::default(),
+		};
+
+		Ok(Self { capacity, policy })
+	}
+}
+
+/// Bounded FIFO queue of processed blocks awaiting trigger execution
+///
+/// Each queued block carries the signal of its trigger completion, so a dropped block resolves
+/// its completion as not executed.
+#[derive(Debug)]
+pub struct TriggerQueue {
+	config: TriggerQueueConfig,
+	blocks: Mutex<VecDeque<(ProcessedBlock, CompletionSignal)>>,
+	/// Signaled when a block is queued
+	block_queued: Notify,
+	/// Signaled when a slot is freed
+	slot_freed: Notify,
+}
+
+impl TriggerQueue {
+	/// Creates an empty queue
+	///
+	/// # Arguments
+	/// * `config` - Capacity and backpressure policy of the queue
+	pub fn new(config: TriggerQueueConfig) -> Self {
+		Self {
+			config,
+			blocks: Mutex::new(VecDeque::with_capacity(config.capacity)),
+			block_queued: Notify::new(),
+			slot_freed: Notify::new(),
+		}
+	}
+
+	/// Queues a processed block
+	///
+	/// When the queue is full, waits for a free slot with the `Block` policy, or drops the
+	/// oldest queued block with the `DropOldest` policy.
+	///
+	/// # Arguments
+	/// * `block` - The processed block to queue
+	///
+	/// # Returns
+	/// The completion resolved once the consumer has executed the block's triggers
+	pub async fn push(&self, block: ProcessedBlock) -> TriggerCompletion {
+		let (signal, completion) = TriggerCompletion::pending();
+		let mut entry = Some((block, signal));
+		loop {
+			let slot_freed = self.slot_freed.notified();
+			{
+				let mut blocks = self.blocks.lock().unwrap_or_else(|e| e.into_inner());
+				if blocks.len() >= self.config.capacity
+					&& self.config.policy == BackpressurePolicy::DropOldest
+				{
+					if let Some((dropped, _)) = blocks.pop_front() {
+						tracing::warn!(
+							network = %dropped.network_slug,
+							"Trigger queue full, dropping matches of block {}",
+							dropped.block_number
+						);
+						TRIGGER_QUEUE_DROPPED_BLOCKS_TOTAL
+							.with_label_values(&[&dropped.network_slug])
+							.inc();
+					}
+				}
+				if blocks.len() < self.config.capacity {
+					if let Some(entry) = entry.take() {
+						blocks.push_back(entry);
+					}
+					self.block_queued.notify_one();
+					return completion;
+				}
+			}
+			slot_freed.await;
+		}
+	}
+
+	/// Takes the oldest queued block, waiting for one if the queue is empty
+	///
+	/// # Returns
+	/// The block and the signal to complete once its triggers have been executed
+	pub async fn pop(&self) -> (ProcessedBlock, CompletionSignal) {
+		loop {
+			let block_queued = self.block_queued.notified();
+			if let Some(entry) = self
+				.blocks
+				.lock()
+				.unwrap_or_else(|e| e.into_inner())
+				.pop_front()
+			{
+				self.slot_freed.notify_one();
+				return entry;
+			}
+			block_queued.await;
+		}
+	}
+
+	/// Returns the number of queued blocks
+	pub fn len(&self) -> usize {
+		self.blocks.lock().unwrap_or_else(|e| e.into_inner()).len()
+	}
+
+	/// Returns whether no block is queued
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::{sync::Arc, time::Duration};
+
+	fn create_block(block_number: u64, network_slug: &str) -> ProcessedBlock {
+		ProcessedBlock {
+			block_number,
+			network_slug: network_slug.to_string(),
+			processing_results: vec![],
+		}
+	}
+
+	#[tokio::test]
+	async fn test_push_blocks_when_full() {
+		let queue = Arc::new(TriggerQueue::new(TriggerQueueConfig {
+			capacity: 2,
+			policy: BackpressurePolicy::Block,
+		}));
+		queue.push(create_block(1, "ethereum_mainnet")).await;
+		queue.push(create_block(2, "ethereum_mainnet")).await;
+
+		let producer = tokio::spawn({
+			let queue = queue.clone();
+			async move { queue.push(create_block(3, "ethereum_mainnet")).await }
+		});
+
+		// The producer waits while the slow consumer has not taken a block
+		tokio::time::sleep(Duration::from_millis(50)).await;
+		assert!(!producer.is_finished());
+		assert_eq!(queue.len(), 2);
+
+		assert_eq!(queue.pop().await.0.block_number, 1);
+		tokio::time::timeout(Duration::from_secs(1), producer)
+			.await
+			.expect("Producer should resume once a slot is freed")
+			.unwrap();
+
+		assert_eq!(queue.pop().await.0.block_number, 2);
+		assert_eq!(queue.pop().await.0.block_number, 3);
+		assert!(queue.is_empty());
+	}
+
+	#[tokio::test]
+	async fn test_push_drops_oldest_when_full() {
+		let network_slug = "trigger_queue_drop_oldest";
+		let queue = TriggerQueue::new(TriggerQueueConfig {
+			capacity: 2,
+			policy: BackpressurePolicy::DropOldest,
+		});
+
+		for block_number in 1..=4 {
+			tokio::time::timeout(
+				Duration::from_secs(1),
+				queue.push(create_block(block_number, network_slug)),
+			)
+			.await
+			.expect("Push should never wait with the drop oldest policy");
+		}
+
+		assert_eq!(
+			TRIGGER_QUEUE_DROPPED_BLOCKS_TOTAL
+				.with_label_values(&[network_slug])
+				.get(),
+			2
+		);
+		assert_eq!(queue.pop().await.0.block_number, 3);
+		assert_eq!(queue.pop().await.0.block_number, 4);
+	}
+
+	#[tokio::test]
+	async fn test_dropped_block_completion_is_not_executed() {
+		let queue = TriggerQueue::new(TriggerQueueConfig {
+			capacity: 1,
+			policy: BackpressurePolicy::DropOldest,
+		});
+
+		let dropped = queue
+			.push(create_block(1, "trigger_queue_completion"))
+			.await;
+		let executed = queue
+			.push(create_block(2, "trigger_queue_completion"))
+			.await;
+
+		let (block, signal) = queue.pop().await;
+		assert_eq!(block.block_number, 2);
+		signal.complete();
+
+		assert!(!dropped.wait().await);
+		assert!(executed.wait().await);
+	}
+
+	#[tokio::test]
+	async fn test_pop_waits_for_block() {
+		let queue = Arc::new(TriggerQueue::new(TriggerQueueConfig::default()));
+
+		let consumer = tokio::spawn({
+			let queue = queue.clone();
+			async move { queue.pop().await.0 }
+		});
+		tokio::time::sleep(Duration::from_millis(20)).await;
+		assert!(!consumer.is_finished());
+
+		queue.push(create_block(7, "ethereum_mainnet")).await;
+		let block = tokio::time::timeout(Duration::from_secs(1), consumer)
+			.await
+			.unwrap()
+			.unwrap();
+		assert_eq!(block.block_number, 7);
+	}
+
+	#[test]
+	fn test_backpressure_policy_from_str() {
+		assert_eq!(
+			"block".parse::<BackpressurePolicy>().unwrap(),
+			BackpressurePolicy::Block
+		);
+		assert_eq!(
+			"drop_oldest".parse::<BackpressurePolicy>().unwrap(),
+			BackpressurePolicy::DropOldest
+		);
+		assert!("drop_newest".parse::<BackpressurePolicy>().is_err());
+	}
+}
@@ -0,0 +1,190 @@
+//! Address normalization across blockchains.
+//!
+//! Each chain has its own rules for when two address strings refer to the same account:
+//! EVM addresses are case-insensitive hex, optionally carrying an EIP-55 checksum in their
+//! casing, while Stellar strkeys are compared ignoring case. The [`AddressNormalizer`] of a
+//! chain is looked up by its [`BlockChainType`], so that new chains only need to provide
+//! their own normalizer.
+
+use alloy::primitives::Address;
+
+use crate::{
+	models::{BlockChainType, Network},
+	services::filter::{evm_helpers, stellar_helpers},
+};
+
+/// Rules for normalizing and comparing the addresses of a chain
+pub trait AddressNormalizer: Send + Sync {
+	/// Normalizes an address to the form addresses are compared in
+	///
+	/// # Arguments
+	/// * `address` - The address to normalize
+	///
+	/// # Returns
+	/// The normalized address
+	fn normalize(&self, address: &str) -> String;
+
+	/// Checks whether an address can be compared
+	///
+	/// # Arguments
+	/// * `address` - The address to check
+	///
+	/// # Returns
+	/// `true` if the address is valid, `false` otherwise
+	fn is_valid(&self, _address: &str) -> bool {
+		true
+	}
+
+	/// Compares two addresses for equality
+	///
+	/// # Arguments
+	/// * `address1` - First address to compare
+	/// * `address2` - Second address to compare
+	///
+	/// # Returns
+	/// `true` if both addresses are valid and equivalent, `false` otherwise
+	fn are_same_address(&self, address1: &str, address2: &str) -> bool {
+		self.is_valid(address1)
+			&& self.is_valid(address2)
+			&& self.normalize(address1) == self.normalize(address2)
+	}
+}
+
+/// Normalizer of EVM addresses
+///
+/// Addresses are compared ignoring case and "0x" prefixes. With `strict_checksum`, mixed-case
+/// addresses must also carry a valid EIP-55 checksum, so that mistyped addresses never match.
+/// All-lowercase and all-uppercase addresses carry no checksum and are always valid.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EvmAddressNormalizer {
+	/// Whether mixed-case addresses must have a valid checksum
+	pub strict_checksum: bool,
+}
+
+impl AddressNormalizer for EvmAddressNormalizer {
+	fn normalize(&self, address: &str) -> String {
+		evm_helpers::normalize_address(address)
+	}
+
+	fn is_valid(&self, address: &str) -> bool {
+		if !self.strict_checksum {
+			return true;
+		}
+		let hex = address.trim();
+		let hex = hex.strip_prefix("0x").unwrap_or(hex);
+		let is_checksummed = hex.chars().any(|c| c.is_ascii_lowercase())
+			&& hex.chars().any(|c| c.is_ascii_uppercase());
+		!is_checksummed || Address::parse_checksummed(format!("0x{}", hex), None).is_ok()
+	}
+}
+
+/// Normalizer of Stellar account and contract strkeys, compared ignoring case and whitespace
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StellarAddressNormalizer;
+
+impl AddressNormalizer for StellarAddressNormalizer {
+	fn normalize(&self, address: &str) -> String {
+		stellar_helpers::normalize_address(address)
+	}
+}
+
+/// Normalizer of case-sensitive addresses (e.g. base58), only ignoring surrounding whitespace
+///
+/// Used for chains without address rules of their own.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExactAddressNormalizer;
+
+impl AddressNormalizer for ExactAddressNormalizer {
+	fn normalize(&self, address: &str) -> String {
+		address.trim().to_string()
+	}
+}
+
+/// Returns the address normalizer of a chain
+///
+/// # Arguments
+/// * `network_type` - Type of the chain
+/// * `strict_checksum` - Whether EVM addresses must carry a valid checksum when mixed-case
+///
+/// # Returns
+/// The normalizer of the chain's addresses
+pub fn address_normalizer(
+	network_type: &BlockChainType,
+	strict_checksum: bool,
+) -> &'static dyn AddressNormalizer {
+	match network_type {
+		BlockChainType::EVM if strict_checksum => &EvmAddressNormalizer {
+			strict_checksum: true,
+		},
+		BlockChainType::EVM => &EvmAddressNormalizer {
+			strict_checksum: false,
+		},
+		BlockChainType::Stellar => &StellarAddressNormalizer,
+		BlockChainType::Midnight | BlockChainType::Solana => &ExactAddressNormalizer,
+	}
+}
+
+/// Returns the address normalizer of a network, honoring its `strict_address_checksum`
+///
+/// # Arguments
+/// * `network` - The network whose addresses are normalized
+///
+/// # Returns
+/// The normalizer of the network's addresses
+pub fn network_address_normalizer(network: &Network) -> &'static dyn AddressNormalizer {
+	address_normalizer(
+		&network.network_type,
+		network.strict_address_checksum.unwrap_or(false),
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const CHECKSUMMED: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+	const LOWERCASE: &str = "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed";
+	// The checksummed address with the case of one letter flipped
+	const BAD_CHECKSUM: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD";
+
+	#[test]
+	fn test_evm_loose_comparison_ignores_checksum() {
+		let normalizer = address_normalizer(&BlockChainType::EVM, false);
+		assert!(normalizer.are_same_address(CHECKSUMMED, LOWERCASE));
+		assert!(normalizer.are_same_address(BAD_CHECKSUM, LOWERCASE));
+		assert_eq!(
+			normalizer.normalize(CHECKSUMMED),
+			"5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"
+		);
+	}
+
+	#[test]
+	fn test_evm_strict_comparison_requires_valid_checksum() {
+		let normalizer = address_normalizer(&BlockChainType::EVM, true);
+		assert!(normalizer.are_same_address(CHECKSUMMED, LOWERCASE));
+		assert!(normalizer.are_same_address(LOWERCASE, &LOWERCASE.to_uppercase()[2..]));
+		assert!(!normalizer.is_valid(BAD_CHECKSUM));
+		assert!(!normalizer.are_same_address(BAD_CHECKSUM, LOWERCASE));
+	}
+
+	#[test]
+	fn test_stellar_strkey_normalization() {
+		let normalizer = address_normalizer(&BlockChainType::Stellar, false);
+		assert_eq!(
+			normalizer.normalize(" GBZXN7PIRZGNMHGA7MUUUF4GWPY5AYPV6LY4UV2GL6VJGIQRXFDNMADI "),
+			"gbzxn7pirzgnmhga7muuuf4gwpy5aypv6ly4uv2gl6vjgiqrxfdnmadi"
+		);
+		assert!(normalizer.are_same_address(
+			"GBZXN7PIRZGNMHGA7MUUUF4GWPY5AYPV6LY4UV2GL6VJGIQRXFDNMADI",
+			"gbzxn7pirzgnmhga7muuuf4gwpy5aypv6ly4uv2gl6vjgiqrxfdnmadi"
+		));
+	}
+
+	#[test]
+	fn test_chains_without_rules_compare_exactly() {
+		let normalizer = address_normalizer(&BlockChainType::Solana, false);
+		let address = "4Nd1mBQtrMJVYVfKf2PJy9NZUZdTAsp7D4xWLs4gDB4T";
+		assert!(normalizer.are_same_address(address, &format!(" {} ", address)));
+		assert!(!normalizer.are_same_address(address, &address.to_lowercase()));
+	}
+}
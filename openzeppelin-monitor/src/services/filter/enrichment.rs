@@ -0,0 +1,482 @@
+//! Enrichment of monitor matches with read-only contract calls.
+//!
+//! Monitors with `enrichment` settings get the results of their contract calls attached to
+//! their matches, which exposes them to notification templates as `enrichment.<name>`. Results
+//! of calls with a `cache_ttl_secs` are cached per network, contract and function for that
+//! long, and calls exceeding the monitor's timeout are skipped.
+
+use std::{
+	collections::HashMap,
+	sync::Mutex,
+	time::{Duration, Instant},
+};
+
+use crate::{
+	models::{EnrichmentCall, MonitorMatch, Network},
+	services::blockchain::BlockChainClient,
+};
+
+/// Number of enrichment results kept when not configured
+pub const DEFAULT_ENRICHMENT_CACHE_MAX_ENTRIES: usize = 1000;
+
+/// Network slug, normalized contract address and function of an enrichment call
+type CacheKey = (String, String, String);
+
+/// Cached result of an enrichment call
+#[derive(Debug)]
+struct CachedResult {
+	value: String,
+	expires_at: Instant,
+	last_used: Instant,
+}
+
+/// Bounded cache of the results of enrichment calls opting in with `cache_ttl_secs`
+///
+/// Results expire after the TTL of their call. When the cache is full, expired results are
+/// evicted first, then the least recently used one.
+#[derive(Debug)]
+pub struct EnrichmentCache {
+	entries: Mutex<HashMap<CacheKey, CachedResult>>,
+	max_entries: usize,
+}
+
+impl Default for EnrichmentCache {
+	fn default() -> Self {
+		Self::new(DEFAULT_ENRICHMENT_CACHE_MAX_ENTRIES)
+	}
+}
+
+impl EnrichmentCache {
+	/// Creates an empty cache
+	///
+	/// # Arguments
+	/// * `max_entries` - Maximum number of results kept
+	pub fn new(max_entries: usize) -> Self {
+		Self {
+			entries: Mutex::new(HashMap::new()),
+			max_entries,
+		}
+	}
+
+	fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<CacheKey, CachedResult>> {
+		self.entries.lock().unwrap_or_else(|e| e.into_inner())
+	}
+
+	/// Returns the cached result of a call if it has not expired
+	fn get(&self, key: &CacheKey, now: Instant) -> Option<String> {
+		let mut entries = self.lock();
+		match entries.get_mut(key) {
+			Some(entry) if entry.expires_at > now => {
+				entry.last_used = now;
+				Some(entry.value.clone())
+			}
+			Some(_) => {
+				entries.remove(key);
+				None
+			}
+			None => None,
+		}
+	}
+
+	/// Caches the result of a call for `ttl`
+	fn insert(&self, key: CacheKey, value: String, ttl: Duration, now: Instant) {
+		let mut entries = self.lock();
+		if entries.len() >= self.max_entries && !entries.contains_key(&key) {
+			entries.retain(|_, entry| entry.expires_at > now);
+		}
+		if entries.len() >= self.max_entries && !entries.contains_key(&key) {
+			if let Some(least_recently_used) = entries
+				.iter()
+				.min_by_key(|(_, entry)| entry.last_used)
+				.map(|(key, _)| key.clone())
+			{
+				entries.remove(&least_recently_used);
+			}
+		}
+		entries.insert(
+			key,
+			CachedResult {
+				value,
+				expires_at: now + ttl,
+				last_used: now,
+			},
+		);
+	}
+
+	/// Attaches the results of their monitor's enrichment calls to matches
+	///
+	/// Calls that fail or time out are logged and left out of the match, and are attempted
+	/// again for the next match.
+	///
+	/// # Arguments
+	/// * `client` - The client of the network the matches were found on
+	/// * `network` - The network the matches were found on
+	/// * `matches` - The matches to enrich
+	///
+	/// # Returns
+	/// The matches with their enrichment results
+	pub async fn enrich_matches<T: BlockChainClient>(
+		&self,
+		client: &T,
+		network: &Network,
+		mut matches: Vec<MonitorMatch>,
+	) -> Vec<MonitorMatch> {
+		for monitor_match in &mut matches {
+			let (monitor, enrichment) = match monitor_match {
+				MonitorMatch::EVM(evm_match) => (&evm_match.monitor, &mut evm_match.enrichment),
+				MonitorMatch::Stellar(stellar_match) => {
+					(&stellar_match.monitor, &mut stellar_match.enrichment)
+				}
+			};
+			let Some(config) = &monitor.enrichment else {
+				continue;
+			};
+
+			let timeout = Duration::from_millis(config.timeout_ms);
+			for call in &config.calls {
+				let Some(address) = call
+					.address
+					.as_deref()
+					.or_else(|| monitor.addresses.first().map(|a| a.address.as_str()))
+				else {
+					continue;
+				};
+
+				match self
+					.call_contract_function(client, network, address, call, timeout)
+					.await
+				{
+					Ok(value) => {
+						enrichment.insert(call.name.clone(), value);
+					}
+					Err(e) => {
+						tracing::warn!(
+							monitor = %monitor.name,
+							call = %call.name,
+							"Failed to enrich match: {}",
+							e
+						);
+					}
+				}
+			}
+		}
+		matches
+	}
+
+	/// Calls a contract function, returning the cached result of a previous call if any
+	async fn call_contract_function<T: BlockChainClient>(
+		&self,
+		client: &T,
+		network: &Network,
+		address: &str,
+		call: &EnrichmentCall,
+		timeout: Duration,
+	) -> Result<String, anyhow::Error> {
+		let key = (
+			network.slug.clone(),
+			address.to_lowercase(),
+			call.function.clone(),
+		);
+		if call.cache_ttl_secs.is_some() {
+			if let Some(value) = self.get(&key, Instant::now()) {
+				return Ok(value);
+			}
+		}
+
+		let value = tokio::time::timeout(
+			timeout,
+			client.call_contract_function(address, &call.function, call.output.as_deref()),
+		)
+		.await
+		.map_err(|_| {
+			anyhow::anyhow!(
+				"Call of {} on {} timed out after {}ms",
+				call.function,
+				address,
+				timeout.as_millis()
+			)
+		})??;
+
+		if let Some(cache_ttl_secs) = call.cache_ttl_secs {
+			self.insert(
+				key,
+				value.clone(),
+				Duration::from_secs(cache_ttl_secs),
+				Instant::now(),
+			);
+		}
+		Ok(value)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{BlockType, EVMMonitorMatch, EVMTransaction, MatchConditions, Monitor},
+		utils::tests::{builders::evm::monitor::MonitorBuilder, builders::network::NetworkBuilder},
+	};
+	use async_trait::async_trait;
+	use std::sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	};
+
+	/// Client answering contract calls with the function name after a delay
+	#[derive(Clone)]
+	struct CallClient {
+		calls: Arc<AtomicUsize>,
+		delay: Duration,
+	}
+
+	#[async_trait]
+	impl BlockChainClient for CallClient {
+		async fn get_latest_block_number(&self) -> Result<u64, anyhow::Error> {
+			Ok(0)
+		}
+
+		async fn get_blocks(
+			&self,
+			_start_block: u64,
+			_end_block: Option<u64>,
+		) -> Result<Vec<BlockType>, anyhow::Error> {
+			Ok(vec![])
+		}
+
+		async fn call_contract_function(
+			&self,
+			contract_id: &str,
+			function: &str,
+			_output: Option<&str>,
+		) -> Result<String, anyhow::Error> {
+			self.calls.fetch_add(1, Ordering::SeqCst);
+			tokio::time::sleep(self.delay).await;
+			Ok(format!("{}:{}", contract_id, function))
+		}
+	}
+
+	fn create_client(delay_ms: u64) -> CallClient {
+		CallClient {
+			calls: Arc::new(AtomicUsize::new(0)),
+			delay: Duration::from_millis(delay_ms),
+		}
+	}
+
+	fn create_monitor_match(monitor: Monitor) -> MonitorMatch {
+		MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor,
+			transaction: EVMTransaction::default(),
+			receipt: None,
+			revert_reason: None,
+			logs: None,
+			block_timestamp: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: None,
+			enrichment: HashMap::new(),
+		}))
+	}
+
+	fn enrichment(monitor_match: &MonitorMatch) -> &HashMap<String, String> {
+		match monitor_match {
+			MonitorMatch::EVM(evm_match) => &evm_match.enrichment,
+			MonitorMatch::Stellar(stellar_match) => &stellar_match.enrichment,
+		}
+	}
+
+	fn cache_key(function: &str) -> CacheKey {
+		(
+			"ethereum_mainnet".to_string(),
+			"0xaaaa".to_string(),
+			function.to_string(),
+		)
+	}
+
+	#[tokio::test]
+	async fn test_enrich_matches_caches_results() {
+		let client = create_client(0);
+		let cache = EnrichmentCache::default();
+		let network = NetworkBuilder::new().slug("ethereum_mainnet").build();
+		let monitor = MonitorBuilder::new()
+			.address("0xAAAA")
+			.enrichment_call("symbol", "symbol()", Some("string"), None)
+			.enrichment_cache_ttl_secs(60)
+			.enrichment_call("owner_symbol", "symbol()", Some("string"), Some("0xbbbb"))
+			.enrichment_cache_ttl_secs(60)
+			.build();
+
+		let matches = vec![
+			create_monitor_match(monitor.clone()),
+			create_monitor_match(monitor),
+		];
+		let matches = cache.enrich_matches(&client, &network, matches).await;
+
+		for monitor_match in &matches {
+			let enrichment = enrichment(monitor_match);
+			assert_eq!(enrichment["symbol"], "0xAAAA:symbol()");
+			assert_eq!(enrichment["owner_symbol"], "0xbbbb:symbol()");
+		}
+		// Each contract is only called once
+		assert_eq!(client.calls.load(Ordering::SeqCst), 2);
+
+		// Results are not shared with other caches
+		let matches = EnrichmentCache::default()
+			.enrich_matches(&client, &network, matches)
+			.await;
+		assert_eq!(enrichment(&matches[0])["symbol"], "0xAAAA:symbol()");
+		assert_eq!(client.calls.load(Ordering::SeqCst), 4);
+	}
+
+	#[tokio::test]
+	async fn test_enrich_matches_without_cache_ttl() {
+		let client = create_client(0);
+		let cache = EnrichmentCache::default();
+		let network = NetworkBuilder::new().slug("ethereum_mainnet").build();
+		let monitor = MonitorBuilder::new()
+			.address("0xAAAA")
+			.enrichment_call("symbol", "symbol()", Some("string"), None)
+			.build();
+
+		let matches = cache
+			.enrich_matches(
+				&client,
+				&network,
+				vec![
+					create_monitor_match(monitor.clone()),
+					create_monitor_match(monitor),
+				],
+			)
+			.await;
+
+		assert!(matches
+			.iter()
+			.all(|m| enrichment(m)["symbol"] == "0xAAAA:symbol()"));
+		// Calls without a cache TTL are made for every match
+		assert_eq!(client.calls.load(Ordering::SeqCst), 2);
+		assert!(cache.get(&cache_key("symbol()"), Instant::now()).is_none());
+	}
+
+	#[test]
+	fn test_enrichment_cache_expiry() {
+		let cache = EnrichmentCache::default();
+		let now = Instant::now();
+		cache.insert(
+			cache_key("symbol()"),
+			"USDC".to_string(),
+			Duration::from_secs(60),
+			now,
+		);
+
+		assert_eq!(
+			cache.get(&cache_key("symbol()"), now + Duration::from_secs(59)),
+			Some("USDC".to_string())
+		);
+		assert_eq!(
+			cache.get(&cache_key("symbol()"), now + Duration::from_secs(60)),
+			None
+		);
+		// Expired results are removed
+		assert!(cache.lock().is_empty());
+	}
+
+	#[test]
+	fn test_enrichment_cache_evicts_least_recently_used_when_full() {
+		let cache = EnrichmentCache::new(2);
+		let now = Instant::now();
+		let ttl = Duration::from_secs(60);
+		cache.insert(cache_key("a()"), "a".to_string(), ttl, now);
+		cache.insert(
+			cache_key("b()"),
+			"b".to_string(),
+			ttl,
+			now + Duration::from_secs(1),
+		);
+		// Using the oldest result makes the other one the least recently used
+		assert!(cache
+			.get(&cache_key("a()"), now + Duration::from_secs(2))
+			.is_some());
+
+		let later = now + Duration::from_secs(3);
+		cache.insert(cache_key("c()"), "c".to_string(), ttl, later);
+
+		assert_eq!(cache.lock().len(), 2);
+		assert!(cache.get(&cache_key("a()"), later).is_some());
+		assert!(cache.get(&cache_key("b()"), later).is_none());
+		assert!(cache.get(&cache_key("c()"), later).is_some());
+	}
+
+	#[test]
+	fn test_enrichment_cache_evicts_expired_results_first() {
+		let cache = EnrichmentCache::new(2);
+		let now = Instant::now();
+		cache.insert(
+			cache_key("a()"),
+			"a".to_string(),
+			Duration::from_secs(60),
+			now,
+		);
+		// The most recently used result is the only one to expire
+		cache.insert(
+			cache_key("b()"),
+			"b".to_string(),
+			Duration::from_secs(1),
+			now + Duration::from_secs(1),
+		);
+
+		let later = now + Duration::from_secs(3);
+		cache.insert(
+			cache_key("c()"),
+			"c".to_string(),
+			Duration::from_secs(60),
+			later,
+		);
+
+		assert_eq!(cache.lock().len(), 2);
+		assert!(cache.get(&cache_key("a()"), later).is_some());
+		assert!(cache.get(&cache_key("c()"), later).is_some());
+	}
+
+	#[tokio::test]
+	async fn test_enrich_matches_timeout() {
+		let client = create_client(200);
+		let cache = EnrichmentCache::default();
+		let network = NetworkBuilder::new().slug("ethereum_mainnet").build();
+		let monitor = MonitorBuilder::new()
+			.enrichment_call("symbol", "symbol()", Some("string"), None)
+			.enrichment_cache_ttl_secs(60)
+			.enrichment_timeout_ms(10)
+			.build();
+
+		let matches = cache
+			.enrich_matches(
+				&client,
+				&network,
+				vec![
+					create_monitor_match(monitor.clone()),
+					create_monitor_match(monitor),
+				],
+			)
+			.await;
+
+		assert!(matches.iter().all(|m| enrichment(m).is_empty()));
+		// Failed calls are not cached
+		assert_eq!(client.calls.load(Ordering::SeqCst), 2);
+	}
+
+	#[tokio::test]
+	async fn test_enrich_matches_without_enrichment() {
+		let client = create_client(0);
+		let network = NetworkBuilder::new().slug("ethereum_mainnet").build();
+
+		let matches = EnrichmentCache::default()
+			.enrich_matches(
+				&client,
+				&network,
+				vec![create_monitor_match(MonitorBuilder::new().build())],
+			)
+			.await;
+
+		assert!(enrichment(&matches[0]).is_empty());
+		assert_eq!(client.calls.load(Ordering::SeqCst), 0);
+	}
+}
@@ -18,6 +18,41 @@ pub enum LiteralValue<'a> {
 	/// Store as string slice to preserve original form until evaluation phase.
 	/// Conversion to specific type is done within chain context during evaluation.
 	Number(&'a str),
+	/// The hash of a literal value, compared as a `0x` prefixed hex string.
+	/// e.g., keccak256("MINTER_ROLE"), sha256(0x1234)
+	Hash {
+		/// The hash function to apply.
+		function: HashFunction,
+		/// The value to hash.
+		input: HashInput<'a>,
+	},
+}
+
+/// Represents the hash functions that can be applied to a literal on the right side of a condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashFunction {
+	/// Keccak-256 hash (e.g., "keccak256('MINTER_ROLE')")
+	Keccak256,
+	/// SHA-256 hash (e.g., "sha256('MINTER_ROLE')")
+	Sha256,
+}
+
+impl std::fmt::Display for HashFunction {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			HashFunction::Keccak256 => write!(f, "keccak256"),
+			HashFunction::Sha256 => write!(f, "sha256"),
+		}
+	}
+}
+
+/// Represents the value a hash function is applied to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashInput<'a> {
+	/// A quoted string, hashed as its UTF-8 bytes. e.g., "MINTER_ROLE"
+	Text(&'a str),
+	/// An unquoted hexadecimal string, hashed as the bytes it encodes. e.g., 0x1234
+	Bytes(&'a str),
 }
 
 /// Represents the possible comparison operators that can be used in filter expressions.
@@ -69,8 +104,27 @@ pub struct VariablePath<'a> {
 	pub accessors: Vec<Accessor<'a>>,
 }
 
+/// Represents the built-in functions that can be applied to the left side of a condition.
+/// Functions operate on bytes and string values, using their byte representation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueFunction {
+	/// Byte length of the value (e.g., "length(input)")
+	Length,
+	/// Leading `n` bytes of the value (e.g., "prefix(input, 4)")
+	Prefix(usize),
+}
+
+impl std::fmt::Display for ValueFunction {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			ValueFunction::Length => write!(f, "length"),
+			ValueFunction::Prefix(_) => write!(f, "prefix"),
+		}
+	}
+}
+
 /// Represents the left side of a condition (LHS) in a filter expression.
-/// The left side can either be a simple variable name or a path to a variable.
+/// The left side can either be a simple variable name, a path to a variable or a function applied to either.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ConditionLeft<'a> {
 	/// A simple variable name (e.g., "name", "age", etc.)
@@ -78,6 +132,13 @@ pub enum ConditionLeft<'a> {
 	Simple(&'a str),
 	/// A sequence of accessors that form a path to a variable (e.g., "person.name", "person[0].age", etc.)
 	Path(VariablePath<'a>),
+	/// A built-in function applied to a variable or path (e.g., "length(input)", "prefix(input, 4)")
+	Function {
+		/// The function to apply to the resolved value.
+		function: ValueFunction,
+		/// The variable or path the function is applied to.
+		argument: Box<ConditionLeft<'a>>,
+	},
 }
 
 impl<'a> ConditionLeft<'a> {
@@ -86,6 +147,7 @@ impl<'a> ConditionLeft<'a> {
 		match self {
 			ConditionLeft::Simple(name) => name,
 			ConditionLeft::Path(path) => path.base,
+			ConditionLeft::Function { argument, .. } => argument.base_name(),
 		}
 	}
 
@@ -97,6 +159,15 @@ impl<'a> ConditionLeft<'a> {
 		match self {
 			ConditionLeft::Simple(_) => &[],
 			ConditionLeft::Path(path) => &path.accessors,
+			ConditionLeft::Function { argument, .. } => argument.accessors(),
+		}
+	}
+
+	/// Helper method to get the function applied to the variable or path, if any.
+	pub fn function(&self) -> Option<ValueFunction> {
+		match self {
+			ConditionLeft::Function { function, .. } => Some(*function),
+			_ => None,
 		}
 	}
 }
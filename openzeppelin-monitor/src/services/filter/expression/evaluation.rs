@@ -5,13 +5,20 @@
 //! for evaluating conditions based on the context of the chain.
 
 use super::error::EvaluationError;
-use crate::services::filter::expression::ast::{ComparisonOperator, LiteralValue};
+use crate::services::filter::expression::ast::{ComparisonOperator, LiteralValue, ValueFunction};
 
 /// The `ConditionEvaluator` trait defines methods for evaluating conditions in filter expressions.
 pub trait ConditionEvaluator {
 	/// Gets the raw string value and kind for a base variable name
 	fn get_base_param(&self, name: &str) -> Result<(&str, &str), EvaluationError>;
 
+	/// Gets the raw string value and kind of a nested value addressed by its full path
+	/// (e.g. `order.maker` or `amounts[0]`), for chains that flatten nested values into
+	/// addressable parameters. Paths not found are resolved by JSON path traversal instead.
+	fn get_path_param(&self, _path: &str) -> Option<(&str, &str)> {
+		None
+	}
+
 	/// Performs the final comparison between the left resolved value (after all path traversal) and the literal value
 	fn compare_final_values(
 		&self,
@@ -23,4 +30,14 @@ pub trait ConditionEvaluator {
 
 	/// Gets the chain-specific kind of a value from a JSON value
 	fn get_kind_from_json_value(&self, value: &serde_json::Value) -> String;
+
+	/// Applies a built-in function (e.g. `length(x)`, `prefix(x, n)`) to a resolved value
+	/// Returns the chain-specific kind and the value of the result, which are then compared
+	/// using `compare_final_values`
+	fn apply_function(
+		&self,
+		function: &ValueFunction,
+		kind: &str,
+		value: &str,
+	) -> Result<(String, String), EvaluationError>;
 }
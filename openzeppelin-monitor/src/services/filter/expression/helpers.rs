@@ -1,7 +1,10 @@
 //! Utility functions for evaluating expressions and resolving JSON paths
 
 use super::{
-	ast::{Accessor, ComparisonOperator, ConditionLeft, Expression, LogicalOperator},
+	ast::{
+		Accessor, ComparisonOperator, ConditionLeft, Expression, HashFunction, HashInput,
+		LiteralValue, LogicalOperator, ValueFunction,
+	},
 	error::EvaluationError,
 	evaluation::ConditionEvaluator,
 };
@@ -22,10 +25,22 @@ pub fn evaluate(
 			let final_left_value_str: String;
 			let final_left_kind: String;
 
+			let path_param = if accessors.is_empty() {
+				None
+			} else {
+				build_path_segments(base_name, accessors)
+					.last()
+					.and_then(|path| evaluator.get_path_param(path))
+			};
+
 			if accessors.is_empty() {
 				// No accessors, use the base value directly
 				final_left_value_str = base_value_str.to_string();
 				final_left_kind = base_kind_str.to_string();
+			} else if let Some((path_value_str, path_kind_str)) = path_param {
+				// The nested value is addressable by its full path
+				final_left_value_str = path_value_str.to_string();
+				final_left_kind = path_kind_str.to_string();
 			} else {
 				let resolved_value = resolve_path_to_json_value(
 					base_value_str,
@@ -51,11 +66,34 @@ pub fn evaluate(
 				};
 			}
 
+			// Hashes on the right side are compared as hex strings
+			let hashed_right = match &condition.right {
+				LiteralValue::Hash { function, input } => {
+					Some(apply_hash_function(function, input)?)
+				}
+				_ => None,
+			};
+			let right = match &hashed_right {
+				Some(hash) => LiteralValue::Str(hash),
+				None => condition.right.clone(),
+			};
+
+			if let Some(function) = condition.left.function() {
+				let (function_kind, function_value) =
+					evaluator.apply_function(&function, &final_left_kind, &final_left_value_str)?;
+				return evaluator.compare_final_values(
+					&function_kind,
+					&function_value,
+					&condition.operator,
+					&right,
+				);
+			}
+
 			evaluator.compare_final_values(
 				&final_left_kind,
 				&final_left_value_str,
 				&condition.operator,
-				&condition.right,
+				&right,
 			)
 		}
 		Expression::Logical {
@@ -111,6 +149,61 @@ pub fn compare_ordered_values<T: Ord>(
 	}
 }
 
+/// Decodes a hex encoded byte string, with or without the `0x` prefix
+/// Returns an error if the value is not valid hex
+pub fn decode_hex_bytes(value: &str) -> Result<Vec<u8>, EvaluationError> {
+	let hex_str = value
+		.strip_prefix("0x")
+		.or_else(|| value.strip_prefix("0X"))
+		.unwrap_or(value);
+	hex::decode(hex_str).map_err(|e| {
+		let msg = format!("Failed to decode '{}' as hex bytes", value);
+		EvaluationError::parse_error(msg, Some(e.into()), None)
+	})
+}
+
+/// Applies a built-in function to the byte representation of a value
+/// Returns the byte length for `length` and the leading bytes for `prefix`
+/// (all bytes if the value is shorter than the requested prefix)
+pub fn apply_bytes_function(function: &ValueFunction, bytes: &[u8]) -> BytesFunctionResult {
+	match function {
+		ValueFunction::Length => BytesFunctionResult::Length(bytes.len()),
+		ValueFunction::Prefix(n) => {
+			BytesFunctionResult::Prefix(bytes[..bytes.len().min(*n)].to_vec())
+		}
+	}
+}
+
+/// Hashes a literal with the given hash function
+/// Returns the `0x` prefixed lowercase hex digest
+/// Returns an error if a hex input cannot be decoded
+pub fn apply_hash_function(
+	function: &HashFunction,
+	input: &HashInput<'_>,
+) -> Result<String, EvaluationError> {
+	let bytes = match input {
+		HashInput::Text(text) => text.as_bytes().to_vec(),
+		HashInput::Bytes(hex_str) => decode_hex_bytes(hex_str)?,
+	};
+	let digest: Vec<u8> = match function {
+		HashFunction::Keccak256 => alloy::primitives::keccak256(&bytes).to_vec(),
+		HashFunction::Sha256 => {
+			use sha2::{Digest, Sha256};
+			Sha256::digest(&bytes).to_vec()
+		}
+	};
+	Ok(format!("0x{}", hex::encode(digest)))
+}
+
+/// Result of applying a built-in function to the byte representation of a value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BytesFunctionResult {
+	/// Byte length of the value
+	Length(usize),
+	/// Leading bytes of the value
+	Prefix(Vec<u8>),
+}
+
 /// Resolves a JSON path from a base variable name and accessors
 /// Returns the resolved JSON value
 /// Returns an error if the traversal fails
@@ -343,4 +436,60 @@ mod tests {
 			vec!["base.field".to_string(), "base.field[0]".to_string()]
 		);
 	}
+
+	// --- Tests for `decode_hex_bytes` and `apply_bytes_function` ---
+	#[test]
+	fn test_decode_hex_bytes() {
+		assert_eq!(
+			decode_hex_bytes("0xa9059cbb").unwrap(),
+			vec![0xa9, 0x05, 0x9c, 0xbb]
+		);
+		assert_eq!(decode_hex_bytes("0XA905").unwrap(), vec![0xa9, 0x05]);
+		assert_eq!(decode_hex_bytes("a905").unwrap(), vec![0xa9, 0x05]);
+		assert!(decode_hex_bytes("0x").unwrap().is_empty());
+		assert!(matches!(
+			decode_hex_bytes("0xzz"),
+			Err(EvaluationError::ParseError(_))
+		));
+		assert!(decode_hex_bytes("0xabc").is_err());
+	}
+
+	#[test]
+	fn test_apply_bytes_function() {
+		let bytes = [0xa9, 0x05, 0x9c, 0xbb, 0x00];
+		assert_eq!(
+			apply_bytes_function(&ValueFunction::Length, &bytes),
+			BytesFunctionResult::Length(5)
+		);
+		assert_eq!(
+			apply_bytes_function(&ValueFunction::Prefix(4), &bytes),
+			BytesFunctionResult::Prefix(vec![0xa9, 0x05, 0x9c, 0xbb])
+		);
+		// Prefix longer than the value returns all bytes
+		assert_eq!(
+			apply_bytes_function(&ValueFunction::Prefix(10), &bytes),
+			BytesFunctionResult::Prefix(bytes.to_vec())
+		);
+	}
+
+	#[test]
+	fn test_apply_hash_function() {
+		assert_eq!(
+			apply_hash_function(&HashFunction::Keccak256, &HashInput::Text("MINTER_ROLE")).unwrap(),
+			"0x9f2df0fed2c77648de5860a4cc508cd0818c85b8b8a1ab4ceeef8d981c8956a6"
+		);
+		assert_eq!(
+			apply_hash_function(&HashFunction::Sha256, &HashInput::Text("abc")).unwrap(),
+			"0xba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+		);
+		// Hex inputs are hashed as the bytes they encode
+		assert_eq!(
+			apply_hash_function(&HashFunction::Sha256, &HashInput::Bytes("0x616263")).unwrap(),
+			"0xba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+		);
+		assert!(matches!(
+			apply_hash_function(&HashFunction::Keccak256, &HashInput::Bytes("0xzz")),
+			Err(EvaluationError::ParseError(_))
+		));
+	}
 }
@@ -6,8 +6,10 @@ mod evaluation;
 mod helpers;
 mod parsing;
 
-pub use ast::{ComparisonOperator, LiteralValue};
+pub use ast::{ComparisonOperator, LiteralValue, ValueFunction};
 pub use error::EvaluationError;
 pub use evaluation::ConditionEvaluator;
-pub use helpers::{compare_ordered_values, evaluate};
+pub use helpers::{
+	apply_bytes_function, compare_ordered_values, decode_hex_bytes, evaluate, BytesFunctionResult,
+};
 pub use parsing::parse;
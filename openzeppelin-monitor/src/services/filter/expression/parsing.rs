@@ -3,8 +3,8 @@
 //! The parser converts the input string into an abstract syntax tree (AST) representation of the expression.
 
 use super::ast::{
-	Accessor, ComparisonOperator, Condition, ConditionLeft, Expression, LiteralValue,
-	LogicalOperator, VariablePath,
+	Accessor, ComparisonOperator, Condition, ConditionLeft, Expression, HashFunction, HashInput,
+	LiteralValue, LogicalOperator, ValueFunction, VariablePath,
 };
 use winnow::{
 	ascii::{digit1, space0, space1, Caseless},
@@ -227,7 +227,7 @@ fn parse_base_variable_name<'a>(input: &mut Input<'a>) -> ParserResult<&'a str>
 	.parse_next(input)
 }
 
-fn parse_condition_lhs<'a>(input: &mut Input<'a>) -> ParserResult<ConditionLeft<'a>> {
+fn parse_variable_lhs<'a>(input: &mut Input<'a>) -> ParserResult<ConditionLeft<'a>> {
 	// Parse the base variable name
 	let base = parse_base_variable_name.parse_next(input)?;
 
@@ -241,6 +241,71 @@ fn parse_condition_lhs<'a>(input: &mut Input<'a>) -> ParserResult<ConditionLeft<
 	}
 }
 
+/// Parses a built-in function applied to a variable (e.g., "length(input)", "prefix(input, 4)")
+fn parse_function_lhs<'a>(input: &mut Input<'a>) -> ParserResult<ConditionLeft<'a>> {
+	let length_parser = delimited(
+		(literal("length"), space0, literal("("), space0),
+		parse_variable_lhs,
+		(space0, literal(")")),
+	)
+	.map(|argument| (ValueFunction::Length, argument));
+
+	let prefix_parser = (
+		(literal("prefix"), space0, literal("("), space0),
+		parse_variable_lhs,
+		(space0, literal(","), space0),
+		digit1.try_map(|s: &str| s.parse::<usize>()),
+		(space0, literal(")")),
+	)
+		.map(|(_, argument, _, n, _)| (ValueFunction::Prefix(n), argument));
+
+	alt((length_parser, prefix_parser))
+		.map(|(function, argument)| ConditionLeft::Function {
+			function,
+			argument: Box::new(argument),
+		})
+		.context(StrContext::Expected(StrContextValue::Description(
+			"function call like 'length(input)' or 'prefix(input, 4)'",
+		)))
+		.parse_next(input)
+}
+
+fn parse_condition_lhs<'a>(input: &mut Input<'a>) -> ParserResult<ConditionLeft<'a>> {
+	alt((parse_function_lhs, parse_variable_lhs)).parse_next(input)
+}
+
+/// Parses a hash function applied to a literal (e.g., "keccak256('MINTER_ROLE')", "sha256(0x1234)")
+/// Quoted strings are hashed as their UTF-8 bytes and unquoted hex strings as the bytes they encode
+fn parse_hash<'a>(input: &mut Input<'a>) -> ParserResult<LiteralValue<'a>> {
+	let function = alt((
+		literal("keccak256").value(HashFunction::Keccak256),
+		literal("sha256").value(HashFunction::Sha256),
+	));
+
+	let hash_input = alt((
+		parse_quoted_string.map(|value| match value {
+			LiteralValue::Str(s) => HashInput::Text(s),
+			_ => unreachable!("quoted strings are parsed as string literals"),
+		}),
+		parse_hex_string.map(|value| match value {
+			LiteralValue::Str(s) => HashInput::Bytes(s),
+			_ => unreachable!("hex strings are parsed as string literals"),
+		}),
+	));
+
+	(
+		function,
+		(space0, literal("("), space0),
+		hash_input,
+		(space0, literal(")")),
+	)
+		.map(|(function, _, input, _)| LiteralValue::Hash { function, input })
+		.context(StrContext::Expected(StrContextValue::Description(
+			"hash function call like 'keccak256(\"MINTER_ROLE\")' or 'sha256(0x1234)'",
+		)))
+		.parse_next(input)
+}
+
 /// Parses any valid LiteralValue (boolean, number, string, or variable)
 /// Handles optional whitespace around the value
 fn parse_value<'a>(input: &mut Input<'a>) -> ParserResult<LiteralValue<'a>> {
@@ -249,6 +314,7 @@ fn parse_value<'a>(input: &mut Input<'a>) -> ParserResult<LiteralValue<'a>> {
 		alt((
 			parse_quoted_string,       // "'string'" or '"string"'
 			parse_boolean,             // "true" / "false"
+			parse_hash,                // "keccak256('string')" / "sha256(0x...)"
 			parse_hex_string,          // "0x..."
 			parse_number_or_fixed_str, // "123" / "-123" / "123.456"
 			parse_unquoted_string,     // "unquoted_string"
@@ -803,6 +869,110 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_parse_function_lhs() {
+		assert_parses_ok(
+			parse_condition_lhs,
+			"length(input)",
+			ConditionLeft::Function {
+				function: ValueFunction::Length,
+				argument: Box::new(ConditionLeft::Simple("input")),
+			},
+			"",
+		);
+		assert_parses_ok(
+			parse_condition_lhs,
+			"prefix( data.payload , 4 )",
+			ConditionLeft::Function {
+				function: ValueFunction::Prefix(4),
+				argument: Box::new(ConditionLeft::Path(VariablePath {
+					base: "data",
+					accessors: vec![Accessor::Key("payload")],
+				})),
+			},
+			"",
+		);
+		// Variables named like a function are still plain variables
+		assert_parses_ok(
+			parse_condition_lhs,
+			"length",
+			ConditionLeft::Simple("length"),
+			"",
+		);
+		assert_parses_ok(
+			parse_condition_lhs,
+			"prefix.size",
+			ConditionLeft::Path(VariablePath {
+				base: "prefix",
+				accessors: vec![Accessor::Key("size")],
+			}),
+			"",
+		);
+
+		assert_parse_fails(parse_function_lhs, "length()");
+		assert_parse_fails(parse_function_lhs, "prefix(input)");
+		assert_parse_fails(parse_function_lhs, "prefix(input, -1)");
+		assert_parse_fails(parse_function_lhs, "size(input)");
+	}
+
+	#[test]
+	fn test_parse_function_condition() {
+		let expected = Expression::Condition(Condition {
+			left: ConditionLeft::Function {
+				function: ValueFunction::Prefix(4),
+				argument: Box::new(ConditionLeft::Simple("input")),
+			},
+			operator: ComparisonOperator::Eq,
+			right: LiteralValue::Str("0xa9059cbb"),
+		});
+		assert_eq!(parse("prefix(input, 4) == 0xa9059cbb").unwrap(), expected);
+
+		let expected = Expression::Condition(Condition {
+			left: ConditionLeft::Function {
+				function: ValueFunction::Length,
+				argument: Box::new(ConditionLeft::Simple("input")),
+			},
+			operator: ComparisonOperator::Gt,
+			right: LiteralValue::Number("100"),
+		});
+		assert_eq!(parse("length(input) > 100").unwrap(), expected);
+	}
+
+	#[test]
+	fn test_parse_hash_condition() {
+		let expected = Expression::Condition(Condition {
+			left: ConditionLeft::Simple("role"),
+			operator: ComparisonOperator::Eq,
+			right: LiteralValue::Hash {
+				function: HashFunction::Keccak256,
+				input: HashInput::Text("MINTER_ROLE"),
+			},
+		});
+		assert_eq!(
+			parse("role == keccak256(\"MINTER_ROLE\")").unwrap(),
+			expected
+		);
+		assert_eq!(
+			parse("role == keccak256( 'MINTER_ROLE' )").unwrap(),
+			expected
+		);
+
+		let expected = Expression::Condition(Condition {
+			left: ConditionLeft::Simple("digest"),
+			operator: ComparisonOperator::Ne,
+			right: LiteralValue::Hash {
+				function: HashFunction::Sha256,
+				input: HashInput::Bytes("0x1234"),
+			},
+		});
+		assert_eq!(parse("digest != sha256(0x1234)").unwrap(), expected);
+
+		// Only quoted strings and hex literals can be hashed
+		assert_parse_fails(parse_hash, "keccak256(MINTER_ROLE)");
+		assert_parse_fails(parse_hash, "keccak256('MINTER_ROLE'");
+		assert_parse_fails(parse_hash, "md5('abc')");
+	}
+
 	#[test]
 	fn test_parse_condition() {
 		let expr = "var == 123";
@@ -16,10 +16,11 @@ use crate::{
 	services::{
 		filter::{
 			evm_helpers::{b256_to_string, h160_to_string},
-			FilterError,
+			match_severity, FilterError, SEVERITY_VARIABLE,
 		},
 		trigger::TriggerExecutionServiceTrait,
 	},
+	utils::timestamp::{default_timezone, insert_timestamp_variables},
 };
 
 /// Process a monitor match by executing associated triggers.
@@ -39,7 +40,9 @@ use crate::{
 /// # Example
 /// The function converts blockchain data into template variables like:
 /// ```text
+/// "match.id": "3f1c5e0a9d7b6c2e4f8a1b3d5c7e9f0a2b4c6d8e0f1a3b5c7d9e1f2a4b6c8d0e"
 /// "monitor.name": "Transfer USDT Token"
+/// "monitor.labels.team": "payments"
 /// "transaction.hash": "0x99139c8f64b9b939678e261e1553660b502d9fd01c2ab1516e699ee6c8cc5791"
 /// "transaction.from": "0xf401346fd255e034a2e43151efe1d68c1e0f8ca5"
 /// "transaction.to": "0x0000000000001ff3684f28c67538d4d072c22734"
@@ -48,7 +51,13 @@ use crate::{
 /// "events.0.args.to": "0x70bf6634ee8cb27d04478f184b9b8bb13e5f4710"
 /// "events.0.args.from": "0x2e8135be71230c6b1b4045696d41c09db0414226"
 /// "events.0.args.value": "88248701"
+/// "enrichment.symbol": "USDT"
+/// "block.timestamp": "1700000000"
+/// "block.timestamp_iso": "2023-11-14T22:13:20Z"
+/// "severity": "critical"
 /// ```
+///
+/// The `severity` variable is only set when one of the monitor's `severity_rules` applies.
 pub async fn handle_match<T: TriggerExecutionServiceTrait>(
 	matching_monitor: MonitorMatch,
 	trigger_service: &T,
@@ -62,9 +71,14 @@ pub async fn handle_match<T: TriggerExecutionServiceTrait>(
 
 			// Create structured JSON data
 			let mut data_json = json!({
+				"match": {
+					"id": matching_monitor.match_id(),
+				},
 				"monitor": {
 					"name": evm_monitor_match.monitor.name.clone(),
+					"labels": evm_monitor_match.monitor.labels.clone(),
 				},
+				"enrichment": evm_monitor_match.enrichment.clone(),
 				"transaction": {
 					"hash": b256_to_string(*transaction.hash()),
 					"from": h160_to_string(*sender),
@@ -79,6 +93,11 @@ pub async fn handle_match<T: TriggerExecutionServiceTrait>(
 				data_json["transaction"]["to"] = json!(h160_to_string(*to));
 			}
 
+			// Add revert reason if it was decoded
+			if let Some(revert_reason) = &evm_monitor_match.revert_reason {
+				data_json["transaction"]["revert_reason"] = json!(revert_reason);
+			}
+
 			// Process matched functions
 			let functions = data_json["functions"].as_array_mut().unwrap();
 			for func in evm_monitor_match.matched_on.functions.iter() {
@@ -133,6 +152,18 @@ pub async fn handle_match<T: TriggerExecutionServiceTrait>(
 				events.push(event_data);
 			}
 
+			let mut variables = json_to_hashmap(&data_json);
+			if let Some(block_timestamp) = evm_monitor_match.block_timestamp {
+				insert_timestamp_variables(
+					&mut variables,
+					block_timestamp as i64,
+					default_timezone(),
+				);
+			}
+			if let Some(severity) = match_severity(&matching_monitor) {
+				variables.insert(SEVERITY_VARIABLE.to_string(), severity);
+			}
+
 			// Swallow any errors since it's logged in the trigger service and we want to continue
 			// processing other matches
 			let _ = trigger_service
@@ -141,9 +172,10 @@ pub async fn handle_match<T: TriggerExecutionServiceTrait>(
 						.monitor
 						.triggers
 						.iter()
-						.map(|s| s.to_string())
+						.filter(|trigger| trigger.applies_to(&evm_monitor_match.matched_on))
+						.map(|trigger| trigger.name().to_string())
 						.collect::<Vec<_>>(),
-					json_to_hashmap(&data_json),
+					variables,
 					&matching_monitor,
 					trigger_scripts,
 				)
@@ -154,9 +186,14 @@ pub async fn handle_match<T: TriggerExecutionServiceTrait>(
 
 			// Create structured JSON data
 			let mut data_json = json!({
+				"match": {
+					"id": matching_monitor.match_id(),
+				},
 				"monitor": {
 					"name": stellar_monitor_match.monitor.name.clone(),
+					"labels": stellar_monitor_match.monitor.labels.clone(),
 				},
+				"enrichment": stellar_monitor_match.enrichment.clone(),
 				"transaction": {
 					"hash": transaction.hash().to_string(),
 				},
@@ -218,6 +255,18 @@ pub async fn handle_match<T: TriggerExecutionServiceTrait>(
 				events.push(event_data);
 			}
 
+			let mut variables = json_to_hashmap(&data_json);
+			if transaction.ledger_close_time > 0 {
+				insert_timestamp_variables(
+					&mut variables,
+					transaction.ledger_close_time,
+					default_timezone(),
+				);
+			}
+			if let Some(severity) = match_severity(&matching_monitor) {
+				variables.insert(SEVERITY_VARIABLE.to_string(), severity);
+			}
+
 			// Swallow any errors since it's logged in the trigger service and we want to continue
 			// processing other matches
 			let _ = trigger_service
@@ -226,9 +275,10 @@ pub async fn handle_match<T: TriggerExecutionServiceTrait>(
 						.monitor
 						.triggers
 						.iter()
-						.map(|s| s.to_string())
+						.filter(|trigger| trigger.applies_to(&stellar_monitor_match.matched_on))
+						.map(|trigger| trigger.name().to_string())
 						.collect::<Vec<_>>(),
-					json_to_hashmap(&data_json),
+					variables,
 					&matching_monitor,
 					trigger_scripts,
 				)
@@ -302,6 +352,23 @@ mod tests {
 		assert_eq!(hashmap["transaction.hash"], "0x1234567890abcdef");
 	}
 
+	#[test]
+	fn test_json_to_hashmap_with_enrichment() {
+		let json = json!({
+			"monitor": {
+				"name": "Test Monitor",
+			},
+			"enrichment": {
+				"symbol": "USDC",
+				"decimals": "6",
+			},
+		});
+
+		let hashmap = json_to_hashmap(&json);
+		assert_eq!(hashmap["enrichment.symbol"], "USDC");
+		assert_eq!(hashmap["enrichment.decimals"], "6");
+	}
+
 	#[test]
 	fn test_json_to_hashmap_with_functions() {
 		let json = json!({
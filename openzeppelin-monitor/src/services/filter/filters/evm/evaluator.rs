@@ -5,8 +5,8 @@ use super::helpers::{are_same_address, string_to_i256, string_to_u256};
 use crate::{
 	models::EVMMatchParamEntry,
 	services::filter::expression::{
-		compare_ordered_values, ComparisonOperator, ConditionEvaluator, EvaluationError,
-		LiteralValue,
+		apply_bytes_function, compare_ordered_values, decode_hex_bytes, BytesFunctionResult,
+		ComparisonOperator, ConditionEvaluator, EvaluationError, LiteralValue, ValueFunction,
 	},
 };
 use rust_decimal::Decimal;
@@ -19,6 +19,9 @@ const UNSIGNED_INTEGER_KINDS: &[&str] = &[
 	"uint8", "uint16", "uint32", "uint64", "uint128", "uint256", "number",
 ];
 
+/// Literal matching an absent address, e.g. the `to` of a contract creation transaction
+const NULL_ADDRESS_LITERAL: &str = "null";
+
 const SIGNED_INTEGER_KINDS: &[&str] = &["int8", "int16", "int32", "int64", "int128", "int256"];
 
 const ARRAY_KINDS: &[&str] = &[
@@ -548,7 +551,7 @@ impl<'a> EVMConditionEvaluator<'a> {
 	}
 
 	/// Compares an EVM address (string) with a literal value based on the operator.
-	/// Only supports Eq and Ne operators.
+	/// Only supports Eq and Ne operators. The `null` literal matches an empty address.
 	///
 	/// Arguments:
 	/// - left: The left-hand side value as a string.
@@ -576,9 +579,15 @@ impl<'a> EVMConditionEvaluator<'a> {
 
 		tracing::debug!("Comparing addresses: left: {}, right: {}", left, right);
 
+		let is_same = if right.eq_ignore_ascii_case(NULL_ADDRESS_LITERAL) {
+			left.trim().is_empty()
+		} else {
+			are_same_address(left, right)
+		};
+
 		match operator {
-			ComparisonOperator::Eq => Ok(are_same_address(left, right)),
-			ComparisonOperator::Ne => Ok(!are_same_address(left, right)),
+			ComparisonOperator::Eq => Ok(is_same),
+			ComparisonOperator::Ne => Ok(!is_same),
 			_ => {
 				let msg = format!("Unsupported operator for address type: {:?}", operator);
 				Err(EvaluationError::unsupported_operator(msg, None, None))
@@ -860,6 +869,20 @@ impl ConditionEvaluator for EVMConditionEvaluator<'_> {
 			})
 	}
 
+	/// This method is used to get a nested parameter flattened by the EVM filter.
+	///
+	/// Arguments:
+	/// - path: The full path of the parameter, e.g. `order.maker` or `amounts[0]`.
+	///
+	/// Returns:
+	/// - The value and kind of the parameter, if it was flattened.
+	fn get_path_param(&self, path: &str) -> Option<(&str, &str)> {
+		self.args
+			.iter()
+			.find(|p| p.name == path)
+			.map(|p| (p.value.as_str(), p.kind.as_str()))
+	}
+
 	/// This method is used to compare the final values of the EVM condition evaluator.
 	///
 	/// Arguments:
@@ -971,6 +994,52 @@ impl ConditionEvaluator for EVMConditionEvaluator<'_> {
 			serde_json::Value::Null => "null".to_string(),
 		}
 	}
+
+	/// This method is used to apply a built-in function to a bytes or string value.
+	///
+	/// Arguments:
+	/// - function: The function to apply.
+	/// - kind: The kind of the value.
+	/// - value: The value as a string (hex encoded for bytes kinds).
+	///
+	/// Returns:
+	/// - The kind and value of the result: a `number` for `length`, and for `prefix`
+	///   a hex encoded `bytes` value or a `string` value depending on the input kind.
+	fn apply_function(
+		&self,
+		function: &ValueFunction,
+		kind: &str,
+		value: &str,
+	) -> Result<(String, String), EvaluationError> {
+		let kind = kind.to_lowercase();
+		let is_bytes_kind = kind == "bytes"
+			|| kind
+				.strip_prefix("bytes")
+				.is_some_and(|size| size.parse::<u8>().is_ok());
+
+		let bytes = if is_bytes_kind {
+			decode_hex_bytes(value)?
+		} else if kind == "string" {
+			value.as_bytes().to_vec()
+		} else {
+			let msg = format!(
+				"Function '{}' is not supported for EVM parameter kind: {}",
+				function, kind
+			);
+			return Err(EvaluationError::type_mismatch(msg, None, None));
+		};
+
+		Ok(match apply_bytes_function(function, &bytes) {
+			BytesFunctionResult::Length(length) => ("number".to_string(), length.to_string()),
+			BytesFunctionResult::Prefix(prefix) if is_bytes_kind => {
+				("bytes".to_string(), format!("0x{}", hex::encode(prefix)))
+			}
+			BytesFunctionResult::Prefix(prefix) => (
+				"string".to_string(),
+				String::from_utf8_lossy(&prefix).into_owned(),
+			),
+		})
+	}
 }
 
 #[cfg(test)]
@@ -1296,6 +1365,32 @@ mod tests {
 			.unwrap());
 	}
 
+	#[test]
+	fn test_compare_address_null() {
+		let evaluator = create_evaluator();
+
+		assert!(evaluator
+			.compare_address("", &ComparisonOperator::Eq, &LiteralValue::Str("null"))
+			.unwrap());
+		assert!(!evaluator
+			.compare_address("", &ComparisonOperator::Ne, &LiteralValue::Str("null"))
+			.unwrap());
+		assert!(!evaluator
+			.compare_address(
+				"0x1234567890123456789012345678901234567890",
+				&ComparisonOperator::Eq,
+				&LiteralValue::Str("null")
+			)
+			.unwrap());
+		assert!(evaluator
+			.compare_address(
+				"0x1234567890123456789012345678901234567890",
+				&ComparisonOperator::Ne,
+				&LiteralValue::Str("null")
+			)
+			.unwrap());
+	}
+
 	#[test]
 	fn test_compare_address_error() {
 		let evaluator = create_evaluator();
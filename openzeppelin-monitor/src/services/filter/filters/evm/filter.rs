@@ -5,34 +5,45 @@
 //! - Transaction matching based on conditions
 //! - Function call detection and parameter matching
 //! - Event log processing and filtering
+//! - Aggregation of event parameters across a transaction
+//! - Percentage changes of event parameters across a block
 //! - ABI-based decoding of function calls and events
+//! - Raw selector and topic0 matching for contracts without an ABI
 
 use alloy::core::dyn_abi::{DynSolType, DynSolValue, EventExt};
-use alloy::core::json_abi::{AbiItem, JsonAbi};
-use alloy::primitives::{LogData, U64};
+use alloy::core::json_abi::JsonAbi;
+use alloy::primitives::{LogData, U256, U64};
 use async_trait::async_trait;
-use std::marker::PhantomData;
+use rust_decimal::{prelude::FromPrimitive, Decimal};
+use std::{
+	borrow::Cow,
+	collections::{BTreeSet, HashMap},
+	marker::PhantomData,
+};
 use tracing::instrument;
 
 use crate::{
 	models::{
-		AddressWithSpec, BlockType, ContractSpec, EVMContractSpec, EVMMatchArguments,
-		EVMMatchParamEntry, EVMMatchParamsMap, EVMMonitorMatch, EVMReceiptLog, EVMTransaction,
-		EVMTransactionReceipt, EventCondition, FunctionCondition, MatchConditions, Monitor,
-		MonitorMatch, Network, TransactionCondition, TransactionStatus,
+		AddressWithSpec, AggregateCondition, BlockType, ContractSpec, EVMBlock, EVMContractSpec,
+		EVMMatchArguments, EVMMatchParamEntry, EVMMatchParamsMap, EVMMonitorMatch, EVMReceiptLog,
+		EVMTransaction, EVMTransactionReceipt, EventCondition, EventField, FunctionCondition,
+		MatchConditions, Monitor, MonitorMatch, Network, PercentageChangeCondition,
+		TransactionCondition, TransactionStatus,
 	},
 	services::{
 		blockchain::{BlockChainClient, EvmClientTrait},
 		filter::{
 			evm_helpers::{
-				are_same_address, are_same_signature, b256_to_string, format_token_value,
-				h160_to_string, normalize_address,
+				are_same_address, are_same_signature, b256_to_string, flatten_param_value,
+				format_token_value, h160_to_string, parse_event_topic, parse_function_selector,
+				string_to_u256,
 			},
 			expression::{self, EvaluationError},
 			filters::evm::evaluator::EVMConditionEvaluator,
-			BlockFilter, FilterError,
+			network_address_normalizer, AddressNormalizer, BlockFilter, FilterError,
 		},
 	},
+	utils::metrics::TRANSACTION_LOGS_TRUNCATED_TOTAL,
 };
 
 /// Filter implementation for EVM-compatible blockchains
@@ -40,6 +51,19 @@ pub struct EVMBlockFilter<T> {
 	pub _client: PhantomData<T>,
 }
 
+/// Percentage change condition matched in a block
+#[derive(Debug, Clone)]
+pub struct PercentageChangeMatch {
+	/// The matched condition
+	pub condition: PercentageChangeCondition,
+
+	/// Hash of the transaction that emitted the changed value
+	pub transaction_hash: String,
+
+	/// The events holding the reference and the changed value, in that order
+	pub events: Vec<EVMMatchParamsMap>,
+}
+
 impl<T> EVMBlockFilter<T> {
 	/// Finds transactions that match the monitor's conditions.
 	///
@@ -47,15 +71,20 @@ impl<T> EVMBlockFilter<T> {
 	/// * `tx_status` - Status of the transaction (success/failure)
 	/// * `transaction` - The transaction to check
 	/// * `tx_receipt` - Transaction receipt
+	/// * `revert_reason` - Decoded revert reason of a failed transaction
 	/// * `monitor` - Monitor containing match conditions
 	/// * `matched_transactions` - Vector to store matching transactions
+	/// * `block_params` - Parameters of the block containing the transaction
+	#[allow(clippy::too_many_arguments)]
 	pub fn find_matching_transaction(
 		&self,
 		tx_status: &TransactionStatus,
 		transaction: &EVMTransaction,
 		tx_receipt: &Option<EVMTransactionReceipt>,
+		revert_reason: Option<&str>,
 		monitor: &Monitor,
 		matched_transactions: &mut Vec<TransactionCondition>,
+		block_params: &[EVMMatchParamEntry],
 	) {
 		if monitor.match_conditions.transactions.is_empty() {
 			// Match all transactions
@@ -74,7 +103,7 @@ impl<T> EVMBlockFilter<T> {
 
 				if status_matches {
 					if let Some(expr) = &condition.expression {
-						let tx_params = vec![
+						let mut tx_params = vec![
 							EVMMatchParamEntry {
 								name: "value".to_string(),
 								value: transaction.value.to_string(),
@@ -135,7 +164,7 @@ impl<T> EVMBlockFilter<T> {
 							EVMMatchParamEntry {
 								name: "input".to_string(),
 								value: format!("0x{}", hex::encode(&transaction.input)),
-								kind: "string".to_string(),
+								kind: "bytes".to_string(),
 								indexed: false,
 							},
 							EVMMatchParamEntry {
@@ -155,7 +184,27 @@ impl<T> EVMBlockFilter<T> {
 								kind: "uint64".to_string(),
 								indexed: false,
 							},
+							EVMMatchParamEntry {
+								name: "revert_reason".to_string(),
+								value: revert_reason.unwrap_or_default().to_string(),
+								kind: "string".to_string(),
+								indexed: false,
+							},
+							EVMMatchParamEntry {
+								name: "access_list".to_string(),
+								value: serde_json::to_string(
+									&transaction
+										.access_list_addresses()
+										.into_iter()
+										.map(|address| h160_to_string(*address))
+										.collect::<Vec<_>>(),
+								)
+								.unwrap_or_else(|_| "[]".to_string()),
+								kind: "address[]".to_string(),
+								indexed: false,
+							},
 						];
+						tx_params.extend_from_slice(block_params);
 
 						// Evaluate the expression with transaction parameters
 						match self.evaluate_expression(expr, &tx_params) {
@@ -188,7 +237,8 @@ impl<T> EVMBlockFilter<T> {
 	/// Finds function calls in a transaction that match the monitor's conditions.
 	///
 	/// Decodes the transaction input data using the contract ABI and matches against
-	/// the monitor's function conditions.
+	/// the monitor's function conditions. Conditions given as a raw 4-byte selector
+	/// (e.g. `0xa9059cbb`) are matched against the input data directly, without decoding.
 	///
 	/// # Arguments
 	/// * `contract_specs` - List of contract specifications
@@ -196,6 +246,7 @@ impl<T> EVMBlockFilter<T> {
 	/// * `monitor` - Monitor containing function match conditions
 	/// * `matched_functions` - Vector to store matching functions
 	/// * `matched_on_args` - Arguments from matched function calls
+	/// * `block_params` - Parameters of the block containing the transaction
 	pub fn find_matching_functions_for_transaction(
 		&self,
 		contract_specs: &[(String, EVMContractSpec)],
@@ -203,6 +254,7 @@ impl<T> EVMBlockFilter<T> {
 		monitor: &Monitor,
 		matched_functions: &mut Vec<FunctionCondition>,
 		matched_on_args: &mut EVMMatchArguments,
+		block_params: &[EVMMatchParamEntry],
 	) {
 		if !monitor.match_conditions.functions.is_empty() {
 			// Try to decode the function call if there's input data
@@ -213,6 +265,30 @@ impl<T> EVMBlockFilter<T> {
 					.to
 					.is_some_and(|to| are_same_address(&addr.address, &h160_to_string(to)))
 			}) {
+				// Match raw selector conditions without decoding
+				if let Some(selector) = input_data.0.get(..4) {
+					let matches_raw_selector =
+						monitor.match_conditions.functions.iter().any(|condition| {
+							parse_function_selector(&condition.signature)
+								.is_some_and(|raw| raw == selector)
+						});
+					if matches_raw_selector {
+						let hex_signature = format!("0x{}", hex::encode(selector));
+						matched_functions.push(FunctionCondition {
+							signature: hex_signature.clone(),
+							expression: None,
+						});
+						if let Some(functions) = &mut matched_on_args.functions {
+							functions.push(EVMMatchParamsMap {
+								signature: hex_signature.clone(),
+								args: None,
+								hex_signature: Some(hex_signature),
+							});
+						}
+						return;
+					}
+				}
+
 				// Process the matching address's ABI
 				if let Some((_, abi)) = contract_specs
 					.iter()
@@ -308,8 +384,26 @@ impl<T> EVMBlockFilter<T> {
 										})
 										.collect();
 									if let Some(expr) = &condition.expression {
+										// Nested tuple and array values are addressable by path
+										let nested_params: Vec<EVMMatchParamEntry> = function
+											.inputs
+											.iter()
+											.zip(decoded.iter())
+											.flat_map(|(input, value)| {
+												flatten_param_value(
+													&input.name,
+													&input.ty,
+													&input.components,
+													value,
+												)
+											})
+											.collect();
 										// Evaluate the expression condition
-										match self.evaluate_expression(expr, &params) {
+										match self.evaluate_expression(
+											expr,
+											&[params.as_slice(), &nested_params, block_params]
+												.concat(),
+										) {
 											Ok(true) => {
 												matched_functions.push(FunctionCondition {
 													signature: function_signature_with_params
@@ -370,7 +464,9 @@ impl<T> EVMBlockFilter<T> {
 	/// Finds events in a transaction receipt that match the monitor's conditions.
 	///
 	/// Processes event logs from the transaction receipt and matches them against
-	/// the monitor's event conditions.
+	/// the monitor's event conditions. Conditions given as a raw topic0 hash are matched
+	/// against the log's first topic directly, without decoding. Logs that cannot be decoded
+	/// are labeled with the address's `event_signatures`, exposing their values as raw hex.
 	///
 	/// # Arguments
 	/// * `logs` - Transaction receipt containing event logs
@@ -378,6 +474,7 @@ impl<T> EVMBlockFilter<T> {
 	/// * `matched_events` - Vector to store matching events
 	/// * `matched_on_args` - Arguments from matched events
 	/// * `involved_addresses` - Addresses involved in matched events
+	/// * `block_params` - Parameters of the block containing the transaction
 	pub fn find_matching_events_for_transaction(
 		&self,
 		logs: &[EVMReceiptLog],
@@ -385,6 +482,7 @@ impl<T> EVMBlockFilter<T> {
 		matched_events: &mut Vec<EventCondition>,
 		matched_on_args: &mut EVMMatchArguments,
 		involved_addresses: &mut Vec<String>,
+		block_params: &[EVMMatchParamEntry],
 	) {
 		for log in logs {
 			// Find the specific monitored address that matches the log address
@@ -401,63 +499,95 @@ impl<T> EVMBlockFilter<T> {
 			// Add the contract address that emitted the event
 			involved_addresses.push(h160_to_string(log.address));
 
-			// Process the matching address's ABI
-			if let Some(abi) = &monitored_addr.contract_spec {
-				let decoded_log = self.decode_events(abi, log);
+			// Match raw topic0 conditions without decoding
+			if let Some(topic0) = log.topics.first() {
+				let matches_raw_topic = monitor.match_conditions.events.iter().any(|condition| {
+					parse_event_topic(&condition.signature).is_some_and(|raw| raw == *topic0)
+				});
+				if matches_raw_topic {
+					let hex_signature = b256_to_string(*topic0);
+					matched_events.push(EventCondition {
+						signature: hex_signature.clone(),
+						expression: None,
+					});
+					if let Some(events) = &mut matched_on_args.events {
+						events.push(self.label_raw_event(monitored_addr, log).unwrap_or(
+							EVMMatchParamsMap {
+								signature: hex_signature.clone(),
+								args: None,
+								hex_signature: Some(hex_signature),
+							},
+						));
+					}
+					continue;
+				}
+			}
 
-				if let Some(event_condition) = decoded_log {
-					if monitor.match_conditions.events.is_empty() {
-						// Match all events
-						matched_events.push(EventCondition {
-							signature: event_condition.signature.clone(),
-							expression: None,
-						});
-						if let Some(events) = &mut matched_on_args.events {
-							events.push(event_condition);
-						}
-					} else {
-						// Check if this event matches any of the conditions
-						for condition in &monitor.match_conditions.events {
-							// Remove any whitespaces to ensure accurate matching
-							// For example: Transfer(address, address, uint256) ==
-							// Transfer(address,address,uint256)
-							if are_same_signature(&condition.signature, &event_condition.signature)
-							{
-								if condition.expression.is_none() {
-									matched_events.push(EventCondition {
-										signature: event_condition.signature.clone(),
-										expression: None,
-									});
-									if let Some(events) = &mut matched_on_args.events {
-										events.push(event_condition);
-									}
-									break;
-								} else {
-									// Evaluate the expression condition
-									if let Some(expr) = &condition.expression {
-										match self.evaluate_expression(
-											expr,
+			// Decode the log with the address's ABI, or label it with its event signature
+			let decoded_log = monitored_addr
+				.merged_contract_spec()
+				.and_then(|abi| self.decode_event_params(&abi, log))
+				.or_else(|| {
+					self.label_raw_event(monitored_addr, log)
+						.map(|event| (event, Vec::new()))
+				});
+
+			if let Some((event_condition, nested_params)) = decoded_log {
+				if monitor.match_conditions.events.is_empty() {
+					// Match all events
+					matched_events.push(EventCondition {
+						signature: event_condition.signature.clone(),
+						expression: None,
+					});
+					if let Some(events) = &mut matched_on_args.events {
+						events.push(event_condition);
+					}
+				} else {
+					// Check if this event matches any of the conditions
+					for condition in &monitor.match_conditions.events {
+						// Remove any whitespaces to ensure accurate matching
+						// For example: Transfer(address, address, uint256) ==
+						// Transfer(address,address,uint256)
+						if are_same_signature(&condition.signature, &event_condition.signature) {
+							if condition.expression.is_none() {
+								matched_events.push(EventCondition {
+									signature: event_condition.signature.clone(),
+									expression: None,
+								});
+								if let Some(events) = &mut matched_on_args.events {
+									events.push(event_condition);
+								}
+								break;
+							} else {
+								// Evaluate the expression condition
+								if let Some(expr) = &condition.expression {
+									match self.evaluate_expression(
+										expr,
+										&[
 											event_condition.args.as_deref().unwrap_or_default(),
-										) {
-											Ok(true) => {
-												matched_events.push(EventCondition {
-													signature: event_condition.signature.clone(),
-													expression: Some(expr.to_string()),
-												});
-												if let Some(events) = &mut matched_on_args.events {
-													events.push(event_condition);
-												}
-												break;
-											}
-											Ok(false) => continue,
-											Err(e) => {
-												tracing::error!(
-													"Failed to evaluate expression '{}': {}",
-													expr,
-													e
-												);
-												continue;
+											&nested_params,
+											block_params,
+										]
+										.concat(),
+									) {
+										Ok(true) => {
+											matched_events.push(EventCondition {
+												signature: event_condition.signature.clone(),
+												expression: Some(expr.to_string()),
+											});
+											if let Some(events) = &mut matched_on_args.events {
+												events.push(event_condition);
 											}
+											break;
+										}
+										Ok(false) => continue,
+										Err(e) => {
+											tracing::error!(
+												"Failed to evaluate expression '{}': {}",
+												expr,
+												e
+											);
+											continue;
 										}
 									}
 								}
@@ -469,6 +599,357 @@ impl<T> EVMBlockFilter<T> {
 		}
 	}
 
+	/// Sums an event parameter across a transaction's logs and matches the totals against
+	/// the monitor's aggregate conditions.
+	///
+	/// Only logs emitted by monitored addresses and matching the condition's signature (and
+	/// filter, if any) are included. A condition is only evaluated when at least one event
+	/// contributed, and a total exceeding `U256::MAX` saturates rather than wrapping.
+	///
+	/// # Arguments
+	/// * `logs` - Transaction receipt containing event logs
+	/// * `monitor` - Monitor containing aggregate match conditions
+	/// * `matched_aggregates` - Vector to store matching aggregate conditions
+	/// * `block_params` - Parameters of the block containing the transaction
+	pub fn find_matching_aggregates_for_transaction(
+		&self,
+		logs: &[EVMReceiptLog],
+		monitor: &Monitor,
+		matched_aggregates: &mut Vec<AggregateCondition>,
+		block_params: &[EVMMatchParamEntry],
+	) {
+		if monitor.match_conditions.aggregates.is_empty() {
+			return;
+		}
+
+		// Decode the logs emitted by monitored addresses once for all conditions
+		let decoded_logs: Vec<EVMMatchParamsMap> =
+			logs.iter()
+				.filter_map(|log| {
+					let monitored_addr = monitor.addresses.iter().find(|addr| {
+						are_same_address(&addr.address, &h160_to_string(log.address))
+					})?;
+					self.decode_events(&monitored_addr.merged_contract_spec()?, log)
+				})
+				.collect();
+
+		for condition in &monitor.match_conditions.aggregates {
+			let mut sum = U256::ZERO;
+			let mut count: u64 = 0;
+			let mut overflowed = false;
+
+			for event in decoded_logs
+				.iter()
+				.filter(|event| are_same_signature(&condition.signature, &event.signature))
+			{
+				let args = event.args.as_deref().unwrap_or_default();
+
+				if let Some(filter) = &condition.filter {
+					match self.evaluate_expression(filter, &[args, block_params].concat()) {
+						Ok(true) => {}
+						Ok(false) => continue,
+						Err(e) => {
+							tracing::error!(
+								"Failed to evaluate aggregate filter '{}': {}",
+								filter,
+								e
+							);
+							continue;
+						}
+					}
+				}
+
+				let Some(param) = args.iter().find(|arg| arg.name == condition.field) else {
+					tracing::warn!(
+						"Event {} has no parameter '{}' to aggregate",
+						event.signature,
+						condition.field
+					);
+					continue;
+				};
+				let value = match string_to_u256(&param.value) {
+					Ok(value) if param.kind.starts_with("uint") => value,
+					_ => {
+						tracing::warn!(
+							"Cannot aggregate parameter '{}' of kind '{}': expected an unsigned \
+							 integer",
+							param.name,
+							param.kind
+						);
+						continue;
+					}
+				};
+
+				sum = sum.checked_add(value).unwrap_or_else(|| {
+					overflowed = true;
+					U256::MAX
+				});
+				count += 1;
+			}
+
+			if count == 0 {
+				continue;
+			}
+			if overflowed {
+				tracing::warn!(
+					"Sum of '{}' for {} overflowed, saturating at U256::MAX",
+					condition.field,
+					condition.signature
+				);
+			}
+
+			let totals = [
+				EVMMatchParamEntry {
+					name: "sum".to_string(),
+					value: sum.to_string(),
+					kind: "uint256".to_string(),
+					indexed: false,
+				},
+				EVMMatchParamEntry {
+					name: "count".to_string(),
+					value: count.to_string(),
+					kind: "uint256".to_string(),
+					indexed: false,
+				},
+			];
+			match self.evaluate_expression(&condition.expression, &[&totals, block_params].concat())
+			{
+				Ok(true) => matched_aggregates.push(condition.clone()),
+				Ok(false) => {}
+				Err(e) => {
+					tracing::error!(
+						"Failed to evaluate aggregate expression '{}': {}",
+						condition.expression,
+						e
+					);
+				}
+			}
+		}
+	}
+
+	/// Compares event parameters across a block and matches the percentage change between
+	/// them against the monitor's percentage change conditions.
+	///
+	/// The reference value is taken from the first event matching a condition's `from`
+	/// field, and the changed value from the last event matching its `to` field that was
+	/// emitted after it. Conditions are skipped when either value is missing or when the
+	/// reference value is zero, since no percentage change can be computed.
+	///
+	/// # Arguments
+	/// * `logs` - Event logs of the whole block, in block order
+	/// * `monitor` - Monitor containing percentage change match conditions
+	/// * `matched_percentage_changes` - Vector to store matching percentage changes
+	/// * `block_params` - Parameters of the block
+	pub fn find_matching_percentage_changes_for_block(
+		&self,
+		logs: &[EVMReceiptLog],
+		monitor: &Monitor,
+		matched_percentage_changes: &mut Vec<PercentageChangeMatch>,
+		block_params: &[EVMMatchParamEntry],
+	) {
+		if monitor.match_conditions.percentage_changes.is_empty() {
+			return;
+		}
+
+		// Decode the logs emitted by monitored addresses once for all conditions, keeping
+		// the transaction each log belongs to
+		let decoded_logs: Vec<(String, EVMMatchParamsMap)> =
+			logs.iter()
+				.filter_map(|log| {
+					let monitored_addr = monitor.addresses.iter().find(|addr| {
+						are_same_address(&addr.address, &h160_to_string(log.address))
+					})?;
+					let event = self.decode_events(&monitored_addr.merged_contract_spec()?, log)?;
+					Some((
+						b256_to_string(log.transaction_hash.unwrap_or_default()),
+						event,
+					))
+				})
+				.collect();
+
+		for condition in &monitor.match_conditions.percentage_changes {
+			let Some((first_index, first)) =
+				decoded_logs
+					.iter()
+					.enumerate()
+					.find_map(|(index, (_, event))| {
+						self.event_field_value(&condition.from, event, block_params)
+							.map(|value| (index, value))
+					})
+			else {
+				continue;
+			};
+			let Some((second_index, second)) = decoded_logs
+				.iter()
+				.enumerate()
+				.skip(first_index + 1)
+				.rev()
+				.find_map(|(index, (_, event))| {
+					self.event_field_value(&condition.to, event, block_params)
+						.map(|value| (index, value))
+				})
+			else {
+				tracing::debug!(
+					"No value of '{}' for {} after the reference value, skipping percentage \
+					 change",
+					condition.to.field,
+					condition.to.signature
+				);
+				continue;
+			};
+
+			if first.1 == 0.0 {
+				tracing::warn!(
+					"Cannot compute the percentage change of '{}' for {} from a zero value",
+					condition.from.field,
+					condition.from.signature
+				);
+				continue;
+			}
+
+			let change = (second.1 - first.1) / first.1.abs() * 100.0;
+			let Some(change) = Decimal::from_f64(change) else {
+				tracing::warn!(
+					"Percentage change of '{}' for {} is out of range: {}",
+					condition.to.field,
+					condition.to.signature,
+					change
+				);
+				continue;
+			};
+			let change = change.round_dp(6).normalize();
+
+			let values = [
+				EVMMatchParamEntry {
+					name: "change".to_string(),
+					value: change.to_string(),
+					kind: "fixed".to_string(),
+					indexed: false,
+				},
+				EVMMatchParamEntry {
+					name: "abs_change".to_string(),
+					value: change.abs().to_string(),
+					kind: "fixed".to_string(),
+					indexed: false,
+				},
+				EVMMatchParamEntry {
+					name: "first".to_string(),
+					..first.0
+				},
+				EVMMatchParamEntry {
+					name: "second".to_string(),
+					..second.0
+				},
+			];
+			match self.evaluate_expression(&condition.expression, &[&values, block_params].concat())
+			{
+				Ok(true) => {
+					let (_, first_event) = &decoded_logs[first_index];
+					let (transaction_hash, second_event) = &decoded_logs[second_index];
+					matched_percentage_changes.push(PercentageChangeMatch {
+						condition: condition.clone(),
+						transaction_hash: transaction_hash.clone(),
+						events: vec![first_event.clone(), second_event.clone()],
+					});
+				}
+				Ok(false) => {}
+				Err(e) => {
+					tracing::error!(
+						"Failed to evaluate percentage change expression '{}': {}",
+						condition.expression,
+						e
+					);
+				}
+			}
+		}
+	}
+
+	/// Returns the numeric value of an event parameter referenced by a percentage change
+	/// condition
+	///
+	/// # Arguments
+	/// * `event_field` - Event and parameter to read
+	/// * `event` - Decoded event
+	/// * `block_params` - Parameters of the block, available to the event filter
+	///
+	/// # Returns
+	/// The parameter and its value, or `None` if the event does not match or the parameter
+	/// is missing or not numeric
+	fn event_field_value(
+		&self,
+		event_field: &EventField,
+		event: &EVMMatchParamsMap,
+		block_params: &[EVMMatchParamEntry],
+	) -> Option<(EVMMatchParamEntry, f64)> {
+		if !are_same_signature(&event_field.signature, &event.signature) {
+			return None;
+		}
+		let args = event.args.as_deref().unwrap_or_default();
+
+		if let Some(filter) = &event_field.filter {
+			match self.evaluate_expression(filter, &[args, block_params].concat()) {
+				Ok(true) => {}
+				Ok(false) => return None,
+				Err(e) => {
+					tracing::error!(
+						"Failed to evaluate percentage change filter '{}': {}",
+						filter,
+						e
+					);
+					return None;
+				}
+			}
+		}
+
+		let Some(param) = args.iter().find(|arg| arg.name == event_field.field) else {
+			tracing::warn!(
+				"Event {} has no parameter '{}' to compare",
+				event.signature,
+				event_field.field
+			);
+			return None;
+		};
+		let is_numeric = param.kind.starts_with("uint")
+			|| param.kind.starts_with("int")
+			|| param.kind == "fixed"
+			|| param.kind == "ufixed";
+		match param.value.parse::<f64>() {
+			Ok(value) if is_numeric => Some((param.clone(), value)),
+			_ => {
+				tracing::warn!(
+					"Cannot compare parameter '{}' of kind '{}': expected a number",
+					param.name,
+					param.kind
+				);
+				None
+			}
+		}
+	}
+
+	/// Builds the block-level parameters available to match expressions.
+	///
+	/// # Arguments
+	/// * `block` - The block being processed
+	///
+	/// # Returns
+	/// Vector containing the `block_number` and `block_timestamp` parameters
+	pub fn block_params(&self, block: &EVMBlock) -> Vec<EVMMatchParamEntry> {
+		vec![
+			EVMMatchParamEntry {
+				name: "block_number".to_string(),
+				value: block.number.unwrap_or(U64::from(0)).to_string(),
+				kind: "uint64".to_string(),
+				indexed: false,
+			},
+			EVMMatchParamEntry {
+				name: "block_timestamp".to_string(),
+				value: block.timestamp.to_string(),
+				kind: "uint64".to_string(),
+				indexed: false,
+			},
+		]
+	}
+
 	/// Evaluates a match expression against provided parameters.
 	///
 	/// # Arguments
@@ -508,6 +989,10 @@ impl<T> EVMBlockFilter<T> {
 
 	/// Decodes event logs using the provided ABI.
 	///
+	/// Events are found by their topic0 selector. Logs matching no selector are decoded with
+	/// the ABI's anonymous events instead, as long as exactly one of them has as many indexed
+	/// inputs as the log has topics and decodes the log; ambiguous logs are not decoded.
+	///
 	/// # Arguments
 	/// * `abi` - Contract ABI for decoding
 	/// * `log` - Event log to decode
@@ -519,6 +1004,74 @@ impl<T> EVMBlockFilter<T> {
 		abi: &ContractSpec,
 		log: &EVMReceiptLog,
 	) -> Option<EVMMatchParamsMap> {
+		self.decode_event_params(abi, log)
+			.map(|(decoded_event, _)| decoded_event)
+	}
+
+	/// Labels an event log with the signature its topic0 is mapped to in the address's
+	/// `event_signatures`, for logs that cannot be decoded with a contract spec
+	///
+	/// Indexed values are exposed as the `bytes32` arguments `topic1` to `topic3` and the
+	/// non-indexed values as the `bytes` argument `data`, all as raw hex.
+	///
+	/// # Arguments
+	/// * `address` - Monitored address that emitted the log
+	/// * `log` - Event log to label
+	///
+	/// # Returns
+	/// The labeled event, or `None` if the address has no signature for the log's topic0
+	fn label_raw_event(
+		&self,
+		address: &AddressWithSpec,
+		log: &EVMReceiptLog,
+	) -> Option<EVMMatchParamsMap> {
+		let topic0 = log.topics.first()?;
+		let signature = address
+			.event_signatures
+			.iter()
+			.find(|(topic, _)| parse_event_topic(topic).is_some_and(|topic| topic == *topic0))
+			.map(|(_, signature)| signature)?;
+
+		let mut args: Vec<EVMMatchParamEntry> = log
+			.topics
+			.iter()
+			.enumerate()
+			.skip(1)
+			.map(|(index, topic)| EVMMatchParamEntry {
+				name: format!("topic{}", index),
+				value: b256_to_string(*topic),
+				indexed: true,
+				kind: "bytes32".to_string(),
+			})
+			.collect();
+		args.push(EVMMatchParamEntry {
+			name: "data".to_string(),
+			value: alloy::primitives::hex::encode_prefixed(&log.data),
+			indexed: false,
+			kind: "bytes".to_string(),
+		});
+
+		Some(EVMMatchParamsMap {
+			signature: signature.clone(),
+			args: Some(args),
+			hex_signature: Some(b256_to_string(*topic0)),
+		})
+	}
+
+	/// Decodes an event log like `decode_events`, along with the nested tuple and array
+	/// values of its parameters flattened into addressable parameters
+	///
+	/// # Arguments
+	/// * `abi` - Contract ABI for decoding
+	/// * `log` - Event log to decode
+	///
+	/// # Returns
+	/// Option containing the decoded event data and its nested parameters if successful
+	fn decode_event_params(
+		&self,
+		abi: &ContractSpec,
+		log: &EVMReceiptLog,
+	) -> Option<(EVMMatchParamsMap, Vec<EVMMatchParamEntry>)> {
 		// Create contract object from ABI
 		let contract = match abi {
 			ContractSpec::EVM(evm_spec) => {
@@ -537,77 +1090,119 @@ impl<T> EVMBlockFilter<T> {
 			_ => return None,
 		};
 
-		// Find the matching Event
-		let event = match contract
-			.items()
-			.filter_map(|item| match item {
-				AbiItem::Event(e) => Some(e),
-				_ => None,
-			})
-			.find(|e| e.selector() == log.topics[0])
-		{
-			Some(event) => event,
-			None => {
-				FilterError::internal_error(
-					format!("No matching event found for log topic: {:?}", log.topics[0]),
-					None,
-					None,
-				);
-				return None;
-			}
-		};
-
 		// Decode event in one call (covering non-indexed and indexed params)
 		let log_data = match LogData::new(log.topics.clone(), log.data.clone()) {
 			Some(data) => data,
 			None => {
 				FilterError::internal_error(
-					format!("Failed to create log data: {:?}", log.topics[0]),
+					format!("Failed to create log data: {:?}", log.topics.first()),
 					None,
 					None,
 				);
 				return None;
 			}
 		};
-		let decoded = match event.decode_log(&log_data) {
-			Ok(decoded) => decoded,
-			Err(e) => {
-				FilterError::internal_error(
-					format!("Failed to decode log data: {:?}", e.to_string()),
-					Some(e.into()),
-					None,
-				);
-				return None;
+
+		let events: Vec<_> = contract.events().collect();
+
+		// Find the matching Event by its topic0 selector
+		let named_event = log.topics.first().and_then(|topic0| {
+			events
+				.iter()
+				.find(|e| !e.anonymous && e.selector() == *topic0)
+		});
+
+		let (event, decoded) = match named_event {
+			Some(event) => match event.decode_log(&log_data) {
+				Ok(decoded) => (*event, decoded),
+				Err(e) => {
+					FilterError::internal_error(
+						format!("Failed to decode log data: {:?}", e.to_string()),
+						Some(e.into()),
+						None,
+					);
+					return None;
+				}
+			},
+			None => {
+				// Anonymous events have no selector topic, so they are matched positionally:
+				// a candidate must have as many indexed inputs as the log has topics, and its
+				// layout must decode the log
+				let mut candidates = events
+					.iter()
+					.filter(|e| {
+						e.anonymous
+							&& e.inputs.iter().filter(|p| p.indexed).count() == log.topics.len()
+					})
+					.filter_map(|e| e.decode_log(&log_data).ok().map(|decoded| (*e, decoded)));
+
+				match (candidates.next(), candidates.next()) {
+					(Some(candidate), None) => candidate,
+					(Some(_), Some(_)) => {
+						FilterError::internal_error(
+							format!(
+								"Ambiguous anonymous events for log with {} topics",
+								log.topics.len()
+							),
+							None,
+							None,
+						);
+						return None;
+					}
+					(None, _) => {
+						FilterError::internal_error(
+							format!(
+								"No matching event found for log topic: {:?}",
+								log.topics.first()
+							),
+							None,
+							None,
+						);
+						return None;
+					}
+				}
 			}
 		};
 
 		// Build two iterators (we always have both indexed and non-indexed params in the exact sequence declared in the ABI)
-		let mut indexed_vals = decoded.indexed.into_iter().map(|v| format_token_value(&v));
-		let mut body_vals = decoded.body.into_iter().map(|v| format_token_value(&v));
+		let mut indexed_vals = decoded.indexed.into_iter();
+		let mut body_vals = decoded.body.into_iter();
 
 		// Map over the event inputs
+		let mut nested_params = Vec::new();
 		let decoded_params: Vec<_> = event
 			.inputs
 			.iter()
 			.map(|param| {
 				let (value, indexed) = if param.indexed {
 					// pull from our indexed iterator
-					(indexed_vals.next().unwrap_or_default(), true)
+					(indexed_vals.next(), true)
 				} else {
 					// pull from our body iterator
-					(body_vals.next().unwrap_or_default(), false)
+					(body_vals.next(), false)
 				};
 
+				// Indexed tuples and arrays are hashed into their topic, so only non-indexed
+				// values have addressable components
+				if let Some(value) = &value {
+					nested_params.extend(flatten_param_value(
+						&param.name,
+						&param.ty,
+						&param.components,
+						value,
+					));
+				}
+
 				EVMMatchParamEntry {
 					name: param.name.clone(),
-					value,
+					value: value.as_ref().map(format_token_value).unwrap_or_default(),
 					kind: param.ty.to_string(),
 					indexed,
 				}
 			})
 			.collect();
 
-		Some(EVMMatchParamsMap {
+		let decoded_event = EVMMatchParamsMap {
 			signature: format!(
 				"{}({})",
 				event.name,
@@ -619,34 +1214,170 @@ impl<T> EVMBlockFilter<T> {
 					.join(",")
 			),
 			args: Some(decoded_params),
-			hex_signature: Some(format!("0x{}", hex::encode(event.selector()))),
-		})
+			// Anonymous events do not emit their selector
+			hex_signature: (!event.anonymous)
+				.then(|| format!("0x{}", hex::encode(event.selector()))),
+		};
+
+		Some((decoded_event, nested_params))
 	}
 
-	/// Checks if a monitor has any transaction conditions that require a receipt
+	/// Returns the topic0 hashes of a monitor's event, aggregate and percentage change
+	/// conditions
+	///
+	/// Raw topic conditions are used as-is. Signature conditions are resolved against the
+	/// `event_signatures` and ABIs of the monitored addresses; signatures not found in any of
+	/// them cannot match a log and contribute no topic.
 	///
 	/// # Arguments
-	/// * `monitor` - Monitor to check
-	/// * `logs` - Logs to check
+	/// * `monitor` - Monitor whose conditions are resolved
 	///
 	/// # Returns
-	/// `true` if the monitor has any transaction conditions that require a receipt, `false` otherwise
-	fn needs_receipt(&self, monitor: &Monitor, logs: &[EVMReceiptLog]) -> bool {
-		monitor
-			.match_conditions
-			.transactions
+	/// The `0x`-prefixed topic0 hashes of the monitor's conditions, or `None` when a condition
+	/// resolves to an anonymous event, whose logs have no topic0 to filter on
+	fn condition_topics(&self, monitor: &Monitor) -> Option<Vec<String>> {
+		let abis: Vec<JsonAbi> = monitor
+			.addresses
 			.iter()
-			.any(|condition| {
-				// If the status is not Any, and there are no logs, we need a receipt to validate the transaction most likely failed
-				let status_needs_receipt =
-					condition.status != TransactionStatus::Any && logs.is_empty();
-				// If the expression contains gas_used, we need a receipt to get the gas used
-				let gas_used_in_expr = condition
-					.clone()
-					.expression
-					.is_some_and(|expr| expr.contains("gas_used"));
-				status_needs_receipt || gas_used_in_expr
-			})
+			.filter_map(|addr| match addr.merged_contract_spec() {
+				Some(ContractSpec::EVM(spec)) => {
+					serde_json::from_slice::<JsonAbi>(spec.to_string().as_bytes()).ok()
+				}
+				_ => None,
+			})
+			.collect();
+
+		let conditions = &monitor.match_conditions;
+		let signatures = conditions
+			.events
+			.iter()
+			.map(|condition| &condition.signature)
+			.chain(
+				conditions
+					.aggregates
+					.iter()
+					.map(|condition| &condition.signature),
+			)
+			.chain(
+				conditions
+					.percentage_changes
+					.iter()
+					.flat_map(|condition| [&condition.from.signature, &condition.to.signature]),
+			);
+
+		let mut topics = Vec::new();
+		for signature in signatures {
+			if let Some(topic) = parse_event_topic(signature) {
+				topics.push(b256_to_string(topic));
+				continue;
+			}
+			for (topic, label) in monitor
+				.addresses
+				.iter()
+				.flat_map(|addr| &addr.event_signatures)
+			{
+				if are_same_signature(signature, label) {
+					topics.extend(parse_event_topic(topic).map(b256_to_string));
+				}
+			}
+			for event in abis.iter().flat_map(|abi| abi.events()) {
+				let event_signature = format!(
+					"{}({})",
+					event.name,
+					event
+						.inputs
+						.iter()
+						.map(|p| p.selector_type())
+						.collect::<Vec<_>>()
+						.join(",")
+				);
+				if are_same_signature(signature, &event_signature) {
+					if event.anonymous {
+						return None;
+					}
+					topics.push(b256_to_string(event.selector()));
+				}
+			}
+		}
+		Some(topics)
+	}
+
+	/// Builds the address and topic0 filters used to fetch a block's logs
+	///
+	/// Addresses are the union of the addresses of all monitors, and topics the union of
+	/// their event, aggregate and percentage change condition topics. Topics are not
+	/// filtered when any monitor has none of these conditions, since such a monitor
+	/// considers every log of its addresses, or when a condition refers to an anonymous
+	/// event. An empty union leaves the corresponding filter
+	/// unset.
+	///
+	/// # Arguments
+	/// * `monitors` - Monitors the block is filtered for
+	/// * `normalizer` - Normalizer of the network's addresses
+	///
+	/// # Returns
+	/// The address and topic0 filters, `None` meaning unfiltered
+	fn log_filters(
+		&self,
+		monitors: &[Monitor],
+		normalizer: &dyn AddressNormalizer,
+	) -> (Option<Vec<String>>, Option<Vec<String>>) {
+		let mut addresses = BTreeSet::new();
+		let mut topics = Some(BTreeSet::new());
+
+		for monitor in monitors {
+			addresses.extend(
+				monitor
+					.addresses
+					.iter()
+					.map(|addr| format!("0x{}", normalizer.normalize(&addr.address))),
+			);
+
+			let conditions = &monitor.match_conditions;
+			if conditions.events.is_empty()
+				&& conditions.aggregates.is_empty()
+				&& conditions.percentage_changes.is_empty()
+			{
+				topics = None;
+			} else if let Some(union) = &mut topics {
+				match self.condition_topics(monitor) {
+					Some(condition_topics) => union.extend(condition_topics),
+					None => topics = None,
+				}
+			}
+		}
+
+		let addresses = (!addresses.is_empty()).then(|| addresses.into_iter().collect());
+		let topics = topics
+			.filter(|topics| !topics.is_empty())
+			.map(|topics| topics.into_iter().collect());
+		(addresses, topics)
+	}
+
+	/// Checks if a monitor has any transaction conditions that require a receipt
+	///
+	/// # Arguments
+	/// * `monitor` - Monitor to check
+	/// * `logs` - Logs to check
+	///
+	/// # Returns
+	/// `true` if the monitor has any transaction conditions that require a receipt, `false` otherwise
+	fn needs_receipt(&self, monitor: &Monitor, logs: &[EVMReceiptLog]) -> bool {
+		monitor
+			.match_conditions
+			.transactions
+			.iter()
+			.any(|condition| {
+				// If the status is not Any, and there are no logs, we need a receipt to validate the transaction most likely failed
+				let status_needs_receipt =
+					condition.status != TransactionStatus::Any && logs.is_empty();
+				// If the expression contains gas_used or revert_reason, we need a receipt to get
+				// the gas used or to know whether the transaction failed
+				let receipt_field_in_expr = condition.clone().expression.is_some_and(|expr| {
+					expr.contains("gas_used") || expr.contains("revert_reason")
+				});
+				status_needs_receipt || receipt_field_in_expr
+			})
 	}
 }
 
@@ -683,20 +1414,32 @@ impl<T: BlockChainClient + EvmClientTrait> BlockFilter for EVMBlockFilter<T> {
 			}
 		};
 
+		// Monitored addresses are validated against the network's address rules, such as
+		// `strict_address_checksum`, when the monitors are loaded
+		let addresses = network_address_normalizer(network);
+
 		tracing::debug!(
 			"Processing block {}",
 			evm_block.number.unwrap_or(U64::from(0))
 		);
 
 		let current_block_number = evm_block.number.unwrap_or(U64::from(0)).to::<u64>();
+		let block_params = self.block_params(evm_block);
 
 		// Get logs for the block
 		// We use this to get all the logs for a single block.
 		// We could further optimize by getting logs for a range of blocks and calling this in the parent function
 		// However, due to limitations by certain RPC providers (e.g. Quicknode only allows a block range of 5),
 		// it's safer to just fetch the logs for a single block at a time as it's more reliable.
+		// Only the logs of monitored addresses and topics are requested
+		let (log_addresses, log_topics) = self.log_filters(monitors, addresses);
 		let all_block_logs = client
-			.get_logs_for_blocks(current_block_number, current_block_number, None)
+			.get_logs_for_blocks(
+				current_block_number,
+				current_block_number,
+				log_addresses,
+				log_topics,
+			)
 			.await?;
 
 		tracing::debug!(
@@ -705,8 +1448,6 @@ impl<T: BlockChainClient + EvmClientTrait> BlockFilter for EVMBlockFilter<T> {
 			current_block_number
 		);
 
-		let mut matching_results = Vec::new();
-
 		// Cast contract specs to EVMContractSpec
 		let contract_specs = contract_specs
 			.unwrap_or(&[])
@@ -725,8 +1466,29 @@ impl<T: BlockChainClient + EvmClientTrait> BlockFilter for EVMBlockFilter<T> {
 			logs_by_tx.entry(tx_hash).or_default().push(log);
 		}
 
+		// Transactions emitting an excessive number of logs (e.g. batch airdrops) only have
+		// their first logs matched, bounding the work done per monitor
+		let max_logs_per_tx = network.get_max_logs_per_tx();
+		for (tx_hash, logs) in logs_by_tx.iter_mut() {
+			if logs.len() > max_logs_per_tx {
+				tracing::warn!(
+					network = %network.slug,
+					"Transaction {} emitted {} logs, only the first {} are matched",
+					tx_hash,
+					logs.len(),
+					max_logs_per_tx
+				);
+				TRANSACTION_LOGS_TRUNCATED_TOTAL
+					.with_label_values(&[network.slug.as_str()])
+					.inc();
+				logs.truncate(max_logs_per_tx);
+			}
+		}
+
 		tracing::debug!("Processing {} transactions with logs", logs_by_tx.len());
 
+		// Conditions on the logs of the whole block are checked once per monitor
+		let mut monitor_states = Vec::with_capacity(monitors.len());
 		for monitor in monitors {
 			tracing::debug!("Processing monitor: {:?}", monitor.name);
 			let monitored_addresses: Vec<String> = monitor
@@ -738,176 +1500,367 @@ impl<T: BlockChainClient + EvmClientTrait> BlockFilter for EVMBlockFilter<T> {
 			// Check if this monitor needs a receipt
 			let should_fetch_receipt = self.needs_receipt(monitor, &all_block_logs);
 
-			// Process all transactions in the block
-			for transaction in &evm_block.transactions {
-				let tx_hash = b256_to_string(transaction.hash);
-				let empty_logs = Vec::new();
-				let logs = logs_by_tx.get(&tx_hash).unwrap_or(&empty_logs);
-				let tx_hash_str = tx_hash.clone();
+			// Percentage changes compare events across transactions, so they are checked on the
+			// logs of the whole block
+			let mut matched_percentage_changes = Vec::<PercentageChangeMatch>::new();
+			self.find_matching_percentage_changes_for_block(
+				&all_block_logs,
+				monitor,
+				&mut matched_percentage_changes,
+				&block_params,
+			);
+
+			monitor_states.push((
+				monitored_addresses,
+				should_fetch_receipt,
+				matched_percentage_changes,
+			));
+		}
 
-				let receipt = if should_fetch_receipt {
-					Some(client.get_transaction_receipt(tx_hash_str).await?)
+		// Matches are collected per monitor, so that they are reported in the same order
+		// whether or not the transactions are processed in chunks
+		let mut transaction_matches: Vec<Vec<MonitorMatch>> = vec![Vec::new(); monitors.len()];
+		let mut percentage_change_matches: Vec<Vec<MonitorMatch>> =
+			vec![Vec::new(); monitors.len()];
+
+		// With a chunk size, the block only holds the transaction hashes. The transactions of a
+		// chunk, and the receipts the monitors need, are fetched in one batch request each and
+		// released once every monitor processed the chunk, bounding memory usage
+		let chunk_size = network
+			.tx_chunk_size
+			.unwrap_or(evm_block.transactions.len())
+			.max(1);
+		for chunk in evm_block.transactions.chunks(chunk_size) {
+			let (transactions, chunk_receipts) = if network.tx_chunk_size.is_some() {
+				let transaction_hashes = chunk
+					.iter()
+					.map(|transaction| b256_to_string(transaction.hash))
+					.collect::<Vec<_>>();
+				let receipt_hashes = transaction_hashes
+					.iter()
+					.filter(|tx_hash| {
+						monitors.iter().zip(&monitor_states).any(
+							|(monitor, (_, should_fetch_receipt, _))| {
+								*should_fetch_receipt && monitor.samples_transaction(tx_hash)
+							},
+						)
+					})
+					.cloned()
+					.collect::<Vec<_>>();
+				let receipts = if receipt_hashes.is_empty() {
+					HashMap::new()
 				} else {
-					None
+					receipt_hashes
+						.clone()
+						.into_iter()
+						.zip(client.get_transaction_receipts(receipt_hashes).await?)
+						.collect()
 				};
+				(
+					Cow::Owned(client.get_transactions(transaction_hashes).await?),
+					receipts,
+				)
+			} else {
+				(Cow::Borrowed(chunk), HashMap::new())
+			};
 
-				// Reset matched_on_args for each transaction
-				let mut matched_on_args = EVMMatchArguments {
-					events: Some(Vec::new()),
-					functions: Some(Vec::new()),
-				};
+			for (monitor_index, monitor) in monitors.iter().enumerate() {
+				let (monitored_addresses, should_fetch_receipt, matched_percentage_changes) =
+					&mut monitor_states[monitor_index];
+				let should_fetch_receipt = *should_fetch_receipt;
 
-				// Get transaction status from receipt
-				let tx_status = if let Some(receipt) = receipt.clone() {
-					if receipt.status.map(|s| s.to::<u64>() == 1).unwrap_or(false) {
-						TransactionStatus::Success
-					} else {
-						TransactionStatus::Failure
+				// Process all transactions in the chunk
+				let matching_results = &mut transaction_matches[monitor_index];
+				for transaction in transactions.iter() {
+					let tx_hash = b256_to_string(transaction.hash);
+
+					// Skip transactions outside of the monitor's sample before fetching receipts
+					if !monitor.samples_transaction(&tx_hash) {
+						continue;
 					}
-				} else {
-					// Transaction receipt is only fetched when:
-					// 1. The monitor has conditions requiring receipt data (e.g., gas_used)
-					// 2. We need to verify transaction status and have no logs
-					// Otherwise, we can assume success since failed transactions don't emit logs
-					TransactionStatus::Success
-				};
 
-				// Collect all involved addresses from receipt logs, transaction.to, and transaction.from
-				let mut involved_addresses = Vec::new();
-				// Add transaction addresses
-				if let Some(from) = transaction.from {
-					involved_addresses.push(h160_to_string(from));
-				}
-				if let Some(to) = transaction.to {
-					involved_addresses.push(h160_to_string(to));
-				}
+					let empty_logs = Vec::new();
+					let logs = logs_by_tx.get(&tx_hash).unwrap_or(&empty_logs);
+					let tx_hash_str = tx_hash.clone();
 
-				let mut matched_events = Vec::<EventCondition>::new();
-				let mut matched_transactions = Vec::<TransactionCondition>::new();
-				let mut matched_functions = Vec::<FunctionCondition>::new();
-
-				// Check transaction match conditions
-				self.find_matching_transaction(
-					&tx_status,
-					transaction,
-					&receipt.clone(),
-					monitor,
-					&mut matched_transactions,
-				);
+					let receipt = if !should_fetch_receipt {
+						None
+					} else if let Some(receipt) = chunk_receipts.get(&tx_hash) {
+						Some(receipt.clone())
+					} else {
+						Some(client.get_transaction_receipt(tx_hash_str).await?)
+					};
 
-				// Check for event match conditions
-				self.find_matching_events_for_transaction(
-					logs,
-					monitor,
-					&mut matched_events,
-					&mut matched_on_args,
-					&mut involved_addresses,
-				);
+					// Reset matched_on_args for each transaction
+					let mut matched_on_args = EVMMatchArguments {
+						events: Some(Vec::new()),
+						functions: Some(Vec::new()),
+					};
 
-				// Check function match conditions
-				self.find_matching_functions_for_transaction(
-					&contract_specs,
-					transaction,
-					monitor,
-					&mut matched_functions,
-					&mut matched_on_args,
-				);
+					// Get transaction status from receipt
+					let tx_status = if let Some(receipt) = receipt.clone() {
+						if receipt.status.map(|s| s.to::<u64>() == 1).unwrap_or(false) {
+							TransactionStatus::Success
+						} else {
+							TransactionStatus::Failure
+						}
+					} else {
+						// Transaction receipt is only fetched when:
+						// 1. The monitor has conditions requiring receipt data (e.g., gas_used)
+						// 2. We need to verify transaction status and have no logs
+						// Otherwise, we can assume success since failed transactions don't emit logs
+						TransactionStatus::Success
+					};
+
+					// Decoding the revert reason costs an extra call, so it is opt-in per network
+					let revert_reason = if tx_status == TransactionStatus::Failure
+						&& network.decode_revert_reasons.unwrap_or(false)
+					{
+						client
+							.get_revert_reason(transaction)
+							.await
+							.unwrap_or_else(|e| {
+								tracing::warn!(
+									"Failed to get revert reason for {}: {}",
+									tx_hash,
+									e
+								);
+								None
+							})
+					} else {
+						None
+					};
 
-				// Remove duplicates
-				involved_addresses.sort_unstable();
-				involved_addresses.dedup();
+					// Collect all involved addresses from receipt logs, transaction.to, and transaction.from
+					let mut involved_addresses = Vec::new();
+					// Add transaction addresses
+					if let Some(from) = transaction.from {
+						involved_addresses.push(h160_to_string(from));
+					}
+					if let Some(to) = transaction.to {
+						involved_addresses.push(h160_to_string(to));
+					}
 
-				let has_address_match = monitored_addresses.iter().any(|addr| {
-					involved_addresses
-						.iter()
-						.map(|a| normalize_address(a))
-						.collect::<Vec<String>>()
-						.contains(&normalize_address(addr))
-				});
+					let mut matched_events = Vec::<EventCondition>::new();
+					let mut matched_transactions = Vec::<TransactionCondition>::new();
+					let mut matched_functions = Vec::<FunctionCondition>::new();
+					let mut matched_aggregates = Vec::<AggregateCondition>::new();
+
+					// Check transaction match conditions
+					self.find_matching_transaction(
+						&tx_status,
+						transaction,
+						&receipt.clone(),
+						revert_reason.as_deref(),
+						monitor,
+						&mut matched_transactions,
+						&block_params,
+					);
+
+					// Check for event match conditions
+					self.find_matching_events_for_transaction(
+						logs,
+						monitor,
+						&mut matched_events,
+						&mut matched_on_args,
+						&mut involved_addresses,
+						&block_params,
+					);
+
+					// Check aggregate match conditions
+					self.find_matching_aggregates_for_transaction(
+						logs,
+						monitor,
+						&mut matched_aggregates,
+						&block_params,
+					);
+
+					// Check function match conditions
+					self.find_matching_functions_for_transaction(
+						&contract_specs,
+						transaction,
+						monitor,
+						&mut matched_functions,
+						&mut matched_on_args,
+						&block_params,
+					);
+
+					// Remove duplicates
+					involved_addresses.sort_unstable();
+					involved_addresses.dedup();
+
+					let has_address_match = monitored_addresses.iter().any(|addr| {
+						involved_addresses
+							.iter()
+							.any(|involved| addresses.are_same_address(involved, addr))
+					});
+
+					// Only proceed if we have a matching address
+					if has_address_match {
+						let monitor_conditions = &monitor.match_conditions;
+						let has_event_match =
+							!monitor_conditions.events.is_empty() && !matched_events.is_empty();
+						let has_function_match = !monitor_conditions.functions.is_empty()
+							&& !matched_functions.is_empty();
+						let has_transaction_match = !monitor_conditions.transactions.is_empty()
+							&& !matched_transactions.is_empty();
+						let has_aggregate_match = !monitor_conditions.aggregates.is_empty()
+							&& !matched_aggregates.is_empty();
+
+						// Aggregates are event conditions over the whole transaction, so they are
+						// grouped with events. Percentage changes are matched per block below, but
+						// must not make a monitor match every transaction either.
+						let should_match: bool = match (
+							monitor_conditions.events.is_empty()
+								&& monitor_conditions.aggregates.is_empty()
+								&& monitor_conditions.percentage_changes.is_empty(),
+							monitor_conditions.functions.is_empty(),
+							monitor_conditions.transactions.is_empty(),
+						) {
+							// Case 1: No conditions defined, match everything
+							(true, true, true) => true,
+
+							// Case 2: Only transaction conditions defined
+							(true, true, false) => has_transaction_match,
+
+							// Case 3: No transaction conditions, match based on events/functions
+							(_, _, true) => {
+								has_event_match || has_aggregate_match || has_function_match
+							}
 
-				// Only proceed if we have a matching address
-				if has_address_match {
-					let monitor_conditions = &monitor.match_conditions;
-					let has_event_match =
-						!monitor_conditions.events.is_empty() && !matched_events.is_empty();
-					let has_function_match =
-						!monitor_conditions.functions.is_empty() && !matched_functions.is_empty();
-					let has_transaction_match = !monitor_conditions.transactions.is_empty()
-						&& !matched_transactions.is_empty();
-
-					let should_match: bool = match (
-						monitor_conditions.events.is_empty(),
-						monitor_conditions.functions.is_empty(),
-						monitor_conditions.transactions.is_empty(),
-					) {
-						// Case 1: No conditions defined, match everything
-						(true, true, true) => true,
-
-						// Case 2: Only transaction conditions defined
-						(true, true, false) => has_transaction_match,
-
-						// Case 3: No transaction conditions, match based on events/functions
-						(_, _, true) => has_event_match || has_function_match,
-
-						// Case 4: Transaction conditions exist, they must be satisfied along
-						// with events/functions
-						_ => (has_event_match || has_function_match) && has_transaction_match,
-					};
+							// Case 4: Transaction conditions exist, they must be satisfied along
+							// with events/functions
+							_ => {
+								(has_event_match || has_aggregate_match || has_function_match)
+									&& has_transaction_match
+							}
+						};
 
-					if should_match {
-						matching_results.push(MonitorMatch::EVM(Box::new(EVMMonitorMatch {
-							monitor: Monitor {
-								// Omit ABI from monitor since we do not need it here
-								addresses: monitor
-									.addresses
-									.iter()
-									.map(|addr| AddressWithSpec {
-										contract_spec: None,
-										..addr.clone()
-									})
-									.collect(),
-								..monitor.clone()
-							},
-							transaction: transaction.clone(),
-							receipt,
-							logs: Some(logs.clone()),
-							network_slug: network.slug.clone(),
-							matched_on: MatchConditions {
-								events: matched_events
-									.clone()
-									.into_iter()
-									.filter(|_| has_event_match)
-									.collect(),
-								functions: matched_functions
-									.clone()
-									.into_iter()
-									.filter(|_| has_function_match)
-									.collect(),
-								transactions: matched_transactions
-									.clone()
-									.into_iter()
-									.filter(|_| has_transaction_match)
-									.collect(),
-							},
-							matched_on_args: Some(EVMMatchArguments {
-								events: if has_event_match {
-									matched_on_args.events.clone()
-								} else {
-									None
+						if should_match {
+							matching_results.push(MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+								monitor: Monitor {
+									// Omit ABI from monitor since we do not need it here
+									addresses: monitor
+										.addresses
+										.iter()
+										.map(|addr| AddressWithSpec {
+											contract_spec: None,
+											additional_specs: vec![],
+											event_signatures: HashMap::new(),
+											..addr.clone()
+										})
+										.collect(),
+									..monitor.clone()
 								},
-								functions: if has_function_match {
-									matched_on_args.functions.clone()
-								} else {
-									None
+								transaction: transaction.clone(),
+								receipt,
+								revert_reason: revert_reason.clone(),
+								logs: Some(logs.clone()),
+								block_timestamp: u64::try_from(evm_block.timestamp).ok(),
+								network_slug: network.slug.clone(),
+								matched_on: MatchConditions {
+									events: matched_events
+										.clone()
+										.into_iter()
+										.filter(|_| has_event_match)
+										.collect(),
+									functions: matched_functions
+										.clone()
+										.into_iter()
+										.filter(|_| has_function_match)
+										.collect(),
+									transactions: matched_transactions
+										.clone()
+										.into_iter()
+										.filter(|_| has_transaction_match)
+										.collect(),
+									operations: vec![],
+									aggregates: matched_aggregates
+										.clone()
+										.into_iter()
+										.filter(|_| has_aggregate_match)
+										.collect(),
+									percentage_changes: vec![],
 								},
-							}),
-						})));
+								matched_on_args: Some(EVMMatchArguments {
+									events: if has_event_match {
+										matched_on_args.events.clone()
+									} else {
+										None
+									},
+									functions: if has_function_match {
+										matched_on_args.functions.clone()
+									} else {
+										None
+									},
+								}),
+								enrichment: HashMap::new(),
+							})));
+						}
 					}
 				}
+
+				// Report the percentage changes on the transactions that emitted the changed
+				// values
+				let matching_results = &mut percentage_change_matches[monitor_index];
+				for transaction in transactions.iter() {
+					let tx_hash = b256_to_string(transaction.hash);
+					let (tx_matches, remaining): (Vec<_>, Vec<_>) =
+						std::mem::take(matched_percentage_changes)
+							.into_iter()
+							.partition(|m| m.transaction_hash == tx_hash);
+					*matched_percentage_changes = remaining;
+					if tx_matches.is_empty() {
+						continue;
+					}
+
+					matching_results.push(MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+						monitor: Monitor {
+							// Omit ABI from monitor since we do not need it here
+							addresses: monitor
+								.addresses
+								.iter()
+								.map(|addr| AddressWithSpec {
+									contract_spec: None,
+									additional_specs: vec![],
+									event_signatures: HashMap::new(),
+									..addr.clone()
+								})
+								.collect(),
+							..monitor.clone()
+						},
+						transaction: transaction.clone(),
+						receipt: None,
+						revert_reason: None,
+						logs: logs_by_tx.get(&tx_hash).cloned(),
+						block_timestamp: u64::try_from(evm_block.timestamp).ok(),
+						network_slug: network.slug.clone(),
+						matched_on: MatchConditions {
+							percentage_changes: tx_matches
+								.iter()
+								.map(|m| m.condition.clone())
+								.collect(),
+							..MatchConditions::default()
+						},
+						matched_on_args: Some(EVMMatchArguments {
+							events: Some(tx_matches.into_iter().flat_map(|m| m.events).collect()),
+							functions: None,
+						}),
+						enrichment: HashMap::new(),
+					})));
+				}
 			}
 		}
 
-		Ok(matching_results)
+		Ok(transaction_matches
+			.into_iter()
+			.zip(percentage_change_matches)
+			.flat_map(|(transaction_matches, percentage_change_matches)| {
+				transaction_matches
+					.into_iter()
+					.chain(percentage_change_matches)
+			})
+			.collect())
 	}
 }
 
@@ -915,6 +1868,7 @@ impl<T: BlockChainClient + EvmClientTrait> BlockFilter for EVMBlockFilter<T> {
 mod tests {
 	use crate::{
 		models::{ContractSpec, EVMContractSpec},
+		services::filter::EvmAddressNormalizer,
 		utils::tests::evm::{
 			monitor::MonitorBuilder, receipt::ReceiptBuilder, transaction::TransactionBuilder,
 		},
@@ -927,6 +1881,7 @@ mod tests {
 	use alloy::primitives::{Address, Bytes, B256, U256};
 	use serde_json::json;
 	use std::str::FromStr;
+	use tracing_test::traced_test;
 
 	fn create_test_filter() -> EVMBlockFilter<()> {
 		EVMBlockFilter::<()> {
@@ -934,6 +1889,13 @@ mod tests {
 		}
 	}
 
+	fn create_test_block(number: u64, timestamp: u64) -> EVMBlock {
+		let mut block = EVMBlock::default();
+		block.0.number = Some(U64::from(number));
+		block.0.timestamp = U256::from(timestamp);
+		block
+	}
+
 	/// Creates a test monitor with customizable parameters
 	fn create_test_monitor(
 		event_conditions: Vec<EventCondition>,
@@ -941,21 +1903,20 @@ mod tests {
 		transaction_conditions: Vec<TransactionCondition>,
 		addresses: Vec<AddressWithSpec>,
 	) -> Monitor {
-		MonitorBuilder::new()
+		let mut monitor = MonitorBuilder::new()
 			.name("test")
 			.networks(vec!["evm_mainnet".to_string()])
 			.match_conditions(MatchConditions {
 				events: event_conditions,
 				functions: function_conditions,
 				transactions: transaction_conditions,
+				operations: vec![],
+				aggregates: vec![],
+				percentage_changes: vec![],
 			})
-			.addresses_with_spec(
-				addresses
-					.into_iter()
-					.map(|a| (a.address, a.contract_spec))
-					.collect(),
-			)
-			.build()
+			.build();
+		monitor.addresses = addresses;
+		monitor
 	}
 
 	fn create_test_abi(abi_type: &str) -> ContractSpec {
@@ -1016,6 +1977,9 @@ mod tests {
 		AddressWithSpec {
 			address: address.to_string(),
 			contract_spec: spec,
+			contract_spec_path: None,
+			additional_specs: vec![],
+			event_signatures: HashMap::new(),
 		}
 	}
 
@@ -1059,8 +2023,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&TransactionBuilder::new().build(),
 			&Some(receipt),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 
 		assert_eq!(matched.len(), 1);
@@ -1090,8 +2056,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&TransactionBuilder::new().build(),
 			&Some(receipt_success),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 
 		assert_eq!(matched.len(), 1);
@@ -1105,8 +2073,10 @@ mod tests {
 			&TransactionStatus::Failure,
 			&TransactionBuilder::new().build(),
 			&Some(receipt_failure),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 
 		assert_eq!(matched.len(), 0);
@@ -1137,8 +2107,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_1,
 			&Some(tx_receipt_1),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 
 		assert_eq!(matched.len(), 1);
@@ -1156,8 +2128,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_2,
 			&Some(tx_receipt_2),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 
 		assert_eq!(matched.len(), 0);
@@ -1190,8 +2164,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_matching,
 			&Some(tx_receipt_matching),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 
 		assert_eq!(matched.len(), 1);
@@ -1210,8 +2186,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_non_matching,
 			&Some(tx_receipt_non_matching),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 
 		assert_eq!(matched.len(), 0);
@@ -1244,8 +2222,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_matching,
 			&Some(tx_receipt_matching),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 
 		assert_eq!(matched.len(), 1);
@@ -1264,8 +2244,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_non_matching,
 			&Some(tx_receipt_non_matching),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 
 		assert_eq!(matched.len(), 0);
@@ -1295,8 +2277,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_matching,
 			&Some(tx_receipt_matching),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 
 		assert_eq!(matched.len(), 1);
@@ -1316,8 +2300,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_non_matching,
 			&Some(tx_receipt_non_matching),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 
 		assert_eq!(matched.len(), 0);
@@ -1347,8 +2333,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_matching,
 			&Some(tx_receipt_matching),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 		assert_eq!(matched.len(), 1);
 		assert_eq!(matched[0].expression, Some(expression));
@@ -1367,8 +2355,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_non_matching,
 			&Some(tx_receipt_non_matching),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 		assert_eq!(matched.len(), 0);
 	}
@@ -1397,8 +2387,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_matching,
 			&Some(tx_receipt_matching),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 		assert_eq!(matched.len(), 1);
 		assert_eq!(matched[0].expression, Some(expression));
@@ -1417,8 +2409,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_non_matching,
 			&Some(tx_receipt_non_matching),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 		assert_eq!(matched.len(), 0);
 	}
@@ -1447,8 +2441,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_matching,
 			&Some(tx_receipt_matching),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 		assert_eq!(matched.len(), 1);
 		assert_eq!(matched[0].expression, Some(expression));
@@ -1467,8 +2463,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_non_matching,
 			&Some(tx_receipt_non_matching),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 		assert_eq!(matched.len(), 0);
 	}
@@ -1495,8 +2493,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_matching,
 			&Some(tx_receipt_matching),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 		assert_eq!(matched.len(), 1);
 		assert_eq!(matched[0].expression, Some(expression));
@@ -1513,8 +2513,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_non_matching,
 			&Some(tx_receipt_non_matching),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 		assert_eq!(matched.len(), 0);
 	}
@@ -1541,8 +2543,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_matching,
 			&Some(tx_receipt_matching),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 		assert_eq!(matched.len(), 1);
 		assert_eq!(matched[0].expression, Some(expression));
@@ -1559,8 +2563,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_non_matching,
 			&Some(tx_receipt_non_matching),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 		assert_eq!(matched.len(), 0);
 	}
@@ -1588,8 +2594,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_matching,
 			&Some(tx_receipt_matching),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 		assert_eq!(matched.len(), 1);
 		assert_eq!(matched[0].expression, Some(expression));
@@ -1607,8 +2615,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_non_matching,
 			&Some(tx_receipt_non_matching),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 		assert_eq!(matched.len(), 0);
 	}
@@ -1634,8 +2644,10 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_matching,
 			&Some(tx_receipt_matching),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
 		);
 		assert_eq!(matched.len(), 1);
 		assert_eq!(matched[0].expression, Some(expression));
@@ -1651,8 +2663,182 @@ mod tests {
 			&TransactionStatus::Success,
 			&tx_non_matching,
 			&Some(tx_receipt_non_matching),
+			None,
 			&monitor,
 			&mut matched,
+			&[],
+		);
+		assert_eq!(matched.len(), 0);
+	}
+
+	#[test]
+	fn test_access_list_matching() {
+		let target = Address::from_str("0x0000000000000000000000000000000000004321").unwrap();
+		let expression = format!("access_list contains {}", target);
+		let condition = TransactionCondition {
+			status: TransactionStatus::Any,
+			expression: Some(expression.clone()),
+		};
+		let filter = create_test_filter();
+		let mut matched = Vec::new();
+		let monitor = create_test_monitor(vec![], vec![], vec![condition], vec![]);
+
+		// Test transaction accessing the target address
+		let tx_matching = TransactionBuilder::new()
+			.access_list(vec![
+				Address::from_str("0x0000000000000000000000000000000000001234").unwrap(),
+				target,
+			])
+			.build();
+
+		filter.find_matching_transaction(
+			&TransactionStatus::Success,
+			&tx_matching,
+			&None,
+			None,
+			&monitor,
+			&mut matched,
+			&[],
+		);
+		assert_eq!(matched.len(), 1);
+		assert_eq!(matched[0].expression, Some(expression));
+
+		// Test transaction not accessing the target address
+		let tx_non_matching = TransactionBuilder::new()
+			.access_list(vec![Address::from_str(
+				"0x0000000000000000000000000000000000001234",
+			)
+			.unwrap()])
+			.build();
+
+		matched.clear();
+		filter.find_matching_transaction(
+			&TransactionStatus::Success,
+			&tx_non_matching,
+			&None,
+			None,
+			&monitor,
+			&mut matched,
+			&[],
+		);
+		assert_eq!(matched.len(), 0);
+
+		// Test transaction without access list
+		filter.find_matching_transaction(
+			&TransactionStatus::Success,
+			&TransactionBuilder::new().build(),
+			&None,
+			None,
+			&monitor,
+			&mut matched,
+			&[],
+		);
+		assert_eq!(matched.len(), 0);
+	}
+
+	#[test]
+	fn test_contract_creation_matching() {
+		let expression = "to == null".to_string();
+		let condition = TransactionCondition {
+			status: TransactionStatus::Any,
+			expression: Some(expression.clone()),
+		};
+		let filter = create_test_filter();
+		let mut matched = Vec::new();
+		let monitor = create_test_monitor(vec![], vec![], vec![condition], vec![]);
+
+		// Test contract creation transaction, which has no recipient
+		let tx_creation = TransactionBuilder::new()
+			.input(Bytes(hex::decode("60806040").unwrap().into()))
+			.build();
+
+		filter.find_matching_transaction(
+			&TransactionStatus::Success,
+			&tx_creation,
+			&None,
+			None,
+			&monitor,
+			&mut matched,
+			&[],
+		);
+		assert_eq!(matched.len(), 1);
+		assert_eq!(matched[0].expression, Some(expression));
+
+		// Test transaction sent to an address
+		let tx_call = TransactionBuilder::new()
+			.to(Address::from_str("0x0000000000000000000000000000000000004321").unwrap())
+			.build();
+
+		matched.clear();
+		filter.find_matching_transaction(
+			&TransactionStatus::Success,
+			&tx_call,
+			&None,
+			None,
+			&monitor,
+			&mut matched,
+			&[],
+		);
+		assert_eq!(matched.len(), 0);
+	}
+
+	#[test]
+	fn test_block_params_matching() {
+		let expression = "block_number > 100 AND block_timestamp >= 1700000000".to_string();
+		let condition = TransactionCondition {
+			status: TransactionStatus::Any,
+			expression: Some(expression.clone()),
+		};
+		let filter = create_test_filter();
+		let mut matched = Vec::new();
+		let monitor = create_test_monitor(vec![], vec![], vec![condition], vec![]);
+		let tx = TransactionBuilder::new().build();
+
+		// Test block above the threshold
+		let block = create_test_block(150, 1_700_000_000);
+		let block_params = filter.block_params(&block);
+		assert_eq!(block_params[0].name, "block_number");
+		assert_eq!(block_params[0].value, "150");
+		assert_eq!(block_params[1].name, "block_timestamp");
+		assert_eq!(block_params[1].value, "1700000000");
+
+		filter.find_matching_transaction(
+			&TransactionStatus::Success,
+			&tx,
+			&None,
+			None,
+			&monitor,
+			&mut matched,
+			&block_params,
+		);
+		assert_eq!(matched.len(), 1);
+		assert_eq!(matched[0].expression, Some(expression));
+
+		// Test block below the threshold
+		let block = create_test_block(100, 1_700_000_000);
+
+		matched.clear();
+		filter.find_matching_transaction(
+			&TransactionStatus::Success,
+			&tx,
+			&None,
+			None,
+			&monitor,
+			&mut matched,
+			&filter.block_params(&block),
+		);
+		assert_eq!(matched.len(), 0);
+
+		// Test without block parameters
+		matched.clear();
+		filter.find_matching_transaction(
+			&TransactionStatus::Success,
+			&tx,
+			&None,
+			None,
+			&monitor,
+			&mut matched,
+			&[],
 		);
 		assert_eq!(matched.len(), 0);
 	}
@@ -1734,6 +2920,7 @@ mod tests {
 			&monitor,
 			&mut matched_functions,
 			&mut matched_on_args,
+			&[],
 		);
 
 		assert_eq!(matched_functions.len(), 1);
@@ -1819,6 +3006,7 @@ mod tests {
 			&monitor,
 			&mut matched_functions,
 			&mut matched_on_args,
+			&[],
 		);
 
 		assert_eq!(matched_functions.len(), 1);
@@ -1852,13 +3040,14 @@ mod tests {
 			&monitor,
 			&mut matched_functions,
 			&mut matched_on_args,
+			&[],
 		);
 
 		assert_eq!(matched_functions.len(), 0);
 	}
 
 	#[test]
-	fn test_find_matching_functions_non_matching_address() {
+	fn test_find_matching_functions_with_nested_tuple_expression() {
 		let filter = create_test_filter();
 		let mut matched_functions = Vec::new();
 		let mut matched_on_args = EVMMatchArguments {
@@ -1866,13 +3055,144 @@ mod tests {
 			functions: Some(Vec::new()),
 		};
 
+		let abi = json!([{
+			"type": "function",
+			"name": "fill",
+			"inputs": [
+				{
+					"name": "order",
+					"type": "tuple",
+					"components": [
+						{ "name": "maker", "type": "address" },
+						{ "name": "amounts", "type": "uint256[]" },
+						{
+							"name": "legs",
+							"type": "tuple[]",
+							"components": [
+								{ "name": "token", "type": "address" },
+								{ "name": "", "type": "uint256" }
+							]
+						}
+					]
+				},
+				{ "name": "fee", "type": "uint256" }
+			],
+			"outputs": [],
+			"stateMutability": "nonpayable"
+		}]);
 		let contract_with_spec = (
 			"0x0000000000000000000000000000000000004321".to_string(),
-			EVMContractSpec::from(create_test_abi("function")),
+			EVMContractSpec::from(abi.clone()),
 		);
+		let function: Function = serde_json::from_value(abi[0].clone()).unwrap();
+
+		let maker = Address::from_str("0x0000000000000000000000000000000000001234").unwrap();
+		let token = Address::from_str("0x0000000000000000000000000000000000005678").unwrap();
+		let encoded = function
+			.abi_encode_input(&[
+				DynSolValue::Tuple(vec![
+					DynSolValue::Address(maker),
+					DynSolValue::Array(vec![
+						DynSolValue::Uint(U256::from(5), 256),
+						DynSolValue::Uint(U256::from(1000), 256),
+					]),
+					DynSolValue::Array(vec![DynSolValue::Tuple(vec![
+						DynSolValue::Address(token),
+						DynSolValue::Uint(U256::from(42), 256),
+					])]),
+				]),
+				DynSolValue::Uint(U256::from(3), 256),
+			])
+			.unwrap();
+		let transaction = TransactionBuilder::new()
+			.to(Address::from_str(&contract_with_spec.0).unwrap())
+			.input(Bytes(encoded.into()))
+			.build();
 
-		let monitor = create_test_monitor(
-			vec![],
+		let find_matches = |expression: &str,
+		                    matched_functions: &mut Vec<FunctionCondition>,
+		                    matched_on_args: &mut EVMMatchArguments| {
+			let monitor = create_test_monitor(
+				vec![],
+				vec![FunctionCondition {
+					signature: "fill((address,uint256[],(address,uint256)[]),uint256)".to_string(),
+					expression: Some(expression.to_string()),
+				}],
+				vec![],
+				vec![create_test_address(
+					&contract_with_spec.0,
+					Some(ContractSpec::EVM(contract_with_spec.1.clone())),
+				)],
+			);
+			matched_functions.clear();
+			filter.find_matching_functions_for_transaction(
+				&[contract_with_spec.clone()],
+				&transaction,
+				&monitor,
+				matched_functions,
+				matched_on_args,
+				&[],
+			);
+			matched_functions.len()
+		};
+
+		// Tuple components, dynamic array elements and unnamed components are addressable
+		for expression in [
+			"order.maker == 0x0000000000000000000000000000000000001234",
+			"order.amounts[1] > 100",
+			"order.amounts[0] == 5 AND fee == 3",
+			"order.legs[0].token == 0x0000000000000000000000000000000000005678",
+			"order.legs[0].1 == 42",
+		] {
+			assert_eq!(
+				find_matches(expression, &mut matched_functions, &mut matched_on_args),
+				1,
+				"{}",
+				expression
+			);
+		}
+
+		for expression in [
+			"order.maker == 0x0000000000000000000000000000000000005678",
+			"order.amounts[1] > 1000",
+			// Out of bounds elements do not match
+			"order.amounts[2] > 0",
+		] {
+			assert_eq!(
+				find_matches(expression, &mut matched_functions, &mut matched_on_args),
+				0,
+				"{}",
+				expression
+			);
+		}
+
+		// Nested values are not added to the match arguments
+		let args = matched_on_args.functions.as_ref().unwrap()[0]
+			.args
+			.as_ref()
+			.unwrap();
+		assert_eq!(
+			args.iter().map(|arg| arg.name.as_str()).collect::<Vec<_>>(),
+			vec!["order", "fee"]
+		);
+	}
+
+	#[test]
+	fn test_find_matching_functions_non_matching_address() {
+		let filter = create_test_filter();
+		let mut matched_functions = Vec::new();
+		let mut matched_on_args = EVMMatchArguments {
+			events: None,
+			functions: Some(Vec::new()),
+		};
+
+		let contract_with_spec = (
+			"0x0000000000000000000000000000000000004321".to_string(),
+			EVMContractSpec::from(create_test_abi("function")),
+		);
+
+		let monitor = create_test_monitor(
+			vec![],
 			vec![FunctionCondition {
 				signature: "transfer(address,uint256)".to_string(),
 				expression: None,
@@ -1930,6 +3250,7 @@ mod tests {
 			&monitor,
 			&mut matched_functions,
 			&mut matched_on_args,
+			&[],
 		);
 
 		assert_eq!(matched_functions.len(), 0);
@@ -1957,6 +3278,9 @@ mod tests {
 				}],
 				events: vec![],
 				transactions: vec![],
+				operations: vec![],
+				aggregates: vec![],
+				percentage_changes: vec![],
 			})
 			.addresses_with_spec(vec![(
 				contract_with_spec.0.clone(),
@@ -1979,11 +3303,231 @@ mod tests {
 			&monitor,
 			&mut matched_functions,
 			&mut matched_on_args,
+			&[],
 		);
 
 		assert_eq!(matched_functions.len(), 0);
 	}
 
+	#[test]
+	fn test_find_matching_functions_raw_selector_without_spec() {
+		let filter = create_test_filter();
+		let mut matched_functions = Vec::new();
+		let mut matched_on_args = EVMMatchArguments {
+			events: None,
+			functions: Some(Vec::new()),
+		};
+
+		let monitor = create_test_monitor(
+			vec![],
+			vec![FunctionCondition {
+				signature: "0xA9059CBB".to_string(),
+				expression: None,
+			}],
+			vec![],
+			vec![create_test_address(
+				"0x0000000000000000000000000000000000004321",
+				None,
+			)],
+		);
+
+		let mut input = hex::decode("a9059cbb").unwrap();
+		input.extend_from_slice(&[0u8; 64]);
+		let transaction = TransactionBuilder::new()
+			.to(Address::from_str("0x0000000000000000000000000000000000004321").unwrap())
+			.input(Bytes(input.into()))
+			.build();
+
+		filter.find_matching_functions_for_transaction(
+			&[],
+			&transaction,
+			&monitor,
+			&mut matched_functions,
+			&mut matched_on_args,
+			&[],
+		);
+
+		assert_eq!(matched_functions.len(), 1);
+		assert_eq!(matched_functions[0].signature, "0xa9059cbb");
+		let functions = matched_on_args.functions.unwrap();
+		assert_eq!(functions.len(), 1);
+		assert_eq!(functions[0].hex_signature.as_deref(), Some("0xa9059cbb"));
+		assert!(functions[0].args.is_none());
+
+		// A different selector does not match
+		let mut matched_functions = Vec::new();
+		let transaction = TransactionBuilder::new()
+			.to(Address::from_str("0x0000000000000000000000000000000000004321").unwrap())
+			.input(Bytes(hex::decode("095ea7b3").unwrap().into()))
+			.build();
+		filter.find_matching_functions_for_transaction(
+			&[],
+			&transaction,
+			&monitor,
+			&mut matched_functions,
+			&mut EVMMatchArguments {
+				events: None,
+				functions: Some(Vec::new()),
+			},
+			&[],
+		);
+		assert!(matched_functions.is_empty());
+	}
+
+	//////////////////////////////////////////////////////////////////////////////
+	// Test cases for log_filters method:
+	//////////////////////////////////////////////////////////////////////////////
+
+	#[test]
+	fn test_log_filters_union_addresses_and_topics() {
+		let filter = create_test_filter();
+		let transfer_topic = b256_to_string(keccak256(b"Transfer(address,address,uint256)"));
+		let approval_topic = b256_to_string(keccak256(b"Approval(address,address,uint256)"));
+
+		let transfer_monitor = create_test_monitor(
+			vec![EventCondition {
+				signature: "Transfer(address, address, uint256)".to_string(),
+				expression: None,
+			}],
+			vec![],
+			vec![],
+			vec![create_test_address(
+				"0x0000000000000000000000000000000000004321",
+				Some(create_test_abi("event")),
+			)],
+		);
+		let approval_monitor = create_test_monitor(
+			vec![EventCondition {
+				signature: approval_topic.clone(),
+				expression: None,
+			}],
+			vec![],
+			vec![],
+			vec![
+				create_test_address("0x000000000000000000000000000000000000ABCD", None),
+				create_test_address("0x0000000000000000000000000000000000004321", None),
+			],
+		);
+
+		let (addresses, topics) = filter.log_filters(
+			&[transfer_monitor, approval_monitor],
+			&EvmAddressNormalizer::default(),
+		);
+
+		assert_eq!(
+			addresses,
+			Some(vec![
+				"0x0000000000000000000000000000000000004321".to_string(),
+				"0x000000000000000000000000000000000000abcd".to_string(),
+			])
+		);
+		let topics = topics.unwrap();
+		assert_eq!(topics.len(), 2);
+		assert!(topics.contains(&transfer_topic));
+		assert!(topics.contains(&approval_topic));
+	}
+
+	#[test]
+	fn test_log_filters_unfiltered_topics_when_monitor_matches_all_events() {
+		let filter = create_test_filter();
+		let event_monitor = create_test_monitor(
+			vec![EventCondition {
+				signature: "Transfer(address,address,uint256)".to_string(),
+				expression: None,
+			}],
+			vec![],
+			vec![],
+			vec![create_test_address(
+				"0x0000000000000000000000000000000000004321",
+				Some(create_test_abi("event")),
+			)],
+		);
+		let transaction_monitor = create_test_monitor(
+			vec![],
+			vec![],
+			vec![TransactionCondition {
+				status: TransactionStatus::Success,
+				expression: None,
+			}],
+			vec![create_test_address(
+				"0x0000000000000000000000000000000000005678",
+				None,
+			)],
+		);
+
+		let (addresses, topics) = filter.log_filters(
+			&[event_monitor, transaction_monitor],
+			&EvmAddressNormalizer::default(),
+		);
+
+		assert_eq!(addresses.map(|a| a.len()), Some(2));
+		assert!(topics.is_none());
+	}
+
+	#[test]
+	fn test_log_filters_unresolved_signature_leaves_topics_unfiltered() {
+		let filter = create_test_filter();
+		let monitor = create_test_monitor(
+			vec![EventCondition {
+				signature: "Transfer(address,address,uint256)".to_string(),
+				expression: None,
+			}],
+			vec![],
+			vec![],
+			vec![],
+		);
+
+		assert_eq!(
+			filter.log_filters(&[monitor], &EvmAddressNormalizer::default()),
+			(None, None)
+		);
+	}
+
+	#[test]
+	fn test_log_filters_anonymous_event_leaves_topics_unfiltered() {
+		let filter = create_test_filter();
+		let abi = json!([{
+			"type": "event",
+			"name": "Deposit",
+			"inputs": [
+				{"name": "account", "type": "address", "indexed": true},
+				{"name": "amount", "type": "uint256", "indexed": false}
+			],
+			"anonymous": true
+		}]);
+		let anonymous_monitor = create_test_monitor(
+			vec![EventCondition {
+				signature: "Deposit(address,uint256)".to_string(),
+				expression: None,
+			}],
+			vec![],
+			vec![],
+			vec![create_test_address(
+				"0x0000000000000000000000000000000000004321",
+				Some(ContractSpec::EVM(EVMContractSpec::from(abi))),
+			)],
+		);
+		let transfer_monitor = create_test_monitor(
+			vec![EventCondition {
+				signature: "Transfer(address,address,uint256)".to_string(),
+				expression: None,
+			}],
+			vec![],
+			vec![],
+			vec![create_test_address(
+				"0x0000000000000000000000000000000000004321",
+				Some(create_test_abi("event")),
+			)],
+		);
+
+		let (_, topics) = filter.log_filters(
+			&[transfer_monitor, anonymous_monitor],
+			&EvmAddressNormalizer::default(),
+		);
+
+		assert!(topics.is_none());
+	}
+
 	//////////////////////////////////////////////////////////////////////////////
 	// Test cases for find_matching_events_for_transaction method:
 	//////////////////////////////////////////////////////////////////////////////
@@ -2028,6 +3572,7 @@ mod tests {
 			&mut matched_events,
 			&mut matched_on_args,
 			&mut involved_addresses,
+			&[],
 		);
 
 		assert_eq!(matched_events.len(), 1);
@@ -2043,6 +3588,160 @@ mod tests {
 		);
 	}
 
+	#[tokio::test]
+	async fn test_find_matching_events_raw_topic_without_spec() {
+		let filter = create_test_filter();
+		let mut matched_events = Vec::new();
+		let mut matched_on_args = EVMMatchArguments {
+			events: Some(Vec::new()),
+			functions: None,
+		};
+		let mut involved_addresses = Vec::new();
+
+		let transfer_topic = b256_to_string(keccak256(b"Transfer(address,address,uint256)"));
+		let monitor = create_test_monitor(
+			vec![EventCondition {
+				signature: transfer_topic.clone(),
+				expression: None,
+			}],
+			vec![],
+			vec![],
+			vec![create_test_address(
+				"0x0000000000000000000000000000000000004321",
+				None,
+			)],
+		);
+
+		let contract_address =
+			Address::from_str("0x0000000000000000000000000000000000004321").unwrap();
+		let receipt = ReceiptBuilder::new()
+			.contract_address(contract_address)
+			.from(Address::from_str("0x0000000000000000000000000000000000001234").unwrap())
+			.to(Address::from_str("0x0000000000000000000000000000000000005678").unwrap())
+			.value(U256::from(100))
+			.build();
+
+		filter.find_matching_events_for_transaction(
+			&receipt.logs,
+			&monitor,
+			&mut matched_events,
+			&mut matched_on_args,
+			&mut involved_addresses,
+			&[],
+		);
+
+		assert_eq!(matched_events.len(), 1);
+		assert_eq!(matched_events[0].signature, transfer_topic);
+		let events = matched_on_args.events.unwrap();
+		assert_eq!(events.len(), 1);
+		assert_eq!(
+			events[0].hex_signature.as_deref(),
+			Some(transfer_topic.as_str())
+		);
+		assert!(events[0].args.is_none());
+	}
+
+	#[tokio::test]
+	async fn test_find_matching_events_labeled_without_spec() {
+		let filter = create_test_filter();
+		let mut matched_events = Vec::new();
+		let mut matched_on_args = EVMMatchArguments {
+			events: Some(Vec::new()),
+			functions: None,
+		};
+		let mut involved_addresses = Vec::new();
+
+		let transfer_topic = b256_to_string(keccak256(b"Transfer(address,address,uint256)"));
+		let mut address = create_test_address("0x0000000000000000000000000000000000004321", None);
+		address.event_signatures.insert(
+			transfer_topic.to_uppercase().replacen("0X", "0x", 1),
+			"Transfer(address,address,uint256)".to_string(),
+		);
+		let monitor = create_test_monitor(
+			vec![EventCondition {
+				signature: "Transfer(address, address, uint256)".to_string(),
+				expression: Some(
+					"data == 0x0000000000000000000000000000000000000000000000000000000000000064"
+						.to_string(),
+				),
+			}],
+			vec![],
+			vec![],
+			vec![address],
+		);
+
+		let contract_address =
+			Address::from_str("0x0000000000000000000000000000000000004321").unwrap();
+		let receipt = ReceiptBuilder::new()
+			.contract_address(contract_address)
+			.from(Address::from_str("0x0000000000000000000000000000000000001234").unwrap())
+			.to(Address::from_str("0x0000000000000000000000000000000000005678").unwrap())
+			.value(U256::from(100))
+			.build();
+
+		filter.find_matching_events_for_transaction(
+			&receipt.logs,
+			&monitor,
+			&mut matched_events,
+			&mut matched_on_args,
+			&mut involved_addresses,
+			&[],
+		);
+
+		assert_eq!(matched_events.len(), 1);
+		assert_eq!(
+			matched_events[0].signature,
+			"Transfer(address,address,uint256)"
+		);
+		let events = matched_on_args.events.unwrap();
+		assert_eq!(events.len(), 1);
+		assert_eq!(
+			events[0].hex_signature.as_deref(),
+			Some(transfer_topic.as_str())
+		);
+		let args = events[0].args.as_ref().unwrap();
+		let arg = |name: &str| args.iter().find(|arg| arg.name == name).unwrap();
+		assert_eq!(
+			arg("topic1").value,
+			"0x0000000000000000000000000000000000000000000000000000000000001234"
+		);
+		assert_eq!(arg("topic1").kind, "bytes32");
+		assert!(arg("topic1").indexed);
+		assert_eq!(
+			arg("topic2").value,
+			"0x0000000000000000000000000000000000000000000000000000000000005678"
+		);
+		assert_eq!(
+			arg("data").value,
+			"0x0000000000000000000000000000000000000000000000000000000000000064"
+		);
+		assert_eq!(arg("data").kind, "bytes");
+		assert!(!arg("data").indexed);
+	}
+
+	#[test]
+	fn test_log_filters_resolves_labeled_signature() {
+		let filter = create_test_filter();
+		let transfer_topic = b256_to_string(keccak256(b"Transfer(address,address,uint256)"));
+		let mut address = create_test_address("0x0000000000000000000000000000000000004321", None);
+		address.event_signatures.insert(
+			transfer_topic.clone(),
+			"Transfer(address,address,uint256)".to_string(),
+		);
+		let monitor = create_test_monitor(
+			vec![EventCondition {
+				signature: "Transfer(address,address,uint256)".to_string(),
+				expression: None,
+			}],
+			vec![],
+			vec![],
+			vec![address],
+		);
+
+		let (_, topics) = filter.log_filters(&[monitor], &EvmAddressNormalizer::default());
+		assert_eq!(topics, Some(vec![transfer_topic]));
+	}
+
 	#[tokio::test]
 	async fn test_find_matching_events_with_expression() {
 		let filter = create_test_filter();
@@ -2083,6 +3782,7 @@ mod tests {
 			&mut matched_events,
 			&mut matched_on_args,
 			&mut involved_addresses,
+			&[],
 		);
 
 		assert_eq!(matched_events.len(), 1);
@@ -2106,14 +3806,66 @@ mod tests {
 			.build();
 
 		filter.find_matching_events_for_transaction(
-			&receipt_no_match.logs,
+			&receipt_no_match.logs,
+			&monitor,
+			&mut matched_events,
+			&mut matched_on_args,
+			&mut involved_addresses,
+			&[],
+		);
+
+		assert_eq!(matched_events.len(), 0);
+	}
+
+	#[tokio::test]
+	async fn test_find_matching_events_with_block_number_expression() {
+		let filter = create_test_filter();
+		let mut matched_events = Vec::new();
+		let mut matched_on_args = EVMMatchArguments {
+			events: Some(Vec::new()),
+			functions: None,
+		};
+		let mut involved_addresses = Vec::new();
+
+		let monitor = create_test_monitor(
+			vec![EventCondition {
+				signature: "Transfer(address,address,uint256)".to_string(),
+				expression: Some("value > 500 AND block_number >= 200".to_string()),
+			}],
+			vec![],
+			vec![],
+			vec![create_test_address(
+				"0x0000000000000000000000000000000000004321",
+				Some(create_test_abi("event")),
+			)],
+		);
+
+		let receipt = ReceiptBuilder::new()
+			.contract_address(
+				Address::from_str("0x0000000000000000000000000000000000004321").unwrap(),
+			)
+			.from(Address::from_str("0x0000000000000000000000000000000000001234").unwrap())
+			.to(Address::from_str("0x0000000000000000000000000000000000005678").unwrap())
+			.value(U256::from(1000))
+			.build();
+		let block = create_test_block(200, 0);
+
+		filter.find_matching_events_for_transaction(
+			&receipt.logs,
 			&monitor,
 			&mut matched_events,
 			&mut matched_on_args,
 			&mut involved_addresses,
+			&filter.block_params(&block),
 		);
 
-		assert_eq!(matched_events.len(), 0);
+		assert_eq!(matched_events.len(), 1);
+		// Block parameters are only used for evaluation and are not reported as event args
+		let args = matched_on_args.events.as_ref().unwrap()[0]
+			.args
+			.as_ref()
+			.unwrap();
+		assert!(args.iter().all(|arg| arg.name != "block_number"));
 	}
 
 	#[tokio::test]
@@ -2155,12 +3907,334 @@ mod tests {
 			&mut matched_events,
 			&mut matched_on_args,
 			&mut involved_addresses,
+			&[],
 		);
 
 		assert_eq!(matched_events.len(), 0);
 		assert_eq!(involved_addresses.len(), 0);
 	}
 
+	//////////////////////////////////////////////////////////////////////////////
+	// Test cases for find_matching_aggregates_for_transaction method:
+	//////////////////////////////////////////////////////////////////////////////
+	const TRANSFER_TOPIC: &str =
+		"0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+
+	fn create_transfer_log(to: &str, value: U256) -> EVMReceiptLog {
+		create_test_log(
+			Address::from_str("0x0000000000000000000000000000000000004321").unwrap(),
+			TRANSFER_TOPIC,
+			Address::from_str("0x0000000000000000000000000000000000001234").unwrap(),
+			Address::from_str(to).unwrap(),
+			&hex::encode(value.to_be_bytes::<32>()),
+		)
+	}
+
+	fn create_aggregate_monitor(filter: Option<&str>, expression: &str) -> Monitor {
+		let mut monitor = create_test_monitor(
+			vec![],
+			vec![],
+			vec![],
+			vec![create_test_address(
+				"0x0000000000000000000000000000000000004321",
+				Some(create_test_abi("event")),
+			)],
+		);
+		monitor.match_conditions.aggregates = vec![AggregateCondition {
+			signature: "Transfer(address,address,uint256)".to_string(),
+			field: "value".to_string(),
+			filter: filter.map(|f| f.to_string()),
+			expression: expression.to_string(),
+		}];
+		monitor
+	}
+
+	#[test]
+	fn test_find_matching_aggregates_sum_exceeds_threshold() {
+		let filter = create_test_filter();
+		let to = "0x0000000000000000000000000000000000005678";
+		let logs = vec![
+			create_transfer_log(to, U256::from(400)),
+			create_transfer_log(to, U256::from(400)),
+			create_transfer_log(to, U256::from(400)),
+		];
+
+		// No single transfer exceeds the threshold...
+		let mut matched_events = Vec::new();
+		filter.find_matching_events_for_transaction(
+			&logs,
+			&create_test_monitor(
+				vec![EventCondition {
+					signature: "Transfer(address,address,uint256)".to_string(),
+					expression: Some("value > 1000".to_string()),
+				}],
+				vec![],
+				vec![],
+				vec![create_test_address(
+					"0x0000000000000000000000000000000000004321",
+					Some(create_test_abi("event")),
+				)],
+			),
+			&mut matched_events,
+			&mut EVMMatchArguments {
+				events: Some(Vec::new()),
+				functions: None,
+			},
+			&mut Vec::new(),
+			&[],
+		);
+		assert!(matched_events.is_empty());
+
+		// ...but their total does
+		let monitor = create_aggregate_monitor(None, "sum > 1000 AND count == 3");
+		let mut matched_aggregates = Vec::new();
+		filter.find_matching_aggregates_for_transaction(
+			&logs,
+			&monitor,
+			&mut matched_aggregates,
+			&[],
+		);
+		assert_eq!(matched_aggregates, monitor.match_conditions.aggregates);
+	}
+
+	#[test]
+	fn test_find_matching_aggregates_sum_below_threshold() {
+		let filter = create_test_filter();
+		let to = "0x0000000000000000000000000000000000005678";
+		let logs = vec![
+			create_transfer_log(to, U256::from(400)),
+			create_transfer_log(to, U256::from(400)),
+		];
+
+		let mut matched_aggregates = Vec::new();
+		filter.find_matching_aggregates_for_transaction(
+			&logs,
+			&create_aggregate_monitor(None, "sum > 1000"),
+			&mut matched_aggregates,
+			&[],
+		);
+		assert!(matched_aggregates.is_empty());
+	}
+
+	#[test]
+	fn test_find_matching_aggregates_with_filter() {
+		let filter = create_test_filter();
+		let logs = vec![
+			create_transfer_log(
+				"0x0000000000000000000000000000000000005678",
+				U256::from(600),
+			),
+			create_transfer_log(
+				"0x0000000000000000000000000000000000005678",
+				U256::from(600),
+			),
+			create_transfer_log(
+				"0x0000000000000000000000000000000000009999",
+				U256::from(600),
+			),
+		];
+
+		// Only transfers to 0x...5678 are counted
+		let mut matched_aggregates = Vec::new();
+		filter.find_matching_aggregates_for_transaction(
+			&logs,
+			&create_aggregate_monitor(
+				Some("to == 0x0000000000000000000000000000000000005678"),
+				"sum == 1200",
+			),
+			&mut matched_aggregates,
+			&[],
+		);
+		assert_eq!(matched_aggregates.len(), 1);
+	}
+
+	#[test]
+	fn test_find_matching_aggregates_no_logs() {
+		let filter = create_test_filter();
+
+		// Conditions are not evaluated when no events contributed
+		let mut matched_aggregates = Vec::new();
+		filter.find_matching_aggregates_for_transaction(
+			&[],
+			&create_aggregate_monitor(None, "sum >= 0"),
+			&mut matched_aggregates,
+			&[],
+		);
+		assert!(matched_aggregates.is_empty());
+	}
+
+	#[test]
+	fn test_find_matching_aggregates_overflow_saturates() {
+		let filter = create_test_filter();
+		let to = "0x0000000000000000000000000000000000005678";
+		let logs = vec![
+			create_transfer_log(to, U256::MAX),
+			create_transfer_log(to, U256::from(1)),
+		];
+
+		let mut matched_aggregates = Vec::new();
+		filter.find_matching_aggregates_for_transaction(
+			&logs,
+			&create_aggregate_monitor(None, &format!("sum == {}", U256::MAX)),
+			&mut matched_aggregates,
+			&[],
+		);
+		assert_eq!(matched_aggregates.len(), 1);
+	}
+
+	//////////////////////////////////////////////////////////////////////////////
+	// Test cases for find_matching_percentage_changes_for_block method:
+	//////////////////////////////////////////////////////////////////////////////
+	fn create_block_transfer_log(tx_hash: B256, log_index: u64, value: U256) -> EVMReceiptLog {
+		let mut log = create_transfer_log("0x0000000000000000000000000000000000005678", value);
+		log.transaction_hash = Some(tx_hash);
+		log.log_index = Some(U256::from(log_index));
+		log
+	}
+
+	fn create_percentage_change_monitor(expression: &str) -> Monitor {
+		let mut monitor = create_test_monitor(
+			vec![],
+			vec![],
+			vec![],
+			vec![create_test_address(
+				"0x0000000000000000000000000000000000004321",
+				Some(create_test_abi("event")),
+			)],
+		);
+		monitor.match_conditions.percentage_changes = vec![PercentageChangeCondition {
+			from: EventField {
+				signature: "Transfer(address,address,uint256)".to_string(),
+				field: "value".to_string(),
+				filter: None,
+			},
+			to: EventField {
+				signature: "Transfer(address,address,uint256)".to_string(),
+				field: "value".to_string(),
+				filter: None,
+			},
+			expression: expression.to_string(),
+		}];
+		monitor
+	}
+
+	#[test]
+	fn test_find_matching_percentage_changes_crosses_threshold() {
+		let filter = create_test_filter();
+		let logs = vec![
+			create_block_transfer_log(B256::with_last_byte(1), 0, U256::from(1000)),
+			create_block_transfer_log(B256::with_last_byte(2), 1, U256::from(1050)),
+			create_block_transfer_log(B256::with_last_byte(3), 2, U256::from(1120)),
+		];
+
+		// The first value is compared with the last one: (1120 - 1000) / 1000 = 12%
+		let monitor = create_percentage_change_monitor("change == 12 AND abs_change > 10");
+		let mut matched = Vec::new();
+		filter.find_matching_percentage_changes_for_block(&logs, &monitor, &mut matched, &[]);
+
+		assert_eq!(matched.len(), 1);
+		assert_eq!(
+			matched[0].condition,
+			monitor.match_conditions.percentage_changes[0]
+		);
+		assert_eq!(
+			matched[0].transaction_hash,
+			b256_to_string(B256::with_last_byte(3))
+		);
+
+		// Both compared events are captured
+		assert_eq!(matched[0].events.len(), 2);
+		let values: Vec<&str> = matched[0]
+			.events
+			.iter()
+			.map(|event| {
+				event
+					.args
+					.as_ref()
+					.unwrap()
+					.iter()
+					.find(|arg| arg.name == "value")
+					.unwrap()
+					.value
+					.as_str()
+			})
+			.collect();
+		assert_eq!(values, vec!["1000", "1120"]);
+	}
+
+	#[test]
+	fn test_find_matching_percentage_changes_stays_under_threshold() {
+		let filter = create_test_filter();
+		let logs = vec![
+			create_block_transfer_log(B256::with_last_byte(1), 0, U256::from(1000)),
+			create_block_transfer_log(B256::with_last_byte(2), 1, U256::from(960)),
+		];
+
+		let mut matched = Vec::new();
+		filter.find_matching_percentage_changes_for_block(
+			&logs,
+			&create_percentage_change_monitor("abs_change > 5"),
+			&mut matched,
+			&[],
+		);
+		assert!(matched.is_empty());
+
+		// A decrease is reported as a negative change
+		filter.find_matching_percentage_changes_for_block(
+			&logs,
+			&create_percentage_change_monitor("change == -4 AND first == 1000 AND second == 960"),
+			&mut matched,
+			&[],
+		);
+		assert_eq!(matched.len(), 1);
+	}
+
+	#[test]
+	#[traced_test]
+	fn test_find_matching_percentage_changes_from_zero() {
+		let filter = create_test_filter();
+		let logs = vec![
+			create_block_transfer_log(B256::with_last_byte(1), 0, U256::ZERO),
+			create_block_transfer_log(B256::with_last_byte(2), 1, U256::from(1000)),
+		];
+
+		let mut matched = Vec::new();
+		filter.find_matching_percentage_changes_for_block(
+			&logs,
+			&create_percentage_change_monitor("abs_change >= 0"),
+			&mut matched,
+			&[],
+		);
+		assert!(matched.is_empty());
+		assert!(logs_contain(
+			"Cannot compute the percentage change of 'value' for \
+			 Transfer(address,address,uint256) from a zero value"
+		));
+	}
+
+	#[test]
+	fn test_find_matching_percentage_changes_missing_second_value() {
+		let filter = create_test_filter();
+		let monitor = create_percentage_change_monitor("abs_change >= 0");
+
+		// A single event provides no value to compare with
+		let mut matched = Vec::new();
+		filter.find_matching_percentage_changes_for_block(
+			&[create_block_transfer_log(
+				B256::with_last_byte(1),
+				0,
+				U256::from(1000),
+			)],
+			&monitor,
+			&mut matched,
+			&[],
+		);
+		assert!(matched.is_empty());
+
+		filter.find_matching_percentage_changes_for_block(&[], &monitor, &mut matched, &[]);
+		assert!(matched.is_empty());
+	}
+
 	//////////////////////////////////////////////////////////////////////////////
 	// Test cases for evaluate_expression method:
 	//////////////////////////////////////////////////////////////////////////////
@@ -2353,6 +4427,95 @@ mod tests {
 			.unwrap());
 	}
 
+	#[test]
+	fn test_evaluate_expression_bytes_length_and_prefix() {
+		let filter = create_test_filter();
+		// transfer(address,uint256) calldata: 4 byte selector and two 32 byte words
+		let args = vec![
+			create_test_param(
+				"input",
+				"0xa9059cbb000000000000000000000000abababababababababababababababababababab0000000000000000000000000000000000000000000000000000000000000064",
+				"bytes",
+			),
+			create_test_param("name", "Alice", "string"),
+			create_test_param("amount", "1000", "uint256"),
+		];
+
+		// Length thresholds
+		assert!(filter
+			.evaluate_expression("length(input) == 68", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("length(input) > 64", &args)
+			.unwrap());
+		assert!(!filter
+			.evaluate_expression("length(input) > 100", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("length(name) == 5", &args)
+			.unwrap());
+
+		// Prefix equality on hex inputs
+		assert!(filter
+			.evaluate_expression("prefix(input, 4) == 0xa9059cbb", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("prefix(input, 4) == '0xA9059CBB'", &args)
+			.unwrap());
+		assert!(!filter
+			.evaluate_expression("prefix(input, 4) == 0x095ea7b3", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression(
+				"prefix(input, 4) != 0x095ea7b3 AND length(input) >= 68",
+				&args
+			)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("prefix(name, 3) == 'ali'", &args)
+			.unwrap());
+
+		// Functions are only supported for bytes and string kinds
+		assert!(matches!(
+			filter.evaluate_expression("length(amount) > 1", &args),
+			Err(EvaluationError::TypeMismatch(_))
+		));
+	}
+
+	#[test]
+	fn test_evaluate_expression_hash_functions() {
+		let filter = create_test_filter();
+		let args = vec![
+			create_test_param(
+				"role",
+				"0x9f2df0fed2c77648de5860a4cc508cd0818c85b8b8a1ab4ceeef8d981c8956a6",
+				"bytes32",
+			),
+			create_test_param(
+				"digest",
+				"0xba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+				"bytes32",
+			),
+		];
+
+		assert!(filter
+			.evaluate_expression("role == keccak256(\"MINTER_ROLE\")", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("role != keccak256('PAUSER_ROLE')", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("digest == sha256('abc')", &args)
+			.unwrap());
+		// Hex inputs are hashed as the bytes they encode
+		assert!(filter
+			.evaluate_expression("digest == sha256(0x616263)", &args)
+			.unwrap());
+		assert!(!filter
+			.evaluate_expression("role == sha256('MINTER_ROLE')", &args)
+			.unwrap());
+	}
+
 	#[test]
 	fn test_evaluate_expression_basic_field_access() {
 		let filter = create_test_filter();
@@ -3257,6 +5420,131 @@ mod tests {
 		assert_eq!(decoded.signature, "TupleEvent((uint256,address),uint256)");
 	}
 
+	fn create_anonymous_log(topics: Vec<B256>, data: &str) -> EVMReceiptLog {
+		EVMReceiptLog {
+			address: Address::from_str("0x0000000000000000000000000000000000004321").unwrap(),
+			topics,
+			data: Bytes(hex::decode(data).unwrap().into()),
+			block_hash: None,
+			block_number: None,
+			transaction_hash: None,
+			transaction_index: None,
+			log_index: Some(U256::from(0)),
+			transaction_log_index: Some(U256::from(0)),
+			log_type: None,
+			removed: Some(false),
+		}
+	}
+
+	fn create_anonymous_abi() -> ContractSpec {
+		ContractSpec::EVM(EVMContractSpec::from(json!([
+			{
+				"type": "event",
+				"name": "Transfer",
+				"inputs": [
+					{"name": "from", "type": "address", "indexed": true},
+					{"name": "to", "type": "address", "indexed": true},
+					{"name": "value", "type": "uint256", "indexed": false}
+				],
+				"anonymous": false
+			},
+			{
+				"type": "event",
+				"name": "Deposit",
+				"inputs": [
+					{"name": "account", "type": "address", "indexed": true},
+					{"name": "amount", "type": "uint256", "indexed": false}
+				],
+				"anonymous": true
+			}
+		])))
+	}
+
+	#[tokio::test]
+	async fn test_decode_events_anonymous_event() {
+		let filter = create_test_filter();
+		let account = Address::from_str("0x0000000000000000000000000000000000001234").unwrap();
+		let log = create_anonymous_log(
+			vec![B256::left_padding_from(account.as_slice())],
+			"0000000000000000000000000000000000000000000000000000000000000064",
+		);
+
+		let decoded = filter
+			.decode_events(&create_anonymous_abi(), &log)
+			.expect("Anonymous event should be decoded");
+
+		assert_eq!(decoded.signature, "Deposit(address,uint256)");
+		assert_eq!(decoded.hex_signature, None);
+		let args = decoded.args.unwrap();
+		assert_eq!(args[0].name, "account");
+		assert_eq!(args[0].value, "0x0000000000000000000000000000000000001234");
+		assert!(args[0].indexed);
+		assert_eq!(args[1].name, "amount");
+		assert_eq!(args[1].value, "100");
+		assert!(!args[1].indexed);
+	}
+
+	#[tokio::test]
+	async fn test_decode_events_prefers_selector_over_anonymous_event() {
+		let filter = create_test_filter();
+		let log = create_test_log(
+			Address::from_str("0x0000000000000000000000000000000000004321").unwrap(),
+			"0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+			Address::from_str("0x0000000000000000000000000000000000001234").unwrap(),
+			Address::from_str("0x0000000000000000000000000000000000005678").unwrap(),
+			"0000000000000000000000000000000000000000000000000000000000000064",
+		);
+
+		let decoded = filter.decode_events(&create_anonymous_abi(), &log).unwrap();
+
+		assert_eq!(decoded.signature, "Transfer(address,address,uint256)");
+		assert!(decoded.hex_signature.is_some());
+	}
+
+	#[tokio::test]
+	async fn test_decode_events_ambiguous_anonymous_events() {
+		let filter = create_test_filter();
+		let abi = ContractSpec::EVM(EVMContractSpec::from(json!([
+			{
+				"type": "event",
+				"name": "Deposit",
+				"inputs": [
+					{"name": "account", "type": "address", "indexed": true},
+					{"name": "amount", "type": "uint256", "indexed": false}
+				],
+				"anonymous": true
+			},
+			{
+				"type": "event",
+				"name": "Withdrawal",
+				"inputs": [
+					{"name": "account", "type": "address", "indexed": true},
+					{"name": "amount", "type": "uint256", "indexed": false}
+				],
+				"anonymous": true
+			}
+		])));
+		let log = create_anonymous_log(
+			vec![B256::ZERO],
+			"0000000000000000000000000000000000000000000000000000000000000064",
+		);
+
+		assert!(filter.decode_events(&abi, &log).is_none());
+	}
+
+	#[tokio::test]
+	async fn test_decode_events_anonymous_event_topic_count_mismatch() {
+		let filter = create_test_filter();
+		let log = create_anonymous_log(
+			vec![B256::ZERO, B256::ZERO],
+			"0000000000000000000000000000000000000000000000000000000000000064",
+		);
+
+		assert!(filter
+			.decode_events(&create_anonymous_abi(), &log)
+			.is_none());
+	}
+
 	#[tokio::test]
 	async fn test_decode_events_only_indexed_parameters() {
 		let filter = create_test_filter();
@@ -5,9 +5,13 @@
 //! and token value formatting.
 
 use alloy::core::dyn_abi::DynSolValue;
-use alloy::primitives::{Address, B256, I256, U256};
+use alloy::json_abi::Param;
+use alloy::primitives::{keccak256, Address, B256, I256, U256};
+use alloy::sol_types::{Panic, Revert, SolError};
 use std::str::FromStr;
 
+use crate::models::EVMMatchParamEntry;
+
 /// Converts an B256 hash to its hexadecimal string representation.
 ///
 /// # Arguments
@@ -96,6 +100,43 @@ pub fn normalize_signature(signature: &str) -> String {
 	signature.replace(" ", "").to_lowercase()
 }
 
+/// Parses a raw 4-byte function selector such as `0xa9059cbb`.
+///
+/// Raw selectors let monitors match calls to contracts without an ABI.
+///
+/// # Arguments
+/// * `signature` - The condition signature to parse
+///
+/// # Returns
+/// The selector bytes, or `None` if the signature is not a raw selector
+pub fn parse_function_selector(signature: &str) -> Option<[u8; 4]> {
+	parse_hex_prefixed(signature)?.try_into().ok()
+}
+
+/// Parses a raw event topic0 hash such as
+/// `0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef`.
+///
+/// Raw topics let monitors match events from contracts without an ABI.
+///
+/// # Arguments
+/// * `signature` - The condition signature to parse
+///
+/// # Returns
+/// The topic hash, or `None` if the signature is not a raw topic0 hash
+pub fn parse_event_topic(signature: &str) -> Option<B256> {
+	let bytes: [u8; 32] = parse_hex_prefixed(signature)?.try_into().ok()?;
+	Some(B256::from(bytes))
+}
+
+/// Decodes a `0x`-prefixed hex string, returning `None` for anything else.
+fn parse_hex_prefixed(value: &str) -> Option<Vec<u8>> {
+	let value = value.trim();
+	let digits = value
+		.strip_prefix("0x")
+		.or_else(|| value.strip_prefix("0X"))?;
+	alloy::primitives::hex::decode(digits).ok()
+}
+
 /// Formats a DynSolValue into a consistent string representation.
 ///
 /// # Arguments
@@ -187,6 +228,80 @@ pub fn dyn_value_to_string(val: &DynSolValue) -> String {
 	}
 }
 
+/// Flattens the components of a decoded tuple or array value into addressable parameters.
+///
+/// Tuple components are named `<name>.<component>`, using the component's index when it is
+/// unnamed, and array elements are named `<name>[<index>]`, for fixed and dynamic arrays
+/// alike. Nested values are flattened recursively, so `order.items[0].amount` addresses a
+/// field of a struct in an array of a struct. Other values have no components.
+///
+/// # Arguments
+/// * `name` - Name of the parameter holding the value
+/// * `ty` - ABI type of the value (e.g. `tuple`, `tuple[]` or `uint256[3]`)
+/// * `components` - ABI components of the value's tuple type, empty for other types
+/// * `value` - The decoded value
+///
+/// # Returns
+/// The flattened parameters in depth-first order, each with its ABI type as kind
+pub fn flatten_param_value(
+	name: &str,
+	ty: &str,
+	components: &[Param],
+	value: &DynSolValue,
+) -> Vec<EVMMatchParamEntry> {
+	let mut params = Vec::new();
+	flatten_param_value_into(name, ty, components, value, &mut params);
+	params
+}
+
+fn flatten_param_value_into(
+	name: &str,
+	ty: &str,
+	components: &[Param],
+	value: &DynSolValue,
+	params: &mut Vec<EVMMatchParamEntry>,
+) {
+	match value {
+		DynSolValue::Tuple(values) => {
+			for (index, (component, value)) in components.iter().zip(values).enumerate() {
+				let path = if component.name.is_empty() {
+					format!("{}.{}", name, index)
+				} else {
+					format!("{}.{}", name, component.name)
+				};
+				params.push(EVMMatchParamEntry {
+					name: path.clone(),
+					value: format_token_value(value),
+					kind: component.ty.clone(),
+					indexed: false,
+				});
+				flatten_param_value_into(
+					&path,
+					&component.ty,
+					&component.components,
+					value,
+					params,
+				);
+			}
+		}
+		DynSolValue::Array(values) | DynSolValue::FixedArray(values) => {
+			// The element type drops the outermost array dimension, e.g. `tuple[2][]` -> `tuple[2]`
+			let element_ty = ty.rsplit_once('[').map_or(ty, |(element_ty, _)| element_ty);
+			for (index, value) in values.iter().enumerate() {
+				let path = format!("{}[{}]", name, index);
+				params.push(EVMMatchParamEntry {
+					name: path.clone(),
+					value: format_token_value(value),
+					kind: element_ty.to_string(),
+					indexed: false,
+				});
+				flatten_param_value_into(&path, element_ty, components, value, params);
+			}
+		}
+		_ => {}
+	}
+}
+
 /// Converts a string to a U256 value.
 pub fn string_to_u256(value_str: &str) -> Result<U256, String> {
 	let trimmed = value_str.trim();
@@ -234,11 +349,105 @@ pub fn string_to_i256(value_str: &str) -> Result<I256, String> {
 	}
 }
 
+/// Decodes the revert reason from the return data of a reverted call.
+///
+/// Supports `Error(string)` reverts, which decode to the plain reason string, and
+/// `Panic(uint256)` reverts, which decode to a description of the panic code.
+///
+/// # Arguments
+/// * `data` - Return data of the reverted call
+///
+/// # Returns
+/// The decoded reason, or `None` if the data is not a known revert encoding
+pub fn decode_revert_reason(data: &[u8]) -> Option<String> {
+	if let Ok(revert) = Revert::abi_decode(data) {
+		return Some(revert.reason);
+	}
+	Panic::abi_decode(data).ok().map(|panic| panic.to_string())
+}
+
+/// Checks whether a monitored address is an ENS name rather than a hex address.
+///
+/// # Arguments
+/// * `address` - The monitored address
+///
+/// # Returns
+/// `true` if the address is an ENS name (e.g. `vitalik.eth`), `false` otherwise
+pub fn is_ens_name(address: &str) -> bool {
+	let address = address.trim();
+	!address.starts_with("0x") && address.contains('.')
+}
+
+/// Computes the ENS namehash of a name, as used to look it up in the ENS registry.
+///
+/// The name is lowercased, which normalizes ASCII names.
+///
+/// # Arguments
+/// * `name` - The ENS name (e.g. `vitalik.eth`)
+///
+/// # Returns
+/// The namehash of the name
+pub fn ens_namehash(name: &str) -> B256 {
+	let name = name.trim().to_lowercase();
+	if name.is_empty() {
+		return B256::ZERO;
+	}
+	name.rsplit('.').fold(B256::ZERO, |node, label| {
+		keccak256([node.as_slice(), keccak256(label.as_bytes()).as_slice()].concat())
+	})
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 	use alloy::primitives::{hex, Address, B256};
 
+	#[test]
+	fn test_flatten_param_value() {
+		let components: Vec<Param> = serde_json::from_value(serde_json::json!([
+			{ "name": "maker", "type": "address" },
+			{ "name": "amounts", "type": "uint256[2]" },
+			{ "name": "", "type": "bool" }
+		]))
+		.unwrap();
+		let maker = Address::from_str("0x0000000000000000000000000000000000001234").unwrap();
+		let value = DynSolValue::Tuple(vec![
+			DynSolValue::Address(maker),
+			DynSolValue::FixedArray(vec![
+				DynSolValue::Uint(U256::from(1), 256),
+				DynSolValue::Uint(U256::from(2), 256),
+			]),
+			DynSolValue::Bool(true),
+		]);
+
+		let flattened = flatten_param_value("order", "tuple", &components, &value)
+			.into_iter()
+			.map(|param| (param.name, param.value, param.kind))
+			.collect::<Vec<_>>();
+		let expected = [
+			(
+				"order.maker",
+				"0x0000000000000000000000000000000000001234",
+				"address",
+			),
+			("order.amounts", "[1,2]", "uint256[2]"),
+			("order.amounts[0]", "1", "uint256"),
+			("order.amounts[1]", "2", "uint256"),
+			("order.2", "true", "bool"),
+		]
+		.map(|(name, value, kind)| (name.to_string(), value.to_string(), kind.to_string()));
+		assert_eq!(flattened, expected);
+
+		// Values without components are not flattened
+		assert!(flatten_param_value(
+			"amount",
+			"uint256",
+			&[],
+			&DynSolValue::Uint(U256::from(1), 256)
+		)
+		.is_empty());
+	}
+
 	#[test]
 	fn test_b256_to_string() {
 		let hash_bytes =
@@ -701,4 +910,75 @@ mod tests {
 		assert!(function_result.ends_with("\""));
 		assert_eq!(function_result.len(), 52); // "0x" + 48 hex chars + 2 quotes
 	}
+
+	#[test]
+	fn test_parse_function_selector() {
+		assert_eq!(
+			parse_function_selector("0xa9059cbb"),
+			Some([0xa9, 0x05, 0x9c, 0xbb])
+		);
+		assert_eq!(
+			parse_function_selector(" 0XA9059CBB "),
+			Some([0xa9, 0x05, 0x9c, 0xbb])
+		);
+		assert_eq!(parse_function_selector("a9059cbb"), None);
+		assert_eq!(parse_function_selector("0xa9059c"), None);
+		assert_eq!(parse_function_selector("transfer(address,uint256)"), None);
+	}
+
+	#[test]
+	fn test_parse_event_topic() {
+		let topic = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+		assert_eq!(
+			parse_event_topic(topic).map(b256_to_string),
+			Some(topic.to_string())
+		);
+		assert_eq!(parse_event_topic("0xa9059cbb"), None);
+		assert_eq!(parse_event_topic("Transfer(address,address,uint256)"), None);
+	}
+
+	#[test]
+	fn test_decode_revert_reason() {
+		// Error(string) with reason "Insufficient balance"
+		let data = Revert::from("Insufficient balance").abi_encode();
+		assert_eq!(&data[..4], &hex!("08c379a0"));
+		assert_eq!(
+			decode_revert_reason(&data),
+			Some("Insufficient balance".to_string())
+		);
+
+		// Panic(uint256) with an arithmetic overflow code
+		let data = Panic::from(0x11).abi_encode();
+		assert_eq!(
+			decode_revert_reason(&data),
+			Some("panic: arithmetic underflow or overflow (0x11)".to_string())
+		);
+
+		// Custom errors and empty data are not decoded
+		assert_eq!(decode_revert_reason(&hex!("deadbeef")), None);
+		assert_eq!(decode_revert_reason(&[]), None);
+	}
+
+	#[test]
+	fn test_is_ens_name() {
+		assert!(is_ens_name("vitalik.eth"));
+		assert!(is_ens_name("sub.domain.eth"));
+		assert!(!is_ens_name("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045"));
+		assert!(!is_ens_name("d8dA6BF26964aF9D7eEd9e03E53415D37aA96045"));
+	}
+
+	#[test]
+	fn test_ens_namehash() {
+		// Test vectors from EIP-137
+		assert_eq!(ens_namehash(""), B256::ZERO);
+		assert_eq!(
+			b256_to_string(ens_namehash("eth")),
+			"0x93cdeb708b7545dc668eb9280176169d1c33cfd8ed6f04690a0bcc88a93fc4ae"
+		);
+		assert_eq!(
+			b256_to_string(ens_namehash("foo.eth")),
+			"0xde9b09fd7c5f901e23a3f19fecc54828e9c848539801e86591bd9801b019f84f"
+		);
+		assert_eq!(ens_namehash("Foo.ETH"), ens_namehash("foo.eth"));
+	}
 }
@@ -0,0 +1,83 @@
+//! Midnight blockchain filter implementation.
+//!
+//! Midnight blocks cannot be filtered yet. The filter reports an unsupported
+//! operation so that block processing for a Midnight network fails gracefully.
+
+use async_trait::async_trait;
+use std::{collections::HashMap, marker::PhantomData};
+
+use crate::{
+	models::{BlockType, ContractSpec, Monitor, MonitorMatch, Network},
+	services::{
+		blockchain::{BlockChainClient, BlockChainError, MidnightClientTrait},
+		filter::{error::FilterError, BlockFilter},
+	},
+};
+
+/// Implementation of the block filter for Midnight blockchain
+pub struct MidnightBlockFilter<T> {
+	pub _client: PhantomData<T>,
+}
+
+#[async_trait]
+impl<T: BlockChainClient + MidnightClientTrait> BlockFilter for MidnightBlockFilter<T> {
+	type Client = T;
+	/// Filters a Midnight block against provided monitors
+	///
+	/// # Returns
+	/// Always returns a filter error wrapping `BlockChainError::UnsupportedOperation`
+	async fn filter_block(
+		&self,
+		_client: &T,
+		network: &Network,
+		_block: &BlockType,
+		_monitors: &[Monitor],
+		_contract_specs: Option<&[(String, ContractSpec)]>,
+	) -> Result<Vec<MonitorMatch>, FilterError> {
+		Err(FilterError::Other(
+			BlockChainError::unsupported_operation(
+				"Filtering Midnight blocks is not supported yet",
+				None,
+				Some(HashMap::from([(
+					"network".to_string(),
+					network.slug.clone(),
+				)])),
+			)
+			.into(),
+		))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::BlockChainType, services::blockchain::MidnightClient,
+		utils::tests::builders::network::NetworkBuilder,
+	};
+
+	#[tokio::test]
+	async fn test_filter_block_returns_unsupported_operation() {
+		let network = NetworkBuilder::new()
+			.slug("midnight_testnet")
+			.network_type(BlockChainType::Midnight)
+			.build();
+		let client = MidnightClient::new(&network);
+		let filter = MidnightBlockFilter::<MidnightClient> {
+			_client: PhantomData,
+		};
+		let block = BlockType::Stellar(Box::default());
+
+		let result = filter
+			.filter_block(&client, &network, &block, &[], None)
+			.await;
+
+		match result {
+			Err(FilterError::Other(e)) => {
+				let error = e.downcast::<BlockChainError>().unwrap();
+				assert!(matches!(error, BlockChainError::UnsupportedOperation(_)));
+			}
+			_ => panic!("expected an unsupported operation error"),
+		}
+	}
+}
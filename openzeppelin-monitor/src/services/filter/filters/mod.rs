@@ -5,12 +5,16 @@
 //! - Generic BlockFilter trait
 //! - EVM-specific implementation
 //! - Stellar-specific implementation
+//! - Midnight scaffolding (filtering is not supported yet)
 
 pub mod evm {
 	pub mod evaluator;
 	pub mod filter;
 	pub mod helpers;
 }
+pub mod midnight {
+	pub mod filter;
+}
 pub mod stellar {
 	pub mod evaluator;
 	pub mod filter;
@@ -21,12 +25,19 @@ use async_trait::async_trait;
 
 use crate::{
 	models::{BlockType, ContractSpec, Monitor, MonitorMatch, Network},
-	services::{blockchain::BlockFilterFactory, filter::error::FilterError},
+	services::{
+		blockchain::{BlockChainClient, BlockFilterFactory},
+		filter::{error::FilterError, EnrichmentCache},
+	},
 };
 pub use evm::evaluator::{EVMArgs, EVMConditionEvaluator};
 pub use evm::filter::EVMBlockFilter;
+pub use midnight::filter::MidnightBlockFilter;
 pub use stellar::evaluator::{StellarArgs, StellarConditionEvaluator};
-pub use stellar::filter::{EventMap, StellarBlockFilter};
+pub use stellar::filter::{
+	ContractSpecCache, EventMap, StellarBlockFilter, DEFAULT_CONTRACT_SPEC_CACHE_MAX_ENTRIES,
+	DEFAULT_CONTRACT_SPEC_CACHE_TTL,
+};
 
 /// Trait for filtering blockchain data
 ///
@@ -47,12 +58,18 @@ pub trait BlockFilter {
 
 /// Service for filtering blockchain data
 ///
-/// This service provides a way to filter blockchain data based on a set of monitors.
-pub struct FilterService {}
+/// This service provides a way to filter blockchain data based on a set of monitors, and
+/// to enrich the resulting matches.
+pub struct FilterService {
+	/// Results of the enrichment calls opting in to caching
+	enrichment_cache: EnrichmentCache,
+}
 
 impl FilterService {
 	pub fn new() -> Self {
-		FilterService {}
+		FilterService {
+			enrichment_cache: EnrichmentCache::default(),
+		}
 	}
 }
 
@@ -71,9 +88,30 @@ impl FilterService {
 		monitors: &[Monitor],
 		contract_specs: Option<&[(String, ContractSpec)]>,
 	) -> Result<Vec<MonitorMatch>, FilterError> {
-		let filter = T::filter();
+		let filter = client.filter();
 		filter
 			.filter_block(client, network, block, monitors, contract_specs)
 			.await
 	}
+	/// Attaches the results of their monitor's enrichment calls to matches
+	///
+	/// Results of calls with a `cache_ttl_secs` are cached by this service.
+	///
+	/// # Arguments
+	/// * `client` - The client of the network the matches were found on
+	/// * `network` - The network the matches were found on
+	/// * `matches` - The matches to enrich
+	///
+	/// # Returns
+	/// The matches with their enrichment results
+	pub async fn enrich_matches<T: BlockChainClient>(
+		&self,
+		client: &T,
+		network: &Network,
+		matches: Vec<MonitorMatch>,
+	) -> Vec<MonitorMatch> {
+		self.enrichment_cache
+			.enrich_matches(client, network, matches)
+			.await
+	}
 }
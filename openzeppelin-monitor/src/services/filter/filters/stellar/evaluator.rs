@@ -5,8 +5,8 @@ use super::helpers;
 use crate::{
 	models::StellarMatchParamEntry,
 	services::filter::expression::{
-		compare_ordered_values, ComparisonOperator, ConditionEvaluator, EvaluationError,
-		LiteralValue,
+		apply_bytes_function, compare_ordered_values, decode_hex_bytes, BytesFunctionResult,
+		ComparisonOperator, ConditionEvaluator, EvaluationError, LiteralValue, ValueFunction,
 	},
 };
 use serde_json::Value as JsonValue;
@@ -355,6 +355,17 @@ impl<'a> StellarConditionEvaluator<'a> {
 		if is_address_kind && is_strict_eq_operator {
 			left_normalized = helpers::normalize_address(lhs_str);
 			right_normalized = helpers::normalize_address(right_str);
+		} else if lhs_kind == "bytes" {
+			// Bytes are hex encoded without a prefix, allow literals like 0xa9059cbb
+			let strip_hex_prefix = |value: &str| {
+				let lowercase = value.to_lowercase();
+				match lowercase.strip_prefix("0x") {
+					Some(stripped) => stripped.to_string(),
+					None => lowercase,
+				}
+			};
+			left_normalized = strip_hex_prefix(lhs_str);
+			right_normalized = strip_hex_prefix(right_str);
 		} else {
 			left_normalized = lhs_str.to_lowercase();
 			right_normalized = right_str.to_lowercase();
@@ -527,6 +538,47 @@ impl ConditionEvaluator for StellarConditionEvaluator<'_> {
 		helpers::get_kind_from_value(value)
 	}
 
+	/// This method is used to apply a built-in function to a bytes or string value.
+	///
+	/// Arguments:
+	/// - function: The function to apply.
+	/// - kind: The kind of the value.
+	/// - value: The value as a string (hex encoded for bytes).
+	///
+	/// Returns:
+	/// - The kind and value of the result: a `u64` for `length`, and for `prefix`
+	///   a hex encoded `bytes` value or a `string` value depending on the input kind.
+	fn apply_function(
+		&self,
+		function: &ValueFunction,
+		kind: &str,
+		value: &str,
+	) -> Result<(String, String), EvaluationError> {
+		let kind = kind.to_lowercase();
+		let bytes = match kind.as_str() {
+			"bytes" => decode_hex_bytes(value)?,
+			"string" | "symbol" => value.as_bytes().to_vec(),
+			_ => {
+				let msg = format!(
+					"Function '{}' is not supported for parameter type: {}",
+					function, kind
+				);
+				return Err(EvaluationError::type_mismatch(msg, None, None));
+			}
+		};
+
+		Ok(match apply_bytes_function(function, &bytes) {
+			BytesFunctionResult::Length(length) => ("u64".to_string(), length.to_string()),
+			BytesFunctionResult::Prefix(prefix) if kind == "bytes" => {
+				("bytes".to_string(), hex::encode(prefix))
+			}
+			BytesFunctionResult::Prefix(prefix) => (
+				"string".to_string(),
+				String::from_utf8_lossy(&prefix).into_owned(),
+			),
+		})
+	}
+
 	/// This method is used to compare the final values of the Stellar condition evaluator.
 	///
 	/// Arguments:
@@ -947,6 +999,24 @@ mod tests {
 				&LiteralValue::Str("AB")
 			)
 			.unwrap());
+
+		// Bytes Eq ignores the hex prefix on either side
+		assert!(evaluator
+			.compare_string(
+				"bytes",
+				"a9059cbb",
+				&ComparisonOperator::Eq,
+				&LiteralValue::Str("0xA9059CBB")
+			)
+			.unwrap());
+		assert!(evaluator
+			.compare_string(
+				"bytes",
+				"0xa9059cbb",
+				&ComparisonOperator::Eq,
+				&LiteralValue::Str("0XA9059CBB")
+			)
+			.unwrap());
 	}
 
 	#[test]
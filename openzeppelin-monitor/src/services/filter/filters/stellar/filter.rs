@@ -6,35 +6,167 @@
 //! - Compare different types of parameter values
 //! - Evaluate complex matching expressions
 
-use std::marker::PhantomData;
+use std::{
+	collections::{HashMap, HashSet},
+	marker::PhantomData,
+	sync::{Arc, Mutex},
+	time::{Duration, Instant},
+};
 
 use async_trait::async_trait;
 use base64::Engine;
 use serde_json::Value;
+use stellar_strkey::Contract;
 use stellar_xdr::curr::{FeeBumpTransactionInnerTx, OperationBody, TransactionEnvelope};
 use tracing::instrument;
 
 use crate::{
 	models::{
 		BlockType, ContractSpec, EventCondition, FunctionCondition, MatchConditions, Monitor,
-		MonitorMatch, Network, StellarContractFunction, StellarEvent, StellarFormattedContractSpec,
-		StellarMatchArguments, StellarMatchParamEntry, StellarMatchParamsMap, StellarMonitorMatch,
-		StellarTransaction, TransactionCondition, TransactionStatus,
+		MonitorMatch, Network, OperationCondition, StellarContractFunction, StellarEvent,
+		StellarEventFilter, StellarFormattedContractSpec, StellarMatchArguments,
+		StellarMatchParamEntry, StellarMatchParamsMap, StellarMonitorMatch, StellarTransaction,
+		TransactionCondition, TransactionStatus,
 	},
 	services::{
 		blockchain::{BlockChainClient, StellarClientTrait},
 		filter::{
 			expression::{self, EvaluationError},
 			filters::stellar::evaluator::StellarConditionEvaluator,
+			network_address_normalizer,
 			stellar_helpers::{
-				are_same_signature, get_kind_from_value, normalize_address, parse_xdr_value,
-				process_invoke_host_function,
+				are_same_signature, get_kind_from_value, get_operation_params, normalize_address,
+				parse_xdr_value, process_invoke_host_function,
 			},
 			BlockFilter, FilterError,
 		},
 	},
 };
 
+/// Minimum interval between on-chain refreshes of the same contract spec
+const CONTRACT_SPEC_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Number of refreshed contract specs kept when not configured
+pub const DEFAULT_CONTRACT_SPEC_CACHE_MAX_ENTRIES: usize = 1000;
+
+/// How long a refreshed contract spec is kept when not configured
+pub const DEFAULT_CONTRACT_SPEC_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// A contract spec re-fetched from chain after decoding against the cached spec failed
+#[derive(Clone)]
+struct RefreshedContractSpec {
+	/// When the refresh was attempted, used for rate limiting and expiry
+	refreshed_at: Instant,
+	/// The refreshed spec, if the refresh succeeded
+	spec: Option<StellarFormattedContractSpec>,
+}
+
+/// Bounded cache of the contract specs refreshed from chain
+///
+/// Entries are keyed by normalized contract address and expire after the configured TTL. When
+/// the cache is full, the oldest refresh is evicted. Clones share the same entries, so a client
+/// can hand its cache to every filter it creates.
+#[derive(Clone)]
+pub struct ContractSpecCache {
+	entries: Arc<Mutex<HashMap<String, RefreshedContractSpec>>>,
+	max_entries: usize,
+	ttl: Duration,
+}
+
+impl Default for ContractSpecCache {
+	fn default() -> Self {
+		Self::new(
+			DEFAULT_CONTRACT_SPEC_CACHE_MAX_ENTRIES,
+			DEFAULT_CONTRACT_SPEC_CACHE_TTL,
+		)
+	}
+}
+
+impl ContractSpecCache {
+	/// Creates an empty cache
+	///
+	/// # Arguments
+	/// * `max_entries` - Maximum number of refreshed specs kept
+	/// * `ttl` - How long a refreshed spec is kept
+	pub fn new(max_entries: usize, ttl: Duration) -> Self {
+		Self {
+			entries: Arc::new(Mutex::new(HashMap::new())),
+			max_entries,
+			ttl,
+		}
+	}
+
+	fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, RefreshedContractSpec>> {
+		match self.entries.lock() {
+			Ok(entries) => entries,
+			Err(poisoned) => poisoned.into_inner(),
+		}
+	}
+
+	/// Returns the refreshed spec of a contract, if refreshed successfully and not expired
+	///
+	/// # Arguments
+	/// * `address` - Address of the contract
+	pub fn get(&self, address: &str) -> Option<StellarFormattedContractSpec> {
+		self.lock()
+			.get(&normalize_address(address))
+			.filter(|entry| entry.refreshed_at.elapsed() < self.ttl)
+			.and_then(|entry| entry.spec.clone())
+	}
+
+	/// Returns whether the contract was refreshed within the last
+	/// `CONTRACT_SPEC_REFRESH_INTERVAL`, successfully or not
+	///
+	/// # Arguments
+	/// * `address` - Address of the contract
+	pub fn is_rate_limited(&self, address: &str) -> bool {
+		self.lock()
+			.get(&normalize_address(address))
+			.is_some_and(|entry| entry.refreshed_at.elapsed() < CONTRACT_SPEC_REFRESH_INTERVAL)
+	}
+
+	/// Records a refresh attempt, purging expired entries and evicting the oldest refresh
+	/// when the cache is full
+	///
+	/// # Arguments
+	/// * `address` - Address of the contract
+	/// * `spec` - The refreshed spec, or `None` if the refresh failed
+	pub fn insert(&self, address: &str, spec: Option<StellarFormattedContractSpec>) {
+		if self.max_entries == 0 {
+			return;
+		}
+		let key = normalize_address(address);
+		let mut entries = self.lock();
+		entries.retain(|_, entry| entry.refreshed_at.elapsed() < self.ttl);
+		if !entries.contains_key(&key) && entries.len() >= self.max_entries {
+			let oldest = entries
+				.iter()
+				.min_by_key(|(_, entry)| entry.refreshed_at)
+				.map(|(address, _)| address.clone());
+			if let Some(oldest) = oldest {
+				entries.remove(&oldest);
+			}
+		}
+		entries.insert(
+			key,
+			RefreshedContractSpec {
+				refreshed_at: Instant::now(),
+				spec,
+			},
+		);
+	}
+
+	/// Returns the number of cached refreshes, including expired ones not yet purged
+	pub fn len(&self) -> usize {
+		self.lock().len()
+	}
+
+	/// Returns whether no refresh is cached
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+
 /// Represents a mapping between a Stellar event and its transaction hash
 #[derive(Debug)]
 pub struct EventMap {
@@ -45,9 +177,33 @@ pub struct EventMap {
 /// Implementation of the block filter for Stellar blockchain
 pub struct StellarBlockFilter<T> {
 	pub _client: PhantomData<T>,
+	/// Contract specs refreshed from chain after the cached spec failed to decode
+	contract_spec_cache: ContractSpecCache,
+}
+
+impl<T> Default for StellarBlockFilter<T> {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
 impl<T> StellarBlockFilter<T> {
+	/// Creates a filter with its own contract spec cache
+	pub fn new() -> Self {
+		Self::with_contract_spec_cache(ContractSpecCache::default())
+	}
+
+	/// Creates a filter sharing the given contract spec cache
+	///
+	/// # Arguments
+	/// * `contract_spec_cache` - Cache of the contract specs refreshed from chain
+	pub fn with_contract_spec_cache(contract_spec_cache: ContractSpecCache) -> Self {
+		Self {
+			_client: PhantomData,
+			contract_spec_cache,
+		}
+	}
+
 	/// Finds matching transactions based on monitor conditions
 	///
 	/// # Arguments
@@ -174,6 +330,7 @@ impl<T> StellarBlockFilter<T> {
 										indexed: false,
 									},
 								]);
+								tx_params.extend(self.block_params(transaction));
 
 								// Evaluate the expression with transaction parameters
 								match self.evaluate_expression(expr, &tx_params) {
@@ -197,7 +354,9 @@ impl<T> StellarBlockFilter<T> {
 							}
 						} else {
 							// Even with no operations, still evaluate base parameters
-							match self.evaluate_expression(expr, &base_params) {
+							let mut tx_params = base_params.clone();
+							tx_params.extend(self.block_params(transaction));
+							match self.evaluate_expression(expr, &tx_params) {
 								Ok(true) => {
 									matched_transactions.push(TransactionCondition {
 										expression: Some(expr.to_string()),
@@ -389,7 +548,14 @@ impl<T> StellarBlockFilter<T> {
 								) {
 									// Evaluate expression if it exists
 									if let Some(expr) = &condition.expression {
-										match self.evaluate_expression(expr, &param_entries) {
+										match self.evaluate_expression(
+											expr,
+											&[
+												param_entries.clone(),
+												self.block_params(transaction),
+											]
+											.concat(),
+										) {
 											Ok(true) => {
 												matched_functions.push(FunctionCondition {
 													signature: parsed_operation
@@ -454,6 +620,75 @@ impl<T> StellarBlockFilter<T> {
 		}
 	}
 
+	/// Finds matching operations within a transaction
+	///
+	/// Operations are matched by type (e.g. "payment") and, optionally, by an expression over
+	/// the operation's fields (see `get_operation_params`) and the block parameters.
+	///
+	/// # Arguments
+	/// * `transaction` - The transaction to check
+	/// * `monitor` - The monitor containing match conditions
+	/// * `matched_operations` - Vector to store matching operations
+	pub fn find_matching_operations_for_transaction(
+		&self,
+		transaction: &StellarTransaction,
+		monitor: &Monitor,
+		matched_operations: &mut Vec<OperationCondition>,
+	) {
+		if monitor.match_conditions.operations.is_empty() {
+			return;
+		}
+
+		let tx_to_process = match transaction.decoded().and_then(|d| d.envelope.as_ref()) {
+			Some(TransactionEnvelope::Tx(tx)) => tx,
+			Some(TransactionEnvelope::TxFeeBump(tx_fee_bump)) => match &tx_fee_bump.tx.inner_tx {
+				FeeBumpTransactionInnerTx::Tx(inner_tx) => inner_tx,
+			},
+			_ => return,
+		};
+
+		for operation in tx_to_process.tx.operations.iter() {
+			let params = get_operation_params(operation, &tx_to_process.tx.source_account);
+			let operation_type = &params[0].value;
+
+			for condition in &monitor.match_conditions.operations {
+				if !condition
+					.operation_type
+					.trim()
+					.eq_ignore_ascii_case(operation_type)
+				{
+					continue;
+				}
+
+				if let Some(expr) = &condition.expression {
+					match self.evaluate_expression(
+						expr,
+						&[params.clone(), self.block_params(transaction)].concat(),
+					) {
+						Ok(true) => {
+							matched_operations.push(OperationCondition {
+								operation_type: operation_type.clone(),
+								expression: Some(expr.clone()),
+							});
+							break;
+						}
+						Ok(false) => continue,
+						Err(e) => {
+							tracing::error!("Failed to evaluate expression '{}': {}", expr, e);
+							continue;
+						}
+					}
+				} else {
+					matched_operations.push(OperationCondition {
+						operation_type: operation_type.clone(),
+						expression: None,
+					});
+					break;
+				}
+			}
+		}
+	}
+
 	/// Finds matching events for a transaction
 	///
 	/// # Arguments
@@ -498,7 +733,10 @@ impl<T> StellarBlockFilter<T> {
 					match &condition.expression {
 						Some(expr) => {
 							if let Some(args) = &event.args {
-								match self.evaluate_expression(expr, args) {
+								match self.evaluate_expression(
+									expr,
+									&[args.clone(), self.block_params(transaction)].concat(),
+								) {
 									Ok(true) => {
 										matched_events.push(EventCondition {
 											signature: event.signature.clone(),
@@ -682,6 +920,212 @@ impl<T> StellarBlockFilter<T> {
 		decoded_events
 	}
 
+	/// Builds the event filters to fetch a block's events with, when monitors only match on events
+	///
+	/// Monitors with event conditions and no function or operation conditions can only match
+	/// transactions that emitted an event from a monitored contract. For those, the events are
+	/// fetched first, filtered by contract id, and only the transactions that emitted them are
+	/// processed.
+	///
+	/// # Arguments
+	/// * `monitors` - Monitors the block is filtered for
+	///
+	/// # Returns
+	/// The event filters, or `None` if transactions must be processed regardless of their events
+	/// or the monitored contracts exceed the RPC filter limits
+	pub fn event_only_filters(&self, monitors: &[Monitor]) -> Option<Vec<StellarEventFilter>> {
+		let events_only = !monitors.is_empty()
+			&& monitors.iter().all(|monitor| {
+				let conditions = &monitor.match_conditions;
+				!conditions.events.is_empty()
+					&& conditions.functions.is_empty()
+					&& conditions.operations.is_empty()
+			});
+		if !events_only {
+			return None;
+		}
+
+		// Events are only emitted by contracts, so account addresses can never match
+		let mut contract_ids = monitors
+			.iter()
+			.flat_map(|monitor| monitor.addresses.iter())
+			.map(|address| address.address.trim().to_string())
+			.filter(|address| Contract::from_string(address).is_ok())
+			.collect::<Vec<String>>();
+		contract_ids.sort();
+		contract_ids.dedup();
+
+		if contract_ids.len() > StellarEventFilter::MAX_FILTERS * StellarEventFilter::MAX_ENTRIES {
+			return None;
+		}
+
+		Some(
+			contract_ids
+				.chunks(StellarEventFilter::MAX_ENTRIES)
+				.map(|contract_ids| StellarEventFilter {
+					contract_ids: contract_ids.to_vec(),
+					topics: vec![],
+				})
+				.collect(),
+		)
+	}
+
+	/// Finds monitored contracts whose cached spec cannot decode an invoked function
+	///
+	/// A contract upgrade can add or change functions, in which case the spec fetched at
+	/// startup no longer matches the invocations seen on chain.
+	///
+	/// # Arguments
+	/// * `transactions` - Transactions in the block being processed
+	/// * `monitored_addresses` - Normalized addresses being monitored
+	/// * `contract_specs` - Cached contract specs for monitored addresses
+	///
+	/// # Returns
+	/// Addresses of contracts whose spec should be refreshed
+	pub fn find_stale_contract_specs(
+		&self,
+		transactions: &[StellarTransaction],
+		monitored_addresses: &[String],
+		contract_specs: &[(String, StellarFormattedContractSpec)],
+	) -> Vec<String> {
+		let mut stale_addresses: Vec<String> = Vec::new();
+
+		for transaction in transactions {
+			let Some(decoded) = transaction.decoded() else {
+				continue;
+			};
+			let tx = match &decoded.envelope {
+				Some(TransactionEnvelope::Tx(tx)) => tx,
+				Some(TransactionEnvelope::TxFeeBump(tx_fee_bump)) => {
+					match &tx_fee_bump.tx.inner_tx {
+						FeeBumpTransactionInnerTx::Tx(inner_tx) => inner_tx,
+					}
+				}
+				_ => continue,
+			};
+
+			for operation in tx.tx.operations.iter() {
+				let OperationBody::InvokeHostFunction(invoke_host_function) = &operation.body
+				else {
+					continue;
+				};
+				let (parsed_operation, contract_spec) =
+					process_invoke_host_function(invoke_host_function, Some(contract_specs));
+				let address = normalize_address(&parsed_operation.contract_address);
+				if !monitored_addresses.contains(&address) {
+					continue;
+				}
+
+				// Contracts without a cached spec are not refreshed, only stale ones
+				let Some(contract_spec) = contract_spec else {
+					continue;
+				};
+				let decodable = contract_spec.functions.iter().any(|f| {
+					are_same_signature(&f.signature, &parsed_operation.function_signature)
+				});
+				if !decodable
+					&& !stale_addresses
+						.iter()
+						.any(|a| normalize_address(a) == address)
+				{
+					stale_addresses.push(parsed_operation.contract_address.clone());
+				}
+			}
+		}
+
+		stale_addresses
+	}
+
+	/// Finds monitored contracts with a cached spec that emitted events failing to decode
+	///
+	/// # Arguments
+	/// * `events` - Events emitted in the block being processed
+	/// * `monitored_addresses` - Normalized addresses being monitored
+	/// * `contract_specs` - Cached contract specs for monitored addresses
+	///
+	/// # Returns
+	/// Addresses of contracts whose spec should be refreshed
+	pub fn find_stale_event_contract_specs(
+		&self,
+		events: &[StellarEvent],
+		monitored_addresses: &[String],
+		contract_specs: &[(String, StellarFormattedContractSpec)],
+	) -> Vec<String> {
+		let decodes = |xdr: &str, indexed: bool| {
+			base64::engine::general_purpose::STANDARD
+				.decode(xdr)
+				.ok()
+				.and_then(|bytes| parse_xdr_value(&bytes, indexed))
+				.is_some()
+		};
+
+		let mut stale_addresses: Vec<String> = Vec::new();
+		for event in events {
+			let address = normalize_address(&event.contract_id);
+			if !monitored_addresses.contains(&address)
+				|| stale_addresses.contains(&address)
+				|| !contract_specs
+					.iter()
+					.any(|(spec_address, _)| normalize_address(spec_address) == address)
+			{
+				continue;
+			}
+
+			let topics_decode = event
+				.topic_xdr
+				.as_ref()
+				.is_none_or(|topics| topics.iter().skip(1).all(|topic| decodes(topic, true)));
+			let value_decodes = event
+				.value_xdr
+				.as_ref()
+				.is_none_or(|value| decodes(value, false));
+			if !topics_decode || !value_decodes {
+				stale_addresses.push(address);
+			}
+		}
+
+		stale_addresses
+	}
+
+	/// Replaces cached contract specs with any specs refreshed from chain
+	///
+	/// # Arguments
+	/// * `contract_specs` - Contract specs to update in place
+	pub fn apply_refreshed_contract_specs(
+		&self,
+		contract_specs: &mut [(String, StellarFormattedContractSpec)],
+	) {
+		for (address, spec) in contract_specs.iter_mut() {
+			if let Some(refreshed) = self.contract_spec_cache.get(address) {
+				*spec = refreshed;
+			}
+		}
+	}
+
+	/// Builds the ledger-level parameters available to match expressions
+	///
+	/// # Arguments
+	/// * `transaction` - The transaction whose ledger information is used
+	///
+	/// # Returns
+	/// Vector containing the `block_number` and `block_timestamp` parameters
+	pub fn block_params(&self, transaction: &StellarTransaction) -> Vec<StellarMatchParamEntry> {
+		vec![
+			StellarMatchParamEntry {
+				name: "block_number".to_string(),
+				value: transaction.ledger.to_string(),
+				kind: "u32".to_string(),
+				indexed: false,
+			},
+			StellarMatchParamEntry {
+				name: "block_timestamp".to_string(),
+				value: transaction.ledger_close_time.to_string(),
+				kind: "i64".to_string(),
+				indexed: false,
+			},
+		]
+	}
+
 	/// Evaluates a complex matching expression against provided arguments
 	///
 	/// # Arguments
@@ -720,6 +1164,72 @@ impl<T> StellarBlockFilter<T> {
 	}
 }
 
+impl<T: BlockChainClient + StellarClientTrait> StellarBlockFilter<T> {
+	/// Fetches the events of a block
+	///
+	/// # Arguments
+	/// * `client` - The blockchain client to use
+	/// * `sequence` - Sequence of the block
+	/// * `filters` - Event filters applied by the RPC
+	///
+	/// # Returns
+	/// The block's events or a filter error
+	async fn get_events(
+		&self,
+		client: &T,
+		sequence: u32,
+		filters: &[StellarEventFilter],
+	) -> Result<Vec<StellarEvent>, FilterError> {
+		client
+			.get_events(sequence, None, filters)
+			.await
+			.map_err(|e| {
+				FilterError::network_error(
+					format!("Failed to get events for block {}", sequence),
+					Some(e.into()),
+					None,
+				)
+			})
+	}
+
+	/// Re-fetches stale contract specs from chain
+	///
+	/// Each contract is refreshed at most once per `CONTRACT_SPEC_REFRESH_INTERVAL`. Refreshed
+	/// specs are kept in the filter's contract spec cache for subsequent blocks and applied with
+	/// `apply_refreshed_contract_specs`.
+	///
+	/// # Arguments
+	/// * `client` - The blockchain client to fetch specs with
+	/// * `stale_addresses` - Addresses of contracts whose spec failed to decode
+	pub async fn refresh_contract_specs(&self, client: &T, stale_addresses: &[String]) {
+		for address in stale_addresses {
+			if self.contract_spec_cache.is_rate_limited(address) {
+				continue;
+			}
+
+			tracing::warn!(
+				"Failed to decode data of {} with cached contract spec, refreshing spec",
+				address
+			);
+			let spec = match client.get_contract_spec(address).await {
+				Ok(ContractSpec::Stellar(spec)) => Some(StellarFormattedContractSpec::from(spec)),
+				Ok(_) => {
+					tracing::warn!(
+						"Refreshed contract spec for {} is not a Stellar spec",
+						address
+					);
+					None
+				}
+				Err(e) => {
+					tracing::warn!("Failed to refresh contract spec for {}: {}", address, e);
+					None
+				}
+			};
+			self.contract_spec_cache.insert(address, spec);
+		}
+	}
+}
+
 #[async_trait]
 impl<T: BlockChainClient + StellarClientTrait> BlockFilter for StellarBlockFilter<T> {
 	type Client = T;
@@ -754,7 +1264,30 @@ impl<T: BlockChainClient + StellarClientTrait> BlockFilter for StellarBlockFilte
 			}
 		};
 
-		let transactions = match client.get_transactions(stellar_block.sequence, None).await {
+		let event_filters = self.event_only_filters(monitors);
+
+		// Monitors only matching on events need the block's events first, to skip the
+		// transactions that did not emit any
+		let fetched_events = match &event_filters {
+			Some(filters) if filters.is_empty() => {
+				tracing::debug!("No monitored contract can emit events");
+				return Ok(vec![]);
+			}
+			Some(filters) => Some(
+				self.get_events(client, stellar_block.sequence, filters)
+					.await?,
+			),
+			None => None,
+		};
+		if fetched_events
+			.as_ref()
+			.is_some_and(|events| events.is_empty())
+		{
+			tracing::debug!("No events found for block {}", stellar_block.sequence);
+			return Ok(vec![]);
+		}
+
+		let mut transactions = match client.get_transactions(stellar_block.sequence, None).await {
 			Ok(transactions) => transactions,
 			Err(e) => {
 				return Err(FilterError::network_error(
@@ -768,31 +1301,33 @@ impl<T: BlockChainClient + StellarClientTrait> BlockFilter for StellarBlockFilte
 			}
 		};
 
-		if transactions.is_empty() {
-			tracing::debug!("No transactions found for block {}", stellar_block.sequence);
-			return Ok(vec![]);
-		}
-
-		tracing::debug!("Processing {} transaction(s)", transactions.len());
-
-		let events = match client.get_events(stellar_block.sequence, None).await {
-			Ok(events) => events,
-			Err(e) => {
-				return Err(FilterError::network_error(
-					format!("Failed to get events for block {}", stellar_block.sequence),
-					Some(e.into()),
-					None,
-				));
+		let events = match fetched_events {
+			Some(events) => {
+				let transaction_hashes = events
+					.iter()
+					.map(|event| event.transaction_hash.as_str())
+					.collect::<HashSet<&str>>();
+				transactions
+					.retain(|transaction| transaction_hashes.contains(transaction.hash().as_str()));
+				events
+			}
+			None => {
+				if transactions.is_empty() {
+					tracing::debug!("No transactions found for block {}", stellar_block.sequence);
+					return Ok(vec![]);
+				}
+				self.get_events(client, stellar_block.sequence, &[]).await?
 			}
 		};
 
+		tracing::debug!("Processing {} transaction(s)", transactions.len());
 		tracing::debug!("Processing {} event(s)", events.len());
 		tracing::debug!("Processing {} monitor(s)", monitors.len());
 
 		let mut matching_results = Vec::new();
 
 		// Cast contract specs to StellarContractSpec
-		let contract_specs = contract_specs
+		let mut contract_specs = contract_specs
 			.unwrap_or(&[])
 			.iter()
 			.filter_map(|(address, spec)| match spec {
@@ -804,6 +1339,37 @@ impl<T: BlockChainClient + StellarClientTrait> BlockFilter for StellarBlockFilte
 			})
 			.collect::<Vec<(String, StellarFormattedContractSpec)>>();
 
+		// Use specs refreshed in earlier blocks, then refresh any that fail to decode this block
+		self.apply_refreshed_contract_specs(&mut contract_specs);
+		let addresses = network_address_normalizer(network);
+		let all_monitored_addresses = monitors
+			.iter()
+			.flat_map(|m| {
+				m.addresses
+					.iter()
+					.map(|addr| addresses.normalize(&addr.address))
+			})
+			.collect::<Vec<String>>();
+		let mut stale_addresses = self.find_stale_contract_specs(
+			&transactions,
+			&all_monitored_addresses,
+			&contract_specs,
+		);
+		for address in
+			self.find_stale_event_contract_specs(&events, &all_monitored_addresses, &contract_specs)
+		{
+			if !stale_addresses
+				.iter()
+				.any(|a| normalize_address(a) == address)
+			{
+				stale_addresses.push(address);
+			}
+		}
+		if !stale_addresses.is_empty() {
+			self.refresh_contract_specs(client, &stale_addresses).await;
+			self.apply_refreshed_contract_specs(&mut contract_specs);
+		}
+
 		// Process each monitor first
 		for monitor in monitors {
 			tracing::debug!("Processing monitor: {}", monitor.name);
@@ -811,16 +1377,21 @@ impl<T: BlockChainClient + StellarClientTrait> BlockFilter for StellarBlockFilte
 			let monitored_addresses = monitor
 				.addresses
 				.iter()
-				.map(|addr| normalize_address(&addr.address))
+				.map(|addr| addresses.normalize(&addr.address))
 				.collect::<Vec<String>>();
 
 			let decoded_events = self.decode_events(&events, &monitored_addresses, &contract_specs);
 
 			// Then process transactions for this monitor
 			for transaction in &transactions {
+				if !monitor.samples_transaction(transaction.hash()) {
+					continue;
+				}
+
 				let mut matched_transactions = Vec::<TransactionCondition>::new();
 				let mut matched_functions = Vec::<FunctionCondition>::new();
 				let mut matched_events = Vec::<EventCondition>::new();
+				let mut matched_operations = Vec::<OperationCondition>::new();
 				let mut matched_on_args = StellarMatchArguments {
 					events: Some(Vec::new()),
 					functions: Some(Vec::new()),
@@ -849,31 +1420,43 @@ impl<T: BlockChainClient + StellarClientTrait> BlockFilter for StellarBlockFilte
 					&mut matched_on_args,
 				);
 
+				self.find_matching_operations_for_transaction(
+					transaction,
+					monitor,
+					&mut matched_operations,
+				);
+
 				let monitor_conditions = &monitor.match_conditions;
 				let has_event_match =
 					!monitor_conditions.events.is_empty() && !matched_events.is_empty();
 				let has_function_match =
 					!monitor_conditions.functions.is_empty() && !matched_functions.is_empty();
+				let has_operation_match =
+					!monitor_conditions.operations.is_empty() && !matched_operations.is_empty();
 				let has_transaction_match =
 					!monitor_conditions.transactions.is_empty() && !matched_transactions.is_empty();
 
 				let should_match = match (
 					monitor_conditions.events.is_empty(),
 					monitor_conditions.functions.is_empty(),
+					monitor_conditions.operations.is_empty(),
 					monitor_conditions.transactions.is_empty(),
 				) {
 					// Case 1: No conditions defined, match everything
-					(true, true, true) => true,
+					(true, true, true, true) => true,
 
 					// Case 2: Only transaction conditions defined
-					(true, true, false) => has_transaction_match,
+					(true, true, true, false) => has_transaction_match,
 
-					// Case 3: No transaction conditions, match based on events/functions
-					(_, _, true) => has_event_match || has_function_match,
+					// Case 3: No transaction conditions, match based on events/functions/operations
+					(_, _, _, true) => has_event_match || has_function_match || has_operation_match,
 
 					// Case 4: Transaction conditions exist, they must be satisfied along with
-					// events/functions
-					_ => (has_event_match || has_function_match) && has_transaction_match,
+					// events/functions/operations
+					_ => {
+						(has_event_match || has_function_match || has_operation_match)
+							&& has_transaction_match
+					}
 				};
 
 				if should_match {
@@ -900,6 +1483,13 @@ impl<T: BlockChainClient + StellarClientTrait> BlockFilter for StellarBlockFilte
 								.into_iter()
 								.filter(|_| has_transaction_match)
 								.collect(),
+							operations: matched_operations
+								.clone()
+								.into_iter()
+								.filter(|_| has_operation_match)
+								.collect(),
+							aggregates: vec![],
+							percentage_changes: vec![],
 						},
 						matched_on_args: Some(StellarMatchArguments {
 							events: if has_event_match {
@@ -913,6 +1503,7 @@ impl<T: BlockChainClient + StellarClientTrait> BlockFilter for StellarBlockFilte
 								None
 							},
 						}),
+						enrichment: HashMap::new(),
 					})));
 				}
 			}
@@ -944,9 +1535,7 @@ mod tests {
 	};
 
 	fn create_test_filter() -> StellarBlockFilter<()> {
-		StellarBlockFilter::<()> {
-			_client: PhantomData,
-		}
+		StellarBlockFilter::<()>::new()
 	}
 
 	/// Creates a test monitor with customizable parameters
@@ -970,6 +1559,9 @@ mod tests {
 				events: event_conditions,
 				functions: function_conditions,
 				transactions: transaction_conditions,
+				operations: vec![],
+				aggregates: vec![],
+				percentage_changes: vec![],
 			})
 			.build()
 	}
@@ -1283,41 +1875,56 @@ mod tests {
 	}
 
 	#[test]
-	fn test_find_matching_transaction_no_match() {
+	fn test_find_matching_transaction_with_block_params() {
 		let filter = create_test_filter();
 		let mut matched_transactions = Vec::new();
-		let transaction = create_test_transaction(
+		let mut transaction = create_test_transaction(
 			"SUCCESS",
 			"3389e9f0f1a65f19736cacf544c2e825313e8447f569233bb8db39aa607c8889",
 			1,
-			None,
+			Some("150"),
 			None,
 			None,
 			None,
 			false,
 		);
+		transaction.0.ledger = 150;
+		transaction.0.ledger_close_time = 1_700_000_000;
 
 		let monitor = create_test_monitor(
 			vec![],
 			vec![],
 			vec![TransactionCondition {
 				status: TransactionStatus::Success,
-				expression: Some("value > 1000000".to_string()),
+				expression: Some(
+					"block_number > 100 AND block_timestamp >= 1700000000".to_string(),
+				),
 			}],
 			vec![],
 		);
 
+		let block_params = filter.block_params(&transaction);
+		assert_eq!(block_params[0].name, "block_number");
+		assert_eq!(block_params[0].value, "150");
+		assert_eq!(block_params[1].name, "block_timestamp");
+		assert_eq!(block_params[1].value, "1700000000");
+
 		filter.find_matching_transaction(&transaction, &monitor, &mut matched_transactions);
+		assert_eq!(matched_transactions.len(), 1);
 
+		// Ledger below the threshold should not match
+		transaction.0.ledger = 100;
+		matched_transactions.clear();
+		filter.find_matching_transaction(&transaction, &monitor, &mut matched_transactions);
 		assert_eq!(matched_transactions.len(), 0);
 	}
 
 	#[test]
-	fn test_find_matching_transaction_status_mismatch() {
+	fn test_find_matching_transaction_no_match() {
 		let filter = create_test_filter();
 		let mut matched_transactions = Vec::new();
 		let transaction = create_test_transaction(
-			"FAILED",
+			"SUCCESS",
 			"3389e9f0f1a65f19736cacf544c2e825313e8447f569233bb8db39aa607c8889",
 			1,
 			None,
@@ -1332,7 +1939,37 @@ mod tests {
 			vec![],
 			vec![TransactionCondition {
 				status: TransactionStatus::Success,
-				expression: None,
+				expression: Some("value > 1000000".to_string()),
+			}],
+			vec![],
+		);
+
+		filter.find_matching_transaction(&transaction, &monitor, &mut matched_transactions);
+
+		assert_eq!(matched_transactions.len(), 0);
+	}
+
+	#[test]
+	fn test_find_matching_transaction_status_mismatch() {
+		let filter = create_test_filter();
+		let mut matched_transactions = Vec::new();
+		let transaction = create_test_transaction(
+			"FAILED",
+			"3389e9f0f1a65f19736cacf544c2e825313e8447f569233bb8db39aa607c8889",
+			1,
+			None,
+			None,
+			None,
+			None,
+			false,
+		);
+
+		let monitor = create_test_monitor(
+			vec![],
+			vec![],
+			vec![TransactionCondition {
+				status: TransactionStatus::Success,
+				expression: None,
 			}],
 			vec![],
 		);
@@ -1415,6 +2052,9 @@ mod tests {
 			vec![AddressWithSpec {
 				address: normalized_contract_address.clone(),
 				contract_spec: None,
+				contract_spec_path: None,
+				additional_specs: vec![],
+				event_signatures: HashMap::new(),
 			}],
 		);
 
@@ -1494,6 +2134,9 @@ mod tests {
 			vec![AddressWithSpec {
 				address: normalized_contract_address.clone(),
 				contract_spec: None,
+				contract_spec_path: None,
+				additional_specs: vec![],
+				event_signatures: HashMap::new(),
 			}],
 		);
 
@@ -1568,6 +2211,9 @@ mod tests {
 			vec![AddressWithSpec {
 				address: normalized_contract_address.clone(),
 				contract_spec: None,
+				contract_spec_path: None,
+				additional_specs: vec![],
+				event_signatures: HashMap::new(),
 			}],
 		);
 
@@ -1642,6 +2288,9 @@ mod tests {
 			vec![AddressWithSpec {
 				address: normalized_different_address.clone(),
 				contract_spec: None,
+				contract_spec_path: None,
+				additional_specs: vec![],
+				event_signatures: HashMap::new(),
 			}],
 		);
 
@@ -1720,6 +2369,9 @@ mod tests {
 			vec![AddressWithSpec {
 				address: normalized_contract_address.clone(),
 				contract_spec: None,
+				contract_spec_path: None,
+				additional_specs: vec![],
+				event_signatures: HashMap::new(),
 			}],
 		);
 
@@ -1795,6 +2447,9 @@ mod tests {
 			vec![AddressWithSpec {
 				address: normalized_contract_address.clone(),
 				contract_spec: None,
+				contract_spec_path: None,
+				additional_specs: vec![],
+				event_signatures: HashMap::new(),
 			}],
 		);
 
@@ -1839,6 +2494,138 @@ mod tests {
 	// Test cases for find_matching_events_for_transaction method:
 	//////////////////////////////////////////////////////////////////////////////
 
+	#[test]
+	fn test_find_matching_operations_payment_amount_threshold() {
+		let filter = create_test_filter();
+		let monitor = MonitorBuilder::new()
+			.name("test")
+			.operation("payment", Some("amount > 1000000".to_string()))
+			.build();
+
+		let large_payment = create_test_transaction(
+			"SUCCESS",
+			"large",
+			1,
+			Some("5000000"),
+			None,
+			Some("GBZXN7PIRZGNMHGA7MUUUF4GWPY5AYPV6LY4UV2GL6VJGIQRXFDNMADI"),
+			None,
+			false,
+		);
+		let mut matched_operations = Vec::new();
+		filter.find_matching_operations_for_transaction(
+			&large_payment,
+			&monitor,
+			&mut matched_operations,
+		);
+		assert_eq!(matched_operations.len(), 1);
+		assert_eq!(matched_operations[0].operation_type, "payment");
+		assert_eq!(
+			matched_operations[0].expression,
+			Some("amount > 1000000".to_string())
+		);
+
+		let small_payment =
+			create_test_transaction("SUCCESS", "small", 1, Some("500"), None, None, None, false);
+		let mut matched_operations = Vec::new();
+		filter.find_matching_operations_for_transaction(
+			&small_payment,
+			&monitor,
+			&mut matched_operations,
+		);
+		assert!(matched_operations.is_empty());
+	}
+
+	#[test]
+	fn test_find_matching_operations_destination_and_asset() {
+		let filter = create_test_filter();
+		let destination = "GBZXN7PIRZGNMHGA7MUUUF4GWPY5AYPV6LY4UV2GL6VJGIQRXFDNMADI";
+		let monitor = MonitorBuilder::new()
+			.name("test")
+			.operation(
+				"payment",
+				Some(format!(
+					"destination == '{}' AND asset == 'native'",
+					destination
+				)),
+			)
+			.build();
+
+		let transaction = create_test_transaction(
+			"SUCCESS",
+			"hash",
+			1,
+			Some("100"),
+			None,
+			Some(destination),
+			None,
+			true,
+		);
+		let mut matched_operations = Vec::new();
+		filter.find_matching_operations_for_transaction(
+			&transaction,
+			&monitor,
+			&mut matched_operations,
+		);
+		assert_eq!(matched_operations.len(), 1);
+	}
+
+	#[test]
+	fn test_find_matching_operations_type_mismatch() {
+		let filter = create_test_filter();
+		let monitor = MonitorBuilder::new()
+			.name("test")
+			.operation("change_trust", None)
+			.build();
+
+		let payment = create_test_transaction(
+			"SUCCESS",
+			"hash",
+			1,
+			Some("5000000"),
+			None,
+			None,
+			None,
+			false,
+		);
+		let mut matched_operations = Vec::new();
+		filter.find_matching_operations_for_transaction(
+			&payment,
+			&monitor,
+			&mut matched_operations,
+		);
+		assert!(matched_operations.is_empty());
+
+		let monitor = MonitorBuilder::new()
+			.name("test")
+			.operation("invoke_host_function", None)
+			.build();
+		filter.find_matching_operations_for_transaction(
+			&payment,
+			&monitor,
+			&mut matched_operations,
+		);
+		assert!(matched_operations.is_empty());
+
+		let invocation = create_test_transaction(
+			"SUCCESS",
+			"hash",
+			1,
+			None,
+			None,
+			None,
+			Some("invoke_host_function"),
+			false,
+		);
+		filter.find_matching_operations_for_transaction(
+			&invocation,
+			&monitor,
+			&mut matched_operations,
+		);
+		assert_eq!(matched_operations.len(), 1);
+		assert_eq!(matched_operations[0].operation_type, "invoke_host_function");
+	}
+
 	#[test]
 	fn test_find_matching_events_empty_conditions_matches_all() {
 		let filter = create_test_filter();
@@ -1982,6 +2769,53 @@ mod tests {
 		assert_eq!(matched_args.events.as_ref().unwrap().len(), 1);
 	}
 
+	#[test]
+	fn test_find_matching_events_with_block_number_expression() {
+		let filter = create_test_filter();
+		let mut matched_events = Vec::new();
+		let mut matched_args = StellarMatchArguments {
+			events: Some(Vec::new()),
+			functions: Some(Vec::new()),
+		};
+
+		let mut transaction =
+			create_test_transaction("SUCCESS", "tx_hash_123", 1, None, None, None, None, false);
+		transaction.0.ledger = 200;
+
+		let events = vec![create_test_event(
+			"tx_hash_123",
+			"Transfer(address,uint256)",
+			Some(vec![StellarMatchParamEntry {
+				name: "0".to_string(),
+				value: "100".to_string(),
+				kind: "u64".to_string(),
+				indexed: false,
+			}]),
+		)];
+		let monitor = create_test_monitor(
+			vec![EventCondition {
+				signature: "Transfer(address,uint256)".to_string(),
+				expression: Some("0 > 50 AND block_number >= 200".to_string()),
+			}],
+			vec![],
+			vec![],
+			vec![],
+		);
+
+		filter.find_matching_events_for_transaction(
+			&events,
+			&transaction,
+			&monitor,
+			&mut matched_events,
+			&mut matched_args,
+		);
+
+		assert_eq!(matched_events.len(), 1);
+		// Block parameters are only used for evaluation and are not reported as event args
+		let matched = &matched_args.events.as_ref().unwrap()[0];
+		assert_eq!(matched.args.as_ref().unwrap().len(), 1);
+	}
+
 	#[test]
 	fn test_find_matching_events_no_match() {
 		let filter = create_test_filter();
@@ -2073,6 +2907,113 @@ mod tests {
 		assert_eq!(matched_args.events.as_ref().unwrap().len(), 0);
 	}
 
+	//////////////////////////////////////////////////////////////////////////////
+	// Test cases for the contract spec cache:
+	//////////////////////////////////////////////////////////////////////////////
+
+	#[test]
+	fn test_contract_spec_cache_evicts_oldest_when_full() {
+		let cache = ContractSpecCache::new(2, Duration::from_secs(60));
+		cache.insert("CONTRACT_A", Some(StellarFormattedContractSpec::default()));
+		std::thread::sleep(Duration::from_millis(5));
+		cache.insert("CONTRACT_B", Some(StellarFormattedContractSpec::default()));
+		std::thread::sleep(Duration::from_millis(5));
+		cache.insert("CONTRACT_C", Some(StellarFormattedContractSpec::default()));
+
+		assert_eq!(cache.len(), 2);
+		assert!(cache.get("CONTRACT_A").is_none());
+		assert!(cache.get("CONTRACT_B").is_some());
+		assert!(cache.get("CONTRACT_C").is_some());
+	}
+
+	#[test]
+	fn test_contract_spec_cache_expires_entries() {
+		let cache = ContractSpecCache::new(10, Duration::from_millis(10));
+		cache.insert("CONTRACT_A", Some(StellarFormattedContractSpec::default()));
+		assert!(cache.get("contract_a").is_some());
+
+		std::thread::sleep(Duration::from_millis(20));
+		assert!(cache.get("CONTRACT_A").is_none());
+
+		// Expired entries are purged on the next insert
+		cache.insert("CONTRACT_B", None);
+		assert_eq!(cache.len(), 1);
+	}
+
+	#[test]
+	fn test_contract_spec_cache_rate_limits_failed_refreshes() {
+		let cache = ContractSpecCache::default();
+		assert!(!cache.is_rate_limited("CONTRACT_A"));
+
+		cache.insert("CONTRACT_A", None);
+		assert!(cache.is_rate_limited("CONTRACT_A"));
+		assert!(cache.get("CONTRACT_A").is_none());
+	}
+
+	#[test]
+	fn test_filters_share_client_contract_spec_cache() {
+		let cache = ContractSpecCache::default();
+		let first = StellarBlockFilter::<()>::with_contract_spec_cache(cache.clone());
+		let second = StellarBlockFilter::<()>::with_contract_spec_cache(cache.clone());
+		first
+			.contract_spec_cache
+			.insert("CONTRACT_A", Some(StellarFormattedContractSpec::default()));
+
+		let mut contract_specs = vec![(
+			"CONTRACT_A".to_string(),
+			StellarFormattedContractSpec {
+				functions: vec![StellarContractFunction::default()],
+			},
+		)];
+		second.apply_refreshed_contract_specs(&mut contract_specs);
+		assert!(contract_specs[0].1.functions.is_empty());
+
+		// A filter with its own cache does not see the refresh
+		assert!(create_test_filter().contract_spec_cache.is_empty());
+	}
+
+	#[test]
+	fn test_find_stale_event_contract_specs() {
+		let filter = create_test_filter();
+		let contract_address = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
+		let monitored_addresses = vec![normalize_address(contract_address)];
+		let contract_specs = vec![(
+			contract_address.to_string(),
+			StellarFormattedContractSpec::default(),
+		)];
+		let event_name = encode_event_name("Transfer");
+
+		let decodable = create_test_stellar_event(
+			contract_address,
+			"tx_hash_123",
+			vec![event_name.clone()],
+			None,
+		);
+		assert!(filter
+			.find_stale_event_contract_specs(&[decodable], &monitored_addresses, &contract_specs)
+			.is_empty());
+
+		let undecodable = create_test_stellar_event(
+			contract_address,
+			"tx_hash_123",
+			vec![event_name],
+			Some("not-base64!".to_string()),
+		);
+		assert_eq!(
+			filter.find_stale_event_contract_specs(
+				&[undecodable.clone(), undecodable.clone()],
+				&monitored_addresses,
+				&contract_specs
+			),
+			vec![normalize_address(contract_address)]
+		);
+
+		// Contracts without a cached spec are not refreshed
+		assert!(filter
+			.find_stale_event_contract_specs(&[undecodable], &monitored_addresses, &[])
+			.is_empty());
+	}
+
 	//////////////////////////////////////////////////////////////////////////////
 	// Test cases for decode_event method:
 	//////////////////////////////////////////////////////////////////////////////
@@ -2188,6 +3129,106 @@ mod tests {
 		assert!(!args[0].indexed);
 	}
 
+	//////////////////////////////////////////////////////////////////////////////
+	// Test cases for event_only_filters method:
+	//////////////////////////////////////////////////////////////////////////////
+
+	fn create_test_address(address: &str) -> AddressWithSpec {
+		AddressWithSpec {
+			address: address.to_string(),
+			contract_spec: None,
+			contract_spec_path: None,
+			additional_specs: vec![],
+			event_signatures: HashMap::new(),
+		}
+	}
+
+	fn create_test_event_condition() -> EventCondition {
+		EventCondition {
+			signature: "Transfer(Address,Address,I128)".to_string(),
+			expression: None,
+		}
+	}
+
+	#[test]
+	fn test_event_only_filters_chunks_contract_ids() {
+		let filter = create_test_filter();
+		let contract_ids = (0..7u8)
+			.map(|i| Contract([i; 32]).to_string())
+			.collect::<Vec<String>>();
+		let account = StrPublicKey([0; 32]).to_string();
+
+		let monitor = create_test_monitor(
+			vec![create_test_event_condition()],
+			vec![],
+			vec![],
+			contract_ids
+				.iter()
+				.chain(std::iter::once(&account))
+				.map(|address| create_test_address(address))
+				.collect(),
+		);
+
+		let filters = filter.event_only_filters(&[monitor]).unwrap();
+		assert_eq!(filters.len(), 2);
+		assert_eq!(
+			filters[0].contract_ids.len(),
+			StellarEventFilter::MAX_ENTRIES
+		);
+		assert_eq!(filters[1].contract_ids.len(), 2);
+
+		// Account addresses cannot emit events
+		let mut filtered_ids = filters
+			.iter()
+			.flat_map(|filter| filter.contract_ids.clone())
+			.collect::<Vec<String>>();
+		filtered_ids.sort();
+		let mut expected_ids = contract_ids;
+		expected_ids.sort();
+		assert_eq!(filtered_ids, expected_ids);
+	}
+
+	#[test]
+	fn test_event_only_filters_requires_event_only_monitors() {
+		let filter = create_test_filter();
+		let address = create_test_address(&Contract([1; 32]).to_string());
+
+		let function_monitor = create_test_monitor(
+			vec![create_test_event_condition()],
+			vec![FunctionCondition {
+				signature: "transfer(Address,Address,I128)".to_string(),
+				expression: None,
+			}],
+			vec![],
+			vec![address.clone()],
+		);
+		assert!(filter.event_only_filters(&[function_monitor]).is_none());
+
+		let unconditional_monitor = create_test_monitor(vec![], vec![], vec![], vec![address]);
+		assert!(filter
+			.event_only_filters(&[unconditional_monitor])
+			.is_none());
+
+		assert!(filter.event_only_filters(&[]).is_none());
+	}
+
+	#[test]
+	fn test_event_only_filters_exceeding_rpc_limits() {
+		let filter = create_test_filter();
+		let addresses = (0..=(StellarEventFilter::MAX_FILTERS * StellarEventFilter::MAX_ENTRIES)
+			as u8)
+			.map(|i| create_test_address(&Contract([i; 32]).to_string()))
+			.collect();
+
+		let monitor = create_test_monitor(
+			vec![create_test_event_condition()],
+			vec![],
+			vec![],
+			addresses,
+		);
+		assert!(filter.event_only_filters(&[monitor]).is_none());
+	}
+
 	//////////////////////////////////////////////////////////////////////////////
 	// Test cases for evaluate_expression method:
 	//////////////////////////////////////////////////////////////////////////////
@@ -2277,6 +3318,86 @@ mod tests {
 			.unwrap());
 	}
 
+	#[test]
+	fn test_evaluate_expression_bytes_length_and_prefix() {
+		let filter = create_test_filter();
+		let args = vec![
+			StellarMatchParamEntry {
+				name: "payload".to_string(),
+				value: format!("a9059cbb{}", "00".repeat(64)),
+				kind: "Bytes".to_string(),
+				indexed: false,
+			},
+			StellarMatchParamEntry {
+				name: "memo".to_string(),
+				value: "hello world".to_string(),
+				kind: "String".to_string(),
+				indexed: false,
+			},
+			StellarMatchParamEntry {
+				name: "amount".to_string(),
+				value: "100".to_string(),
+				kind: "I128".to_string(),
+				indexed: false,
+			},
+		];
+
+		// Length thresholds
+		assert!(filter
+			.evaluate_expression("length(payload) == 68", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("length(payload) > 64", &args)
+			.unwrap());
+		assert!(!filter
+			.evaluate_expression("length(payload) > 100", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("length(memo) <= 11", &args)
+			.unwrap());
+
+		// Prefix equality on hex inputs, with or without the 0x prefix
+		assert!(filter
+			.evaluate_expression("prefix(payload, 4) == 0xa9059cbb", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("prefix(payload, 4) == 'A9059CBB'", &args)
+			.unwrap());
+		assert!(!filter
+			.evaluate_expression("prefix(payload, 4) == 0x095ea7b3", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("prefix(memo, 5) == 'hello'", &args)
+			.unwrap());
+
+		// Functions are only supported for bytes and string kinds
+		assert!(matches!(
+			filter.evaluate_expression("length(amount) > 1", &args),
+			Err(EvaluationError::TypeMismatch(_))
+		));
+	}
+
+	#[test]
+	fn test_evaluate_expression_hash_functions() {
+		let filter = create_test_filter();
+		let args = vec![StellarMatchParamEntry {
+			name: "role".to_string(),
+			value: "9f2df0fed2c77648de5860a4cc508cd0818c85b8b8a1ab4ceeef8d981c8956a6".to_string(),
+			kind: "Bytes".to_string(),
+			indexed: false,
+		}];
+
+		assert!(filter
+			.evaluate_expression("role == keccak256(\"MINTER_ROLE\")", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("role != keccak256('PAUSER_ROLE')", &args)
+			.unwrap());
+		assert!(!filter
+			.evaluate_expression("role == sha256('MINTER_ROLE')", &args)
+			.unwrap());
+	}
+
 	#[test]
 	fn test_evaluate_expression_basic_field_access() {
 		let filter = create_test_filter();
@@ -14,14 +14,17 @@ use soroban_spec::read;
 use std::collections::BTreeMap;
 use stellar_strkey::{ed25519::PublicKey as StrkeyPublicKey, Contract};
 use stellar_xdr::curr::{
-	AccountId, ContractExecutable, Hash, HostFunction, Int128Parts, Int256Parts,
-	InvokeHostFunctionOp, LedgerEntryData, LedgerKey, LedgerKeyContractCode, Limits, PublicKey,
-	ReadXdr, ScAddress, ScMapEntry, ScSpecEntry, ScSpecTypeDef, ScVal, UInt128Parts, UInt256Parts,
+	AccountId, Asset, ChangeTrustAsset, ContractExecutable, Hash, HostFunction, Int128Parts,
+	Int256Parts, InvokeContractArgs, InvokeHostFunctionOp, LedgerEntryData, LedgerKey,
+	LedgerKeyContractCode, Limits, Memo, MuxedAccount, Operation, OperationBody, OperationType,
+	Preconditions, PublicKey, ReadXdr, ScAddress, ScMapEntry, ScSpecEntry, ScSpecTypeDef, ScSymbol,
+	ScVal, SequenceNumber, StringM, Transaction, TransactionEnvelope, TransactionExt,
+	TransactionV1Envelope, UInt128Parts, UInt256Parts, Uint256, VecM,
 };
 
 use crate::models::{
 	StellarContractFunction, StellarContractInput, StellarDecodedParamEntry,
-	StellarFormattedContractSpec, StellarParsedOperationResult,
+	StellarFormattedContractSpec, StellarMatchParamEntry, StellarParsedOperationResult,
 };
 
 /// Represents all possible Stellar smart contract types
@@ -694,6 +697,156 @@ pub fn process_invoke_host_function(
 	}
 }
 
+/// Returns the snake_case name of a Stellar operation type.
+///
+/// # Arguments
+/// * `body` - The operation body
+///
+/// # Returns
+/// The operation type name (e.g., "payment", "change_trust", "invoke_host_function")
+pub fn get_operation_type(body: &OperationBody) -> String {
+	operation_name_to_snake_case(body.name())
+}
+
+/// Checks whether a string names a Stellar operation type (e.g., "payment").
+///
+/// # Arguments
+/// * `operation_type` - The operation type name to check, in snake_case
+///
+/// # Returns
+/// `true` if the name matches a known operation type, `false` otherwise
+pub fn is_operation_type(operation_type: &str) -> bool {
+	OperationType::VARIANTS_STR
+		.iter()
+		.any(|name| operation_name_to_snake_case(name).eq_ignore_ascii_case(operation_type.trim()))
+}
+
+/// Converts an XDR operation name (e.g., "ChangeTrust") to snake_case (e.g., "change_trust").
+fn operation_name_to_snake_case(name: &str) -> String {
+	let mut operation_type = String::with_capacity(name.len() + 4);
+	for (index, c) in name.chars().enumerate() {
+		if c.is_ascii_uppercase() {
+			if index > 0 {
+				operation_type.push('_');
+			}
+			operation_type.push(c.to_ascii_lowercase());
+		} else {
+			operation_type.push(c);
+		}
+	}
+	operation_type
+}
+
+/// Formats a Stellar asset as `native` or `CODE:ISSUER`.
+///
+/// # Arguments
+/// * `asset` - The asset to format
+///
+/// # Returns
+/// The formatted asset string
+pub fn format_asset(asset: &Asset) -> String {
+	match asset {
+		Asset::Native => "native".to_string(),
+		Asset::CreditAlphanum4(asset) => format!("{}:{}", asset.asset_code, asset.issuer),
+		Asset::CreditAlphanum12(asset) => format!("{}:{}", asset.asset_code, asset.issuer),
+	}
+}
+
+/// Extracts the fields of a Stellar operation as match parameters.
+///
+/// Every operation exposes `type` and `source`. Operations that move or reference assets
+/// additionally expose fields such as `destination`, `asset` and `amount` (in stroops).
+/// For path payments, `asset` and `amount` describe the exact leg of the payment
+/// (destination for strict receive, source for strict send).
+///
+/// # Arguments
+/// * `operation` - The operation to extract fields from
+/// * `tx_source` - The transaction source account, used when the operation has none
+///
+/// # Returns
+/// A vector of match parameter entries describing the operation
+pub fn get_operation_params(
+	operation: &Operation,
+	tx_source: &MuxedAccount,
+) -> Vec<StellarMatchParamEntry> {
+	let param = |name: &str, value: String, kind: &str| StellarMatchParamEntry {
+		name: name.to_string(),
+		value,
+		kind: kind.to_string(),
+		indexed: false,
+	};
+
+	let source = operation.source_account.as_ref().unwrap_or(tx_source);
+	let mut params = vec![
+		param("type", get_operation_type(&operation.body), "string"),
+		param("source", source.to_string(), "address"),
+	];
+
+	match &operation.body {
+		OperationBody::CreateAccount(op) => params.extend([
+			param("destination", op.destination.to_string(), "address"),
+			param("amount", op.starting_balance.to_string(), "i64"),
+		]),
+		OperationBody::Payment(op) => params.extend([
+			param("destination", op.destination.to_string(), "address"),
+			param("asset", format_asset(&op.asset), "string"),
+			param("amount", op.amount.to_string(), "i64"),
+		]),
+		OperationBody::PathPaymentStrictReceive(op) => params.extend([
+			param("destination", op.destination.to_string(), "address"),
+			param("asset", format_asset(&op.dest_asset), "string"),
+			param("amount", op.dest_amount.to_string(), "i64"),
+			param("send_asset", format_asset(&op.send_asset), "string"),
+			param("send_max", op.send_max.to_string(), "i64"),
+		]),
+		OperationBody::PathPaymentStrictSend(op) => params.extend([
+			param("destination", op.destination.to_string(), "address"),
+			param("asset", format_asset(&op.send_asset), "string"),
+			param("amount", op.send_amount.to_string(), "i64"),
+			param("dest_asset", format_asset(&op.dest_asset), "string"),
+			param("dest_min", op.dest_min.to_string(), "i64"),
+		]),
+		OperationBody::ChangeTrust(op) => {
+			let asset = match &op.line {
+				ChangeTrustAsset::Native => "native".to_string(),
+				ChangeTrustAsset::CreditAlphanum4(asset) => {
+					format!("{}:{}", asset.asset_code, asset.issuer)
+				}
+				ChangeTrustAsset::CreditAlphanum12(asset) => {
+					format!("{}:{}", asset.asset_code, asset.issuer)
+				}
+				ChangeTrustAsset::PoolShare(_) => "liquidity_pool".to_string(),
+			};
+			params.extend([
+				param("asset", asset, "string"),
+				param("limit", op.limit.to_string(), "i64"),
+			]);
+		}
+		OperationBody::AccountMerge(destination) => {
+			params.push(param("destination", destination.to_string(), "address"));
+		}
+		OperationBody::Clawback(op) => params.extend([
+			param("from", op.from.to_string(), "address"),
+			param("asset", format_asset(&op.asset), "string"),
+			param("amount", op.amount.to_string(), "i64"),
+		]),
+		OperationBody::InvokeHostFunction(op) => {
+			let (parsed_operation, _) = process_invoke_host_function(op, None);
+			params.extend([
+				param(
+					"contract_address",
+					parsed_operation.contract_address,
+					"address",
+				),
+				param("function_name", parsed_operation.function_name, "string"),
+			]);
+		}
+		_ => {}
+	}
+
+	params
+}
+
 /// Checks if a string is a valid Stellar address.
 ///
 /// # Arguments
@@ -937,6 +1090,53 @@ pub fn get_contract_instance_ledger_key(contract_id: &str) -> Result<LedgerKey,
 	))
 }
 
+/// Creates a transaction envelope invoking a contract function without arguments.
+///
+/// The envelope is only meant to be simulated, so it is unsigned and sent from the zero
+/// account.
+///
+/// # Arguments
+/// * `contract_id` - The contract ID in Stellar strkey format (starts with 'C')
+/// * `function` - The name of the function to invoke
+///
+/// # Returns
+/// A Result containing the TransactionEnvelope if successful, or an error if the contract ID or
+/// function name is invalid
+pub fn get_contract_call_transaction_envelope(
+	contract_id: &str,
+	function: &str,
+) -> Result<TransactionEnvelope, anyhow::Error> {
+	let contract = Contract::from_string(contract_id.to_uppercase().as_str())
+		.map_err(|e| anyhow::anyhow!("Failed to decode contract ID: {}", e))?;
+	let function_name = StringM::try_from(function)
+		.map_err(|e| anyhow::anyhow!("Invalid function name {}: {}", function, e))?;
+
+	let operation = Operation {
+		source_account: None,
+		body: OperationBody::InvokeHostFunction(InvokeHostFunctionOp {
+			host_function: HostFunction::InvokeContract(InvokeContractArgs {
+				contract_address: ScAddress::Contract(Hash(contract.0)),
+				function_name: ScSymbol(function_name),
+				args: VecM::default(),
+			}),
+			auth: VecM::default(),
+		}),
+	};
+
+	Ok(TransactionEnvelope::Tx(TransactionV1Envelope {
+		tx: Transaction {
+			source_account: MuxedAccount::Ed25519(Uint256([0; 32])),
+			fee: 100,
+			seq_num: SequenceNumber(0),
+			cond: Preconditions::None,
+			memo: Memo::None,
+			operations: vec![operation].try_into()?,
+			ext: TransactionExt::V0,
+		},
+		signatures: VecM::default(),
+	}))
+}
+
 /// Extracts contract code ledger key from a contract's XDR-encoded executable.
 ///
 /// # Arguments
@@ -1286,6 +1486,31 @@ mod tests {
 		assert!(result.is_err());
 	}
 
+	#[test]
+	fn test_get_contract_call_transaction_envelope() {
+		let contract_id = "CA6PUJLBYKZKUEKLZJMKBZLEKP2OTHANDEOWSFF44FTSYLKQPIICCJBE";
+		let envelope = get_contract_call_transaction_envelope(contract_id, "symbol").unwrap();
+
+		let TransactionEnvelope::Tx(envelope) = envelope else {
+			panic!("Expected a transaction envelope");
+		};
+		assert_eq!(envelope.tx.operations.len(), 1);
+		match &envelope.tx.operations[0].body {
+			OperationBody::InvokeHostFunction(InvokeHostFunctionOp {
+				host_function: HostFunction::InvokeContract(args),
+				..
+			}) => {
+				assert_eq!(args.contract_address.to_string(), contract_id);
+				assert_eq!(args.function_name.to_string(), "symbol");
+				assert!(args.args.is_empty());
+			}
+			_ => panic!("Expected a contract invocation"),
+		}
+
+		assert!(get_contract_call_transaction_envelope("invalid_contract_id", "symbol").is_err());
+		assert!(get_contract_call_transaction_envelope(contract_id, &"a".repeat(33)).is_err());
+	}
+
 	#[test]
 	fn test_get_contract_code_ledger_key() {
 		// Test valid WASM hash
@@ -2433,4 +2658,73 @@ mod tests {
 			StellarType::Map(_, _)
 		));
 	}
+
+	#[test]
+	fn test_get_operation_type() {
+		assert_eq!(get_operation_type(&OperationBody::Inflation), "inflation");
+		assert_eq!(
+			get_operation_type(&OperationBody::EndSponsoringFutureReserves),
+			"end_sponsoring_future_reserves"
+		);
+		assert!(is_operation_type("payment"));
+		assert!(is_operation_type("change_trust"));
+		assert!(is_operation_type("Invoke_Host_Function"));
+		assert!(is_operation_type("extend_footprint_ttl"));
+		assert!(!is_operation_type("transfer"));
+	}
+
+	#[test]
+	fn test_get_operation_params_payment() {
+		let issuer = AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([3; 32])));
+		let operation = Operation {
+			source_account: None,
+			body: OperationBody::Payment(stellar_xdr::curr::PaymentOp {
+				destination: MuxedAccount::Ed25519(Uint256([2; 32])),
+				asset: Asset::CreditAlphanum4(stellar_xdr::curr::AlphaNum4 {
+					asset_code: stellar_xdr::curr::AssetCode4(*b"USDC"),
+					issuer: issuer.clone(),
+				}),
+				amount: 5_000_000,
+			}),
+		};
+		let tx_source = MuxedAccount::Ed25519(Uint256([1; 32]));
+
+		let params = get_operation_params(&operation, &tx_source);
+		let get = |name: &str| {
+			params
+				.iter()
+				.find(|p| p.name == name)
+				.map(|p| (p.value.clone(), p.kind.clone()))
+				.unwrap()
+		};
+
+		assert_eq!(get("type"), ("payment".to_string(), "string".to_string()));
+		assert_eq!(get("source").0, tx_source.to_string());
+		assert_eq!(
+			get("destination").0,
+			MuxedAccount::Ed25519(Uint256([2; 32])).to_string()
+		);
+		assert_eq!(get("asset").0, format!("USDC:{}", issuer));
+		assert_eq!(get("amount"), ("5000000".to_string(), "i64".to_string()));
+	}
+
+	#[test]
+	fn test_get_operation_params_change_trust_uses_operation_source() {
+		let operation_source = MuxedAccount::Ed25519(Uint256([4; 32]));
+		let operation = Operation {
+			source_account: Some(operation_source.clone()),
+			body: OperationBody::ChangeTrust(stellar_xdr::curr::ChangeTrustOp {
+				line: ChangeTrustAsset::Native,
+				limit: 1000,
+			}),
+		};
+
+		let params = get_operation_params(&operation, &MuxedAccount::Ed25519(Uint256([1; 32])));
+		let names: Vec<_> = params.iter().map(|p| p.name.as_str()).collect();
+		assert_eq!(names, vec!["type", "source", "asset", "limit"]);
+		assert_eq!(params[0].value, "change_trust");
+		assert_eq!(params[1].value, operation_source.to_string());
+		assert_eq!(params[2].value, "native");
+		assert_eq!(params[3].value, "1000");
+	}
 }
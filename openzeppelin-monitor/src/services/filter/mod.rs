@@ -3,8 +3,12 @@
 //! Implements the core filtering logic for monitoring blockchain activity:
 //! - Block filtering for different chain types
 //! - Match handling and processing
+//! - Match enrichment with read-only contract calls
+//! - Match severity computed from the monitor's severity rules
 //! - Chain-specific helper functions
 
+mod address;
+mod enrichment;
 mod error;
 #[cfg(fuzzing)]
 pub mod expression;
@@ -12,14 +16,22 @@ pub mod expression;
 mod expression;
 mod filter_match;
 mod filters;
+mod severity;
 
+pub use address::{
+	address_normalizer, network_address_normalizer, AddressNormalizer, EvmAddressNormalizer,
+	ExactAddressNormalizer, StellarAddressNormalizer,
+};
+pub use enrichment::{EnrichmentCache, DEFAULT_ENRICHMENT_CACHE_MAX_ENTRIES};
 pub use error::FilterError;
 pub use filter_match::handle_match;
+pub use severity::{match_severity, SEVERITY_VARIABLE};
 
 pub use filters::{
-	evm::helpers as evm_helpers, stellar::helpers as stellar_helpers, BlockFilter, EVMArgs,
-	EVMBlockFilter, EVMConditionEvaluator, EventMap, FilterService, StellarArgs,
-	StellarBlockFilter, StellarConditionEvaluator,
+	evm::helpers as evm_helpers, stellar::helpers as stellar_helpers, BlockFilter,
+	ContractSpecCache, EVMArgs, EVMBlockFilter, EVMConditionEvaluator, EventMap, FilterService,
+	MidnightBlockFilter, StellarArgs, StellarBlockFilter, StellarConditionEvaluator,
+	DEFAULT_CONTRACT_SPEC_CACHE_MAX_ENTRIES, DEFAULT_CONTRACT_SPEC_CACHE_TTL,
 };
 
 pub use expression::{ComparisonOperator, ConditionEvaluator, EvaluationError, LiteralValue};
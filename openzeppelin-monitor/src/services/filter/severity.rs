@@ -0,0 +1,226 @@
+//! Severity of monitor matches.
+//!
+//! Monitors may define `severity_rules` assigning a severity, such as `warning` or `critical`,
+//! to each match. The severity is exposed to notification templates as the `severity`
+//! variable and selects the message template of the triggers named after it.
+
+use crate::{
+	models::{MatchConditions, MonitorMatch, SeverityRule},
+	services::filter::{
+		expression, ConditionEvaluator, EVMConditionEvaluator, StellarConditionEvaluator,
+	},
+};
+
+/// Variable holding the severity of a match
+pub const SEVERITY_VARIABLE: &str = "severity";
+
+/// Computes the severity of a match from the severity rules of its monitor
+///
+/// # Arguments
+/// * `monitor_match` - The match to compute the severity of
+///
+/// # Returns
+/// * `Option<String>` - The severity of the first rule applying to the match, or `None` if no
+///   rule applies
+pub fn match_severity(monitor_match: &MonitorMatch) -> Option<String> {
+	match monitor_match {
+		MonitorMatch::EVM(evm_match) => {
+			let matched_args = evm_match.matched_on_args.as_ref();
+			select_severity(
+				&evm_match.monitor.severity_rules,
+				&evm_match.matched_on,
+				|signature| {
+					matched_args
+						.into_iter()
+						.flat_map(|args| args.functions.iter().chain(args.events.iter()))
+						.flatten()
+						.find(|params| params.signature == signature)
+						.and_then(|params| params.args.as_deref())
+						.unwrap_or_default()
+				},
+				|condition, args| {
+					evaluate_rule_expression(condition, &EVMConditionEvaluator::new(args))
+				},
+			)
+		}
+		MonitorMatch::Stellar(stellar_match) => {
+			let matched_args = stellar_match.matched_on_args.as_ref();
+			select_severity(
+				&stellar_match.monitor.severity_rules,
+				&stellar_match.matched_on,
+				|signature| {
+					matched_args
+						.into_iter()
+						.flat_map(|args| args.functions.iter().chain(args.events.iter()))
+						.flatten()
+						.find(|params| params.signature == signature)
+						.and_then(|params| params.args.as_deref())
+						.unwrap_or_default()
+				},
+				|condition, args| {
+					evaluate_rule_expression(condition, &StellarConditionEvaluator::new(args))
+				},
+			)
+		}
+	}
+}
+
+/// Returns the severity of the first rule applying to one of the matched functions or events
+///
+/// # Arguments
+/// * `rules` - The severity rules of the monitor
+/// * `matched_on` - The conditions the match was made on
+/// * `args_of` - Returns the arguments of the matched function or event with a signature
+/// * `evaluate` - Evaluates an expression against arguments
+fn select_severity<'a, P: 'a>(
+	rules: &[SeverityRule],
+	matched_on: &MatchConditions,
+	args_of: impl Fn(&str) -> &'a [P],
+	evaluate: impl Fn(&str, &'a [P]) -> bool,
+) -> Option<String> {
+	let signatures: Vec<&str> = matched_on
+		.functions
+		.iter()
+		.map(|function| function.signature.as_str())
+		.chain(
+			matched_on
+				.events
+				.iter()
+				.map(|event| event.signature.as_str()),
+		)
+		.collect();
+
+	rules
+		.iter()
+		.find(|rule| {
+			if rule.signature.is_none() && rule.expression.is_none() {
+				return true;
+			}
+			signatures.iter().any(|signature| {
+				rule.signature
+					.as_deref()
+					.is_none_or(|rule_signature| rule_signature == *signature)
+					&& rule
+						.expression
+						.as_deref()
+						.is_none_or(|condition| evaluate(condition, args_of(signature)))
+			})
+		})
+		.map(|rule| rule.severity.clone())
+}
+
+/// Evaluates the expression of a severity rule, errors counting as not applying
+fn evaluate_rule_expression(condition: &str, evaluator: &impl ConditionEvaluator) -> bool {
+	let parsed = match expression::parse(condition) {
+		Ok(parsed) => parsed,
+		Err(e) => {
+			tracing::warn!("Failed to parse severity expression '{}': {}", condition, e);
+			return false;
+		}
+	};
+	expression::evaluate(&parsed, evaluator).unwrap_or_else(|e| {
+		tracing::debug!(
+			"Failed to evaluate severity expression '{}': {}",
+			condition,
+			e
+		);
+		false
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{
+			EVMMatchArguments, EVMMatchParamEntry, EVMMatchParamsMap, EVMMonitorMatch,
+			EventCondition, FunctionCondition, Monitor,
+		},
+		utils::tests::{
+			builders::evm::monitor::MonitorBuilder, evm::transaction::TransactionBuilder,
+		},
+	};
+	use std::collections::HashMap;
+
+	fn create_transfer_match(monitor: Monitor, value: &str) -> MonitorMatch {
+		let signature = "Transfer(address,address,uint256)".to_string();
+		MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor,
+			transaction: TransactionBuilder::new().build(),
+			receipt: None,
+			revert_reason: None,
+			logs: None,
+			block_timestamp: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions {
+				events: vec![EventCondition {
+					signature: signature.clone(),
+					expression: None,
+				}],
+				..Default::default()
+			},
+			matched_on_args: Some(EVMMatchArguments {
+				functions: None,
+				events: Some(vec![EVMMatchParamsMap {
+					signature,
+					args: Some(vec![EVMMatchParamEntry {
+						name: "value".to_string(),
+						value: value.to_string(),
+						kind: "uint256".to_string(),
+						indexed: false,
+					}]),
+					hex_signature: None,
+				}]),
+			}),
+			enrichment: HashMap::new(),
+		}))
+	}
+
+	#[test]
+	fn test_match_severity_selects_first_applying_rule() {
+		let monitor = MonitorBuilder::new()
+			.severity_rule(
+				"critical",
+				Some("Transfer(address,address,uint256)"),
+				Some("value > 1000"),
+			)
+			.severity_rule("info", None, None)
+			.build();
+
+		assert_eq!(
+			match_severity(&create_transfer_match(monitor.clone(), "5000")),
+			Some("critical".to_string())
+		);
+		assert_eq!(
+			match_severity(&create_transfer_match(monitor, "10")),
+			Some("info".to_string())
+		);
+	}
+
+	#[test]
+	fn test_match_severity_without_applying_rule() {
+		let monitor = MonitorBuilder::new()
+			.severity_rule("critical", Some("Approval(address,address,uint256)"), None)
+			.severity_rule("warning", None, Some("value > 1000"))
+			.build();
+		assert_eq!(match_severity(&create_transfer_match(monitor, "10")), None);
+
+		let monitor = MonitorBuilder::new().build();
+		assert_eq!(match_severity(&create_transfer_match(monitor, "10")), None);
+	}
+
+	#[test]
+	fn test_match_severity_rule_on_function_without_args() {
+		let monitor = MonitorBuilder::new()
+			.severity_rule("warning", Some("pause()"), None)
+			.build();
+		let mut monitor_match = create_transfer_match(monitor, "10");
+		if let MonitorMatch::EVM(evm_match) = &mut monitor_match {
+			evm_match.matched_on.functions.push(FunctionCondition {
+				signature: "pause()".to_string(),
+				expression: None,
+			});
+		}
+		assert_eq!(match_severity(&monitor_match), Some("warning".to_string()));
+	}
+}
@@ -0,0 +1,241 @@
+//! Audit log of fired notifications.
+//!
+//! Keeps a durable, append-only record of every notification sent, with its monitor,
+//! trigger, match and outcome. The audit log is separate from the operational logs, and
+//! failing to write a record never fails the notification.
+
+use async_trait::async_trait;
+use chrono::{SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+	env,
+	path::{Path, PathBuf},
+};
+use tokio::{fs::OpenOptions, io::AsyncWriteExt, sync::Mutex};
+
+use crate::models::{MonitorMatch, Trigger, TriggerType};
+
+/// Environment variable setting the path of the notification audit log file
+pub const AUDIT_LOG_PATH_ENV: &str = "NOTIFICATION_AUDIT_LOG_PATH";
+
+/// Outcome of an audited notification
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditOutcome {
+	/// The notification was sent
+	Success,
+	/// The notification could not be sent
+	Failure,
+}
+
+/// Record of a fired notification
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditRecord {
+	/// RFC 3339 time the notification completed at
+	pub timestamp: String,
+	/// Name of the monitor that matched
+	pub monitor: String,
+	/// Name of the trigger that sent the notification
+	pub trigger: String,
+	/// Type of the trigger, e.g. `slack` or `webhook`
+	pub trigger_type: String,
+	/// Deterministic identifier of the match, see [`MonitorMatch::match_id`]
+	pub match_id: String,
+	/// Whether the notification was sent
+	pub outcome: AuditOutcome,
+	/// Error of a failed notification
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub error: Option<String>,
+}
+
+impl AuditRecord {
+	/// Creates the record of a notification completed now
+	///
+	/// # Arguments
+	/// * `trigger` - Trigger that sent the notification
+	/// * `monitor_match` - Match the notification was sent for
+	/// * `error` - Error of the notification, `None` if it was sent
+	pub fn new(trigger: &Trigger, monitor_match: &MonitorMatch, error: Option<String>) -> Self {
+		let monitor = match monitor_match {
+			MonitorMatch::EVM(evm_match) => &evm_match.monitor,
+			MonitorMatch::Stellar(stellar_match) => &stellar_match.monitor,
+		};
+		let trigger_type = match &trigger.trigger_type {
+			TriggerType::Slack => "slack",
+			TriggerType::Email => "email",
+			TriggerType::Webhook => "webhook",
+			TriggerType::Telegram => "telegram",
+			TriggerType::Discord => "discord",
+			TriggerType::Script => "script",
+			TriggerType::Kafka => "kafka",
+			TriggerType::Nats => "nats",
+			TriggerType::Grpc => "grpc",
+			TriggerType::Custom(_) => "custom",
+		};
+
+		Self {
+			timestamp: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+			monitor: monitor.name.clone(),
+			trigger: trigger.name.clone(),
+			trigger_type: trigger_type.to_string(),
+			match_id: monitor_match.match_id(),
+			outcome: if error.is_none() {
+				AuditOutcome::Success
+			} else {
+				AuditOutcome::Failure
+			},
+			error,
+		}
+	}
+}
+
+/// Append-only store of notification audit records
+#[async_trait]
+pub trait AuditLog: Send + Sync {
+	/// Appends a record to the audit log
+	async fn record(&self, record: &AuditRecord) -> Result<(), anyhow::Error>;
+}
+
+/// Audit log appending records as JSON lines to a file
+///
+/// The file and its parent directories are created on the first record. Records are
+/// flushed as they are written.
+pub struct FileAuditLog {
+	path: PathBuf,
+	lock: Mutex<()>,
+}
+
+impl FileAuditLog {
+	/// Creates an audit log appending to the file at the given path
+	pub fn new(path: impl Into<PathBuf>) -> Self {
+		Self {
+			path: path.into(),
+			lock: Mutex::new(()),
+		}
+	}
+
+	/// Creates an audit log from `NOTIFICATION_AUDIT_LOG_PATH`, if set
+	pub fn from_env() -> Option<Self> {
+		env::var(AUDIT_LOG_PATH_ENV)
+			.ok()
+			.filter(|path| !path.trim().is_empty())
+			.map(Self::new)
+	}
+
+	/// Returns the path of the audit log file
+	pub fn path(&self) -> &Path {
+		&self.path
+	}
+}
+
+#[async_trait]
+impl AuditLog for FileAuditLog {
+	async fn record(&self, record: &AuditRecord) -> Result<(), anyhow::Error> {
+		let mut line = serde_json::to_string(record)?;
+		line.push('\n');
+
+		// Serialize writers so that concurrent records are never interleaved
+		let _guard = self.lock.lock().await;
+		if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+			tokio::fs::create_dir_all(parent).await?;
+		}
+		let mut file = OpenOptions::new()
+			.create(true)
+			.append(true)
+			.open(&self.path)
+			.await?;
+		file.write_all(line.as_bytes()).await?;
+		file.flush().await?;
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{EVMMonitorMatch, MatchConditions},
+		utils::tests::builders::{
+			evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+			trigger::TriggerBuilder,
+		},
+	};
+	use std::collections::HashMap;
+	use tempfile::TempDir;
+
+	fn create_test_match() -> MonitorMatch {
+		MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new().name("audited_monitor").build(),
+			transaction: TransactionBuilder::new().build(),
+			receipt: None,
+			revert_reason: None,
+			logs: None,
+			block_timestamp: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: None,
+			enrichment: HashMap::new(),
+		}))
+	}
+
+	#[test]
+	fn test_audit_record_fields() {
+		let trigger = TriggerBuilder::new()
+			.name("audited_trigger")
+			.slack("https://hooks.slack.com/services/xxx")
+			.build();
+		let monitor_match = create_test_match();
+
+		let record = AuditRecord::new(&trigger, &monitor_match, None);
+		assert_eq!(record.monitor, "audited_monitor");
+		assert_eq!(record.trigger, "audited_trigger");
+		assert_eq!(record.trigger_type, "slack");
+		assert_eq!(record.match_id, monitor_match.match_id());
+		assert_eq!(record.outcome, AuditOutcome::Success);
+		assert!(chrono::DateTime::parse_from_rfc3339(&record.timestamp).is_ok());
+
+		let record = AuditRecord::new(&trigger, &monitor_match, Some("timeout".to_string()));
+		assert_eq!(record.outcome, AuditOutcome::Failure);
+		assert_eq!(record.error.as_deref(), Some("timeout"));
+	}
+
+	#[tokio::test]
+	async fn test_file_audit_log_appends_json_lines() {
+		let temp_dir = TempDir::new().unwrap();
+		let audit_log = FileAuditLog::new(temp_dir.path().join("audit").join("audit.jsonl"));
+		let trigger = TriggerBuilder::new()
+			.name("audited_trigger")
+			.webhook("https://example.com/webhook")
+			.build();
+		let monitor_match = create_test_match();
+
+		let records = [
+			AuditRecord::new(&trigger, &monitor_match, None),
+			AuditRecord::new(&trigger, &monitor_match, Some("HTTP 500".to_string())),
+		];
+		for record in &records {
+			audit_log.record(record).await.unwrap();
+		}
+
+		let content = std::fs::read_to_string(audit_log.path()).unwrap();
+		let written: Vec<AuditRecord> = content
+			.lines()
+			.map(|line| serde_json::from_str(line).unwrap())
+			.collect();
+		assert_eq!(written, records);
+	}
+
+	#[tokio::test]
+	async fn test_file_audit_log_write_failure() {
+		let temp_dir = TempDir::new().unwrap();
+		// A directory cannot be opened as the audit log file
+		let audit_log = FileAuditLog::new(temp_dir.path());
+		let trigger = TriggerBuilder::new()
+			.name("audited_trigger")
+			.webhook("https://example.com/webhook")
+			.build();
+
+		let record = AuditRecord::new(&trigger, &create_test_match(), None);
+		assert!(audit_log.record(&record).await.is_err());
+	}
+}
@@ -0,0 +1,230 @@
+//! Circuit breaker for notification endpoints.
+//!
+//! After a configurable number of consecutive failures the breaker opens and
+//! notifications to the endpoint fail fast instead of going through the full
+//! retry policy. Once the cooldown has elapsed, a single probe notification is
+//! let through (half-open): success closes the breaker, failure reopens it for
+//! another cooldown.
+
+use std::{
+	env,
+	sync::Mutex,
+	time::{Duration, Instant},
+};
+
+/// Environment variable setting the consecutive failures that open a notification circuit
+pub const CIRCUIT_BREAKER_THRESHOLD_ENV: &str = "NOTIFICATION_CIRCUIT_BREAKER_THRESHOLD";
+
+/// Environment variable setting how long an open notification circuit fails fast, in seconds
+pub const CIRCUIT_BREAKER_COOLDOWN_ENV: &str = "NOTIFICATION_CIRCUIT_BREAKER_COOLDOWN_SECS";
+
+/// Default cooldown used when only the failure threshold is configured
+const DEFAULT_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// Thresholds applied to the circuit breakers of a notification client pool.
+///
+/// The default configuration disables circuit breaking.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+	/// Consecutive failures after which the circuit opens. `None` disables the breaker.
+	pub failure_threshold: Option<u32>,
+	/// Time an open circuit fails fast before letting a probe notification through
+	pub cooldown: Duration,
+}
+
+impl CircuitBreakerConfig {
+	/// Reads the circuit breaker configuration from the environment.
+	///
+	/// Uses `NOTIFICATION_CIRCUIT_BREAKER_THRESHOLD` and
+	/// `NOTIFICATION_CIRCUIT_BREAKER_COOLDOWN_SECS`. Circuit breaking stays disabled unless
+	/// the threshold is set; the cooldown defaults to 60 seconds.
+	pub fn from_env() -> Result<Self, anyhow::Error> {
+		Ok(Self {
+			failure_threshold: parse_positive_env(CIRCUIT_BREAKER_THRESHOLD_ENV)?
+				.map(|value| u32::try_from(value).unwrap_or(u32::MAX)),
+			cooldown: parse_positive_env(CIRCUIT_BREAKER_COOLDOWN_ENV)?
+				.map(Duration::from_secs)
+				.unwrap_or(DEFAULT_COOLDOWN),
+		})
+	}
+
+	/// Returns whether circuit breaking is enabled
+	pub fn is_enabled(&self) -> bool {
+		self.failure_threshold.is_some()
+	}
+}
+
+fn parse_positive_env(name: &str) -> Result<Option<u64>, anyhow::Error> {
+	let Ok(value) = env::var(name) else {
+		return Ok(None);
+	};
+	match value.trim().parse::<u64>() {
+		Ok(parsed) if parsed > 0 => Ok(Some(parsed)),
+		_ => Err(anyhow::anyhow!(
+			"Invalid {} value '{}': expected a positive integer",
+			name,
+			value
+		)),
+	}
+}
+
+/// State of a circuit breaker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+	/// Notifications are sent normally
+	Closed,
+	/// Notifications fail fast until the cooldown elapses
+	Open,
+	/// The cooldown has elapsed and a probe notification decides the next state
+	HalfOpen,
+}
+
+#[derive(Debug, Default)]
+struct BreakerState {
+	consecutive_failures: u32,
+	opened_at: Option<Instant>,
+	probe_in_flight: bool,
+}
+
+/// Circuit breaker guarding a single notification endpoint
+#[derive(Debug)]
+pub struct CircuitBreaker {
+	config: CircuitBreakerConfig,
+	state: Mutex<BreakerState>,
+}
+
+impl CircuitBreaker {
+	/// Creates a closed circuit breaker
+	pub fn new(config: CircuitBreakerConfig) -> Self {
+		Self {
+			config,
+			state: Mutex::new(BreakerState::default()),
+		}
+	}
+
+	/// Returns the current state of the breaker
+	pub fn state(&self) -> CircuitState {
+		let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+		match state.opened_at {
+			None => CircuitState::Closed,
+			Some(opened_at) if opened_at.elapsed() < self.config.cooldown => CircuitState::Open,
+			Some(_) => CircuitState::HalfOpen,
+		}
+	}
+
+	/// Asks the breaker whether a notification may be sent.
+	///
+	/// Returns `false` while the circuit is open. Once the cooldown has elapsed, only one
+	/// caller is allowed through as a probe until its outcome is recorded.
+	pub fn try_acquire(&self) -> bool {
+		let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+		match state.opened_at {
+			None => true,
+			Some(opened_at) if opened_at.elapsed() < self.config.cooldown => false,
+			Some(_) if state.probe_in_flight => false,
+			Some(_) => {
+				state.probe_in_flight = true;
+				true
+			}
+		}
+	}
+
+	/// Records a successful notification, closing the circuit
+	pub fn record_success(&self) {
+		let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+		*state = BreakerState::default();
+	}
+
+	/// Records a failed notification, opening the circuit once the threshold is reached
+	/// or when a half-open probe fails
+	pub fn record_failure(&self) {
+		let Some(threshold) = self.config.failure_threshold else {
+			return;
+		};
+		let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+		state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+		if state.probe_in_flight || state.consecutive_failures >= threshold {
+			state.opened_at = Some(Instant::now());
+			state.probe_in_flight = false;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn create_breaker(threshold: u32, cooldown: Duration) -> CircuitBreaker {
+		CircuitBreaker::new(CircuitBreakerConfig {
+			failure_threshold: Some(threshold),
+			cooldown,
+		})
+	}
+
+	#[test]
+	fn test_breaker_opens_after_consecutive_failures() {
+		let breaker = create_breaker(3, Duration::from_secs(60));
+
+		breaker.record_failure();
+		breaker.record_failure();
+		assert_eq!(breaker.state(), CircuitState::Closed);
+		assert!(breaker.try_acquire());
+
+		breaker.record_failure();
+		assert_eq!(breaker.state(), CircuitState::Open);
+		assert!(!breaker.try_acquire());
+	}
+
+	#[test]
+	fn test_success_resets_failure_count() {
+		let breaker = create_breaker(2, Duration::from_secs(60));
+
+		breaker.record_failure();
+		breaker.record_success();
+		breaker.record_failure();
+		assert_eq!(breaker.state(), CircuitState::Closed);
+	}
+
+	#[test]
+	fn test_half_open_probe_success_closes_circuit() {
+		let breaker = create_breaker(1, Duration::from_millis(20));
+
+		breaker.record_failure();
+		assert!(!breaker.try_acquire());
+
+		std::thread::sleep(Duration::from_millis(30));
+		assert_eq!(breaker.state(), CircuitState::HalfOpen);
+		assert!(breaker.try_acquire(), "First caller should be the probe");
+		assert!(!breaker.try_acquire(), "Only one probe should be in flight");
+
+		breaker.record_success();
+		assert_eq!(breaker.state(), CircuitState::Closed);
+		assert!(breaker.try_acquire());
+	}
+
+	#[test]
+	fn test_half_open_probe_failure_reopens_circuit() {
+		let breaker = create_breaker(3, Duration::from_millis(20));
+
+		for _ in 0..3 {
+			breaker.record_failure();
+		}
+		std::thread::sleep(Duration::from_millis(30));
+		assert!(breaker.try_acquire());
+
+		breaker.record_failure();
+		assert_eq!(breaker.state(), CircuitState::Open);
+		assert!(!breaker.try_acquire());
+	}
+
+	#[test]
+	fn test_disabled_breaker_never_opens() {
+		let breaker = CircuitBreaker::new(CircuitBreakerConfig::default());
+
+		for _ in 0..10 {
+			breaker.record_failure();
+		}
+		assert_eq!(breaker.state(), CircuitState::Closed);
+		assert!(breaker.try_acquire());
+	}
+}
@@ -0,0 +1,404 @@
+//! Dead-letter store of failed notifications.
+//!
+//! Notifications that could not be delivered, including those skipped while an
+//! endpoint's circuit breaker is open, are appended to a JSON Lines file so that
+//! they can be re-sent once the endpoint has recovered (see `--drain-dead-letter`).
+//! Only the trigger slug is persisted, not the trigger configuration, so that no
+//! credential is written to the store.
+
+use serde::{Deserialize, Serialize};
+use std::{
+	collections::{HashMap, HashSet},
+	env,
+	path::{Path, PathBuf},
+};
+use tokio::{io::AsyncWriteExt, sync::Mutex};
+use uuid::Uuid;
+
+use crate::{
+	models::MonitorMatch, services::notification::NotificationError,
+	utils::logging::error::ErrorCode,
+};
+
+/// Environment variable setting the path of the dead-letter store
+pub const DEAD_LETTER_PATH_ENV: &str = "NOTIFICATION_DEAD_LETTER_PATH";
+
+/// A notification that could not be delivered
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadLetterRecord {
+	/// Unique identifier of the record
+	pub id: String,
+	/// Slug of the trigger the notification was sent through
+	pub trigger: String,
+	/// Match the notification was sent for
+	pub monitor_match: MonitorMatch,
+	/// Variables substituted in the trigger's message template
+	pub variables: HashMap<String, String>,
+	/// Code classifying the delivery failure
+	pub error_code: ErrorCode,
+	/// Message of the delivery failure
+	pub error: String,
+	/// Unix timestamp in seconds of the failure
+	pub failed_at: i64,
+}
+
+impl DeadLetterRecord {
+	/// Creates a record of a failed notification
+	///
+	/// # Arguments
+	/// * `trigger` - Slug of the trigger the notification was sent through
+	/// * `monitor_match` - Match the notification was sent for
+	/// * `variables` - Variables substituted in the trigger's message template
+	/// * `error` - Error the notification failed with
+	pub fn new(
+		trigger: impl Into<String>,
+		monitor_match: &MonitorMatch,
+		variables: &HashMap<String, String>,
+		error: &NotificationError,
+	) -> Self {
+		Self {
+			id: Uuid::new_v4().to_string(),
+			trigger: trigger.into(),
+			monitor_match: monitor_match.clone(),
+			variables: variables.clone(),
+			error_code: error.code(),
+			error: error.to_string(),
+			failed_at: chrono::Utc::now().timestamp(),
+		}
+	}
+}
+
+/// File-based store of failed notifications
+///
+/// Records are stored one JSON document per line, and removed by rewriting the file
+/// atomically. Accesses to a store are serialized across instances and processes, such as a
+/// running monitor and `--drain-dead-letter`, by an advisory lock on a `.lock` file next to
+/// the store file.
+#[derive(Debug)]
+pub struct DeadLetterStore {
+	path: PathBuf,
+	lock: Mutex<()>,
+}
+
+impl DeadLetterStore {
+	/// Creates a store persisting records to the given file
+	///
+	/// The file and its parent directory are created on the first record.
+	pub fn new(path: impl Into<PathBuf>) -> Self {
+		Self {
+			path: path.into(),
+			lock: Mutex::new(()),
+		}
+	}
+
+	/// Creates the store configured by `NOTIFICATION_DEAD_LETTER_PATH`
+	///
+	/// # Returns
+	/// * `Option<Self>` - The store, or `None` if the variable is unset or empty
+	pub fn from_env() -> Option<Self> {
+		env::var(DEAD_LETTER_PATH_ENV)
+			.ok()
+			.filter(|path| !path.trim().is_empty())
+			.map(Self::new)
+	}
+
+	/// Returns the path of the store file
+	pub fn path(&self) -> &Path {
+		&self.path
+	}
+
+	/// Appends a record to the store
+	pub async fn append(&self, record: &DeadLetterRecord) -> Result<(), anyhow::Error> {
+		let mut line = serde_json::to_vec(record)
+			.map_err(|e| anyhow::anyhow!("Failed to serialize dead-letter record: {}", e))?;
+		line.push(b'\n');
+
+		let _guard = self.lock.lock().await;
+		let _file_lock = self.lock_file().await?;
+		let mut file = tokio::fs::OpenOptions::new()
+			.create(true)
+			.append(true)
+			.open(&self.path)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to open dead-letter store: {}", e))?;
+		file.write_all(&line)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to write dead-letter record: {}", e))?;
+		file.flush()
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to write dead-letter record: {}", e))
+	}
+
+	/// Loads all records of the store, oldest first
+	///
+	/// Lines that cannot be parsed are skipped with a warning.
+	pub async fn load(&self) -> Result<Vec<DeadLetterRecord>, anyhow::Error> {
+		let _guard = self.lock.lock().await;
+		let _file_lock = self.lock_file().await?;
+		self.read_records().await
+	}
+
+	/// Removes the records with the given identifiers from the store
+	///
+	/// The file is read again before being rewritten, so records appended since they were
+	/// loaded are kept.
+	pub async fn remove(&self, ids: &HashSet<String>) -> Result<(), anyhow::Error> {
+		if ids.is_empty() {
+			return Ok(());
+		}
+
+		let _guard = self.lock.lock().await;
+		let _file_lock = self.lock_file().await?;
+		let content = match tokio::fs::read_to_string(&self.path).await {
+			Ok(content) => content,
+			Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+			Err(e) => return Err(anyhow::anyhow!("Failed to read dead-letter store: {}", e)),
+		};
+		let remaining = content
+			.lines()
+			.filter(|line| {
+				serde_json::from_str::<DeadLetterRecord>(line)
+					.map_or(!line.trim().is_empty(), |record| !ids.contains(&record.id))
+			})
+			.fold(String::new(), |mut acc, line| {
+				acc.push_str(line);
+				acc.push('\n');
+				acc
+			});
+
+		let tmp_path = self.path.with_extension("tmp");
+		tokio::fs::write(&tmp_path, remaining)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to write dead-letter store: {}", e))?;
+		tokio::fs::rename(&tmp_path, &self.path)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to write dead-letter store: {}", e))
+	}
+
+	/// Takes the advisory lock of the store, released when the returned file is dropped
+	///
+	/// The lock is taken on a separate file, as the store file is replaced when records are
+	/// removed. The file and its parent directory are created if needed.
+	async fn lock_file(&self) -> Result<std::fs::File, anyhow::Error> {
+		if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+			tokio::fs::create_dir_all(parent)
+				.await
+				.map_err(|e| anyhow::anyhow!("Failed to create dead-letter directory: {}", e))?;
+		}
+		let lock_path = self.lock_path();
+		tokio::task::spawn_blocking(move || {
+			let file = std::fs::OpenOptions::new()
+				.create(true)
+				.truncate(false)
+				.write(true)
+				.open(lock_path)?;
+			#[cfg(unix)]
+			{
+				use std::os::unix::io::AsRawFd;
+				// SAFETY: the descriptor is owned by `file`, which outlives the call
+				if unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX) } != 0 {
+					return Err(std::io::Error::last_os_error());
+				}
+			}
+			Ok(file)
+		})
+		.await
+		.map_err(|e| anyhow::anyhow!("Failed to lock dead-letter store: {}", e))?
+		.map_err(|e| anyhow::anyhow!("Failed to lock dead-letter store: {}", e))
+	}
+
+	fn lock_path(&self) -> PathBuf {
+		let mut path = self.path.clone().into_os_string();
+		path.push(".lock");
+		path.into()
+	}
+
+	async fn read_records(&self) -> Result<Vec<DeadLetterRecord>, anyhow::Error> {
+		let content = match tokio::fs::read_to_string(&self.path).await {
+			Ok(content) => content,
+			Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+			Err(e) => return Err(anyhow::anyhow!("Failed to read dead-letter store: {}", e)),
+		};
+		Ok(content
+			.lines()
+			.enumerate()
+			.filter(|(_, line)| !line.trim().is_empty())
+			.filter_map(
+				|(index, line)| match serde_json::from_str::<DeadLetterRecord>(line) {
+					Ok(record) => Some(record),
+					Err(e) => {
+						tracing::warn!(
+							"Skipping invalid dead-letter record at line {} of {}: {}",
+							index + 1,
+							self.path.display(),
+							e
+						);
+						None
+					}
+				},
+			)
+			.collect())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{EVMMonitorMatch, EVMTransaction, MatchConditions},
+		utils::tests::builders::evm::monitor::MonitorBuilder,
+	};
+	use tempfile::TempDir;
+
+	fn create_record(trigger: &str) -> DeadLetterRecord {
+		let monitor_match = MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new().name("test_monitor").build(),
+			transaction: EVMTransaction::default(),
+			receipt: None,
+			revert_reason: None,
+			logs: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: None,
+			enrichment: HashMap::new(),
+			block_timestamp: None,
+		}));
+		let error = NotificationError::notify_failed(
+			"Webhook returned error status: 503",
+			None,
+			Some(HashMap::from([(
+				"status_code".to_string(),
+				"503".to_string(),
+			)])),
+		);
+		DeadLetterRecord::new(
+			trigger,
+			&monitor_match,
+			&HashMap::from([("monitor.name".to_string(), "test_monitor".to_string())]),
+			&error,
+		)
+	}
+
+	#[test]
+	fn test_record_captures_error_code() {
+		let record = create_record("test_trigger");
+		assert_eq!(record.trigger, "test_trigger");
+		assert_eq!(record.error_code, ErrorCode::Http5xx);
+		assert!(record.error.contains("503"));
+		assert_eq!(
+			record.variables.get("monitor.name"),
+			Some(&"test_monitor".to_string())
+		);
+	}
+
+	#[tokio::test]
+	async fn test_append_and_load() {
+		let dir = TempDir::new().unwrap();
+		let store = DeadLetterStore::new(dir.path().join("nested").join("dead_letter.jsonl"));
+		assert!(store.load().await.unwrap().is_empty());
+
+		let first = create_record("first");
+		let second = create_record("second");
+		store.append(&first).await.unwrap();
+		store.append(&second).await.unwrap();
+
+		let records = store.load().await.unwrap();
+		assert_eq!(records.len(), 2);
+		assert_eq!(records[0].id, first.id);
+		assert_eq!(records[1].id, second.id);
+		assert_eq!(records[1].error_code, ErrorCode::Http5xx);
+	}
+
+	#[tokio::test]
+	async fn test_load_skips_invalid_lines() {
+		let dir = TempDir::new().unwrap();
+		let store = DeadLetterStore::new(dir.path().join("dead_letter.jsonl"));
+		let record = create_record("test_trigger");
+		store.append(&record).await.unwrap();
+		let mut file = tokio::fs::OpenOptions::new()
+			.append(true)
+			.open(store.path())
+			.await
+			.unwrap();
+		file.write_all(b"not json\n\n").await.unwrap();
+
+		let records = store.load().await.unwrap();
+		assert_eq!(records.len(), 1);
+		assert_eq!(records[0].id, record.id);
+	}
+
+	#[tokio::test]
+	async fn test_remove_keeps_other_records() {
+		let dir = TempDir::new().unwrap();
+		let store = DeadLetterStore::new(dir.path().join("dead_letter.jsonl"));
+		let records = ["first", "second", "third"].map(create_record);
+		for record in &records {
+			store.append(record).await.unwrap();
+		}
+
+		store
+			.remove(&HashSet::from([
+				records[0].id.clone(),
+				records[2].id.clone(),
+			]))
+			.await
+			.unwrap();
+
+		let remaining = store.load().await.unwrap();
+		assert_eq!(remaining.len(), 1);
+		assert_eq!(remaining[0].id, records[1].id);
+
+		// Records appended after the removal are kept
+		let fourth = create_record("fourth");
+		store.append(&fourth).await.unwrap();
+		assert_eq!(store.load().await.unwrap().len(), 2);
+	}
+
+	#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+	async fn test_remove_keeps_records_appended_by_other_stores() {
+		let dir = TempDir::new().unwrap();
+		let path = dir.path().join("dead_letter.jsonl");
+		let appender = std::sync::Arc::new(DeadLetterStore::new(&path));
+		let remover = DeadLetterStore::new(&path);
+
+		let drained = (0..20)
+			.map(|_| create_record("drained"))
+			.collect::<Vec<_>>();
+		for record in &drained {
+			remover.append(record).await.unwrap();
+		}
+		let drained_ids = drained
+			.iter()
+			.map(|record| record.id.clone())
+			.collect::<Vec<_>>();
+
+		// Records appended by another store while records are being removed are kept
+		let appends = tokio::spawn({
+			let appender = appender.clone();
+			async move {
+				for _ in 0..50 {
+					appender.append(&create_record("appended")).await.unwrap();
+				}
+			}
+		});
+		for id in drained_ids {
+			remover.remove(&HashSet::from([id])).await.unwrap();
+		}
+		appends.await.unwrap();
+
+		let records = appender.load().await.unwrap();
+		assert_eq!(records.len(), 50);
+		assert!(records.iter().all(|record| record.trigger == "appended"));
+	}
+
+	#[tokio::test]
+	async fn test_remove_missing_store() {
+		let dir = TempDir::new().unwrap();
+		let store = DeadLetterStore::new(dir.path().join("dead_letter.jsonl"));
+		store
+			.remove(&HashSet::from(["unknown".to_string()]))
+			.await
+			.unwrap();
+		assert!(!store.path().exists());
+	}
+}
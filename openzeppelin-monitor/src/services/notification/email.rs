@@ -8,16 +8,16 @@ use email_address::EmailAddress;
 use lettre::{
 	message::{
 		header::{self, ContentType},
-		Mailbox, Mailboxes,
+		Attachment, Mailbox, Mailboxes, MultiPart, SinglePart,
 	},
 	transport::smtp::Error as SmtpError,
 	AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
 };
 use pulldown_cmark::{html, Options, Parser};
-use std::{collections::HashMap, error::Error as StdError, sync::Arc};
+use std::{collections::HashMap, error::Error as StdError, path::Path, sync::Arc};
 
 use crate::{
-	models::TriggerTypeConfig,
+	models::{EmailContentType, TriggerTypeConfig},
 	services::notification::{template_formatter, NotificationError},
 	utils::{JitterSetting, RetryConfig},
 };
@@ -35,6 +35,16 @@ pub struct EmailNotifier<T: AsyncTransport + Send + Sync> {
 	sender: EmailAddress,
 	/// Email recipients
 	recipients: Vec<EmailAddress>,
+	/// Email addresses copied on the email
+	cc: Vec<EmailAddress>,
+	/// Email addresses blind copied on the email
+	bcc: Vec<EmailAddress>,
+	/// Address replies are sent to, defaulting to the sender
+	reply_to: Option<EmailAddress>,
+	/// Content type of the email body
+	content_type: EmailContentType,
+	/// Paths of static files to attach to the email
+	attachments: Vec<String>,
 	/// Retry policy for SMTP requests
 	retry_policy: RetryConfig,
 }
@@ -55,6 +65,11 @@ pub struct EmailContent {
 	pub body_template: String,
 	pub sender: EmailAddress,
 	pub recipients: Vec<EmailAddress>,
+	pub cc: Vec<EmailAddress>,
+	pub bcc: Vec<EmailAddress>,
+	pub reply_to: Option<EmailAddress>,
+	pub content_type: EmailContentType,
+	pub attachments: Vec<String>,
 }
 
 // This implementation is only for testing purposes
@@ -82,12 +97,17 @@ where
 			body_template: email_content.body_template,
 			sender: email_content.sender,
 			recipients: email_content.recipients,
+			cc: email_content.cc,
+			bcc: email_content.bcc,
+			reply_to: email_content.reply_to,
+			content_type: email_content.content_type,
+			attachments: email_content.attachments,
 			client: Arc::new(transport),
 			retry_policy,
 		}
 	}
 
-	/// Sends a formatted message to email
+	/// Sends a formatted message to email using the configured subject
 	///
 	/// # Arguments
 	/// * `message` - The formatted message to send
@@ -95,23 +115,27 @@ where
 	/// # Returns
 	/// * `Result<(), NotificationError>` - Success or error
 	pub async fn notify(&self, message: &str) -> Result<(), NotificationError> {
-		let recipients_str = self
-			.recipients
-			.iter()
-			.map(ToString::to_string)
-			.collect::<Vec<_>>()
-			.join(", ");
-
-		let mailboxes: Mailboxes = recipients_str.parse::<Mailboxes>().map_err(|e| {
-			NotificationError::notify_failed(
-				format!("Failed to parse recipients: {}", e),
-				Some(e.into()),
-				None,
-			)
-		})?;
-		let recipients_header: header::To = mailboxes.into();
+		self.notify_with_subject(&self.subject, message).await
+	}
 
-		let email = Message::builder()
+	/// Sends a formatted message to email with the given subject
+	///
+	/// # Arguments
+	/// * `subject` - The formatted subject to use
+	/// * `message` - The formatted message to send
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	pub async fn notify_with_subject(
+		&self,
+		subject: &str,
+		message: &str,
+	) -> Result<(), NotificationError> {
+		let recipients_header: header::To =
+			Self::parse_mailboxes(&self.recipients, "recipients")?.into();
+		let reply_to = self.reply_to.as_ref().unwrap_or(&self.sender);
+
+		let mut email = Message::builder()
 			.mailbox(recipients_header)
 			.from(self.sender.to_string().parse::<Mailbox>().map_err(|e| {
 				NotificationError::notify_failed(
@@ -120,23 +144,48 @@ where
 					None,
 				)
 			})?)
-			.reply_to(self.sender.to_string().parse::<Mailbox>().map_err(|e| {
+			.reply_to(reply_to.to_string().parse::<Mailbox>().map_err(|e| {
 				NotificationError::notify_failed(
 					format!("Failed to parse reply-to: {}", e),
 					Some(e.into()),
 					None,
 				)
 			})?)
-			.subject(&self.subject)
-			.header(ContentType::TEXT_HTML)
-			.body(message.to_owned())
-			.map_err(|e| {
-				NotificationError::notify_failed(
-					format!("Failed to build email message: {}", e),
-					Some(e.into()),
-					None,
-				)
-			})?;
+			.subject(subject);
+		if !self.cc.is_empty() {
+			let cc_header: header::Cc = Self::parse_mailboxes(&self.cc, "cc")?.into();
+			email = email.mailbox(cc_header);
+		}
+		if !self.bcc.is_empty() {
+			let bcc_header: header::Bcc = Self::parse_mailboxes(&self.bcc, "bcc")?.into();
+			email = email.mailbox(bcc_header);
+		}
+
+		let body_content_type = match self.content_type {
+			EmailContentType::Markdown | EmailContentType::Html => ContentType::TEXT_HTML,
+			EmailContentType::Text => ContentType::TEXT_PLAIN,
+		};
+
+		let email = if self.attachments.is_empty() {
+			email.header(body_content_type).body(message.to_owned())
+		} else {
+			let mut multipart = MultiPart::mixed().singlepart(
+				SinglePart::builder()
+					.header(body_content_type)
+					.body(message.to_owned()),
+			);
+			for attachment_path in &self.attachments {
+				multipart = multipart.singlepart(Self::build_attachment(attachment_path).await?);
+			}
+			email.multipart(multipart)
+		}
+		.map_err(|e| {
+			NotificationError::notify_failed(
+				format!("Failed to build email message: {}", e),
+				Some(e.into()),
+				None,
+			)
+		})?;
 
 		let operation = || async {
 			self.client.send(email.clone()).await.map_err(|e| {
@@ -180,6 +229,81 @@ where
 			.when(should_retry)
 			.await
 	}
+
+	/// Parses a list of email addresses into mailboxes for an address header
+	///
+	/// # Arguments
+	/// * `addresses` - Addresses to parse
+	/// * `field` - Name of the field, used in error messages
+	///
+	/// # Returns
+	/// * `Result<Mailboxes, NotificationError>` - Parsed mailboxes or error
+	fn parse_mailboxes(
+		addresses: &[EmailAddress],
+		field: &str,
+	) -> Result<Mailboxes, NotificationError> {
+		addresses
+			.iter()
+			.map(ToString::to_string)
+			.collect::<Vec<_>>()
+			.join(", ")
+			.parse::<Mailboxes>()
+			.map_err(|e| {
+				NotificationError::notify_failed(
+					format!("Failed to parse {}: {}", field, e),
+					Some(e.into()),
+					None,
+				)
+			})
+	}
+
+	/// Reads a file from disk and builds an email attachment part from it
+	///
+	/// # Arguments
+	/// * `path` - Path of the file to attach
+	///
+	/// # Returns
+	/// * `Result<SinglePart, NotificationError>` - Attachment part or error
+	async fn build_attachment(path: &str) -> Result<SinglePart, NotificationError> {
+		let content = tokio::fs::read(path).await.map_err(|e| {
+			NotificationError::notify_failed(
+				format!("Failed to read email attachment: {}", e),
+				Some(e.into()),
+				Some(HashMap::from([("path".to_string(), path.to_string())])),
+			)
+		})?;
+
+		let file_path = Path::new(path);
+		let file_name = file_path
+			.file_name()
+			.map(|name| name.to_string_lossy().to_string())
+			.unwrap_or_else(|| path.to_string());
+		let mime_type = match file_path
+			.extension()
+			.and_then(|ext| ext.to_str())
+			.map(str::to_lowercase)
+			.as_deref()
+		{
+			Some("pdf") => "application/pdf",
+			Some("json") => "application/json",
+			Some("csv") => "text/csv",
+			Some("txt") | Some("log") => "text/plain",
+			Some("html") | Some("htm") => "text/html",
+			Some("png") => "image/png",
+			Some("jpg") | Some("jpeg") => "image/jpeg",
+			Some("gif") => "image/gif",
+			_ => "application/octet-stream",
+		};
+		let content_type = ContentType::parse(mime_type).map_err(|e| {
+			NotificationError::internal_error(
+				format!("Invalid attachment content type: {}", mime_type),
+				Some(e.into()),
+				None,
+			)
+		})?;
+
+		Ok(Attachment::new(file_name).body(content, content_type))
+	}
 }
 
 impl EmailNotifier<AsyncSmtpTransport<Tokio1Executor>> {
@@ -201,16 +325,58 @@ impl EmailNotifier<AsyncSmtpTransport<Tokio1Executor>> {
 			body_template: email_content.body_template,
 			sender: email_content.sender,
 			recipients: email_content.recipients,
+			cc: email_content.cc,
+			bcc: email_content.bcc,
+			reply_to: email_content.reply_to,
+			content_type: email_content.content_type,
+			attachments: email_content.attachments,
 			client: smtp_client,
 			retry_policy,
 		})
 	}
 
+	/// Returns the subject template of the email.
+	pub fn subject(&self) -> &str {
+		&self.subject
+	}
+
 	/// Returns the body template of the email.
 	pub fn body_template(&self) -> &str {
 		&self.body_template
 	}
 
+	/// Formats a subject by substituting variables in the template
+	/// Line breaks are replaced with spaces, as they are not allowed in the subject header
+	///
+	/// # Arguments
+	/// * `subject_template` - Subject template with variable placeholders
+	/// * `variables` - Map of variable names to values
+	///
+	/// # Returns
+	/// * `String` - Formatted subject with variables replaced
+	pub fn format_subject(subject_template: &str, variables: &HashMap<String, String>) -> String {
+		template_formatter::format_template(subject_template, variables).replace(['\r', '\n'], " ")
+	}
+
+	/// Formats the body template according to the configured content type
+	///
+	/// Markdown bodies are converted to HTML, while HTML and plain text bodies only go
+	/// through variable substitution.
+	///
+	/// # Arguments
+	/// * `variables` - Map of variable names to values
+	///
+	/// # Returns
+	/// * `String` - Formatted message body
+	pub fn format_body(&self, variables: &HashMap<String, String>) -> String {
+		match self.content_type {
+			EmailContentType::Markdown => Self::format_message(&self.body_template, variables),
+			EmailContentType::Html | EmailContentType::Text => {
+				template_formatter::format_template(&self.body_template, variables)
+			}
+		}
+	}
+
 	/// Formats a message by substituting variables in the template and converts it to HTML
 	/// Method is static because property-based tests do not have tokio runtime available,
 	/// which is required for AsyncSmtpTransport
@@ -251,6 +417,11 @@ impl EmailNotifier<AsyncSmtpTransport<Tokio1Executor>> {
 			message,
 			sender,
 			recipients,
+			cc,
+			bcc,
+			reply_to,
+			content_type,
+			attachments,
 			retry_policy,
 			..
 		} = config
@@ -260,6 +431,11 @@ impl EmailNotifier<AsyncSmtpTransport<Tokio1Executor>> {
 				body_template: message.body.clone(),
 				sender: sender.clone(),
 				recipients: recipients.clone(),
+				cc: cc.clone(),
+				bcc: bcc.clone(),
+				reply_to: reply_to.clone(),
+				content_type: *content_type,
+				attachments: attachments.clone().unwrap_or_default(),
 			};
 
 			Self::new(smtp_client, email_content, retry_policy.clone())
@@ -291,6 +467,11 @@ mod tests {
 			body_template: "Hello ${name}, your balance is ${balance}".to_string(),
 			sender: "sender@test.com".parse().unwrap(),
 			recipients: vec!["recipient@test.com".parse().unwrap()],
+			cc: vec![],
+			bcc: vec![],
+			reply_to: None,
+			content_type: EmailContentType::default(),
+			attachments: vec![],
 		}
 	}
 
@@ -325,6 +506,11 @@ mod tests {
 			},
 			sender: "sender@test.com".parse().unwrap(),
 			recipients: vec!["recipient@test.com".parse().unwrap()],
+			cc: vec![],
+			bcc: vec![],
+			reply_to: None,
+			content_type: EmailContentType::default(),
+			attachments: None,
 			retry_policy: RetryConfig::default(),
 		}
 	}
@@ -378,6 +564,29 @@ mod tests {
 		assert_eq!(result, expected_result);
 	}
 
+	#[tokio::test]
+	async fn test_format_subject_substitution() {
+		let notifier = create_test_notifier();
+		let mut variables = HashMap::new();
+		variables.insert("value".to_string(), "42".to_string());
+
+		let result = EmailNotifier::format_subject("Transfer of ${value} detected", &variables);
+		assert_eq!(result, "Transfer of 42 detected");
+		assert_eq!(notifier.subject(), "Test Subject");
+	}
+
+	#[tokio::test]
+	async fn test_format_subject_strips_line_breaks() {
+		let mut variables = HashMap::new();
+		variables.insert(
+			"value".to_string(),
+			"42\r\nBcc: attacker@test.com".to_string(),
+		);
+
+		let result = EmailNotifier::format_subject("Alert ${value}", &variables);
+		assert_eq!(result, "Alert 42  Bcc: attacker@test.com");
+	}
+
 	////////////////////////////////////////////////////////////
 	// from_config tests
 	////////////////////////////////////////////////////////////
@@ -425,6 +634,8 @@ mod tests {
 				body: "Hello ${name}".to_string(),
 			},
 			retry_policy: RetryConfig::default(),
+			max_concurrent_notifications: None,
+			max_message_length: None,
 		};
 
 		// Correct config to create SmtpTransport
@@ -491,6 +702,78 @@ mod tests {
 		assert_eq!(transport.messages().await.len(), 1);
 	}
 
+	#[tokio::test]
+	async fn test_notify_with_subject_uses_formatted_subject() {
+		let transport = AsyncStubTransport::new_ok();
+		let mut email_content = create_test_email_content();
+		email_content.subject = "Transfer of ${value}".to_string();
+		let notifier =
+			EmailNotifier::with_transport(email_content, transport.clone(), RetryConfig::default());
+
+		let variables = HashMap::from([("value".to_string(), "42".to_string())]);
+		let subject = EmailNotifier::format_subject(&notifier.subject, &variables);
+		notifier
+			.notify_with_subject(&subject, "test message")
+			.await
+			.unwrap();
+
+		let messages = transport.messages().await;
+		assert_eq!(messages.len(), 1);
+		assert!(messages[0].1.contains("Subject: Transfer of 42"));
+	}
+
+	#[tokio::test]
+	async fn test_notify_sets_cc_bcc_and_reply_to() {
+		let transport = AsyncStubTransport::new_ok();
+		let mut email_content = create_test_email_content();
+		email_content
+			.recipients
+			.push("second@test.com".parse().unwrap());
+		email_content.cc = vec!["team@test.com".parse().unwrap()];
+		email_content.bcc = vec!["audit@test.com".parse().unwrap()];
+		email_content.reply_to = Some("oncall@test.com".parse().unwrap());
+		let notifier =
+			EmailNotifier::with_transport(email_content, transport.clone(), RetryConfig::default());
+
+		notifier.notify("Test message").await.unwrap();
+
+		let messages = transport.messages().await;
+		assert_eq!(messages.len(), 1);
+		let (envelope, message) = &messages[0];
+		assert!(message.contains("To: recipient@test.com, second@test.com"));
+		assert!(message.contains("Cc: team@test.com"));
+		assert!(message.contains("Reply-To: oncall@test.com"));
+		// BCC recipients receive the email without being disclosed in the headers
+		assert!(!message.contains("audit@test.com"));
+		let envelope_recipients: Vec<String> =
+			envelope.to().iter().map(ToString::to_string).collect();
+		assert_eq!(
+			envelope_recipients,
+			vec![
+				"recipient@test.com",
+				"second@test.com",
+				"team@test.com",
+				"audit@test.com"
+			]
+		);
+	}
+
+	#[tokio::test]
+	async fn test_notify_replies_to_sender_by_default() {
+		let transport = AsyncStubTransport::new_ok();
+		let notifier = EmailNotifier::with_transport(
+			create_test_email_content(),
+			transport.clone(),
+			RetryConfig::default(),
+		);
+
+		notifier.notify("Test message").await.unwrap();
+
+		let messages = transport.messages().await;
+		assert!(messages[0].1.contains("Reply-To: sender@test.com"));
+		assert!(!messages[0].1.contains("Cc:"));
+	}
+
 	#[tokio::test]
 	async fn test_notify_fails_after_all_retries() {
 		let transport = AsyncStubTransport::new_error();
@@ -510,4 +793,89 @@ mod tests {
 			"Should be called 1 time + default max retries"
 		);
 	}
+
+	#[tokio::test]
+	async fn test_notify_html_content_type() {
+		let transport = AsyncStubTransport::new_ok();
+		let mut email_content = create_test_email_content();
+		email_content.content_type = EmailContentType::Html;
+		email_content.body_template = "<h1>Balance: ${balance}</h1>".to_string();
+		let notifier =
+			EmailNotifier::with_transport(email_content, transport.clone(), RetryConfig::default());
+
+		let variables = HashMap::from([("balance".to_string(), "100".to_string())]);
+		let body = template_formatter::format_template(&notifier.body_template, &variables);
+		notifier.notify(&body).await.unwrap();
+
+		let messages = transport.messages().await;
+		assert_eq!(messages.len(), 1);
+		assert!(messages[0].1.contains("Content-Type: text/html"));
+		assert!(messages[0].1.contains("<h1>Balance: 100</h1>"));
+	}
+
+	#[tokio::test]
+	async fn test_notify_text_content_type() {
+		let transport = AsyncStubTransport::new_ok();
+		let mut email_content = create_test_email_content();
+		email_content.content_type = EmailContentType::Text;
+		let notifier =
+			EmailNotifier::with_transport(email_content, transport.clone(), RetryConfig::default());
+
+		notifier.notify("plain message").await.unwrap();
+
+		let messages = transport.messages().await;
+		assert!(messages[0].1.contains("Content-Type: text/plain"));
+	}
+
+	#[tokio::test]
+	async fn test_notify_includes_attachment() {
+		let temp_dir = tempfile::TempDir::new().unwrap();
+		let attachment_path = temp_dir.path().join("report.csv");
+		std::fs::write(&attachment_path, "block,value\n1,42\n").unwrap();
+
+		let transport = AsyncStubTransport::new_ok();
+		let mut email_content = create_test_email_content();
+		email_content.content_type = EmailContentType::Html;
+		email_content.attachments = vec![attachment_path.display().to_string()];
+		let notifier =
+			EmailNotifier::with_transport(email_content, transport.clone(), RetryConfig::default());
+
+		notifier.notify("<p>See attached</p>").await.unwrap();
+
+		let messages = transport.messages().await;
+		assert_eq!(messages.len(), 1);
+		let raw = &messages[0].1;
+		assert!(raw.contains("multipart/mixed"));
+		assert!(raw.contains("Content-Type: text/html"));
+		assert!(raw.contains("Content-Disposition: attachment; filename=\"report.csv\""));
+		assert!(raw.contains("Content-Type: text/csv"));
+	}
+
+	#[tokio::test]
+	async fn test_notify_missing_attachment_fails() {
+		let transport = AsyncStubTransport::new_ok();
+		let mut email_content = create_test_email_content();
+		email_content.attachments = vec!["/non/existent/report.pdf".to_string()];
+		let notifier =
+			EmailNotifier::with_transport(email_content, transport.clone(), RetryConfig::default());
+
+		let result = notifier.notify("message").await;
+		assert!(matches!(result, Err(NotificationError::NotifyFailed(_))));
+		assert!(transport.messages().await.is_empty());
+	}
+
+	#[tokio::test]
+	async fn test_format_body_by_content_type() {
+		let variables = HashMap::from([("name".to_string(), "Alice".to_string())]);
+		let mut notifier = create_test_notifier();
+		notifier.body_template = "**Hello ${name}**".to_string();
+
+		assert_eq!(
+			notifier.format_body(&variables),
+			"<p><strong>Hello Alice</strong></p>\n"
+		);
+
+		notifier.content_type = EmailContentType::Html;
+		assert_eq!(notifier.format_body(&variables), "**Hello Alice**");
+	}
 }
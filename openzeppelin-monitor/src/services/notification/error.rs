@@ -3,7 +3,7 @@
 //! Provides error types for notification-related operations,
 //! including network issues and configuration problems.
 
-use crate::utils::logging::error::{ErrorContext, TraceableError};
+use crate::utils::logging::error::{ErrorCode, ErrorContext, TraceableError};
 use std::collections::HashMap;
 use thiserror::Error as ThisError;
 
@@ -26,6 +26,10 @@ pub enum NotificationError {
 	#[error("Script execution error: {0}")]
 	ExecutionError(Box<ErrorContext>),
 
+	/// Errors related to obtaining a pooled client, circuit breaker or concurrency permit
+	#[error("Client pool error: {0}")]
+	ClientPoolError(Box<ErrorContext>),
+
 	/// Error when Notifier `notify`` method fails (e.g., webhook failure, parsing error, invalid signature)
 	#[error("Notification failed: {0}")]
 	NotifyFailed(Box<ErrorContext>),
@@ -38,7 +42,7 @@ impl NotificationError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::NetworkError(Box::new(ErrorContext::new_with_log(msg, source, metadata)))
+		Self::NetworkError(Box::new(ErrorContext::new(msg, source, metadata))).with_log()
 	}
 
 	// Config error
@@ -47,7 +51,7 @@ impl NotificationError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::ConfigError(Box::new(ErrorContext::new_with_log(msg, source, metadata)))
+		Self::ConfigError(Box::new(ErrorContext::new(msg, source, metadata))).with_log()
 	}
 
 	// Internal error
@@ -56,7 +60,7 @@ impl NotificationError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::InternalError(Box::new(ErrorContext::new_with_log(msg, source, metadata)))
+		Self::InternalError(Box::new(ErrorContext::new(msg, source, metadata))).with_log()
 	}
 
 	// Execution error
@@ -65,7 +69,16 @@ impl NotificationError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::ExecutionError(Box::new(ErrorContext::new_with_log(msg, source, metadata)))
+		Self::ExecutionError(Box::new(ErrorContext::new(msg, source, metadata))).with_log()
+	}
+
+	// Client pool error
+	pub fn client_pool_error(
+		msg: impl Into<String>,
+		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+		metadata: Option<HashMap<String, String>>,
+	) -> Self {
+		Self::ClientPoolError(Box::new(ErrorContext::new(msg, source, metadata))).with_log()
 	}
 
 	// Notify failed error
@@ -74,7 +87,54 @@ impl NotificationError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::NotifyFailed(Box::new(ErrorContext::new_with_log(msg, source, metadata)))
+		Self::NotifyFailed(Box::new(ErrorContext::new(msg, source, metadata))).with_log()
+	}
+}
+
+impl NotificationError {
+	/// Returns the machine-readable code classifying this error
+	///
+	/// Delivery failures are classified from the HTTP status recorded in the
+	/// `status_code` metadata, or from the underlying source error.
+	pub fn code(&self) -> ErrorCode {
+		match self {
+			Self::ConfigError(_) => ErrorCode::Config,
+			Self::InternalError(_) => ErrorCode::Internal,
+			Self::ExecutionError(_) => ErrorCode::Script,
+			Self::ClientPoolError(_) => ErrorCode::ClientPool,
+			Self::NetworkError(ctx) => Self::classify(ctx).unwrap_or(ErrorCode::Network),
+			Self::NotifyFailed(ctx) => Self::classify(ctx).unwrap_or(ErrorCode::Unknown),
+		}
+	}
+
+	fn classify(ctx: &ErrorContext) -> Option<ErrorCode> {
+		ctx.metadata
+			.as_ref()
+			.and_then(|m| m.get("status_code"))
+			.and_then(|status| status.parse::<u16>().ok())
+			.and_then(ErrorCode::from_http_status)
+			.or_else(|| {
+				ctx.source
+					.as_ref()
+					.and_then(|source| ErrorCode::from_error(source.as_ref()))
+			})
+	}
+
+	fn context(&self) -> &ErrorContext {
+		match self {
+			Self::NetworkError(ctx) => ctx,
+			Self::ConfigError(ctx) => ctx,
+			Self::InternalError(ctx) => ctx,
+			Self::ExecutionError(ctx) => ctx,
+			Self::ClientPoolError(ctx) => ctx,
+			Self::NotifyFailed(ctx) => ctx,
+		}
+	}
+
+	/// Logs the error together with its error code
+	fn with_log(self) -> Self {
+		self.context().log_with_code(self.code());
+		self
 	}
 }
 
@@ -85,6 +145,7 @@ impl TraceableError for NotificationError {
 			Self::ConfigError(ctx) => ctx.trace_id.clone(),
 			Self::InternalError(ctx) => ctx.trace_id.clone(),
 			Self::ExecutionError(ctx) => ctx.trace_id.clone(),
+			Self::ClientPoolError(ctx) => ctx.trace_id.clone(),
 			Self::NotifyFailed(ctx) => ctx.trace_id.clone(),
 		}
 	}
@@ -203,6 +264,88 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_error_codes_by_variant() {
+		assert_eq!(
+			NotificationError::config_error("bad config", None, None).code(),
+			ErrorCode::Config
+		);
+		assert_eq!(
+			NotificationError::internal_error("oops", None, None).code(),
+			ErrorCode::Internal
+		);
+		assert_eq!(
+			NotificationError::execution_error("script failed", None, None).code(),
+			ErrorCode::Script
+		);
+		assert_eq!(
+			NotificationError::client_pool_error("pool exhausted", None, None).code(),
+			ErrorCode::ClientPool
+		);
+		assert_eq!(
+			NotificationError::network_error("unreachable", None, None).code(),
+			ErrorCode::Network
+		);
+		assert_eq!(
+			NotificationError::notify_failed("failed", None, None).code(),
+			ErrorCode::Unknown
+		);
+	}
+
+	#[test]
+	fn test_error_codes_from_http_status() {
+		let with_status = |status: &str| {
+			NotificationError::notify_failed(
+				"Webhook request failed",
+				None,
+				Some(HashMap::from([(
+					"status_code".to_string(),
+					status.to_string(),
+				)])),
+			)
+		};
+
+		assert_eq!(with_status("503").code(), ErrorCode::Http5xx);
+		assert_eq!(with_status("500").code(), ErrorCode::Http5xx);
+		assert_eq!(with_status("404").code(), ErrorCode::Http4xx);
+		assert_eq!(with_status("401").code(), ErrorCode::Auth);
+		assert_eq!(with_status("403").code(), ErrorCode::Auth);
+		assert_eq!(with_status("408").code(), ErrorCode::Timeout);
+	}
+
+	#[tokio::test]
+	async fn test_error_codes_from_source() {
+		let elapsed = tokio::time::timeout(
+			std::time::Duration::from_millis(1),
+			std::future::pending::<()>(),
+		)
+		.await
+		.unwrap_err();
+		let error = NotificationError::notify_failed("timed out", Some(Box::new(elapsed)), None);
+		assert_eq!(error.code(), ErrorCode::Timeout);
+
+		let io_error = IoError::new(ErrorKind::TimedOut, "timed out");
+		let error = NotificationError::notify_failed("timed out", Some(Box::new(io_error)), None);
+		assert_eq!(error.code(), ErrorCode::Timeout);
+
+		let io_error = IoError::new(ErrorKind::ConnectionRefused, "refused");
+		let error = NotificationError::notify_failed("refused", Some(Box::new(io_error)), None);
+		assert_eq!(error.code(), ErrorCode::Network);
+
+		// Codes are found through nested error contexts
+		let inner = NotificationError::notify_failed(
+			"Webhook request failed",
+			None,
+			Some(HashMap::from([(
+				"status_code".to_string(),
+				"502".to_string(),
+			)])),
+		);
+		let error =
+			NotificationError::notify_failed("retry exhausted", Some(Box::new(inner)), None);
+		assert_eq!(error.code(), ErrorCode::Http5xx);
+	}
+
 	#[test]
 	fn test_all_error_variants_have_and_propagate_consistent_trace_id() {
 		let create_context_with_id = || {
@@ -0,0 +1,514 @@
+//! gRPC notification implementation.
+//!
+//! Provides functionality to send monitor matches to a gRPC service implementing the
+//! `openzeppelin.monitor.v1.NotificationService` contract of `proto/notification.proto`.
+//! Each notification carries the serialized `MonitorMatch` and, when a message is
+//! configured, the rendered title and body.
+
+use async_trait::async_trait;
+use backon::{BackoffBuilder, ExponentialBuilder, Retryable};
+use http::uri::PathAndQuery;
+use std::{collections::HashMap, str::FromStr, sync::Arc, time::Duration};
+use tonic::{
+	codec::ProstCodec,
+	metadata::{MetadataKey, MetadataValue},
+	transport::Channel,
+	Request,
+};
+
+use crate::{
+	models::{MonitorMatch, NotificationMessage, TriggerTypeConfig},
+	services::notification::{template_formatter, NotificationError},
+	utils::{JitterSetting, RetryConfig},
+};
+
+/// Path of the `Notify` method of the notification service
+pub const GRPC_NOTIFY_PATH: &str = "/openzeppelin.monitor.v1.NotificationService/Notify";
+
+/// Notification sent to the gRPC service
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GrpcNotification {
+	/// Name of the monitor that matched
+	#[prost(string, tag = "1")]
+	pub monitor: String,
+	/// Deterministic identifier of the match
+	#[prost(string, tag = "2")]
+	pub match_id: String,
+	/// Rendered notification title, empty when no message is configured
+	#[prost(string, tag = "3")]
+	pub title: String,
+	/// Rendered notification body, empty when no message is configured
+	#[prost(string, tag = "4")]
+	pub body: String,
+	/// Monitor match serialized as JSON
+	#[prost(string, tag = "5")]
+	pub monitor_match: String,
+}
+
+/// Acknowledgement returned by the gRPC service
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GrpcNotificationAck {}
+
+/// Abstraction over a gRPC channel so that delivery can be tested without a server
+#[async_trait]
+pub trait GrpcNotificationClient: Send + Sync {
+	/// Sends a single notification to the `Notify` method of the service
+	///
+	/// # Arguments
+	/// * `request` - Notification request, including its metadata and timeout
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	async fn notify(&self, request: Request<GrpcNotification>) -> Result<(), NotificationError>;
+}
+
+#[async_trait]
+impl GrpcNotificationClient for Channel {
+	async fn notify(&self, request: Request<GrpcNotification>) -> Result<(), NotificationError> {
+		let mut client = tonic::client::Grpc::new(self.clone());
+		client.ready().await.map_err(|e| {
+			NotificationError::network_error(
+				format!("gRPC service is not ready: {}", e),
+				Some(Box::new(e)),
+				None,
+			)
+		})?;
+
+		client
+			.unary::<_, GrpcNotificationAck, _>(
+				request,
+				PathAndQuery::from_static(GRPC_NOTIFY_PATH),
+				ProstCodec::default(),
+			)
+			.await
+			.map(|_| ())
+			.map_err(|status| {
+				let metadata = Some(HashMap::from([(
+					"grpc_status".to_string(),
+					format!("{:?}", status.code()),
+				)]));
+				NotificationError::notify_failed(
+					format!("Failed to send gRPC notification: {}", status.message()),
+					Some(Box::new(status)),
+					metadata,
+				)
+			})
+	}
+}
+
+/// Implementation of gRPC notifications
+pub struct GrpcNotifier<C: GrpcNotificationClient> {
+	/// Metadata sent with each request
+	metadata: HashMap<String, String>,
+	/// Optional message template; only the serialized monitor match is sent when absent
+	message: Option<NotificationMessage>,
+	/// Timeout of each request
+	timeout: Duration,
+	/// gRPC client used for delivery
+	client: Arc<C>,
+	/// Retry policy for notification delivery
+	retry_policy: RetryConfig,
+}
+
+impl<C: GrpcNotificationClient> GrpcNotifier<C> {
+	/// Creates a new gRPC notifier instance
+	///
+	/// # Arguments
+	/// * `client` - gRPC client used for delivery
+	/// * `metadata` - Metadata sent with each request
+	/// * `message` - Optional message template
+	/// * `timeout` - Timeout of each request
+	/// * `retry_policy` - Retry policy for notification delivery
+	///
+	/// # Returns
+	/// * `Self` - gRPC notifier instance
+	pub fn new(
+		client: Arc<C>,
+		metadata: HashMap<String, String>,
+		message: Option<NotificationMessage>,
+		timeout: Duration,
+		retry_policy: RetryConfig,
+	) -> Self {
+		Self {
+			metadata,
+			message,
+			timeout,
+			client,
+			retry_policy,
+		}
+	}
+
+	/// Creates a gRPC notifier from a trigger configuration
+	///
+	/// # Arguments
+	/// * `config` - Trigger configuration containing gRPC parameters
+	/// * `client` - gRPC client used for delivery
+	///
+	/// # Returns
+	/// * `Result<Self, NotificationError>` - Notifier instance if config is gRPC type
+	pub fn from_config(
+		config: &TriggerTypeConfig,
+		client: Arc<C>,
+	) -> Result<Self, NotificationError> {
+		if let TriggerTypeConfig::Grpc {
+			metadata,
+			message,
+			timeout_ms,
+			retry_policy,
+			..
+		} = config
+		{
+			Ok(Self::new(
+				client,
+				metadata.clone(),
+				message.clone(),
+				Duration::from_millis(*timeout_ms),
+				retry_policy.clone(),
+			))
+		} else {
+			Err(NotificationError::config_error(
+				format!("Invalid grpc configuration: {:?}", config),
+				None,
+				None,
+			))
+		}
+	}
+
+	/// Builds the notification for a monitor match
+	///
+	/// # Arguments
+	/// * `variables` - Variables to substitute in the template
+	/// * `monitor_match` - Monitor match to send
+	///
+	/// # Returns
+	/// * `Result<GrpcNotification, NotificationError>` - Notification message
+	pub fn build_notification(
+		&self,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+	) -> Result<GrpcNotification, NotificationError> {
+		let monitor = match monitor_match {
+			MonitorMatch::EVM(evm_match) => &evm_match.monitor,
+			MonitorMatch::Stellar(stellar_match) => &stellar_match.monitor,
+		};
+		let serialized_match = serde_json::to_string(monitor_match).map_err(|e| {
+			NotificationError::internal_error(
+				format!("Failed to serialize monitor match: {}", e),
+				Some(e.into()),
+				None,
+			)
+		})?;
+		let (title, body) = match &self.message {
+			Some(message) => (
+				template_formatter::format_template(&message.title, variables),
+				template_formatter::format_template(&message.body, variables),
+			),
+			None => (String::new(), String::new()),
+		};
+
+		Ok(GrpcNotification {
+			monitor: monitor.name.clone(),
+			match_id: monitor_match.match_id(),
+			title,
+			body,
+			monitor_match: serialized_match,
+		})
+	}
+
+	/// Wraps a notification in a request carrying the configured metadata and timeout
+	fn build_request(
+		&self,
+		notification: GrpcNotification,
+	) -> Result<Request<GrpcNotification>, NotificationError> {
+		let mut request = Request::new(notification);
+		request.set_timeout(self.timeout);
+		for (key, value) in &self.metadata {
+			let key = MetadataKey::from_str(key).map_err(|e| {
+				NotificationError::config_error(
+					format!("Invalid gRPC metadata key '{}'", key),
+					Some(Box::new(e)),
+					None,
+				)
+			})?;
+			let value = MetadataValue::from_str(value).map_err(|e| {
+				NotificationError::config_error(
+					format!("Invalid gRPC metadata value for key '{}'", key),
+					Some(Box::new(e)),
+					None,
+				)
+			})?;
+			request.metadata_mut().insert(key, value);
+		}
+		Ok(request)
+	}
+
+	/// Sends a monitor match to the gRPC service
+	///
+	/// # Arguments
+	/// * `variables` - Variables to substitute in the template
+	/// * `monitor_match` - Monitor match to send
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	pub async fn notify(
+		&self,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+	) -> Result<(), NotificationError> {
+		let notification = self.build_notification(variables, monitor_match)?;
+		// Fail before sending anything if the metadata is invalid
+		self.build_request(notification.clone())?;
+
+		let operation = || async {
+			let request = self.build_request(notification.clone())?;
+			self.client.notify(request).await
+		};
+
+		let backoff = ExponentialBuilder::default()
+			.with_min_delay(self.retry_policy.initial_backoff)
+			.with_max_delay(self.retry_policy.max_backoff);
+
+		let backoff_with_jitter = match self.retry_policy.jitter {
+			JitterSetting::Full => backoff.with_jitter(),
+			JitterSetting::None => backoff,
+		};
+
+		operation
+			.retry(
+				backoff_with_jitter
+					.build()
+					.take(self.retry_policy.max_retries as usize),
+			)
+			.await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{EVMMonitorMatch, EVMTransactionReceipt, MatchConditions},
+		utils::tests::{
+			builders::evm::monitor::MonitorBuilder, evm::transaction::TransactionBuilder,
+		},
+	};
+	use std::sync::atomic::{AtomicUsize, Ordering};
+	use tokio::sync::Mutex;
+
+	/// In-memory client recording every sent request
+	#[derive(Default)]
+	struct InMemoryClient {
+		requests: Mutex<Vec<(GrpcNotification, HashMap<String, String>)>>,
+		failures_remaining: AtomicUsize,
+		attempts: AtomicUsize,
+	}
+
+	impl InMemoryClient {
+		fn failing(failures: usize) -> Self {
+			Self {
+				failures_remaining: AtomicUsize::new(failures),
+				..Default::default()
+			}
+		}
+	}
+
+	#[async_trait]
+	impl GrpcNotificationClient for InMemoryClient {
+		async fn notify(
+			&self,
+			request: Request<GrpcNotification>,
+		) -> Result<(), NotificationError> {
+			self.attempts.fetch_add(1, Ordering::SeqCst);
+			if self
+				.failures_remaining
+				.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+				.is_ok()
+			{
+				return Err(NotificationError::notify_failed(
+					"service unavailable",
+					None,
+					None,
+				));
+			}
+			let metadata = request
+				.metadata()
+				.iter()
+				.filter_map(|entry| match entry {
+					tonic::metadata::KeyAndValueRef::Ascii(key, value) => Some((
+						key.to_string(),
+						value.to_str().unwrap_or_default().to_string(),
+					)),
+					tonic::metadata::KeyAndValueRef::Binary(..) => None,
+				})
+				.collect();
+			self.requests
+				.lock()
+				.await
+				.push((request.into_inner(), metadata));
+			Ok(())
+		}
+	}
+
+	fn create_test_monitor_match() -> MonitorMatch {
+		MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new().name("test_monitor").build(),
+			transaction: TransactionBuilder::new().build(),
+			receipt: Some(EVMTransactionReceipt::default()),
+			revert_reason: None,
+			logs: Some(vec![]),
+			block_timestamp: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: None,
+			enrichment: HashMap::new(),
+		}))
+	}
+
+	fn create_test_variables() -> HashMap<String, String> {
+		HashMap::from([
+			("monitor.name".to_string(), "test_monitor".to_string()),
+			("value".to_string(), "42".to_string()),
+		])
+	}
+
+	fn create_retry_policy(max_retries: u32) -> RetryConfig {
+		RetryConfig {
+			max_retries,
+			initial_backoff: Duration::from_millis(1),
+			max_backoff: Duration::from_millis(2),
+			jitter: JitterSetting::None,
+			..RetryConfig::default()
+		}
+	}
+
+	#[tokio::test]
+	async fn test_notify_sends_serialized_match() {
+		let client = Arc::new(InMemoryClient::default());
+		let notifier = GrpcNotifier::new(
+			client.clone(),
+			HashMap::from([("authorization".to_string(), "Bearer token".to_string())]),
+			None,
+			Duration::from_secs(5),
+			RetryConfig::default(),
+		);
+		let monitor_match = create_test_monitor_match();
+
+		notifier
+			.notify(&create_test_variables(), &monitor_match)
+			.await
+			.unwrap();
+
+		let requests = client.requests.lock().await;
+		assert_eq!(requests.len(), 1);
+		let (notification, metadata) = &requests[0];
+		assert_eq!(notification.monitor, "test_monitor");
+		assert_eq!(notification.match_id, monitor_match.match_id());
+		assert!(notification.title.is_empty());
+		assert!(notification.body.is_empty());
+		assert_eq!(
+			notification.monitor_match,
+			serde_json::to_string(&monitor_match).unwrap()
+		);
+		assert_eq!(metadata["authorization"], "Bearer token");
+		assert_eq!(metadata["grpc-timeout"], "5000000u");
+	}
+
+	#[tokio::test]
+	async fn test_notify_sends_rendered_template() {
+		let client = Arc::new(InMemoryClient::default());
+		let notifier = GrpcNotifier::new(
+			client.clone(),
+			HashMap::new(),
+			Some(NotificationMessage {
+				title: "Alert for ${monitor.name}".to_string(),
+				body: "Value is ${value}".to_string(),
+			}),
+			Duration::from_secs(5),
+			RetryConfig::default(),
+		);
+
+		notifier
+			.notify(&create_test_variables(), &create_test_monitor_match())
+			.await
+			.unwrap();
+
+		let requests = client.requests.lock().await;
+		let (notification, _) = &requests[0];
+		assert_eq!(notification.title, "Alert for test_monitor");
+		assert_eq!(notification.body, "Value is 42");
+	}
+
+	#[tokio::test]
+	async fn test_notify_retries_until_success() {
+		let client = Arc::new(InMemoryClient::failing(2));
+		let notifier = GrpcNotifier::new(
+			client.clone(),
+			HashMap::new(),
+			None,
+			Duration::from_secs(5),
+			create_retry_policy(3),
+		);
+
+		let result = notifier
+			.notify(&create_test_variables(), &create_test_monitor_match())
+			.await;
+
+		assert!(result.is_ok());
+		assert_eq!(client.attempts.load(Ordering::SeqCst), 3);
+		assert_eq!(client.requests.lock().await.len(), 1);
+	}
+
+	#[tokio::test]
+	async fn test_notify_fails_after_all_retries() {
+		let client = Arc::new(InMemoryClient::failing(usize::MAX));
+		let notifier = GrpcNotifier::new(
+			client.clone(),
+			HashMap::new(),
+			None,
+			Duration::from_secs(5),
+			create_retry_policy(2),
+		);
+
+		let result = notifier
+			.notify(&create_test_variables(), &create_test_monitor_match())
+			.await;
+
+		assert!(matches!(result, Err(NotificationError::NotifyFailed(_))));
+		assert_eq!(client.attempts.load(Ordering::SeqCst), 3);
+		assert!(client.requests.lock().await.is_empty());
+	}
+
+	#[tokio::test]
+	async fn test_notify_rejects_invalid_metadata() {
+		let client = Arc::new(InMemoryClient::default());
+		let notifier = GrpcNotifier::new(
+			client.clone(),
+			HashMap::from([("invalid key".to_string(), "value".to_string())]),
+			None,
+			Duration::from_secs(5),
+			create_retry_policy(2),
+		);
+
+		let result = notifier
+			.notify(&create_test_variables(), &create_test_monitor_match())
+			.await;
+
+		assert!(matches!(result, Err(NotificationError::ConfigError(_))));
+		assert_eq!(client.attempts.load(Ordering::SeqCst), 0);
+	}
+
+	#[test]
+	fn test_from_config_invalid_type() {
+		let config = TriggerTypeConfig::Script {
+			language: crate::models::ScriptLanguage::Bash,
+			script_path: "script.sh".to_string(),
+			arguments: None,
+			timeout_ms: 1000,
+			script_checksum: None,
+			retry_policy: None,
+		};
+
+		let result = GrpcNotifier::from_config(&config, Arc::new(InMemoryClient::default()));
+		assert!(matches!(result, Err(NotificationError::ConfigError(_))));
+	}
+}
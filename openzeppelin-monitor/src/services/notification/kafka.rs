@@ -0,0 +1,421 @@
+//! Kafka notification implementation.
+//!
+//! Provides functionality to publish monitor matches to a Kafka topic, either as the
+//! serialized `MonitorMatch` or as a rendered message template with variable substitution.
+
+use async_trait::async_trait;
+use backon::{BackoffBuilder, ExponentialBuilder, Retryable};
+use rdkafka::{
+	producer::{FutureProducer, FutureRecord},
+	util::Timeout,
+};
+use serde_json::json;
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use crate::{
+	models::{MonitorMatch, NotificationMessage, TriggerTypeConfig},
+	services::notification::{template_formatter, NotificationError},
+	utils::{JitterSetting, RetryConfig},
+};
+
+/// Maximum time a record may wait in the producer queue before the send is aborted
+const KAFKA_QUEUE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Abstraction over a Kafka producer so that delivery can be tested without a broker
+#[async_trait]
+pub trait KafkaProducer: Send + Sync {
+	/// Publishes a single record to the given topic
+	///
+	/// # Arguments
+	/// * `topic` - Topic to publish the record to
+	/// * `key` - Optional record key
+	/// * `payload` - Serialized record payload
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	async fn send(
+		&self,
+		topic: &str,
+		key: Option<&str>,
+		payload: &str,
+	) -> Result<(), NotificationError>;
+}
+
+#[async_trait]
+impl KafkaProducer for FutureProducer {
+	async fn send(
+		&self,
+		topic: &str,
+		key: Option<&str>,
+		payload: &str,
+	) -> Result<(), NotificationError> {
+		let mut record = FutureRecord::<str, str>::to(topic).payload(payload);
+		if let Some(key) = key {
+			record = record.key(key);
+		}
+
+		FutureProducer::send(self, record, Timeout::After(KAFKA_QUEUE_TIMEOUT))
+			.await
+			.map(|_| ())
+			.map_err(|(e, _)| {
+				NotificationError::notify_failed(
+					format!("Failed to publish Kafka record: {}", e),
+					Some(Box::new(e)),
+					Some(HashMap::from([("topic".to_string(), topic.to_string())])),
+				)
+			})
+	}
+}
+
+/// Implementation of Kafka notifications
+pub struct KafkaNotifier<P: KafkaProducer> {
+	/// Topic to publish records to
+	topic: String,
+	/// Optional record key template with variable placeholders
+	key_template: Option<String>,
+	/// Optional message template; the serialized monitor match is published when absent
+	message: Option<NotificationMessage>,
+	/// Kafka producer used for delivery
+	producer: Arc<P>,
+	/// Retry policy for record delivery
+	retry_policy: RetryConfig,
+}
+
+impl<P: KafkaProducer> KafkaNotifier<P> {
+	/// Creates a new Kafka notifier instance
+	///
+	/// # Arguments
+	/// * `producer` - Kafka producer used for delivery
+	/// * `topic` - Topic to publish records to
+	/// * `key_template` - Optional record key template
+	/// * `message` - Optional message template
+	/// * `retry_policy` - Retry policy for record delivery
+	///
+	/// # Returns
+	/// * `Self` - Kafka notifier instance
+	pub fn new(
+		producer: Arc<P>,
+		topic: String,
+		key_template: Option<String>,
+		message: Option<NotificationMessage>,
+		retry_policy: RetryConfig,
+	) -> Self {
+		Self {
+			topic,
+			key_template,
+			message,
+			producer,
+			retry_policy,
+		}
+	}
+
+	/// Creates a Kafka notifier from a trigger configuration
+	///
+	/// # Arguments
+	/// * `config` - Trigger configuration containing Kafka parameters
+	/// * `producer` - Kafka producer used for delivery
+	///
+	/// # Returns
+	/// * `Result<Self, NotificationError>` - Notifier instance if config is Kafka type
+	pub fn from_config(
+		config: &TriggerTypeConfig,
+		producer: Arc<P>,
+	) -> Result<Self, NotificationError> {
+		if let TriggerTypeConfig::Kafka {
+			topic,
+			key_template,
+			message,
+			retry_policy,
+			..
+		} = config
+		{
+			Ok(Self::new(
+				producer,
+				topic.clone(),
+				key_template.clone(),
+				message.clone(),
+				retry_policy.clone(),
+			))
+		} else {
+			Err(NotificationError::config_error(
+				format!("Invalid kafka configuration: {:?}", config),
+				None,
+				None,
+			))
+		}
+	}
+
+	/// Builds the record key and payload for a monitor match
+	///
+	/// The key is rendered from the key template when one is configured. The payload is the
+	/// rendered message (as a `title`/`body` JSON object) when a message is configured,
+	/// otherwise the serialized monitor match.
+	///
+	/// # Arguments
+	/// * `variables` - Variables to substitute in the templates
+	/// * `monitor_match` - Monitor match to publish
+	///
+	/// # Returns
+	/// * `Result<(Option<String>, String), NotificationError>` - Record key and payload
+	pub fn build_record(
+		&self,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+	) -> Result<(Option<String>, String), NotificationError> {
+		let key = self
+			.key_template
+			.as_ref()
+			.map(|template| template_formatter::format_template(template, variables));
+
+		let payload = match &self.message {
+			Some(message) => json!({
+				"title": template_formatter::format_template(&message.title, variables),
+				"body": template_formatter::format_template(&message.body, variables),
+			})
+			.to_string(),
+			None => serde_json::to_string(monitor_match).map_err(|e| {
+				NotificationError::internal_error(
+					format!("Failed to serialize monitor match: {}", e),
+					Some(e.into()),
+					None,
+				)
+			})?,
+		};
+
+		Ok((key, payload))
+	}
+
+	/// Publishes a monitor match to the configured topic
+	///
+	/// # Arguments
+	/// * `variables` - Variables to substitute in the templates
+	/// * `monitor_match` - Monitor match to publish
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	pub async fn notify(
+		&self,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+	) -> Result<(), NotificationError> {
+		let (key, payload) = self.build_record(variables, monitor_match)?;
+
+		let operation = || async {
+			self.producer
+				.send(&self.topic, key.as_deref(), &payload)
+				.await
+		};
+
+		let backoff = ExponentialBuilder::default()
+			.with_min_delay(self.retry_policy.initial_backoff)
+			.with_max_delay(self.retry_policy.max_backoff);
+
+		let backoff_with_jitter = match self.retry_policy.jitter {
+			JitterSetting::Full => backoff.with_jitter(),
+			JitterSetting::None => backoff,
+		};
+
+		operation
+			.retry(
+				backoff_with_jitter
+					.build()
+					.take(self.retry_policy.max_retries as usize),
+			)
+			.await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{EVMMonitorMatch, EVMTransactionReceipt, MatchConditions},
+		utils::tests::{
+			builders::evm::monitor::MonitorBuilder, evm::transaction::TransactionBuilder,
+		},
+	};
+	use std::sync::atomic::{AtomicUsize, Ordering};
+	use tokio::sync::Mutex;
+
+	/// In-memory producer recording every published record
+	#[derive(Default)]
+	struct InMemoryProducer {
+		records: Mutex<Vec<(String, Option<String>, String)>>,
+		failures_remaining: AtomicUsize,
+		attempts: AtomicUsize,
+	}
+
+	impl InMemoryProducer {
+		fn failing(failures: usize) -> Self {
+			Self {
+				failures_remaining: AtomicUsize::new(failures),
+				..Default::default()
+			}
+		}
+	}
+
+	#[async_trait]
+	impl KafkaProducer for InMemoryProducer {
+		async fn send(
+			&self,
+			topic: &str,
+			key: Option<&str>,
+			payload: &str,
+		) -> Result<(), NotificationError> {
+			self.attempts.fetch_add(1, Ordering::SeqCst);
+			if self
+				.failures_remaining
+				.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+				.is_ok()
+			{
+				return Err(NotificationError::notify_failed("broker down", None, None));
+			}
+			self.records.lock().await.push((
+				topic.to_string(),
+				key.map(str::to_string),
+				payload.to_string(),
+			));
+			Ok(())
+		}
+	}
+
+	fn create_test_monitor_match() -> MonitorMatch {
+		MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new().name("test_monitor").build(),
+			transaction: TransactionBuilder::new().build(),
+			receipt: Some(EVMTransactionReceipt::default()),
+			revert_reason: None,
+			logs: Some(vec![]),
+			block_timestamp: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: None,
+			enrichment: HashMap::new(),
+		}))
+	}
+
+	fn create_test_variables() -> HashMap<String, String> {
+		HashMap::from([
+			("monitor.name".to_string(), "test_monitor".to_string()),
+			("value".to_string(), "42".to_string()),
+		])
+	}
+
+	fn create_retry_policy(max_retries: u32) -> RetryConfig {
+		RetryConfig {
+			max_retries,
+			initial_backoff: Duration::from_millis(1),
+			max_backoff: Duration::from_millis(2),
+			jitter: JitterSetting::None,
+			..RetryConfig::default()
+		}
+	}
+
+	#[tokio::test]
+	async fn test_notify_publishes_serialized_match_with_key() {
+		let producer = Arc::new(InMemoryProducer::default());
+		let notifier = KafkaNotifier::new(
+			producer.clone(),
+			"alerts".to_string(),
+			Some("${monitor.name}-${value}".to_string()),
+			None,
+			RetryConfig::default(),
+		);
+		let monitor_match = create_test_monitor_match();
+
+		notifier
+			.notify(&create_test_variables(), &monitor_match)
+			.await
+			.unwrap();
+
+		let records = producer.records.lock().await;
+		assert_eq!(records.len(), 1);
+		let (topic, key, payload) = &records[0];
+		assert_eq!(topic, "alerts");
+		assert_eq!(key.as_deref(), Some("test_monitor-42"));
+		assert_eq!(payload, &serde_json::to_string(&monitor_match).unwrap());
+	}
+
+	#[tokio::test]
+	async fn test_notify_publishes_rendered_template_without_key() {
+		let producer = Arc::new(InMemoryProducer::default());
+		let notifier = KafkaNotifier::new(
+			producer.clone(),
+			"alerts".to_string(),
+			None,
+			Some(NotificationMessage {
+				title: "Alert for ${monitor.name}".to_string(),
+				body: "Value is ${value}".to_string(),
+			}),
+			RetryConfig::default(),
+		);
+
+		notifier
+			.notify(&create_test_variables(), &create_test_monitor_match())
+			.await
+			.unwrap();
+
+		let records = producer.records.lock().await;
+		let (_, key, payload) = &records[0];
+		assert!(key.is_none());
+		assert_eq!(
+			serde_json::from_str::<serde_json::Value>(payload).unwrap(),
+			json!({"title": "Alert for test_monitor", "body": "Value is 42"})
+		);
+	}
+
+	#[tokio::test]
+	async fn test_notify_retries_until_success() {
+		let producer = Arc::new(InMemoryProducer::failing(2));
+		let notifier = KafkaNotifier::new(
+			producer.clone(),
+			"alerts".to_string(),
+			None,
+			None,
+			create_retry_policy(3),
+		);
+
+		let result = notifier
+			.notify(&create_test_variables(), &create_test_monitor_match())
+			.await;
+
+		assert!(result.is_ok());
+		assert_eq!(producer.attempts.load(Ordering::SeqCst), 3);
+		assert_eq!(producer.records.lock().await.len(), 1);
+	}
+
+	#[tokio::test]
+	async fn test_notify_fails_after_all_retries() {
+		let producer = Arc::new(InMemoryProducer::failing(usize::MAX));
+		let notifier = KafkaNotifier::new(
+			producer.clone(),
+			"alerts".to_string(),
+			None,
+			None,
+			create_retry_policy(2),
+		);
+
+		let result = notifier
+			.notify(&create_test_variables(), &create_test_monitor_match())
+			.await;
+
+		assert!(matches!(result, Err(NotificationError::NotifyFailed(_))));
+		assert_eq!(producer.attempts.load(Ordering::SeqCst), 3);
+		assert!(producer.records.lock().await.is_empty());
+	}
+
+	#[test]
+	fn test_from_config_invalid_type() {
+		let config = TriggerTypeConfig::Script {
+			language: crate::models::ScriptLanguage::Bash,
+			script_path: "script.sh".to_string(),
+			arguments: None,
+			timeout_ms: 1000,
+			script_checksum: None,
+			retry_policy: None,
+		};
+
+		let result = KafkaNotifier::from_config(&config, Arc::new(InMemoryProducer::default()));
+		assert!(matches!(result, Err(NotificationError::ConfigError(_))));
+	}
+}
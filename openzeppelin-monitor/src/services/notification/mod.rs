@@ -5,10 +5,16 @@
 
 use async_trait::async_trait;
 
-use std::{collections::HashMap, sync::Arc};
+use std::{borrow::Cow, collections::HashMap, sync::Arc};
 
+mod audit;
+mod circuit_breaker;
+mod dead_letter;
 mod email;
 mod error;
+mod grpc;
+mod kafka;
+mod nats;
 pub mod payload_builder;
 mod pool;
 mod script;
@@ -19,18 +25,37 @@ use crate::{
 	models::{
 		MonitorMatch, NotificationMessage, ScriptLanguage, Trigger, TriggerType, TriggerTypeConfig,
 	},
-	utils::{normalize_string, RetryConfig},
+	services::filter::SEVERITY_VARIABLE,
+	utils::{
+		metrics::record_notification,
+		normalize_string,
+		timestamp::{insert_timestamp_variables, parse_timezone, BLOCK_TIMESTAMP_VARIABLE},
+		RetryConfig,
+	},
 };
 
+pub use audit::{AuditLog, AuditOutcome, AuditRecord, FileAuditLog, AUDIT_LOG_PATH_ENV};
+pub use circuit_breaker::{
+	CircuitBreaker, CircuitBreakerConfig, CircuitState, CIRCUIT_BREAKER_COOLDOWN_ENV,
+	CIRCUIT_BREAKER_THRESHOLD_ENV,
+};
+pub use dead_letter::{DeadLetterRecord, DeadLetterStore, DEAD_LETTER_PATH_ENV};
 pub use email::{EmailContent, EmailNotifier, SmtpConfig};
 pub use error::NotificationError;
+pub use grpc::{
+	GrpcNotification, GrpcNotificationAck, GrpcNotificationClient, GrpcNotifier, GRPC_NOTIFY_PATH,
+};
+pub use kafka::{KafkaNotifier, KafkaProducer};
+pub use nats::{NatsNotifier, NatsPublisher};
 pub use payload_builder::{
 	DiscordPayloadBuilder, GenericWebhookPayloadBuilder, SlackPayloadBuilder,
 	TelegramPayloadBuilder, WebhookPayloadBuilder,
 };
 pub use pool::NotificationClientPool;
 pub use script::ScriptNotifier;
-pub use webhook::{WebhookConfig, WebhookNotifier};
+pub use webhook::{
+	verify_webhook_signature, WebhookConfig, WebhookNotifier, WebhookSignatureAlgorithm,
+};
 
 /// A container for all components needed to configure and send a webhook notification.
 struct WebhookComponents {
@@ -67,6 +92,7 @@ impl AsWebhookComponents for TriggerTypeConfig {
 				method,
 				secret,
 				headers,
+				payload_fields,
 				..
 			} => (
 				url.as_ref().to_string(),
@@ -74,11 +100,15 @@ impl AsWebhookComponents for TriggerTypeConfig {
 				method.clone(),
 				secret.as_ref().map(|s| s.as_ref().to_string()),
 				headers.clone(),
-				Box::new(GenericWebhookPayloadBuilder),
+				Box::new(GenericWebhookPayloadBuilder {
+					payload_fields: payload_fields.clone(),
+				}),
 			),
 			TriggerTypeConfig::Discord {
 				discord_url,
 				message,
+				max_message_length,
+				embed,
 				..
 			} => (
 				discord_url.as_ref().to_string(),
@@ -86,13 +116,17 @@ impl AsWebhookComponents for TriggerTypeConfig {
 				Some("POST".to_string()),
 				None,
 				None,
-				Box::new(DiscordPayloadBuilder),
+				Box::new(DiscordPayloadBuilder {
+					max_message_length: *max_message_length,
+					embed: embed.clone(),
+				}),
 			),
 			TriggerTypeConfig::Telegram {
 				token,
 				message,
 				chat_id,
 				disable_web_preview,
+				max_message_length,
 				..
 			} => (
 				format!("https://api.telegram.org/bot{}/sendMessage", token),
@@ -103,17 +137,23 @@ impl AsWebhookComponents for TriggerTypeConfig {
 				Box::new(TelegramPayloadBuilder {
 					chat_id: chat_id.clone(),
 					disable_web_preview: disable_web_preview.unwrap_or(false),
+					max_message_length: *max_message_length,
 				}),
 			),
 			TriggerTypeConfig::Slack {
-				slack_url, message, ..
+				slack_url,
+				message,
+				max_message_length,
+				..
 			} => (
 				slack_url.as_ref().to_string(),
 				message.clone(),
 				Some("POST".to_string()),
 				None,
 				None,
-				Box::new(SlackPayloadBuilder),
+				Box::new(SlackPayloadBuilder {
+					max_message_length: *max_message_length,
+				}),
 			),
 			_ => {
 				return Err(NotificationError::config_error(
@@ -124,6 +164,11 @@ impl AsWebhookComponents for TriggerTypeConfig {
 			}
 		};
 
+		let payload_fields = match self {
+			TriggerTypeConfig::Webhook { payload_fields, .. } => payload_fields.clone(),
+			_ => None,
+		};
+
 		// Construct the final WebhookConfig from the extracted parts.
 		let config = WebhookConfig {
 			url,
@@ -133,7 +178,7 @@ impl AsWebhookComponents for TriggerTypeConfig {
 			secret,
 			headers,
 			url_params: None,
-			payload_fields: None,
+			payload_fields,
 		};
 
 		// Use the retry policy from the trigger config
@@ -174,19 +219,102 @@ pub trait ScriptExecutor {
 	) -> Result<(), NotificationError>;
 }
 
+/// Interface for custom notification channels
+///
+/// Notifiers registered with a [`NotificationServiceBuilder`] send the notifications of
+/// triggers of type `TriggerType::Custom` with their registered name.
+#[async_trait]
+pub trait Notifier: Send + Sync {
+	/// Sends a notification for a match
+	///
+	/// # Arguments
+	/// * `trigger` - Trigger whose notification is sent, holding the custom configuration
+	/// * `variables` - Variables to substitute in the trigger's message template
+	/// * `monitor_match` - The match the notification is sent for
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	async fn notify(
+		&self,
+		trigger: &Trigger,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+	) -> Result<(), NotificationError>;
+}
+
 /// Service for managing notifications across different channels
 pub struct NotificationService {
-	/// Client pool for managing notification clients (HTTP, SMTP)
+	/// Client pool for managing notification clients (HTTP, SMTP, Kafka, NATS)
 	client_pool: Arc<NotificationClientPool>,
+	/// Notifiers of custom triggers keyed by name
+	custom_notifiers: HashMap<String, Arc<dyn Notifier>>,
+	/// Audit log recording every notification sent, if enabled
+	audit_log: Option<Arc<dyn AuditLog>>,
 }
 
-impl NotificationService {
-	/// Creates a new notification service instance
+/// Builder for a notification service with custom notifiers
+#[derive(Default)]
+pub struct NotificationServiceBuilder {
+	client_pool: Option<Arc<NotificationClientPool>>,
+	custom_notifiers: HashMap<String, Arc<dyn Notifier>>,
+	audit_log: Option<Arc<dyn AuditLog>>,
+}
+
+impl NotificationServiceBuilder {
+	/// Creates a builder for a service with a new client pool and no custom notifier
 	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Sends the built-in channels through the given client pool
+	pub fn client_pool(mut self, client_pool: Arc<NotificationClientPool>) -> Self {
+		self.client_pool = Some(client_pool);
+		self
+	}
+
+	/// Registers the notifier of the custom triggers with the given name
+	///
+	/// Registering a notifier under an existing name replaces the previous notifier.
+	pub fn notifier(mut self, name: impl Into<String>, notifier: Arc<dyn Notifier>) -> Self {
+		self.custom_notifiers.insert(name.into(), notifier);
+		self
+	}
+
+	/// Records every notification sent in the given audit log
+	pub fn audit_log(mut self, audit_log: Arc<dyn AuditLog>) -> Self {
+		self.audit_log = Some(audit_log);
+		self
+	}
+
+	/// Builds the notification service
+	pub fn build(self) -> NotificationService {
 		NotificationService {
-			client_pool: Arc::new(NotificationClientPool::new()),
+			client_pool: self
+				.client_pool
+				.unwrap_or_else(|| Arc::new(NotificationClientPool::new())),
+			custom_notifiers: self.custom_notifiers,
+			audit_log: self.audit_log,
 		}
 	}
+}
+
+impl NotificationService {
+	/// Creates a new notification service instance
+	pub fn new() -> Self {
+		NotificationServiceBuilder::new().build()
+	}
+
+	/// Creates a notification service that sends through the given client pool
+	pub fn with_client_pool(client_pool: Arc<NotificationClientPool>) -> Self {
+		NotificationServiceBuilder::new()
+			.client_pool(client_pool)
+			.build()
+	}
+
+	/// Returns a builder for a notification service with custom notifiers
+	pub fn builder() -> NotificationServiceBuilder {
+		NotificationServiceBuilder::new()
+	}
 
 	/// Executes a notification based on the trigger configuration
 	///
@@ -197,6 +325,11 @@ impl NotificationService {
 	/// * `trigger_scripts` - Contains the script content to execute (needed for custom script
 	///   trigger)
 	///
+	/// The trigger labels are added to the variables as `trigger.labels.<name>`, and
+	/// `block.timestamp_iso` is formatted in the trigger's `timezone`, if set. The outcome
+	/// is recorded in the notification metrics and in the audit log, if enabled. Failing to
+	/// write the audit record is logged and does not fail the notification.
+	///
 	/// # Returns
 	/// * `Result<(), NotificationError>` - Success or error
 	pub async fn execute(
@@ -206,6 +339,84 @@ impl NotificationService {
 		monitor_match: &MonitorMatch,
 		trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
 	) -> Result<(), NotificationError> {
+		let mut variables = Cow::Borrowed(variables);
+		for (name, value) in &trigger.labels {
+			variables
+				.to_mut()
+				.insert(format!("trigger.labels.{}", name), value.clone());
+		}
+
+		// Format the block timestamp in the timezone of the trigger, if any
+		let timezone = trigger.timezone.as_deref().and_then(parse_timezone);
+		let block_timestamp = variables
+			.get(BLOCK_TIMESTAMP_VARIABLE)
+			.and_then(|timestamp| timestamp.parse::<i64>().ok());
+		if let (Some(timezone), Some(block_timestamp)) = (timezone, block_timestamp) {
+			insert_timestamp_variables(variables.to_mut(), block_timestamp, timezone);
+		}
+
+		let result = self
+			.send(trigger, &variables, monitor_match, trigger_scripts)
+			.await;
+
+		let monitor = match monitor_match {
+			MonitorMatch::EVM(evm_match) => &evm_match.monitor,
+			MonitorMatch::Stellar(stellar_match) => &stellar_match.monitor,
+		};
+		record_notification(monitor, trigger, result.is_ok());
+
+		if let Some(audit_log) = &self.audit_log {
+			let record = AuditRecord::new(
+				trigger,
+				monitor_match,
+				result.as_ref().err().map(|e| e.to_string()),
+			);
+			if let Err(e) = audit_log.record(&record).await {
+				tracing::error!(
+					monitor = %record.monitor,
+					trigger = %record.trigger,
+					match_id = %record.match_id,
+					"Failed to write notification audit record: {}",
+					e
+				);
+			}
+		}
+
+		result
+	}
+
+	/// Sends a notification through the channel of the trigger
+	///
+	/// The `notification_retry` of the monitor, when set, replaces the retry policy of the
+	/// trigger, and the template of the trigger named after the severity of the match, if any,
+	/// replaces its message.
+	async fn send(
+		&self,
+		trigger: &Trigger,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+		trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
+	) -> Result<(), NotificationError> {
+		let monitor = match monitor_match {
+			MonitorMatch::EVM(evm_match) => &evm_match.monitor,
+			MonitorMatch::Stellar(stellar_match) => &stellar_match.monitor,
+		};
+		let template = variables
+			.get(SEVERITY_VARIABLE)
+			.and_then(|severity| trigger.templates.get(severity));
+		let trigger = if monitor.notification_retry.is_some() || template.is_some() {
+			let mut trigger = trigger.clone();
+			if let Some(retry_policy) = &monitor.notification_retry {
+				trigger.config.set_retry_policy(retry_policy.clone());
+			}
+			if let Some(template) = template {
+				trigger.config.set_message(template.clone());
+			}
+			Cow::Owned(trigger)
+		} else {
+			Cow::Borrowed(trigger)
+		};
+
 		match &trigger.trigger_type {
 			// Match Webhook-based triggers
 			TriggerType::Slack
@@ -213,7 +424,7 @@ impl NotificationService {
 			| TriggerType::Webhook
 			| TriggerType::Telegram => {
 				// Use the Webhookable trait to get config, retry policy and payload builder
-				let components = trigger.config.as_webhook_components()?;
+				let mut components = trigger.config.as_webhook_components()?;
 
 				// Get or create the HTTP client from the pool based on the retry policy
 				let http_client = self
@@ -221,7 +432,7 @@ impl NotificationService {
 					.get_or_create_http_client(&components.retry_policy)
 					.await
 					.map_err(|e| {
-						NotificationError::execution_error(
+						NotificationError::client_pool_error(
 							"Failed to get or create HTTP client from pool".to_string(),
 							Some(e.into()),
 							None,
@@ -235,10 +446,77 @@ impl NotificationService {
 					variables,
 				);
 
-				// Create the notifier
+				// Create the notifier. The circuit breaker and concurrency limit are keyed by the
+				// configured URL, shared by every URL it expands to
+				let endpoint = components.config.url.clone();
+				components.config.url = components.builder.build_url(&endpoint, variables);
 				let notifier = WebhookNotifier::new(components.config, http_client)?;
 
-				notifier.notify_json(&payload).await?;
+				// Fail fast while the endpoint's circuit is open instead of running the full
+				// retry policy against an endpoint that is known to be down
+				let circuit_breaker = if self.client_pool.circuit_breaker_config().is_enabled() {
+					let breaker = self
+						.client_pool
+						.get_or_create_circuit_breaker(&endpoint)
+						.await
+						.map_err(|e| {
+							NotificationError::client_pool_error(
+								"Failed to get or create circuit breaker from pool".to_string(),
+								Some(e.into()),
+								None,
+							)
+						})?;
+					if !breaker.try_acquire() {
+						return Err(NotificationError::notify_failed(
+							format!(
+								"Circuit breaker is open for the endpoint of trigger '{}', \
+								 skipping notification",
+								trigger.name
+							),
+							None,
+							None,
+						));
+					}
+					Some(breaker)
+				} else {
+					None
+				};
+
+				// Bound the number of in-flight notifications to the endpoint, if configured.
+				// Notifications over the limit wait for a permit rather than being dropped.
+				let _permit = match trigger.config.get_max_concurrent_notifications() {
+					Some(max_concurrent) => {
+						let semaphore = self
+							.client_pool
+							.get_or_create_channel_semaphore(&endpoint, max_concurrent)
+							.await
+							.map_err(|e| {
+								NotificationError::client_pool_error(
+									"Failed to get or create channel semaphore from pool"
+										.to_string(),
+									Some(e.into()),
+									None,
+								)
+							})?;
+						Some(semaphore.acquire_owned().await.map_err(|e| {
+							NotificationError::client_pool_error(
+								"Failed to acquire notification permit".to_string(),
+								Some(e.into()),
+								None,
+							)
+						})?)
+					}
+					None => None,
+				};
+
+				let result = notifier.notify_json(&payload).await;
+				if let Some(breaker) = circuit_breaker {
+					match &result {
+						Ok(_) => breaker.record_success(),
+						Err(_) => breaker.record_failure(),
+					}
+				}
+				result?;
 			}
 			TriggerType::Email => {
 				// Extract SMTP configuration from the trigger
@@ -270,7 +548,7 @@ impl NotificationService {
 					.get_or_create_smtp_client(&smtp_config)
 					.await
 					.map_err(|e| {
-						NotificationError::execution_error(
+						NotificationError::client_pool_error(
 							"Failed to get SMTP client from pool".to_string(),
 							Some(e.into()),
 							None,
@@ -278,8 +556,9 @@ impl NotificationService {
 					})?;
 
 				let notifier = EmailNotifier::from_config(&trigger.config, smtp_client)?;
-				let message = EmailNotifier::format_message(notifier.body_template(), variables);
-				notifier.notify(&message).await?;
+				let subject = EmailNotifier::format_subject(notifier.subject(), variables);
+				let message = notifier.format_body(variables);
+				notifier.notify_with_subject(&subject, &message).await?;
 			}
 			TriggerType::Script => {
 				let notifier = ScriptNotifier::from_config(&trigger.config)?;
@@ -321,6 +600,100 @@ impl NotificationService {
 					.script_notify(monitor_match, script_content)
 					.await?;
 			}
+			TriggerType::Kafka => {
+				let brokers = match &trigger.config {
+					TriggerTypeConfig::Kafka { brokers, .. } => brokers,
+					_ => {
+						return Err(NotificationError::config_error(
+							"Invalid kafka configuration".to_string(),
+							None,
+							None,
+						));
+					}
+				};
+
+				// Get or create the Kafka producer from the pool
+				let producer = self
+					.client_pool
+					.get_or_create_kafka_producer(brokers)
+					.await
+					.map_err(|e| {
+						NotificationError::client_pool_error(
+							"Failed to get Kafka producer from pool".to_string(),
+							Some(e.into()),
+							None,
+						)
+					})?;
+
+				let notifier = KafkaNotifier::from_config(&trigger.config, producer)?;
+				notifier.notify(variables, monitor_match).await?;
+			}
+			TriggerType::Nats => {
+				let server_url = match &trigger.config {
+					TriggerTypeConfig::Nats { server_url, .. } => server_url,
+					_ => {
+						return Err(NotificationError::config_error(
+							"Invalid nats configuration".to_string(),
+							None,
+							None,
+						));
+					}
+				};
+
+				// Get or create the NATS client from the pool
+				let client = self
+					.client_pool
+					.get_or_create_nats_client(server_url)
+					.await
+					.map_err(|e| {
+						NotificationError::client_pool_error(
+							"Failed to get NATS client from pool".to_string(),
+							Some(e.into()),
+							None,
+						)
+					})?;
+
+				let notifier = NatsNotifier::from_config(&trigger.config, client)?;
+				notifier.notify(variables, monitor_match).await?;
+			}
+			TriggerType::Grpc => {
+				let endpoint = match &trigger.config {
+					TriggerTypeConfig::Grpc { endpoint, .. } => endpoint,
+					_ => {
+						return Err(NotificationError::config_error(
+							"Invalid grpc configuration".to_string(),
+							None,
+							None,
+						));
+					}
+				};
+
+				// Get or create the gRPC channel from the pool
+				let channel = self
+					.client_pool
+					.get_or_create_grpc_channel(endpoint)
+					.await
+					.map_err(|e| {
+						NotificationError::client_pool_error(
+							"Failed to get gRPC channel from pool".to_string(),
+							Some(e.into()),
+							None,
+						)
+					})?;
+
+				let notifier = GrpcNotifier::from_config(&trigger.config, channel)?;
+				notifier.notify(variables, monitor_match).await?;
+			}
+			TriggerType::Custom(name) => {
+				let notifier = self.custom_notifiers.get(name).ok_or_else(|| {
+					NotificationError::config_error(
+						format!("No notifier registered for custom trigger type '{}'", name),
+						None,
+						None,
+					)
+				})?;
+				notifier.notify(&trigger, variables, monitor_match).await?;
+			}
 		}
 		Ok(())
 	}
@@ -382,14 +755,20 @@ mod tests {
 			monitor: create_test_monitor(vec![], vec![], vec![], vec![]),
 			transaction: TransactionBuilder::new().build(),
 			receipt: Some(EVMTransactionReceipt::default()),
+			revert_reason: None,
 			logs: Some(vec![]),
+			block_timestamp: None,
 			network_slug: "evm_mainnet".to_string(),
 			matched_on: MatchConditions {
 				functions: vec![],
 				events: vec![],
 				transactions: vec![],
+				operations: vec![],
+				aggregates: vec![],
+				percentage_changes: vec![],
 			},
 			matched_on_args: None,
+			enrichment: HashMap::new(),
 		}))
 	}
 
@@ -571,6 +950,62 @@ mod tests {
 		}
 	}
 
+	#[tokio::test]
+	async fn test_kafka_notification_invalid_config() {
+		let service = NotificationService::new();
+
+		let trigger = TriggerBuilder::new()
+			.name("test_kafka")
+			.script("invalid", ScriptLanguage::Python)
+			.trigger_type(TriggerType::Kafka) // Intentionally wrong config type
+			.build();
+
+		let variables = HashMap::new();
+		let result = service
+			.execute(
+				&trigger,
+				&variables,
+				&create_mock_monitor_match(),
+				&HashMap::new(),
+			)
+			.await;
+		assert!(result.is_err());
+		match result {
+			Err(NotificationError::ConfigError(ctx)) => {
+				assert!(ctx.message.contains("Invalid kafka configuration"));
+			}
+			_ => panic!("Expected ConfigError"),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_nats_notification_invalid_config() {
+		let service = NotificationService::new();
+
+		let trigger = TriggerBuilder::new()
+			.name("test_nats")
+			.script("invalid", ScriptLanguage::Python)
+			.trigger_type(TriggerType::Nats) // Intentionally wrong config type
+			.build();
+
+		let variables = HashMap::new();
+		let result = service
+			.execute(
+				&trigger,
+				&variables,
+				&create_mock_monitor_match(),
+				&HashMap::new(),
+			)
+			.await;
+		assert!(result.is_err());
+		match result {
+			Err(NotificationError::ConfigError(ctx)) => {
+				assert!(ctx.message.contains("Invalid nats configuration"));
+			}
+			_ => panic!("Expected ConfigError"),
+		}
+	}
+
 	#[test]
 	fn as_webhook_components_trait_for_slack_config() {
 		let title = "Slack Title";
@@ -585,6 +1020,8 @@ mod tests {
 				body: message.to_string(),
 			},
 			retry_policy: RetryConfig::default(),
+			max_concurrent_notifications: None,
+			max_message_length: None,
 		};
 
 		let components = slack_config.as_webhook_components().unwrap();
@@ -623,6 +1060,9 @@ mod tests {
 				body: message.to_string(),
 			},
 			retry_policy: RetryConfig::default(),
+			max_concurrent_notifications: None,
+			max_message_length: None,
+			embed: None,
 		};
 
 		let components = discord_config.as_webhook_components().unwrap();
@@ -660,6 +1100,8 @@ mod tests {
 				body: message.to_string(),
 			},
 			retry_policy: RetryConfig::default(),
+			max_concurrent_notifications: None,
+			max_message_length: None,
 		};
 
 		let components = telegram_config.as_webhook_components().unwrap();
@@ -697,6 +1139,8 @@ mod tests {
 			))),
 			headers: Some([("X-Custom".to_string(), "Value".to_string())].into()),
 			retry_policy: RetryConfig::default(),
+			max_concurrent_notifications: None,
+			payload_fields: None,
 		};
 
 		let components = webhook_config.as_webhook_components().unwrap();
@@ -718,4 +1162,88 @@ mod tests {
 		assert!(payload.get("title").is_some());
 		assert!(payload.get("body").is_some());
 	}
+
+	/// Notifier recording the trigger name and variables of every notification
+	#[derive(Default)]
+	struct InMemoryNotifier {
+		sent: std::sync::Mutex<Vec<(String, HashMap<String, String>)>>,
+	}
+
+	#[async_trait]
+	impl Notifier for InMemoryNotifier {
+		async fn notify(
+			&self,
+			trigger: &Trigger,
+			variables: &HashMap<String, String>,
+			_monitor_match: &MonitorMatch,
+		) -> Result<(), NotificationError> {
+			self.sent
+				.lock()
+				.unwrap()
+				.push((trigger.name.clone(), variables.clone()));
+			Ok(())
+		}
+	}
+
+	#[tokio::test]
+	async fn test_custom_notifier_is_invoked_for_custom_trigger() {
+		let notifier = Arc::new(InMemoryNotifier::default());
+		let service = NotificationService::builder()
+			.notifier("in_memory", notifier.clone())
+			.build();
+
+		let trigger = TriggerBuilder::new()
+			.name("test_custom")
+			.custom("in_memory")
+			.labels(vec![("team", "ops")])
+			.build();
+		let variables = HashMap::from([("monitor.name".to_string(), "test".to_string())]);
+
+		let result = service
+			.execute(
+				&trigger,
+				&variables,
+				&create_mock_monitor_match(),
+				&HashMap::new(),
+			)
+			.await;
+		assert!(result.is_ok());
+
+		let sent = notifier.sent.lock().unwrap();
+		assert_eq!(sent.len(), 1);
+		assert_eq!(sent[0].0, "test_custom");
+		assert_eq!(sent[0].1["monitor.name"], "test");
+		assert_eq!(sent[0].1["trigger.labels.team"], "ops");
+	}
+
+	#[tokio::test]
+	async fn test_custom_trigger_without_registered_notifier() {
+		let notifier = Arc::new(InMemoryNotifier::default());
+		let service = NotificationService::builder()
+			.notifier("in_memory", notifier.clone())
+			.build();
+
+		let trigger = TriggerBuilder::new()
+			.name("test_custom")
+			.custom("pagerduty")
+			.build();
+
+		let result = service
+			.execute(
+				&trigger,
+				&HashMap::new(),
+				&create_mock_monitor_match(),
+				&HashMap::new(),
+			)
+			.await;
+		match result {
+			Err(NotificationError::ConfigError(ctx)) => {
+				assert!(ctx
+					.message
+					.contains("No notifier registered for custom trigger type 'pagerduty'"));
+			}
+			_ => panic!("Expected ConfigError"),
+		}
+		assert!(notifier.sent.lock().unwrap().is_empty());
+	}
 }
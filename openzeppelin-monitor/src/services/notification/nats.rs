@@ -0,0 +1,448 @@
+//! NATS notification implementation.
+//!
+//! Provides functionality to publish monitor matches to a NATS subject, optionally through
+//! JetStream, either as the serialized `MonitorMatch` or as a rendered message template
+//! with variable substitution.
+
+use async_nats::jetstream::{self, context::Publish};
+use async_trait::async_trait;
+use backon::{BackoffBuilder, ExponentialBuilder, Retryable};
+use serde_json::json;
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use crate::{
+	models::{MonitorMatch, NotificationMessage, TriggerTypeConfig},
+	services::notification::{template_formatter, NotificationError},
+	utils::{JitterSetting, RetryConfig},
+};
+
+/// Maximum time to wait for a message to be flushed to the server or acknowledged by JetStream
+const NATS_PUBLISH_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Abstraction over a NATS client so that delivery can be tested without a server
+#[async_trait]
+pub trait NatsPublisher: Send + Sync {
+	/// Publishes a single message to the given subject
+	///
+	/// # Arguments
+	/// * `subject` - Subject to publish the message to
+	/// * `stream` - Optional JetStream stream expected to store the message
+	/// * `payload` - Serialized message payload
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	async fn publish(
+		&self,
+		subject: &str,
+		stream: Option<&str>,
+		payload: &str,
+	) -> Result<(), NotificationError>;
+}
+
+#[async_trait]
+impl NatsPublisher for async_nats::Client {
+	async fn publish(
+		&self,
+		subject: &str,
+		stream: Option<&str>,
+		payload: &str,
+	) -> Result<(), NotificationError> {
+		let metadata = Some(HashMap::from([(
+			"subject".to_string(),
+			subject.to_string(),
+		)]));
+
+		let result = match stream {
+			Some(stream) => {
+				let context = jetstream::new(self.clone());
+				let publish = Publish::build()
+					.payload(payload.to_string().into())
+					.expected_stream(stream);
+				tokio::time::timeout(NATS_PUBLISH_TIMEOUT, async {
+					context
+						.send_publish(subject.to_string(), publish)
+						.await?
+						.await
+						.map(|_| ())
+				})
+				.await
+				.map(|r| r.map_err(|e| e.to_string()))
+			}
+			None => {
+				tokio::time::timeout(NATS_PUBLISH_TIMEOUT, async {
+					async_nats::Client::publish(
+						self,
+						subject.to_string(),
+						payload.to_string().into(),
+					)
+					.await
+					.map_err(|e| e.to_string())?;
+					self.flush().await.map_err(|e| e.to_string())
+				})
+				.await
+			}
+		};
+
+		match result {
+			Ok(Ok(())) => Ok(()),
+			Ok(Err(e)) => Err(NotificationError::notify_failed(
+				format!("Failed to publish NATS message: {}", e),
+				None,
+				metadata,
+			)),
+			Err(e) => Err(NotificationError::notify_failed(
+				format!(
+					"Timed out publishing NATS message after {:?}",
+					NATS_PUBLISH_TIMEOUT
+				),
+				Some(Box::new(e)),
+				metadata,
+			)),
+		}
+	}
+}
+
+/// Implementation of NATS notifications
+pub struct NatsNotifier<P: NatsPublisher> {
+	/// Subject to publish messages to
+	subject: String,
+	/// Optional JetStream stream expected to store the messages
+	stream: Option<String>,
+	/// Optional message template; the serialized monitor match is published when absent
+	message: Option<NotificationMessage>,
+	/// NATS publisher used for delivery
+	publisher: Arc<P>,
+	/// Retry policy for message delivery
+	retry_policy: RetryConfig,
+}
+
+impl<P: NatsPublisher> NatsNotifier<P> {
+	/// Creates a new NATS notifier instance
+	///
+	/// # Arguments
+	/// * `publisher` - NATS publisher used for delivery
+	/// * `subject` - Subject to publish messages to
+	/// * `stream` - Optional JetStream stream
+	/// * `message` - Optional message template
+	/// * `retry_policy` - Retry policy for message delivery
+	///
+	/// # Returns
+	/// * `Self` - NATS notifier instance
+	pub fn new(
+		publisher: Arc<P>,
+		subject: String,
+		stream: Option<String>,
+		message: Option<NotificationMessage>,
+		retry_policy: RetryConfig,
+	) -> Self {
+		Self {
+			subject,
+			stream,
+			message,
+			publisher,
+			retry_policy,
+		}
+	}
+
+	/// Creates a NATS notifier from a trigger configuration
+	///
+	/// # Arguments
+	/// * `config` - Trigger configuration containing NATS parameters
+	/// * `publisher` - NATS publisher used for delivery
+	///
+	/// # Returns
+	/// * `Result<Self, NotificationError>` - Notifier instance if config is NATS type
+	pub fn from_config(
+		config: &TriggerTypeConfig,
+		publisher: Arc<P>,
+	) -> Result<Self, NotificationError> {
+		if let TriggerTypeConfig::Nats {
+			subject,
+			stream,
+			message,
+			retry_policy,
+			..
+		} = config
+		{
+			Ok(Self::new(
+				publisher,
+				subject.clone(),
+				stream.clone(),
+				message.clone(),
+				retry_policy.clone(),
+			))
+		} else {
+			Err(NotificationError::config_error(
+				format!("Invalid nats configuration: {:?}", config),
+				None,
+				None,
+			))
+		}
+	}
+
+	/// Builds the message payload for a monitor match
+	///
+	/// The payload is the rendered message (as a `title`/`body` JSON object) when a message
+	/// is configured, otherwise the serialized monitor match.
+	///
+	/// # Arguments
+	/// * `variables` - Variables to substitute in the template
+	/// * `monitor_match` - Monitor match to publish
+	///
+	/// # Returns
+	/// * `Result<String, NotificationError>` - Message payload
+	pub fn build_payload(
+		&self,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+	) -> Result<String, NotificationError> {
+		match &self.message {
+			Some(message) => Ok(json!({
+				"title": template_formatter::format_template(&message.title, variables),
+				"body": template_formatter::format_template(&message.body, variables),
+			})
+			.to_string()),
+			None => serde_json::to_string(monitor_match).map_err(|e| {
+				NotificationError::internal_error(
+					format!("Failed to serialize monitor match: {}", e),
+					Some(e.into()),
+					None,
+				)
+			}),
+		}
+	}
+
+	/// Publishes a monitor match to the configured subject
+	///
+	/// # Arguments
+	/// * `variables` - Variables to substitute in the template
+	/// * `monitor_match` - Monitor match to publish
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	pub async fn notify(
+		&self,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+	) -> Result<(), NotificationError> {
+		let payload = self.build_payload(variables, monitor_match)?;
+
+		let operation = || async {
+			self.publisher
+				.publish(&self.subject, self.stream.as_deref(), &payload)
+				.await
+		};
+
+		let backoff = ExponentialBuilder::default()
+			.with_min_delay(self.retry_policy.initial_backoff)
+			.with_max_delay(self.retry_policy.max_backoff);
+
+		let backoff_with_jitter = match self.retry_policy.jitter {
+			JitterSetting::Full => backoff.with_jitter(),
+			JitterSetting::None => backoff,
+		};
+
+		operation
+			.retry(
+				backoff_with_jitter
+					.build()
+					.take(self.retry_policy.max_retries as usize),
+			)
+			.await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{EVMMonitorMatch, EVMTransactionReceipt, MatchConditions},
+		utils::tests::{
+			builders::evm::monitor::MonitorBuilder, evm::transaction::TransactionBuilder,
+		},
+	};
+	use std::sync::atomic::{AtomicUsize, Ordering};
+	use tokio::sync::Mutex;
+
+	/// In-memory publisher recording every published message
+	#[derive(Default)]
+	struct InMemoryPublisher {
+		messages: Mutex<Vec<(String, Option<String>, String)>>,
+		failures_remaining: AtomicUsize,
+		attempts: AtomicUsize,
+	}
+
+	impl InMemoryPublisher {
+		fn failing(failures: usize) -> Self {
+			Self {
+				failures_remaining: AtomicUsize::new(failures),
+				..Default::default()
+			}
+		}
+	}
+
+	#[async_trait]
+	impl NatsPublisher for InMemoryPublisher {
+		async fn publish(
+			&self,
+			subject: &str,
+			stream: Option<&str>,
+			payload: &str,
+		) -> Result<(), NotificationError> {
+			self.attempts.fetch_add(1, Ordering::SeqCst);
+			if self
+				.failures_remaining
+				.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+				.is_ok()
+			{
+				return Err(NotificationError::notify_failed("server down", None, None));
+			}
+			self.messages.lock().await.push((
+				subject.to_string(),
+				stream.map(str::to_string),
+				payload.to_string(),
+			));
+			Ok(())
+		}
+	}
+
+	fn create_test_monitor_match() -> MonitorMatch {
+		MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new().name("test_monitor").build(),
+			transaction: TransactionBuilder::new().build(),
+			receipt: Some(EVMTransactionReceipt::default()),
+			revert_reason: None,
+			logs: Some(vec![]),
+			block_timestamp: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: None,
+			enrichment: HashMap::new(),
+		}))
+	}
+
+	fn create_test_variables() -> HashMap<String, String> {
+		HashMap::from([
+			("monitor.name".to_string(), "test_monitor".to_string()),
+			("value".to_string(), "42".to_string()),
+		])
+	}
+
+	fn create_retry_policy(max_retries: u32) -> RetryConfig {
+		RetryConfig {
+			max_retries,
+			initial_backoff: Duration::from_millis(1),
+			max_backoff: Duration::from_millis(2),
+			jitter: JitterSetting::None,
+			..RetryConfig::default()
+		}
+	}
+
+	#[tokio::test]
+	async fn test_notify_publishes_serialized_match() {
+		let publisher = Arc::new(InMemoryPublisher::default());
+		let notifier = NatsNotifier::new(
+			publisher.clone(),
+			"monitor.alerts".to_string(),
+			Some("ALERTS".to_string()),
+			None,
+			RetryConfig::default(),
+		);
+		let monitor_match = create_test_monitor_match();
+
+		notifier
+			.notify(&create_test_variables(), &monitor_match)
+			.await
+			.unwrap();
+
+		let messages = publisher.messages.lock().await;
+		assert_eq!(messages.len(), 1);
+		let (subject, stream, payload) = &messages[0];
+		assert_eq!(subject, "monitor.alerts");
+		assert_eq!(stream.as_deref(), Some("ALERTS"));
+		assert_eq!(payload, &serde_json::to_string(&monitor_match).unwrap());
+	}
+
+	#[tokio::test]
+	async fn test_notify_publishes_rendered_template() {
+		let publisher = Arc::new(InMemoryPublisher::default());
+		let notifier = NatsNotifier::new(
+			publisher.clone(),
+			"monitor.alerts".to_string(),
+			None,
+			Some(NotificationMessage {
+				title: "Alert for ${monitor.name}".to_string(),
+				body: "Value is ${value}".to_string(),
+			}),
+			RetryConfig::default(),
+		);
+
+		notifier
+			.notify(&create_test_variables(), &create_test_monitor_match())
+			.await
+			.unwrap();
+
+		let messages = publisher.messages.lock().await;
+		let (_, stream, payload) = &messages[0];
+		assert!(stream.is_none());
+		assert_eq!(
+			serde_json::from_str::<serde_json::Value>(payload).unwrap(),
+			json!({"title": "Alert for test_monitor", "body": "Value is 42"})
+		);
+	}
+
+	#[tokio::test]
+	async fn test_notify_retries_until_success() {
+		let publisher = Arc::new(InMemoryPublisher::failing(2));
+		let notifier = NatsNotifier::new(
+			publisher.clone(),
+			"monitor.alerts".to_string(),
+			None,
+			None,
+			create_retry_policy(3),
+		);
+
+		let result = notifier
+			.notify(&create_test_variables(), &create_test_monitor_match())
+			.await;
+
+		assert!(result.is_ok());
+		assert_eq!(publisher.attempts.load(Ordering::SeqCst), 3);
+		assert_eq!(publisher.messages.lock().await.len(), 1);
+	}
+
+	#[tokio::test]
+	async fn test_notify_fails_after_all_retries() {
+		let publisher = Arc::new(InMemoryPublisher::failing(usize::MAX));
+		let notifier = NatsNotifier::new(
+			publisher.clone(),
+			"monitor.alerts".to_string(),
+			None,
+			None,
+			create_retry_policy(2),
+		);
+
+		let result = notifier
+			.notify(&create_test_variables(), &create_test_monitor_match())
+			.await;
+
+		assert!(matches!(result, Err(NotificationError::NotifyFailed(_))));
+		assert_eq!(publisher.attempts.load(Ordering::SeqCst), 3);
+		assert!(publisher.messages.lock().await.is_empty());
+	}
+
+	#[test]
+	fn test_from_config_invalid_type() {
+		let config = TriggerTypeConfig::Script {
+			language: crate::models::ScriptLanguage::Bash,
+			script_path: "script.sh".to_string(),
+			arguments: None,
+			timeout_ms: 1000,
+			script_checksum: None,
+			retry_policy: None,
+		};
+
+		let result = NatsNotifier::from_config(&config, Arc::new(InMemoryPublisher::default()));
+		assert!(matches!(result, Err(NotificationError::ConfigError(_))));
+	}
+}
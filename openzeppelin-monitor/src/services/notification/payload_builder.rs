@@ -7,6 +7,13 @@ use serde_json::json;
 use std::collections::HashMap;
 
 use super::template_formatter;
+use crate::{
+	models::{DiscordEmbedConfig, WebhookPayloadFields},
+	utils::metrics::NOTIFICATION_MESSAGES_TRUNCATED_TOTAL,
+};
+
+/// Marker ending truncated messages
+pub const TRUNCATION_MARKER: &str = "... truncated";
 
 /// Trait for building webhook payloads.
 pub trait WebhookPayloadBuilder: Send + Sync {
@@ -27,6 +34,20 @@ pub trait WebhookPayloadBuilder: Send + Sync {
 		body_template: &str,
 		variables: &HashMap<String, String>,
 	) -> serde_json::Value;
+
+	/// Builds the URL the payload is sent to.
+	///
+	/// # Arguments
+	///
+	/// * `url` - The configured URL.
+	/// * `variables` - The map of variables to substitute into the URL.
+	///
+	/// # Returns
+	///
+	/// The URL to send the payload to. The configured URL is used unchanged by default.
+	fn build_url(&self, url: &str, _variables: &HashMap<String, String>) -> String {
+		url.to_string()
+	}
 }
 
 /// Formats a message by substituting variables in the template.
@@ -34,8 +55,101 @@ pub fn format_template(template: &str, variables: &HashMap<String, String>) -> S
 	template_formatter::format_template(template, variables)
 }
 
+/// Formats a message by substituting escaped variable values in the template.
+///
+/// Only the substituted values are escaped, so that the markup of the template text is
+/// kept while matched values (e.g. decoded calldata) cannot inject markup of their own.
+pub fn format_template_with_escaped_values(
+	template: &str,
+	variables: &HashMap<String, String>,
+	escape: impl Fn(&str) -> String,
+) -> String {
+	let escaped_variables = variables
+		.iter()
+		.map(|(key, value)| (key.clone(), escape(value)))
+		.collect();
+	template_formatter::format_template(template, &escaped_variables)
+}
+
+/// Truncates a message to at most `max_length` characters, ending it with `marker`.
+///
+/// The message is cut on a character boundary, and a trailing backslash left by the cut is
+/// dropped so that it cannot escape the marker.
+///
+/// # Returns
+///
+/// The truncated message, or `None` if the message is not longer than `max_length`.
+pub fn truncate_message(message: &str, max_length: usize, marker: &str) -> Option<String> {
+	if message.chars().count() <= max_length {
+		return None;
+	}
+
+	let marker_length = marker.chars().count();
+	if max_length <= marker_length {
+		return Some(message.chars().take(max_length).collect());
+	}
+
+	let mut truncated: String = message.chars().take(max_length - marker_length).collect();
+	let trailing_backslashes = truncated.chars().rev().take_while(|c| *c == '\\').count();
+	if trailing_backslashes % 2 == 1 {
+		truncated.pop();
+	}
+	truncated.push_str(marker);
+	Some(truncated)
+}
+
+/// Truncates a message to the length limit of a channel, counting truncated messages.
+fn limit_message_length(message: String, max_length: usize, marker: &str, channel: &str) -> String {
+	match truncate_message(&message, max_length, marker) {
+		Some(truncated) => {
+			tracing::warn!(
+				"Truncated {} message of {} characters to {} characters",
+				channel,
+				message.chars().count(),
+				max_length
+			);
+			NOTIFICATION_MESSAGES_TRUNCATED_TOTAL
+				.with_label_values(&[channel])
+				.inc();
+			truncated
+		}
+		None => message,
+	}
+}
+
 /// A payload builder for Slack.
-pub struct SlackPayloadBuilder;
+#[derive(Default)]
+pub struct SlackPayloadBuilder {
+	/// Maximum length of the message, defaults to [`SlackPayloadBuilder::MAX_MESSAGE_LENGTH`]
+	pub max_message_length: Option<usize>,
+}
+
+impl SlackPayloadBuilder {
+	/// Maximum length of the text of a Slack section block
+	pub const MAX_MESSAGE_LENGTH: usize = 3000;
+
+	/// Escapes text for Slack mrkdwn.
+	///
+	/// `&`, `<` and `>` are replaced with HTML entities, as required by Slack. Slack has no
+	/// escape sequence for formatting characters, so these are replaced with lookalike
+	/// characters instead.
+	fn escape_mrkdwn(text: &str) -> String {
+		let mut out = String::with_capacity(text.len());
+		for c in text.chars() {
+			match c {
+				'&' => out.push_str("&amp;"),
+				'<' => out.push_str("&lt;"),
+				'>' => out.push_str("&gt;"),
+				'*' => out.push('\u{2217}'),
+				'_' => out.push('\u{FF3F}'),
+				'~' => out.push('\u{223C}'),
+				'`' => out.push('\u{02CB}'),
+				c => out.push(c),
+			}
+		}
+		out
+	}
+}
 
 impl WebhookPayloadBuilder for SlackPayloadBuilder {
 	fn build_payload(
@@ -44,9 +158,16 @@ impl WebhookPayloadBuilder for SlackPayloadBuilder {
 		body_template: &str,
 		variables: &HashMap<String, String>,
 	) -> serde_json::Value {
-		let formatted_title = format_template(title, variables);
-		let formatted_message = format_template(body_template, variables);
-		let full_message = format!("*{}*\n\n{}", formatted_title, formatted_message);
+		let formatted_title =
+			format_template_with_escaped_values(title, variables, Self::escape_mrkdwn);
+		let formatted_message =
+			format_template_with_escaped_values(body_template, variables, Self::escape_mrkdwn);
+		let full_message = limit_message_length(
+			format!("*{}*\n\n{}", formatted_title, formatted_message),
+			self.max_message_length.unwrap_or(Self::MAX_MESSAGE_LENGTH),
+			TRUNCATION_MARKER,
+			"slack",
+		);
 		json!({
 			"blocks": [
 				{
@@ -62,7 +183,43 @@ impl WebhookPayloadBuilder for SlackPayloadBuilder {
 }
 
 /// A payload builder for Discord.
-pub struct DiscordPayloadBuilder;
+#[derive(Default)]
+pub struct DiscordPayloadBuilder {
+	/// Maximum length of the message, defaults to [`DiscordPayloadBuilder::MAX_MESSAGE_LENGTH`]
+	/// or to [`DiscordPayloadBuilder::MAX_EMBED_DESCRIPTION_LENGTH`] for embeds
+	pub max_message_length: Option<usize>,
+	/// Sends the message as an embed instead of plain content when set
+	pub embed: Option<DiscordEmbedConfig>,
+}
+
+impl DiscordPayloadBuilder {
+	/// Maximum length of the content of a Discord message
+	pub const MAX_MESSAGE_LENGTH: usize = 2000;
+	/// Maximum length of the title of a Discord embed
+	pub const MAX_EMBED_TITLE_LENGTH: usize = 256;
+	/// Maximum length of the description of a Discord embed
+	pub const MAX_EMBED_DESCRIPTION_LENGTH: usize = 4096;
+	/// Maximum number of fields of a Discord embed
+	pub const MAX_EMBED_FIELDS: usize = 25;
+	/// Maximum length of the name of a Discord embed field
+	pub const MAX_EMBED_FIELD_NAME_LENGTH: usize = 256;
+	/// Maximum length of the value of a Discord embed field
+	pub const MAX_EMBED_FIELD_VALUE_LENGTH: usize = 1024;
+
+	/// Escapes text for Discord markdown by prefixing special characters with a backslash.
+	fn escape_markdown(text: &str) -> String {
+		const SPECIAL: &[char] = &['\\', '*', '_', '~', '`', '|', '>', '[', ']', '(', ')'];
+
+		let mut out = String::with_capacity(text.len());
+		for c in text.chars() {
+			if SPECIAL.contains(&c) {
+				out.push('\\');
+			}
+			out.push(c);
+		}
+		out
+	}
+}
 
 impl WebhookPayloadBuilder for DiscordPayloadBuilder {
 	fn build_payload(
@@ -71,30 +228,103 @@ impl WebhookPayloadBuilder for DiscordPayloadBuilder {
 		body_template: &str,
 		variables: &HashMap<String, String>,
 	) -> serde_json::Value {
-		let formatted_title = format_template(title, variables);
-		let formatted_message = format_template(body_template, variables);
-		let full_message = format!("*{}*\n\n{}", formatted_title, formatted_message);
+		let formatted_title =
+			format_template_with_escaped_values(title, variables, Self::escape_markdown);
+		let formatted_message =
+			format_template_with_escaped_values(body_template, variables, Self::escape_markdown);
+		if let Some(embed) = &self.embed {
+			return self.build_embed_payload(embed, formatted_title, formatted_message, variables);
+		}
+		let full_message = limit_message_length(
+			format!("*{}*\n\n{}", formatted_title, formatted_message),
+			self.max_message_length.unwrap_or(Self::MAX_MESSAGE_LENGTH),
+			TRUNCATION_MARKER,
+			"discord",
+		);
 		json!({
 			"content": full_message
 		})
 	}
 }
 
+impl DiscordPayloadBuilder {
+	/// Builds a payload holding a single embed, with a field for each configured variable
+	/// present in the match.
+	///
+	/// Discord rejects embeds exceeding its limits, so the title, field names and values are
+	/// truncated to these limits, and fields beyond [`Self::MAX_EMBED_FIELDS`] are left out.
+	fn build_embed_payload(
+		&self,
+		embed: &DiscordEmbedConfig,
+		title: String,
+		description: String,
+		variables: &HashMap<String, String>,
+	) -> serde_json::Value {
+		let truncate = |text: String, max_length: usize| {
+			truncate_message(&text, max_length, TRUNCATION_MARKER).unwrap_or(text)
+		};
+		let description = limit_message_length(
+			description,
+			self.max_message_length
+				.unwrap_or(Self::MAX_EMBED_DESCRIPTION_LENGTH)
+				.min(Self::MAX_EMBED_DESCRIPTION_LENGTH),
+			TRUNCATION_MARKER,
+			"discord",
+		);
+		// Discord rejects fields with an empty value
+		let fields: Vec<serde_json::Value> = embed
+			.fields
+			.iter()
+			.filter_map(|name| {
+				variables
+					.get(name)
+					.filter(|value| !value.is_empty())
+					.map(|value| (name, value))
+			})
+			.take(Self::MAX_EMBED_FIELDS)
+			.map(|(name, value)| {
+				json!({
+					"name": truncate(Self::escape_markdown(name), Self::MAX_EMBED_FIELD_NAME_LENGTH),
+					"value": truncate(Self::escape_markdown(value), Self::MAX_EMBED_FIELD_VALUE_LENGTH),
+					"inline": true
+				})
+			})
+			.collect();
+
+		json!({
+			"embeds": [
+				{
+					"title": truncate(title, Self::MAX_EMBED_TITLE_LENGTH),
+					"description": description,
+					"color": embed.severity.color(),
+					"fields": fields
+				}
+			]
+		})
+	}
+}
+
 /// A payload builder for Telegram.
 pub struct TelegramPayloadBuilder {
 	pub chat_id: String,
 	pub disable_web_preview: bool,
+	/// Maximum length of the message, defaults to [`TelegramPayloadBuilder::MAX_MESSAGE_LENGTH`]
+	pub max_message_length: Option<usize>,
 }
 
 impl TelegramPayloadBuilder {
+	/// Maximum length of the text of a Telegram message
+	pub const MAX_MESSAGE_LENGTH: usize = 4096;
+
+	/// Characters with a special meaning in MarkdownV2
+	const SPECIAL: &'static [char] = &[
+		'_', '*', '[', ']', '(', ')', '~', '`', '>', '#', '+', '-', '=', '|', '{', '}', '.', '!',
+		'\\',
+	];
+
 	/// Escape a full MarkdownV2 message, preserving entities and
 	/// escaping *all* special chars inside link URLs too.
 	fn escape_markdown_v2(text: &str) -> String {
-		const SPECIAL: &[char] = &[
-			'_', '*', '[', ']', '(', ')', '~', '`', '>', '#', '+', '-', '=', '|', '{', '}', '.',
-			'!', '\\',
-		];
-
 		let re =
 			Regex::new(r"(?s)```.*?```|`[^`]*`|\*[^*]*\*|_[^_]*_|~[^~]*~|\[([^\]]+)\]\(([^)]+)\)")
 				.unwrap();
@@ -106,7 +336,7 @@ impl TelegramPayloadBuilder {
 			let mat = caps.get(0).unwrap();
 
 			for c in text[last..mat.start()].chars() {
-				if SPECIAL.contains(&c) {
+				if Self::SPECIAL.contains(&c) {
 					out.push('\\');
 				}
 				out.push(c);
@@ -115,14 +345,14 @@ impl TelegramPayloadBuilder {
 			if let (Some(lbl), Some(url)) = (caps.get(1), caps.get(2)) {
 				let mut esc_label = String::with_capacity(lbl.as_str().len() * 2);
 				for c in lbl.as_str().chars() {
-					if SPECIAL.contains(&c) {
+					if Self::SPECIAL.contains(&c) {
 						esc_label.push('\\');
 					}
 					esc_label.push(c);
 				}
 				let mut esc_url = String::with_capacity(url.as_str().len() * 2);
 				for c in url.as_str().chars() {
-					if SPECIAL.contains(&c) {
+					if Self::SPECIAL.contains(&c) {
 						esc_url.push('\\');
 					}
 					esc_url.push(c);
@@ -141,7 +371,7 @@ impl TelegramPayloadBuilder {
 		}
 
 		for c in text[last..].chars() {
-			if SPECIAL.contains(&c) {
+			if Self::SPECIAL.contains(&c) {
 				out.push('\\');
 			}
 			out.push(c);
@@ -149,6 +379,46 @@ impl TelegramPayloadBuilder {
 
 		out
 	}
+
+	/// Escape every MarkdownV2 special character, without preserving any entities.
+	fn escape_markdown_v2_text(text: &str) -> String {
+		let mut out = String::with_capacity(text.len());
+		for c in text.chars() {
+			if Self::SPECIAL.contains(&c) {
+				out.push('\\');
+			}
+			out.push(c);
+		}
+		out
+	}
+
+	/// Formats a template for MarkdownV2.
+	///
+	/// The entities of the template text are preserved, while the substituted values are
+	/// escaped in full. Values are swapped for placeholders made of private use characters
+	/// while the template text is escaped, so that they cannot form entities with it.
+	fn format_markdown_v2(template: &str, variables: &HashMap<String, String>) -> String {
+		let (keys, values): (Vec<_>, Vec<_>) = variables.iter().unzip();
+		let placeholders: HashMap<String, String> = keys
+			.into_iter()
+			.enumerate()
+			.map(|(index, key)| (key.clone(), format!("\u{E000}{}\u{E001}", index)))
+			.collect();
+
+		let escaped_template = Self::escape_markdown_v2(&format_template(template, &placeholders));
+
+		// Placeholders are replaced in a single pass, so that values are not scanned again
+		let re = Regex::new("\u{E000}([0-9]+)\u{E001}").unwrap();
+		re.replace_all(&escaped_template, |caps: &regex::Captures| {
+			caps[1]
+				.parse::<usize>()
+				.ok()
+				.and_then(|index| values.get(index))
+				.map(|value| Self::escape_markdown_v2_text(value))
+				.unwrap_or_default()
+		})
+		.into_owned()
+	}
 }
 
 impl WebhookPayloadBuilder for TelegramPayloadBuilder {
@@ -158,15 +428,17 @@ impl WebhookPayloadBuilder for TelegramPayloadBuilder {
 		body_template: &str,
 		variables: &HashMap<String, String>,
 	) -> serde_json::Value {
-		// First, substitute variables.
-		let formatted_title = format_template(title, variables);
-		let formatted_message = format_template(body_template, variables);
-
-		// Then, escape both the title and the formatted message for Telegram MarkdownV2.
-		let escaped_title = Self::escape_markdown_v2(&formatted_title);
-		let escaped_message = Self::escape_markdown_v2(&formatted_message);
-
-		let full_message = format!("*{}* \n\n{}", escaped_title, escaped_message);
+		// Substitute variables and escape both the title and the message for Telegram
+		// MarkdownV2.
+		let escaped_title = Self::format_markdown_v2(title, variables);
+		let escaped_message = Self::format_markdown_v2(body_template, variables);
+
+		let full_message = limit_message_length(
+			format!("*{}* \n\n{}", escaped_title, escaped_message),
+			self.max_message_length.unwrap_or(Self::MAX_MESSAGE_LENGTH),
+			&Self::escape_markdown_v2_text(TRUNCATION_MARKER),
+			"telegram",
+		);
 		json!({
 			"chat_id": self.chat_id,
 			"text": full_message,
@@ -177,7 +449,14 @@ impl WebhookPayloadBuilder for TelegramPayloadBuilder {
 }
 
 /// A payload builder for generic webhooks.
-pub struct GenericWebhookPayloadBuilder;
+///
+/// When payload fields are configured, the template variables they allow are added to
+/// the payload under `variables`.
+#[derive(Default)]
+pub struct GenericWebhookPayloadBuilder {
+	/// Template variables to add to the payload
+	pub payload_fields: Option<WebhookPayloadFields>,
+}
 
 impl WebhookPayloadBuilder for GenericWebhookPayloadBuilder {
 	fn build_payload(
@@ -188,9 +467,29 @@ impl WebhookPayloadBuilder for GenericWebhookPayloadBuilder {
 	) -> serde_json::Value {
 		let formatted_title = format_template(title, variables);
 		let formatted_message = format_template(body_template, variables);
-		json!({
+		let mut payload = json!({
 			"title": formatted_title,
 			"body": formatted_message
+		});
+
+		if let Some(fields) = &self.payload_fields {
+			let selected: serde_json::Map<String, serde_json::Value> = variables
+				.iter()
+				.filter(|(name, _)| fields.allows(name))
+				.map(|(name, value)| (name.clone(), json!(value)))
+				.collect();
+			payload["variables"] = serde_json::Value::Object(selected);
+		}
+
+		payload
+	}
+
+	/// Substitutes variables like `${...}` in the path and query of the URL.
+	///
+	/// Substituted values are URL-encoded, so that they cannot change the structure of the URL.
+	fn build_url(&self, url: &str, variables: &HashMap<String, String>) -> String {
+		format_template_with_escaped_values(url, variables, |value| {
+			urlencoding::encode(value).into_owned()
 		})
 	}
 }
@@ -198,6 +497,7 @@ impl WebhookPayloadBuilder for GenericWebhookPayloadBuilder {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::models::DiscordEmbedSeverity;
 	use serde_json::json;
 
 	#[test]
@@ -208,7 +508,7 @@ mod tests {
 			("title_value".to_string(), "Title".to_string()),
 			("message_value".to_string(), "Message".to_string()),
 		]);
-		let payload = SlackPayloadBuilder.build_payload(title, message, &variables);
+		let payload = SlackPayloadBuilder::default().build_payload(title, message, &variables);
 		assert_eq!(
 			payload,
 			json!({
@@ -233,7 +533,7 @@ mod tests {
 			("title_value".to_string(), "Title".to_string()),
 			("message_value".to_string(), "Message".to_string()),
 		]);
-		let payload = DiscordPayloadBuilder.build_payload(title, message, &variables);
+		let payload = DiscordPayloadBuilder::default().build_payload(title, message, &variables);
 		assert_eq!(
 			payload,
 			json!({
@@ -242,11 +542,91 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_discord_embed_payload_builder() {
+		let builder = DiscordPayloadBuilder {
+			embed: Some(DiscordEmbedConfig {
+				severity: DiscordEmbedSeverity::Critical,
+				fields: vec![
+					"functions.0.args.value".to_string(),
+					"missing".to_string(),
+					"transaction.hash".to_string(),
+				],
+			}),
+			..Default::default()
+		};
+		let variables = HashMap::from([
+			("title_value".to_string(), "Title".to_string()),
+			("functions.0.args.value".to_string(), "1_000".to_string()),
+			("transaction.hash".to_string(), "0x1234".to_string()),
+		]);
+		let payload = builder.build_payload(
+			"Test ${title_value}",
+			"Transferred ${functions.0.args.value}",
+			&variables,
+		);
+		assert_eq!(
+			payload,
+			json!({
+				"embeds": [
+					{
+						"title": "Test Title",
+						"description": "Transferred 1\\_000",
+						"color": 0xE74C3C,
+						"fields": [
+							{
+								"name": "functions.0.args.value",
+								"value": "1\\_000",
+								"inline": true
+							},
+							{
+								"name": "transaction.hash",
+								"value": "0x1234",
+								"inline": true
+							}
+						]
+					}
+				]
+			})
+		);
+	}
+
+	#[test]
+	fn test_discord_embed_payload_builder_limits() {
+		let builder = DiscordPayloadBuilder {
+			embed: Some(DiscordEmbedConfig {
+				fields: (0..30).map(|i| format!("field_{}", i)).collect(),
+				..Default::default()
+			}),
+			..Default::default()
+		};
+		let mut variables: HashMap<String, String> = (0..30)
+			.map(|i| (format!("field_{}", i), "x".repeat(2000)))
+			.collect();
+		variables.insert("args".to_string(), "x".repeat(10_000));
+		let payload = builder.build_payload("Alert", "${args}", &variables);
+
+		let embed = &payload["embeds"][0];
+		assert_eq!(embed["color"], json!(DiscordEmbedSeverity::Info.color()));
+		assert_eq!(
+			embed["description"].as_str().unwrap().chars().count(),
+			DiscordPayloadBuilder::MAX_EMBED_DESCRIPTION_LENGTH
+		);
+		let fields = embed["fields"].as_array().unwrap();
+		assert_eq!(fields.len(), DiscordPayloadBuilder::MAX_EMBED_FIELDS);
+		assert_eq!(fields[0]["name"], json!("field\\_0"));
+		assert!(fields.iter().all(|field| {
+			field["value"].as_str().unwrap().chars().count()
+				== DiscordPayloadBuilder::MAX_EMBED_FIELD_VALUE_LENGTH
+		}));
+	}
+
 	#[test]
 	fn test_telegram_payload_builder() {
 		let builder = TelegramPayloadBuilder {
 			chat_id: "12345".to_string(),
 			disable_web_preview: true,
+			max_message_length: None,
 		};
 		let title = "Test ${title_value}";
 		let message = "Test ${message_value}";
@@ -266,6 +646,103 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_truncate_message() {
+		assert_eq!(truncate_message("short", 10, TRUNCATION_MARKER), None);
+		assert_eq!(
+			truncate_message(&"a".repeat(30), 20, TRUNCATION_MARKER),
+			Some(format!("{}{}", "a".repeat(7), TRUNCATION_MARKER))
+		);
+		// Multi-byte characters are not split
+		assert_eq!(
+			truncate_message(&"é".repeat(30), 20, TRUNCATION_MARKER),
+			Some(format!("{}{}", "é".repeat(7), TRUNCATION_MARKER))
+		);
+		// A dangling escape is dropped
+		assert_eq!(
+			truncate_message("abcdef\\.ghijklmnop", 20, TRUNCATION_MARKER),
+			None
+		);
+		assert_eq!(
+			truncate_message("abcdef\\.ghijklmnopqrstu", 20, TRUNCATION_MARKER),
+			Some(format!("abcdef{}", TRUNCATION_MARKER))
+		);
+		// Limits shorter than the marker cut the message only
+		assert_eq!(
+			truncate_message("abcdef", 3, TRUNCATION_MARKER),
+			Some("abc".to_string())
+		);
+	}
+
+	#[test]
+	fn test_oversized_messages_are_truncated_to_channel_limit() {
+		let variables = HashMap::from([("args".to_string(), "x".repeat(10_000))]);
+		let truncations = || {
+			["slack", "discord", "telegram"]
+				.map(|channel| {
+					NOTIFICATION_MESSAGES_TRUNCATED_TOTAL
+						.with_label_values(&[channel])
+						.get()
+				})
+				.iter()
+				.sum::<u64>()
+		};
+		let truncations_before = truncations();
+
+		let slack = SlackPayloadBuilder::default().build_payload("Alert", "${args}", &variables);
+		let slack_text = slack["blocks"][0]["text"]["text"].as_str().unwrap();
+		assert_eq!(
+			slack_text.chars().count(),
+			SlackPayloadBuilder::MAX_MESSAGE_LENGTH
+		);
+		assert!(slack_text.ends_with(TRUNCATION_MARKER));
+
+		let discord =
+			DiscordPayloadBuilder::default().build_payload("Alert", "${args}", &variables);
+		let discord_content = discord["content"].as_str().unwrap();
+		assert_eq!(
+			discord_content.chars().count(),
+			DiscordPayloadBuilder::MAX_MESSAGE_LENGTH
+		);
+		assert!(discord_content.ends_with(TRUNCATION_MARKER));
+
+		let telegram = TelegramPayloadBuilder {
+			chat_id: "12345".to_string(),
+			disable_web_preview: false,
+			max_message_length: None,
+		}
+		.build_payload("Alert", "${args}", &variables);
+		let telegram_text = telegram["text"].as_str().unwrap();
+		assert_eq!(
+			telegram_text.chars().count(),
+			TelegramPayloadBuilder::MAX_MESSAGE_LENGTH
+		);
+		assert!(telegram_text.ends_with("\\.\\.\\. truncated"));
+
+		// Configured limits override the channel's limit
+		let limited = SlackPayloadBuilder {
+			max_message_length: Some(100),
+		}
+		.build_payload("Alert", "${args}", &variables);
+		assert_eq!(
+			limited["blocks"][0]["text"]["text"]
+				.as_str()
+				.unwrap()
+				.chars()
+				.count(),
+			100
+		);
+
+		assert!(truncations() >= truncations_before + 4);
+
+		// Messages within the limit are unchanged
+		let short = SlackPayloadBuilder {
+			max_message_length: Some(100),
+		}
+		.build_payload("Alert", "Short", &variables);
+		assert_eq!(short["blocks"][0]["text"]["text"], "*Alert*\n\nShort");
+	}
+
 	#[test]
 	fn test_generic_webhook_payload_builder() {
 		let title = "Test ${title_value}";
@@ -274,7 +751,8 @@ mod tests {
 			("title_value".to_string(), "Title".to_string()),
 			("message_value".to_string(), "Message".to_string()),
 		]);
-		let payload = GenericWebhookPayloadBuilder.build_payload(title, message, &variables);
+		let payload =
+			GenericWebhookPayloadBuilder::default().build_payload(title, message, &variables);
 		assert_eq!(
 			payload,
 			json!({
@@ -284,6 +762,199 @@ mod tests {
 		);
 	}
 
+	fn create_match_variables() -> HashMap<String, String> {
+		HashMap::from([
+			("monitor.name".to_string(), "Large Transfer".to_string()),
+			("transaction.hash".to_string(), "0x1234".to_string()),
+			("transaction.from".to_string(), "0xabcd".to_string()),
+			(
+				"events.0.signature".to_string(),
+				"Transfer(address,address,uint256)".to_string(),
+			),
+			("events.0.args.value".to_string(), "100".to_string()),
+		])
+	}
+
+	#[test]
+	fn test_generic_webhook_payload_with_included_fields() {
+		let builder = GenericWebhookPayloadBuilder {
+			payload_fields: Some(WebhookPayloadFields {
+				include: vec!["transaction.hash".to_string(), "events".to_string()],
+				exclude: vec![],
+			}),
+		};
+		let payload =
+			builder.build_payload("Alert", "Body ${monitor.name}", &create_match_variables());
+
+		assert_eq!(payload["body"], "Body Large Transfer");
+		assert_eq!(
+			payload["variables"],
+			json!({
+				"transaction.hash": "0x1234",
+				"events.0.signature": "Transfer(address,address,uint256)",
+				"events.0.args.value": "100"
+			})
+		);
+	}
+
+	#[test]
+	fn test_generic_webhook_payload_with_excluded_fields() {
+		let builder = GenericWebhookPayloadBuilder {
+			payload_fields: Some(WebhookPayloadFields {
+				include: vec![],
+				exclude: vec!["transaction.from".to_string(), "events.0.args".to_string()],
+			}),
+		};
+		let payload = builder.build_payload("Alert", "Body", &create_match_variables());
+		let variables = payload["variables"].as_object().unwrap();
+
+		assert_eq!(variables.len(), 3);
+		assert_eq!(variables["monitor.name"], "Large Transfer");
+		assert_eq!(variables["transaction.hash"], "0x1234");
+		assert!(variables.contains_key("events.0.signature"));
+		assert!(!variables.contains_key("transaction.from"));
+		assert!(!variables.contains_key("events.0.args.value"));
+	}
+
+	#[test]
+	fn test_generic_webhook_payload_without_fields_omits_variables() {
+		let payload = GenericWebhookPayloadBuilder::default().build_payload(
+			"Alert",
+			"Body",
+			&create_match_variables(),
+		);
+		assert!(payload.get("variables").is_none());
+	}
+
+	#[test]
+	fn test_generic_webhook_build_url_encodes_variables() {
+		let variables = HashMap::from([
+			(
+				"monitor.name".to_string(),
+				"Large Transfer/USDC".to_string(),
+			),
+			("transaction.hash".to_string(), "0x12&ab=cd".to_string()),
+		]);
+		let url = GenericWebhookPayloadBuilder::default().build_url(
+			"https://api.example.com/monitors/${monitor.name}?tx=${transaction.hash}",
+			&variables,
+		);
+		assert_eq!(
+			url,
+			"https://api.example.com/monitors/Large%20Transfer%2FUSDC?tx=0x12%26ab%3Dcd"
+		);
+
+		// Other builders leave the URL unchanged
+		assert_eq!(
+			SlackPayloadBuilder::default()
+				.build_url("https://hooks.slack.com/${monitor.name}", &variables),
+			"https://hooks.slack.com/${monitor.name}"
+		);
+	}
+
+	#[test]
+	fn test_title_variable_substitution_for_all_builders() {
+		let title = "Transfer of ${value}";
+		let message = "Body";
+		let variables = HashMap::from([("value".to_string(), "42".to_string())]);
+
+		let builders: Vec<(&str, Box<dyn WebhookPayloadBuilder>)> = vec![
+			("slack", Box::new(SlackPayloadBuilder::default())),
+			("discord", Box::new(DiscordPayloadBuilder::default())),
+			(
+				"telegram",
+				Box::new(TelegramPayloadBuilder {
+					chat_id: "12345".to_string(),
+					disable_web_preview: false,
+					max_message_length: None,
+				}),
+			),
+			("webhook", Box::new(GenericWebhookPayloadBuilder::default())),
+		];
+
+		for (channel, builder) in builders {
+			let payload = builder
+				.build_payload(title, message, &variables)
+				.to_string();
+			assert!(
+				payload.contains("Transfer of 42"),
+				"Title was not substituted for {}: {}",
+				channel,
+				payload
+			);
+			assert!(
+				!payload.contains("${value}"),
+				"Title placeholder left in {} payload: {}",
+				channel,
+				payload
+			);
+		}
+	}
+
+	fn create_markup_variables() -> HashMap<String, String> {
+		HashMap::from([
+			("value".to_string(), "*_[]".to_string()),
+			("events.0.signature".to_string(), "Transfer()".to_string()),
+			("events.0.args.memo".to_string(), "_x_".to_string()),
+		])
+	}
+
+	#[test]
+	fn test_slack_payload_escapes_variable_values() {
+		let payload = SlackPayloadBuilder::default().build_payload(
+			"${value}",
+			"Value: ${value}",
+			&create_markup_variables(),
+		);
+		assert_eq!(
+			payload["blocks"][0]["text"]["text"],
+			"*\u{2217}\u{FF3F}[]*\n\nValue: \u{2217}\u{FF3F}[]"
+		);
+
+		let variables = HashMap::from([(
+			"value".to_string(),
+			"<!channel> & <https://x.io|y>".to_string(),
+		)]);
+		let payload = SlackPayloadBuilder::default().build_payload("Alert", "${value}", &variables);
+		assert_eq!(
+			payload["blocks"][0]["text"]["text"],
+			"*Alert*\n\n&lt;!channel&gt; &amp; &lt;https://x.io|y&gt;"
+		);
+	}
+
+	#[test]
+	fn test_discord_payload_escapes_variable_values() {
+		let payload = DiscordPayloadBuilder::default().build_payload(
+			"${value}",
+			"Value: ${value}${events}",
+			&create_markup_variables(),
+		);
+		let content = payload["content"].as_str().unwrap();
+		assert!(content.starts_with("*\\*\\_\\[\\]*\n\nValue: \\*\\_\\[\\]"));
+		// Values in the events section are escaped, its markup is kept
+		assert!(content.contains("*Signature:* `Transfer\\(\\)`"));
+		assert!(content.contains("memo: `\\_x\\_`"));
+	}
+
+	#[test]
+	fn test_telegram_payload_escapes_variable_values() {
+		let builder = TelegramPayloadBuilder {
+			chat_id: "12345".to_string(),
+			disable_web_preview: false,
+			max_message_length: None,
+		};
+		let payload = builder.build_payload(
+			"Alert",
+			"*${value}* _${value}_ ${value}${events}",
+			&create_markup_variables(),
+		);
+		let text = payload["text"].as_str().unwrap();
+		// The entities of the template are preserved around the escaped values
+		assert!(text.starts_with("*Alert* \n\n*\\*\\_\\[\\]* _\\*\\_\\[\\]_ \\*\\_\\[\\]"));
+		assert!(text.contains("*Signature:* `Transfer\\(\\)`"));
+		assert!(text.contains("memo: `\\_x\\_`"));
+	}
+
 	#[test]
 	fn test_escape_markdown_v2() {
 		// Test for real life examples
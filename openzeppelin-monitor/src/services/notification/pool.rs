@@ -1,14 +1,17 @@
 use crate::services::blockchain::TransientErrorRetryStrategy;
-use crate::services::notification::SmtpConfig;
+use crate::services::notification::{CircuitBreaker, CircuitBreakerConfig, SmtpConfig};
 use crate::utils::client_storage::ClientStorage;
-use crate::utils::{create_retryable_http_client, RetryConfig};
+use crate::utils::{create_retryable_http_client, user_agent, RetryConfig};
 use lettre::Tokio1Executor;
 use lettre::{transport::smtp::authentication::Credentials, AsyncSmtpTransport};
+use rdkafka::{producer::FutureProducer, ClientConfig};
 use reqwest::Client as ReqwestClient;
 use reqwest_middleware::ClientWithMiddleware;
 use std::sync::Arc;
 use std::time::Duration;
 use thiserror::Error;
+use tokio::sync::Semaphore;
+use tonic::transport::{Channel, ClientTlsConfig, Endpoint};
 
 #[derive(Debug, Error)]
 pub enum NotificationPoolError {
@@ -17,36 +20,72 @@ pub enum NotificationPoolError {
 
 	#[error("Failed to create SMTP client: {0}")]
 	SmtpClientBuildError(String),
+
+	#[error("Failed to create Kafka producer: {0}")]
+	KafkaProducerBuildFailed(String),
+
+	#[error("Failed to create NATS client: {0}")]
+	NatsClientBuildFailed(String),
+
+	#[error("Failed to create gRPC channel: {0}")]
+	GrpcChannelBuildFailed(String),
 }
 
-/// Notification client pool that manages HTTP and SMTP clients for sending notifications.
+/// Notification client pool that manages HTTP, SMTP, Kafka, NATS and gRPC clients for
+/// sending notifications.
 ///
-/// Provides a thread-safe way to access and create HTTP and SMTP clients
-/// for sending notifications. It uses a `ClientStorage` to hold the clients,
-/// allowing for efficient reuse and management of HTTP and SMTP connections.
+/// Provides a thread-safe way to access and create HTTP, SMTP, Kafka, NATS and gRPC clients
+/// for sending notifications. It uses a `ClientStorage` to hold the clients, allowing for
+/// efficient reuse and management of HTTP, SMTP, Kafka, NATS and gRPC connections.
+/// It also holds per-channel semaphores bounding the number of in-flight notifications
+/// and per-endpoint circuit breakers that fast-fail notifications to failing endpoints.
 pub struct NotificationClientPool {
 	http_clients: ClientStorage<ClientWithMiddleware>,
 	smtp_clients: ClientStorage<AsyncSmtpTransport<Tokio1Executor>>,
+	kafka_producers: ClientStorage<FutureProducer>,
+	nats_clients: ClientStorage<async_nats::Client>,
+	grpc_channels: ClientStorage<Channel>,
+	channel_semaphores: ClientStorage<Semaphore>,
+	circuit_breakers: ClientStorage<CircuitBreaker>,
+	circuit_breaker_config: CircuitBreakerConfig,
 }
 
 impl NotificationClientPool {
 	pub fn new() -> Self {
+		Self::with_circuit_breaker_config(CircuitBreakerConfig::default())
+	}
+
+	/// Creates a new empty pool whose endpoint circuit breakers use the given thresholds.
+	pub fn with_circuit_breaker_config(circuit_breaker_config: CircuitBreakerConfig) -> Self {
 		Self {
 			http_clients: ClientStorage::new(),
 			smtp_clients: ClientStorage::new(),
+			kafka_producers: ClientStorage::new(),
+			nats_clients: ClientStorage::new(),
+			grpc_channels: ClientStorage::new(),
+			channel_semaphores: ClientStorage::new(),
+			circuit_breakers: ClientStorage::new(),
+			circuit_breaker_config,
 		}
 	}
 
+	/// Returns the thresholds applied to the endpoint circuit breakers.
+	pub fn circuit_breaker_config(&self) -> CircuitBreakerConfig {
+		self.circuit_breaker_config
+	}
+
 	/// A private, generic method to handle the core logic of getting or creating a client.
+	///
+	/// The `create` future is only awaited when no client exists for the key.
 	async fn get_or_create_client<T, F>(
 		&self,
 		key: &str,
 		storage: &ClientStorage<T>,
-		create_fn: F,
+		create: F,
 	) -> Result<Arc<T>, NotificationPoolError>
 	where
 		T: Send + Sync,
-		F: FnOnce() -> Result<T, NotificationPoolError>,
+		F: std::future::Future<Output = Result<T, NotificationPoolError>>,
 	{
 		// 1. Fast path (read lock)
 		if let Some(client) = storage.clients.read().await.get(key) {
@@ -61,7 +100,7 @@ impl NotificationClientPool {
 		}
 
 		// 4. Create and insert
-		let new_client = create_fn()?;
+		let new_client = create.await?;
 		let arc_client = Arc::new(new_client);
 		clients.insert(key.to_string(), arc_client.clone());
 
@@ -81,8 +120,9 @@ impl NotificationClientPool {
 		retry_policy: &RetryConfig,
 	) -> Result<Arc<ClientWithMiddleware>, NotificationPoolError> {
 		let key = format!("{:?}", retry_policy);
-		self.get_or_create_client(&key, &self.http_clients, || {
+		self.get_or_create_client(&key, &self.http_clients, async {
 			let base_client = ReqwestClient::builder()
+				.user_agent(user_agent())
 				.pool_max_idle_per_host(10)
 				.pool_idle_timeout(Some(Duration::from_secs(90)))
 				.connect_timeout(Duration::from_secs(10))
@@ -111,7 +151,7 @@ impl NotificationClientPool {
 		smtp_config: &SmtpConfig,
 	) -> Result<Arc<AsyncSmtpTransport<Tokio1Executor>>, NotificationPoolError> {
 		let key = format!("{:?}", smtp_config);
-		self.get_or_create_client(&key, &self.smtp_clients, || {
+		self.get_or_create_client(&key, &self.smtp_clients, async {
 			let creds =
 				Credentials::new(smtp_config.username.clone(), smtp_config.password.clone());
 			Ok(
@@ -125,6 +165,116 @@ impl NotificationClientPool {
 		.await
 	}
 
+	/// Get or create a Kafka producer for publishing records.
+	/// # Arguments
+	/// * `brokers` - Kafka bootstrap brokers (host:port)
+	/// # Returns
+	/// * `Result<Arc<FutureProducer>, NotificationPoolError>` - The Kafka producer
+	///   wrapped in an `Arc` for shared ownership, or an error if producer creation
+	///   fails.
+	pub async fn get_or_create_kafka_producer(
+		&self,
+		brokers: &[String],
+	) -> Result<Arc<FutureProducer>, NotificationPoolError> {
+		let key = brokers.join(",");
+		self.get_or_create_client(&key, &self.kafka_producers, async {
+			ClientConfig::new()
+				.set("bootstrap.servers", &key)
+				.set("message.timeout.ms", "5000")
+				.create::<FutureProducer>()
+				.map_err(|e| NotificationPoolError::KafkaProducerBuildFailed(e.to_string()))
+		})
+		.await
+	}
+
+	/// Get or create a NATS client for publishing messages.
+	///
+	/// The connection is established in the background, so creating the client does not
+	/// wait for the server to be reachable.
+	/// # Arguments
+	/// * `server_url` - NATS server URL
+	/// # Returns
+	/// * `Result<Arc<async_nats::Client>, NotificationPoolError>` - The NATS client
+	///   wrapped in an `Arc` for shared ownership, or an error if client creation
+	///   fails.
+	pub async fn get_or_create_nats_client(
+		&self,
+		server_url: &str,
+	) -> Result<Arc<async_nats::Client>, NotificationPoolError> {
+		self.get_or_create_client(server_url, &self.nats_clients, async {
+			async_nats::ConnectOptions::new()
+				.retry_on_initial_connect()
+				.connect(server_url)
+				.await
+				.map_err(|e| NotificationPoolError::NatsClientBuildFailed(e.to_string()))
+		})
+		.await
+	}
+
+	/// Get or create a gRPC channel for sending notifications.
+	///
+	/// The channel connects lazily, so creating it does not wait for the service to be
+	/// reachable. `https://` endpoints are verified against the web PKI roots.
+	/// # Arguments
+	/// * `endpoint` - gRPC service URL
+	/// # Returns
+	/// * `Result<Arc<Channel>, NotificationPoolError>` - The gRPC channel wrapped in an
+	///   `Arc` for shared ownership, or an error if the endpoint is invalid.
+	pub async fn get_or_create_grpc_channel(
+		&self,
+		endpoint: &str,
+	) -> Result<Arc<Channel>, NotificationPoolError> {
+		self.get_or_create_client(endpoint, &self.grpc_channels, async {
+			let mut channel_endpoint = Endpoint::from_shared(endpoint.to_string())
+				.map_err(|e| NotificationPoolError::GrpcChannelBuildFailed(e.to_string()))?;
+			if endpoint.starts_with("https://") {
+				channel_endpoint = channel_endpoint
+					.tls_config(ClientTlsConfig::new().with_webpki_roots())
+					.map_err(|e| NotificationPoolError::GrpcChannelBuildFailed(e.to_string()))?;
+			}
+			Ok(channel_endpoint.connect_lazy())
+		})
+		.await
+	}
+
+	/// Get or create the semaphore bounding concurrent notifications to a channel.
+	///
+	/// The semaphore is created with `max_concurrent` permits the first time the channel
+	/// is seen; later calls for the same channel share it regardless of their limit.
+	/// # Arguments
+	/// * `channel` - Identifier of the notification channel (e.g. the endpoint URL)
+	/// * `max_concurrent` - Maximum number of notifications in flight to the channel
+	/// # Returns
+	/// * `Result<Arc<Semaphore>, NotificationPoolError>` - The channel semaphore
+	///   wrapped in an `Arc` for shared ownership.
+	pub async fn get_or_create_channel_semaphore(
+		&self,
+		channel: &str,
+		max_concurrent: usize,
+	) -> Result<Arc<Semaphore>, NotificationPoolError> {
+		self.get_or_create_client(channel, &self.channel_semaphores, async {
+			Ok(Semaphore::new(max_concurrent))
+		})
+		.await
+	}
+
+	/// Get or create the circuit breaker guarding a notification endpoint.
+	///
+	/// # Arguments
+	/// * `endpoint` - Identifier of the notification endpoint (e.g. the endpoint URL)
+	/// # Returns
+	/// * `Result<Arc<CircuitBreaker>, NotificationPoolError>` - The endpoint circuit breaker
+	///   wrapped in an `Arc` for shared ownership.
+	pub async fn get_or_create_circuit_breaker(
+		&self,
+		endpoint: &str,
+	) -> Result<Arc<CircuitBreaker>, NotificationPoolError> {
+		self.get_or_create_client(endpoint, &self.circuit_breakers, async {
+			Ok(CircuitBreaker::new(self.circuit_breaker_config))
+		})
+		.await
+	}
+
 	/// Get the number of active HTTP clients in the pool
 	#[cfg(test)]
 	pub async fn get_active_http_client_count(&self) -> usize {
@@ -136,6 +286,30 @@ impl NotificationClientPool {
 	pub async fn get_active_smtp_client_count(&self) -> usize {
 		self.smtp_clients.clients.read().await.len()
 	}
+
+	/// Get the number of active Kafka producers in the pool
+	#[cfg(test)]
+	pub async fn get_active_kafka_producer_count(&self) -> usize {
+		self.kafka_producers.clients.read().await.len()
+	}
+
+	/// Get the number of active NATS clients in the pool
+	#[cfg(test)]
+	pub async fn get_active_nats_client_count(&self) -> usize {
+		self.nats_clients.clients.read().await.len()
+	}
+
+	/// Get the number of active gRPC channels in the pool
+	#[cfg(test)]
+	pub async fn get_active_grpc_channel_count(&self) -> usize {
+		self.grpc_channels.clients.read().await.len()
+	}
+
+	/// Get the number of channel semaphores in the pool
+	#[cfg(test)]
+	pub async fn get_channel_semaphore_count(&self) -> usize {
+		self.channel_semaphores.clients.read().await.len()
+	}
 }
 
 impl Default for NotificationClientPool {
@@ -370,4 +544,130 @@ mod tests {
 			"Pool should still have two active SMTP clients after getting an existing one"
 		);
 	}
+
+	#[tokio::test]
+	async fn test_pool_returns_same_kafka_producer_for_same_brokers() {
+		let pool = create_pool();
+		let brokers = vec!["localhost:9092".to_string()];
+		let other_brokers = vec!["localhost:9093".to_string()];
+
+		let producer1 = pool.get_or_create_kafka_producer(&brokers).await.unwrap();
+		let producer2 = pool.get_or_create_kafka_producer(&brokers).await.unwrap();
+		let producer3 = pool
+			.get_or_create_kafka_producer(&other_brokers)
+			.await
+			.unwrap();
+
+		assert!(
+			Arc::ptr_eq(&producer1, &producer2),
+			"Should return the same producer instance for the same brokers"
+		);
+		assert!(
+			!Arc::ptr_eq(&producer1, &producer3),
+			"Should return different producer instances for different brokers"
+		);
+		assert_eq!(
+			pool.get_active_kafka_producer_count().await,
+			2,
+			"Pool should have two active Kafka producers"
+		);
+	}
+
+	#[tokio::test]
+	async fn test_pool_returns_same_grpc_channel_for_endpoint() {
+		let pool = create_pool();
+
+		let channel1 = pool
+			.get_or_create_grpc_channel("http://localhost:50051")
+			.await
+			.unwrap();
+		let channel2 = pool
+			.get_or_create_grpc_channel("http://localhost:50051")
+			.await
+			.unwrap();
+		let channel3 = pool
+			.get_or_create_grpc_channel("https://localhost:50052")
+			.await
+			.unwrap();
+
+		assert!(
+			Arc::ptr_eq(&channel1, &channel2),
+			"Should return the same channel instance for the same endpoint"
+		);
+		assert!(
+			!Arc::ptr_eq(&channel1, &channel3),
+			"Should return different channel instances for different endpoints"
+		);
+		assert_eq!(
+			pool.get_active_grpc_channel_count().await,
+			2,
+			"Pool should have two active gRPC channels"
+		);
+		assert!(matches!(
+			pool.get_or_create_grpc_channel("not a url").await,
+			Err(NotificationPoolError::GrpcChannelBuildFailed(_))
+		));
+	}
+
+	#[tokio::test]
+	async fn test_pool_returns_same_circuit_breaker_for_endpoint() {
+		let pool = NotificationClientPool::with_circuit_breaker_config(CircuitBreakerConfig {
+			failure_threshold: Some(1),
+			cooldown: Duration::from_secs(60),
+		});
+		let breaker1 = pool
+			.get_or_create_circuit_breaker("https://example.com/a")
+			.await
+			.unwrap();
+		let breaker2 = pool
+			.get_or_create_circuit_breaker("https://example.com/a")
+			.await
+			.unwrap();
+		let breaker3 = pool
+			.get_or_create_circuit_breaker("https://example.com/b")
+			.await
+			.unwrap();
+
+		assert!(Arc::ptr_eq(&breaker1, &breaker2));
+		assert!(!Arc::ptr_eq(&breaker1, &breaker3));
+
+		breaker1.record_failure();
+		assert!(!breaker2.try_acquire(), "Endpoint circuit should be open");
+		assert!(
+			breaker3.try_acquire(),
+			"Other endpoints should not be affected"
+		);
+	}
+
+	#[tokio::test]
+	async fn test_pool_returns_same_semaphore_for_channel() {
+		let pool = create_pool();
+		let semaphore1 = pool
+			.get_or_create_channel_semaphore("https://example.com/a", 1)
+			.await
+			.unwrap();
+		let semaphore2 = pool
+			.get_or_create_channel_semaphore("https://example.com/a", 5)
+			.await
+			.unwrap();
+		let semaphore3 = pool
+			.get_or_create_channel_semaphore("https://example.com/b", 1)
+			.await
+			.unwrap();
+
+		assert!(
+			Arc::ptr_eq(&semaphore1, &semaphore2),
+			"Should return the same semaphore for the same channel"
+		);
+		assert!(
+			!Arc::ptr_eq(&semaphore1, &semaphore3),
+			"Should return different semaphores for different channels"
+		);
+		assert_eq!(
+			semaphore2.available_permits(),
+			1,
+			"Semaphore should keep the limit it was created with"
+		);
+		assert_eq!(pool.get_channel_semaphore_count().await, 2);
+	}
 }
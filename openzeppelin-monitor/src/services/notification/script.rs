@@ -3,7 +3,7 @@ use async_trait::async_trait;
 use crate::{
 	models::{MonitorMatch, ScriptLanguage, TriggerTypeConfig},
 	services::notification::{NotificationError, ScriptExecutor},
-	services::trigger::ScriptExecutorFactory,
+	services::trigger::{execute_with_retry, ScriptExecutorFactory},
 };
 
 /// A notification handler that executes scripts when triggered
@@ -44,17 +44,20 @@ impl ScriptExecutor for ScriptNotifier {
 				language,
 				arguments,
 				timeout_ms,
+				script_checksum: _,
+				retry_policy,
 			} => {
 				let executor = ScriptExecutorFactory::create(language, &script_content.1);
 
-				let result = executor
-					.execute(
-						monitor_match.clone(),
-						timeout_ms,
-						arguments.as_deref(),
-						true,
-					)
-					.await;
+				let result = execute_with_retry(
+					executor.as_ref(),
+					monitor_match,
+					timeout_ms,
+					arguments.as_deref(),
+					true,
+					retry_policy.as_ref(),
+				)
+				.await;
 
 				match result {
 					Ok(true) => Ok(()),
@@ -90,9 +93,12 @@ mod tests {
 			NotificationMessage, SecretString, SecretValue, TriggerType,
 		},
 		services::notification::NotificationService,
-		utils::tests::{
-			builders::evm::monitor::MonitorBuilder, evm::transaction::TransactionBuilder,
-			trigger::TriggerBuilder,
+		utils::{
+			tests::{
+				builders::evm::monitor::MonitorBuilder, evm::transaction::TransactionBuilder,
+				trigger::TriggerBuilder,
+			},
+			JitterSetting, RetryConfig,
 		},
 	};
 	use std::{collections::HashMap, time::Instant};
@@ -103,6 +109,8 @@ mod tests {
 			script_path: "test_script.py".to_string(),
 			arguments: Some(vec!["arg1".to_string(), "arg2".to_string()]),
 			timeout_ms: 1000,
+			script_checksum: None,
+			retry_policy: None,
 		}
 	}
 
@@ -125,10 +133,13 @@ mod tests {
 			monitor: create_test_monitor("test_monitor", vec!["ethereum_mainnet"], false, vec![]),
 			transaction: TransactionBuilder::new().build(),
 			receipt: Some(EVMTransactionReceipt::default()),
+			revert_reason: None,
 			logs: Some(vec![]),
+			block_timestamp: None,
 			network_slug: "ethereum_mainnet".to_string(),
 			matched_on: MatchConditions::default(),
 			matched_on_args: None,
+			enrichment: HashMap::new(),
 		}))
 	}
 
@@ -149,6 +160,8 @@ mod tests {
 				body: "This is a test message".to_string(),
 			},
 			retry_policy: Default::default(),
+			max_concurrent_notifications: None,
+			max_message_length: None,
 		};
 
 		let notifier = ScriptNotifier::from_config(&config);
@@ -178,6 +191,8 @@ mod tests {
 			script_path: "test_script.py".to_string(),
 			arguments: None,
 			timeout_ms: 1000, // Timeout longer than sleep time
+			script_checksum: None,
+			retry_policy: None,
 		};
 		let notifier = ScriptNotifier::from_config(&config).unwrap();
 		let monitor_match = create_test_monitor_match();
@@ -207,6 +222,8 @@ mod tests {
 			script_path: "test_script.py".to_string(),
 			arguments: None,
 			timeout_ms: 400, // Set timeout lower than the sleep time
+			script_checksum: None,
+			retry_policy: None,
 		};
 		let notifier = ScriptNotifier::from_config(&config).unwrap();
 		let monitor_match = create_test_monitor_match();
@@ -231,6 +248,41 @@ mod tests {
 		assert!(elapsed.as_millis() >= 400 && elapsed.as_millis() < 600);
 	}
 
+	#[tokio::test]
+	async fn test_script_notify_retries_flaky_script() {
+		// Fails on the first run and succeeds once the marker file exists
+		let marker_dir = tempfile::tempdir().unwrap();
+		let marker = marker_dir.path().join("attempted");
+		let config = TriggerTypeConfig::Script {
+			language: ScriptLanguage::Bash,
+			script_path: "flaky.sh".to_string(),
+			arguments: None,
+			timeout_ms: 1000,
+			script_checksum: None,
+			retry_policy: Some(RetryConfig {
+				max_retries: 1,
+				initial_backoff: std::time::Duration::from_millis(10),
+				jitter: JitterSetting::None,
+				..Default::default()
+			}),
+		};
+		let notifier = ScriptNotifier::from_config(&config).unwrap();
+		let monitor_match = create_test_monitor_match();
+		let script_content = (
+			ScriptLanguage::Bash,
+			format!(
+				"cat > /dev/null; if [ -f '{0}' ]; then exit 0; else touch '{0}'; exit 1; fi",
+				marker.display()
+			),
+		);
+
+		let result = notifier
+			.script_notify(&monitor_match, &script_content)
+			.await;
+		assert!(result.is_ok());
+		assert!(marker.exists());
+	}
+
 	#[tokio::test]
 	async fn test_script_notify_with_invalid_script() {
 		let config = create_test_script_config();
@@ -255,6 +307,8 @@ mod tests {
 			script_path: "non_existent_script.py".to_string(), // This path won't be in the map
 			arguments: None,
 			timeout_ms: 1000,
+			script_checksum: None,
+			retry_policy: None,
 		};
 		let trigger = TriggerBuilder::new()
         .name("test_script_missing")
@@ -2,6 +2,9 @@
 //!
 //! Provides functionality to send formatted messages to webhooks
 //! via incoming webhooks, supporting message templates with variable substitution.
+//!
+//! Signed requests carry `X-Signature` and `X-Timestamp` headers. Receivers can check them
+//! with [`verify_webhook_signature`], which mirrors how the notifier signs payloads.
 
 use chrono::Utc;
 use hmac::{Hmac, Mac};
@@ -13,11 +16,76 @@ use reqwest_middleware::ClientWithMiddleware;
 use sha2::Sha256;
 use std::{collections::HashMap, sync::Arc};
 
-use crate::{models::TriggerTypeConfig, services::notification::NotificationError};
+use crate::{
+	models::{TriggerTypeConfig, WebhookPayloadFields},
+	services::notification::NotificationError,
+};
 
 /// HMAC SHA256 type alias
 type HmacSha256 = Hmac<Sha256>;
 
+/// Algorithm used to sign webhook payloads
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WebhookSignatureAlgorithm {
+	/// Hex-encoded HMAC-SHA256 over the request body followed by the timestamp
+	#[default]
+	HmacSha256,
+}
+
+/// Creates the MAC used to sign or verify a webhook request
+///
+/// The signed message is the serialized request body immediately followed by the
+/// `X-Timestamp` header value.
+fn webhook_mac(
+	body: &[u8],
+	timestamp: &str,
+	secret: &str,
+	algorithm: WebhookSignatureAlgorithm,
+) -> Option<HmacSha256> {
+	// Explicitly reject empty secret, because `HmacSha256::new_from_slice` currently allows empty secrets
+	if secret.is_empty() {
+		return None;
+	}
+
+	match algorithm {
+		WebhookSignatureAlgorithm::HmacSha256 => {
+			let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).ok()?;
+			mac.update(body);
+			mac.update(timestamp.as_bytes());
+			Some(mac)
+		}
+	}
+}
+
+/// Verifies the signature of a webhook request sent by [`WebhookNotifier`]
+///
+/// The comparison runs in constant time, so response timing does not reveal how much of
+/// a forged signature was correct.
+///
+/// # Arguments
+/// * `body` - The raw request body, exactly as received
+/// * `signature` - The value of the `X-Signature` header
+/// * `timestamp` - The value of the `X-Timestamp` header
+/// * `secret` - The secret configured on the webhook trigger
+/// * `algorithm` - The algorithm used to sign the request
+///
+/// # Returns
+/// * `bool` - `true` if the signature matches the body and timestamp
+pub fn verify_webhook_signature(
+	body: &[u8],
+	signature: &str,
+	timestamp: &str,
+	secret: &str,
+	algorithm: WebhookSignatureAlgorithm,
+) -> bool {
+	let Ok(signature) = hex::decode(signature.trim()) else {
+		return false;
+	};
+
+	webhook_mac(body, timestamp, secret, algorithm)
+		.is_some_and(|mac| mac.verify_slice(&signature).is_ok())
+}
+
 /// Represents a webhook configuration
 #[derive(Clone)]
 pub struct WebhookConfig {
@@ -28,7 +96,7 @@ pub struct WebhookConfig {
 	pub method: Option<String>,
 	pub secret: Option<String>,
 	pub headers: Option<HashMap<String, String>>,
-	pub payload_fields: Option<HashMap<String, serde_json::Value>>,
+	pub payload_fields: Option<WebhookPayloadFields>,
 }
 
 /// Implementation of webhook notifications via webhooks
@@ -49,7 +117,7 @@ pub struct WebhookNotifier {
 	/// Headers to use for the webhook request
 	pub headers: Option<HashMap<String, String>>,
 	/// Payload fields to use for the webhook request
-	pub payload_fields: Option<HashMap<String, serde_json::Value>>,
+	pub payload_fields: Option<WebhookPayloadFields>,
 }
 
 impl WebhookNotifier {
@@ -99,6 +167,7 @@ impl WebhookNotifier {
 			method,
 			secret,
 			headers,
+			payload_fields,
 			..
 		} = config
 		{
@@ -110,7 +179,7 @@ impl WebhookNotifier {
 				method: method.clone(),
 				secret: secret.as_ref().map(|s| s.as_ref().to_string()),
 				headers: headers.clone(),
-				payload_fields: None,
+				payload_fields: payload_fields.clone(),
 			};
 
 			WebhookNotifier::new(webhook_config, http_client)
@@ -134,12 +203,7 @@ impl WebhookNotifier {
 			));
 		}
 
-		let timestamp = Utc::now().timestamp_millis();
-
-		// Create HMAC instance
-		let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).map_err(|e| {
-			NotificationError::config_error(format!("Invalid secret: {}", e), None, None)
-		})?; // Handle error if secret is invalid
+		let timestamp = Utc::now().timestamp_millis().to_string();
 
 		// Create the message to sign
 		let serialized_payload = serde_json::to_string(payload).map_err(|e| {
@@ -149,13 +213,19 @@ impl WebhookNotifier {
 				None,
 			)
 		})?;
-		let message = format!("{}{}", serialized_payload, timestamp);
-		mac.update(message.as_bytes());
+
+		let mac = webhook_mac(
+			serialized_payload.as_bytes(),
+			&timestamp,
+			secret,
+			WebhookSignatureAlgorithm::HmacSha256,
+		)
+		.ok_or_else(|| NotificationError::config_error("Invalid secret", None, None))?;
 
 		// Get the HMAC result
 		let signature = hex::encode(mac.finalize().into_bytes());
 
-		Ok((signature, timestamp.to_string()))
+		Ok((signature, timestamp))
 	}
 
 	/// Sends a JSON payload to Webhook
@@ -174,7 +244,8 @@ impl WebhookNotifier {
 				.map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
 				.collect();
 			if !params_str.is_empty() {
-				url = format!("{}?{}", url, params_str.join("&"));
+				let separator = if url.contains('?') { '&' } else { '?' };
+				url = format!("{}{}{}", url, separator, params_str.join("&"));
 			}
 		}
 
@@ -262,7 +333,10 @@ impl WebhookNotifier {
 			return Err(NotificationError::notify_failed(
 				format!("Webhook request failed with status: {}", status),
 				None,
-				None,
+				Some(HashMap::from([(
+					"status_code".to_string(),
+					status.as_u16().to_string(),
+				)])),
 			));
 		}
 
@@ -275,7 +349,7 @@ mod tests {
 	use crate::{
 		models::{NotificationMessage, SecretString, SecretValue},
 		services::notification::{GenericWebhookPayloadBuilder, WebhookPayloadBuilder},
-		utils::{tests::create_test_http_client, RetryConfig},
+		utils::{logging::error::ErrorCode, tests::create_test_http_client, RetryConfig},
 	};
 
 	use super::*;
@@ -312,11 +386,13 @@ mod tests {
 				body: "Test message ${value}".to_string(),
 			},
 			retry_policy: RetryConfig::default(),
+			max_concurrent_notifications: None,
+			payload_fields: None,
 		}
 	}
 
 	fn create_test_payload() -> serde_json::Value {
-		GenericWebhookPayloadBuilder.build_payload(
+		GenericWebhookPayloadBuilder::default().build_payload(
 			"Test Alert",
 			"Test message with value ${value}",
 			&HashMap::from([("value".to_string(), "42".to_string())]),
@@ -388,6 +464,8 @@ mod tests {
 				body: "Test message ${value}".to_string(),
 			},
 			retry_policy: RetryConfig::default(),
+			max_concurrent_notifications: None,
+			max_message_length: None,
 		};
 
 		let http_client = create_test_http_client();
@@ -410,6 +488,34 @@ mod tests {
 		assert!(result.is_err());
 	}
 
+	#[tokio::test]
+	async fn test_notify_failure_error_codes() {
+		let mut server = mockito::Server::new_async().await;
+		let unauthorized = server
+			.mock("POST", "/unauthorized")
+			.with_status(401)
+			.create_async()
+			.await;
+		let not_found = server
+			.mock("POST", "/missing")
+			.with_status(404)
+			.create_async()
+			.await;
+
+		let payload = create_test_payload();
+
+		let notifier = create_test_notifier(&format!("{}/unauthorized", server.url()), None, None);
+		let error = notifier.notify_json(&payload).await.unwrap_err();
+		assert_eq!(error.code(), ErrorCode::Auth);
+
+		let notifier = create_test_notifier(&format!("{}/missing", server.url()), None, None);
+		let error = notifier.notify_json(&payload).await.unwrap_err();
+		assert_eq!(error.code(), ErrorCode::Http4xx);
+
+		unauthorized.assert();
+		not_found.assert();
+	}
+
 	#[tokio::test]
 	async fn test_notify_includes_signature_and_timestamp() {
 		let mut server = mockito::Server::new_async().await;
@@ -536,4 +642,87 @@ mod tests {
 			"Timestamp should be valid i64"
 		);
 	}
+
+	#[test]
+	fn test_verify_webhook_signature_valid() {
+		let notifier =
+			create_test_notifier("https://webhook.example.com", Some("test-secret"), None);
+		let payload = create_test_payload();
+		let (signature, timestamp) = notifier.sign_payload("test-secret", &payload).unwrap();
+		let body = serde_json::to_vec(&payload).unwrap();
+
+		assert!(verify_webhook_signature(
+			&body,
+			&signature,
+			&timestamp,
+			"test-secret",
+			WebhookSignatureAlgorithm::HmacSha256,
+		));
+	}
+
+	#[test]
+	fn test_verify_webhook_signature_tampered() {
+		let notifier =
+			create_test_notifier("https://webhook.example.com", Some("test-secret"), None);
+		let payload = create_test_payload();
+		let (signature, timestamp) = notifier.sign_payload("test-secret", &payload).unwrap();
+		let body = serde_json::to_vec(&payload).unwrap();
+		let algorithm = WebhookSignatureAlgorithm::default();
+
+		// Tampered body
+		let mut tampered_body = body.clone();
+		tampered_body.extend_from_slice(b" ");
+		assert!(!verify_webhook_signature(
+			&tampered_body,
+			&signature,
+			&timestamp,
+			"test-secret",
+			algorithm,
+		));
+
+		// Replayed signature with a different timestamp
+		let other_timestamp = (timestamp.parse::<i64>().unwrap() + 1).to_string();
+		assert!(!verify_webhook_signature(
+			&body,
+			&signature,
+			&other_timestamp,
+			"test-secret",
+			algorithm,
+		));
+
+		// Tampered signature
+		let mut tampered_signature = signature.clone();
+		let last = if tampered_signature.ends_with('0') {
+			"1"
+		} else {
+			"0"
+		};
+		tampered_signature.replace_range(tampered_signature.len() - 1.., last);
+		assert!(!verify_webhook_signature(
+			&body,
+			&tampered_signature,
+			&timestamp,
+			"test-secret",
+			algorithm,
+		));
+
+		// Wrong secret, malformed signature and empty secret
+		assert!(!verify_webhook_signature(
+			&body,
+			&signature,
+			&timestamp,
+			"other-secret",
+			algorithm,
+		));
+		assert!(!verify_webhook_signature(
+			&body,
+			"not-hex",
+			&timestamp,
+			"test-secret",
+			algorithm,
+		));
+		assert!(!verify_webhook_signature(
+			&body, &signature, &timestamp, "", algorithm,
+		));
+	}
 }
@@ -0,0 +1,138 @@
+//! Deduplication of identical matches across blocks.
+//!
+//! Suppresses matches whose content is identical to a match notified in a recent block, as
+//! configured by the monitor's `match_dedup`.
+
+use std::{collections::HashMap, sync::Mutex};
+
+use crate::models::MonitorMatch;
+
+/// Maximum number of notified matches remembered for a monitor on a network
+///
+/// When reached, the match notified in the oldest block is forgotten to bound memory.
+pub const MAX_DEDUP_ENTRIES: usize = 10_000;
+
+/// Tracker of the matches notified for each monitor, suppressing identical matches
+///
+/// Matches are keyed by [`MonitorMatch::content_key`], and remembered for `window_blocks`
+/// blocks after the block they were notified in.
+#[derive(Debug, Default)]
+pub struct MatchDeduplicator {
+	/// Block number of the notified matches by content key, per network and monitor
+	notified: Mutex<HashMap<(String, String), HashMap<String, u64>>>,
+}
+
+impl MatchDeduplicator {
+	/// Creates a deduplicator without any notified match
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Checks whether a match must be suppressed, recording it as notified otherwise
+	///
+	/// Matches of monitors without `match_dedup` are never suppressed. Identical matches of
+	/// the same block are distinct transactions and are not suppressed either.
+	///
+	/// # Arguments
+	/// * `monitor_match` - The match to check
+	/// * `block_number` - Number of the block the match was found in
+	///
+	/// # Returns
+	/// * `bool` - `true` if an identical match was notified within the window
+	pub fn is_duplicate(&self, monitor_match: &MonitorMatch, block_number: u64) -> bool {
+		let (network_slug, monitor) = match monitor_match {
+			MonitorMatch::EVM(m) => (&m.network_slug, &m.monitor),
+			MonitorMatch::Stellar(m) => (&m.network_slug, &m.monitor),
+		};
+		let Some(config) = &monitor.match_dedup else {
+			return false;
+		};
+
+		let content_key = monitor_match.content_key();
+		let mut notified = self.notified.lock().unwrap_or_else(|e| e.into_inner());
+		let entries = notified
+			.entry((network_slug.clone(), monitor.name.clone()))
+			.or_default();
+
+		// Forget the matches notified before the window
+		entries.retain(|_, notified_block| {
+			block_number.abs_diff(*notified_block) <= config.window_blocks
+		});
+
+		if entries
+			.get(&content_key)
+			.is_some_and(|notified_block| *notified_block != block_number)
+		{
+			return true;
+		}
+
+		if entries.len() >= MAX_DEDUP_ENTRIES && !entries.contains_key(&content_key) {
+			if let Some(oldest) = entries
+				.iter()
+				.min_by_key(|(_, notified_block)| **notified_block)
+				.map(|(key, _)| key.clone())
+			{
+				entries.remove(&oldest);
+			}
+		}
+		entries.insert(content_key, block_number);
+		false
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{EVMMonitorMatch, MatchConditions},
+		utils::tests::builders::evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+	};
+	use alloy::primitives::B256;
+
+	fn create_match(monitor_name: &str, tx_hash: u8, window_blocks: Option<u64>) -> MonitorMatch {
+		let mut monitor = MonitorBuilder::new().name(monitor_name);
+		if let Some(window_blocks) = window_blocks {
+			monitor = monitor.match_dedup(window_blocks);
+		}
+		MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: monitor.build(),
+			transaction: TransactionBuilder::new()
+				.hash(B256::with_last_byte(tx_hash))
+				.build(),
+			receipt: None,
+			revert_reason: None,
+			logs: None,
+			block_timestamp: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: None,
+			enrichment: HashMap::new(),
+		}))
+	}
+
+	#[test]
+	fn test_identical_matches_suppressed_within_window() {
+		let deduplicator = MatchDeduplicator::new();
+
+		assert!(!deduplicator.is_duplicate(&create_match("monitor", 1, Some(2)), 100));
+		// Identical matches of the same block are kept
+		assert!(!deduplicator.is_duplicate(&create_match("monitor", 2, Some(2)), 100));
+		assert!(deduplicator.is_duplicate(&create_match("monitor", 3, Some(2)), 101));
+		assert!(deduplicator.is_duplicate(&create_match("monitor", 4, Some(2)), 102));
+		// The window elapsed since the notified match
+		assert!(!deduplicator.is_duplicate(&create_match("monitor", 5, Some(2)), 103));
+		assert!(deduplicator.is_duplicate(&create_match("monitor", 6, Some(2)), 104));
+	}
+
+	#[test]
+	fn test_distinct_matches_not_suppressed() {
+		let deduplicator = MatchDeduplicator::new();
+
+		assert!(!deduplicator.is_duplicate(&create_match("first", 1, Some(10)), 100));
+		assert!(!deduplicator.is_duplicate(&create_match("second", 1, Some(10)), 101));
+
+		// Monitors without deduplication are never suppressed
+		assert!(!deduplicator.is_duplicate(&create_match("plain", 1, None), 100));
+		assert!(!deduplicator.is_duplicate(&create_match("plain", 2, None), 101));
+	}
+}
@@ -0,0 +1,267 @@
+//! Digest buffering for triggers.
+//!
+//! Triggers with `digest` settings do not send a notification per match. Their matches are
+//! accumulated in a buffer, which is flushed into a single summary notification every
+//! `digest_interval_ms`.
+
+use std::{
+	collections::{BTreeSet, HashMap},
+	sync::Mutex,
+	time::{Duration, Instant},
+};
+
+use crate::models::{DigestConfig, MonitorMatch, ScriptLanguage};
+
+/// Matches accumulated for a trigger since its last digest
+#[derive(Debug, Clone)]
+pub struct PendingDigest {
+	/// Number of matches accumulated, including the ones not detailed
+	pub count: usize,
+	/// Template variables of the detailed matches, in arrival order
+	pub matches: Vec<HashMap<String, String>>,
+	/// First match of the digest, used as the match of the summary notification
+	pub monitor_match: MonitorMatch,
+	/// Trigger scripts of the latest match
+	pub trigger_scripts: HashMap<String, (ScriptLanguage, String)>,
+	/// Time the first match was accumulated
+	started_at: Instant,
+	/// Interval after which the digest is sent
+	interval: Duration,
+}
+
+impl PendingDigest {
+	/// Builds the template variables of the summary notification
+	///
+	/// - `digest.count`: number of matches accumulated
+	/// - `digest.monitors`: comma separated names of the monitors that matched
+	/// - `digest.summary`: one line per detailed match, with the monitor name and the
+	///   transaction hash
+	/// - `digest.matches.<index>.<variable>`: variables of each detailed match
+	pub fn variables(&self) -> HashMap<String, String> {
+		let mut variables = HashMap::new();
+		variables.insert("digest.count".to_string(), self.count.to_string());
+
+		let monitors = self
+			.matches
+			.iter()
+			.filter_map(|variables| variables.get("monitor.name"))
+			.map(String::as_str)
+			.collect::<BTreeSet<_>>();
+		variables.insert(
+			"digest.monitors".to_string(),
+			monitors.into_iter().collect::<Vec<_>>().join(", "),
+		);
+
+		let mut summary = self
+			.matches
+			.iter()
+			.enumerate()
+			.map(|(index, match_variables)| {
+				let field = |name: &str| match_variables.get(name).map_or("", String::as_str);
+				format!(
+					"{}. {}: {}",
+					index + 1,
+					field("monitor.name"),
+					field("transaction.hash")
+				)
+			})
+			.collect::<Vec<_>>();
+		if self.count > self.matches.len() {
+			summary.push(format!("... and {} more", self.count - self.matches.len()));
+		}
+		variables.insert("digest.summary".to_string(), summary.join("\n"));
+
+		for (index, match_variables) in self.matches.iter().enumerate() {
+			for (name, value) in match_variables {
+				variables.insert(format!("digest.matches.{}.{}", index, name), value.clone());
+			}
+		}
+		variables
+	}
+
+	/// Checks whether the digest interval has elapsed at the given time
+	fn is_due(&self, now: Instant) -> bool {
+		now.saturating_duration_since(self.started_at) >= self.interval
+	}
+}
+
+/// Buffers of the matches of the triggers in digest mode
+#[derive(Debug, Default)]
+pub struct DigestBuffer {
+	pending: Mutex<HashMap<String, PendingDigest>>,
+}
+
+impl DigestBuffer {
+	/// Creates an empty buffer
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Accumulates a match into the digest of a trigger
+	///
+	/// # Arguments
+	/// * `trigger_slug` - Identifier of the trigger
+	/// * `config` - Digest settings of the trigger
+	/// * `variables` - Template variables of the match
+	/// * `monitor_match` - The match
+	/// * `trigger_scripts` - Loaded trigger scripts
+	pub fn add(
+		&self,
+		trigger_slug: &str,
+		config: &DigestConfig,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+		trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
+	) {
+		let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+		let digest = pending
+			.entry(trigger_slug.to_string())
+			.or_insert_with(|| PendingDigest {
+				count: 0,
+				matches: Vec::new(),
+				monitor_match: monitor_match.clone(),
+				trigger_scripts: HashMap::new(),
+				started_at: Instant::now(),
+				interval: Duration::from_millis(config.digest_interval_ms),
+			});
+
+		digest.count += 1;
+		if digest.matches.len() < config.max_matches {
+			digest.matches.push(variables.clone());
+		}
+		digest.trigger_scripts = trigger_scripts.clone();
+	}
+
+	/// Removes and returns the digests whose interval has elapsed
+	pub fn take_due(&self) -> Vec<(String, PendingDigest)> {
+		let now = Instant::now();
+		let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+		let due = pending
+			.iter()
+			.filter(|(_, digest)| digest.is_due(now))
+			.map(|(trigger_slug, _)| trigger_slug.clone())
+			.collect::<Vec<_>>();
+		due.into_iter()
+			.filter_map(|trigger_slug| pending.remove_entry(&trigger_slug))
+			.collect()
+	}
+
+	/// Removes and returns all digests
+	pub fn take_all(&self) -> Vec<(String, PendingDigest)> {
+		self.pending
+			.lock()
+			.unwrap_or_else(|e| e.into_inner())
+			.drain()
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{EVMMonitorMatch, EVMTransactionReceipt, MatchConditions},
+		utils::tests::builders::evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+	};
+
+	fn create_monitor_match() -> MonitorMatch {
+		MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new().name("test").build(),
+			transaction: TransactionBuilder::new().build(),
+			receipt: Some(EVMTransactionReceipt::default()),
+			logs: Some(vec![]),
+			block_timestamp: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: None,
+			revert_reason: None,
+			enrichment: HashMap::new(),
+		}))
+	}
+
+	fn create_variables(monitor: &str, hash: &str) -> HashMap<String, String> {
+		HashMap::from([
+			("monitor.name".to_string(), monitor.to_string()),
+			("transaction.hash".to_string(), hash.to_string()),
+		])
+	}
+
+	#[test]
+	fn test_digest_accumulates_matches() {
+		let buffer = DigestBuffer::new();
+		let config = DigestConfig {
+			digest_interval_ms: 60000,
+			max_matches: 2,
+		};
+		let monitor_match = create_monitor_match();
+
+		for (monitor, hash) in [("first", "0x1"), ("second", "0x2"), ("first", "0x3")] {
+			buffer.add(
+				"digest",
+				&config,
+				&create_variables(monitor, hash),
+				&monitor_match,
+				&HashMap::new(),
+			);
+		}
+
+		// The interval has not elapsed yet
+		assert!(buffer.take_due().is_empty());
+
+		let digests = buffer.take_all();
+		assert_eq!(digests.len(), 1);
+		let (trigger_slug, digest) = &digests[0];
+		assert_eq!(trigger_slug, "digest");
+		assert_eq!(digest.count, 3);
+		assert_eq!(digest.matches.len(), 2);
+
+		let variables = digest.variables();
+		assert_eq!(variables["digest.count"], "3");
+		assert_eq!(variables["digest.monitors"], "first, second");
+		assert_eq!(
+			variables["digest.summary"],
+			"1. first: 0x1\n2. second: 0x2\n... and 1 more"
+		);
+		assert_eq!(variables["digest.matches.1.transaction.hash"], "0x2");
+		assert!(!variables.contains_key("digest.matches.2.transaction.hash"));
+
+		assert!(buffer.take_all().is_empty());
+	}
+
+	#[test]
+	fn test_digest_take_due() {
+		let buffer = DigestBuffer::new();
+		let monitor_match = create_monitor_match();
+		let variables = create_variables("first", "0x1");
+
+		buffer.add(
+			"due",
+			&DigestConfig {
+				digest_interval_ms: 10,
+				max_matches: 10,
+			},
+			&variables,
+			&monitor_match,
+			&HashMap::new(),
+		);
+		buffer.add(
+			"not_due",
+			&DigestConfig {
+				digest_interval_ms: 60000,
+				max_matches: 10,
+			},
+			&variables,
+			&monitor_match,
+			&HashMap::new(),
+		);
+
+		std::thread::sleep(Duration::from_millis(20));
+		let due = buffer.take_due();
+		assert_eq!(due.len(), 1);
+		assert_eq!(due[0].0, "due");
+
+		let remaining = buffer.take_all();
+		assert_eq!(remaining.len(), 1);
+		assert_eq!(remaining[0].0, "not_due");
+	}
+}
@@ -0,0 +1,270 @@
+//! Persistence of the matches dispatched for each block.
+//!
+//! Records the [`MonitorMatch::match_id`] of every match whose triggers were executed, so
+//! that a block re-processed after an interruption (e.g. a crash during trigger dispatch)
+//! only dispatches the matches that were not dispatched yet.
+//!
+//! [`MonitorMatch::match_id`]: crate::models::MonitorMatch::match_id
+
+use std::{collections::HashSet, path::PathBuf};
+use tokio::sync::Mutex;
+
+/// Number of blocks the dispatched matches of a block are remembered for
+///
+/// Records of blocks older than this many blocks before the latest dispatched block are
+/// pruned, as those blocks are never re-processed.
+pub const DISPATCHED_MATCHES_RETENTION_BLOCKS: u64 = 1000;
+
+/// File-based store of the matches dispatched for each block
+///
+/// Records are appended to "{network_slug}_dispatched_matches.txt" as lines of the form
+/// "{block} {match_id}".
+#[derive(Debug)]
+pub struct DispatchedMatchStore {
+	/// Base path of the dispatched matches files
+	storage_path: PathBuf,
+	/// Number of blocks the records of a block are kept for
+	retention_blocks: u64,
+	/// Serializes the appends and rewrites of the files
+	lock: Mutex<()>,
+}
+
+impl DispatchedMatchStore {
+	/// Creates a store keeping records for [`DISPATCHED_MATCHES_RETENTION_BLOCKS`] blocks
+	pub fn new(storage_path: PathBuf) -> Self {
+		Self::with_retention(storage_path, DISPATCHED_MATCHES_RETENTION_BLOCKS)
+	}
+
+	/// Creates a store keeping records for the given number of blocks
+	///
+	/// # Arguments
+	/// * `storage_path` - Base path of the dispatched matches files
+	/// * `retention_blocks` - Number of blocks the records of a block are kept for
+	pub fn with_retention(storage_path: PathBuf, retention_blocks: u64) -> Self {
+		Self {
+			storage_path,
+			retention_blocks,
+			lock: Mutex::new(()),
+		}
+	}
+
+	fn file_path(&self, network_slug: &str) -> PathBuf {
+		self.storage_path
+			.join(format!("{}_dispatched_matches.txt", network_slug))
+	}
+
+	/// Reads the records of a network as (block, match_id) pairs
+	///
+	/// A trailing partial line (e.g. from a crash mid-write) is ignored.
+	async fn read_records(&self, network_slug: &str) -> Result<Vec<(u64, String)>, anyhow::Error> {
+		let file_path = self.file_path(network_slug);
+		if !file_path.exists() {
+			return Ok(Vec::new());
+		}
+
+		let content = tokio::fs::read_to_string(file_path)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to read dispatched matches: {}", e))?;
+		let complete = if content.ends_with('\n') {
+			content.as_str()
+		} else {
+			content
+				.rsplit_once('\n')
+				.map_or("", |(complete, _)| complete)
+		};
+		Ok(complete
+			.lines()
+			.filter_map(|line| {
+				let (block, match_id) = line.trim().split_once(' ')?;
+				Some((block.parse::<u64>().ok()?, match_id.to_string()))
+			})
+			.collect())
+	}
+
+	/// Retrieves the identifiers of the matches dispatched for a block
+	///
+	/// # Arguments
+	/// * `network_slug` - Network the block belongs to
+	/// * `block_number` - Number of the block
+	///
+	/// # Returns
+	/// * `Result<HashSet<String>, anyhow::Error>` - Identifiers of the dispatched matches
+	pub async fn get_dispatched(
+		&self,
+		network_slug: &str,
+		block_number: u64,
+	) -> Result<HashSet<String>, anyhow::Error> {
+		let _guard = self.lock.lock().await;
+		Ok(self
+			.read_records(network_slug)
+			.await?
+			.into_iter()
+			.filter(|(block, _)| *block == block_number)
+			.map(|(_, match_id)| match_id)
+			.collect())
+	}
+
+	/// Records that the triggers of a match have been dispatched
+	///
+	/// # Arguments
+	/// * `network_slug` - Network the block belongs to
+	/// * `block_number` - Number of the block the match was found in
+	/// * `match_id` - Identifier of the dispatched match
+	///
+	/// # Returns
+	/// * `Result<(), anyhow::Error>` - Success or error
+	pub async fn record_dispatched(
+		&self,
+		network_slug: &str,
+		block_number: u64,
+		match_id: &str,
+	) -> Result<(), anyhow::Error> {
+		let _guard = self.lock.lock().await;
+		let mut file = tokio::fs::OpenOptions::new()
+			.create(true)
+			.append(true)
+			.open(self.file_path(network_slug))
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to open dispatched matches file: {}", e))?;
+
+		tokio::io::AsyncWriteExt::write_all(
+			&mut file,
+			format!("{} {}\n", block_number, match_id).as_bytes(),
+		)
+		.await
+		.map_err(|e| anyhow::anyhow!("Failed to record dispatched match: {}", e))?;
+
+		Ok(())
+	}
+
+	/// Forgets the records of the blocks outside the retention window of a block
+	///
+	/// # Arguments
+	/// * `network_slug` - Network the block belongs to
+	/// * `block_number` - Number of the latest dispatched block
+	///
+	/// # Returns
+	/// * `Result<(), anyhow::Error>` - Success or error
+	pub async fn prune(&self, network_slug: &str, block_number: u64) -> Result<(), anyhow::Error> {
+		let _guard = self.lock.lock().await;
+		let records = self.read_records(network_slug).await?;
+		let cutoff = block_number.saturating_sub(self.retention_blocks);
+		if records.iter().all(|(block, _)| *block >= cutoff) {
+			return Ok(());
+		}
+
+		let retained = records
+			.iter()
+			.filter(|(block, _)| *block >= cutoff)
+			.map(|(block, match_id)| format!("{} {}\n", block, match_id))
+			.collect::<String>();
+		tokio::fs::write(self.file_path(network_slug), retained)
+			.await
+			.map_err(|e| anyhow::anyhow!("Failed to prune dispatched matches: {}", e))?;
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[tokio::test]
+	async fn test_record_and_get_dispatched() {
+		let temp_dir = tempfile::tempdir().unwrap();
+		let store = DispatchedMatchStore::new(temp_dir.path().to_path_buf());
+
+		assert!(store
+			.get_dispatched("ethereum_mainnet", 100)
+			.await
+			.unwrap()
+			.is_empty());
+
+		store
+			.record_dispatched("ethereum_mainnet", 100, "first")
+			.await
+			.unwrap();
+		store
+			.record_dispatched("ethereum_mainnet", 100, "second")
+			.await
+			.unwrap();
+		store
+			.record_dispatched("ethereum_mainnet", 101, "third")
+			.await
+			.unwrap();
+		store
+			.record_dispatched("stellar_mainnet", 100, "fourth")
+			.await
+			.unwrap();
+
+		assert_eq!(
+			store.get_dispatched("ethereum_mainnet", 100).await.unwrap(),
+			HashSet::from(["first".to_string(), "second".to_string()])
+		);
+		assert_eq!(
+			store.get_dispatched("ethereum_mainnet", 101).await.unwrap(),
+			HashSet::from(["third".to_string()])
+		);
+		assert_eq!(
+			store.get_dispatched("stellar_mainnet", 100).await.unwrap(),
+			HashSet::from(["fourth".to_string()])
+		);
+	}
+
+	#[tokio::test]
+	async fn test_get_dispatched_ignores_partial_line() {
+		let temp_dir = tempfile::tempdir().unwrap();
+		let store = DispatchedMatchStore::new(temp_dir.path().to_path_buf());
+
+		tokio::fs::write(
+			temp_dir
+				.path()
+				.join("ethereum_mainnet_dispatched_matches.txt"),
+			"100 first\n100 sec",
+		)
+		.await
+		.unwrap();
+
+		assert_eq!(
+			store.get_dispatched("ethereum_mainnet", 100).await.unwrap(),
+			HashSet::from(["first".to_string()])
+		);
+	}
+
+	#[tokio::test]
+	async fn test_prune() {
+		let temp_dir = tempfile::tempdir().unwrap();
+		let store = DispatchedMatchStore::with_retention(temp_dir.path().to_path_buf(), 10);
+
+		for block in [100, 105, 110] {
+			store
+				.record_dispatched("ethereum_mainnet", block, &block.to_string())
+				.await
+				.unwrap();
+		}
+
+		store.prune("ethereum_mainnet", 112).await.unwrap();
+
+		assert!(store
+			.get_dispatched("ethereum_mainnet", 100)
+			.await
+			.unwrap()
+			.is_empty());
+		assert_eq!(
+			store
+				.get_dispatched("ethereum_mainnet", 105)
+				.await
+				.unwrap()
+				.len(),
+			1
+		);
+		assert_eq!(
+			store
+				.get_dispatched("ethereum_mainnet", 110)
+				.await
+				.unwrap()
+				.len(),
+			1
+		);
+	}
+}
@@ -3,7 +3,7 @@
 //! Provides error types for trigger-related operations,
 //! including execution failures and configuration issues.
 
-use crate::utils::logging::error::{ErrorContext, TraceableError};
+use crate::utils::logging::error::{ErrorCode, ErrorContext, TraceableError};
 use std::collections::HashMap;
 use thiserror::Error as ThisError;
 use uuid::Uuid;
@@ -35,7 +35,7 @@ impl TriggerError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::NotFound(ErrorContext::new_with_log(msg, source, metadata))
+		Self::NotFound(ErrorContext::new(msg, source, metadata)).with_log()
 	}
 
 	// Execution error
@@ -44,7 +44,7 @@ impl TriggerError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::ExecutionError(ErrorContext::new_with_log(msg, source, metadata))
+		Self::ExecutionError(ErrorContext::new(msg, source, metadata)).with_log()
 	}
 
 	// Execution error without logging
@@ -62,7 +62,36 @@ impl TriggerError {
 		source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 		metadata: Option<HashMap<String, String>>,
 	) -> Self {
-		Self::ConfigurationError(ErrorContext::new_with_log(msg, source, metadata))
+		Self::ConfigurationError(ErrorContext::new(msg, source, metadata)).with_log()
+	}
+}
+
+impl TriggerError {
+	/// Returns the machine-readable code classifying this error
+	///
+	/// Execution errors take the code of the notification failure that caused them.
+	pub fn code(&self) -> ErrorCode {
+		match self {
+			Self::NotFound(_) => ErrorCode::NotFound,
+			Self::ConfigurationError(_) => ErrorCode::Config,
+			Self::ExecutionError(ctx) => ctx
+				.source
+				.as_ref()
+				.and_then(|source| ErrorCode::from_error(source.as_ref()))
+				.unwrap_or(ErrorCode::Unknown),
+			Self::Other(_) => ErrorCode::Unknown,
+		}
+	}
+
+	/// Logs the error together with its error code
+	fn with_log(self) -> Self {
+		match &self {
+			Self::NotFound(ctx) | Self::ExecutionError(ctx) | Self::ConfigurationError(ctx) => {
+				ctx.log_with_code(self.code())
+			}
+			Self::Other(_) => {}
+		}
+		self
 	}
 }
 
@@ -80,6 +109,7 @@ impl TraceableError for TriggerError {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::services::notification::NotificationError;
 	use std::io::{Error as IoError, ErrorKind};
 
 	#[test]
@@ -133,6 +163,52 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_error_codes() {
+		assert_eq!(
+			TriggerError::not_found("missing", None, None).code(),
+			ErrorCode::NotFound
+		);
+		assert_eq!(
+			TriggerError::configuration_error("bad config", None, None).code(),
+			ErrorCode::Config
+		);
+		assert_eq!(
+			TriggerError::execution_error("failed", None, None).code(),
+			ErrorCode::Unknown
+		);
+		assert_eq!(
+			TriggerError::from(anyhow::anyhow!("other")).code(),
+			ErrorCode::Unknown
+		);
+	}
+
+	#[test]
+	fn test_execution_error_code_from_notification_error() {
+		let notification_error = NotificationError::notify_failed(
+			"Webhook request failed",
+			None,
+			Some(HashMap::from([(
+				"status_code".to_string(),
+				"503".to_string(),
+			)])),
+		);
+		let trigger_error = TriggerError::execution_error_without_log(
+			notification_error.to_string(),
+			Some(notification_error.into()),
+			None,
+		);
+		assert_eq!(trigger_error.code(), ErrorCode::Http5xx);
+
+		// Aggregated trigger failures report the code of the wrapped failure
+		let aggregate = TriggerError::execution_error(
+			"Some trigger(s) failed (1 failure(s))",
+			Some(trigger_error.into()),
+			None,
+		);
+		assert_eq!(aggregate.code(), ErrorCode::Http5xx);
+	}
+
 	#[test]
 	fn test_from_anyhow_error() {
 		let anyhow_error = anyhow::anyhow!("test anyhow error");
@@ -4,13 +4,26 @@
 //! which are configurable actions that can be initiated based on
 //! various conditions.
 
+mod dedup;
+mod digest;
+mod dispatched;
 mod error;
+mod pause;
 mod script;
 mod service;
+mod staleness;
+mod throttle;
 
+pub use dedup::{MatchDeduplicator, MAX_DEDUP_ENTRIES};
+pub use digest::{DigestBuffer, PendingDigest};
+pub use dispatched::{DispatchedMatchStore, DISPATCHED_MATCHES_RETENTION_BLOCKS};
 pub use error::TriggerError;
+pub use pause::NotificationPause;
 pub use script::{
-	process_script_output, validate_script_config, ScriptError, ScriptExecutor,
-	ScriptExecutorFactory,
+	execute_with_retry, is_remote_script, is_transient_script_error, is_valid_checksum,
+	load_script, process_script_output, script_checksum, script_extension, validate_script_config,
+	verify_script_checksum, ScriptError, ScriptExecutor, ScriptExecutorFactory,
 };
 pub use service::{TriggerExecutionService, TriggerExecutionServiceTrait};
+pub use staleness::{MonitorStaleness, MAX_STALENESS_CHECK_INTERVAL};
+pub use throttle::{NotificationThrottle, ThrottleDecision};
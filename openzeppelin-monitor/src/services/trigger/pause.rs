@@ -0,0 +1,66 @@
+//! Global pause of notifications.
+//!
+//! Lets operators mute all notifications during an incident without redeploying. Matches
+//! are still processed and logged while notifications are paused.
+
+use std::sync::{
+	atomic::{AtomicBool, Ordering},
+	Arc,
+};
+
+/// Shared switch pausing the notifications of every monitor
+///
+/// Clones share the same state, so that a pause requested through one handle (e.g. the
+/// admin endpoints of the metrics server) applies to the trigger execution service.
+#[derive(Debug, Clone, Default)]
+pub struct NotificationPause {
+	paused: Arc<AtomicBool>,
+}
+
+impl NotificationPause {
+	/// Creates a switch with notifications enabled
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Pauses the notifications
+	///
+	/// # Returns
+	/// * `bool` - Whether the notifications were already paused
+	pub fn pause(&self) -> bool {
+		self.paused.swap(true, Ordering::SeqCst)
+	}
+
+	/// Resumes the notifications
+	///
+	/// # Returns
+	/// * `bool` - Whether the notifications were paused
+	pub fn resume(&self) -> bool {
+		self.paused.swap(false, Ordering::SeqCst)
+	}
+
+	/// Returns whether the notifications are paused
+	pub fn is_paused(&self) -> bool {
+		self.paused.load(Ordering::SeqCst)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_pause_is_shared_between_clones() {
+		let pause = NotificationPause::new();
+		let handle = pause.clone();
+		assert!(!pause.is_paused());
+
+		assert!(!handle.pause());
+		assert!(pause.is_paused());
+		assert!(handle.pause());
+
+		assert!(pause.resume());
+		assert!(!handle.is_paused());
+		assert!(!pause.resume());
+	}
+}
@@ -2,7 +2,7 @@
 //!
 //! This module provides functionality to execute scripts in different languages.
 
-use crate::models::MonitorMatch;
+use crate::{models::MonitorMatch, services::trigger::script::error::ScriptError};
 use anyhow::Context;
 use async_trait::async_trait;
 use std::{any::Any, process::Stdio, time::Duration};
@@ -162,6 +162,8 @@ impl ScriptExecutor for BashScriptExecutor {
 /// * The script execution was not successful (non-zero exit code)
 /// * The output cannot be parsed as a boolean
 /// * The script produced no output
+///
+/// Errors caused by an invalid output are `ScriptError::ParseError`s, which are not retried.
 #[allow(clippy::result_large_err)]
 pub fn process_script_output(
 	output: std::process::Output,
@@ -184,22 +186,24 @@ pub fn process_script_output(
 	let stdout = String::from_utf8_lossy(&output.stdout);
 
 	if stdout.trim().is_empty() {
-		return Err(anyhow::anyhow!("Script produced no output"));
+		return Err(ScriptError::parse_error("Script produced no output", None, None).into());
 	}
 
 	let last_line = stdout
 		.lines()
 		.last()
-		.ok_or_else(|| anyhow::anyhow!("No output from script"))?
+		.ok_or_else(|| ScriptError::parse_error("No output from script", None, None))?
 		.trim();
 
 	match last_line.to_lowercase().as_str() {
 		"true" => Ok(true),
 		"false" => Ok(false),
-		_ => Err(anyhow::anyhow!(
-			"Last line of output is not a valid boolean: {}",
-			last_line
-		)),
+		_ => Err(ScriptError::parse_error(
+			format!("Last line of output is not a valid boolean: {}", last_line),
+			None,
+			None,
+		)
+		.into()),
 	}
 }
 
@@ -248,7 +252,7 @@ mod tests {
 			monitor::MonitorBuilder, receipt::ReceiptBuilder, transaction::TransactionBuilder,
 		},
 	};
-	use std::{fs, path::Path, time::Instant};
+	use std::{collections::HashMap, fs, path::Path, time::Instant};
 
 	fn read_fixture(filename: &str) -> String {
 		let fixture_path = Path::new(env!("CARGO_MANIFEST_DIR"))
@@ -298,14 +302,20 @@ mod tests {
 			monitor: create_test_monitor(vec![], vec![], vec![], vec![]),
 			transaction: TransactionBuilder::new().build(),
 			receipt: Some(ReceiptBuilder::new().build()),
+			revert_reason: None,
 			logs: Some(create_test_evm_logs()),
+			block_timestamp: None,
 			network_slug: "evm_mainnet".to_string(),
 			matched_on: MatchConditions {
 				functions: vec![],
 				events: vec![],
 				transactions: vec![],
+				operations: vec![],
+				aggregates: vec![],
+				percentage_changes: vec![],
 			},
 			matched_on_args: None,
+			enrichment: HashMap::new(),
 		}))
 	}
 
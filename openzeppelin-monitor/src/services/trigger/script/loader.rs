@@ -0,0 +1,194 @@
+//! Trigger script loading implementation.
+//!
+//! Scripts are read from disk, or fetched when their path is an `http(s)://` URL. Their
+//! content can be verified against a SHA-256 checksum, which is required for remote scripts.
+
+use std::time::Duration;
+
+use sha2::{Digest, Sha256};
+
+use crate::services::trigger::script::ScriptError;
+
+/// Timeout of the request fetching a remote script
+const REMOTE_SCRIPT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Checks whether a script path is an `http(s)://` URL
+///
+/// # Arguments
+/// * `script_path` - Path or URL of the script
+///
+/// # Returns
+/// `true` if the script is fetched from a URL, `false` if it is read from disk
+pub fn is_remote_script(script_path: &str) -> bool {
+	let lowercase = script_path.trim().to_ascii_lowercase();
+	lowercase.starts_with("http://") || lowercase.starts_with("https://")
+}
+
+/// Returns the file extension of a script
+///
+/// The extension of a remote script is taken from the path of its URL, ignoring the query.
+///
+/// # Arguments
+/// * `script_path` - Path or URL of the script
+///
+/// # Returns
+/// The extension of the script, if any
+pub fn script_extension(script_path: &str) -> Option<String> {
+	let path = if is_remote_script(script_path) {
+		url::Url::parse(script_path.trim()).ok()?.path().to_string()
+	} else {
+		script_path.to_string()
+	};
+	std::path::Path::new(&path)
+		.extension()
+		.and_then(|ext| ext.to_str())
+		.map(str::to_string)
+}
+
+/// Computes the SHA-256 checksum of a script, hex encoded
+///
+/// # Arguments
+/// * `content` - Content of the script
+///
+/// # Returns
+/// The lowercase hex encoded checksum
+pub fn script_checksum(content: &str) -> String {
+	hex::encode(Sha256::digest(content.as_bytes()))
+}
+
+/// Checks whether a checksum is a hex encoded SHA-256 digest
+///
+/// # Arguments
+/// * `checksum` - The checksum to check
+///
+/// # Returns
+/// `true` if the checksum is 64 hex characters, `false` otherwise
+pub fn is_valid_checksum(checksum: &str) -> bool {
+	checksum.len() == 64 && checksum.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Verifies the content of a script against its expected checksum
+///
+/// # Arguments
+/// * `script_path` - Path or URL of the script, used in the error message
+/// * `content` - Content of the script
+/// * `expected` - Expected hex encoded SHA-256 checksum
+///
+/// # Returns
+/// * `Ok(())` if the checksums match
+/// * `Err(ScriptError)` otherwise
+#[allow(clippy::result_large_err)]
+pub fn verify_script_checksum(
+	script_path: &str,
+	content: &str,
+	expected: &str,
+) -> Result<(), ScriptError> {
+	let actual = script_checksum(content);
+	if !actual.eq_ignore_ascii_case(expected.trim()) {
+		return Err(ScriptError::parse_error(
+			format!(
+				"Checksum mismatch for script {}: expected {}, got {}",
+				script_path, expected, actual
+			),
+			None,
+			None,
+		));
+	}
+	Ok(())
+}
+
+/// Loads the content of a script, from disk or from its URL
+///
+/// # Arguments
+/// * `script_path` - Path or URL of the script
+///
+/// # Returns
+/// * `Ok(String)` - Content of the script
+/// * `Err(ScriptError)` - If the script cannot be read or fetched
+pub async fn load_script(script_path: &str) -> Result<String, ScriptError> {
+	if is_remote_script(script_path) {
+		return fetch_remote_script(script_path).await;
+	}
+
+	tokio::fs::read_to_string(script_path).await.map_err(|e| {
+		ScriptError::system_error(
+			format!("Failed to read script file {}: {}", script_path, e),
+			Some(Box::new(e)),
+			None,
+		)
+	})
+}
+
+/// Fetches the content of a remote script
+async fn fetch_remote_script(url: &str) -> Result<String, ScriptError> {
+	let client = reqwest::Client::builder()
+		.timeout(REMOTE_SCRIPT_TIMEOUT)
+		.build()
+		.map_err(|e| {
+			ScriptError::system_error("Failed to create HTTP client", Some(Box::new(e)), None)
+		})?;
+
+	let response = client.get(url.trim()).send().await.map_err(|e| {
+		ScriptError::system_error(
+			format!("Failed to fetch script {}", url),
+			Some(Box::new(e)),
+			None,
+		)
+	})?;
+
+	let status = response.status();
+	if !status.is_success() {
+		return Err(ScriptError::system_error(
+			format!("Failed to fetch script {}: HTTP status {}", url, status),
+			None,
+			None,
+		));
+	}
+
+	response.text().await.map_err(|e| {
+		ScriptError::system_error(
+			format!("Failed to read script {}", url),
+			Some(Box::new(e)),
+			None,
+		)
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_is_remote_script() {
+		assert!(is_remote_script("https://scripts.example.com/filter.py"));
+		assert!(is_remote_script("HTTP://scripts.example.com/filter.py"));
+		assert!(!is_remote_script("./config/filters/filter.py"));
+		assert!(!is_remote_script("/opt/https/filter.py"));
+	}
+
+	#[test]
+	fn test_script_extension() {
+		assert_eq!(
+			script_extension("https://scripts.example.com/filter.py?version=2").as_deref(),
+			Some("py")
+		);
+		assert_eq!(
+			script_extension("./config/filters/filter.js").as_deref(),
+			Some("js")
+		);
+		assert_eq!(script_extension("https://scripts.example.com/filter"), None);
+	}
+
+	#[test]
+	fn test_verify_script_checksum() {
+		let checksum = script_checksum("print(True)");
+		assert!(is_valid_checksum(&checksum));
+		assert!(verify_script_checksum("filter.py", "print(True)", &checksum).is_ok());
+		assert!(
+			verify_script_checksum("filter.py", "print(True)", &checksum.to_uppercase()).is_ok()
+		);
+
+		let error = verify_script_checksum("filter.py", "print(False)", &checksum).unwrap_err();
+		assert!(error.to_string().contains("Checksum mismatch"));
+	}
+}
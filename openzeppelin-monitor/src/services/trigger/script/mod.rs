@@ -7,8 +7,15 @@
 mod error;
 mod executor;
 mod factory;
+mod loader;
+mod retry;
 mod validation;
 pub use error::ScriptError;
 pub use executor::{process_script_output, ScriptExecutor};
 pub use factory::ScriptExecutorFactory;
+pub use loader::{
+	is_remote_script, is_valid_checksum, load_script, script_checksum, script_extension,
+	verify_script_checksum,
+};
+pub use retry::{execute_with_retry, is_transient_script_error};
 pub use validation::validate_script_config;
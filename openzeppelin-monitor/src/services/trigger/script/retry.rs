@@ -0,0 +1,233 @@
+//! Retry of failed trigger scripts.
+//!
+//! Trigger conditions and script notifications may fail for transient reasons, e.g. the
+//! interpreter failing to start or the script timing out. These failures are retried with
+//! exponential backoff following the script's retry policy. Deterministic failures, where
+//! the script ran but its output is invalid, are not retried.
+
+use backon::{ExponentialBuilder, Retryable};
+
+use crate::{
+	models::MonitorMatch,
+	services::trigger::script::{error::ScriptError, executor::ScriptExecutor},
+	utils::{JitterSetting, RetryConfig},
+};
+
+/// Returns whether a script failure may succeed when the script is run again
+///
+/// Failures to spawn or wait for the script, timeouts and non-zero exit codes are transient,
+/// while an invalid output is deterministic.
+pub fn is_transient_script_error(error: &anyhow::Error) -> bool {
+	!matches!(
+		error.downcast_ref::<ScriptError>(),
+		Some(ScriptError::ParseError(_))
+	)
+}
+
+/// Executes a script, retrying transient failures following the retry policy
+///
+/// # Arguments
+/// * `executor` - The executor of the script
+/// * `input` - The monitor match passed to the script
+/// * `timeout_ms` - The timeout of each attempt in milliseconds
+/// * `args` - Additional arguments passed to the script
+/// * `from_custom_notification` - Whether the script is from a custom notification
+/// * `retry_policy` - The retry policy, the script is run once when `None`
+///
+/// # Returns
+/// * `Result<bool, anyhow::Error>` - The result of the first successful attempt, or the error
+///   of the last attempt
+pub async fn execute_with_retry(
+	executor: &dyn ScriptExecutor,
+	input: &MonitorMatch,
+	timeout_ms: &u32,
+	args: Option<&[String]>,
+	from_custom_notification: bool,
+	retry_policy: Option<&RetryConfig>,
+) -> Result<bool, anyhow::Error> {
+	let operation = || executor.execute(input.clone(), timeout_ms, args, from_custom_notification);
+
+	let Some(retry_policy) = retry_policy else {
+		return operation().await;
+	};
+
+	let backoff = ExponentialBuilder::default()
+		.with_factor(retry_policy.base_for_backoff as f32)
+		.with_min_delay(retry_policy.initial_backoff)
+		.with_max_delay(retry_policy.max_backoff)
+		.with_max_times(retry_policy.max_retries as usize);
+
+	let backoff = match retry_policy.jitter {
+		JitterSetting::Full => backoff.with_jitter(),
+		JitterSetting::None => backoff,
+	};
+
+	operation
+		.retry(backoff)
+		.when(is_transient_script_error)
+		.notify(|error, delay| {
+			tracing::warn!(
+				"Script execution failed, retrying in {:?}: {}",
+				delay,
+				error
+			);
+		})
+		.await
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{EVMMonitorMatch, MatchConditions, ScriptLanguage},
+		services::trigger::ScriptExecutorFactory,
+		utils::tests::{
+			builders::evm::monitor::MonitorBuilder, evm::transaction::TransactionBuilder,
+		},
+	};
+	use std::{collections::HashMap, time::Duration};
+
+	fn create_test_monitor_match() -> MonitorMatch {
+		MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new().name("test").build(),
+			transaction: TransactionBuilder::new().build(),
+			receipt: None,
+			revert_reason: None,
+			logs: None,
+			block_timestamp: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: None,
+			enrichment: HashMap::new(),
+		}))
+	}
+
+	fn create_retry_policy(max_retries: u32) -> RetryConfig {
+		RetryConfig {
+			max_retries,
+			initial_backoff: Duration::from_millis(10),
+			jitter: JitterSetting::None,
+			..Default::default()
+		}
+	}
+
+	/// Bash script reading its input and appending a line to `attempts` on each run, then
+	/// running `body`
+	fn counting_script(attempts: &std::path::Path, body: &str) -> String {
+		format!(
+			"cat > /dev/null\necho run >> '{}'\n{}",
+			attempts.display(),
+			body
+		)
+	}
+
+	fn count_attempts(attempts: &std::path::Path) -> usize {
+		std::fs::read_to_string(attempts).unwrap().lines().count()
+	}
+
+	#[tokio::test]
+	async fn test_execute_with_retry_succeeds_on_second_attempt() {
+		let dir = tempfile::tempdir().unwrap();
+		let attempts = dir.path().join("attempts");
+		let script = counting_script(
+			&attempts,
+			&format!(
+				"[ $(wc -l < '{}') -ge 2 ] && echo true || exit 1",
+				attempts.display()
+			),
+		);
+		let executor = ScriptExecutorFactory::create(&ScriptLanguage::Bash, &script);
+
+		let result = execute_with_retry(
+			executor.as_ref(),
+			&create_test_monitor_match(),
+			&1000,
+			None,
+			false,
+			Some(&create_retry_policy(3)),
+		)
+		.await;
+
+		assert!(result.unwrap());
+		assert_eq!(count_attempts(&attempts), 2);
+	}
+
+	#[tokio::test]
+	async fn test_execute_with_retry_gives_up_after_max_retries() {
+		let dir = tempfile::tempdir().unwrap();
+		let attempts = dir.path().join("attempts");
+		let script = counting_script(&attempts, "exit 1");
+		let executor = ScriptExecutorFactory::create(&ScriptLanguage::Bash, &script);
+
+		let result = execute_with_retry(
+			executor.as_ref(),
+			&create_test_monitor_match(),
+			&1000,
+			None,
+			false,
+			Some(&create_retry_policy(2)),
+		)
+		.await;
+
+		assert!(result
+			.unwrap_err()
+			.to_string()
+			.contains("Script execution failed"));
+		assert_eq!(count_attempts(&attempts), 3);
+	}
+
+	#[tokio::test]
+	async fn test_execute_with_retry_does_not_retry_invalid_output() {
+		let dir = tempfile::tempdir().unwrap();
+		let attempts = dir.path().join("attempts");
+		let script = counting_script(&attempts, "echo maybe");
+		let executor = ScriptExecutorFactory::create(&ScriptLanguage::Bash, &script);
+
+		let result = execute_with_retry(
+			executor.as_ref(),
+			&create_test_monitor_match(),
+			&1000,
+			None,
+			false,
+			Some(&create_retry_policy(3)),
+		)
+		.await;
+
+		assert!(!is_transient_script_error(&result.unwrap_err()));
+		assert_eq!(count_attempts(&attempts), 1);
+	}
+
+	#[tokio::test]
+	async fn test_execute_with_retry_runs_once_without_policy() {
+		let dir = tempfile::tempdir().unwrap();
+		let attempts = dir.path().join("attempts");
+		let script = counting_script(&attempts, "exit 1");
+		let executor = ScriptExecutorFactory::create(&ScriptLanguage::Bash, &script);
+
+		let result = execute_with_retry(
+			executor.as_ref(),
+			&create_test_monitor_match(),
+			&1000,
+			None,
+			false,
+			None,
+		)
+		.await;
+
+		assert!(result.is_err());
+		assert_eq!(count_attempts(&attempts), 1);
+	}
+
+	#[test]
+	fn test_is_transient_script_error() {
+		assert!(is_transient_script_error(&anyhow::anyhow!(
+			"Script execution timed out"
+		)));
+		assert!(is_transient_script_error(&anyhow::Error::new(
+			ScriptError::execution_error("Script execution failed", None, None)
+		)));
+		assert!(!is_transient_script_error(&anyhow::Error::new(
+			ScriptError::parse_error("Script produced no output", None, None)
+		)));
+	}
+}
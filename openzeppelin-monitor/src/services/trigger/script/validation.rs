@@ -2,15 +2,19 @@
 //!
 //! This module provides functionality to validate script configuration parameters.
 
-use crate::models::{ConfigError, ScriptLanguage};
+use crate::{
+	models::{ConfigError, ScriptLanguage},
+	services::trigger::script::{is_remote_script, is_valid_checksum, script_extension},
+};
 use std::path::Path;
 
 /// Validates script configuration parameters
 ///
 /// # Arguments
-/// * `script_path` - Path to the script file
+/// * `script_path` - Path to the script file, or URL of a remote script
 /// * `language` - The supported script language
 /// * `timeout_ms` - Timeout in milliseconds
+/// * `script_checksum` - SHA-256 checksum of the script, required for remote scripts
 ///
 /// # Returns
 /// * `Ok(())` if validation passes
@@ -20,9 +24,29 @@ pub fn validate_script_config(
 	script_path: &str,
 	language: &ScriptLanguage,
 	timeout_ms: &u32,
+	script_checksum: Option<&str>,
 ) -> Result<(), ConfigError> {
-	// Validate script path exists
-	if !Path::new(script_path).exists() {
+	if is_remote_script(script_path) {
+		// Remote scripts are fetched at startup and must be pinned by their checksum
+		if url::Url::parse(script_path.trim()).is_err() {
+			return Err(ConfigError::validation_error(
+				format!("Invalid script URL: {}", script_path),
+				None,
+				None,
+			));
+		}
+		if script_checksum.is_none() {
+			return Err(ConfigError::validation_error(
+				format!(
+					"script_checksum is required for remote script: {}",
+					script_path
+				),
+				None,
+				None,
+			));
+		}
+	} else if !Path::new(script_path).exists() {
+		// Validate script path exists
 		return Err(ConfigError::validation_error(
 			format!("Script path does not exist: {}", script_path),
 			None,
@@ -30,12 +54,21 @@ pub fn validate_script_config(
 		));
 	}
 
-	let script_path_instance = Path::new(script_path);
+	if let Some(checksum) = script_checksum {
+		if !is_valid_checksum(checksum) {
+			return Err(ConfigError::validation_error(
+				format!(
+					"script_checksum must be a hex encoded SHA-256 checksum: {}",
+					checksum
+				),
+				None,
+				None,
+			));
+		}
+	}
+
 	// Validate file extension matches language
-	let extension = script_path_instance
-		.extension()
-		.and_then(|ext| ext.to_str())
-		.unwrap_or("");
+	let extension = script_extension(script_path).unwrap_or_default();
 
 	let valid_extension = match language {
 		ScriptLanguage::Python => extension == "py",
@@ -79,7 +112,7 @@ mod tests {
 		let python_path = path + ".py";
 		fs::rename(temp_file.path(), &python_path).unwrap();
 
-		let result = validate_script_config(&python_path, &ScriptLanguage::Python, &1000);
+		let result = validate_script_config(&python_path, &ScriptLanguage::Python, &1000, None);
 
 		assert!(result.is_ok());
 		fs::remove_file(python_path).unwrap();
@@ -87,8 +120,12 @@ mod tests {
 
 	#[test]
 	fn test_validate_script_config_invalid_path() {
-		let result =
-			validate_script_config("nonexistent_script.py", &ScriptLanguage::Python, &1000);
+		let result = validate_script_config(
+			"nonexistent_script.py",
+			&ScriptLanguage::Python,
+			&1000,
+			None,
+		);
 
 		assert!(result.is_err());
 		if let Err(e) = result {
@@ -103,7 +140,7 @@ mod tests {
 		let wrong_path = path + ".py";
 		fs::rename(temp_file.path(), &wrong_path).unwrap();
 
-		let result = validate_script_config(&wrong_path, &ScriptLanguage::JavaScript, &1000);
+		let result = validate_script_config(&wrong_path, &ScriptLanguage::JavaScript, &1000, None);
 
 		assert!(result.is_err());
 		if let Err(e) = result {
@@ -112,6 +149,34 @@ mod tests {
 		fs::remove_file(wrong_path).unwrap();
 	}
 
+	#[test]
+	fn test_validate_script_config_remote_script() {
+		let checksum = "a".repeat(64);
+		let url = "https://scripts.example.com/filters/filter.py?version=2";
+
+		let result = validate_script_config(url, &ScriptLanguage::Python, &1000, Some(&checksum));
+		assert!(result.is_ok());
+
+		// Remote scripts must have a checksum
+		let result = validate_script_config(url, &ScriptLanguage::Python, &1000, None);
+		assert!(result
+			.unwrap_err()
+			.to_string()
+			.contains("script_checksum is required"));
+
+		// Checksums must be SHA-256 digests
+		let result = validate_script_config(url, &ScriptLanguage::Python, &1000, Some("abc"));
+		assert!(result
+			.unwrap_err()
+			.to_string()
+			.contains("hex encoded SHA-256 checksum"));
+
+		// The extension is taken from the URL path
+		let result =
+			validate_script_config(url, &ScriptLanguage::JavaScript, &1000, Some(&checksum));
+		assert!(result.is_err());
+	}
+
 	#[test]
 	fn test_validate_script_config_zero_timeout() {
 		let temp_file = NamedTempFile::new().unwrap();
@@ -119,7 +184,7 @@ mod tests {
 		let python_path = path + ".py";
 		fs::rename(temp_file.path(), &python_path).unwrap();
 
-		let result = validate_script_config(&python_path, &ScriptLanguage::Python, &0);
+		let result = validate_script_config(&python_path, &ScriptLanguage::Python, &0, None);
 
 		assert!(result.is_err());
 		if let Err(e) = result {
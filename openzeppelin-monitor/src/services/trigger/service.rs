@@ -3,16 +3,32 @@
 //! Provides functionality to execute triggers with variable substitution
 //! and notification delivery. Manages trigger lookup and execution flow.
 
-use std::{collections::HashMap, path::Path};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 
-use anyhow::Context;
 use async_trait::async_trait;
+use tokio::{
+	sync::{watch, Semaphore},
+	task::JoinHandle,
+};
 
 use crate::{
-	models::{Monitor, MonitorMatch, ScriptLanguage, TriggerTypeConfig},
+	models::{Monitor, MonitorMatch, ScriptLanguage, ThrottleOverflow, TriggerTypeConfig},
 	repositories::{TriggerRepositoryTrait, TriggerService},
-	services::{notification::NotificationService, trigger::error::TriggerError},
-	utils::normalize_string,
+	services::{
+		notification::{DeadLetterRecord, DeadLetterStore, NotificationError, NotificationService},
+		trigger::{
+			digest::DigestBuffer,
+			error::TriggerError,
+			pause::NotificationPause,
+			script::{load_script, verify_script_checksum},
+			staleness::MonitorStaleness,
+			throttle::{NotificationThrottle, ThrottleDecision},
+		},
+	},
+	utils::{
+		metrics::{NOTIFICATIONS_PAUSED_TOTAL, NOTIFICATIONS_THROTTLED_TOTAL},
+		normalize_string,
+	},
 };
 
 /// Trait for executing triggers
@@ -32,6 +48,8 @@ pub trait TriggerExecutionServiceTrait {
 		&self,
 		monitors: &[Monitor],
 	) -> Result<HashMap<String, (ScriptLanguage, String)>, TriggerError>;
+	/// Records a match of a monitor, whether it is notified or not
+	fn record_match(&self, _monitor_name: &str) {}
 }
 
 /// Service for executing triggers with notifications
@@ -43,6 +61,16 @@ pub struct TriggerExecutionService<T: TriggerRepositoryTrait> {
 	trigger_service: TriggerService<T>,
 	/// Service for sending notifications
 	notification_service: NotificationService,
+	/// Rate limiter of the notifications of each monitor
+	throttle: NotificationThrottle,
+	/// Matches accumulated for the triggers in digest mode
+	digests: DigestBuffer,
+	/// Switch pausing all notifications
+	pause: NotificationPause,
+	/// Last match time of each monitor
+	staleness: MonitorStaleness,
+	/// Store recording the notifications that could not be delivered
+	dead_letter_store: Option<Arc<DeadLetterStore>>,
 }
 
 impl<T: TriggerRepositoryTrait> TriggerExecutionService<T> {
@@ -61,8 +89,257 @@ impl<T: TriggerRepositoryTrait> TriggerExecutionService<T> {
 		Self {
 			trigger_service,
 			notification_service,
+			throttle: NotificationThrottle::new(),
+			digests: DigestBuffer::new(),
+			pause: NotificationPause::new(),
+			staleness: MonitorStaleness::new(),
+			dead_letter_store: None,
 		}
 	}
+
+	/// Records the notifications that could not be delivered in the given store
+	///
+	/// # Arguments
+	/// * `dead_letter_store` - Store of the failed notifications
+	///
+	/// # Returns
+	/// * `Self` - The service recording its failed notifications
+	pub fn with_dead_letter_store(mut self, dead_letter_store: Arc<DeadLetterStore>) -> Self {
+		self.dead_letter_store = Some(dead_letter_store);
+		self
+	}
+
+	/// Records a failed notification in the dead-letter store, if one is configured
+	///
+	/// Failing to record is only logged, so that the notification error is reported.
+	async fn record_dead_letter(
+		&self,
+		trigger_slug: &str,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+		error: &NotificationError,
+	) {
+		let Some(store) = &self.dead_letter_store else {
+			return;
+		};
+		let record = DeadLetterRecord::new(trigger_slug, monitor_match, variables, error);
+		if let Err(e) = store.append(&record).await {
+			tracing::warn!(
+				trigger = %trigger_slug,
+				"Failed to record notification in dead-letter store: {}",
+				e
+			);
+		}
+	}
+
+	/// Returns a handle to the switch pausing the notifications of this service
+	///
+	/// Pausing through the handle suppresses the notifications of every match until they
+	/// are resumed.
+	pub fn notification_pause(&self) -> NotificationPause {
+		self.pause.clone()
+	}
+
+	/// Returns the last match time of each monitor
+	pub fn staleness(&self) -> &MonitorStaleness {
+		&self.staleness
+	}
+
+	/// Executes a single trigger
+	///
+	/// Matches of triggers in digest mode are accumulated until the next digest is sent.
+	///
+	/// # Arguments
+	/// * `trigger_slug` - Identifier of the trigger to execute
+	/// * `variables` - Variables to substitute in trigger templates
+	/// * `monitor_match` - Monitor match that fired the trigger
+	/// * `trigger_scripts` - Loaded trigger scripts
+	///
+	/// # Returns
+	/// * `Result<(), TriggerError>` - Success or error
+	async fn execute_trigger(
+		&self,
+		trigger_slug: &str,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+		trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
+	) -> Result<(), TriggerError> {
+		let trigger = self
+			.trigger_service
+			.get(trigger_slug)
+			.ok_or_else(|| TriggerError::not_found(trigger_slug.to_string(), None, None))?;
+
+		if let Some(digest) = &trigger.digest {
+			self.digests.add(
+				trigger_slug,
+				digest,
+				variables,
+				monitor_match,
+				trigger_scripts,
+			);
+			return Ok(());
+		}
+
+		if let Err(e) = self
+			.notification_service
+			.execute(&trigger, variables, monitor_match, trigger_scripts)
+			.await
+		{
+			self.record_dead_letter(trigger_slug, variables, monitor_match, &e)
+				.await;
+			// We remove logging capability here since we're logging it further down. The
+			// notification error is kept as the source so its error code is preserved.
+			return Err(TriggerError::execution_error_without_log(
+				e.to_string(),
+				Some(e.into()),
+				None,
+			));
+		}
+		Ok(())
+	}
+
+	/// Sends the digests of the triggers in digest mode
+	///
+	/// Each digest is sent as a single notification through its trigger, with the digest
+	/// variables (`digest.count`, `digest.monitors`, `digest.summary` and
+	/// `digest.matches.<index>.<variable>`) available to the message template.
+	///
+	/// # Arguments
+	/// * `force` - Whether to send all digests, even the ones whose interval has not elapsed
+	///
+	/// # Returns
+	/// * `Result<(), TriggerError>` - Success or the error of the first digest that failed
+	pub async fn flush_digests(&self, force: bool) -> Result<(), TriggerError> {
+		// Pending digests are kept until notifications are resumed, unless forced on shutdown
+		if !force && self.pause.is_paused() {
+			return Ok(());
+		}
+
+		let digests = if force {
+			self.digests.take_all()
+		} else {
+			self.digests.take_due()
+		};
+
+		let mut result = Ok(());
+		for (trigger_slug, digest) in digests {
+			// The trigger may have been removed since the matches were accumulated
+			let Some(trigger) = self.trigger_service.get(&trigger_slug) else {
+				continue;
+			};
+
+			tracing::debug!(
+				trigger = %trigger_slug,
+				matches_count = digest.count,
+				"Sending digest notification"
+			);
+			let variables = digest.variables();
+			if let Err(e) = self
+				.notification_service
+				.execute(
+					&trigger,
+					&variables,
+					&digest.monitor_match,
+					&digest.trigger_scripts,
+				)
+				.await
+			{
+				self.record_dead_letter(&trigger_slug, &variables, &digest.monitor_match, &e)
+					.await;
+				let error = TriggerError::execution_error(
+					format!("Failed to send digest of trigger '{}'", trigger_slug),
+					Some(e.into()),
+					None,
+				);
+				if result.is_ok() {
+					result = Err(error);
+				}
+			}
+		}
+		result
+	}
+}
+
+impl<T: TriggerRepositoryTrait + Send + Sync + 'static> TriggerExecutionService<T> {
+	/// Starts a background task sending the digests of the triggers in digest mode
+	///
+	/// The task checks the digests at the shortest `digest_interval_ms` of the triggers. On
+	/// shutdown, the pending digests are sent before the task stops.
+	///
+	/// # Arguments
+	/// * `shutdown_rx` - Receiver of the shutdown signal
+	///
+	/// # Returns
+	/// * `Option<JoinHandle<()>>` - The task, or `None` if no trigger is in digest mode
+	pub fn start_digest_flusher(
+		self: &Arc<Self>,
+		mut shutdown_rx: watch::Receiver<bool>,
+	) -> Option<JoinHandle<()>> {
+		let tick = self
+			.trigger_service
+			.get_all()
+			.values()
+			.filter_map(|trigger| trigger.digest.as_ref())
+			.map(|digest| digest.digest_interval_ms)
+			.min()?;
+
+		let service = self.clone();
+		Some(tokio::spawn(async move {
+			let mut interval = tokio::time::interval(Duration::from_millis(tick));
+			// The first tick completes immediately
+			interval.tick().await;
+			loop {
+				tokio::select! {
+					_ = interval.tick() => {
+						if let Err(e) = service.flush_digests(false).await {
+							tracing::error!("Failed to send digests: {}", e);
+						}
+					}
+					_ = shutdown_rx.changed() => {
+						if let Err(e) = service.flush_digests(true).await {
+							tracing::error!("Failed to send digests on shutdown: {}", e);
+						}
+						break;
+					}
+				}
+			}
+		}))
+	}
+
+	/// Starts a background task checking that monitors match within their expected window
+	///
+	/// The task checks the monitors with an `expected_match_window_secs` at their shortest
+	/// window, at least every minute, until shutdown.
+	///
+	/// # Arguments
+	/// * `monitors` - Monitors to check
+	/// * `shutdown_rx` - Receiver of the shutdown signal
+	///
+	/// # Returns
+	/// * `Option<JoinHandle<()>>` - The task, or `None` if no monitor has an expected match
+	///   window
+	pub fn start_staleness_checker(
+		self: &Arc<Self>,
+		monitors: Vec<Monitor>,
+		mut shutdown_rx: watch::Receiver<bool>,
+	) -> Option<JoinHandle<()>> {
+		let tick = MonitorStaleness::check_interval(&monitors)?;
+
+		let service = self.clone();
+		Some(tokio::spawn(async move {
+			let mut interval = tokio::time::interval(tick);
+			// The first tick completes immediately
+			interval.tick().await;
+			loop {
+				tokio::select! {
+					_ = interval.tick() => {
+						service.staleness.check(&monitors);
+					}
+					_ = shutdown_rx.changed() => break,
+				}
+			}
+		}))
+	}
 }
 
 #[async_trait]
@@ -75,6 +352,17 @@ impl<T: TriggerRepositoryTrait + Send + Sync> TriggerExecutionServiceTrait
 	/// * `trigger_slugs` - List of trigger identifiers to execute
 	/// * `variables` - Variables to substitute in trigger templates
 	///
+	/// Triggers run concurrently, at most `trigger_concurrency` at a time if set, unless the
+	/// monitor's `trigger_execution` settings request ordered execution, in which case they run
+	/// sequentially and stop at the first failure unless `continue_on_error` is set.
+	///
+	/// Matches exceeding the monitor's `notification_throttle` are not notified. With the
+	/// `summary` overflow, the next notification sent reports their count through the
+	/// `throttle.suppressed_count` variable.
+	///
+	/// While notifications are paused, see [`Self::notification_pause`], matches are not
+	/// notified nor counted against the throttle.
+	///
 	/// # Returns
 	/// * `Result<(), TriggerError>` - Success or error
 	///
@@ -84,46 +372,96 @@ impl<T: TriggerRepositoryTrait + Send + Sync> TriggerExecutionServiceTrait
 	async fn execute(
 		&self,
 		trigger_slugs: &[String],
-		variables: HashMap<String, String>,
+		mut variables: HashMap<String, String>,
 		monitor_match: &MonitorMatch,
 		trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
 	) -> Result<(), TriggerError> {
 		use futures::future::join_all;
 
-		let futures = trigger_slugs.iter().map(|trigger_slug| async {
-			let trigger = self
-				.trigger_service
-				.get(trigger_slug)
-				.ok_or_else(|| TriggerError::not_found(trigger_slug.to_string(), None, None))?;
+		let monitor = match monitor_match {
+			MonitorMatch::EVM(evm_match) => &evm_match.monitor,
+			MonitorMatch::Stellar(stellar_match) => &stellar_match.monitor,
+		};
+		let execution_config = monitor.trigger_execution.clone().unwrap_or_default();
 
-			self.notification_service
-				.execute(&trigger, &variables, monitor_match, trigger_scripts)
-				.await
-				// We remove logging capability here since we're logging it further down
-				.map_err(|e| TriggerError::execution_error_without_log(e.to_string(), None, None))
-		});
+		if self.pause.is_paused() {
+			tracing::info!(monitor = %monitor.name, "Notifications paused, match not notified");
+			NOTIFICATIONS_PAUSED_TOTAL
+				.with_label_values(&[&monitor.name])
+				.inc();
+			return Ok(());
+		}
+
+		if let Some(throttle_config) = &monitor.notification_throttle {
+			match self.throttle.acquire(&monitor.name, throttle_config) {
+				ThrottleDecision::Throttled => {
+					tracing::debug!(monitor = %monitor.name, "Notification throttled");
+					NOTIFICATIONS_THROTTLED_TOTAL
+						.with_label_values(&[&monitor.name])
+						.inc();
+					return Ok(());
+				}
+				ThrottleDecision::Allowed { suppressed } => {
+					if throttle_config.overflow == ThrottleOverflow::Summary {
+						variables.insert(
+							"throttle.suppressed_count".to_string(),
+							suppressed.to_string(),
+						);
+					}
+				}
+			}
+		}
 
-		let results = join_all(futures).await;
-		let errors: Vec<_> = results.into_iter().filter_map(|r| r.err()).collect();
+		let errors: Vec<_> = if execution_config.ordered {
+			// Run triggers one at a time in declared order
+			let mut errors = Vec::new();
+			for trigger_slug in trigger_slugs {
+				if let Err(e) = self
+					.execute_trigger(trigger_slug, &variables, monitor_match, trigger_scripts)
+					.await
+				{
+					errors.push(e);
+					if !execution_config.continue_on_error {
+						break;
+					}
+				}
+			}
+			errors
+		} else {
+			let semaphore = execution_config.trigger_concurrency.map(Semaphore::new);
+			join_all(trigger_slugs.iter().map(|trigger_slug| async {
+				// The semaphore is never closed, so acquiring a permit only waits
+				let _permit = match &semaphore {
+					Some(semaphore) => semaphore.acquire().await.ok(),
+					None => None,
+				};
+				self.execute_trigger(trigger_slug, &variables, monitor_match, trigger_scripts)
+					.await
+			}))
+			.await
+			.into_iter()
+			.filter_map(|r| r.err())
+			.collect()
+		};
 
 		if errors.is_empty() {
 			Ok(())
 		} else {
+			let failure_count = errors.len();
+			let joined = errors
+				.iter()
+				.map(|e| format!("[{}] {}", e.code(), e))
+				.collect::<Vec<_>>()
+				.join(", ");
 			Err(TriggerError::execution_error(
-				format!("Some trigger(s) failed ({} failure(s))", errors.len()),
+				format!("Some trigger(s) failed ({} failure(s))", failure_count),
 				// We join all errors into a single string for the source and wrap it as a single
-				// Execution
+				// Execution. The first failure is kept as its source so the aggregate error
+				// reports that failure's error code.
 				Some(
-					TriggerError::execution_error(
-						format!(
-							"{:#?}",
-							errors
-								.iter()
-								.map(|e| e.to_string())
-								.collect::<Vec<_>>()
-								.join(", ")
-						),
-						None,
+					TriggerError::execution_error_without_log(
+						format!("{:#?}", joined),
+						errors.into_iter().next().map(Into::into),
 						None,
 					)
 					.into(),
@@ -132,8 +470,16 @@ impl<T: TriggerRepositoryTrait + Send + Sync> TriggerExecutionServiceTrait
 			))
 		}
 	}
+	/// Records a match of a monitor as its last match, see [`Self::staleness`]
+	fn record_match(&self, monitor_name: &str) {
+		self.staleness.record_match(monitor_name);
+	}
+
 	/// Loads trigger condition scripts for monitors
 	///
+	/// Scripts whose path is an `http(s)://` URL are fetched, once per URL. Scripts with a
+	/// `script_checksum` are verified against it.
+	///
 	/// # Arguments
 	/// * `monitors` - List of monitors containing trigger conditions
 	///
@@ -142,12 +488,15 @@ impl<T: TriggerRepositoryTrait + Send + Sync> TriggerExecutionServiceTrait
 	///   and script path to their script language and content
 	///
 	/// # Errors
-	/// - Returns `TriggerError::ConfigurationError` if script files cannot be read
+	/// - Returns `TriggerError::ConfigurationError` if scripts cannot be read or fetched, or do
+	///   not match their checksum
 	async fn load_scripts(
 		&self,
 		monitors: &[Monitor],
 	) -> Result<HashMap<String, (ScriptLanguage, String)>, TriggerError> {
 		let mut scripts = HashMap::new();
+		// Contents by script path, so that scripts shared by monitors are only read once
+		let mut contents = HashMap::new();
 
 		for monitor in monitors {
 			// Skip monitors without trigger conditions
@@ -157,14 +506,13 @@ impl<T: TriggerRepositoryTrait + Send + Sync> TriggerExecutionServiceTrait
 
 			// For each monitor, we'll load all its trigger condition scripts
 			for condition in &monitor.trigger_conditions {
-				let script_path = Path::new(&condition.script_path);
-
 				// Read the script content
-				let content = tokio::fs::read_to_string(script_path)
-					.await
-					.with_context(|| {
-						format!("Failed to read script file: {}", condition.script_path)
-					})?;
+				let content = read_script(
+					&condition.script_path,
+					condition.script_checksum.as_deref(),
+					&mut contents,
+				)
+				.await?;
 				// Store the script content with its language
 				scripts.insert(
 					format!(
@@ -178,44 +526,31 @@ impl<T: TriggerRepositoryTrait + Send + Sync> TriggerExecutionServiceTrait
 
 			// For each trigger, we'll load the script
 			for trigger in &monitor.triggers {
-				let trigger_config =
-					self.trigger_service.get(trigger.as_str()).ok_or_else(|| {
-						TriggerError::configuration_error(
-							format!("Failed to get trigger: {}", trigger),
-							None,
-							None,
-						)
-					})?;
+				let trigger_config = self.trigger_service.get(trigger.name()).ok_or_else(|| {
+					TriggerError::configuration_error(
+						format!("Failed to get trigger: {}", trigger.name()),
+						None,
+						None,
+					)
+				})?;
 
 				let TriggerTypeConfig::Script {
 					language,
 					script_path,
 					arguments: _,
 					timeout_ms: _,
+					script_checksum,
+					retry_policy: _,
 				} = &trigger_config.config
 				else {
 					continue;
 				};
 
-				let script_path = Path::new(script_path);
-				let content = tokio::fs::read_to_string(script_path).await.map_err(|e| {
-					TriggerError::configuration_error(
-						format!(
-							"Failed to read script file {}: {}",
-							script_path.display(),
-							e
-						),
-						None,
-						None,
-					)
-				})?;
+				let content =
+					read_script(script_path, script_checksum.as_deref(), &mut contents).await?;
 
 				scripts.insert(
-					format!(
-						"{}|{}",
-						normalize_string(&monitor.name),
-						script_path.display()
-					),
+					format!("{}|{}", normalize_string(&monitor.name), script_path),
 					(language.clone(), content),
 				);
 			}
@@ -224,3 +559,37 @@ impl<T: TriggerRepositoryTrait + Send + Sync> TriggerExecutionServiceTrait
 		Ok(scripts)
 	}
 }
+
+/// Reads a script from disk or its URL, verifying its checksum when configured
+///
+/// # Arguments
+/// * `script_path` - Path or URL of the script
+/// * `script_checksum` - Expected SHA-256 checksum of the script
+/// * `contents` - Contents of the scripts already read, by script path
+///
+/// # Returns
+/// * `Result<String, TriggerError>` - Content of the script
+async fn read_script(
+	script_path: &str,
+	script_checksum: Option<&str>,
+	contents: &mut HashMap<String, String>,
+) -> Result<String, TriggerError> {
+	let content = match contents.get(script_path) {
+		Some(content) => content.clone(),
+		None => {
+			let content = load_script(script_path).await.map_err(|e| {
+				TriggerError::configuration_error(e.to_string(), Some(Box::new(e)), None)
+			})?;
+			contents.insert(script_path.to_string(), content.clone());
+			content
+		}
+	};
+
+	if let Some(checksum) = script_checksum {
+		verify_script_checksum(script_path, &content, checksum).map_err(|e| {
+			TriggerError::configuration_error(e.to_string(), Some(Box::new(e)), None)
+		})?;
+	}
+
+	Ok(content)
+}
@@ -0,0 +1,217 @@
+//! Detection of monitors that stopped matching.
+//!
+//! A monitor that never matches is often misconfigured, e.g. watching the wrong address or
+//! event signature. Monitors with an `expected_match_window_secs` report the time elapsed
+//! since their last match through the `monitor_last_match_age_seconds` metric, and a warning
+//! is logged while they have not matched within their window.
+
+use std::{
+	collections::HashMap,
+	sync::Mutex,
+	time::{Duration, Instant},
+};
+
+use crate::{models::Monitor, utils::metrics::MONITOR_LAST_MATCH_AGE_SECONDS};
+
+/// Longest interval between two checks of the monitors
+pub const MAX_STALENESS_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Last match time of each monitor
+///
+/// Monitors that have not matched yet are considered to have matched when they were first
+/// tracked, so that their age is the time elapsed since the service started.
+#[derive(Debug)]
+pub struct MonitorStaleness {
+	started: Instant,
+	last_matches: Mutex<HashMap<String, Instant>>,
+}
+
+impl Default for MonitorStaleness {
+	fn default() -> Self {
+		Self::new_at(Instant::now())
+	}
+}
+
+impl MonitorStaleness {
+	/// Creates a tracker without any match
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Creates a tracker without any match, started at the given time
+	pub fn new_at(started: Instant) -> Self {
+		Self {
+			started,
+			last_matches: Mutex::new(HashMap::new()),
+		}
+	}
+
+	/// Records a match of a monitor
+	pub fn record_match(&self, monitor_name: &str) {
+		self.record_match_at(monitor_name, Instant::now());
+	}
+
+	/// Records a match of a monitor at the given time
+	pub fn record_match_at(&self, monitor_name: &str, now: Instant) {
+		self.last_matches
+			.lock()
+			.unwrap_or_else(|e| e.into_inner())
+			.insert(monitor_name.to_string(), now);
+	}
+
+	/// Returns the time elapsed since the last match of a monitor at the given time
+	pub fn last_match_age_at(&self, monitor_name: &str, now: Instant) -> Duration {
+		let last_matches = self.last_matches.lock().unwrap_or_else(|e| e.into_inner());
+		let last_match = last_matches
+			.get(monitor_name)
+			.copied()
+			.unwrap_or(self.started);
+		now.saturating_duration_since(last_match)
+	}
+
+	/// Checks the monitors with an expected match window
+	///
+	/// Updates the `monitor_last_match_age_seconds` metric of each monitor and logs a warning
+	/// for the monitors that have not matched within their window. Paused monitors are skipped.
+	///
+	/// # Arguments
+	/// * `monitors` - Monitors to check
+	///
+	/// # Returns
+	/// * `Vec<String>` - Names of the monitors that have not matched within their window
+	pub fn check(&self, monitors: &[Monitor]) -> Vec<String> {
+		self.check_at(monitors, Instant::now())
+	}
+
+	/// Checks the monitors with an expected match window at the given time
+	pub fn check_at(&self, monitors: &[Monitor], now: Instant) -> Vec<String> {
+		let mut stale = Vec::new();
+		for monitor in monitors {
+			let Some(window_secs) = monitor.expected_match_window_secs else {
+				continue;
+			};
+			if monitor.is_paused() {
+				continue;
+			}
+
+			let age = self.last_match_age_at(&monitor.name, now);
+			MONITOR_LAST_MATCH_AGE_SECONDS
+				.with_label_values(&[&monitor.name])
+				.set(age.as_secs_f64());
+
+			if age > Duration::from_secs(window_secs) {
+				tracing::warn!(
+					monitor = %monitor.name,
+					"Monitor '{}' has not matched for {}s, expected a match within {}s",
+					monitor.name,
+					age.as_secs(),
+					window_secs
+				);
+				stale.push(monitor.name.clone());
+			}
+		}
+		stale
+	}
+
+	/// Returns the interval at which the monitors are checked
+	///
+	/// The interval is the shortest expected match window of the monitors, bounded by
+	/// `MAX_STALENESS_CHECK_INTERVAL`, or `None` if no monitor has an expected match window.
+	pub fn check_interval(monitors: &[Monitor]) -> Option<Duration> {
+		monitors
+			.iter()
+			.filter_map(|monitor| monitor.expected_match_window_secs)
+			.min()
+			.map(|window_secs| Duration::from_secs(window_secs).min(MAX_STALENESS_CHECK_INTERVAL))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::utils::tests::builders::evm::monitor::MonitorBuilder;
+	use tracing_test::traced_test;
+
+	fn create_test_monitor(name: &str, window_secs: Option<u64>) -> Monitor {
+		let mut monitor = MonitorBuilder::new().name(name).build();
+		monitor.expected_match_window_secs = window_secs;
+		monitor
+	}
+
+	fn last_match_age(monitor_name: &str) -> f64 {
+		MONITOR_LAST_MATCH_AGE_SECONDS
+			.with_label_values(&[monitor_name])
+			.get()
+	}
+
+	#[test]
+	#[traced_test]
+	fn test_check_reports_monitor_without_match_past_window() {
+		let started = Instant::now();
+		let staleness = MonitorStaleness::new_at(started);
+		let monitors = vec![create_test_monitor("staleness_never_matched", Some(60))];
+
+		assert!(staleness.check_at(&monitors, started).is_empty());
+		assert_eq!(last_match_age("staleness_never_matched"), 0.0);
+
+		let later = started + Duration::from_secs(61);
+		assert_eq!(
+			staleness.check_at(&monitors, later),
+			vec!["staleness_never_matched".to_string()]
+		);
+		assert_eq!(last_match_age("staleness_never_matched"), 61.0);
+		assert!(logs_contain(
+			"Monitor 'staleness_never_matched' has not matched for 61s, expected a match within 60s"
+		));
+	}
+
+	#[test]
+	fn test_check_resets_age_on_match() {
+		let started = Instant::now();
+		let staleness = MonitorStaleness::new_at(started);
+		let monitors = vec![create_test_monitor("staleness_matched", Some(60))];
+
+		staleness.record_match_at("staleness_matched", started + Duration::from_secs(50));
+
+		let later = started + Duration::from_secs(100);
+		assert!(staleness.check_at(&monitors, later).is_empty());
+		assert_eq!(last_match_age("staleness_matched"), 50.0);
+
+		let much_later = started + Duration::from_secs(111);
+		assert_eq!(
+			staleness.check_at(&monitors, much_later),
+			vec!["staleness_matched".to_string()]
+		);
+	}
+
+	#[test]
+	fn test_check_skips_monitors_without_window_or_paused() {
+		let started = Instant::now();
+		let staleness = MonitorStaleness::new_at(started);
+		let mut paused = create_test_monitor("staleness_paused", Some(60));
+		paused.paused = true;
+		let monitors = vec![create_test_monitor("staleness_no_window", None), paused];
+
+		let later = started + Duration::from_secs(3600);
+		assert!(staleness.check_at(&monitors, later).is_empty());
+	}
+
+	#[test]
+	fn test_check_interval() {
+		assert_eq!(
+			MonitorStaleness::check_interval(&[create_test_monitor("a", None)]),
+			None
+		);
+		assert_eq!(
+			MonitorStaleness::check_interval(&[
+				create_test_monitor("a", Some(30)),
+				create_test_monitor("b", Some(10)),
+			]),
+			Some(Duration::from_secs(10))
+		);
+		assert_eq!(
+			MonitorStaleness::check_interval(&[create_test_monitor("a", Some(86400))]),
+			Some(MAX_STALENESS_CHECK_INTERVAL)
+		);
+	}
+}
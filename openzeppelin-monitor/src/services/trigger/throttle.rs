@@ -0,0 +1,196 @@
+//! Notification throttling for monitors.
+//!
+//! Limits the number of matches notified for a monitor within a time window with a token
+//! bucket per monitor, as configured by the monitor's `notification_throttle`.
+
+use std::{
+	collections::HashMap,
+	sync::Mutex,
+	time::{Duration, Instant},
+};
+
+use crate::models::NotificationThrottleConfig;
+
+/// Outcome of a throttling check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThrottleDecision {
+	/// The match can be notified, `suppressed` matches were throttled since the last one
+	Allowed { suppressed: u64 },
+	/// The match exceeds the monitor's limit and must not be notified
+	Throttled,
+}
+
+/// Token bucket of a monitor
+#[derive(Debug)]
+struct TokenBucket {
+	tokens: f64,
+	last_refill: Instant,
+	suppressed: u64,
+}
+
+/// Token bucket rate limiter of the notifications of each monitor
+///
+/// Buckets start full, hold up to `max_notifications_per_window` tokens and are refilled
+/// continuously, at `max_notifications_per_window` tokens per `window_ms`.
+#[derive(Debug, Default)]
+pub struct NotificationThrottle {
+	buckets: Mutex<HashMap<String, TokenBucket>>,
+}
+
+impl NotificationThrottle {
+	/// Creates a throttle without any bucket
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Consumes a token from the bucket of a monitor
+	///
+	/// # Arguments
+	/// * `monitor_name` - Name of the monitor the match belongs to
+	/// * `config` - Throttle settings of the monitor
+	///
+	/// # Returns
+	/// * `ThrottleDecision` - Whether the match can be notified
+	pub fn acquire(
+		&self,
+		monitor_name: &str,
+		config: &NotificationThrottleConfig,
+	) -> ThrottleDecision {
+		self.acquire_at(monitor_name, config, Instant::now())
+	}
+
+	/// Consumes a token from the bucket of a monitor at the given time
+	pub fn acquire_at(
+		&self,
+		monitor_name: &str,
+		config: &NotificationThrottleConfig,
+		now: Instant,
+	) -> ThrottleDecision {
+		let capacity = config.max_notifications_per_window as f64;
+		let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+		let bucket = buckets
+			.entry(monitor_name.to_string())
+			.or_insert_with(|| TokenBucket {
+				tokens: capacity,
+				last_refill: now,
+				suppressed: 0,
+			});
+
+		// Refill the tokens earned since the last check, without exceeding the capacity
+		let window = Duration::from_millis(config.window_ms).as_secs_f64();
+		let elapsed = now
+			.saturating_duration_since(bucket.last_refill)
+			.as_secs_f64();
+		if window > 0.0 {
+			bucket.tokens = (bucket.tokens + elapsed * capacity / window).min(capacity);
+		}
+		bucket.last_refill = now;
+
+		if bucket.tokens >= 1.0 {
+			bucket.tokens -= 1.0;
+			ThrottleDecision::Allowed {
+				suppressed: std::mem::take(&mut bucket.suppressed),
+			}
+		} else {
+			bucket.suppressed += 1;
+			ThrottleDecision::Throttled
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::models::ThrottleOverflow;
+
+	fn create_config(
+		max_notifications_per_window: u32,
+		window_ms: u64,
+	) -> NotificationThrottleConfig {
+		NotificationThrottleConfig {
+			max_notifications_per_window,
+			window_ms,
+			overflow: ThrottleOverflow::Drop,
+		}
+	}
+
+	#[test]
+	fn test_throttle_empties_bucket() {
+		let throttle = NotificationThrottle::new();
+		let config = create_config(3, 60000);
+		let now = Instant::now();
+
+		for _ in 0..3 {
+			assert_eq!(
+				throttle.acquire_at("monitor", &config, now),
+				ThrottleDecision::Allowed { suppressed: 0 }
+			);
+		}
+		assert_eq!(
+			throttle.acquire_at("monitor", &config, now),
+			ThrottleDecision::Throttled
+		);
+		assert_eq!(
+			throttle.acquire_at("monitor", &config, now + Duration::from_millis(1000)),
+			ThrottleDecision::Throttled
+		);
+	}
+
+	#[test]
+	fn test_throttle_refills_bucket_over_window() {
+		let throttle = NotificationThrottle::new();
+		let config = create_config(2, 1000);
+		let now = Instant::now();
+
+		throttle.acquire_at("monitor", &config, now);
+		throttle.acquire_at("monitor", &config, now);
+		assert_eq!(
+			throttle.acquire_at("monitor", &config, now),
+			ThrottleDecision::Throttled
+		);
+
+		// Half a window refills one token and reports the throttled match
+		let later = now + Duration::from_millis(500);
+		assert_eq!(
+			throttle.acquire_at("monitor", &config, later),
+			ThrottleDecision::Allowed { suppressed: 1 }
+		);
+		assert_eq!(
+			throttle.acquire_at("monitor", &config, later),
+			ThrottleDecision::Throttled
+		);
+
+		// Refilling never exceeds the capacity
+		let much_later = later + Duration::from_secs(3600);
+		for _ in 0..2 {
+			assert!(matches!(
+				throttle.acquire_at("monitor", &config, much_later),
+				ThrottleDecision::Allowed { .. }
+			));
+		}
+		assert_eq!(
+			throttle.acquire_at("monitor", &config, much_later),
+			ThrottleDecision::Throttled
+		);
+	}
+
+	#[test]
+	fn test_throttle_buckets_per_monitor() {
+		let throttle = NotificationThrottle::new();
+		let config = create_config(1, 60000);
+		let now = Instant::now();
+
+		assert_eq!(
+			throttle.acquire_at("first", &config, now),
+			ThrottleDecision::Allowed { suppressed: 0 }
+		);
+		assert_eq!(
+			throttle.acquire_at("first", &config, now),
+			ThrottleDecision::Throttled
+		);
+		assert_eq!(
+			throttle.acquire_at("second", &config, now),
+			ThrottleDecision::Allowed { suppressed: 0 }
+		);
+	}
+}
@@ -1,14 +1,117 @@
 //! Utility functions for working with cron schedules and time intervals
 //!
 //! This module provides helper functions for parsing and analyzing cron expressions,
+//! as well as the `@interval:<duration>` schedule form used for sub-second polling and the
+//! `@adaptive` schedule form polling on the network's observed block cadence.
 
 use chrono::Utc;
 use cron::Schedule;
+use std::{str::FromStr, time::Duration};
+
+/// Prefix identifying a fixed-interval schedule (e.g. `@interval:500ms`)
+pub const INTERVAL_SCHEDULE_PREFIX: &str = "@interval:";
+
+/// Schedule polling near the expected time of the next block instead of on a fixed schedule
+pub const ADAPTIVE_SCHEDULE: &str = "@adaptive";
+
+/// Number of configured block times bounding the adaptive schedule's poll interval
+pub const ADAPTIVE_MAX_BLOCK_TIMES: u32 = 8;
+
+/// Returns whether a schedule is the adaptive schedule
+///
+/// # Arguments
+///
+/// * `schedule` - The schedule string to check
+pub fn is_adaptive_schedule(schedule: &str) -> bool {
+	schedule.trim() == ADAPTIVE_SCHEDULE
+}
+
+/// Parses a fixed-interval schedule of the form `@interval:<n><unit>`
+///
+/// Supported units are `ms`, `s` and `m`. Interval schedules bypass cron entirely,
+/// which allows polling more often than cron's one second resolution.
+///
+/// # Arguments
+///
+/// * `schedule` - The schedule string to parse
+///
+/// # Returns
+///
+/// * `Ok(Some(Duration))` - If the schedule is a valid interval schedule
+/// * `Ok(None)` - If the schedule does not use the interval form
+/// * `Err(String)` - If the schedule uses the interval form but is malformed
+pub fn parse_interval_schedule(schedule: &str) -> Result<Option<Duration>, String> {
+	let Some(value) = schedule.trim().strip_prefix(INTERVAL_SCHEDULE_PREFIX) else {
+		return Ok(None);
+	};
+
+	let value = value.trim();
+	let split_at = value
+		.find(|c: char| !c.is_ascii_digit())
+		.unwrap_or(value.len());
+	let (amount, unit) = value.split_at(split_at);
+
+	let amount: u64 = amount.parse().map_err(|_| {
+		format!(
+			"Invalid interval schedule '{}': expected a positive integer followed by a unit \
+			 (ms, s or m), e.g. '{}500ms'",
+			schedule, INTERVAL_SCHEDULE_PREFIX
+		)
+	})?;
+
+	let duration = match unit {
+		"ms" => Duration::from_millis(amount),
+		"s" => Duration::from_secs(amount),
+		"m" => Duration::from_secs(amount.saturating_mul(60)),
+		_ => {
+			return Err(format!(
+				"Invalid interval schedule '{}': unsupported unit '{}', expected one of ms, s or m",
+				schedule, unit
+			))
+		}
+	};
+
+	if duration.is_zero() {
+		return Err(format!(
+			"Invalid interval schedule '{}': interval must be greater than 0",
+			schedule
+		));
+	}
+
+	Ok(Some(duration))
+}
+
+/// Validates a network schedule, accepting a cron expression, an interval schedule or the
+/// adaptive schedule
+///
+/// # Arguments
+///
+/// * `schedule` - The schedule string to validate
+///
+/// # Returns
+///
+/// * `Ok(())` - If the schedule is valid
+/// * `Err(String)` - A description of why the schedule is invalid
+pub fn validate_schedule(schedule: &str) -> Result<(), String> {
+	if is_adaptive_schedule(schedule) || parse_interval_schedule(schedule)?.is_some() {
+		return Ok(());
+	}
+
+	Schedule::from_str(schedule).map(|_| ()).map_err(|e| {
+		format!(
+			"Invalid cron schedule '{}': {}. Expected a 6-field cron expression \
+			 (sec min hour day month weekday) such as '*/15 * * * * *', an interval \
+			 such as '{}500ms', or '{}'",
+			schedule, e, INTERVAL_SCHEDULE_PREFIX, ADAPTIVE_SCHEDULE
+		)
+	})
+}
 
 /// Calculates the time interval between two consecutive occurrences of a cron schedule
 ///
 /// This function takes a cron expression and determines how many milliseconds will elapse
-/// between two consecutive runs of the schedule.
+/// between two consecutive runs of the schedule. Interval schedules (e.g. `@interval:500ms`)
+/// return their configured interval directly.
 ///
 /// # Arguments
 ///
@@ -20,6 +123,12 @@ use cron::Schedule;
 /// * `None` - If the cron expression is invalid or if two consecutive occurrences cannot be
 ///   determined
 pub fn get_cron_interval_ms(cron_schedule: &str) -> Option<i64> {
+	match parse_interval_schedule(cron_schedule) {
+		Ok(Some(interval)) => return i64::try_from(interval.as_millis()).ok(),
+		Ok(None) => {}
+		Err(_) => return None,
+	}
+
 	// Parse the cron schedule
 	let schedule = match cron_schedule.parse::<Schedule>() {
 		Ok(schedule) => schedule,
@@ -40,3 +149,58 @@ pub fn get_cron_interval_ms(cron_schedule: &str) -> Option<i64> {
 		None // Return None if we cannot find two occurrences
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_interval_schedule_units() {
+		assert_eq!(
+			parse_interval_schedule("@interval:500ms").unwrap(),
+			Some(Duration::from_millis(500))
+		);
+		assert_eq!(
+			parse_interval_schedule("@interval:2s").unwrap(),
+			Some(Duration::from_secs(2))
+		);
+		assert_eq!(
+			parse_interval_schedule("@interval:1m").unwrap(),
+			Some(Duration::from_secs(60))
+		);
+	}
+
+	#[test]
+	fn test_parse_interval_schedule_not_interval() {
+		assert_eq!(parse_interval_schedule("*/15 * * * * *").unwrap(), None);
+	}
+
+	#[test]
+	fn test_parse_interval_schedule_invalid() {
+		assert!(parse_interval_schedule("@interval:").is_err());
+		assert!(parse_interval_schedule("@interval:0ms").is_err());
+		assert!(parse_interval_schedule("@interval:500").is_err());
+		assert!(parse_interval_schedule("@interval:500h").is_err());
+		assert!(parse_interval_schedule("@interval:-5s").is_err());
+	}
+
+	#[test]
+	fn test_validate_schedule() {
+		assert!(validate_schedule("*/15 * * * * *").is_ok());
+		assert!(validate_schedule("@interval:250ms").is_ok());
+		assert!(validate_schedule("@adaptive").is_ok());
+
+		let err = validate_schedule("every minute").unwrap_err();
+		assert!(err.contains("Invalid cron schedule 'every minute'"));
+		assert!(err.contains("@interval:500ms"));
+		assert!(err.contains("@adaptive"));
+	}
+
+	#[test]
+	fn test_get_cron_interval_ms() {
+		assert_eq!(get_cron_interval_ms("*/15 * * * * *"), Some(15_000));
+		assert_eq!(get_cron_interval_ms("@interval:500ms"), Some(500));
+		assert_eq!(get_cron_interval_ms("@interval:0ms"), None);
+		assert_eq!(get_cron_interval_ms("invalid"), None);
+	}
+}
@@ -1,9 +1,78 @@
-use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
+use http::{Extensions, HeaderName, HeaderValue};
+use reqwest::{Request, Response, ResponseBuilderExt};
+use reqwest_middleware::{ClientBuilder, ClientWithMiddleware, Middleware, Next};
 use reqwest_retry::{
-	policies::ExponentialBackoff, Jitter, RetryTransientMiddleware, RetryableStrategy,
+	policies::ExponentialBackoff, Jitter, RetryError, RetryTransientMiddleware, RetryableStrategy,
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
+use std::{
+	sync::{
+		atomic::{AtomicU32, Ordering},
+		Arc,
+	},
+	time::{Duration, Instant},
+};
+
+use crate::utils::metrics::RETRY_ATTEMPTS_TOTAL;
+
+/// Environment variable overriding the `User-Agent` of outgoing HTTP requests
+pub const HTTP_USER_AGENT_ENV: &str = "HTTP_USER_AGENT";
+
+/// Environment variable setting the name of the request id header, or disabling it when empty
+pub const HTTP_REQUEST_ID_HEADER_ENV: &str = "HTTP_REQUEST_ID_HEADER";
+
+/// Default name of the header carrying the id of outgoing HTTP requests
+pub const DEFAULT_REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Returns the default `User-Agent` of outgoing HTTP requests, e.g. `openzeppelin-monitor/1.0.0`
+pub fn default_user_agent() -> String {
+	format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+}
+
+/// Returns the `User-Agent` of outgoing RPC and notification HTTP requests
+///
+/// Reads `HTTP_USER_AGENT`, falling back to [`default_user_agent`] if it is unset, empty or
+/// not a valid header value.
+pub fn user_agent() -> HeaderValue {
+	let default =
+		|| HeaderValue::from_str(&default_user_agent()).expect("Default user agent is valid");
+	match std::env::var(HTTP_USER_AGENT_ENV) {
+		Ok(value) if value.trim().is_empty() => default(),
+		Ok(value) => HeaderValue::from_str(value.trim()).unwrap_or_else(|_| {
+			tracing::warn!(
+				"Invalid {} '{}', using the default user agent",
+				HTTP_USER_AGENT_ENV,
+				value
+			);
+			default()
+		}),
+		Err(_) => default(),
+	}
+}
+
+/// Returns the name of the header carrying the id of outgoing HTTP requests
+///
+/// Reads `HTTP_REQUEST_ID_HEADER`, falling back to `x-request-id` if it is unset or not a
+/// valid header name. Returns `None` if it is empty, disabling the header.
+pub fn request_id_header() -> Option<HeaderName> {
+	let default = HeaderName::from_static(DEFAULT_REQUEST_ID_HEADER);
+	match std::env::var(HTTP_REQUEST_ID_HEADER_ENV) {
+		Ok(value) if value.trim().is_empty() => None,
+		Ok(value) => Some(
+			HeaderName::from_bytes(value.trim().as_bytes()).unwrap_or_else(|_| {
+				tracing::warn!(
+					"Invalid {} '{}', using {}",
+					HTTP_REQUEST_ID_HEADER_ENV,
+					value,
+					DEFAULT_REQUEST_ID_HEADER
+				);
+				default
+			}),
+		),
+		Err(_) => Some(default),
+	}
+}
 
 /// --- Default values for retry configuration settings ---
 fn default_max_attempts() -> u32 {
@@ -23,7 +92,7 @@ fn default_base_for_backoff() -> u32 {
 }
 
 /// Serializable setting for jitter in retry policies
-#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum JitterSetting {
 	/// No jitter applied to the backoff duration
@@ -34,7 +103,7 @@ pub enum JitterSetting {
 }
 
 /// Configuration for HTTP (RPC and Webhook notifiers) and SMTP (Email notifier) retry policies
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, JsonSchema)]
 pub struct RetryConfig {
 	/// Maximum number of retries for transient errors
 	#[serde(default = "default_max_attempts")]
@@ -68,6 +137,9 @@ impl Default for RetryConfig {
 
 /// Creates a retryable HTTP client with middleware for a single URL
 ///
+/// Unless disabled with `HTTP_REQUEST_ID_HEADER`, each request carries a unique id header,
+/// shared by its retries and logged within the current tracing span.
+///
 /// # Parameters:
 /// - `config`: Configuration for retry policies
 /// - `base_client`: The base HTTP client to use
@@ -81,6 +153,43 @@ pub fn create_retryable_http_client<S>(
 	base_client: reqwest::Client,
 	custom_strategy: Option<S>,
 ) -> ClientWithMiddleware
+where
+	S: RetryableStrategy + Send + Sync + 'static,
+{
+	build_retryable_http_client(config, base_client, custom_strategy, false)
+}
+
+/// Creates a retryable HTTP client for endpoints answering with JSON, such as RPC endpoints
+///
+/// Behaves as [`create_retryable_http_client`], and additionally fails the attempts whose
+/// successful response is not valid JSON (e.g. truncated bodies or HTML error pages) with a
+/// [`MalformedResponseError`], so that a strategy treating it as transient retries them with
+/// the configured backoff.
+///
+/// # Parameters:
+/// - `config`: Configuration for retry policies
+/// - `base_client`: The base HTTP client to use
+/// - `custom_strategy`: Optional custom retry strategy, complementing the default retry behavior
+///
+/// # Returns
+/// A `ClientWithMiddleware` that includes retry capabilities
+pub fn create_retryable_json_http_client<S>(
+	config: &RetryConfig,
+	base_client: reqwest::Client,
+	custom_strategy: Option<S>,
+) -> ClientWithMiddleware
+where
+	S: RetryableStrategy + Send + Sync + 'static,
+{
+	build_retryable_http_client(config, base_client, custom_strategy, true)
+}
+
+fn build_retryable_http_client<S>(
+	config: &RetryConfig,
+	base_client: reqwest::Client,
+	custom_strategy: Option<S>,
+	validate_json: bool,
+) -> ClientWithMiddleware
 where
 	S: RetryableStrategy + Send + Sync + 'static,
 {
@@ -96,14 +205,417 @@ where
 		.retry_bounds(config.initial_backoff, config.max_backoff)
 		.build_with_max_retries(config.max_retries);
 
+	// The request id and outcome middlewares wrap the retries of a request, while the
+	// attempt middleware runs once per attempt
+	let mut builder = ClientBuilder::new(base_client);
+	if let Some(header) = request_id_header() {
+		builder = builder.with(RequestIdMiddleware { header });
+	}
+	let builder = builder.with(RetryOutcomeMiddleware {
+		max_retries: config.max_retries,
+	});
+
 	// If a custom strategy is provided, use it with the retry policy; otherwise, use the retry policy with the default strategy.
-	if let Some(strategy) = custom_strategy {
-		ClientBuilder::new(base_client).with(
-			RetryTransientMiddleware::new_with_policy_and_strategy(retry_policy, strategy),
-		)
+	let builder = if let Some(strategy) = custom_strategy {
+		builder.with(RetryTransientMiddleware::new_with_policy_and_strategy(
+			retry_policy,
+			strategy,
+		))
 	} else {
-		ClientBuilder::new(base_client)
-			.with(RetryTransientMiddleware::new_with_policy(retry_policy))
+		builder.with(RetryTransientMiddleware::new_with_policy(retry_policy))
+	}
+	.with(RetryAttemptMiddleware);
+
+	// The JSON check runs once per attempt, so that malformed responses are retried
+	if validate_json {
+		builder.with(JsonResponseMiddleware).build()
+	} else {
+		builder.build()
+	}
+}
+
+/// Error failing an attempt whose successful response is not valid JSON
+#[derive(Debug)]
+pub struct MalformedResponseError {
+	message: String,
+}
+
+impl std::fmt::Display for MalformedResponseError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Malformed JSON response: {}", self.message)
+	}
+}
+
+impl std::error::Error for MalformedResponseError {}
+
+impl MalformedResponseError {
+	/// Checks whether a request failed because of a malformed response
+	///
+	/// The error is looked for both as returned by an attempt and as wrapped by the retry
+	/// middleware once it gave up.
+	pub fn is_cause_of(error: &reqwest_middleware::Error) -> bool {
+		let reqwest_middleware::Error::Middleware(error) = error else {
+			return false;
+		};
+		error.is::<Self>()
+			|| matches!(
+				error.downcast_ref::<RetryError>(),
+				Some(RetryError::WithRetries { err, .. } | RetryError::Error(err))
+					if Self::is_cause_of(err)
+			)
+	}
+}
+
+/// Middleware running once per attempt, failing successful responses that are not valid JSON
+///
+/// The body is read to be checked, and handed back in a response equivalent to the original.
+struct JsonResponseMiddleware;
+
+#[async_trait::async_trait]
+impl Middleware for JsonResponseMiddleware {
+	async fn handle(
+		&self,
+		req: Request,
+		extensions: &mut Extensions,
+		next: Next<'_>,
+	) -> reqwest_middleware::Result<Response> {
+		let response = next.run(req, extensions).await?;
+		if !response.status().is_success() {
+			return Ok(response);
+		}
+
+		let mut builder = http::Response::builder()
+			.status(response.status())
+			.version(response.version())
+			.url(response.url().clone());
+		if let Some(headers) = builder.headers_mut() {
+			*headers = response.headers().clone();
+		}
+
+		let endpoint = endpoint_origin(response.url());
+		let malformed = |message: String| {
+			tracing::warn!("Malformed response from {}: {}", endpoint, message);
+			reqwest_middleware::Error::middleware(MalformedResponseError { message })
+		};
+		let body = match response.bytes().await {
+			Ok(body) => body,
+			Err(e) => return Err(malformed(format!("Failed to read response body: {}", e))),
+		};
+		if let Err(e) = serde_json::from_slice::<serde::de::IgnoredAny>(&body) {
+			return Err(malformed(e.to_string()));
+		}
+
+		builder
+			.body(body)
+			.map(Response::from)
+			.map_err(reqwest_middleware::Error::middleware)
+	}
+}
+
+/// Number of attempts made for a request, shared between the retry middlewares
+#[derive(Clone, Default)]
+struct RetryAttempts(Arc<AtomicU32>);
+
+/// Returns the origin (scheme, host and port) of a URL
+///
+/// Paths and query strings are left out as they may contain credentials, e.g. in
+/// Slack webhook URLs or RPC URLs with API keys.
+fn endpoint_origin(url: &reqwest::Url) -> String {
+	url.origin().ascii_serialization()
+}
+
+/// Middleware running once per attempt, counting retries of a request
+struct RetryAttemptMiddleware;
+
+#[async_trait::async_trait]
+impl Middleware for RetryAttemptMiddleware {
+	async fn handle(
+		&self,
+		req: Request,
+		extensions: &mut Extensions,
+		next: Next<'_>,
+	) -> reqwest_middleware::Result<Response> {
+		if let Some(RetryAttempts(attempts)) = extensions.get::<RetryAttempts>() {
+			if attempts.fetch_add(1, Ordering::Relaxed) > 0 {
+				RETRY_ATTEMPTS_TOTAL
+					.with_label_values(&[endpoint_origin(req.url()).as_str()])
+					.inc();
+			}
+		}
+		next.run(req, extensions).await
+	}
+}
+
+/// Middleware adding a unique id header to each request, unless already set
+///
+/// The id is logged within the current tracing span, so that the requests received by
+/// providers and webhook receivers can be correlated with the operations sending them.
+struct RequestIdMiddleware {
+	header: HeaderName,
+}
+
+#[async_trait::async_trait]
+impl Middleware for RequestIdMiddleware {
+	async fn handle(
+		&self,
+		mut req: Request,
+		extensions: &mut Extensions,
+		next: Next<'_>,
+	) -> reqwest_middleware::Result<Response> {
+		if !req.headers().contains_key(&self.header) {
+			let request_id = uuid::Uuid::new_v4().to_string();
+			tracing::debug!(
+				request_id = %request_id,
+				endpoint = %endpoint_origin(req.url()),
+				"Sending HTTP request"
+			);
+			if let Ok(value) = HeaderValue::from_str(&request_id) {
+				req.headers_mut().insert(self.header.clone(), value);
+			}
+		}
+		next.run(req, extensions).await
+	}
+}
+
+/// Middleware wrapping all attempts of a request, logging when retries are exhausted
+struct RetryOutcomeMiddleware {
+	max_retries: u32,
+}
+
+#[async_trait::async_trait]
+impl Middleware for RetryOutcomeMiddleware {
+	async fn handle(
+		&self,
+		req: Request,
+		extensions: &mut Extensions,
+		next: Next<'_>,
+	) -> reqwest_middleware::Result<Response> {
+		let endpoint = endpoint_origin(req.url());
+		let attempts = RetryAttempts::default();
+		extensions.insert(attempts.clone());
+		let started = Instant::now();
+
+		let result = next.run(req, extensions).await;
+
+		let attempts = attempts.0.load(Ordering::Relaxed);
+		let last_status = match &result {
+			Ok(response) if response.status().is_success() => return result,
+			Ok(response) => Some(response.status()),
+			Err(error) => error.status(),
+		};
+		if attempts > self.max_retries {
+			tracing::warn!(
+				"Giving up on request to {} after {} attempts in {:?} (last status: {})",
+				endpoint,
+				attempts,
+				started.elapsed(),
+				last_status.map_or_else(|| "none".to_string(), |status| status.to_string())
+			);
+		}
+		result
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::services::blockchain::TransientErrorRetryStrategy;
+	use tracing_test::traced_test;
+
+	fn create_test_client(max_retries: u32) -> ClientWithMiddleware {
+		let config = RetryConfig {
+			max_retries,
+			initial_backoff: Duration::from_millis(1),
+			max_backoff: Duration::from_millis(5),
+			jitter: JitterSetting::None,
+			..Default::default()
+		};
+		create_retryable_http_client(
+			&config,
+			reqwest::Client::new(),
+			Some(TransientErrorRetryStrategy),
+		)
+	}
+
+	#[tokio::test]
+	#[traced_test]
+	async fn test_retries_are_counted_and_logged_when_exhausted() {
+		let mut server = mockito::Server::new_async().await;
+		let mock = server
+			.mock("POST", "/secret-path")
+			.with_status(503)
+			.expect(3)
+			.create_async()
+			.await;
+		let endpoint = server.url();
+		let retries = RETRY_ATTEMPTS_TOTAL.with_label_values(&[endpoint.as_str()]);
+		let retries_before = retries.get();
+
+		let response = create_test_client(2)
+			.post(format!("{}/secret-path", endpoint))
+			.send()
+			.await
+			.unwrap();
+
+		assert_eq!(response.status(), 503);
+		assert_eq!(retries.get() - retries_before, 2);
+		assert!(logs_contain(&format!(
+			"Giving up on request to {} after 3 attempts",
+			endpoint
+		)));
+		assert!(logs_contain("last status: 503 Service Unavailable"));
+		assert!(!logs_contain("secret-path"));
+		mock.assert();
+	}
+
+	#[test]
+	fn test_default_user_agent() {
+		assert_eq!(
+			default_user_agent(),
+			format!("openzeppelin-monitor/{}", env!("CARGO_PKG_VERSION"))
+		);
+	}
+
+	#[tokio::test]
+	#[traced_test]
+	async fn test_request_id_header_is_shared_by_retries() {
+		let mut server = mockito::Server::new_async().await;
+		let mock = server
+			.mock("POST", "/")
+			.match_header(
+				DEFAULT_REQUEST_ID_HEADER,
+				mockito::Matcher::Regex("^[0-9a-f-]{36}$".to_string()),
+			)
+			.with_status(503)
+			.expect(2)
+			.create_async()
+			.await;
+
+		let response = create_test_client(1)
+			.post(server.url())
+			.send()
+			.await
+			.unwrap();
+
+		assert_eq!(response.status(), 503);
+		mock.assert();
+		// The id is logged once for both attempts
+		logs_assert(|lines: &[&str]| {
+			match lines
+				.iter()
+				.filter(|line| line.contains("Sending HTTP request"))
+				.count()
+			{
+				1 => Ok(()),
+				count => Err(format!("expected 1 request id log, got {}", count)),
+			}
+		});
+	}
+
+	#[tokio::test]
+	async fn test_request_id_header_is_not_overridden() {
+		let mut server = mockito::Server::new_async().await;
+		let mock = server
+			.mock("GET", "/")
+			.match_header(DEFAULT_REQUEST_ID_HEADER, "custom-id")
+			.with_status(200)
+			.create_async()
+			.await;
+
+		let response = create_test_client(0)
+			.get(server.url())
+			.header(DEFAULT_REQUEST_ID_HEADER, "custom-id")
+			.send()
+			.await
+			.unwrap();
+
+		assert!(response.status().is_success());
+		mock.assert();
+	}
+
+	#[tokio::test]
+	#[traced_test]
+	async fn test_successful_request_is_not_counted() {
+		let mut server = mockito::Server::new_async().await;
+		let mock = server
+			.mock("GET", "/")
+			.with_status(200)
+			.create_async()
+			.await;
+		let endpoint = server.url();
+		let retries = RETRY_ATTEMPTS_TOTAL.with_label_values(&[endpoint.as_str()]);
+		let retries_before = retries.get();
+
+		let response = create_test_client(2).get(&endpoint).send().await.unwrap();
+
+		assert!(response.status().is_success());
+		assert_eq!(retries.get(), retries_before);
+		assert!(!logs_contain("Giving up"));
+		mock.assert();
+	}
+
+	#[tokio::test]
+	async fn test_json_client_retries_malformed_responses() {
+		let config = RetryConfig {
+			max_retries: 2,
+			initial_backoff: Duration::from_millis(1),
+			max_backoff: Duration::from_millis(5),
+			jitter: JitterSetting::None,
+			..Default::default()
+		};
+		let client = create_retryable_json_http_client(
+			&config,
+			reqwest::Client::new(),
+			Some(TransientErrorRetryStrategy),
+		);
+
+		let mut server = mockito::Server::new_async().await;
+		let malformed = server
+			.mock("POST", "/malformed")
+			.with_status(200)
+			.with_body("<html>502 Bad Gateway</html>")
+			.expect(3)
+			.create_async()
+			.await;
+		let error = client
+			.post(format!("{}/malformed", server.url()))
+			.send()
+			.await
+			.unwrap_err();
+		assert!(MalformedResponseError::is_cause_of(&error));
+		malformed.assert();
+
+		// Valid responses are handed back unchanged, and failed ones are not checked
+		let valid = server
+			.mock("POST", "/valid")
+			.with_status(200)
+			.with_header("x-custom", "value")
+			.with_body(r#"{"result": "0x1"}"#)
+			.expect(1)
+			.create_async()
+			.await;
+		let response = client
+			.post(format!("{}/valid", server.url()))
+			.send()
+			.await
+			.unwrap();
+		assert_eq!(response.headers()["x-custom"], "value");
+		assert_eq!(response.text().await.unwrap(), r#"{"result": "0x1"}"#);
+		valid.assert();
+
+		let failed = server
+			.mock("POST", "/failed")
+			.with_status(400)
+			.with_body("Bad Request")
+			.expect(1)
+			.create_async()
+			.await;
+		let response = client
+			.post(format!("{}/failed", server.url()))
+			.send()
+			.await
+			.unwrap();
+		assert_eq!(response.status(), 400);
+		failed.assert();
 	}
-	.build()
 }
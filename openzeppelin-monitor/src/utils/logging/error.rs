@@ -5,6 +5,7 @@
 //! such as timestamps, trace IDs, and custom metadata.
 
 use chrono::Utc;
+use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, fmt};
 use uuid::Uuid;
 
@@ -88,11 +89,20 @@ impl ErrorContext {
 		let error_context = Self::new(message, source, metadata);
 
 		// Log the error
-		log_error(&error_context);
+		log_error(&error_context, None);
 
 		error_context
 	}
 
+	/// Logs the error context, tagging the log entry with the given error code.
+	///
+	/// # Arguments
+	///
+	/// * `code` - The error code classifying this error
+	pub fn log_with_code(&self, code: ErrorCode) {
+		log_error(self, Some(code));
+	}
+
 	/// Adds a single key-value metadata pair to the error context.
 	///
 	/// This method creates the metadata HashMap if it doesn't already exist.
@@ -164,6 +174,202 @@ impl std::error::Error for ErrorContext {
 unsafe impl Send for ErrorContext {}
 unsafe impl Sync for ErrorContext {}
 
+/// A stable, machine-readable classification of an error.
+///
+/// Codes are intended for alerting and dashboards, so their serialized names
+/// (e.g. `HTTP_5XX`, `TIMEOUT`) must not change once released.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+	/// The remote endpoint rejected the request with a 4xx status
+	#[serde(rename = "HTTP_4XX")]
+	Http4xx,
+	/// The remote endpoint failed with a 5xx status
+	#[serde(rename = "HTTP_5XX")]
+	Http5xx,
+	/// Authentication or authorization was rejected
+	Auth,
+	/// The operation did not complete in time
+	Timeout,
+	/// The remote endpoint could not be reached
+	Network,
+	/// Invalid or missing configuration
+	Config,
+	/// A user-provided script failed
+	Script,
+	/// A pooled client, circuit breaker or concurrency permit could not be obtained
+	ClientPool,
+	/// A referenced resource does not exist
+	NotFound,
+	/// An unexpected internal failure
+	Internal,
+	/// The failure could not be classified
+	Unknown,
+}
+
+impl ErrorCode {
+	/// Returns the stable string representation of the code
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Http4xx => "HTTP_4XX",
+			Self::Http5xx => "HTTP_5XX",
+			Self::Auth => "AUTH",
+			Self::Timeout => "TIMEOUT",
+			Self::Network => "NETWORK",
+			Self::Config => "CONFIG",
+			Self::Script => "SCRIPT",
+			Self::ClientPool => "CLIENT_POOL",
+			Self::NotFound => "NOT_FOUND",
+			Self::Internal => "INTERNAL",
+			Self::Unknown => "UNKNOWN",
+		}
+	}
+
+	/// Maps an HTTP status code to an error code
+	///
+	/// Returns `None` for statuses that do not indicate a failure.
+	pub fn from_http_status(status: u16) -> Option<Self> {
+		match status {
+			401 | 403 => Some(Self::Auth),
+			408 => Some(Self::Timeout),
+			400..=499 => Some(Self::Http4xx),
+			500..=599 => Some(Self::Http5xx),
+			_ => None,
+		}
+	}
+
+	/// Maps a gRPC status code to an error code
+	///
+	/// Unreachable services and expired deadlines are network errors and timeouts, other
+	/// codes follow their usual HTTP equivalent. Returns `None` for `Ok`.
+	pub fn from_grpc_status(code: tonic::Code) -> Option<Self> {
+		use tonic::Code;
+		match code {
+			Code::Ok => None,
+			Code::DeadlineExceeded => Some(Self::Timeout),
+			Code::Unavailable => Some(Self::Network),
+			Code::NotFound => Some(Self::NotFound),
+			Code::Unauthenticated | Code::PermissionDenied => Some(Self::Auth),
+			Code::Cancelled
+			| Code::InvalidArgument
+			| Code::AlreadyExists
+			| Code::ResourceExhausted
+			| Code::FailedPrecondition
+			| Code::Aborted
+			| Code::OutOfRange => Some(Self::Http4xx),
+			Code::Unknown | Code::Unimplemented | Code::Internal | Code::DataLoss => {
+				Some(Self::Http5xx)
+			}
+		}
+	}
+
+	/// Classifies an error by walking its source chain
+	///
+	/// Recognizes errors from the HTTP, SMTP, gRPC and async runtimes as well as the
+	/// application's own coded errors. Returns `None` if nothing in the chain
+	/// can be classified.
+	pub fn from_error(err: &(dyn std::error::Error + 'static)) -> Option<Self> {
+		const MAX_DEPTH: usize = 8;
+		let mut current = Some(err);
+		let mut depth = 0;
+
+		while let Some(err) = current {
+			if depth > MAX_DEPTH {
+				break;
+			}
+			depth += 1;
+
+			if let Some(e) = err.downcast_ref::<crate::services::notification::NotificationError>()
+			{
+				return Some(e.code());
+			}
+			if let Some(e) = err.downcast_ref::<crate::services::trigger::TriggerError>() {
+				return Some(e.code());
+			}
+			if let Some(e) = err.downcast_ref::<reqwest_middleware::Error>() {
+				match e {
+					reqwest_middleware::Error::Reqwest(e) => return Some(Self::from_reqwest(e)),
+					reqwest_middleware::Error::Middleware(e) => {
+						current = Some(e.as_ref());
+						continue;
+					}
+				}
+			}
+			if let Some(e) = err.downcast_ref::<reqwest::Error>() {
+				return Some(Self::from_reqwest(e));
+			}
+			if let Some(e) = err.downcast_ref::<lettre::transport::smtp::Error>() {
+				if e.is_timeout() {
+					return Some(Self::Timeout);
+				}
+				// 530 (authentication required), 534 and 535 (credentials rejected)
+				if let Some(code) = e.status() {
+					if matches!(code.to_string().as_str(), "530" | "534" | "535") {
+						return Some(Self::Auth);
+					}
+				}
+			}
+			if let Some(status) = err.downcast_ref::<tonic::Status>() {
+				return Self::from_grpc_status(status.code());
+			}
+			if err.is::<tonic::transport::Error>() {
+				return Some(Self::Network);
+			}
+			if err.is::<tokio::time::error::Elapsed>() {
+				return Some(Self::Timeout);
+			}
+			if let Some(e) = err.downcast_ref::<std::io::Error>() {
+				match e.kind() {
+					std::io::ErrorKind::TimedOut => return Some(Self::Timeout),
+					std::io::ErrorKind::ConnectionRefused
+					| std::io::ErrorKind::ConnectionReset
+					| std::io::ErrorKind::ConnectionAborted => return Some(Self::Network),
+					_ => {}
+				}
+			}
+
+			// Application errors keep their cause inside an `ErrorContext` rather than
+			// exposing it through `source()`, so unwrap those explicitly.
+			current = match err.downcast_ref::<ErrorContext>() {
+				Some(ctx) => ctx
+					.source
+					.as_ref()
+					.map(|e| e.as_ref() as &(dyn std::error::Error + 'static)),
+				None => match err.downcast_ref::<crate::services::filter::FilterError>() {
+					Some(crate::services::filter::FilterError::BlockTypeMismatch(ctx))
+					| Some(crate::services::filter::FilterError::NetworkError(ctx))
+					| Some(crate::services::filter::FilterError::InternalError(ctx)) => ctx
+						.source
+						.as_ref()
+						.map(|e| e.as_ref() as &(dyn std::error::Error + 'static)),
+					_ => err.source(),
+				},
+			};
+		}
+
+		None
+	}
+
+	fn from_reqwest(err: &reqwest::Error) -> Self {
+		if err.is_timeout() {
+			Self::Timeout
+		} else if let Some(code) = err
+			.status()
+			.and_then(|s| Self::from_http_status(s.as_u16()))
+		{
+			code
+		} else {
+			Self::Network
+		}
+	}
+}
+
+impl fmt::Display for ErrorCode {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
+
 /// A trait for errors that can provide a trace ID
 pub trait TraceableError: std::error::Error + Send + Sync {
 	/// Returns the trace ID for this error
@@ -272,12 +478,14 @@ pub fn metadata_to_fields(metadata: &Option<HashMap<String, String>>) -> Vec<(&s
 }
 
 /// Log the error with structured fields
-fn log_error(error: &ErrorContext) {
+fn log_error(error: &ErrorContext, code: Option<ErrorCode>) {
+	let error_code = code.map(|c| c.as_str());
 	if let Some(err) = &error.source {
 		tracing::error!(
 			message = error.format_with_metadata(),
 			trace_id = %error.trace_id,
 			timestamp = %error.timestamp,
+			error_code,
 			error.chain = %format_error_chain(&**err),
 			"Error occurred"
 		);
@@ -286,6 +494,7 @@ fn log_error(error: &ErrorContext) {
 			message = error.format_with_metadata(),
 			trace_id = %error.trace_id,
 			timestamp = %error.timestamp,
+			error_code,
 			"Error occurred"
 		);
 	}
@@ -390,7 +599,7 @@ mod tests {
 			let error = ErrorContext::new("Test log error", None, None)
 				.with_metadata("test_key", "test_value");
 
-			log_error(&error);
+			log_error(&error, None);
 
 			// Verify log contains our error information
 			assert!(logs_contain("Test log error"));
@@ -402,7 +611,7 @@ mod tests {
 			let error_with_source =
 				ErrorContext::new("Parent error", Some(Box::new(source_error)), None);
 
-			log_error(&error_with_source);
+			log_error(&error_with_source, None);
 
 			assert!(logs_contain("Parent error"));
 			assert!(logs_contain("Source error"));
@@ -579,4 +788,61 @@ mod tests {
 			"Trace ID should propagate through the error chain"
 		);
 	}
+
+	#[test]
+	fn test_error_code_serialization() {
+		assert_eq!(ErrorCode::Http5xx.as_str(), "HTTP_5XX");
+		assert_eq!(ErrorCode::NotFound.to_string(), "NOT_FOUND");
+		assert_eq!(
+			serde_json::to_string(&ErrorCode::Http4xx).unwrap(),
+			"\"HTTP_4XX\""
+		);
+		assert_eq!(
+			serde_json::to_string(&ErrorCode::Timeout).unwrap(),
+			"\"TIMEOUT\""
+		);
+		assert_eq!(
+			serde_json::from_str::<ErrorCode>("\"HTTP_5XX\"").unwrap(),
+			ErrorCode::Http5xx
+		);
+		assert_eq!(ErrorCode::ClientPool.as_str(), "CLIENT_POOL");
+		assert_eq!(
+			serde_json::to_string(&ErrorCode::ClientPool).unwrap(),
+			"\"CLIENT_POOL\""
+		);
+	}
+
+	#[test]
+	fn test_error_code_from_http_status() {
+		assert_eq!(ErrorCode::from_http_status(200), None);
+		assert_eq!(ErrorCode::from_http_status(401), Some(ErrorCode::Auth));
+		assert_eq!(ErrorCode::from_http_status(429), Some(ErrorCode::Http4xx));
+		assert_eq!(ErrorCode::from_http_status(502), Some(ErrorCode::Http5xx));
+	}
+
+	#[test]
+	fn test_error_code_from_grpc_status() {
+		assert_eq!(ErrorCode::from_grpc_status(tonic::Code::Ok), None);
+		assert_eq!(
+			ErrorCode::from_grpc_status(tonic::Code::Unauthenticated),
+			Some(ErrorCode::Auth)
+		);
+		assert_eq!(
+			ErrorCode::from_grpc_status(tonic::Code::Unavailable),
+			Some(ErrorCode::Network)
+		);
+		assert_eq!(
+			ErrorCode::from_grpc_status(tonic::Code::InvalidArgument),
+			Some(ErrorCode::Http4xx)
+		);
+
+		let status = tonic::Status::deadline_exceeded("too slow");
+		assert_eq!(ErrorCode::from_error(&status), Some(ErrorCode::Timeout));
+	}
+
+	#[test]
+	fn test_error_code_from_unclassified_error() {
+		let error = io::Error::new(io::ErrorKind::Other, "something else");
+		assert_eq!(ErrorCode::from_error(&error), None);
+	}
 }
@@ -1,21 +1,23 @@
 //! ## Sets up logging by reading configuration from environment variables.
 //!
 //! Environment variables used:
-//! - LOG_MODE: "stdout" (default) or "file"
+//! - LOG_MODE: "stdout" (default), "stderr" or "file"
 //! - LOG_LEVEL: log level ("trace", "debug", "info", "warn", "error"); default is "info"
 //! - LOG_DATA_DIR: directory for log files; default is "logs/"
 //! - LOG_MAX_SIZE: maximum size of log files in bytes; default is 1GB
+//! - LOG_RETENTION_DAYS: number of days to keep rolled log files; unset keeps them forever
 //! - IN_DOCKER: "true" if running in Docker; default is "false"
 
 pub mod error;
 
-use chrono::Utc;
+use chrono::{Days, NaiveDate, Utc};
 use std::{
 	env,
-	fs::{create_dir_all, metadata},
-	path::Path,
+	fs::{create_dir_all, metadata, read_dir, remove_file, symlink_metadata},
+	path::{Path, PathBuf},
+	time::Duration,
 };
-use tracing::info;
+use tracing::{info, warn};
 use tracing_appender;
 use tracing_subscriber::{filter::EnvFilter, fmt, prelude::*};
 
@@ -104,6 +106,106 @@ pub fn space_based_rolling(
 	final_path
 }
 
+/// How often rolled log files are checked against the retention period
+const LOG_CLEANUP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Parses the date from a rolled log file name produced by `compute_rolled_file_path`.
+///
+/// Only names of the exact form `<stem>-YYYY-MM-DD.<index>.log` are recognised, where
+/// `<stem>` is the base log file name without its `.log` suffix. Any other file yields `None`.
+pub fn parse_rolled_file_date(file_name: &str, base_file_name: &str) -> Option<NaiveDate> {
+	let stem = base_file_name
+		.strip_suffix(".log")
+		.unwrap_or(base_file_name);
+	let rest = file_name
+		.strip_prefix(stem)?
+		.strip_prefix('-')?
+		.strip_suffix(".log")?;
+	let (date_str, index) = rest.rsplit_once('.')?;
+
+	if index.is_empty() || !index.chars().all(|c| c.is_ascii_digit()) {
+		return None;
+	}
+	// Require the zero-padded form written by `compute_rolled_file_path`
+	if date_str.len() != 10 {
+		return None;
+	}
+
+	NaiveDate::parse_from_str(date_str, "%Y-%m-%d").ok()
+}
+
+/// Removes rolled log files in `log_dir` dated more than `retention_days` days before `today`.
+///
+/// Only regular files whose names match the rolled naming scheme of `base_file_name` are
+/// considered, and `active_file` (the file currently being written to) is never removed.
+/// Returns the paths of the removed files.
+pub fn cleanup_rolled_log_files(
+	log_dir: &Path,
+	base_file_name: &str,
+	retention_days: u64,
+	today: NaiveDate,
+	active_file: Option<&Path>,
+) -> std::io::Result<Vec<PathBuf>> {
+	let Some(cutoff) = today.checked_sub_days(Days::new(retention_days)) else {
+		return Ok(Vec::new());
+	};
+
+	let mut removed = Vec::new();
+	for entry in read_dir(log_dir)? {
+		let path = entry?.path();
+		let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+			continue;
+		};
+		let Some(date) = parse_rolled_file_date(file_name, base_file_name) else {
+			continue;
+		};
+		if date >= cutoff {
+			continue;
+		}
+		if active_file.is_some_and(|active| active.file_name() == path.file_name()) {
+			continue;
+		}
+		// Do not follow symlinks or touch directories that happen to match the pattern
+		if !symlink_metadata(&path).is_ok_and(|meta| meta.is_file()) {
+			continue;
+		}
+
+		match remove_file(&path) {
+			Ok(()) => removed.push(path),
+			Err(e) => warn!(
+				"Failed to remove expired log file {}: {}",
+				path.display(),
+				e
+			),
+		}
+	}
+
+	Ok(removed)
+}
+
+/// Runs `cleanup_rolled_log_files` for the current date and logs the outcome
+fn run_log_cleanup(log_dir: &Path, base_file_name: &str, retention_days: u64, active_file: &Path) {
+	let today = Utc::now().date_naive();
+	match cleanup_rolled_log_files(
+		log_dir,
+		base_file_name,
+		retention_days,
+		today,
+		Some(active_file),
+	) {
+		Ok(removed) => {
+			for path in removed {
+				info!("Removed expired log file: {}", path.display());
+			}
+		}
+		Err(e) => warn!(
+			"Failed to clean up log files in {}: {}",
+			log_dir.display(),
+			e
+		),
+	}
+}
+
 /// Creates a log format with configurable ANSI support
 fn create_log_format(with_ansi: bool) -> fmt::format::Format<fmt::format::Compact> {
 	fmt::format()
@@ -193,6 +295,32 @@ pub fn setup_logging() -> Result<(), Box<dyn std::error::Error>> {
 					.fmt_fields(fmt::format::PrettyFields::new()),
 			)
 			.init();
+
+		// Retention: remove expired rolled files now and then periodically in the background
+		if let Some(retention_days) = parse_log_retention_days() {
+			let log_dir = PathBuf::from(&log_dir);
+			let active_file = PathBuf::from(&final_path);
+			info!(
+				"Removing rolled log files older than {} days from {}",
+				retention_days,
+				log_dir.display()
+			);
+			run_log_cleanup(&log_dir, "monitor.log", retention_days, &active_file);
+			std::thread::spawn(move || loop {
+				std::thread::sleep(LOG_CLEANUP_INTERVAL);
+				run_log_cleanup(&log_dir, "monitor.log", retention_days, &active_file);
+			});
+		}
+	} else if log_mode.to_lowercase() == "stderr" {
+		// Initialize the subscriber with stderr, keeping stdout free for command output
+		subscriber
+			.with(
+				fmt::layer()
+					.event_format(format)
+					.with_writer(std::io::stderr)
+					.fmt_fields(fmt::format::PrettyFields::new()),
+			)
+			.init();
 	} else {
 		// Initialize the subscriber with stdout
 		subscriber
@@ -217,6 +345,13 @@ fn parse_log_max_size() -> u64 {
 		.unwrap_or(1_073_741_824)
 }
 
+fn parse_log_retention_days() -> Option<u64> {
+	env::var("LOG_RETENTION_DAYS").ok().map(|s| {
+		s.parse::<u64>()
+			.expect("LOG_RETENTION_DAYS must be a valid u64 if set")
+	})
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -271,6 +406,89 @@ mod tests {
 		assert_eq!(result, initial_path);
 	}
 
+	#[test]
+	fn test_parse_rolled_file_date() {
+		let date = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+		assert_eq!(
+			parse_rolled_file_date("monitor-2023-01-01.1.log", "monitor.log"),
+			Some(date)
+		);
+		assert_eq!(
+			parse_rolled_file_date("monitor-2023-01-01.12.log", "monitor.log"),
+			Some(date)
+		);
+
+		// Unrelated or malformed names are ignored
+		assert_eq!(parse_rolled_file_date("monitor.log", "monitor.log"), None);
+		assert_eq!(
+			parse_rolled_file_date("other-2023-01-01.1.log", "monitor.log"),
+			None
+		);
+		assert_eq!(
+			parse_rolled_file_date("monitor-2023-01-01.log", "monitor.log"),
+			None
+		);
+		assert_eq!(
+			parse_rolled_file_date("monitor-2023-01-01.x.log", "monitor.log"),
+			None
+		);
+		assert_eq!(
+			parse_rolled_file_date("monitor-2023-1-1.1.log", "monitor.log"),
+			None
+		);
+		assert_eq!(
+			parse_rolled_file_date("monitor-2023-02-30.1.log", "monitor.log"),
+			None
+		);
+		assert_eq!(
+			parse_rolled_file_date("monitor-2023-01-01.1.log.gz", "monitor.log"),
+			None
+		);
+	}
+
+	#[test]
+	fn test_cleanup_rolled_log_files() {
+		let dir = tempdir().expect("Failed to create temp directory");
+		let today = NaiveDate::from_ymd_opt(2023, 1, 10).unwrap();
+
+		let create = |name: &str| {
+			let path = dir.path().join(name);
+			File::create(&path).expect("Failed to create test file");
+			path
+		};
+
+		let expired = [
+			create("monitor-2023-01-01.1.log"),
+			create("monitor-2023-01-01.2.log"),
+			create("monitor-2023-01-02.1.log"),
+		];
+		let retained = [
+			create("monitor-2023-01-03.1.log"),
+			create("monitor-2023-01-10.1.log"),
+			create("monitor.log"),
+			create("other-2023-01-01.1.log"),
+			create("monitor-2023-01-01.1.log.bak"),
+			create("notes.txt"),
+		];
+		// Directories matching the naming scheme are left alone
+		let matching_dir = dir.path().join("monitor-2022-12-01.1.log");
+		create_dir_all(&matching_dir).unwrap();
+		// The active file is never removed, even if it has expired
+		let active = create("monitor-2022-12-31.1.log");
+
+		let mut removed =
+			cleanup_rolled_log_files(dir.path(), "monitor.log", 7, today, Some(&active)).unwrap();
+		removed.sort();
+
+		assert_eq!(removed, expired.to_vec());
+		for path in &expired {
+			assert!(!path.exists(), "{} should be removed", path.display());
+		}
+		for path in retained.iter().chain([&active, &matching_dir]) {
+			assert!(path.exists(), "{} should be kept", path.display());
+		}
+	}
+
 	// This test checks if the LOG_MAX_SIZE environment variable is set to a valid u64 value.
 	#[test]
 	#[should_panic(expected = "LOG_MAX_SIZE must be a valid u64 if set")]
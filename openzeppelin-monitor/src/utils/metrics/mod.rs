@@ -4,9 +4,25 @@
 //! - Defines specific metrics for the application.
 
 pub mod server;
+pub mod system;
 use lazy_static::lazy_static;
-use prometheus::{Encoder, Gauge, GaugeVec, Opts, Registry, TextEncoder};
-use sysinfo::{Disks, System};
+use prometheus::{
+	core::{Collector, Desc},
+	proto::{self, MetricFamily, MetricType},
+	Encoder, Gauge, GaugeVec, IntCounterVec, Opts, Registry, TextEncoder,
+};
+use std::{
+	collections::{BTreeMap, HashMap},
+	sync::{Arc, Mutex},
+};
+
+use crate::models::{Monitor, Trigger};
+use system::{default_system_metrics_provider, SystemMetricsProvider};
+
+/// Names of the labels set by the monitor on every `notifications_total` series.
+///
+/// Monitor and trigger labels cannot use these names.
+pub const NOTIFICATION_METRIC_LABELS: &[&str] = &["monitor", "trigger", "status"];
 
 lazy_static! {
 	/// Global Prometheus registry.
@@ -15,6 +31,10 @@ lazy_static! {
 	/// to gather metrics for exposure via the metrics endpoint.
 	pub static ref REGISTRY: Registry = Registry::new();
 
+	/// Provider of the system metrics, selected based on the `IN_DOCKER` environment variable.
+	static ref SYSTEM_METRICS_PROVIDER: Box<dyn SystemMetricsProvider> =
+		default_system_metrics_provider();
+
 	/// Gauge for CPU usage percentage.
 	///
 	/// Tracks the current CPU usage as a percentage (0-100) across all cores.
@@ -134,6 +154,281 @@ lazy_static! {
 		REGISTRY.register(Box::new(gauge.clone())).unwrap();
 		gauge
 	};
+
+	/// Counter Vector for HTTP request retries.
+	///
+	/// Counts the retries made for RPC and webhook requests, with the endpoint origin
+	/// (scheme, host and port) as a label. The first attempt of a request is not counted.
+	pub static ref RETRY_ATTEMPTS_TOTAL: IntCounterVec = {
+		let counter = IntCounterVec::new(
+			Opts::new("retry_attempts_total", "Total number of retried HTTP requests"),
+			&["endpoint"]
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Counter Vector for block processing timeouts.
+	///
+	/// Counts the blocks whose processing exceeded the network's `max_block_processing_ms`,
+	/// with the network slug as a label. Every timed out attempt is counted.
+	pub static ref BLOCK_PROCESSING_TIMEOUTS_TOTAL: IntCounterVec = {
+		let counter = IntCounterVec::new(
+			Opts::new(
+				"block_processing_timeouts_total",
+				"Total number of block processing attempts cancelled by the watchdog"
+			),
+			&["network"]
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Counter Vector for transactions with too many logs.
+	///
+	/// Counts the transactions emitting more logs than the network's `max_logs_per_tx`, whose
+	/// remaining logs were skipped, with the network slug as a label.
+	pub static ref TRANSACTION_LOGS_TRUNCATED_TOTAL: IntCounterVec = {
+		let counter = IntCounterVec::new(
+			Opts::new(
+				"transaction_logs_truncated_total",
+				"Total number of transactions whose logs exceeded the per-transaction limit"
+			),
+			&["network"]
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Counter Vector for throttled notifications.
+	///
+	/// Counts the matches whose notifications were suppressed by the monitor's
+	/// `notification_throttle`, with the monitor name as a label.
+	pub static ref NOTIFICATIONS_THROTTLED_TOTAL: IntCounterVec = {
+		let counter = IntCounterVec::new(
+			Opts::new(
+				"notifications_throttled_total",
+				"Total number of matches whose notifications were throttled"
+			),
+			&["monitor"]
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Counter Vector for notifications suppressed while paused.
+	///
+	/// Counts the matches whose notifications were suppressed because notifications were
+	/// paused through the admin endpoints, with the monitor name as a label.
+	pub static ref NOTIFICATIONS_PAUSED_TOTAL: IntCounterVec = {
+		let counter = IntCounterVec::new(
+			Opts::new(
+				"notifications_paused_total",
+				"Total number of matches whose notifications were suppressed while paused"
+			),
+			&["monitor"]
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Gauge Vector for the time elapsed since the last match of monitors.
+	///
+	/// Tracks the seconds elapsed since the last match of the monitors with an
+	/// `expected_match_window_secs`, or since the service started if they have not matched yet,
+	/// with the monitor name as a label.
+	pub static ref MONITOR_LAST_MATCH_AGE_SECONDS: GaugeVec = {
+		let gauge = GaugeVec::new(
+			Opts::new(
+				"monitor_last_match_age_seconds",
+				"Seconds elapsed since the last match of a monitor"
+			),
+			&["monitor"]
+		).unwrap();
+		REGISTRY.register(Box::new(gauge.clone())).unwrap();
+		gauge
+	};
+
+	/// Counter Vector for matches suppressed as duplicates.
+	///
+	/// Counts the matches suppressed by the monitor's `match_dedup` because an identical
+	/// match was notified in a recent block, with the monitor name as a label.
+	pub static ref MATCHES_DEDUPLICATED_TOTAL: IntCounterVec = {
+		let counter = IntCounterVec::new(
+			Opts::new(
+				"matches_deduplicated_total",
+				"Total number of matches suppressed as duplicates of a recent match"
+			),
+			&["monitor"]
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Counter Vector for blocks dropped from the trigger queue.
+	///
+	/// Counts the processed blocks dropped from the full trigger queue with the `drop_oldest`
+	/// backpressure policy, with the network slug as a label.
+	pub static ref TRIGGER_QUEUE_DROPPED_BLOCKS_TOTAL: IntCounterVec = {
+		let counter = IntCounterVec::new(
+			Opts::new(
+				"trigger_queue_dropped_blocks_total",
+				"Total number of processed blocks dropped from the full trigger queue"
+			),
+			&["network"]
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Counter Vector for truncated notification messages.
+	///
+	/// Counts the notification messages truncated to the maximum message length of their
+	/// channel, with the channel (`slack`, `discord` or `telegram`) as a label.
+	pub static ref NOTIFICATION_MESSAGES_TRUNCATED_TOTAL: IntCounterVec = {
+		let counter = IntCounterVec::new(
+			Opts::new(
+				"notification_messages_truncated_total",
+				"Total number of notification messages truncated to the channel's maximum length"
+			),
+			&["channel"]
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Counter Vector for blocks fetched to catch up.
+	///
+	/// Counts the blocks fetched after the last processed block when catching up, with the
+	/// network slug as a label. Backfills are capped by the network's `max_past_blocks_limit`.
+	pub static ref BACKFILL_BLOCKS_TOTAL: IntCounterVec = {
+		let counter = IntCounterVec::new(
+			Opts::new(
+				"backfill_blocks_total",
+				"Total number of blocks fetched to catch up from the last processed block"
+			),
+			&["network"]
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Counter for sent notifications.
+	///
+	/// Counts notifications with the monitor name, trigger name and outcome (`success` or
+	/// `failure`) as labels, along with the labels configured on the monitor and the trigger.
+	pub static ref NOTIFICATIONS_TOTAL: LabeledCounter = {
+		let counter = LabeledCounter::new(
+			"notifications_total",
+			"Total number of notifications sent by triggers"
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+}
+
+/// Counter whose series each carry their own set of labels.
+///
+/// Unlike `IntCounterVec`, label names are not fixed when the counter is created, which allows
+/// user-defined labels to be attached to the series.
+#[derive(Clone)]
+pub struct LabeledCounter {
+	desc: Desc,
+	values: Arc<Mutex<HashMap<BTreeMap<String, String>, u64>>>,
+}
+
+impl LabeledCounter {
+	/// Creates a new counter with the given name and help text
+	pub fn new(name: &str, help: &str) -> prometheus::Result<Self> {
+		Ok(Self {
+			desc: Desc::new(name.to_string(), help.to_string(), vec![], HashMap::new())?,
+			values: Arc::new(Mutex::new(HashMap::new())),
+		})
+	}
+
+	/// Increments the series with the given labels
+	pub fn inc(&self, labels: BTreeMap<String, String>) {
+		let mut values = self.values.lock().unwrap_or_else(|e| e.into_inner());
+		*values.entry(labels).or_insert(0) += 1;
+	}
+
+	/// Returns the value of the series with the given labels
+	pub fn get(&self, labels: &BTreeMap<String, String>) -> u64 {
+		let values = self.values.lock().unwrap_or_else(|e| e.into_inner());
+		values.get(labels).copied().unwrap_or(0)
+	}
+
+	/// Removes all series
+	pub fn reset(&self) {
+		self.values
+			.lock()
+			.unwrap_or_else(|e| e.into_inner())
+			.clear();
+	}
+}
+
+impl Collector for LabeledCounter {
+	fn desc(&self) -> Vec<&Desc> {
+		vec![&self.desc]
+	}
+
+	fn collect(&self) -> Vec<MetricFamily> {
+		let values = self.values.lock().unwrap_or_else(|e| e.into_inner());
+		let metrics = values
+			.iter()
+			.map(|(labels, value)| {
+				let label_pairs = labels
+					.iter()
+					.map(|(name, value)| {
+						let mut pair = proto::LabelPair::default();
+						pair.set_name(name.clone());
+						pair.set_value(value.clone());
+						pair
+					})
+					.collect();
+				let mut counter = proto::Counter::default();
+				counter.set_value(*value as f64);
+
+				let mut metric = proto::Metric::from_label(label_pairs);
+				metric.set_counter(counter);
+				metric
+			})
+			.collect();
+
+		let mut family = MetricFamily::default();
+		family.set_name(self.desc.fq_name.clone());
+		family.set_help(self.desc.help.clone());
+		family.set_field_type(MetricType::COUNTER);
+		family.set_metric(metrics);
+		vec![family]
+	}
+}
+
+/// Checks whether a name can be used as a Prometheus label name
+pub fn is_valid_label_name(name: &str) -> bool {
+	let mut chars = name.chars();
+	matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+		&& chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+		&& !name.starts_with("__")
+}
+
+/// Records a notification sent by a trigger for a monitor.
+///
+/// Trigger labels take precedence over monitor labels with the same name.
+pub fn record_notification(monitor: &Monitor, trigger: &Trigger, success: bool) {
+	let mut labels: BTreeMap<String, String> = monitor
+		.labels
+		.iter()
+		.chain(trigger.labels.iter())
+		.map(|(name, value)| (name.clone(), value.clone()))
+		.collect();
+	labels.insert("monitor".to_string(), monitor.name.clone());
+	labels.insert("trigger".to_string(), trigger.name.clone());
+	labels.insert(
+		"status".to_string(),
+		if success { "success" } else { "failure" }.to_string(),
+	);
+	NOTIFICATIONS_TOTAL.inc(labels);
 }
 
 /// Gather all metrics and encode into the provided format.
@@ -147,49 +442,38 @@ pub fn gather_metrics() -> Result<Vec<u8>, Box<dyn std::error::Error>> {
 
 /// Updates the system metrics for CPU and memory usage.
 pub fn update_system_metrics() {
-	let mut sys = System::new_all();
-	sys.refresh_all();
-
-	// Overall CPU usage.
-	let cpu_usage = sys.global_cpu_usage();
-	CPU_USAGE.set(cpu_usage as f64);
+	update_system_metrics_with(SYSTEM_METRICS_PROVIDER.as_ref());
+}
 
-	// Total memory (in bytes).
-	let total_memory = sys.total_memory();
-	TOTAL_MEMORY.set(total_memory as f64);
+/// Updates the system metrics for CPU and memory usage from the given provider.
+pub fn update_system_metrics_with(provider: &dyn SystemMetricsProvider) {
+	let metrics = provider.collect();
 
-	// Available memory (in bytes).
-	let available_memory = sys.available_memory();
-	AVAILABLE_MEMORY.set(available_memory as f64);
+	// Overall CPU usage.
+	CPU_USAGE.set(metrics.cpu_usage);
 
-	// Used memory (in bytes).
-	let memory_usage = sys.used_memory();
-	MEMORY_USAGE.set(memory_usage as f64);
+	// Total, available and used memory (in bytes).
+	TOTAL_MEMORY.set(metrics.total_memory as f64);
+	AVAILABLE_MEMORY.set(metrics.available_memory as f64);
+	MEMORY_USAGE.set(metrics.used_memory as f64);
 
 	// Calculate memory usage percentage
-	let memory_percentage = if total_memory > 0 {
-		(memory_usage as f64 / total_memory as f64) * 100.0
+	let memory_percentage = if metrics.total_memory > 0 {
+		(metrics.used_memory as f64 / metrics.total_memory as f64) * 100.0
 	} else {
 		0.0
 	};
 	MEMORY_USAGE_PERCENT.set(memory_percentage);
 
-	// Calculate disk usage:
-	// Sum total space and available space across all disks.
-	let disks = Disks::new_with_refreshed_list();
-	let mut total_disk_space: u64 = 0;
-	let mut total_disk_available: u64 = 0;
-	for disk in disks.list() {
-		total_disk_space += disk.total_space();
-		total_disk_available += disk.available_space();
-	}
 	// Used disk space is total minus available ( in bytes).
-	let used_disk_space = total_disk_space.saturating_sub(total_disk_available);
+	let used_disk_space = metrics
+		.total_disk_space
+		.saturating_sub(metrics.available_disk_space);
 	DISK_USAGE.set(used_disk_space as f64);
 
 	// Calculate disk usage percentage.
-	let disk_percentage = if total_disk_space > 0 {
-		(used_disk_space as f64 / total_disk_space as f64) * 100.0
+	let disk_percentage = if metrics.total_disk_space > 0 {
+		(used_disk_space as f64 / metrics.total_disk_space as f64) * 100.0
 	} else {
 		0.0
 	};
@@ -260,7 +544,7 @@ pub fn update_monitoring_metrics(
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
 	use super::*;
 	use crate::{
 		models::{BlockChainType, Monitor, Network, TransactionStatus, Trigger},
@@ -273,7 +557,7 @@ mod tests {
 
 	// Use a mutex to ensure tests don't run in parallel when they modify shared state
 	lazy_static! {
-		static ref TEST_MUTEX: Mutex<()> = Mutex::new(());
+		pub(crate) static ref TEST_MUTEX: Mutex<()> = Mutex::new(());
 	}
 
 	// Reset all metrics to a known state
@@ -362,6 +646,9 @@ mod tests {
 		CONTRACTS_MONITORED.set(4.0);
 		NETWORKS_MONITORED.set(2.0);
 		NETWORK_MONITORS.with_label_values(&["test"]).set(1.0);
+		RETRY_ATTEMPTS_TOTAL
+			.with_label_values(&["http://localhost:8545"])
+			.inc();
 
 		let metrics = gather_metrics().expect("failed to gather metrics");
 		let output = String::from_utf8(metrics).expect("metrics output is not valid UTF-8");
@@ -382,6 +669,7 @@ mod tests {
 		assert!(output.contains("contracts_monitored"));
 		assert!(output.contains("networks_monitored"));
 		assert!(output.contains("network_monitors"));
+		assert!(output.contains("retry_attempts_total"));
 	}
 
 	#[test]
@@ -421,6 +709,36 @@ mod tests {
 		assert!(available_memory <= total_memory);
 	}
 
+	#[test]
+	fn test_system_metrics_update_with_provider() {
+		struct FixedProvider;
+		impl SystemMetricsProvider for FixedProvider {
+			fn collect(&self) -> system::SystemMetrics {
+				system::SystemMetrics {
+					cpu_usage: 12.5,
+					total_memory: 1000,
+					available_memory: 750,
+					used_memory: 250,
+					total_disk_space: 2000,
+					available_disk_space: 500,
+				}
+			}
+		}
+
+		let _lock = TEST_MUTEX.lock().unwrap();
+		reset_all_metrics();
+
+		update_system_metrics_with(&FixedProvider);
+
+		assert_eq!(CPU_USAGE.get(), 12.5);
+		assert_eq!(TOTAL_MEMORY.get(), 1000.0);
+		assert_eq!(AVAILABLE_MEMORY.get(), 750.0);
+		assert_eq!(MEMORY_USAGE.get(), 250.0);
+		assert_eq!(MEMORY_USAGE_PERCENT.get(), 25.0);
+		assert_eq!(DISK_USAGE.get(), 1500.0);
+		assert_eq!(DISK_USAGE_PERCENT.get(), 75.0);
+	}
+
 	#[test]
 	fn test_monitoring_metrics_update() {
 		let _lock = TEST_MUTEX.lock().unwrap();
@@ -708,4 +1026,75 @@ mod tests {
 			.unwrap();
 		assert_eq!(test_network.get(), 0.0);
 	}
+
+	#[test]
+	fn test_labeled_counter_collect() {
+		let counter = LabeledCounter::new("test_labeled_total", "Test counter").unwrap();
+		let first = BTreeMap::from([("team".to_string(), "payments".to_string())]);
+		let second = BTreeMap::from([
+			("env".to_string(), "prod".to_string()),
+			("team".to_string(), "growth".to_string()),
+		]);
+		counter.inc(first.clone());
+		counter.inc(first.clone());
+		counter.inc(second.clone());
+
+		assert_eq!(counter.get(&first), 2);
+		assert_eq!(counter.get(&second), 1);
+
+		let registry = Registry::new();
+		registry.register(Box::new(counter.clone())).unwrap();
+		let mut buffer = Vec::new();
+		TextEncoder::new()
+			.encode(&registry.gather(), &mut buffer)
+			.unwrap();
+		let output = String::from_utf8(buffer).unwrap();
+		assert!(output.contains("# TYPE test_labeled_total counter"));
+		assert!(output.contains("test_labeled_total{team=\"payments\"} 2"));
+		assert!(output.contains("test_labeled_total{env=\"prod\",team=\"growth\"} 1"));
+
+		counter.reset();
+		assert_eq!(counter.get(&first), 0);
+	}
+
+	#[test]
+	fn test_record_notification() {
+		let monitor = MonitorBuilder::new()
+			.name("record_notification_monitor")
+			.labels(vec![("team", "payments"), ("env", "prod")])
+			.build();
+		let trigger = TriggerBuilder::new()
+			.name("record_notification_trigger")
+			.labels(vec![("team", "oncall")])
+			.build();
+
+		record_notification(&monitor, &trigger, false);
+
+		// Trigger labels take precedence over monitor labels
+		let labels = BTreeMap::from([
+			(
+				"monitor".to_string(),
+				"record_notification_monitor".to_string(),
+			),
+			(
+				"trigger".to_string(),
+				"record_notification_trigger".to_string(),
+			),
+			("status".to_string(), "failure".to_string()),
+			("team".to_string(), "oncall".to_string()),
+			("env".to_string(), "prod".to_string()),
+		]);
+		assert_eq!(NOTIFICATIONS_TOTAL.get(&labels), 1);
+	}
+
+	#[test]
+	fn test_is_valid_label_name() {
+		assert!(is_valid_label_name("team"));
+		assert!(is_valid_label_name("_env"));
+		assert!(is_valid_label_name("cost_center_2"));
+		assert!(!is_valid_label_name(""));
+		assert!(!is_valid_label_name("2fa"));
+		assert!(!is_valid_label_name("team-name"));
+		assert!(!is_valid_label_name("__internal"));
+	}
 }
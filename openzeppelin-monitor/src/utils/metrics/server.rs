@@ -1,19 +1,46 @@
 //! Metrics server module
 //!
 //! This module provides an HTTP server to expose Prometheus metrics for scraping.
+//! When enabled, the same server also exposes an authenticated `POST /evaluate`
+//! endpoint that evaluates a loaded monitor against a block on demand, and authenticated `/admin`
+//! endpoints that pause and resume notifications. In metrics-only mode, the
+//! server exposes the metrics of the loaded configuration without watching any network.
 
 use actix_web::middleware::{Compress, DefaultHeaders, NormalizePath};
-use actix_web::{web, App, HttpResponse, HttpServer, Responder};
-use std::sync::Arc;
+use actix_web::{http::header, web, App, HttpRequest, HttpResponse, HttpServer, Responder};
+use serde::Deserialize;
+use socket2::{Domain, Protocol, Socket, Type};
+use std::{
+	fmt,
+	future::Future,
+	net::{SocketAddr, TcpListener},
+	path::PathBuf,
+	str::FromStr,
+	sync::Arc,
+	time::Duration,
+};
 use tokio::sync::Mutex;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 use crate::{
+	models::SecretString,
 	repositories::{
-		MonitorRepository, MonitorService, NetworkRepository, NetworkService, TriggerRepository,
+		MonitorRepository, MonitorRepositoryTrait, MonitorService, NetworkRepository,
+		NetworkRepositoryTrait, NetworkService, TriggerRepository, TriggerRepositoryTrait,
 		TriggerService,
 	},
-	utils::metrics::{gather_metrics, update_monitoring_metrics, update_system_metrics},
+	services::{
+		blockchain::{ClientPool, ClientPoolTrait},
+		filter::FilterService,
+		trigger::NotificationPause,
+	},
+	utils::{
+		metrics::{gather_metrics, update_monitoring_metrics, update_system_metrics},
+		monitor::{
+			execution::{evaluate_monitor, BlockSelector},
+			MonitorExecutionError,
+		},
+	},
 };
 
 // Type aliases to simplify complex types in function signatures
@@ -53,6 +80,77 @@ pub type NetworkServiceArc = Arc<Mutex<NetworkService<NetworkRepository>>>;
 // For Arc<Mutex<...>> TriggerService
 pub type TriggerServiceArc = Arc<Mutex<TriggerService<TriggerRepository>>>;
 
+/// Address the metrics server binds to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetricsBindAddress {
+	/// TCP address in `HOST:PORT` format, with IPv6 hosts in brackets such as `[::]:8081`
+	Tcp(String),
+	/// Unix domain socket path, configured as `unix:<path>`
+	Unix(PathBuf),
+}
+
+impl FromStr for MetricsBindAddress {
+	type Err = String;
+
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		match value.strip_prefix("unix:") {
+			Some("") => Err(format!(
+				"Invalid metrics address '{}': expected a socket path after 'unix:'",
+				value
+			)),
+			Some(path) => Ok(Self::Unix(PathBuf::from(path))),
+			None => Ok(Self::Tcp(value.to_string())),
+		}
+	}
+}
+
+impl MetricsBindAddress {
+	/// Returns the TCP address listening on all interfaces on the same port, keeping the IP
+	/// version of the configured host
+	fn on_all_interfaces(&self) -> Self {
+		match self {
+			Self::Tcp(address) => {
+				let host = if address.starts_with('[') {
+					"[::]"
+				} else {
+					"0.0.0.0"
+				};
+				let port = address
+					.rsplit_once(':')
+					.map(|(_, port)| port)
+					.unwrap_or("8081");
+				Self::Tcp(format!("{}:{}", host, port))
+			}
+			Self::Unix(path) => Self::Unix(path.clone()),
+		}
+	}
+}
+
+impl fmt::Display for MetricsBindAddress {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Tcp(address) => write!(f, "{}", address),
+			Self::Unix(path) => write!(f, "unix:{}", path.display()),
+		}
+	}
+}
+
+/// Interval between two refreshes of the monitoring metrics in metrics-only mode
+pub const METRICS_ONLY_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Updates the monitoring metrics from the current monitors, networks and triggers
+async fn refresh_monitoring_metrics(
+	monitor_service: &MonitorServiceArc,
+	network_service: &NetworkServiceArc,
+	trigger_service: &TriggerServiceArc,
+) {
+	let monitors = monitor_service.lock().await.get_all();
+	let networks = network_service.lock().await.get_all();
+	let triggers = trigger_service.lock().await.get_all();
+
+	update_monitoring_metrics(&monitors, &triggers, &networks);
+}
+
 /// Metrics endpoint handler
 async fn metrics_handler(
 	monitor_service: MonitorServiceData,
@@ -63,13 +161,7 @@ async fn metrics_handler(
 	update_system_metrics();
 
 	// Get current state and update metrics
-	{
-		let monitors = monitor_service.lock().await.get_all();
-		let networks = network_service.lock().await.get_all();
-		let triggers = trigger_service.lock().await.get_all();
-
-		update_monitoring_metrics(&monitors, &triggers, &networks);
-	}
+	refresh_monitoring_metrics(&monitor_service, &network_service, &trigger_service).await;
 
 	// Gather all metrics
 	match gather_metrics() {
@@ -83,21 +175,231 @@ async fn metrics_handler(
 	}
 }
 
+/// Request body accepted by the `/evaluate` endpoint
+#[derive(Debug, Deserialize)]
+pub struct EvaluateRequest {
+	/// Name of the loaded monitor to evaluate
+	pub monitor: String,
+	/// Network to evaluate the monitor against (defaults to all of the monitor's networks)
+	pub network: Option<String>,
+	/// Block to evaluate the monitor against (defaults to the latest block)
+	pub block: Option<u64>,
+}
+
+/// State backing the `/evaluate` endpoint
+///
+/// Holds the bearer token clients must present along with the services already
+/// constructed at startup, so evaluations share the loaded monitors and clients with
+/// the running monitor.
+pub struct EvaluateApiState<
+	M: MonitorRepositoryTrait<N, TR>,
+	N: NetworkRepositoryTrait + Send + Sync + 'static,
+	TR: TriggerRepositoryTrait + Send + Sync + 'static,
+	CP: ClientPoolTrait + Send + Sync + 'static,
+> {
+	pub token: SecretString,
+	pub monitor_service: Arc<Mutex<MonitorService<M, N, TR>>>,
+	pub network_service: Arc<Mutex<NetworkService<N>>>,
+	pub filter_service: Arc<FilterService>,
+	pub client_pool: Arc<CP>,
+}
+
+/// `/evaluate` state for the concrete services used by the application
+pub type EvaluateApi = EvaluateApiState<
+	MonitorRepository<NetworkRepository, TriggerRepository>,
+	NetworkRepository,
+	TriggerRepository,
+	ClientPool,
+>;
+
+/// Checks the request's `Authorization: Bearer <token>` header against the expected token
+fn is_authorized(req: &HttpRequest, token: &SecretString) -> bool {
+	let Some(provided) = req
+		.headers()
+		.get(header::AUTHORIZATION)
+		.and_then(|value| value.to_str().ok())
+		.and_then(|value| value.strip_prefix("Bearer "))
+	else {
+		return false;
+	};
+
+	// Compare in constant time so response timing does not reveal the token
+	let expected = token.as_str().as_bytes();
+	let provided = provided.trim().as_bytes();
+	expected.len() == provided.len()
+		&& expected
+			.iter()
+			.zip(provided)
+			.fold(0u8, |acc, (a, b)| acc | (a ^ b))
+			== 0
+}
+
+fn error_response(mut builder: actix_web::HttpResponseBuilder, message: String) -> HttpResponse {
+	builder.json(serde_json::json!({ "error": message }))
+}
+
+/// Evaluate endpoint handler
+///
+/// Evaluates the loaded monitor with the requested name against the requested network and
+/// block and returns the matches as JSON. Monitors are only looked up by name, never loaded
+/// from a path, and matches are not sent to the monitor's triggers.
+pub async fn evaluate_handler<
+	M: MonitorRepositoryTrait<N, TR>,
+	N: NetworkRepositoryTrait + Send + Sync + 'static,
+	TR: TriggerRepositoryTrait + Send + Sync + 'static,
+	CP: ClientPoolTrait + Send + Sync + 'static,
+>(
+	req: HttpRequest,
+	state: web::Data<EvaluateApiState<M, N, TR, CP>>,
+	body: web::Bytes,
+) -> HttpResponse {
+	if !is_authorized(&req, &state.token) {
+		return HttpResponse::Unauthorized()
+			.insert_header((header::WWW_AUTHENTICATE, "Bearer"))
+			.json(serde_json::json!({ "error": "Unauthorized" }));
+	}
+
+	let request: EvaluateRequest = match serde_json::from_slice(&body) {
+		Ok(request) => request,
+		Err(e) => {
+			return error_response(
+				HttpResponse::BadRequest(),
+				format!("Invalid request body: {}", e),
+			)
+		}
+	};
+
+	if request.block.is_some() && request.network.is_none() {
+		return error_response(
+			HttpResponse::BadRequest(),
+			"A network must be specified when evaluating a specific block".to_string(),
+		);
+	}
+
+	let monitor = state
+		.monitor_service
+		.lock()
+		.await
+		.get_all()
+		.into_values()
+		.find(|monitor| monitor.name == request.monitor);
+	let Some(monitor) = monitor else {
+		return error_response(
+			HttpResponse::NotFound(),
+			format!("Monitor '{}' not found", request.monitor),
+		);
+	};
+
+	let result = evaluate_monitor(
+		monitor,
+		request.network.as_deref(),
+		request.block.map(BlockSelector::Number),
+		&state.network_service,
+		&state.filter_service,
+		&state.client_pool,
+	)
+	.await;
+
+	match result {
+		Ok(matches) => HttpResponse::Ok().json(matches),
+		Err(e @ MonitorExecutionError::NotFound(_)) => {
+			error_response(HttpResponse::NotFound(), e.to_string())
+		}
+		Err(e) => {
+			error!("Error evaluating monitor: {}", e);
+			error_response(HttpResponse::InternalServerError(), e.to_string())
+		}
+	}
+}
+
+/// State backing the `/admin` endpoints
+///
+/// Holds the bearer token clients must present and the switch pausing the notifications
+/// of the running monitor.
+#[derive(Clone)]
+pub struct AdminApiState {
+	pub token: SecretString,
+	pub pause: NotificationPause,
+}
+
+fn admin_unauthorized() -> HttpResponse {
+	HttpResponse::Unauthorized()
+		.insert_header((header::WWW_AUTHENTICATE, "Bearer"))
+		.json(serde_json::json!({ "error": "Unauthorized" }))
+}
+
+fn admin_status_response(pause: &NotificationPause) -> HttpResponse {
+	HttpResponse::Ok().json(serde_json::json!({ "paused": pause.is_paused() }))
+}
+
+/// Admin pause endpoint handler
+///
+/// Pauses the notifications of every monitor. Matches are still processed and logged.
+pub async fn admin_pause_handler(
+	req: HttpRequest,
+	state: web::Data<AdminApiState>,
+) -> HttpResponse {
+	if !is_authorized(&req, &state.token) {
+		return admin_unauthorized();
+	}
+	if !state.pause.pause() {
+		warn!("Notifications paused through the admin endpoint");
+	}
+	admin_status_response(&state.pause)
+}
+
+/// Admin resume endpoint handler
+///
+/// Resumes the notifications paused through `/admin/pause`.
+pub async fn admin_resume_handler(
+	req: HttpRequest,
+	state: web::Data<AdminApiState>,
+) -> HttpResponse {
+	if !is_authorized(&req, &state.token) {
+		return admin_unauthorized();
+	}
+	if state.pause.resume() {
+		info!("Notifications resumed through the admin endpoint");
+	}
+	admin_status_response(&state.pause)
+}
+
+/// Admin status endpoint handler
+///
+/// Reports whether notifications are paused.
+pub async fn admin_status_handler(
+	req: HttpRequest,
+	state: web::Data<AdminApiState>,
+) -> HttpResponse {
+	if !is_authorized(&req, &state.token) {
+		return admin_unauthorized();
+	}
+	admin_status_response(&state.pause)
+}
+
 // Create metrics server
+//
+// `bind_address` is either a `HOST:PORT` TCP address or a `unix:<path>` Unix domain
+// socket. The `/evaluate` endpoint is only registered when `evaluate_api` is provided, and
+// the `/admin` endpoints only when `admin_api` is provided.
 pub fn create_metrics_server(
 	bind_address: String,
 	monitor_service: MonitorServiceArc,
 	network_service: NetworkServiceArc,
 	trigger_service: TriggerServiceArc,
+	evaluate_api: Option<EvaluateApi>,
+	admin_api: Option<AdminApiState>,
 ) -> std::io::Result<actix_web::dev::Server> {
-	let actual_bind_address = if std::env::var("IN_DOCKER").unwrap_or_default() == "true" {
-		if let Some(port) = bind_address.split(':').nth(1) {
-			format!("0.0.0.0:{}", port)
-		} else {
-			"0.0.0.0:8081".to_string()
+	let actual_bind_address = match bind_address
+		.parse::<MetricsBindAddress>()
+		.map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?
+	{
+		address @ MetricsBindAddress::Tcp(_)
+			if std::env::var("IN_DOCKER").unwrap_or_default() == "true" =>
+		{
+			address.on_all_interfaces()
 		}
-	} else {
-		bind_address.clone()
+		address => address,
 	};
 
 	info!(
@@ -105,20 +407,159 @@ pub fn create_metrics_server(
 		bind_address, actual_bind_address
 	);
 
-	Ok(HttpServer::new(move || {
-		App::new()
+	let evaluate_api = evaluate_api.map(web::Data::new);
+	if evaluate_api.is_some() {
+		info!("Evaluate endpoint enabled at /evaluate");
+	}
+	let admin_api = admin_api.map(web::Data::new);
+	if admin_api.is_some() {
+		info!("Admin endpoints enabled at /admin");
+	}
+
+	let server = HttpServer::new(move || {
+		let app = App::new()
 			.wrap(Compress::default())
 			.wrap(NormalizePath::trim())
 			.wrap(DefaultHeaders::new())
 			.app_data(web::Data::new(monitor_service.clone()))
 			.app_data(web::Data::new(network_service.clone()))
 			.app_data(web::Data::new(trigger_service.clone()))
-			.route("/metrics", web::get().to(metrics_handler))
+			.route("/metrics", web::get().to(metrics_handler));
+
+		let app = match &evaluate_api {
+			Some(evaluate_api) => app.app_data(evaluate_api.clone()).route(
+				"/evaluate",
+				web::post().to(evaluate_handler::<
+					MonitorRepository<NetworkRepository, TriggerRepository>,
+					NetworkRepository,
+					TriggerRepository,
+					ClientPool,
+				>),
+			),
+			None => app,
+		};
+
+		match &admin_api {
+			Some(admin_api) => app
+				.app_data(admin_api.clone())
+				.route("/admin/pause", web::post().to(admin_pause_handler))
+				.route("/admin/resume", web::post().to(admin_resume_handler))
+				.route("/admin/status", web::get().to(admin_status_handler)),
+			None => app,
+		}
 	})
 	.workers(2)
-	.bind(actual_bind_address)?
-	.shutdown_timeout(5)
-	.run())
+	.shutdown_timeout(5);
+
+	let server = match actual_bind_address {
+		MetricsBindAddress::Tcp(address) => match address.parse::<SocketAddr>() {
+			Ok(socket_address) => server.listen(bind_tcp_listener(socket_address)?)?,
+			// Host names may resolve to several addresses, which are all bound
+			Err(_) => server.bind(address)?,
+		},
+		#[cfg(unix)]
+		MetricsBindAddress::Unix(path) => {
+			// Remove a socket left behind by a previous run, as binding would otherwise fail
+			remove_stale_socket(&path)?;
+			server.bind_uds(path)?
+		}
+		#[cfg(not(unix))]
+		MetricsBindAddress::Unix(_) => {
+			return Err(std::io::Error::new(
+				std::io::ErrorKind::Unsupported,
+				"Unix domain sockets are not supported on this platform",
+			))
+		}
+	};
+
+	Ok(server.run())
+}
+
+/// Serves the metrics of the loaded configuration without watching any network
+///
+/// The monitoring metrics are populated once and refreshed every `refresh_interval` until
+/// the server stops or `shutdown` completes. No blockchain client is created, so no RPC call
+/// is made.
+///
+/// # Arguments
+/// * `bind_address` - Address of the metrics server, as accepted by `create_metrics_server`
+/// * `monitor_service` - Service of the loaded monitors
+/// * `network_service` - Service of the loaded networks
+/// * `trigger_service` - Service of the loaded triggers
+/// * `refresh_interval` - Interval between two refreshes of the monitoring metrics
+/// * `shutdown` - Future completing when the server should stop
+pub async fn run_metrics_only(
+	bind_address: String,
+	monitor_service: MonitorServiceArc,
+	network_service: NetworkServiceArc,
+	trigger_service: TriggerServiceArc,
+	refresh_interval: Duration,
+	shutdown: impl Future<Output = ()>,
+) -> std::io::Result<()> {
+	let server = create_metrics_server(
+		bind_address,
+		monitor_service.clone(),
+		network_service.clone(),
+		trigger_service.clone(),
+		None,
+		None,
+	)?;
+	let server_handle = server.handle();
+
+	let refresh = async {
+		let mut ticker = tokio::time::interval(refresh_interval);
+		loop {
+			ticker.tick().await;
+			refresh_monitoring_metrics(&monitor_service, &network_service, &trigger_service).await;
+		}
+	};
+
+	tokio::select! {
+		result = server => result,
+		_ = refresh => Ok(()),
+		_ = shutdown => {
+			server_handle.stop(true).await;
+			Ok(())
+		}
+	}
+}
+
+/// Binds a TCP listener to `address`
+///
+/// Listeners on the unspecified IPv6 address `[::]` are dual-stack where the platform allows
+/// it, so that they also accept IPv4 connections.
+fn bind_tcp_listener(address: SocketAddr) -> std::io::Result<TcpListener> {
+	let socket = Socket::new(
+		Domain::for_address(address),
+		Type::STREAM,
+		Some(Protocol::TCP),
+	)?;
+	#[cfg(not(windows))]
+	socket.set_reuse_address(true)?;
+	if let SocketAddr::V6(address) = address {
+		if address.ip().is_unspecified() {
+			if let Err(e) = socket.set_only_v6(false) {
+				warn!(
+					"Failed to enable dual-stack metrics server, only accepting IPv6 connections: {}",
+					e
+				);
+			}
+		}
+	}
+	socket.bind(&address.into())?;
+	socket.listen(1024)?;
+	Ok(socket.into())
+}
+
+/// Removes an existing Unix domain socket at `path`, leaving any other kind of file untouched
+#[cfg(unix)]
+fn remove_stale_socket(path: &std::path::Path) -> std::io::Result<()> {
+	use std::os::unix::fs::FileTypeExt;
+
+	match std::fs::symlink_metadata(path) {
+		Ok(metadata) if metadata.file_type().is_socket() => std::fs::remove_file(path),
+		_ => Ok(()),
+	}
 }
 
 #[cfg(test)]
@@ -134,7 +575,7 @@ mod tests {
 		},
 	};
 	use actix_web::{test, App};
-	use std::{fs, path::PathBuf};
+	use std::{collections::HashMap, fs, path::PathBuf};
 	use tempfile::TempDir;
 	use tokio::net::TcpListener;
 
@@ -297,6 +738,167 @@ mod tests {
 		assert!(body_str.contains("# HELP"));
 	}
 
+	fn create_test_evaluate_api(
+		monitor_service: MonitorServiceArc,
+		network_service: NetworkServiceArc,
+	) -> EvaluateApi {
+		EvaluateApi {
+			token: SecretString::new("test-token".to_string()),
+			monitor_service,
+			network_service,
+			filter_service: Arc::new(FilterService::new()),
+			client_pool: Arc::new(ClientPool::new()),
+		}
+	}
+
+	#[actix_web::test]
+	async fn test_evaluate_handler_unauthorized() {
+		let (monitor_service, network_service, _trigger_service, _temp_dir) =
+			create_test_services().await;
+		let evaluate_api = create_test_evaluate_api(monitor_service, network_service);
+
+		let app = test::init_service(App::new().app_data(web::Data::new(evaluate_api)).route(
+			"/evaluate",
+			web::post().to(evaluate_handler::<
+				MonitorRepository<NetworkRepository, TriggerRepository>,
+				NetworkRepository,
+				TriggerRepository,
+				ClientPool,
+			>),
+		))
+		.await;
+
+		let body = serde_json::json!({ "monitor": "test_monitor" });
+
+		// Missing token
+		let req = test::TestRequest::post()
+			.uri("/evaluate")
+			.set_json(&body)
+			.to_request();
+		let resp = test::call_service(&app, req).await;
+		assert_eq!(resp.status(), actix_web::http::StatusCode::UNAUTHORIZED);
+		assert_eq!(
+			resp.headers().get(header::WWW_AUTHENTICATE).unwrap(),
+			"Bearer"
+		);
+
+		// Wrong token
+		let req = test::TestRequest::post()
+			.uri("/evaluate")
+			.insert_header((header::AUTHORIZATION, "Bearer wrong-token"))
+			.set_json(&body)
+			.to_request();
+		let resp = test::call_service(&app, req).await;
+		assert_eq!(resp.status(), actix_web::http::StatusCode::UNAUTHORIZED);
+	}
+
+	#[actix_web::test]
+	async fn test_evaluate_handler_rejects_block_without_network() {
+		let (monitor_service, network_service, _trigger_service, _temp_dir) =
+			create_test_services().await;
+		let evaluate_api = create_test_evaluate_api(monitor_service, network_service);
+
+		let app = test::init_service(App::new().app_data(web::Data::new(evaluate_api)).route(
+			"/evaluate",
+			web::post().to(evaluate_handler::<
+				MonitorRepository<NetworkRepository, TriggerRepository>,
+				NetworkRepository,
+				TriggerRepository,
+				ClientPool,
+			>),
+		))
+		.await;
+
+		let req = test::TestRequest::post()
+			.uri("/evaluate")
+			.insert_header((header::AUTHORIZATION, "Bearer test-token"))
+			.set_json(serde_json::json!({ "monitor": "test_monitor", "block": 1 }))
+			.to_request();
+		let resp = test::call_service(&app, req).await;
+		assert_eq!(resp.status(), actix_web::http::StatusCode::BAD_REQUEST);
+	}
+
+	#[actix_web::test]
+	async fn test_admin_handlers_pause_and_resume() {
+		let pause = NotificationPause::new();
+		let admin_api = AdminApiState {
+			token: SecretString::new("test-token".to_string()),
+			pause: pause.clone(),
+		};
+
+		let app = test::init_service(
+			App::new()
+				.app_data(web::Data::new(admin_api))
+				.route("/admin/pause", web::post().to(admin_pause_handler))
+				.route("/admin/resume", web::post().to(admin_resume_handler))
+				.route("/admin/status", web::get().to(admin_status_handler)),
+		)
+		.await;
+
+		// Missing and wrong tokens are rejected without changing the state
+		let req = test::TestRequest::post().uri("/admin/pause").to_request();
+		let resp = test::call_service(&app, req).await;
+		assert_eq!(resp.status(), actix_web::http::StatusCode::UNAUTHORIZED);
+		let req = test::TestRequest::get()
+			.uri("/admin/status")
+			.insert_header((header::AUTHORIZATION, "Bearer wrong-token"))
+			.to_request();
+		let resp = test::call_service(&app, req).await;
+		assert_eq!(resp.status(), actix_web::http::StatusCode::UNAUTHORIZED);
+		assert!(!pause.is_paused());
+
+		for (method, uri, paused) in [
+			(test::TestRequest::get(), "/admin/status", false),
+			(test::TestRequest::post(), "/admin/pause", true),
+			(test::TestRequest::get(), "/admin/status", true),
+			(test::TestRequest::post(), "/admin/resume", false),
+			(test::TestRequest::get(), "/admin/status", false),
+		] {
+			let req = method
+				.uri(uri)
+				.insert_header((header::AUTHORIZATION, "Bearer test-token"))
+				.to_request();
+			let body: serde_json::Value = test::call_and_read_body_json(&app, req).await;
+			assert_eq!(body, serde_json::json!({ "paused": paused }), "{}", uri);
+			assert_eq!(pause.is_paused(), paused);
+		}
+	}
+
+	#[tokio::test]
+	async fn test_evaluate_route_disabled_by_default() {
+		let (monitor_service, network_service, trigger_service, _temp_dir) =
+			create_test_services().await;
+
+		let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+		let port = listener.local_addr().unwrap().port();
+		drop(listener);
+		let bind_address = format!("127.0.0.1:{}", port);
+
+		let server = create_metrics_server(
+			bind_address.clone(),
+			monitor_service,
+			network_service,
+			trigger_service,
+			None,
+			None,
+		)
+		.unwrap();
+		let server_task = tokio::spawn(server);
+		tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+		let response = reqwest::Client::new()
+			.post(format!("http://{}/evaluate", bind_address))
+			.bearer_auth("test-token")
+			.json(&serde_json::json!({ "monitor": "test_monitor" }))
+			.timeout(std::time::Duration::from_secs(1))
+			.send()
+			.await
+			.unwrap();
+		assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+
+		server_task.abort();
+	}
+
 	#[tokio::test]
 	async fn test_create_metrics_server() {
 		// Create test services
@@ -316,6 +918,8 @@ mod tests {
 			monitor_service,
 			network_service,
 			trigger_service,
+			None,
+			None,
 		);
 
 		// Assert server creation is successful
@@ -352,6 +956,277 @@ mod tests {
 		server_task.abort();
 	}
 
+	// The metrics are global, so this test holds the lock of the metrics tests while serving them
+	#[allow(clippy::await_holding_lock)]
+	#[tokio::test]
+	async fn test_run_metrics_only() {
+		let _lock = crate::utils::metrics::tests::TEST_MUTEX
+			.lock()
+			.unwrap_or_else(|poisoned| poisoned.into_inner());
+
+		// Any RPC call made to the network would reach this server
+		let mut rpc_server = mockito::Server::new_async().await;
+		let rpc_mock = rpc_server
+			.mock("POST", mockito::Matcher::Any)
+			.expect(0)
+			.create_async()
+			.await;
+
+		let (monitor_dir, trigger_dir, network_dir, _temp_dir) = create_mock_configs();
+		let network = NetworkBuilder::new()
+			.name("Ethereum Mainnet")
+			.slug("ethereum_mainnet")
+			.network_type(BlockChainType::EVM)
+			.chain_id(1)
+			.rpc_url(&rpc_server.url())
+			.block_time_ms(1000)
+			.confirmation_blocks(1)
+			.cron_schedule("*/1 * * * * *")
+			.store_blocks(false)
+			.build();
+		fs::write(
+			trigger_dir.join("test_trigger.json"),
+			serde_json::to_string(&HashMap::from([(
+				"test_trigger",
+				create_test_trigger("test_trigger"),
+			)]))
+			.unwrap(),
+		)
+		.unwrap();
+		fs::remove_file(network_dir.join("test_network.json")).unwrap();
+		fs::write(
+			network_dir.join("ethereum_mainnet.json"),
+			serde_json::to_string(&network).unwrap(),
+		)
+		.unwrap();
+
+		let network_service = NetworkService::<NetworkRepository>::new(Some(network_dir.as_path()))
+			.await
+			.unwrap();
+		let trigger_service = TriggerService::<TriggerRepository>::new(Some(trigger_dir.as_path()))
+			.await
+			.unwrap();
+		let monitor_service = MonitorService::new(
+			Some(monitor_dir.as_path()),
+			Some(network_service.clone()),
+			Some(trigger_service.clone()),
+		)
+		.await
+		.unwrap();
+
+		let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+		let bind_address = listener.local_addr().unwrap().to_string();
+		drop(listener);
+
+		let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+		let server_task = tokio::spawn(run_metrics_only(
+			bind_address.clone(),
+			Arc::new(Mutex::new(monitor_service)),
+			Arc::new(Mutex::new(network_service)),
+			Arc::new(Mutex::new(trigger_service)),
+			Duration::from_millis(50),
+			async {
+				let _ = shutdown_rx.await;
+			},
+		));
+
+		// Wait for the server to start and refresh the metrics a few times
+		tokio::time::sleep(Duration::from_millis(300)).await;
+
+		let body = reqwest::Client::new()
+			.get(format!("http://{}/metrics", bind_address))
+			.timeout(Duration::from_secs(1))
+			.send()
+			.await
+			.expect("Metrics should be served")
+			.text()
+			.await
+			.unwrap();
+
+		assert!(body.contains("\nmonitors_total 1\n"));
+		assert!(body.contains("\nmonitors_active 1\n"));
+		assert!(body.contains("\ntriggers_total 1\n"));
+		assert!(body.contains("\nnetworks_monitored 1\n"));
+		assert!(body.contains("network_monitors{network=\"ethereum_mainnet\"} 1"));
+
+		shutdown_tx.send(()).unwrap();
+		let result = tokio::time::timeout(Duration::from_secs(5), server_task)
+			.await
+			.expect("Server should stop on shutdown")
+			.unwrap();
+		assert!(result.is_ok());
+
+		// No blockchain client was created, so the network was never called
+		rpc_mock.assert_async().await;
+	}
+
+	#[tokio::test]
+	async fn test_metrics_bind_address_on_all_interfaces() {
+		assert_eq!(
+			MetricsBindAddress::Tcp("127.0.0.1:9090".to_string()).on_all_interfaces(),
+			MetricsBindAddress::Tcp("0.0.0.0:9090".to_string())
+		);
+		assert_eq!(
+			MetricsBindAddress::Tcp("[::1]:9090".to_string()).on_all_interfaces(),
+			MetricsBindAddress::Tcp("[::]:9090".to_string())
+		);
+		assert_eq!(
+			MetricsBindAddress::Tcp("localhost".to_string()).on_all_interfaces(),
+			MetricsBindAddress::Tcp("0.0.0.0:8081".to_string())
+		);
+	}
+
+	/// Starts the metrics server on `bind_address` and returns the status of `GET /metrics`
+	/// requested at `request_address`
+	async fn get_metrics_status(bind_address: String, request_address: String) -> u16 {
+		let (monitor_service, network_service, trigger_service, _temp_dir) =
+			create_test_services().await;
+
+		let server = create_metrics_server(
+			bind_address,
+			monitor_service,
+			network_service,
+			trigger_service,
+			None,
+			None,
+		)
+		.unwrap();
+		let server_task = tokio::spawn(server);
+		tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+		let response = reqwest::Client::new()
+			.get(format!("http://{}/metrics", request_address))
+			.timeout(std::time::Duration::from_secs(1))
+			.send()
+			.await
+			.unwrap();
+
+		server_task.abort();
+		response.status().as_u16()
+	}
+
+	#[tokio::test]
+	async fn test_create_metrics_server_ipv6() {
+		let listener = TcpListener::bind("[::1]:0").await.unwrap();
+		let port = listener.local_addr().unwrap().port();
+		drop(listener);
+
+		let bind_address = format!("[::1]:{}", port);
+		let status = get_metrics_status(bind_address.clone(), bind_address).await;
+		assert_eq!(status, 200);
+	}
+
+	#[tokio::test]
+	async fn test_create_metrics_server_dual_stack() {
+		let listener = TcpListener::bind("[::]:0").await.unwrap();
+		let port = listener.local_addr().unwrap().port();
+		drop(listener);
+
+		// The unspecified IPv6 address also accepts IPv4 connections
+		let status =
+			get_metrics_status(format!("[::]:{}", port), format!("127.0.0.1:{}", port)).await;
+		assert_eq!(status, 200);
+	}
+
+	#[tokio::test]
+	async fn test_parse_metrics_bind_address() {
+		assert_eq!(
+			"127.0.0.1:8081".parse::<MetricsBindAddress>().unwrap(),
+			MetricsBindAddress::Tcp("127.0.0.1:8081".to_string())
+		);
+		assert_eq!(
+			"unix:/run/monitor-metrics.sock"
+				.parse::<MetricsBindAddress>()
+				.unwrap(),
+			MetricsBindAddress::Unix(PathBuf::from("/run/monitor-metrics.sock"))
+		);
+		assert!("unix:".parse::<MetricsBindAddress>().is_err());
+		assert_eq!(
+			"[::]:8081".parse::<MetricsBindAddress>().unwrap(),
+			MetricsBindAddress::Tcp("[::]:8081".to_string())
+		);
+		assert_eq!(
+			"[::1]:8081".parse::<MetricsBindAddress>().unwrap(),
+			MetricsBindAddress::Tcp("[::1]:8081".to_string())
+		);
+
+		// Display round trips to the configured address
+		assert_eq!(
+			MetricsBindAddress::Unix(PathBuf::from("/run/monitor-metrics.sock")).to_string(),
+			"unix:/run/monitor-metrics.sock"
+		);
+		assert_eq!(
+			MetricsBindAddress::Tcp("localhost:9090".to_string()).to_string(),
+			"localhost:9090"
+		);
+	}
+
+	#[cfg(unix)]
+	#[tokio::test]
+	async fn test_create_metrics_server_unix_socket() {
+		use tokio::{
+			io::{AsyncReadExt, AsyncWriteExt},
+			net::UnixStream,
+		};
+
+		let (monitor_service, network_service, trigger_service, temp_dir) =
+			create_test_services().await;
+		let socket_path = temp_dir.path().join("monitor-metrics.sock");
+
+		// A socket left behind by a previous run is replaced
+		let stale = std::os::unix::net::UnixListener::bind(&socket_path).unwrap();
+		drop(stale);
+
+		let server = create_metrics_server(
+			format!("unix:{}", socket_path.display()),
+			monitor_service,
+			network_service,
+			trigger_service,
+			None,
+			None,
+		)
+		.unwrap();
+		let server_task = tokio::spawn(server);
+		tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+		let mut stream = UnixStream::connect(&socket_path).await.unwrap();
+		stream
+			.write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+			.await
+			.unwrap();
+		let mut response = Vec::new();
+		tokio::time::timeout(
+			std::time::Duration::from_secs(1),
+			stream.read_to_end(&mut response),
+		)
+		.await
+		.unwrap()
+		.unwrap();
+		let response = String::from_utf8_lossy(&response);
+		assert!(response.starts_with("HTTP/1.1 200 OK"));
+
+		server_task.abort();
+	}
+
+	#[tokio::test]
+	async fn test_create_metrics_server_invalid_unix_address() {
+		let (monitor_service, network_service, trigger_service, _temp_dir) =
+			create_test_services().await;
+
+		let result = create_metrics_server(
+			"unix:".to_string(),
+			monitor_service,
+			network_service,
+			trigger_service,
+			None,
+			None,
+		);
+		assert_eq!(
+			result.err().unwrap().kind(),
+			std::io::ErrorKind::InvalidInput
+		);
+	}
+
 	#[tokio::test]
 	async fn test_docker_bind_address_handling() {
 		// Save original environment state
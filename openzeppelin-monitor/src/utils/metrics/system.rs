@@ -0,0 +1,332 @@
+//! System metrics providers
+//!
+//! The system metrics (CPU, memory and disk usage) are read through a [`SystemMetricsProvider`]:
+//! - [`SysinfoMetricsProvider`] reports the resources of the host, using `sysinfo`.
+//! - [`CgroupMetricsProvider`] reports the CPU and memory of the container, using the cgroup v2
+//!   files, so that percentages reflect the container limits instead of the host resources.
+//!
+//! The provider is selected based on the `IN_DOCKER` environment variable.
+
+use std::{
+	fs,
+	path::{Path, PathBuf},
+	sync::Mutex,
+	time::Instant,
+};
+use sysinfo::{Disks, System};
+
+/// Default mount point of the cgroup v2 hierarchy
+pub const DEFAULT_CGROUP_PATH: &str = "/sys/fs/cgroup";
+
+/// Snapshot of the system resource usage
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SystemMetrics {
+	/// CPU usage percentage (0-100)
+	pub cpu_usage: f64,
+	/// Total memory in bytes
+	pub total_memory: u64,
+	/// Available memory in bytes
+	pub available_memory: u64,
+	/// Used memory in bytes
+	pub used_memory: u64,
+	/// Total disk space in bytes
+	pub total_disk_space: u64,
+	/// Available disk space in bytes
+	pub available_disk_space: u64,
+}
+
+/// Source of the system resource usage
+pub trait SystemMetricsProvider: Send + Sync {
+	/// Collects the current system resource usage
+	fn collect(&self) -> SystemMetrics;
+}
+
+/// Provider reporting the resources of the host using `sysinfo`
+#[derive(Debug, Default)]
+pub struct SysinfoMetricsProvider;
+
+impl SystemMetricsProvider for SysinfoMetricsProvider {
+	fn collect(&self) -> SystemMetrics {
+		let mut sys = System::new_all();
+		sys.refresh_all();
+
+		let (total_disk_space, available_disk_space) = disk_space();
+		SystemMetrics {
+			cpu_usage: sys.global_cpu_usage() as f64,
+			total_memory: sys.total_memory(),
+			available_memory: sys.available_memory(),
+			used_memory: sys.used_memory(),
+			total_disk_space,
+			available_disk_space,
+		}
+	}
+}
+
+/// Provider reporting the CPU and memory of a container from its cgroup v2 files
+///
+/// - Memory usage is read from `memory.current`, minus the reclaimable page cache
+///   (`inactive_file` in `memory.stat`), and is reported against the `memory.max` limit.
+/// - CPU usage is computed from the `usage_usec` counter of `cpu.stat` between two collections,
+///   relative to the CPUs allowed by `cpu.max`.
+///
+/// Host values are used when a file is missing or when no limit is set.
+#[derive(Debug)]
+pub struct CgroupMetricsProvider {
+	root: PathBuf,
+	last_cpu_sample: Mutex<Option<(u64, Instant)>>,
+}
+
+impl CgroupMetricsProvider {
+	/// Creates a provider reading the cgroup v2 files of the given directory
+	pub fn new(root: impl Into<PathBuf>) -> Self {
+		Self {
+			root: root.into(),
+			last_cpu_sample: Mutex::new(None),
+		}
+	}
+
+	/// Returns whether the cgroup v2 memory files are available
+	pub fn is_available(&self) -> bool {
+		self.root.join("memory.current").is_file()
+	}
+
+	/// Reads the memory limit in bytes, `None` when the cgroup has no limit
+	pub fn memory_limit(&self) -> Option<u64> {
+		parse_limit(&read_file(&self.root, "memory.max")?)
+	}
+
+	/// Reads the memory used by the cgroup in bytes, excluding the reclaimable page cache
+	pub fn memory_usage(&self) -> Option<u64> {
+		let current = read_file(&self.root, "memory.current")?
+			.trim()
+			.parse::<u64>()
+			.ok()?;
+		let inactive_file = read_file(&self.root, "memory.stat")
+			.and_then(|stat| parse_stat(&stat, "inactive_file"))
+			.unwrap_or(0);
+		Some(current.saturating_sub(inactive_file))
+	}
+
+	/// Reads the number of CPUs the cgroup is allowed to use, `None` when it has no limit
+	pub fn cpu_limit(&self) -> Option<f64> {
+		let cpu_max = read_file(&self.root, "cpu.max")?;
+		let mut parts = cpu_max.split_whitespace();
+		let quota = parse_limit(parts.next()?)?;
+		let period = parts.next().and_then(|p| p.parse::<u64>().ok())?;
+		(period > 0).then(|| quota as f64 / period as f64)
+	}
+
+	/// Reads the CPU time consumed by the cgroup in microseconds
+	pub fn cpu_usage_usec(&self) -> Option<u64> {
+		parse_stat(&read_file(&self.root, "cpu.stat")?, "usage_usec")
+	}
+
+	/// Computes the CPU usage percentage since the previous call
+	///
+	/// The first call only records a sample and reports no usage.
+	fn cpu_usage(&self, host_cpus: usize) -> Option<f64> {
+		let usage_usec = self.cpu_usage_usec()?;
+		let now = Instant::now();
+		let previous = self
+			.last_cpu_sample
+			.lock()
+			.unwrap_or_else(|e| e.into_inner())
+			.replace((usage_usec, now));
+
+		let (previous_usage_usec, previous_time) = match previous {
+			Some(sample) => sample,
+			None => return Some(0.0),
+		};
+		let elapsed_usec = now.duration_since(previous_time).as_micros() as f64;
+		let cpus = self.cpu_limit().unwrap_or(host_cpus as f64);
+		if elapsed_usec <= 0.0 || cpus <= 0.0 {
+			return Some(0.0);
+		}
+
+		let used_usec = usage_usec.saturating_sub(previous_usage_usec) as f64;
+		Some((used_usec / (elapsed_usec * cpus) * 100.0).clamp(0.0, 100.0))
+	}
+}
+
+impl SystemMetricsProvider for CgroupMetricsProvider {
+	fn collect(&self) -> SystemMetrics {
+		let mut sys = System::new();
+		sys.refresh_memory();
+
+		let total_memory = self
+			.memory_limit()
+			.map_or(sys.total_memory(), |limit| limit.min(sys.total_memory()));
+		let used_memory = self
+			.memory_usage()
+			.unwrap_or(sys.used_memory())
+			.min(total_memory);
+		let cpu_usage = self
+			.cpu_usage(
+				std::thread::available_parallelism()
+					.map(|n| n.get())
+					.unwrap_or(1),
+			)
+			.unwrap_or(0.0);
+
+		let (total_disk_space, available_disk_space) = disk_space();
+		SystemMetrics {
+			cpu_usage,
+			total_memory,
+			available_memory: total_memory - used_memory,
+			used_memory,
+			total_disk_space,
+			available_disk_space,
+		}
+	}
+}
+
+/// Returns the system metrics provider to use
+///
+/// The cgroup provider is used when running in Docker (`IN_DOCKER` set to `true`) and the
+/// cgroup v2 files are available, the `sysinfo` provider otherwise.
+pub fn default_system_metrics_provider() -> Box<dyn SystemMetricsProvider> {
+	if std::env::var("IN_DOCKER").unwrap_or_default() == "true" {
+		let provider = CgroupMetricsProvider::new(DEFAULT_CGROUP_PATH);
+		if provider.is_available() {
+			return Box::new(provider);
+		}
+		tracing::warn!("cgroup v2 files not found, reporting host system metrics");
+	}
+	Box::new(SysinfoMetricsProvider)
+}
+
+/// Sums the total and available space across all disks
+fn disk_space() -> (u64, u64) {
+	let disks = Disks::new_with_refreshed_list();
+	disks
+		.list()
+		.iter()
+		.fold((0, 0), |(total, available), disk| {
+			(
+				total + disk.total_space(),
+				available + disk.available_space(),
+			)
+		})
+}
+
+/// Reads a cgroup file, `None` if it cannot be read
+fn read_file(root: &Path, name: &str) -> Option<String> {
+	fs::read_to_string(root.join(name)).ok()
+}
+
+/// Parses a cgroup limit, where `max` means no limit
+fn parse_limit(value: &str) -> Option<u64> {
+	match value.trim() {
+		"max" => None,
+		value => value.parse().ok(),
+	}
+}
+
+/// Parses the value of a key from a flat keyed cgroup file (e.g. `memory.stat`)
+fn parse_stat(content: &str, key: &str) -> Option<u64> {
+	content.lines().find_map(|line| {
+		let (name, value) = line.split_once(' ')?;
+		(name == key).then(|| value.trim().parse().ok()).flatten()
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use tempfile::TempDir;
+
+	fn create_cgroup_dir(files: &[(&str, &str)]) -> TempDir {
+		let dir = TempDir::new().unwrap();
+		for (name, content) in files {
+			fs::write(dir.path().join(name), content).unwrap();
+		}
+		dir
+	}
+
+	#[test]
+	fn test_cgroup_memory_limit_and_usage() {
+		let dir = create_cgroup_dir(&[
+			("memory.max", "536870912\n"),
+			("memory.current", "268435456\n"),
+			(
+				"memory.stat",
+				"anon 100\nfile 200\ninactive_file 67108864\n",
+			),
+		]);
+		let provider = CgroupMetricsProvider::new(dir.path());
+
+		assert!(provider.is_available());
+		assert_eq!(provider.memory_limit(), Some(536870912));
+		assert_eq!(provider.memory_usage(), Some(268435456 - 67108864));
+	}
+
+	#[test]
+	fn test_cgroup_memory_without_limit() {
+		let dir = create_cgroup_dir(&[("memory.max", "max\n"), ("memory.current", "1024\n")]);
+		let provider = CgroupMetricsProvider::new(dir.path());
+
+		assert_eq!(provider.memory_limit(), None);
+		assert_eq!(provider.memory_usage(), Some(1024));
+	}
+
+	#[test]
+	fn test_cgroup_collect_reports_usage_against_limit() {
+		let dir = create_cgroup_dir(&[("memory.max", "1048576\n"), ("memory.current", "262144\n")]);
+		let provider = CgroupMetricsProvider::new(dir.path());
+
+		let metrics = provider.collect();
+		assert_eq!(metrics.total_memory, 1048576);
+		assert_eq!(metrics.used_memory, 262144);
+		assert_eq!(metrics.available_memory, 786432);
+		assert_eq!(metrics.cpu_usage, 0.0);
+	}
+
+	#[test]
+	fn test_cgroup_cpu_limit() {
+		let dir = create_cgroup_dir(&[("cpu.max", "150000 100000\n")]);
+		assert_eq!(
+			CgroupMetricsProvider::new(dir.path()).cpu_limit(),
+			Some(1.5)
+		);
+
+		let dir = create_cgroup_dir(&[("cpu.max", "max 100000\n")]);
+		assert_eq!(CgroupMetricsProvider::new(dir.path()).cpu_limit(), None);
+	}
+
+	#[test]
+	fn test_cgroup_cpu_usage() {
+		let dir = create_cgroup_dir(&[
+			("cpu.max", "100000 100000\n"),
+			(
+				"cpu.stat",
+				"usage_usec 1000\nuser_usec 800\nsystem_usec 200\n",
+			),
+		]);
+		let provider = CgroupMetricsProvider::new(dir.path());
+		assert_eq!(provider.cpu_usage_usec(), Some(1000));
+
+		// The first sample has no previous usage to compare with
+		assert_eq!(provider.cpu_usage(4), Some(0.0));
+
+		fs::write(dir.path().join("cpu.stat"), "usage_usec 1000000000\n").unwrap();
+		assert_eq!(provider.cpu_usage(4), Some(100.0));
+	}
+
+	#[test]
+	fn test_cgroup_missing_files() {
+		let dir = TempDir::new().unwrap();
+		let provider = CgroupMetricsProvider::new(dir.path());
+
+		assert!(!provider.is_available());
+		assert_eq!(provider.memory_limit(), None);
+		assert_eq!(provider.memory_usage(), None);
+		assert_eq!(provider.cpu_usage(4), None);
+	}
+
+	#[test]
+	fn test_parse_stat() {
+		let content = "anon 10\ninactive_file 20\nactive_file 30\n";
+		assert_eq!(parse_stat(content, "inactive_file"), Some(20));
+		assert_eq!(parse_stat(content, "file"), None);
+	}
+}
@@ -10,7 +10,10 @@
 //! - metrics: Metrics utilities
 //! - monitor: Monitor utilities
 //! - parsing: Parsing utilities
+//! - selftest: Startup connectivity self-test utilities
 //! - tests: Test utilities
+//! - timestamp: Timestamp formatting utilities for notification variables
+//! - trigger: Trigger utilities
 //! - http: HTTP client utilities (i.e. creation retryable HTTP clients)
 
 mod cron_utils;
@@ -23,7 +26,10 @@ pub mod macros;
 pub mod metrics;
 pub mod monitor;
 pub mod parsing;
+pub mod selftest;
 pub mod tests;
+pub mod timestamp;
+pub mod trigger;
 
 pub use client_storage::ClientStorage;
 pub use constants::*;
@@ -3,8 +3,10 @@
 //! This module provides functionality for executing monitors against a specific block
 //!
 //! - execution: Monitor execution logic against a specific block
+//! - replay: Replay of a block range against the current monitors
 //! - error: Error types for monitor execution
 
 mod error;
 pub use error::MonitorExecutionError;
 pub mod execution;
+pub mod replay;
@@ -0,0 +1,218 @@
+//! Replay monitor module
+//!
+//! This module provides functionality to replay a range of blocks through the block processing
+//! pipeline using the current monitor configurations, for example to investigate an incident.
+//! Replaying never affects the stored last processed block of the network.
+use crate::{
+	bootstrap::{create_block_handler, create_trigger_handler, get_contract_specs},
+	models::{BlockChainType, BlockType, Monitor, Network, ProcessedBlock, ScriptLanguage},
+	services::{
+		blockchain::{BlockChainClient, ClientPoolTrait},
+		filter::FilterService,
+		trigger::TriggerExecutionServiceTrait,
+	},
+	utils::monitor::{execution::ExecutionResult, MonitorExecutionError},
+};
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::watch;
+use tracing::instrument;
+
+/// Maximum number of blocks fetched from the network at once while replaying
+pub const REPLAY_BATCH_SIZE: u64 = 100;
+
+/// Configuration for replaying a block range
+///
+/// # Arguments
+///
+/// * `network` - The network to replay the blocks of
+/// * `from_block` - The first block to replay
+/// * `to_block` - The last block to replay (inclusive)
+/// * `monitors` - The monitors to replay the blocks against
+/// * `filter_service` - The filter service to use
+/// * `trigger_execution_service` - The trigger execution service to use
+/// * `active_monitors_trigger_scripts` - The active monitors trigger scripts to use
+/// * `fire_triggers` - Whether to send the notifications of the matches
+/// * `client_pool` - The client pool to use
+pub struct ReplayConfig<S: TriggerExecutionServiceTrait, CP: ClientPoolTrait> {
+	pub network: Network,
+	pub from_block: u64,
+	pub to_block: u64,
+	pub monitors: Vec<Monitor>,
+	pub filter_service: Arc<FilterService>,
+	pub trigger_execution_service: Arc<S>,
+	pub active_monitors_trigger_scripts: HashMap<String, (ScriptLanguage, String)>,
+	pub fire_triggers: bool,
+	pub client_pool: Arc<CP>,
+}
+
+/// Replays a block range through the block processing pipeline
+///
+/// Blocks are fetched from the network and filtered against the active monitors of the
+/// network, exactly as the block watcher would. The matches are only sent to the monitors'
+/// triggers when `fire_triggers` is set, in which case trigger conditions are evaluated too.
+///
+/// # Arguments
+/// * `config` - The replay configuration
+///
+/// # Returns
+/// * `ExecutionResult<Vec<ProcessedBlock>>` - The replayed blocks with matches, in block order
+#[instrument(skip_all, fields(network = %config.network.slug))]
+pub async fn replay_blocks<
+	S: TriggerExecutionServiceTrait + Send + Sync + 'static,
+	CP: ClientPoolTrait + Send + Sync + 'static,
+>(
+	config: ReplayConfig<S, CP>,
+) -> ExecutionResult<Vec<ProcessedBlock>> {
+	if config.from_block > config.to_block {
+		return Err(MonitorExecutionError::execution_error(
+			format!(
+				"Replay start block {} is after end block {}",
+				config.from_block, config.to_block
+			),
+			None,
+			None,
+		));
+	}
+
+	let network = config.network;
+	let monitors = config
+		.monitors
+		.into_iter()
+		.filter(|monitor| monitor.networks.contains(&network.slug))
+		.collect::<Vec<_>>();
+	if monitors.is_empty() {
+		return Err(MonitorExecutionError::not_found(
+			format!("No monitors found for network '{}'", network.slug),
+			None,
+			None,
+		));
+	}
+
+	let contract_specs =
+		get_contract_specs(&config.client_pool, &[(network.clone(), monitors.clone())]).await;
+
+	// The shutdown channel is only needed by the handlers, a replay runs to completion
+	let (shutdown_tx, _) = watch::channel(false);
+	let block_handler = create_block_handler(
+		shutdown_tx.clone(),
+		config.filter_service,
+		monitors,
+		config.client_pool.clone(),
+		contract_specs,
+	);
+	let trigger_handler = create_trigger_handler(
+		shutdown_tx,
+		config.trigger_execution_service,
+		config.active_monitors_trigger_scripts,
+		// Blocks are replayed one at a time, their notifications are sent before the next one
+		None,
+		// Replayed blocks are dispatched again on purpose
+		None,
+	);
+
+	let mut processed_blocks = Vec::new();
+	let mut batch_start = config.from_block;
+	loop {
+		let batch_end = config
+			.to_block
+			.min(batch_start.saturating_add(REPLAY_BATCH_SIZE - 1));
+
+		tracing::debug!(from = batch_start, to = batch_end, "Fetching blocks");
+		let blocks = match network.network_type {
+			BlockChainType::EVM => {
+				let client = config
+					.client_pool
+					.get_evm_client(&network)
+					.await
+					.map_err(|e| {
+						MonitorExecutionError::execution_error(
+							format!("Failed to get EVM client: {}", e),
+							None,
+							None,
+						)
+					})?;
+				fetch_blocks(&*client, batch_start, batch_end).await?
+			}
+			BlockChainType::Stellar => {
+				let client = config
+					.client_pool
+					.get_stellar_client(&network)
+					.await
+					.map_err(|e| {
+						MonitorExecutionError::execution_error(
+							format!("Failed to get Stellar client: {}", e),
+							None,
+							None,
+						)
+					})?;
+				fetch_blocks(&*client, batch_start, batch_end).await?
+			}
+			BlockChainType::Midnight | BlockChainType::Solana => {
+				return Err(MonitorExecutionError::execution_error(
+					format!("{:?} network not supported", network.network_type),
+					None,
+					None,
+				));
+			}
+		};
+
+		for block in blocks {
+			let processed_block = block_handler(block, network.clone()).await;
+			if processed_block.processing_results.is_empty() {
+				continue;
+			}
+
+			tracing::debug!(
+				block = processed_block.block_number,
+				matches_count = processed_block.processing_results.len(),
+				"Found matches for block"
+			);
+			if config.fire_triggers {
+				trigger_handler(&processed_block).await.map_err(|e| {
+					MonitorExecutionError::execution_error(
+						format!(
+							"Failed to send notifications for block {}: {}",
+							processed_block.block_number, e
+						),
+						None,
+						None,
+					)
+				})?;
+			}
+			processed_blocks.push(processed_block);
+		}
+
+		if batch_end >= config.to_block {
+			break;
+		}
+		batch_start = batch_end + 1;
+	}
+
+	Ok(processed_blocks)
+}
+
+/// Fetches a range of blocks from a network
+///
+/// # Arguments
+/// * `client` - The client of the network
+/// * `from_block` - The first block to fetch
+/// * `to_block` - The last block to fetch (inclusive)
+///
+/// # Returns
+/// * `ExecutionResult<Vec<BlockType>>` - The fetched blocks or error
+async fn fetch_blocks<C: BlockChainClient>(
+	client: &C,
+	from_block: u64,
+	to_block: u64,
+) -> ExecutionResult<Vec<BlockType>> {
+	client
+		.get_blocks(from_block, Some(to_block))
+		.await
+		.map_err(|e| {
+			MonitorExecutionError::execution_error(
+				format!("Failed to get blocks {} to {}: {}", from_block, to_block, e),
+				None,
+				None,
+			)
+		})
+}
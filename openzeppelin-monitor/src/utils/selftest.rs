@@ -0,0 +1,388 @@
+//! Startup self-test utilities.
+//!
+//! Verifies the connectivity of every configured network and trigger before the service
+//! handles any traffic: networks are asked for their latest block, and trigger endpoints are
+//! probed without sending any notification.
+
+use std::{fmt, time::Duration};
+
+use reqwest::{Client, StatusCode};
+use tokio::{net::TcpStream, time::timeout};
+
+use crate::{
+	models::{BlockChainType, Network, Trigger, TriggerTypeConfig},
+	services::{
+		blockchain::{BlockChainClient, ClientPoolTrait},
+		notification::{NotificationClientPool, SmtpConfig},
+		trigger::load_script,
+	},
+};
+
+/// Base URL of the Telegram Bot API
+pub const TELEGRAM_API_URL: &str = "https://api.telegram.org";
+
+/// Maximum duration of a single self-test check
+pub const SELFTEST_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Kind of component verified by a self-test check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelfTestComponent {
+	Network,
+	Trigger,
+}
+
+impl fmt::Display for SelfTestComponent {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Network => write!(f, "network"),
+			Self::Trigger => write!(f, "trigger"),
+		}
+	}
+}
+
+/// Outcome of a self-test check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelfTestStatus {
+	/// The component is reachable
+	Pass,
+	/// The component is unreachable or misconfigured
+	Fail,
+	/// The component cannot be verified without side effects
+	Skip,
+}
+
+impl fmt::Display for SelfTestStatus {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Pass => write!(f, "PASS"),
+			Self::Fail => write!(f, "FAIL"),
+			Self::Skip => write!(f, "SKIP"),
+		}
+	}
+}
+
+/// Result of verifying a single network or trigger
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelfTestCheck {
+	pub component: SelfTestComponent,
+	pub name: String,
+	pub status: SelfTestStatus,
+	pub detail: String,
+}
+
+impl SelfTestCheck {
+	fn from_result(component: SelfTestComponent, name: &str, result: CheckResult) -> Self {
+		let (status, detail) = match result {
+			Ok(Some(detail)) => (SelfTestStatus::Pass, detail),
+			Ok(None) => (
+				SelfTestStatus::Skip,
+				"no side-effect free probe".to_string(),
+			),
+			Err(detail) => (SelfTestStatus::Fail, detail),
+		};
+		Self {
+			component,
+			name: name.to_string(),
+			status,
+			detail,
+		}
+	}
+}
+
+/// Pass/fail matrix of a self-test run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SelfTestReport {
+	pub checks: Vec<SelfTestCheck>,
+}
+
+impl SelfTestReport {
+	/// Returns true if no check failed
+	pub fn passed(&self) -> bool {
+		self.failures().next().is_none()
+	}
+
+	/// Returns the failed checks
+	pub fn failures(&self) -> impl Iterator<Item = &SelfTestCheck> {
+		self.checks
+			.iter()
+			.filter(|check| check.status == SelfTestStatus::Fail)
+	}
+}
+
+impl fmt::Display for SelfTestReport {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let name_width = self
+			.checks
+			.iter()
+			.map(|check| check.name.len())
+			.chain(std::iter::once("NAME".len()))
+			.max()
+			.unwrap_or_default();
+		writeln!(
+			f,
+			"{:<9} {:<name_width$} {:<6} DETAIL",
+			"COMPONENT", "NAME", "STATUS"
+		)?;
+		for check in &self.checks {
+			writeln!(
+				f,
+				"{:<9} {:<name_width$} {:<6} {}",
+				check.component.to_string(),
+				check.name,
+				check.status.to_string(),
+				check.detail
+			)?;
+		}
+		let failed = self.failures().count();
+		write!(f, "{} check(s), {} failed", self.checks.len(), failed)
+	}
+}
+
+/// Successful detail of a check, `None` if it was skipped, or the failure reason
+type CheckResult = Result<Option<String>, String>;
+
+/// Verifies the connectivity of the given networks and triggers
+///
+/// Checks are run one after the other, each bounded by [`SELFTEST_CHECK_TIMEOUT`]. Networks
+/// and triggers are reported sorted by name.
+///
+/// # Arguments
+/// * `networks` - Networks to fetch the latest block of
+/// * `triggers` - Triggers to probe the endpoints of
+/// * `client_pool` - Pool to get the blockchain clients from
+/// * `telegram_api_url` - Base URL of the Telegram Bot API, see [`TELEGRAM_API_URL`]
+///
+/// # Returns
+/// * `SelfTestReport` - Outcome of every check
+pub async fn run_selftest<CP: ClientPoolTrait>(
+	networks: &[Network],
+	triggers: &[Trigger],
+	client_pool: &CP,
+	telegram_api_url: &str,
+) -> SelfTestReport {
+	let mut report = SelfTestReport::default();
+
+	let mut networks = networks.iter().collect::<Vec<_>>();
+	networks.sort_by(|a, b| a.slug.cmp(&b.slug));
+	for network in networks {
+		let result = with_timeout(check_network(network, client_pool)).await;
+		report.checks.push(SelfTestCheck::from_result(
+			SelfTestComponent::Network,
+			&network.slug,
+			result,
+		));
+	}
+
+	let http_client = Client::new();
+	let mut triggers = triggers.iter().collect::<Vec<_>>();
+	triggers.sort_by(|a, b| a.name.cmp(&b.name));
+	for trigger in triggers {
+		let result = with_timeout(check_trigger(trigger, &http_client, telegram_api_url)).await;
+		report.checks.push(SelfTestCheck::from_result(
+			SelfTestComponent::Trigger,
+			&trigger.name,
+			result,
+		));
+	}
+
+	report
+}
+
+async fn with_timeout(check: impl std::future::Future<Output = CheckResult>) -> CheckResult {
+	timeout(SELFTEST_CHECK_TIMEOUT, check)
+		.await
+		.unwrap_or_else(|_| {
+			Err(format!(
+				"timed out after {}s",
+				SELFTEST_CHECK_TIMEOUT.as_secs()
+			))
+		})
+}
+
+async fn check_network<CP: ClientPoolTrait>(network: &Network, client_pool: &CP) -> CheckResult {
+	let latest_block = match network.network_type {
+		BlockChainType::EVM => {
+			let client = client_pool
+				.get_evm_client(network)
+				.await
+				.map_err(|e| format!("failed to create client: {}", e))?;
+			client.get_latest_block_number().await
+		}
+		BlockChainType::Stellar => {
+			let client = client_pool
+				.get_stellar_client(network)
+				.await
+				.map_err(|e| format!("failed to create client: {}", e))?;
+			client.get_latest_block_number().await
+		}
+		_ => return Ok(None),
+	}
+	.map_err(|e| format!("failed to get latest block: {}", e))?;
+
+	Ok(Some(format!("latest block {}", latest_block)))
+}
+
+async fn check_trigger(
+	trigger: &Trigger,
+	http_client: &Client,
+	telegram_api_url: &str,
+) -> CheckResult {
+	match &trigger.config {
+		TriggerTypeConfig::Slack { slack_url: url, .. }
+		| TriggerTypeConfig::Discord {
+			discord_url: url, ..
+		}
+		| TriggerTypeConfig::Webhook { url, .. } => probe_http(http_client, url.as_ref()).await,
+		TriggerTypeConfig::Telegram { token, .. } => {
+			// getMe authenticates the bot token without sending any message
+			let url = format!(
+				"{}/bot{}/getMe",
+				telegram_api_url.trim_end_matches('/'),
+				token.as_ref()
+			);
+			let response = http_client
+				.get(&url)
+				.send()
+				.await
+				.map_err(|e| format!("request failed: {}", e.without_url()))?;
+			if response.status().is_success() {
+				Ok(Some(format!("HTTP {}", response.status().as_u16())))
+			} else {
+				Err(format!(
+					"bot token rejected with HTTP {}",
+					response.status().as_u16()
+				))
+			}
+		}
+		TriggerTypeConfig::Email {
+			host,
+			port,
+			username,
+			password,
+			..
+		} => {
+			let smtp_config = SmtpConfig {
+				host: host.clone(),
+				port: port.unwrap_or(465),
+				username: username.as_ref().to_string(),
+				password: password.as_ref().to_string(),
+			};
+			let client = NotificationClientPool::new()
+				.get_or_create_smtp_client(&smtp_config)
+				.await
+				.map_err(|e| format!("failed to create SMTP client: {}", e))?;
+			match client.test_connection().await {
+				Ok(true) => Ok(Some(format!(
+					"SMTP {}:{}",
+					smtp_config.host, smtp_config.port
+				))),
+				Ok(false) => Err("SMTP server did not respond".to_string()),
+				Err(e) => Err(format!("SMTP connection failed: {}", e)),
+			}
+		}
+		TriggerTypeConfig::Kafka { brokers, .. } => {
+			for broker in brokers {
+				probe_tcp(broker).await?;
+			}
+			Ok(Some(format!("{} broker(s) reachable", brokers.len())))
+		}
+		TriggerTypeConfig::Nats { server_url, .. } => {
+			let address = server_url
+				.split_once("://")
+				.map_or(server_url.as_str(), |(_, address)| address);
+			probe_tcp(address).await?;
+			Ok(Some(format!("{} reachable", address)))
+		}
+		TriggerTypeConfig::Grpc { endpoint, .. } => {
+			let url = url::Url::parse(endpoint).map_err(|e| format!("invalid endpoint: {}", e))?;
+			let host = url.host_str().ok_or("endpoint has no host")?;
+			let port = url.port_or_known_default().ok_or("endpoint has no port")?;
+			let address = format!("{}:{}", host, port);
+			probe_tcp(&address).await?;
+			Ok(Some(format!("{} reachable", address)))
+		}
+		TriggerTypeConfig::Script { script_path, .. } => {
+			load_script(script_path)
+				.await
+				.map_err(|e| format!("failed to load script: {}", e))?;
+			Ok(Some(format!("script {} loaded", script_path)))
+		}
+		TriggerTypeConfig::Custom { .. } => Ok(None),
+	}
+}
+
+/// Probes an HTTP endpoint with a HEAD request
+///
+/// Any response proves the endpoint reachable, except server errors and responses
+/// rejecting the URL or its credentials.
+async fn probe_http(http_client: &Client, url: &str) -> CheckResult {
+	let response = http_client
+		.head(url)
+		.send()
+		.await
+		.map_err(|e| format!("request failed: {}", e.without_url()))?;
+	let status = response.status();
+	if status.is_server_error()
+		|| matches!(
+			status,
+			StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN | StatusCode::NOT_FOUND
+		) {
+		return Err(format!("endpoint responded with HTTP {}", status.as_u16()));
+	}
+	Ok(Some(format!("HTTP {}", status.as_u16())))
+}
+
+async fn probe_tcp(address: &str) -> Result<(), String> {
+	TcpStream::connect(address)
+		.await
+		.map(|_| ())
+		.map_err(|e| format!("failed to connect to {}: {}", address, e))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn check(status: SelfTestStatus) -> SelfTestCheck {
+		SelfTestCheck {
+			component: SelfTestComponent::Trigger,
+			name: "trigger".to_string(),
+			status,
+			detail: String::new(),
+		}
+	}
+
+	#[test]
+	fn test_report_passed() {
+		let report = SelfTestReport {
+			checks: vec![check(SelfTestStatus::Pass), check(SelfTestStatus::Skip)],
+		};
+		assert!(report.passed());
+
+		let report = SelfTestReport {
+			checks: vec![check(SelfTestStatus::Pass), check(SelfTestStatus::Fail)],
+		};
+		assert!(!report.passed());
+		assert_eq!(report.failures().count(), 1);
+	}
+
+	#[test]
+	fn test_report_display() {
+		let report = SelfTestReport {
+			checks: vec![
+				SelfTestCheck {
+					component: SelfTestComponent::Network,
+					name: "ethereum_mainnet".to_string(),
+					status: SelfTestStatus::Pass,
+					detail: "latest block 100".to_string(),
+				},
+				check(SelfTestStatus::Fail),
+			],
+		};
+		let output = report.to_string();
+		assert!(output.contains("ethereum_mainnet"));
+		assert!(output.contains("PASS"));
+		assert!(output.contains("FAIL"));
+		assert!(output.ends_with("2 check(s), 1 failed"));
+	}
+}
@@ -2,20 +2,38 @@
 //!
 //! - `MonitorBuilder`: Builder for creating test Monitor instances
 
-use crate::models::{
-	AddressWithSpec, ContractSpec, EventCondition, FunctionCondition, MatchConditions, Monitor,
-	ScriptLanguage, TransactionCondition, TransactionStatus, TriggerConditions,
+use crate::{
+	models::{
+		AddressWithSpec, AggregateCondition, ContractSpec, EnrichmentCall, EnrichmentConfig,
+		EventCondition, EventField, FunctionCondition, MatchConditionType, MatchConditions,
+		MatchDedupConfig, Monitor, MonitorTrigger, NotificationThrottleConfig,
+		PercentageChangeCondition, ScriptLanguage, SeverityRule, ThrottleOverflow,
+		TransactionCondition, TransactionStatus, TriggerConditions, TriggerExecutionConfig,
+	},
+	utils::RetryConfig,
 };
+use std::collections::HashMap;
 
 /// Builder for creating test Monitor instances
 pub struct MonitorBuilder {
 	name: String,
 	networks: Vec<String>,
 	paused: bool,
+	paused_until: Option<String>,
 	addresses: Vec<AddressWithSpec>,
 	match_conditions: MatchConditions,
 	trigger_conditions: Vec<TriggerConditions>,
-	triggers: Vec<String>,
+	triggers: Vec<MonitorTrigger>,
+	trigger_tags: Vec<String>,
+	trigger_execution: Option<TriggerExecutionConfig>,
+	notification_throttle: Option<NotificationThrottleConfig>,
+	match_dedup: Option<MatchDedupConfig>,
+	notification_retry: Option<RetryConfig>,
+	enrichment: Option<EnrichmentConfig>,
+	labels: HashMap<String, String>,
+	sample_rate: Option<f64>,
+	expected_match_window_secs: Option<u64>,
+	severity_rules: Vec<SeverityRule>,
 }
 
 impl Default for MonitorBuilder {
@@ -24,17 +42,34 @@ impl Default for MonitorBuilder {
 			name: "TestMonitor".to_string(),
 			networks: vec!["ethereum_mainnet".to_string()],
 			paused: false,
+			paused_until: None,
 			addresses: vec![AddressWithSpec {
 				address: "0x0000000000000000000000000000000000000000".to_string(),
 				contract_spec: None,
+				contract_spec_path: None,
+				additional_specs: vec![],
+				event_signatures: HashMap::new(),
 			}],
 			match_conditions: MatchConditions {
 				functions: vec![],
 				events: vec![],
 				transactions: vec![],
+				operations: vec![],
+				aggregates: vec![],
+				percentage_changes: vec![],
 			},
 			trigger_conditions: vec![],
 			triggers: vec![],
+			trigger_tags: vec![],
+			trigger_execution: None,
+			notification_throttle: None,
+			match_dedup: None,
+			notification_retry: None,
+			enrichment: None,
+			labels: HashMap::new(),
+			sample_rate: None,
+			expected_match_window_secs: None,
+			severity_rules: vec![],
 		}
 	}
 }
@@ -59,10 +94,18 @@ impl MonitorBuilder {
 		self
 	}
 
+	pub fn paused_until(mut self, paused_until: &str) -> Self {
+		self.paused_until = Some(paused_until.to_string());
+		self
+	}
+
 	pub fn address(mut self, address: &str) -> Self {
 		self.addresses = vec![AddressWithSpec {
 			address: address.to_string(),
 			contract_spec: None,
+			contract_spec_path: None,
+			additional_specs: vec![],
+			event_signatures: HashMap::new(),
 		}];
 		self
 	}
@@ -73,6 +116,9 @@ impl MonitorBuilder {
 			.map(|addr| AddressWithSpec {
 				address: addr,
 				contract_spec: None,
+				contract_spec_path: None,
+				additional_specs: vec![],
+				event_signatures: HashMap::new(),
 			})
 			.collect();
 		self
@@ -82,6 +128,9 @@ impl MonitorBuilder {
 		self.addresses.push(AddressWithSpec {
 			address: address.to_string(),
 			contract_spec: None,
+			contract_spec_path: None,
+			additional_specs: vec![],
+			event_signatures: HashMap::new(),
 		});
 		self
 	}
@@ -90,6 +139,9 @@ impl MonitorBuilder {
 		self.addresses = vec![AddressWithSpec {
 			address: address.to_string(),
 			contract_spec: spec,
+			contract_spec_path: None,
+			additional_specs: vec![],
+			event_signatures: HashMap::new(),
 		}];
 		self
 	}
@@ -100,11 +152,24 @@ impl MonitorBuilder {
 			.map(|(addr, spec)| AddressWithSpec {
 				address: addr.to_string(),
 				contract_spec: spec,
+				contract_spec_path: None,
+				additional_specs: vec![],
+				event_signatures: HashMap::new(),
 			})
 			.collect();
 		self
 	}
 
+	/// Labels the logs of the last added address with the given topic0 hash
+	pub fn event_signature(mut self, topic: &str, signature: &str) -> Self {
+		if let Some(address) = self.addresses.last_mut() {
+			address
+				.event_signatures
+				.insert(topic.to_string(), signature.to_string());
+		}
+		self
+	}
+
 	pub fn function(mut self, signature: &str, expression: Option<String>) -> Self {
 		self.match_conditions.functions.push(FunctionCondition {
 			signature: signature.to_string(),
@@ -121,6 +186,43 @@ impl MonitorBuilder {
 		self
 	}
 
+	pub fn aggregate(
+		mut self,
+		signature: &str,
+		field: &str,
+		filter: Option<String>,
+		expression: &str,
+	) -> Self {
+		self.match_conditions.aggregates.push(AggregateCondition {
+			signature: signature.to_string(),
+			field: field.to_string(),
+			filter,
+			expression: expression.to_string(),
+		});
+		self
+	}
+
+	pub fn percentage_change(
+		mut self,
+		from: (&str, &str),
+		to: (&str, &str),
+		expression: &str,
+	) -> Self {
+		let event_field = |(signature, field): (&str, &str)| EventField {
+			signature: signature.to_string(),
+			field: field.to_string(),
+			filter: None,
+		};
+		self.match_conditions
+			.percentage_changes
+			.push(PercentageChangeCondition {
+				from: event_field(from),
+				to: event_field(to),
+				expression: expression.to_string(),
+			});
+		self
+	}
+
 	pub fn transaction(mut self, status: TransactionStatus, expression: Option<String>) -> Self {
 		self.match_conditions
 			.transactions
@@ -138,6 +240,8 @@ impl MonitorBuilder {
 		self.trigger_conditions.push(TriggerConditions {
 			script_path: script_path.to_string(),
 			timeout_ms,
+			script_checksum: None,
+			retry_policy: None,
 			arguments,
 			language,
 		});
@@ -145,7 +249,134 @@ impl MonitorBuilder {
 	}
 
 	pub fn triggers(mut self, triggers: Vec<String>) -> Self {
-		self.triggers = triggers;
+		self.triggers = triggers.into_iter().map(MonitorTrigger::from).collect();
+		self
+	}
+
+	pub fn conditional_trigger(mut self, name: &str, when: Vec<MatchConditionType>) -> Self {
+		self.triggers.push(MonitorTrigger::Conditional {
+			name: name.to_string(),
+			when,
+		});
+		self
+	}
+
+	pub fn trigger_tags(mut self, trigger_tags: Vec<String>) -> Self {
+		self.trigger_tags = trigger_tags;
+		self
+	}
+
+	pub fn labels(mut self, labels: Vec<(&str, &str)>) -> Self {
+		self.labels = labels
+			.into_iter()
+			.map(|(key, value)| (key.to_string(), value.to_string()))
+			.collect();
+		self
+	}
+
+	pub fn sample_rate(mut self, sample_rate: f64) -> Self {
+		self.sample_rate = Some(sample_rate);
+		self
+	}
+
+	pub fn expected_match_window_secs(mut self, window_secs: u64) -> Self {
+		self.expected_match_window_secs = Some(window_secs);
+		self
+	}
+
+	pub fn severity_rule(
+		mut self,
+		severity: &str,
+		signature: Option<&str>,
+		expression: Option<&str>,
+	) -> Self {
+		self.severity_rules.push(SeverityRule {
+			severity: severity.to_string(),
+			signature: signature.map(|signature| signature.to_string()),
+			expression: expression.map(|expression| expression.to_string()),
+		});
+		self
+	}
+
+	pub fn trigger_execution(mut self, ordered: bool, continue_on_error: bool) -> Self {
+		self.trigger_execution = Some(TriggerExecutionConfig {
+			ordered,
+			continue_on_error,
+			trigger_concurrency: None,
+		});
+		self
+	}
+
+	pub fn trigger_concurrency(mut self, trigger_concurrency: usize) -> Self {
+		self.trigger_execution = Some(TriggerExecutionConfig {
+			trigger_concurrency: Some(trigger_concurrency),
+			..self.trigger_execution.unwrap_or_default()
+		});
+		self
+	}
+
+	pub fn notification_throttle(
+		mut self,
+		max_notifications_per_window: u32,
+		window_ms: u64,
+		overflow: ThrottleOverflow,
+	) -> Self {
+		self.notification_throttle = Some(NotificationThrottleConfig {
+			max_notifications_per_window,
+			window_ms,
+			overflow,
+		});
+		self
+	}
+
+	pub fn match_dedup(mut self, window_blocks: u64) -> Self {
+		self.match_dedup = Some(MatchDedupConfig { window_blocks });
+		self
+	}
+
+	pub fn notification_retry(mut self, retry_policy: RetryConfig) -> Self {
+		self.notification_retry = Some(retry_policy);
+		self
+	}
+
+	pub fn enrichment_call(
+		mut self,
+		name: &str,
+		function: &str,
+		output: Option<&str>,
+		address: Option<&str>,
+	) -> Self {
+		self.enrichment
+			.get_or_insert_with(|| EnrichmentConfig {
+				calls: vec![],
+				timeout_ms: 5000,
+			})
+			.calls
+			.push(EnrichmentCall {
+				name: name.to_string(),
+				function: function.to_string(),
+				output: output.map(|output| output.to_string()),
+				address: address.map(|address| address.to_string()),
+				cache_ttl_secs: None,
+			});
+		self
+	}
+
+	pub fn enrichment_cache_ttl_secs(mut self, cache_ttl_secs: u64) -> Self {
+		if let Some(call) = self
+			.enrichment
+			.as_mut()
+			.and_then(|enrichment| enrichment.calls.last_mut())
+		{
+			call.cache_ttl_secs = Some(cache_ttl_secs);
+		}
+		self
+	}
+
+	pub fn enrichment_timeout_ms(mut self, timeout_ms: u64) -> Self {
+		if let Some(enrichment) = &mut self.enrichment {
+			enrichment.timeout_ms = timeout_ms;
+		}
 		self
 	}
 
@@ -159,10 +390,21 @@ impl MonitorBuilder {
 			name: self.name,
 			networks: self.networks,
 			paused: self.paused,
+			paused_until: self.paused_until,
 			addresses: self.addresses,
 			match_conditions: self.match_conditions,
 			trigger_conditions: self.trigger_conditions,
 			triggers: self.triggers,
+			trigger_tags: self.trigger_tags,
+			trigger_execution: self.trigger_execution,
+			notification_throttle: self.notification_throttle,
+			notification_retry: self.notification_retry,
+			match_dedup: self.match_dedup,
+			enrichment: self.enrichment,
+			labels: self.labels,
+			sample_rate: self.sample_rate,
+			expected_match_window_secs: self.expected_match_window_secs,
+			severity_rules: self.severity_rules,
 		}
 	}
 }
@@ -313,6 +555,9 @@ mod tests {
 				}],
 				events: vec![],
 				transactions: vec![],
+				operations: vec![],
+				aggregates: vec![],
+				percentage_changes: vec![],
 			})
 			.build();
 		assert_eq!(monitor.match_conditions.functions.len(), 1);
@@ -1,7 +1,7 @@
 use crate::models::{EVMBaseTransaction, EVMTransaction};
 use alloy::{
 	primitives::{Address, Bytes, B256, U256},
-	rpc::types::Index,
+	rpc::types::{AccessList, AccessListItem, Index},
 };
 
 /// A builder for creating test EVM transactions with default values.
@@ -18,6 +18,7 @@ pub struct TransactionBuilder {
 	gas_limit: Option<U256>,
 	nonce: Option<U256>,
 	transaction_index: Option<Index>,
+	access_list: Option<AccessList>,
 }
 
 impl TransactionBuilder {
@@ -92,6 +93,20 @@ impl TransactionBuilder {
 		self
 	}
 
+	/// Sets the addresses of the access list, without storage keys.
+	pub fn access_list(mut self, addresses: Vec<Address>) -> Self {
+		self.access_list = Some(AccessList(
+			addresses
+				.into_iter()
+				.map(|address| AccessListItem {
+					address,
+					storage_keys: vec![],
+				})
+				.collect(),
+		));
+		self
+	}
+
 	/// Builds the Transaction instance.
 	pub fn build(self) -> EVMTransaction {
 		let default_gas_limit = U256::from(21000);
@@ -108,6 +123,7 @@ impl TransactionBuilder {
 			value: self.value.unwrap_or_default(),
 			input: self.input.unwrap_or_default(),
 			transaction_index: self.transaction_index,
+			access_list: self.access_list,
 			..Default::default()
 		};
 
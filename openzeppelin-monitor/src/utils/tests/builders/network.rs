@@ -2,7 +2,10 @@
 //!
 //! - `NetworkBuilder`: Builder for creating test Network instances
 
-use crate::models::{BlockChainType, Network, RpcUrl, SecretString, SecretValue};
+use crate::models::{
+	BlockChainType, EmitMatchesAfter, Finality, Network, RpcUrl, SecretString, SecretValue,
+};
+use std::collections::HashMap;
 
 /// Builder for creating test Network instances
 pub struct NetworkBuilder {
@@ -15,8 +18,23 @@ pub struct NetworkBuilder {
 	rpc_urls: Vec<RpcUrl>,
 	block_time_ms: u64,
 	confirmation_blocks: u64,
+	finality: Option<Finality>,
 	cron_schedule: String,
 	max_past_blocks: Option<u64>,
+	max_past_blocks_limit: Option<u64>,
+	backfill_concurrency: Option<usize>,
+	max_block_processing_ms: Option<u64>,
+	block_processing_retries: Option<u32>,
+	decode_revert_reasons: Option<bool>,
+	strict_address_checksum: Option<bool>,
+	tx_chunk_size: Option<usize>,
+	max_logs_per_tx: Option<usize>,
+	ens_registry: Option<String>,
+	http2_prior_knowledge: Option<bool>,
+	pool_idle_timeout_ms: Option<u64>,
+	pool_max_idle_per_host: Option<usize>,
+	rpc_method_aliases: Option<HashMap<String, String>>,
+	emit_matches_after: Option<EmitMatchesAfter>,
 }
 
 impl Default for NetworkBuilder {
@@ -32,11 +50,27 @@ impl Default for NetworkBuilder {
 				type_: "rpc".to_string(),
 				url: SecretValue::Plain(SecretString::new("https://test.network".to_string())),
 				weight: 100,
+				headers: HashMap::new(),
 			}],
 			block_time_ms: 1000,
 			confirmation_blocks: 1,
+			finality: None,
 			cron_schedule: "0 */5 * * * *".to_string(),
 			max_past_blocks: Some(10),
+			max_past_blocks_limit: None,
+			backfill_concurrency: None,
+			max_block_processing_ms: None,
+			block_processing_retries: None,
+			decode_revert_reasons: None,
+			strict_address_checksum: None,
+			tx_chunk_size: None,
+			max_logs_per_tx: None,
+			ens_registry: None,
+			http2_prior_knowledge: None,
+			pool_idle_timeout_ms: None,
+			pool_max_idle_per_host: None,
+			rpc_method_aliases: None,
+			emit_matches_after: None,
 		}
 	}
 }
@@ -81,6 +115,7 @@ impl NetworkBuilder {
 			type_: "rpc".to_string(),
 			url: SecretValue::Plain(SecretString::new(url.to_string())),
 			weight: 100,
+			headers: HashMap::new(),
 		}];
 		self
 	}
@@ -92,6 +127,7 @@ impl NetworkBuilder {
 				type_: "rpc".to_string(),
 				url: SecretValue::Plain(SecretString::new(url.to_string())),
 				weight: 100,
+				headers: HashMap::new(),
 			})
 			.collect();
 		self
@@ -102,6 +138,7 @@ impl NetworkBuilder {
 			type_: type_.to_string(),
 			url: SecretValue::Plain(SecretString::new(url.to_string())),
 			weight,
+			headers: HashMap::new(),
 		});
 		self
 	}
@@ -111,10 +148,18 @@ impl NetworkBuilder {
 			type_: type_.to_string(),
 			url,
 			weight,
+			headers: HashMap::new(),
 		});
 		self
 	}
 
+	pub fn add_rpc_url_header(mut self, name: &str, value: SecretValue) -> Self {
+		if let Some(rpc_url) = self.rpc_urls.last_mut() {
+			rpc_url.headers.insert(name.to_string(), value);
+		}
+		self
+	}
+
 	pub fn clear_rpc_urls(mut self) -> Self {
 		self.rpc_urls.clear();
 		self
@@ -130,6 +175,11 @@ impl NetworkBuilder {
 		self
 	}
 
+	pub fn finality(mut self, finality: Finality) -> Self {
+		self.finality = Some(finality);
+		self
+	}
+
 	pub fn cron_schedule(mut self, schedule: &str) -> Self {
 		self.cron_schedule = schedule.to_string();
 		self
@@ -140,6 +190,78 @@ impl NetworkBuilder {
 		self
 	}
 
+	pub fn max_past_blocks_limit(mut self, limit: u64) -> Self {
+		self.max_past_blocks_limit = Some(limit);
+		self
+	}
+
+	pub fn backfill_concurrency(mut self, concurrency: usize) -> Self {
+		self.backfill_concurrency = Some(concurrency);
+		self
+	}
+
+	pub fn max_block_processing_ms(mut self, max_block_processing_ms: u64) -> Self {
+		self.max_block_processing_ms = Some(max_block_processing_ms);
+		self
+	}
+
+	pub fn block_processing_retries(mut self, retries: u32) -> Self {
+		self.block_processing_retries = Some(retries);
+		self
+	}
+
+	pub fn decode_revert_reasons(mut self, decode_revert_reasons: bool) -> Self {
+		self.decode_revert_reasons = Some(decode_revert_reasons);
+		self
+	}
+
+	pub fn strict_address_checksum(mut self, strict_address_checksum: bool) -> Self {
+		self.strict_address_checksum = Some(strict_address_checksum);
+		self
+	}
+
+	pub fn tx_chunk_size(mut self, tx_chunk_size: usize) -> Self {
+		self.tx_chunk_size = Some(tx_chunk_size);
+		self
+	}
+
+	pub fn max_logs_per_tx(mut self, max_logs_per_tx: usize) -> Self {
+		self.max_logs_per_tx = Some(max_logs_per_tx);
+		self
+	}
+
+	pub fn ens_registry(mut self, ens_registry: &str) -> Self {
+		self.ens_registry = Some(ens_registry.to_string());
+		self
+	}
+
+	pub fn http2_prior_knowledge(mut self, http2_prior_knowledge: bool) -> Self {
+		self.http2_prior_knowledge = Some(http2_prior_knowledge);
+		self
+	}
+
+	pub fn pool_idle_timeout_ms(mut self, pool_idle_timeout_ms: u64) -> Self {
+		self.pool_idle_timeout_ms = Some(pool_idle_timeout_ms);
+		self
+	}
+
+	pub fn pool_max_idle_per_host(mut self, pool_max_idle_per_host: usize) -> Self {
+		self.pool_max_idle_per_host = Some(pool_max_idle_per_host);
+		self
+	}
+
+	pub fn rpc_method_alias(mut self, method: &str, alias: &str) -> Self {
+		self.rpc_method_aliases
+			.get_or_insert_with(HashMap::new)
+			.insert(method.to_string(), alias.to_string());
+		self
+	}
+
+	pub fn emit_matches_after(mut self, emit_matches_after: EmitMatchesAfter) -> Self {
+		self.emit_matches_after = Some(emit_matches_after);
+		self
+	}
+
 	pub fn build(self) -> Network {
 		Network {
 			name: self.name,
@@ -151,8 +273,23 @@ impl NetworkBuilder {
 			rpc_urls: self.rpc_urls,
 			block_time_ms: self.block_time_ms,
 			confirmation_blocks: self.confirmation_blocks,
+			finality: self.finality,
 			cron_schedule: self.cron_schedule,
 			max_past_blocks: self.max_past_blocks,
+			max_past_blocks_limit: self.max_past_blocks_limit,
+			backfill_concurrency: self.backfill_concurrency,
+			max_block_processing_ms: self.max_block_processing_ms,
+			block_processing_retries: self.block_processing_retries,
+			decode_revert_reasons: self.decode_revert_reasons,
+			strict_address_checksum: self.strict_address_checksum,
+			tx_chunk_size: self.tx_chunk_size,
+			max_logs_per_tx: self.max_logs_per_tx,
+			ens_registry: self.ens_registry,
+			http2_prior_knowledge: self.http2_prior_knowledge,
+			pool_idle_timeout_ms: self.pool_idle_timeout_ms,
+			pool_max_idle_per_host: self.pool_max_idle_per_host,
+			rpc_method_aliases: self.rpc_method_aliases,
+			emit_matches_after: self.emit_matches_after,
 		}
 	}
 }
@@ -4,18 +4,24 @@
 
 use crate::{
 	models::{
-		NotificationMessage, ScriptLanguage, SecretString, SecretValue, Trigger, TriggerType,
-		TriggerTypeConfig,
+		DigestConfig, DiscordEmbedConfig, EmailContentType, NotificationMessage, ScriptLanguage,
+		SecretString, SecretValue, Trigger, TriggerType, TriggerTypeConfig, WebhookPayloadFields,
 	},
 	utils::RetryConfig,
 };
 use email_address::EmailAddress;
+use std::collections::HashMap;
 
 /// Builder for creating test Trigger instances
 pub struct TriggerBuilder {
 	name: String,
 	trigger_type: TriggerType,
 	config: TriggerTypeConfig,
+	tags: Vec<String>,
+	labels: HashMap<String, String>,
+	digest: Option<DigestConfig>,
+	timezone: Option<String>,
+	templates: HashMap<String, NotificationMessage>,
 }
 
 impl Default for TriggerBuilder {
@@ -35,7 +41,14 @@ impl Default for TriggerBuilder {
 					body: "Test message".to_string(),
 				},
 				retry_policy: RetryConfig::default(),
+				max_concurrent_notifications: None,
+				payload_fields: None,
 			},
+			tags: vec![],
+			labels: HashMap::new(),
+			digest: None,
+			timezone: None,
+			templates: HashMap::new(),
 		}
 	}
 }
@@ -55,6 +68,19 @@ impl TriggerBuilder {
 		self
 	}
 
+	pub fn tags(mut self, tags: Vec<&str>) -> Self {
+		self.tags = tags.into_iter().map(|tag| tag.to_string()).collect();
+		self
+	}
+
+	pub fn labels(mut self, labels: Vec<(&str, &str)>) -> Self {
+		self.labels = labels
+			.into_iter()
+			.map(|(key, value)| (key.to_string(), value.to_string()))
+			.collect();
+		self
+	}
+
 	pub fn webhook(mut self, url: &str) -> Self {
 		self.trigger_type = TriggerType::Webhook;
 		self.config = TriggerTypeConfig::Webhook {
@@ -67,6 +93,8 @@ impl TriggerBuilder {
 				body: "Test message".to_string(),
 			},
 			retry_policy: RetryConfig::default(),
+			max_concurrent_notifications: None,
+			payload_fields: None,
 		};
 		self
 	}
@@ -80,6 +108,8 @@ impl TriggerBuilder {
 				body: "Test message".to_string(),
 			},
 			retry_policy: RetryConfig::default(),
+			max_concurrent_notifications: None,
+			max_message_length: None,
 		};
 		self
 	}
@@ -93,6 +123,9 @@ impl TriggerBuilder {
 				body: "Test message".to_string(),
 			},
 			retry_policy: RetryConfig::default(),
+			max_concurrent_notifications: None,
+			max_message_length: None,
+			embed: None,
 		};
 		self
 	}
@@ -108,6 +141,8 @@ impl TriggerBuilder {
 				body: "Test message".to_string(),
 			},
 			retry_policy: RetryConfig::default(),
+			max_concurrent_notifications: None,
+			max_message_length: None,
 		};
 		self
 	}
@@ -126,6 +161,8 @@ impl TriggerBuilder {
 			arguments: None,
 			language,
 			timeout_ms: 1000,
+			script_checksum: None,
+			retry_policy: None,
 		};
 		self
 	}
@@ -144,21 +181,160 @@ impl TriggerBuilder {
 		self
 	}
 
+	pub fn kafka(mut self, brokers: Vec<&str>, topic: &str) -> Self {
+		self.trigger_type = TriggerType::Kafka;
+		self.config = TriggerTypeConfig::Kafka {
+			brokers: brokers.into_iter().map(str::to_string).collect(),
+			topic: topic.to_string(),
+			key_template: None,
+			message: None,
+			retry_policy: RetryConfig::default(),
+		};
+		self
+	}
+
+	pub fn kafka_key_template(mut self, key_template: &str) -> Self {
+		if let TriggerTypeConfig::Kafka {
+			key_template: k, ..
+		} = &mut self.config
+		{
+			*k = Some(key_template.to_string());
+		}
+		self
+	}
+
+	pub fn nats(mut self, server_url: &str, subject: &str) -> Self {
+		self.trigger_type = TriggerType::Nats;
+		self.config = TriggerTypeConfig::Nats {
+			server_url: server_url.to_string(),
+			subject: subject.to_string(),
+			stream: None,
+			message: None,
+			retry_policy: RetryConfig::default(),
+		};
+		self
+	}
+
+	pub fn nats_stream(mut self, stream: &str) -> Self {
+		if let TriggerTypeConfig::Nats { stream: s, .. } = &mut self.config {
+			*s = Some(stream.to_string());
+		}
+		self
+	}
+
+	pub fn grpc(mut self, endpoint: &str) -> Self {
+		self.trigger_type = TriggerType::Grpc;
+		self.config = TriggerTypeConfig::Grpc {
+			endpoint: endpoint.to_string(),
+			metadata: HashMap::new(),
+			message: None,
+			timeout_ms: 5000,
+			retry_policy: RetryConfig::default(),
+		};
+		self
+	}
+
+	pub fn grpc_metadata(mut self, key: &str, value: &str) -> Self {
+		if let TriggerTypeConfig::Grpc { metadata, .. } = &mut self.config {
+			metadata.insert(key.to_string(), value.to_string());
+		}
+		self
+	}
+
+	pub fn grpc_timeout_ms(mut self, timeout_ms: u64) -> Self {
+		if let TriggerTypeConfig::Grpc { timeout_ms: t, .. } = &mut self.config {
+			*t = timeout_ms;
+		}
+		self
+	}
+
+	pub fn custom(mut self, notifier: &str) -> Self {
+		self.trigger_type = TriggerType::Custom(notifier.to_string());
+		self.config = TriggerTypeConfig::Custom {
+			message: NotificationMessage {
+				title: "Alert".to_string(),
+				body: "Test message".to_string(),
+			},
+			settings: HashMap::new(),
+		};
+		self
+	}
+
 	pub fn message(mut self, title: &str, body: &str) -> Self {
 		match &mut self.config {
 			TriggerTypeConfig::Webhook { message, .. }
 			| TriggerTypeConfig::Slack { message, .. }
 			| TriggerTypeConfig::Discord { message, .. }
 			| TriggerTypeConfig::Telegram { message, .. }
-			| TriggerTypeConfig::Email { message, .. } => {
+			| TriggerTypeConfig::Email { message, .. }
+			| TriggerTypeConfig::Custom { message, .. } => {
 				message.title = title.to_string();
 				message.body = body.to_string();
 			}
+			TriggerTypeConfig::Kafka { message, .. }
+			| TriggerTypeConfig::Nats { message, .. }
+			| TriggerTypeConfig::Grpc { message, .. } => {
+				*message = Some(NotificationMessage {
+					title: title.to_string(),
+					body: body.to_string(),
+				});
+			}
 			_ => {}
 		}
 		self
 	}
 
+	pub fn max_concurrent_notifications(mut self, max_concurrent: usize) -> Self {
+		match &mut self.config {
+			TriggerTypeConfig::Webhook {
+				max_concurrent_notifications,
+				..
+			}
+			| TriggerTypeConfig::Slack {
+				max_concurrent_notifications,
+				..
+			}
+			| TriggerTypeConfig::Discord {
+				max_concurrent_notifications,
+				..
+			}
+			| TriggerTypeConfig::Telegram {
+				max_concurrent_notifications,
+				..
+			} => *max_concurrent_notifications = Some(max_concurrent),
+			_ => {}
+		}
+		self
+	}
+
+	pub fn max_message_length(mut self, max_length: usize) -> Self {
+		match &mut self.config {
+			TriggerTypeConfig::Slack {
+				max_message_length, ..
+			}
+			| TriggerTypeConfig::Discord {
+				max_message_length, ..
+			}
+			| TriggerTypeConfig::Telegram {
+				max_message_length, ..
+			} => *max_message_length = Some(max_length),
+			_ => {}
+		}
+		self
+	}
+
+	pub fn retry_policy(mut self, retry_policy: RetryConfig) -> Self {
+		self.config.set_retry_policy(retry_policy);
+		self
+	}
+
+	pub fn discord_embed(mut self, discord_embed: DiscordEmbedConfig) -> Self {
+		if let TriggerTypeConfig::Discord { embed, .. } = &mut self.config {
+			*embed = Some(discord_embed);
+		}
+		self
+	}
+
 	pub fn trigger_type(mut self, trigger_type: TriggerType) -> Self {
 		self.trigger_type = trigger_type;
 		self
@@ -187,6 +363,11 @@ impl TriggerBuilder {
 				.into_iter()
 				.map(EmailAddress::new_unchecked)
 				.collect(),
+			cc: vec![],
+			bcc: vec![],
+			reply_to: None,
+			content_type: EmailContentType::default(),
+			attachments: None,
 			retry_policy: RetryConfig::default(),
 		};
 		self
@@ -206,6 +387,44 @@ impl TriggerBuilder {
 		self
 	}
 
+	pub fn email_content_type(mut self, content_type: EmailContentType) -> Self {
+		if let TriggerTypeConfig::Email {
+			content_type: c, ..
+		} = &mut self.config
+		{
+			*c = content_type;
+		}
+		self
+	}
+
+	pub fn email_attachments(mut self, attachments: Vec<&str>) -> Self {
+		if let TriggerTypeConfig::Email { attachments: a, .. } = &mut self.config {
+			*a = Some(attachments.into_iter().map(str::to_string).collect());
+		}
+		self
+	}
+
+	pub fn email_cc(mut self, cc: Vec<&str>) -> Self {
+		if let TriggerTypeConfig::Email { cc: c, .. } = &mut self.config {
+			*c = cc.into_iter().map(EmailAddress::new_unchecked).collect();
+		}
+		self
+	}
+
+	pub fn email_bcc(mut self, bcc: Vec<&str>) -> Self {
+		if let TriggerTypeConfig::Email { bcc: b, .. } = &mut self.config {
+			*b = bcc.into_iter().map(EmailAddress::new_unchecked).collect();
+		}
+		self
+	}
+
+	pub fn email_reply_to(mut self, reply_to: &str) -> Self {
+		if let TriggerTypeConfig::Email { reply_to: r, .. } = &mut self.config {
+			*r = Some(EmailAddress::new_unchecked(reply_to));
+		}
+		self
+	}
+
 	pub fn email_username(mut self, username: SecretValue) -> Self {
 		if let TriggerTypeConfig::Email { username: u, .. } = &mut self.config {
 			*u = username;
@@ -234,13 +453,23 @@ impl TriggerBuilder {
 		self
 	}
 
-	pub fn webhook_headers(mut self, headers: std::collections::HashMap<String, String>) -> Self {
+	pub fn webhook_headers(mut self, headers: HashMap<String, String>) -> Self {
 		if let TriggerTypeConfig::Webhook { headers: h, .. } = &mut self.config {
 			*h = Some(headers);
 		}
 		self
 	}
 
+	pub fn webhook_payload_fields(mut self, include: Vec<&str>, exclude: Vec<&str>) -> Self {
+		if let TriggerTypeConfig::Webhook { payload_fields, .. } = &mut self.config {
+			*payload_fields = Some(WebhookPayloadFields {
+				include: include.into_iter().map(|field| field.to_string()).collect(),
+				exclude: exclude.into_iter().map(|field| field.to_string()).collect(),
+			});
+		}
+		self
+	}
+
 	pub fn url(mut self, url: SecretValue) -> Self {
 		self.config = match self.config {
 			TriggerTypeConfig::Webhook {
@@ -250,6 +479,8 @@ impl TriggerBuilder {
 				secret,
 				message,
 				retry_policy,
+				max_concurrent_notifications,
+				payload_fields,
 			} => TriggerTypeConfig::Webhook {
 				url,
 				method,
@@ -257,35 +488,76 @@ impl TriggerBuilder {
 				secret,
 				message,
 				retry_policy,
+				max_concurrent_notifications,
+				payload_fields,
 			},
 			TriggerTypeConfig::Discord {
 				discord_url: _,
 				message,
 				retry_policy,
+				max_concurrent_notifications,
+				max_message_length,
+				embed,
 			} => TriggerTypeConfig::Discord {
 				discord_url: url,
 				message,
 				retry_policy,
+				max_concurrent_notifications,
+				max_message_length,
+				embed,
 			},
 			TriggerTypeConfig::Slack {
 				slack_url: _,
 				message,
 				retry_policy,
+				max_concurrent_notifications,
+				max_message_length,
 			} => TriggerTypeConfig::Slack {
 				slack_url: url,
 				message,
 				retry_policy,
+				max_concurrent_notifications,
+				max_message_length,
 			},
 			config => config,
 		};
 		self
 	}
 
+	pub fn digest(mut self, digest_interval_ms: u64, max_matches: usize) -> Self {
+		self.digest = Some(DigestConfig {
+			digest_interval_ms,
+			max_matches,
+		});
+		self
+	}
+
+	pub fn timezone(mut self, timezone: &str) -> Self {
+		self.timezone = Some(timezone.to_string());
+		self
+	}
+
+	pub fn template(mut self, name: &str, title: &str, body: &str) -> Self {
+		self.templates.insert(
+			name.to_string(),
+			NotificationMessage {
+				title: title.to_string(),
+				body: body.to_string(),
+			},
+		);
+		self
+	}
+
 	pub fn build(self) -> Trigger {
 		Trigger {
 			name: self.name,
 			trigger_type: self.trigger_type,
 			config: self.config,
+			tags: self.tags,
+			labels: self.labels,
+			digest: self.digest,
+			timezone: self.timezone,
+			templates: self.templates,
 		}
 	}
 }
@@ -326,6 +598,8 @@ mod tests {
 					body: "Test message".to_string(),
 				},
 				retry_policy: RetryConfig::default(),
+				max_concurrent_notifications: None,
+				payload_fields: None,
 			})
 			.build();
 
@@ -389,6 +663,8 @@ mod tests {
 				headers: h,
 				message,
 				retry_policy: _,
+				max_concurrent_notifications: _,
+				payload_fields: _,
 			} => {
 				assert_eq!(url.as_ref().to_string(), "https://webhook.example.com");
 				assert_eq!(method, Some("POST".to_string()));
@@ -418,6 +694,8 @@ mod tests {
 				slack_url,
 				message,
 				retry_policy: _,
+				max_concurrent_notifications: _,
+				max_message_length: _,
 			} => {
 				assert_eq!(slack_url.as_ref().to_string(), "https://slack.webhook.com");
 				assert_eq!(message.title, "Alert");
@@ -441,6 +719,9 @@ mod tests {
 				discord_url,
 				message,
 				retry_policy: _,
+				max_concurrent_notifications: _,
+				max_message_length: _,
+				embed: _,
 			} => {
 				assert_eq!(
 					discord_url.as_ref().to_string(),
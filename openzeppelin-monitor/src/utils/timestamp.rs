@@ -0,0 +1,136 @@
+//! Timestamp formatting utilities for notification variables.
+//!
+//! Block timestamps are exposed to notification templates both as Unix seconds
+//! (`block.timestamp`) and as an RFC 3339 string (`block.timestamp_iso`) in the timezone of
+//! the trigger, or the global `NOTIFICATION_TIMEZONE`, which defaults to UTC.
+
+use chrono::{DateTime, FixedOffset, SecondsFormat};
+use std::collections::HashMap;
+
+/// Environment variable setting the default timezone of notification timestamps
+pub const NOTIFICATION_TIMEZONE_ENV: &str = "NOTIFICATION_TIMEZONE";
+
+/// Variable holding the block timestamp in Unix seconds
+pub const BLOCK_TIMESTAMP_VARIABLE: &str = "block.timestamp";
+
+/// Variable holding the block timestamp as an RFC 3339 string
+pub const BLOCK_TIMESTAMP_ISO_VARIABLE: &str = "block.timestamp_iso";
+
+/// Parses a timezone given as `UTC` or as a fixed offset such as `+02:00` or `-05:30`
+///
+/// # Returns
+/// * `Option<FixedOffset>` - The offset of the timezone, or `None` if it is invalid
+pub fn parse_timezone(timezone: &str) -> Option<FixedOffset> {
+	let timezone = timezone.trim();
+	if timezone.eq_ignore_ascii_case("utc") || timezone == "Z" {
+		return FixedOffset::east_opt(0);
+	}
+	timezone.parse::<FixedOffset>().ok()
+}
+
+/// Returns the default timezone of notification timestamps
+///
+/// Reads `NOTIFICATION_TIMEZONE`, falling back to UTC if it is unset or invalid.
+pub fn default_timezone() -> FixedOffset {
+	let utc = FixedOffset::east_opt(0).expect("UTC offset is valid");
+	match std::env::var(NOTIFICATION_TIMEZONE_ENV) {
+		Ok(timezone) => parse_timezone(&timezone).unwrap_or_else(|| {
+			tracing::warn!(
+				"Invalid {} '{}', formatting timestamps in UTC",
+				NOTIFICATION_TIMEZONE_ENV,
+				timezone
+			);
+			utc
+		}),
+		Err(_) => utc,
+	}
+}
+
+/// Formats Unix seconds as an RFC 3339 string in the given timezone
+///
+/// UTC timestamps end with `Z`, e.g. `2023-11-14T22:13:20Z`, others with their offset, e.g.
+/// `2023-11-15T00:13:20+02:00`.
+///
+/// # Returns
+/// * `Option<String>` - The formatted timestamp, or `None` if it is out of range
+pub fn format_timestamp(unix_seconds: i64, timezone: FixedOffset) -> Option<String> {
+	DateTime::from_timestamp(unix_seconds, 0).map(|timestamp| {
+		timestamp
+			.with_timezone(&timezone)
+			.to_rfc3339_opts(SecondsFormat::Secs, true)
+	})
+}
+
+/// Adds the block timestamp variables of a match to notification variables
+///
+/// # Arguments
+/// * `variables` - Variables to add `block.timestamp` and `block.timestamp_iso` to
+/// * `unix_seconds` - Timestamp of the block
+/// * `timezone` - Timezone to format `block.timestamp_iso` in
+pub fn insert_timestamp_variables(
+	variables: &mut HashMap<String, String>,
+	unix_seconds: i64,
+	timezone: FixedOffset,
+) {
+	variables.insert(
+		BLOCK_TIMESTAMP_VARIABLE.to_string(),
+		unix_seconds.to_string(),
+	);
+	if let Some(timestamp_iso) = format_timestamp(unix_seconds, timezone) {
+		variables.insert(BLOCK_TIMESTAMP_ISO_VARIABLE.to_string(), timestamp_iso);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_timezone() {
+		assert_eq!(parse_timezone("UTC"), FixedOffset::east_opt(0));
+		assert_eq!(parse_timezone("utc"), FixedOffset::east_opt(0));
+		assert_eq!(parse_timezone("Z"), FixedOffset::east_opt(0));
+		assert_eq!(parse_timezone("+02:00"), FixedOffset::east_opt(2 * 3600));
+		assert_eq!(
+			parse_timezone("-05:30"),
+			FixedOffset::west_opt(5 * 3600 + 30 * 60)
+		);
+		assert_eq!(parse_timezone("Europe/Paris"), None);
+		assert_eq!(parse_timezone(""), None);
+	}
+
+	#[test]
+	fn test_format_timestamp() {
+		assert_eq!(
+			format_timestamp(1_700_000_000, parse_timezone("UTC").unwrap()),
+			Some("2023-11-14T22:13:20Z".to_string())
+		);
+		assert_eq!(
+			format_timestamp(1_700_000_000, parse_timezone("+02:00").unwrap()),
+			Some("2023-11-15T00:13:20+02:00".to_string())
+		);
+		assert_eq!(
+			format_timestamp(1_700_000_000, parse_timezone("-05:30").unwrap()),
+			Some("2023-11-14T16:43:20-05:30".to_string())
+		);
+		assert_eq!(
+			format_timestamp(i64::MAX, parse_timezone("UTC").unwrap()),
+			None
+		);
+	}
+
+	#[test]
+	fn test_insert_timestamp_variables() {
+		let mut variables = HashMap::new();
+		insert_timestamp_variables(
+			&mut variables,
+			1_700_000_000,
+			parse_timezone("+09:00").unwrap(),
+		);
+		assert_eq!(variables[BLOCK_TIMESTAMP_VARIABLE], "1700000000");
+		assert_eq!(
+			variables[BLOCK_TIMESTAMP_ISO_VARIABLE],
+			"2023-11-15T07:13:20+09:00"
+		);
+	}
+}
@@ -0,0 +1,286 @@
+//! Trigger utilities for verifying trigger configurations.
+//!
+//! Provides functionality to send a sample notification through a configured trigger,
+//! so that credentials and message formatting can be checked before deployment, and to
+//! re-send the failed notifications recorded in a dead-letter store.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+	models::{
+		EVMMonitorMatch, EVMTransaction, MatchConditions, Monitor, MonitorMatch, ScriptLanguage,
+		Trigger, TriggerTypeConfig,
+	},
+	repositories::{TriggerRepositoryTrait, TriggerService},
+	services::{
+		notification::{DeadLetterStore, NotificationError, NotificationService},
+		trigger::{load_script, verify_script_checksum},
+	},
+	utils::normalize_string,
+};
+
+/// Name of the synthetic monitor used when testing a trigger
+pub const TEST_TRIGGER_MONITOR_NAME: &str = "Test Trigger";
+
+/// Network slug of the synthetic match used when testing a trigger
+pub const TEST_TRIGGER_NETWORK_SLUG: &str = "test_network";
+
+/// Sends a sample notification through a trigger
+///
+/// The trigger is loaded from the trigger service and executed once with a synthetic
+/// EVM monitor match and sample template variables (see [`sample_variables`]).
+///
+/// # Arguments
+/// * `trigger_name` - Name of the trigger to test
+/// * `trigger_service` - Service to load the trigger from
+/// * `notification_service` - Service to send the notification with
+///
+/// # Returns
+/// * `Result<(), NotificationError>` - Success or error
+pub async fn test_trigger<T: TriggerRepositoryTrait>(
+	trigger_name: &str,
+	trigger_service: &TriggerService<T>,
+	notification_service: &NotificationService,
+) -> Result<(), NotificationError> {
+	let trigger = trigger_service.get(trigger_name).ok_or_else(|| {
+		NotificationError::config_error(format!("Trigger '{}' not found", trigger_name), None, None)
+	})?;
+
+	let monitor_match = MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+		monitor: Monitor {
+			name: TEST_TRIGGER_MONITOR_NAME.to_string(),
+			networks: vec![TEST_TRIGGER_NETWORK_SLUG.to_string()],
+			triggers: vec![trigger_name.into()],
+			..Default::default()
+		},
+		transaction: EVMTransaction::default(),
+		receipt: None,
+		revert_reason: None,
+		logs: None,
+		block_timestamp: None,
+		network_slug: TEST_TRIGGER_NETWORK_SLUG.to_string(),
+		matched_on: MatchConditions::default(),
+		matched_on_args: None,
+		enrichment: HashMap::new(),
+	}));
+
+	let trigger_scripts = load_trigger_scripts(&trigger, TEST_TRIGGER_MONITOR_NAME).await?;
+
+	notification_service
+		.execute(
+			&trigger,
+			&sample_variables(&monitor_match),
+			&monitor_match,
+			&trigger_scripts,
+		)
+		.await
+}
+
+/// Loads the script of a script trigger, keyed as expected by the notification service
+///
+/// Script triggers look up their script content by monitor name and script path. Scripts
+/// with a `script_checksum` are verified against it.
+///
+/// # Arguments
+/// * `trigger` - Trigger to load the script of
+/// * `monitor_name` - Name of the monitor whose match is notified
+///
+/// # Returns
+/// * `Result<HashMap<String, (ScriptLanguage, String)>, NotificationError>` - The script, or an
+///   empty map if the trigger is not a script trigger
+async fn load_trigger_scripts(
+	trigger: &Trigger,
+	monitor_name: &str,
+) -> Result<HashMap<String, (ScriptLanguage, String)>, NotificationError> {
+	let mut trigger_scripts = HashMap::new();
+	if let TriggerTypeConfig::Script {
+		language,
+		script_path,
+		script_checksum,
+		..
+	} = &trigger.config
+	{
+		let content = load_script(script_path)
+			.await
+			.map_err(|e| NotificationError::config_error(e.to_string(), Some(Box::new(e)), None))?;
+		if let Some(checksum) = script_checksum {
+			verify_script_checksum(script_path, &content, checksum).map_err(|e| {
+				NotificationError::config_error(e.to_string(), Some(Box::new(e)), None)
+			})?;
+		}
+		trigger_scripts.insert(
+			format!("{}|{}", normalize_string(monitor_name), script_path),
+			(language.clone(), content),
+		);
+	}
+	Ok(trigger_scripts)
+}
+
+/// Outcome of draining a dead-letter store
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DrainSummary {
+	/// Notifications sent successfully and removed from the store
+	pub delivered: usize,
+	/// Notifications that failed again and were kept in the store
+	pub failed: usize,
+	/// Notifications kept in the store because their trigger no longer exists
+	pub skipped: usize,
+	/// Notifications left in the store after the drain
+	pub remaining: usize,
+}
+
+/// Re-sends the failed notifications recorded in a dead-letter store
+///
+/// Records are re-sent oldest first through the current configuration of their trigger.
+/// Notifications sent successfully are removed from the store, the others are kept.
+///
+/// # Arguments
+/// * `store` - Dead-letter store to drain
+/// * `trigger_service` - Service to load the triggers from
+/// * `notification_service` - Service to send the notifications with
+/// * `trigger_slug` - Only re-send the notifications of this trigger, if set
+/// * `max` - Maximum number of notifications to re-send, if set
+///
+/// # Returns
+/// * `Result<DrainSummary, NotificationError>` - Outcome of the drain, or an error if the
+///   store cannot be read or updated
+pub async fn drain_dead_letter<T: TriggerRepositoryTrait>(
+	store: &DeadLetterStore,
+	trigger_service: &TriggerService<T>,
+	notification_service: &NotificationService,
+	trigger_slug: Option<&str>,
+	max: Option<usize>,
+) -> Result<DrainSummary, NotificationError> {
+	let records = store
+		.load()
+		.await
+		.map_err(|e| NotificationError::internal_error(e.to_string(), Some(e.into()), None))?;
+	let total = records.len();
+
+	let mut summary = DrainSummary::default();
+	let mut delivered = HashSet::new();
+	for record in records
+		.into_iter()
+		.filter(|record| trigger_slug.is_none_or(|slug| record.trigger == slug))
+		.take(max.unwrap_or(usize::MAX))
+	{
+		let Some(trigger) = trigger_service.get(&record.trigger) else {
+			tracing::warn!(
+				trigger = %record.trigger,
+				"Trigger of dead-letter record {} not found, keeping it",
+				record.id
+			);
+			summary.skipped += 1;
+			continue;
+		};
+
+		let monitor_name = match &record.monitor_match {
+			MonitorMatch::EVM(evm_match) => &evm_match.monitor.name,
+			MonitorMatch::Stellar(stellar_match) => &stellar_match.monitor.name,
+		};
+		let result = match load_trigger_scripts(&trigger, monitor_name).await {
+			Ok(trigger_scripts) => {
+				notification_service
+					.execute(
+						&trigger,
+						&record.variables,
+						&record.monitor_match,
+						&trigger_scripts,
+					)
+					.await
+			}
+			Err(e) => Err(e),
+		};
+		match result {
+			Ok(()) => {
+				summary.delivered += 1;
+				delivered.insert(record.id);
+			}
+			Err(e) => {
+				tracing::warn!(
+					trigger = %record.trigger,
+					"Failed to re-send dead-letter record {}: [{}] {}",
+					record.id,
+					e.code(),
+					e
+				);
+				summary.failed += 1;
+			}
+		}
+	}
+
+	store
+		.remove(&delivered)
+		.await
+		.map_err(|e| NotificationError::internal_error(e.to_string(), Some(e.into()), None))?;
+	summary.remaining = total - summary.delivered;
+
+	Ok(summary)
+}
+
+/// Builds sample template variables for a synthetic monitor match
+///
+/// The variables mirror those produced for a matched ERC20 `Transfer` event, so that
+/// the most common templates render with realistic values.
+///
+/// # Arguments
+/// * `monitor_match` - The synthetic monitor match
+///
+/// # Returns
+/// * `HashMap<String, String>` - Template variables
+pub fn sample_variables(monitor_match: &MonitorMatch) -> HashMap<String, String> {
+	let zero_address = format!("0x{}", "0".repeat(40));
+	HashMap::from([
+		("match.id".to_string(), monitor_match.match_id()),
+		(
+			"monitor.name".to_string(),
+			TEST_TRIGGER_MONITOR_NAME.to_string(),
+		),
+		(
+			"transaction.hash".to_string(),
+			format!("0x{}", "0".repeat(64)),
+		),
+		("transaction.from".to_string(), zero_address.clone()),
+		("transaction.to".to_string(), zero_address.clone()),
+		("transaction.value".to_string(), "0".to_string()),
+		(
+			"events.0.signature".to_string(),
+			"Transfer(address,address,uint256)".to_string(),
+		),
+		("events.0.args.from".to_string(), zero_address.clone()),
+		("events.0.args.to".to_string(), zero_address),
+		("events.0.args.value".to_string(), "1000000".to_string()),
+	])
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_sample_variables() {
+		let monitor_match = MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: Monitor {
+				name: TEST_TRIGGER_MONITOR_NAME.to_string(),
+				..Default::default()
+			},
+			transaction: EVMTransaction::default(),
+			receipt: None,
+			revert_reason: None,
+			logs: None,
+			block_timestamp: None,
+			network_slug: TEST_TRIGGER_NETWORK_SLUG.to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: None,
+			enrichment: HashMap::new(),
+		}));
+
+		let variables = sample_variables(&monitor_match);
+		assert_eq!(variables["match.id"], monitor_match.match_id());
+		assert_eq!(variables["monitor.name"], TEST_TRIGGER_MONITOR_NAME);
+		assert_eq!(
+			variables["events.0.signature"],
+			"Transfer(address,address,uint256)"
+		);
+	}
+}
@@ -20,6 +20,7 @@ mod integration {
 				mod transport;
 			}
 			mod stellar {
+				mod graphql;
 				mod http;
 				mod transport;
 			}
@@ -46,14 +47,19 @@ mod integration {
 	}
 	mod notifications {
 		mod email;
+		mod grpc;
+		mod nats;
 		mod script;
 		mod webhook;
 	}
 	mod monitor {
 		mod execution;
+		mod replay;
 	}
 
 	mod security {
 		mod secret;
 	}
+
+	mod selftest;
 }
@@ -13,6 +13,7 @@ use openzeppelin_monitor::{
 	models::{BlockType, EVMBlock, EVMReceiptLog, EVMTransactionReceipt},
 	services::blockchain::{BlockChainClient, EvmClient, EvmClientTrait},
 };
+use serde_json::{json, Value};
 
 #[tokio::test]
 async fn test_get_transaction_receipt() {
@@ -68,12 +69,13 @@ async fn test_get_logs_for_blocks() {
 			predicate::eq(1u64),
 			predicate::eq(2u64),
 			predicate::eq(Some(vec!["0x123".to_string()])),
+			predicate::eq(None),
 		)
 		.times(1)
-		.returning(move |_, _, _| Ok(expected_logs.clone()));
+		.returning(move |_, _, _, _| Ok(expected_logs.clone()));
 
 	let result = mock
-		.get_logs_for_blocks(1, 2, Some(vec!["0x123".to_string()]))
+		.get_logs_for_blocks(1, 2, Some(vec!["0x123".to_string()]), None)
 		.await;
 	assert!(result.is_ok());
 	assert_eq!(result.unwrap().len(), 1);
@@ -91,6 +93,73 @@ async fn test_get_latest_block_number() {
 	assert_eq!(result.unwrap(), 100u64);
 }
 
+/// Creates a client whose transport replies to `eth_getBlockByNumber` with `response`
+fn create_client_with_tag_response(
+	tag: &'static str,
+	response: Value,
+) -> EvmClient<MockEVMTransportClient> {
+	let mut transport = MockEVMTransportClient::new();
+	transport
+		.expect_send_raw_request()
+		.withf(move |method, params| {
+			method == "eth_getBlockByNumber"
+				&& params.as_deref() == Some(&[json!(tag), json!(false)][..])
+		})
+		.times(1)
+		.returning(move |_, _| Ok(response.clone()));
+	EvmClient::new_with_transport(transport)
+}
+
+#[tokio::test]
+async fn test_get_block_number_by_tag() {
+	let client = create_client_with_tag_response(
+		"finalized",
+		json!({"jsonrpc": "2.0", "id": 1, "result": {"number": "0x64", "hash": "0x1"}}),
+	);
+	assert_eq!(
+		client.get_block_number_by_tag("finalized").await.unwrap(),
+		Some(100)
+	);
+
+	let client = create_client_with_tag_response(
+		"safe",
+		json!({"jsonrpc": "2.0", "id": 1, "result": {"number": "0x6e"}}),
+	);
+	assert_eq!(
+		client.get_block_number_by_tag("safe").await.unwrap(),
+		Some(110)
+	);
+}
+
+#[tokio::test]
+async fn test_get_block_number_by_tag_unsupported() {
+	let client = create_client_with_tag_response(
+		"finalized",
+		json!({
+			"jsonrpc": "2.0",
+			"id": 1,
+			"error": {"code": -32602, "message": "invalid block tag finalized"}
+		}),
+	);
+	assert_eq!(
+		client.get_block_number_by_tag("finalized").await.unwrap(),
+		None
+	);
+
+	let client =
+		create_client_with_tag_response("safe", json!({"jsonrpc": "2.0", "id": 1, "result": null}));
+	assert_eq!(client.get_block_number_by_tag("safe").await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_get_block_number_by_tag_invalid_number() {
+	let client = create_client_with_tag_response(
+		"finalized",
+		json!({"jsonrpc": "2.0", "id": 1, "result": {"number": "not-hex"}}),
+	);
+	assert!(client.get_block_number_by_tag("finalized").await.is_err());
+}
+
 #[tokio::test]
 async fn test_get_blocks() {
 	let mut mock = MockEvmClientTrait::<MockEVMTransportClient>::new();
@@ -40,11 +40,15 @@ async fn test_get_events() {
 	};
 
 	mock.expect_get_events()
-		.with(predicate::eq(1u32), predicate::eq(Some(2u32)))
+		.with(
+			predicate::eq(1u32),
+			predicate::eq(Some(2u32)),
+			predicate::always(),
+		)
 		.times(1)
-		.returning(move |_, _| Ok(vec![expected_event.clone()]));
+		.returning(move |_, _, _| Ok(vec![expected_event.clone()]));
 
-	let result = mock.get_events(1, Some(2)).await;
+	let result = mock.get_events(1, Some(2), &[]).await;
 	assert!(result.is_ok());
 	assert_eq!(result.unwrap().len(), 1);
 }
@@ -254,7 +258,7 @@ async fn test_get_events_pagination() {
 		.await;
 
 	let client = StellarClient::new(&network).await.unwrap();
-	let result = client.get_events(1, Some(2)).await.unwrap();
+	let result = client.get_events(1, Some(2), &[]).await.unwrap();
 
 	assert_eq!(result.len(), 2);
 	assert_eq!(result[0].ledger, 1);
@@ -571,7 +575,7 @@ async fn test_get_events_sparse_pagination() {
 	}
 
 	let client = StellarClient::new(&network).await.unwrap();
-	let result = client.get_events(1, Some(150)).await.unwrap();
+	let result = client.get_events(1, Some(150), &[]).await.unwrap();
 
 	// Should find 2 events despite empty intermediate pages
 	assert_eq!(result.len(), 2);
@@ -747,7 +751,7 @@ async fn test_get_events_complex_sparse_pagination_with_boundaries() {
 
 	let client = StellarClient::new(&network).await.unwrap();
 	// Query range: ledger 10 to 100 (inclusive)
-	let result = client.get_events(10, Some(100)).await.unwrap();
+	let result = client.get_events(10, Some(100), &[]).await.unwrap();
 
 	// Should find 4 events: at ledger 10, 45, 47, and 100
 	// Event at ledger 105 should be excluded (beyond range)
@@ -1,13 +1,13 @@
 use openzeppelin_monitor::{
 	models::BlockChainType,
 	services::blockchain::{
-		ClientPool, ClientPoolTrait, EVMTransportClient, EvmClient, StellarClient,
-		StellarTransportClient,
+		ClientPool, ClientPoolConfig, ClientPoolTrait, EVMTransportClient, EvmClient,
+		StellarClient, StellarTransportClient,
 	},
 	utils::{tests::network::NetworkBuilder, RetryConfig},
 };
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use crate::integration::mocks::{
 	create_evm_test_network_with_urls, create_evm_valid_server_mock_network_response,
@@ -208,6 +208,133 @@ async fn test_concurrent_access() {
 	mock.assert();
 }
 
+#[tokio::test]
+async fn test_idle_client_is_evicted_and_recreated() {
+	let mut mock_server = mockito::Server::new_async().await;
+	let mock = create_evm_valid_server_mock_network_response(&mut mock_server).expect(2);
+
+	let pool = ClientPool::with_config(ClientPoolConfig {
+		max_clients: None,
+		idle_ttl: Some(Duration::from_millis(100)),
+	});
+	let network = create_evm_test_network_with_urls(vec![&mock_server.url()]);
+
+	let client1 = pool.get_evm_client(&network).await.unwrap();
+	let client2 = pool.get_evm_client(&network).await.unwrap();
+	assert!(Arc::ptr_eq(&client1, &client2));
+
+	// Leave the client unused for longer than the TTL
+	tokio::time::sleep(Duration::from_millis(200)).await;
+
+	let client3 = pool.get_evm_client(&network).await.unwrap();
+	assert!(!Arc::ptr_eq(&client1, &client3));
+	assert_eq!(
+		pool.get_client_count::<EvmClient<EVMTransportClient>>(BlockChainType::EVM)
+			.await,
+		1
+	);
+
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_recently_used_client_is_not_evicted() {
+	let mut mock_server = mockito::Server::new_async().await;
+	let mock = create_evm_valid_server_mock_network_response(&mut mock_server).expect(1);
+
+	let pool = ClientPool::with_config(ClientPoolConfig {
+		max_clients: None,
+		idle_ttl: Some(Duration::from_millis(300)),
+	});
+	let network = create_evm_test_network_with_urls(vec![&mock_server.url()]);
+
+	let client1 = pool.get_evm_client(&network).await.unwrap();
+	// Each access refreshes the idle timer, so the client outlives the TTL in total
+	for _ in 0..3 {
+		tokio::time::sleep(Duration::from_millis(150)).await;
+		let client = pool.get_evm_client(&network).await.unwrap();
+		assert!(Arc::ptr_eq(&client1, &client));
+	}
+
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_max_clients_evicts_least_recently_used() {
+	let mut mock_server = mockito::Server::new_async().await;
+	let mut mock_server_2 = mockito::Server::new_async().await;
+	let mock = create_evm_valid_server_mock_network_response(&mut mock_server).expect(2);
+	let mock_2 = create_evm_valid_server_mock_network_response(&mut mock_server_2).expect(1);
+
+	let pool = ClientPool::with_config(ClientPoolConfig {
+		max_clients: Some(1),
+		idle_ttl: None,
+	});
+	let network1 = create_evm_test_network_with_urls(vec![&mock_server.url()]);
+	let network2 = NetworkBuilder::new()
+		.name("test-2")
+		.slug("test-2")
+		.network_type(BlockChainType::EVM)
+		.rpc_urls(vec![&mock_server_2.url()])
+		.build();
+
+	let client1 = pool.get_evm_client(&network1).await.unwrap();
+	pool.get_evm_client(&network2).await.unwrap();
+	assert_eq!(
+		pool.get_client_count::<EvmClient<EVMTransportClient>>(BlockChainType::EVM)
+			.await,
+		1
+	);
+
+	// The first client was evicted to make room and is recreated on demand
+	let client1_again = pool.get_evm_client(&network1).await.unwrap();
+	assert!(!Arc::ptr_eq(&client1, &client1_again));
+	assert_eq!(
+		pool.get_client_count::<EvmClient<EVMTransportClient>>(BlockChainType::EVM)
+			.await,
+		1
+	);
+
+	mock.assert();
+	mock_2.assert();
+}
+
+#[tokio::test]
+async fn test_concurrent_access_with_idle_eviction() {
+	let mut mock_server = mockito::Server::new_async().await;
+	let mock = create_evm_valid_server_mock_network_response(&mut mock_server).expect(2);
+
+	let pool = Arc::new(ClientPool::with_config(ClientPoolConfig {
+		max_clients: Some(1),
+		idle_ttl: Some(Duration::from_millis(100)),
+	}));
+	let network = create_evm_test_network_with_urls(vec![&mock_server.url()]);
+
+	let first = pool.get_evm_client(&network).await.unwrap();
+	tokio::time::sleep(Duration::from_millis(200)).await;
+
+	// All concurrent requests after expiry must share a single fresh client
+	let handles: Vec<_> = (0..10)
+		.map(|_| {
+			let pool = pool.clone();
+			let network = network.clone();
+			tokio::spawn(async move { pool.get_evm_client(&network).await.unwrap() })
+		})
+		.collect();
+	let clients: Vec<Arc<EvmClient<EVMTransportClient>>> = futures::future::join_all(handles)
+		.await
+		.into_iter()
+		.map(|r| r.unwrap())
+		.collect();
+
+	for client in &clients {
+		assert!(!Arc::ptr_eq(&first, client));
+		assert!(Arc::ptr_eq(&clients[0], client));
+	}
+
+	mock.assert();
+}
+
 #[tokio::test]
 async fn test_default_creates_empty_pool() {
 	let pool: ClientPool = Default::default();
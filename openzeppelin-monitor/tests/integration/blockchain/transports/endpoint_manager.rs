@@ -2,11 +2,14 @@ use mockito::Server;
 use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
 use reqwest_retry::{policies::ExponentialBackoff, RetryTransientMiddleware};
 use serde_json::{json, Value};
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc, time::Duration};
 use tokio::sync::RwLock;
 
-use openzeppelin_monitor::services::blockchain::{
-	BlockchainTransport, EndpointManager, TransportError,
+use openzeppelin_monitor::{
+	services::blockchain::{
+		BlockchainTransport, EndpointManager, TransientErrorRetryStrategy, TransportError,
+	},
+	utils::{create_retryable_json_http_client, JitterSetting, RetryConfig},
 };
 
 use crate::integration::mocks::{AlwaysFailsToUpdateClientTransport, MockTransport};
@@ -15,6 +18,21 @@ fn get_mock_client_builder() -> ClientWithMiddleware {
 	ClientBuilder::new(reqwest::Client::new()).build()
 }
 
+/// Client retrying malformed JSON responses as the transports do, after `initial_backoff`
+fn get_json_client(max_retries: u32, initial_backoff: Duration) -> ClientWithMiddleware {
+	create_retryable_json_http_client(
+		&RetryConfig {
+			max_retries,
+			initial_backoff,
+			max_backoff: initial_backoff,
+			jitter: JitterSetting::None,
+			..Default::default()
+		},
+		reqwest::Client::new(),
+		Some(TransientErrorRetryStrategy),
+	)
+}
+
 #[tokio::test]
 async fn test_endpoint_rotation() {
 	// Set up mock servers
@@ -75,6 +93,142 @@ async fn test_send_raw_request() {
 	mock.assert();
 }
 
+#[tokio::test]
+async fn test_send_raw_request_increments_ids() {
+	let mut server = Server::new_async().await;
+
+	let mocks = {
+		let mut mocks = Vec::new();
+		for id in 1..=3 {
+			mocks.push(
+				server
+					.mock("POST", "/")
+					.match_body(mockito::Matcher::PartialJson(json!({ "id": id })))
+					.with_status(200)
+					.with_header("content-type", "application/json")
+					.with_body(format!(
+						r#"{{"jsonrpc": "2.0", "result": "success", "id": {}}}"#,
+						id
+					))
+					.create_async()
+					.await,
+			);
+		}
+		mocks
+	};
+
+	let manager = EndpointManager::new(get_mock_client_builder(), server.url().as_ref(), vec![]);
+	let transport = MockTransport::new();
+
+	// Clones of the manager share the id counter
+	for manager in [manager.clone(), manager.clone(), manager] {
+		let result = manager
+			.send_raw_request(&transport, "test_method", Some(json!(["param1"])))
+			.await
+			.unwrap();
+		assert_eq!(result["result"], "success");
+	}
+
+	for mock in mocks {
+		mock.assert();
+	}
+}
+
+#[tokio::test]
+async fn test_send_batch_request() {
+	let mut server = Server::new_async().await;
+
+	// Responses of a batch may come in any order
+	let mock = server
+		.mock("POST", "/")
+		.match_body(mockito::Matcher::PartialJson(json!([
+			{ "method": "test_method", "params": ["param1"] },
+			{ "method": "test_method", "params": ["param2"] }
+		])))
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(
+			r#"[{"jsonrpc": "2.0", "result": "second", "id": 2}, {"jsonrpc": "2.0", "result": "first", "id": 1}]"#,
+		)
+		.create_async()
+		.await;
+
+	let manager = EndpointManager::new(get_mock_client_builder(), server.url().as_ref(), vec![]);
+	let transport = MockTransport::new();
+
+	let results = manager
+		.send_batch_request(
+			&transport,
+			"test_method",
+			vec![json!(["param1"]), json!(["param2"])],
+		)
+		.await
+		.unwrap();
+
+	assert_eq!(results.len(), 2);
+	assert_eq!(results[0]["result"], "first");
+	assert_eq!(results[1]["result"], "second");
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_send_batch_request_missing_response() {
+	let mut server = Server::new_async().await;
+
+	let mock = server
+		.mock("POST", "/")
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(r#"[{"jsonrpc": "2.0", "result": "first", "id": 1}]"#)
+		.create_async()
+		.await;
+
+	let manager = EndpointManager::new(get_mock_client_builder(), server.url().as_ref(), vec![]);
+	let transport = MockTransport::new();
+
+	let result = manager
+		.send_batch_request(
+			&transport,
+			"test_method",
+			vec![json!(["param1"]), json!(["param2"])],
+		)
+		.await;
+
+	assert!(matches!(result, Err(TransportError::ResponseParse(_))));
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_send_raw_request_with_method_alias() {
+	let mut server = Server::new_async().await;
+
+	let mock = server
+		.mock("POST", "/")
+		.match_body(mockito::Matcher::PartialJson(
+			json!({ "method": "eth_getBlockByNumberV2" }),
+		))
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"jsonrpc": "2.0", "result": "success", "id": 1}"#)
+		.create_async()
+		.await;
+
+	let manager = EndpointManager::new(get_mock_client_builder(), server.url().as_ref(), vec![])
+		.with_method_aliases(HashMap::from([(
+			"eth_getBlockByNumber".to_string(),
+			"eth_getBlockByNumberV2".to_string(),
+		)]));
+	let transport = MockTransport::new();
+
+	let result = manager
+		.send_raw_request(&transport, "eth_getBlockByNumber", Some(json!(["latest"])))
+		.await
+		.unwrap();
+
+	assert_eq!(result["result"], "success");
+	mock.assert();
+}
+
 #[tokio::test]
 async fn test_rotation_on_error() {
 	let mut primary_server = Server::new_async().await;
@@ -463,6 +617,41 @@ async fn test_send_raw_request_response_parse_error() {
 		.with_status(200)
 		.with_header("content-type", "application/json")
 		.with_body(r#"{"jsonrpc": "2.0", "result": "invalid_json"#) // Missing closing brace
+		.expect(3)
+		.create_async()
+		.await;
+
+	let manager = EndpointManager::new(
+		get_json_client(2, Duration::from_millis(1)),
+		server.url().as_ref(),
+		vec![],
+	);
+	let transport = MockTransport::new();
+
+	// Send request - should fail with parse error once the client's retries are exhausted
+	let result = manager
+		.send_raw_request(&transport, "test_method", Some(json!(["param1"])))
+		.await;
+
+	assert!(result.is_err());
+	assert!(matches!(
+		result.unwrap_err(),
+		TransportError::ResponseParse(_)
+	));
+
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_send_raw_request_response_parse_error_without_retry_middleware() {
+	let mut server = Server::new_async().await;
+
+	// Without the client's retries, the malformed response is not sent again
+	let mock = server
+		.mock("POST", "/")
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"jsonrpc": "2.0", "result": "invalid_json"#)
 		.expect(1)
 		.create_async()
 		.await;
@@ -470,17 +659,126 @@ async fn test_send_raw_request_response_parse_error() {
 	let manager = EndpointManager::new(get_mock_client_builder(), server.url().as_ref(), vec![]);
 	let transport = MockTransport::new();
 
-	// Send request - should fail with parse error
 	let result = manager
 		.send_raw_request(&transport, "test_method", Some(json!(["param1"])))
 		.await;
 
-	assert!(result.is_err());
 	assert!(matches!(
 		result.unwrap_err(),
 		TransportError::ResponseParse(_)
 	));
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_send_raw_request_retries_html_response() {
+	let mut server = Server::new_async().await;
+
+	// The provider returns an HTML error page once, then a valid response
+	let html_mock = server
+		.mock("POST", "/")
+		.with_status(200)
+		.with_header("content-type", "text/html")
+		.with_body("<html><body>502 Bad Gateway</body></html>")
+		.expect(1)
+		.create_async()
+		.await;
+	let json_mock = server
+		.mock("POST", "/")
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"jsonrpc": "2.0", "result": "success", "id": 1}"#)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let backoff = Duration::from_millis(200);
+	let manager = EndpointManager::new(get_json_client(1, backoff), server.url().as_ref(), vec![]);
+	let transport = MockTransport::new();
+
+	let started = std::time::Instant::now();
+	let result = manager
+		.send_raw_request(&transport, "test_method", Some(json!(["param1"])))
+		.await
+		.unwrap();
+
+	assert_eq!(result["result"], "success");
+	// The retry waits for the configured backoff
+	assert!(started.elapsed() >= backoff);
+	html_mock.assert();
+	json_mock.assert();
+}
+
+#[tokio::test]
+async fn test_send_raw_request_rotates_on_repeated_parse_errors() {
+	let mut server1 = Server::new_async().await;
+	let mut server2 = Server::new_async().await;
+
+	// The active URL keeps returning truncated responses
+	let mock1 = server1
+		.mock("POST", "/")
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"jsonrpc": "2.0", "res"#)
+		.expect(2)
+		.create_async()
+		.await;
+	let mock2 = server2
+		.mock("POST", "/")
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(r#"{"jsonrpc": "2.0", "result": "success", "id": 1}"#)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let manager = EndpointManager::new(
+		get_json_client(1, Duration::from_millis(1)),
+		server1.url().as_ref(),
+		vec![server2.url()],
+	);
+	let transport = MockTransport::new();
+
+	let result = manager
+		.send_raw_request(&transport, "test_method", Some(json!(["param1"])))
+		.await
+		.unwrap();
+
+	assert_eq!(result["result"], "success");
+	assert_eq!(&*manager.active_url.read().await, &server2.url());
+	mock1.assert();
+	mock2.assert();
+}
+
+#[tokio::test]
+async fn test_send_raw_request_returns_json_rpc_error_without_retry() {
+	let mut server = Server::new_async().await;
+
+	// A JSON-RPC error object is a valid response, not a parse failure
+	let mock = server
+		.mock("POST", "/")
+		.with_status(200)
+		.with_header("content-type", "application/json")
+		.with_body(
+			r#"{"jsonrpc": "2.0", "error": {"code": -32601, "message": "Method not found"}, "id": 1}"#,
+		)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let manager = EndpointManager::new(
+		get_json_client(2, Duration::from_millis(1)),
+		server.url().as_ref(),
+		vec![],
+	);
+	let transport = MockTransport::new();
+
+	let result = manager
+		.send_raw_request(&transport, "test_method", Some(json!(["param1"])))
+		.await
+		.unwrap();
 
+	assert_eq!(result["error"]["code"], -32601);
 	mock.assert();
 }
 
@@ -1,10 +1,12 @@
 use alloy::{
-	primitives::{Address, U64},
+	primitives::{Address, Bytes, U64},
 	rpc::types::Index,
+	sol_types::{Revert, SolError},
 };
 use mockall::predicate;
-use openzeppelin_monitor::services::blockchain::{
-	BlockChainClient, EvmClient, EvmClientTrait, TransportError,
+use openzeppelin_monitor::{
+	services::blockchain::{BlockChainClient, EvmClient, EvmClientTrait, TransportError},
+	utils::tests::evm::{receipt::ReceiptBuilder, transaction::TransactionBuilder},
 };
 use serde_json::{json, Value};
 
@@ -80,6 +82,7 @@ async fn test_get_logs_for_blocks_implementation() {
 			Some(vec![
 				"0x1234567890123456789012345678901234567890".to_string()
 			]),
+			None,
 		)
 		.await;
 
@@ -110,7 +113,7 @@ async fn test_get_logs_for_blocks_missing_result() {
 		.returning(move |_: &str, _: Option<Vec<Value>>| Ok(mock_response.clone()));
 
 	let client = EvmClient::<MockEVMTransportClient>::new_with_transport(mock_evm);
-	let result = client.get_logs_for_blocks(1, 10, None).await;
+	let result = client.get_logs_for_blocks(1, 10, None, None).await;
 
 	assert!(result.is_err());
 	let err = result.unwrap_err();
@@ -133,7 +136,7 @@ async fn test_get_logs_for_blocks_invalid_format() {
 		.returning(move |_: &str, _: Option<Vec<Value>>| Ok(mock_response.clone()));
 
 	let client = EvmClient::<MockEVMTransportClient>::new_with_transport(mock_evm);
-	let result = client.get_logs_for_blocks(1, 10, None).await;
+	let result = client.get_logs_for_blocks(1, 10, None, None).await;
 
 	assert!(result.is_err());
 	let err = result.unwrap_err();
@@ -155,7 +158,7 @@ async fn test_get_logs_for_blocks_alloy_error() {
 		});
 
 	let client = EvmClient::<MockEVMTransportClient>::new_with_transport(mock_evm);
-	let result = client.get_logs_for_blocks(1, 10, None).await;
+	let result = client.get_logs_for_blocks(1, 10, None, None).await;
 
 	assert!(result.is_err());
 }
@@ -310,6 +313,176 @@ async fn test_get_transaction_receipt_parse_failure() {
 		.contains("Failed to parse transaction receipt"));
 }
 
+#[tokio::test]
+async fn test_get_revert_reason_error_string() {
+	let mut mock_evm = MockEVMTransportClient::new();
+
+	let mut transaction = TransactionBuilder::new()
+		.from(Address::from_slice(&[1u8; 20]))
+		.to(Address::from_slice(&[2u8; 20]))
+		.input(Bytes::from(vec![0xa9, 0x05, 0x9c, 0xbb]))
+		.build();
+	transaction.0.block_number = Some(U64::from(100));
+
+	// The transaction is replayed against its parent block
+	let expected_params = json!([
+		{
+			"from": "0x0101010101010101010101010101010101010101",
+			"to": "0x0202020202020202020202020202020202020202",
+			"data": "0xa9059cbb",
+			"value": "0x0",
+			"gas": "0x5208",
+		},
+		"0x63"
+	]);
+
+	// Revert data for Error("Insufficient balance")
+	let revert_data = format!(
+		"0x{}",
+		hex::encode(Revert::from("Insufficient balance").abi_encode())
+	);
+	let mock_response = json!({
+		"error": {
+			"code": 3,
+			"message": "execution reverted: Insufficient balance",
+			"data": revert_data,
+		}
+	});
+
+	mock_evm
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("eth_call"),
+			predicate::eq(Some(expected_params.as_array().unwrap().to_vec())),
+		)
+		.returning(move |_: &str, _: Option<Vec<Value>>| Ok(mock_response.clone()));
+
+	let client = EvmClient::<MockEVMTransportClient>::new_with_transport(mock_evm);
+	let result = client.get_revert_reason(&transaction).await.unwrap();
+
+	assert_eq!(result, Some("Insufficient balance".to_string()));
+}
+
+#[tokio::test]
+async fn test_get_revert_reason_fallbacks() {
+	// Reason is taken from the error message when no revert data is returned
+	let mut mock_evm = MockEVMTransportClient::new();
+	mock_evm
+		.expect_send_raw_request()
+		.returning(|_: &str, _: Option<Vec<Value>>| {
+			Ok(json!({
+				"error": { "code": 3, "message": "execution reverted: Paused" }
+			}))
+		});
+	let client = EvmClient::<MockEVMTransportClient>::new_with_transport(mock_evm);
+	let result = client
+		.get_revert_reason(&TransactionBuilder::new().build())
+		.await
+		.unwrap();
+	assert_eq!(result, Some("Paused".to_string()));
+
+	// Successful replays and undecodable errors have no reason
+	let mut mock_evm = MockEVMTransportClient::new();
+	mock_evm
+		.expect_send_raw_request()
+		.returning(|_: &str, _: Option<Vec<Value>>| Ok(json!({ "result": "0x" })));
+	let client = EvmClient::<MockEVMTransportClient>::new_with_transport(mock_evm);
+	let result = client
+		.get_revert_reason(&TransactionBuilder::new().build())
+		.await
+		.unwrap();
+	assert_eq!(result, None);
+
+	let mut mock_evm = MockEVMTransportClient::new();
+	mock_evm
+		.expect_send_raw_request()
+		.returning(|_: &str, _: Option<Vec<Value>>| {
+			Ok(json!({
+				"error": { "code": 3, "message": "execution reverted", "data": "0xdeadbeef" }
+			}))
+		});
+	let client = EvmClient::<MockEVMTransportClient>::new_with_transport(mock_evm);
+	let result = client
+		.get_revert_reason(&TransactionBuilder::new().build())
+		.await
+		.unwrap();
+	assert_eq!(result, None);
+
+	// Responses without a result or an error are rejected
+	let mut mock_evm = MockEVMTransportClient::new();
+	mock_evm
+		.expect_send_raw_request()
+		.returning(|_: &str, _: Option<Vec<Value>>| Ok(json!({})));
+	let client = EvmClient::<MockEVMTransportClient>::new_with_transport(mock_evm);
+	let result = client
+		.get_revert_reason(&TransactionBuilder::new().build())
+		.await;
+	assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_call_contract_function() {
+	let mut mock_evm = MockEVMTransportClient::new();
+
+	// symbol() is called with its selector only
+	let expected_params = json!([
+		{
+			"to": "0x0202020202020202020202020202020202020202",
+			"data": "0x95d89b41",
+		},
+		"latest"
+	]);
+
+	// ABI encoded string "USDC"
+	let mock_response = json!({
+		"result": format!(
+			"0x{:064x}{:064x}{}",
+			0x20,
+			4,
+			"5553444300000000000000000000000000000000000000000000000000000000"
+		)
+	});
+
+	mock_evm
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("eth_call"),
+			predicate::eq(Some(expected_params.as_array().unwrap().to_vec())),
+		)
+		.returning(move |_: &str, _: Option<Vec<Value>>| Ok(mock_response.clone()));
+
+	let client = EvmClient::<MockEVMTransportClient>::new_with_transport(mock_evm);
+	let result = client
+		.call_contract_function(
+			"0x0202020202020202020202020202020202020202",
+			"symbol()",
+			Some("string"),
+		)
+		.await
+		.unwrap();
+
+	assert_eq!(result, "USDC");
+}
+
+#[tokio::test]
+async fn test_call_contract_function_without_output() {
+	let mock_evm = MockEVMTransportClient::new();
+
+	let client = EvmClient::<MockEVMTransportClient>::new_with_transport(mock_evm);
+	let result = client
+		.call_contract_function(
+			"0x0202020202020202020202020202020202020202",
+			"symbol()",
+			None,
+		)
+		.await;
+
+	assert!(result
+		.unwrap_err()
+		.to_string()
+		.contains("Missing output type of symbol()"));
+}
+
 #[tokio::test]
 async fn test_get_latest_block_number_success() {
 	let mut mock_evm = MockEVMTransportClient::new();
@@ -543,3 +716,146 @@ async fn test_get_blocks_parse_failure() {
 	let err = result.unwrap_err();
 	assert!(err.to_string().contains("Failed to parse block"));
 }
+
+#[tokio::test]
+async fn test_get_blocks_with_tx_chunk_size() {
+	let mut mock_evm = MockEVMTransportClient::new();
+
+	mock_evm.expect_clone().times(1).returning(|| {
+		let mut new_mock = MockEVMTransportClient::new();
+		let mut block = create_mock_block(1);
+		block["transactions"] = json!([format!("0x{:064x}", 1), format!("0x{:064x}", 2)]);
+		let mock_response = json!({
+			"jsonrpc": "2.0",
+			"id": 1,
+			"result": block
+		});
+		new_mock
+			.expect_send_raw_request()
+			.with(
+				predicate::eq("eth_getBlockByNumber"),
+				predicate::function(|params: &Option<Vec<Value>>| match params {
+					Some(p) => p == &vec![json!("0x1"), json!(false)],
+					None => false,
+				}),
+			)
+			.returning(move |_: &str, _: Option<Vec<Value>>| Ok(mock_response.clone()));
+
+		new_mock
+			.expect_clone()
+			.returning(MockEVMTransportClient::new);
+		new_mock
+	});
+
+	let client = EvmClient::<MockEVMTransportClient>::new_with_transport(mock_evm)
+		.with_tx_chunk_size(Some(1));
+
+	let result = client.get_blocks(1, None).await;
+	assert!(result.is_ok());
+	let blocks = result.unwrap();
+	assert_eq!(blocks.len(), 1);
+
+	match &blocks[0] {
+		openzeppelin_monitor::models::BlockType::EVM(block) => {
+			assert_eq!(block.transactions.len(), 2);
+			assert_eq!(
+				format!("{:#x}", block.transactions[1].hash),
+				format!("0x{:064x}", 2)
+			);
+		}
+		_ => panic!("Expected EVM block"),
+	}
+}
+
+#[tokio::test]
+async fn test_get_transactions() {
+	let mut mock_evm = MockEVMTransportClient::new();
+
+	// All transactions are fetched with a single batch request
+	mock_evm
+		.expect_send_batch_request()
+		.times(1)
+		.returning(|method: &str, params: Vec<Value>| {
+			assert_eq!(method, "eth_getTransactionByHash");
+			Ok(params
+				.iter()
+				.map(|params| {
+					let transaction = TransactionBuilder::new()
+						.hash(params[0].as_str().unwrap().parse().unwrap())
+						.build();
+					json!({ "jsonrpc": "2.0", "id": 1, "result": transaction })
+				})
+				.collect())
+		});
+
+	let client = EvmClient::<MockEVMTransportClient>::new_with_transport(mock_evm);
+
+	let hashes = vec![format!("0x{:064x}", 1), format!("0x{:064x}", 2)];
+	let result = client.get_transactions(hashes.clone()).await;
+	assert!(result.is_ok());
+	let transactions = result.unwrap();
+	assert_eq!(transactions.len(), 2);
+	for (transaction, hash) in transactions.iter().zip(hashes) {
+		assert_eq!(format!("{:#x}", transaction.hash), hash);
+	}
+}
+
+#[tokio::test]
+async fn test_get_transactions_not_found() {
+	let mut mock_evm = MockEVMTransportClient::new();
+
+	mock_evm
+		.expect_send_batch_request()
+		.times(1)
+		.returning(|_, _| Ok(vec![json!({"jsonrpc": "2.0", "id": 1, "result": null})]));
+
+	let client = EvmClient::<MockEVMTransportClient>::new_with_transport(mock_evm);
+
+	let result = client.get_transactions(vec![format!("0x{:064x}", 1)]).await;
+	assert!(result.is_err());
+	assert!(format!("{:#}", result.unwrap_err()).contains("Not found"));
+}
+
+#[tokio::test]
+async fn test_get_transaction_receipts() {
+	let mut mock_evm = MockEVMTransportClient::new();
+
+	mock_evm
+		.expect_send_batch_request()
+		.times(1)
+		.returning(|method: &str, params: Vec<Value>| {
+			assert_eq!(method, "eth_getTransactionReceipt");
+			Ok(params
+				.iter()
+				.map(|params| {
+					let receipt = ReceiptBuilder::new()
+						.transaction_hash(params[0].as_str().unwrap().parse().unwrap())
+						.build();
+					json!({ "jsonrpc": "2.0", "id": 1, "result": receipt })
+				})
+				.collect())
+		});
+
+	let client = EvmClient::<MockEVMTransportClient>::new_with_transport(mock_evm);
+
+	let hashes = vec![format!("0x{:064x}", 1), format!("0x{:064x}", 2)];
+	let result = client.get_transaction_receipts(hashes.clone()).await;
+	assert!(result.is_ok());
+	let receipts = result.unwrap();
+	assert_eq!(receipts.len(), 2);
+	for (receipt, hash) in receipts.iter().zip(hashes) {
+		assert_eq!(format!("{:#x}", receipt.transaction_hash), hash);
+	}
+}
+
+#[tokio::test]
+async fn test_get_transaction_receipts_invalid_hash() {
+	let mock_evm = MockEVMTransportClient::new();
+	let client = EvmClient::<MockEVMTransportClient>::new_with_transport(mock_evm);
+
+	let result = client
+		.get_transaction_receipts(vec!["invalid_hash".to_string()])
+		.await;
+	assert!(result.is_err());
+	assert!(format!("{:#}", result.unwrap_err()).contains("Invalid transaction hash"));
+}
@@ -1,11 +1,13 @@
-use mockito::Server;
+use mockito::{Matcher, Server};
 use openzeppelin_monitor::{
+	models::{SecretString, SecretValue},
 	services::blockchain::{BlockchainTransport, HttpTransportClient, RotatingTransport},
-	utils::RetryConfig,
+	utils::{default_user_agent, RetryConfig, DEFAULT_REQUEST_ID_HEADER},
 };
 use reqwest_middleware::ClientBuilder;
 use reqwest_retry::{policies::ExponentialBackoff, RetryTransientMiddleware};
 use serde_json::{json, Value};
+use std::collections::HashMap;
 
 use crate::integration::mocks::{
 	create_evm_test_network_with_urls, create_http_valid_server_mock_network_response,
@@ -64,6 +66,33 @@ async fn test_client_creation_with_test_connection_payload() {
 	mock.assert();
 }
 
+#[tokio::test]
+async fn test_client_sends_user_agent_and_request_id() {
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.match_header("user-agent", default_user_agent().as_str())
+		.match_header(
+			DEFAULT_REQUEST_ID_HEADER,
+			Matcher::Regex("^[0-9a-f-]{36}$".to_string()),
+		)
+		.with_header("content-type", "application/json")
+		.with_status(200)
+		.with_body(r#"{"jsonrpc":"2.0","id":0,"result":"1"}"#)
+		.expect(2)
+		.create_async()
+		.await;
+
+	let network = create_evm_test_network_with_urls(vec![&server.url()]);
+	let transport = HttpTransportClient::new(&network, None).await.unwrap();
+	let result = transport
+		.send_raw_request("eth_blockNumber", None::<Value>)
+		.await;
+
+	assert!(result.is_ok());
+	mock.assert();
+}
+
 #[tokio::test]
 async fn test_client_creation_with_fallback() {
 	let mut server = Server::new_async().await;
@@ -208,7 +237,7 @@ async fn test_send_raw_request() {
 	// Test without params
 	let no_params_mock = server
 		.mock("POST", "/")
-		.match_body(r#"{"id":1,"jsonrpc":"2.0","method":"testMethod","params":null}"#)
+		.match_body(r#"{"id":2,"jsonrpc":"2.0","method":"testMethod","params":null}"#)
 		.with_header("content-type", "application/json")
 		.with_status(200)
 		.with_body(r#"{"jsonrpc":"2.0","result":{"data":"success"},"id":1}"#)
@@ -279,3 +308,165 @@ async fn test_update_endpoint_manager_client() {
 	initial_request_mock.assert();
 	updated_mock.assert();
 }
+
+#[tokio::test]
+async fn test_send_raw_request_with_custom_headers() {
+	let mut server = Server::new_async().await;
+
+	// Both the connection test and the request must carry the configured headers
+	let network_mock = server
+		.mock("POST", "/")
+		.match_header("x-api-key", "secret-key")
+		.match_header("x-client-id", "monitor")
+		.match_body(r#"{"id":1,"jsonrpc":"2.0","method":"net_version","params":[]}"#)
+		.with_header("content-type", "application/json")
+		.with_status(200)
+		.with_body(r#"{"jsonrpc":"2.0","id":0,"result":"1"}"#)
+		.create();
+	let test_mock = server
+		.mock("POST", "/")
+		.match_header("x-api-key", "secret-key")
+		.match_header("x-client-id", "monitor")
+		.match_body(r#"{"id":1,"jsonrpc":"2.0","method":"testMethod","params":null}"#)
+		.with_header("content-type", "application/json")
+		.with_status(200)
+		.with_body(r#"{"jsonrpc":"2.0","result":{"data":"success"},"id":1}"#)
+		.create();
+
+	let mut network = create_evm_test_network_with_urls(vec![&server.url()]);
+	network.rpc_urls[0].headers = HashMap::from([
+		(
+			"x-api-key".to_string(),
+			SecretValue::Plain(SecretString::new("secret-key".to_string())),
+		),
+		(
+			"X-Client-Id".to_string(),
+			SecretValue::Plain(SecretString::new("monitor".to_string())),
+		),
+	]);
+	let client = HttpTransportClient::new(&network, None).await.unwrap();
+
+	let result = client.send_raw_request::<Value>("testMethod", None).await;
+	assert!(result.is_ok(), "Request failed: {:?}", result.err());
+	assert_eq!(result.unwrap()["result"]["data"], "success");
+
+	network_mock.assert();
+	test_mock.assert();
+
+	// Header values are never shown in debug output
+	assert!(!format!("{:?}", client).contains("secret-key"));
+}
+
+#[tokio::test]
+async fn test_client_creation_with_invalid_header() {
+	let mut server = Server::new_async().await;
+	let mut network = create_evm_test_network_with_urls(vec![&server.url()]);
+	let mock = create_http_valid_server_mock_network_response(&mut server).expect(0);
+	network.rpc_urls[0].headers = HashMap::from([(
+		"x-api-key".to_string(),
+		SecretValue::Plain(SecretString::new("secret\nkey".to_string())),
+	)]);
+
+	let error = HttpTransportClient::new(&network, None).await.unwrap_err();
+	assert_eq!(
+		error.to_string(),
+		"Invalid value for RPC header 'x-api-key'"
+	);
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_client_creation_with_http2_prior_knowledge() {
+	let mut server = Server::new_async().await;
+	let mock = create_http_valid_server_mock_network_response(&mut server).expect(2);
+	let mut network = create_evm_test_network_with_urls(vec![&server.url()]);
+	network.http2_prior_knowledge = Some(true);
+
+	let client = HttpTransportClient::new(&network, None).await.unwrap();
+
+	// Requests are sent over HTTP/2 without negotiating it first
+	let response = client
+		.client
+		.post(server.url())
+		.json(&json!({"id":1,"jsonrpc":"2.0","method":"net_version","params":[]}))
+		.send()
+		.await
+		.unwrap();
+	assert_eq!(response.version(), reqwest::Version::HTTP_2);
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_client_creation_defaults_to_http1() {
+	let mut server = Server::new_async().await;
+	let mock = create_http_valid_server_mock_network_response(&mut server).expect(2);
+	let network = create_evm_test_network_with_urls(vec![&server.url()]);
+
+	let client = HttpTransportClient::new(&network, None).await.unwrap();
+
+	let response = client
+		.client
+		.post(server.url())
+		.json(&json!({"id":1,"jsonrpc":"2.0","method":"net_version","params":[]}))
+		.send()
+		.await
+		.unwrap();
+	assert_eq!(response.version(), reqwest::Version::HTTP_11);
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_send_raw_request_with_network_method_alias() {
+	let mut server = Server::new_async().await;
+	let network_mock = create_http_valid_server_mock_network_response(&mut server);
+	let alias_mock = server
+		.mock("POST", "/")
+		.match_body(mockito::Matcher::PartialJson(
+			json!({"method": "testMethodAlias"}),
+		))
+		.with_header("content-type", "application/json")
+		.with_status(200)
+		.with_body(r#"{"jsonrpc":"2.0","id":1,"result":"success"}"#)
+		.create();
+	let mut network = create_evm_test_network_with_urls(vec![&server.url()]);
+	network.rpc_method_aliases = Some(HashMap::from([(
+		"testMethod".to_string(),
+		"testMethodAlias".to_string(),
+	)]));
+
+	let client = HttpTransportClient::new(&network, None).await.unwrap();
+	let result = client.send_raw_request::<Value>("testMethod", None).await;
+	assert!(result.is_ok(), "Request failed: {:?}", result.err());
+
+	network_mock.assert();
+	alias_mock.assert();
+}
+
+#[tokio::test]
+async fn test_client_creation_with_pool_settings() {
+	let mut server = Server::new_async().await;
+	let network_mock = create_http_valid_server_mock_network_response(&mut server);
+	let test_mock = server
+		.mock("POST", "/")
+		.match_body(mockito::Matcher::PartialJson(
+			json!({"method": "testMethod"}),
+		))
+		.with_header("content-type", "application/json")
+		.with_status(200)
+		.with_body(r#"{"jsonrpc":"2.0","id":1,"result":"success"}"#)
+		.expect(2)
+		.create();
+	let mut network = create_evm_test_network_with_urls(vec![&server.url()]);
+	network.pool_idle_timeout_ms = Some(1000);
+	// Idle connections are not kept, so that every request opens a new connection
+	network.pool_max_idle_per_host = Some(0);
+
+	let client = HttpTransportClient::new(&network, None).await.unwrap();
+	for _ in 0..2 {
+		let result = client.send_raw_request::<Value>("testMethod", None).await;
+		assert!(result.is_ok(), "Request failed: {:?}", result.err());
+	}
+
+	network_mock.assert();
+	test_mock.assert();
+}
@@ -0,0 +1,237 @@
+use mockito::{Matcher, Mock, Server};
+use openzeppelin_monitor::{
+	models::{BlockChainType, BlockType, Network},
+	services::blockchain::{
+		BlockChainClient, BlockchainTransport, StellarClient, StellarClientTrait,
+		StellarGraphQLTransport, StellarTransportClient,
+	},
+	utils::tests::network::NetworkBuilder,
+};
+use serde_json::{json, Value};
+
+use crate::integration::mocks::{
+	create_stellar_test_network_with_urls, create_stellar_valid_server_mock_network_response,
+};
+
+fn create_stellar_graphql_network(url: &str) -> Network {
+	NetworkBuilder::new()
+		.name("test")
+		.slug("test")
+		.network_type(BlockChainType::Stellar)
+		.network_passphrase("Test SDF Network ; September 2015")
+		.clear_rpc_urls()
+		.add_rpc_url(url, "graphql", 100)
+		.build()
+}
+
+fn create_graphql_connection_mock(server: &mut Server) -> Mock {
+	server
+		.mock("POST", "/")
+		.match_body(r#"{"query":"{ __typename }"}"#)
+		.with_header("content-type", "application/json")
+		.with_status(200)
+		.with_body(r#"{"data":{"__typename":"Query"}}"#)
+		.create()
+}
+
+fn create_graphql_query_mock(
+	server: &mut Server,
+	query_name: &str,
+	response: Value,
+	hits: usize,
+) -> Mock {
+	server
+		.mock("POST", "/")
+		.match_body(Matcher::Regex(format!("query {}\\(", query_name)))
+		.with_header("content-type", "application/json")
+		.with_status(200)
+		.with_body(response.to_string())
+		.expect(hits)
+		.create()
+}
+
+#[tokio::test]
+async fn test_graphql_transport_selected_for_graphql_urls() {
+	let mut server = Server::new_async().await;
+	let mock = create_graphql_connection_mock(&mut server);
+	let network = create_stellar_graphql_network(&server.url());
+
+	let transport = StellarTransportClient::new(&network).await.unwrap();
+	assert!(transport.is_graphql());
+	assert_eq!(transport.get_current_url().await, server.url());
+
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_json_rpc_remains_default() {
+	let mut server = Server::new_async().await;
+	let mock = create_stellar_valid_server_mock_network_response(&mut server);
+	let network = create_stellar_test_network_with_urls(vec![&server.url()]);
+
+	let transport = StellarTransportClient::new(&network).await.unwrap();
+	assert!(!transport.is_graphql());
+
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_graphql_client_fetches_ledger_and_transactions() {
+	let mut server = Server::new_async().await;
+	let connection_mock = create_graphql_connection_mock(&mut server);
+	let ledgers_mock = create_graphql_query_mock(
+		&mut server,
+		"Ledgers",
+		json!({
+			"data": {
+				"ledgers": {
+					"cursor": "100",
+					"ledgers": [{
+						"hash": "ledger_hash_100",
+						"sequence": 100,
+						"ledgerCloseTime": "1734715051",
+						"headerXdr": "header_xdr",
+						"metadataXdr": "metadata_xdr"
+					}]
+				}
+			}
+		}),
+		1,
+	);
+	let transactions_mock = create_graphql_query_mock(
+		&mut server,
+		"Transactions",
+		json!({
+			"data": {
+				"transactions": {
+					"cursor": null,
+					"transactions": [
+						{
+							"status": "SUCCESS",
+							"txHash": "tx_hash_1",
+							"applicationOrder": 1,
+							"feeBump": false,
+							"envelopeXdr": null,
+							"resultXdr": null,
+							"resultMetaXdr": null,
+							"diagnosticEventsXdr": null,
+							"ledger": 100,
+							"createdAt": 1734715051
+						},
+						{
+							"status": "FAILED",
+							"txHash": "tx_hash_2",
+							"applicationOrder": 2,
+							"feeBump": true,
+							"envelopeXdr": null,
+							"resultXdr": null,
+							"resultMetaXdr": null,
+							"diagnosticEventsXdr": null,
+							"ledger": 100,
+							"createdAt": 1734715051
+						}
+					]
+				}
+			}
+		}),
+		1,
+	);
+
+	let network = create_stellar_graphql_network(&server.url());
+	let transport = StellarGraphQLTransport::new(&network).await.unwrap();
+	let client = StellarClient::new_with_transport(transport);
+
+	let blocks = client.get_blocks(100, None).await.unwrap();
+	assert_eq!(blocks.len(), 1);
+	match &blocks[0] {
+		BlockType::Stellar(block) => {
+			assert_eq!(block.sequence, 100);
+			assert_eq!(block.hash, "ledger_hash_100");
+		}
+		_ => panic!("Expected Stellar block"),
+	}
+
+	let transactions = client.get_transactions(100, None).await.unwrap();
+	assert_eq!(transactions.len(), 2);
+	assert_eq!(transactions[0].hash(), "tx_hash_1");
+	assert_eq!(transactions[0].status, "SUCCESS");
+	assert_eq!(transactions[1].hash(), "tx_hash_2");
+	assert!(transactions[1].fee_bump);
+
+	connection_mock.assert();
+	ledgers_mock.assert();
+	transactions_mock.assert();
+}
+
+#[tokio::test]
+async fn test_graphql_latest_ledger_and_variables() {
+	let mut server = Server::new_async().await;
+	let connection_mock = create_graphql_connection_mock(&mut server);
+	let latest_mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::Regex("latestLedger".to_string()))
+		.with_status(200)
+		.with_body(
+			json!({ "data": { "latestLedger": { "id": "abc", "protocolVersion": 22, "sequence": 4242 } } })
+				.to_string(),
+		)
+		.create();
+	let events_mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::PartialJson(json!({
+			"variables": { "startLedger": 4242, "limit": 200, "type": "contract" }
+		})))
+		.with_status(200)
+		.with_body(json!({ "data": { "events": { "cursor": null, "events": [] } } }).to_string())
+		.create();
+
+	let network = create_stellar_graphql_network(&server.url());
+	let transport = StellarGraphQLTransport::new(&network).await.unwrap();
+	let client = StellarClient::new_with_transport(transport);
+
+	assert_eq!(client.get_latest_block_number().await.unwrap(), 4242);
+	assert!(client.get_events(4242, None, &[]).await.unwrap().is_empty());
+
+	connection_mock.assert();
+	latest_mock.assert();
+	events_mock.assert();
+}
+
+#[tokio::test]
+async fn test_graphql_errors_are_reported() {
+	let mut server = Server::new_async().await;
+	let connection_mock = create_graphql_connection_mock(&mut server);
+	let ledgers_mock = create_graphql_query_mock(
+		&mut server,
+		"Ledgers",
+		json!({
+			"data": null,
+			"errors": [{ "message": "indexer is still catching up" }]
+		}),
+		2,
+	);
+
+	let network = create_stellar_graphql_network(&server.url());
+	let transport = StellarGraphQLTransport::new(&network).await.unwrap();
+
+	let response = transport
+		.send_raw_request("getLedgers", Some(json!({ "startLedger": 100 })))
+		.await
+		.unwrap();
+	assert_eq!(response["error"]["message"], "indexer is still catching up");
+
+	let client = StellarClient::new_with_transport(transport.clone());
+	let err = client.get_blocks(100, None).await.unwrap_err();
+	assert!(format!("{:#}", err).contains("indexer is still catching up"));
+
+	let err = transport
+		.send_raw_request::<Value>("simulateTransaction", None)
+		.await
+		.unwrap_err();
+	assert!(err
+		.to_string()
+		.contains("not supported by the GraphQL transport"));
+
+	connection_mock.assert();
+	ledgers_mock.assert();
+}
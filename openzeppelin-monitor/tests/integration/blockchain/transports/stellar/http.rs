@@ -159,7 +159,7 @@ async fn test_send_raw_request() {
 	// Test without params
 	let no_params_mock = server
 		.mock("POST", "/")
-		.match_body(r#"{"id":1,"jsonrpc":"2.0","method":"testMethod","params":null}"#)
+		.match_body(r#"{"id":2,"jsonrpc":"2.0","method":"testMethod","params":null}"#)
 		.with_header("content-type", "application/json")
 		.with_status(200)
 		.with_body(r#"{"jsonrpc":"2.0","result":{"data":"success"},"id":1}"#)
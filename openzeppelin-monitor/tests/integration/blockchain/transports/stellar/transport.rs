@@ -1,6 +1,6 @@
 use mockall::predicate;
 use openzeppelin_monitor::{
-	models::{BlockType, ContractSpec, StellarFormattedContractSpec},
+	models::{BlockType, ContractSpec, StellarEventFilter, StellarFormattedContractSpec},
 	services::blockchain::{
 		BlockChainClient, StellarClient, StellarClientError, StellarClientTrait, TransportError,
 	},
@@ -292,6 +292,7 @@ async fn test_get_events_success() {
 	// Expected request parameters
 	let expected_params = json!({
 		"startLedger": 1,
+		"endLedger": 3,
 		"filters": [{
 			"type": "contract",
 		}],
@@ -336,7 +337,7 @@ async fn test_get_events_success() {
 		.returning(move |_, _| Ok(mock_response.clone()));
 
 	let client = StellarClient::new_with_transport(mock_stellar);
-	let result = client.get_events(1, Some(2)).await;
+	let result = client.get_events(1, Some(2), &[]).await;
 
 	assert!(result.is_ok());
 	let events = result.unwrap();
@@ -352,7 +353,7 @@ async fn test_get_events_invalid_sequence_range() {
 	let mock_stellar = MockStellarTransportClient::new();
 	let client = StellarClient::new_with_transport(mock_stellar);
 
-	let result = client.get_events(2, Some(1)).await;
+	let result = client.get_events(2, Some(1), &[]).await;
 	assert!(result.is_err());
 	let err = result.unwrap_err();
 
@@ -390,7 +391,7 @@ async fn test_get_events_failed_to_parse_event() {
 		.returning(move |_, _| Ok(mock_response.clone()));
 
 	let client = StellarClient::new_with_transport(mock_stellar);
-	let result = client.get_events(1, Some(2)).await;
+	let result = client.get_events(1, Some(2), &[]).await;
 
 	assert!(result.is_err());
 	let err = result.unwrap_err();
@@ -432,7 +433,7 @@ async fn test_get_events_outside_of_rpc_retention_window() {
 
 	let client = StellarClient::new_with_transport(mock_stellar);
 
-	let result = client.get_events(start_block, Some(end_block)).await;
+	let result = client.get_events(start_block, Some(end_block), &[]).await;
 
 	assert!(result.is_err());
 
@@ -475,7 +476,7 @@ async fn test_get_events_generic_rpc_error() {
 		.returning(move |_, _| Ok(mock_response.clone()));
 
 	let client = StellarClient::new_with_transport(mock_stellar);
-	let result = client.get_events(1, Some(2)).await;
+	let result = client.get_events(1, Some(2), &[]).await;
 
 	assert!(result.is_err());
 	let err = result.unwrap_err();
@@ -508,7 +509,7 @@ async fn test_get_events_unexpected_response_structure() {
 		.returning(move |_, _| Ok(mock_response.clone()));
 
 	let client = StellarClient::new_with_transport(mock_stellar);
-	let result = client.get_events(1, Some(2)).await;
+	let result = client.get_events(1, Some(2), &[]).await;
 
 	assert!(result.is_err());
 	let err = result.unwrap_err();
@@ -539,7 +540,7 @@ async fn test_get_events_transport_error() {
 		});
 
 	let client = StellarClient::new_with_transport(mock_stellar);
-	let result = client.get_events(1, Some(2)).await;
+	let result = client.get_events(1, Some(2), &[]).await;
 
 	assert!(result.is_err());
 	let err = result.unwrap_err();
@@ -557,6 +558,178 @@ async fn test_get_events_transport_error() {
 	),);
 }
 
+fn create_test_event_json(ledger: u32, id: &str, tx_hash: &str) -> Value {
+	json!({
+		"type": "contract",
+		"ledger": ledger,
+		"ledgerClosedAt": "2024-12-29T02:50:10Z",
+		"contractId": "CC5WP4L2CXUBZXZY3ZHK2XURV4H7VS6GKYF7K7WIHQSMEUDJYQ2E5TLK",
+		"id": id,
+		"inSuccessfulContractCall": true,
+		"txHash": tx_hash,
+		"topic": ["AAAADwAAAA9jb250cmFjdF9jYWxsZWQA"],
+		"value": "AAAAAQ=="
+	})
+}
+
+#[tokio::test]
+async fn test_get_events_with_filters_and_pagination() {
+	let mut mock_stellar = MockStellarTransportClient::new();
+	let mut sequence = mockall::Sequence::new();
+
+	let expected_filters = json!([
+		{
+			"type": "contract",
+			"contractIds": ["CC5WP4L2CXUBZXZY3ZHK2XURV4H7VS6GKYF7K7WIHQSMEUDJYQ2E5TLK"],
+			"topics": [["AAAADwAAAA9jb250cmFjdF9jYWxsZWQA", "*"]]
+		},
+		{
+			"type": "contract",
+			"contractIds": ["CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC"]
+		}
+	]);
+
+	let first_params = json!({
+		"startLedger": 10,
+		"endLedger": 21,
+		"filters": expected_filters.clone(),
+		"pagination": { "limit": 200 }
+	});
+	mock_stellar
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("getEvents"),
+			predicate::function(move |params: &Option<Value>| {
+				params.as_ref() == Some(&first_params)
+			}),
+		)
+		.times(1)
+		.in_sequence(&mut sequence)
+		.returning(|_, _| {
+			Ok(json!({
+				"result": {
+					"events": [create_test_event_json(10, "0000000001-0000000001", "aa")],
+					"cursor": "0000000001-0000000001"
+				}
+			}))
+		});
+
+	let second_params = json!({
+		"endLedger": 21,
+		"filters": expected_filters,
+		"pagination": { "cursor": "0000000001-0000000001", "limit": 200 }
+	});
+	mock_stellar
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("getEvents"),
+			predicate::function(move |params: &Option<Value>| {
+				params.as_ref() == Some(&second_params)
+			}),
+		)
+		.times(1)
+		.in_sequence(&mut sequence)
+		.returning(|_, _| {
+			Ok(json!({
+				"result": {
+					"events": [create_test_event_json(20, "0000000002-0000000001", "bb")],
+					"cursor": null
+				}
+			}))
+		});
+
+	let filters = vec![
+		StellarEventFilter {
+			contract_ids: vec![
+				"CC5WP4L2CXUBZXZY3ZHK2XURV4H7VS6GKYF7K7WIHQSMEUDJYQ2E5TLK".to_string()
+			],
+			topics: vec![vec![
+				"AAAADwAAAA9jb250cmFjdF9jYWxsZWQA".to_string(),
+				"*".to_string(),
+			]],
+		},
+		StellarEventFilter {
+			contract_ids: vec![
+				"CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC".to_string()
+			],
+			topics: vec![],
+		},
+	];
+
+	let client = StellarClient::new_with_transport(mock_stellar);
+	let events = client.get_events(10, Some(20), &filters).await.unwrap();
+
+	assert_eq!(events.len(), 2);
+	assert_eq!(events[0].transaction_hash, "aa");
+	assert_eq!(events[1].transaction_hash, "bb");
+}
+
+#[tokio::test]
+async fn test_get_events_splits_range_into_ledger_windows() {
+	let mut mock_stellar = MockStellarTransportClient::new();
+	let mut sequence = mockall::Sequence::new();
+
+	// The RPC serves at most 10,000 ledgers per request
+	for (start_ledger, end_ledger) in [(1u32, 10_001u32), (10_001, 20_001), (20_001, 25_001)] {
+		mock_stellar
+			.expect_send_raw_request()
+			.with(
+				predicate::eq("getEvents"),
+				predicate::function(move |params: &Option<Value>| {
+					let params = params.as_ref().unwrap();
+					params["startLedger"] == start_ledger && params["endLedger"] == end_ledger
+				}),
+			)
+			.times(1)
+			.in_sequence(&mut sequence)
+			.returning(move |_, _| {
+				Ok(json!({
+					"result": {
+						"events": [
+							create_test_event_json(start_ledger, &start_ledger.to_string(), "aa"),
+							// Events past the window are fetched with the next window
+							create_test_event_json(end_ledger, "past_window", "bb")
+						],
+						"cursor": "cursor"
+					}
+				}))
+			});
+	}
+
+	let client = StellarClient::new_with_transport(mock_stellar);
+	let events = client.get_events(1, Some(25_000), &[]).await.unwrap();
+
+	assert_eq!(
+		events.iter().map(|event| event.ledger).collect::<Vec<_>>(),
+		vec![1, 10_001, 20_001]
+	);
+}
+
+#[tokio::test]
+async fn test_get_events_filters_exceed_rpc_limits() {
+	let client = StellarClient::new_with_transport(MockStellarTransportClient::new());
+
+	let filters = vec![StellarEventFilter::default(); StellarEventFilter::MAX_FILTERS + 1];
+	let err = client.get_events(1, None, &filters).await.unwrap_err();
+	assert!(matches!(
+		err.source().and_then(|e| e.downcast_ref::<StellarClientError>()),
+		Some(StellarClientError::InvalidInput(ctx)) if ctx.to_string().contains("At most 5 event filters")
+	));
+
+	let filters = vec![StellarEventFilter {
+		contract_ids: vec![
+			"CC5WP4L2CXUBZXZY3ZHK2XURV4H7VS6GKYF7K7WIHQSMEUDJYQ2E5TLK".to_string();
+			StellarEventFilter::MAX_ENTRIES + 1
+		],
+		topics: vec![],
+	}];
+	let err = client.get_events(1, None, &filters).await.unwrap_err();
+	assert!(matches!(
+		err.source().and_then(|e| e.downcast_ref::<StellarClientError>()),
+		Some(StellarClientError::InvalidInput(ctx)) if ctx.to_string().contains("At most 5 contract ids")
+	));
+}
+
 #[tokio::test]
 async fn test_get_latest_block_number() {
 	let mut mock_stellar = MockStellarTransportClient::new();
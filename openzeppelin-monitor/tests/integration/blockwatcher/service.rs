@@ -1,6 +1,13 @@
 use futures::future::BoxFuture;
 use mockall::predicate;
-use std::sync::Arc;
+use std::{
+	collections::{BTreeSet, HashMap},
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc, Mutex,
+	},
+	time::Duration,
+};
 use tokio_cron_scheduler::JobScheduler;
 
 use crate::integration::mocks::{
@@ -8,12 +15,23 @@ use crate::integration::mocks::{
 	MockEVMTransportClient, MockEvmClientTrait, MockJobScheduler,
 };
 use openzeppelin_monitor::{
-	models::{BlockChainType, BlockType, Network, ProcessedBlock},
+	models::{
+		BlockChainType, BlockType, EVMMonitorMatch, EmitMatchesAfter, Finality, MatchConditions,
+		Monitor, MonitorMatch, Network, ProcessedBlock,
+	},
+	services::blockchain::BlockChainClient,
 	services::blockwatcher::{
-		process_new_blocks, BlockTracker, BlockTrackerTrait, BlockWatcherError,
-		BlockWatcherService, NetworkBlockWatcher,
+		get_latest_confirmed_block, process_new_blocks, process_new_blocks_with_sinks,
+		BlockCheckpoints, BlockStorage, BlockTracker, BlockTrackerTrait, BlockWatcherError,
+		BlockWatcherService, FailFastConfig, FileBlockStorage, MatchSink, NetworkBlockWatcher,
+		NetworkFetchLimiter, PendingBlocks, TriggerCompletion, WatcherFailureTracker,
+		DEFAULT_FAIL_FAST_MAX_FAILURES,
+	},
+	utils::{
+		get_cron_interval_ms,
+		metrics::{BACKFILL_BLOCKS_TOTAL, BLOCK_PROCESSING_TIMEOUTS_TOTAL},
+		tests::evm::transaction::TransactionBuilder,
 	},
-	utils::get_cron_interval_ms,
 };
 
 #[derive(Clone, Default)]
@@ -28,6 +46,19 @@ struct MockConfig {
 	history_size: usize,
 }
 
+/// Configures block storage checkpoint expectations, returning the given checkpoints
+fn expect_checkpoints(block_storage: &mut MockBlockStorage, checkpoints: BlockCheckpoints) {
+	block_storage
+		.expect_get_checkpoints()
+		.returning(move |_| Ok(checkpoints.clone()));
+	block_storage
+		.expect_mark_in_progress()
+		.returning(|_, _| Ok(()));
+	block_storage
+		.expect_mark_complete()
+		.returning(|_, _| Ok(()));
+}
+
 /// Helper function to setup mock implementations with configurable expectations
 fn setup_mocks(
 	config: MockConfig,
@@ -73,6 +104,8 @@ fn setup_mocks(
 		block_storage.expect_save_blocks().times(0);
 	}
 
+	expect_checkpoints(&mut block_storage, BlockCheckpoints::default());
+
 	// Wrap the mock in an Arc to share the instance
 	let block_storage_arc = Arc::new(block_storage);
 
@@ -168,7 +201,8 @@ async fn test_normal_block_range() {
 	});
 
 	// Create trigger handler that spawns an empty task
-	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
 
 	let block_tracker_arc = Arc::new(block_tracker);
 
@@ -215,7 +249,7 @@ async fn test_fresh_start_processing() {
 	});
 
 	let trigger_handler = Arc::new(|_processed_block: &ProcessedBlock| {
-		tokio::spawn(async move { /* Handle trigger */ })
+		tokio::spawn(async move { TriggerCompletion::completed() })
 	});
 
 	// Execute process_new_blocks
@@ -262,7 +296,8 @@ async fn test_no_new_blocks() {
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
 
-	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
 
 	// Process blocks
 	let result = process_new_blocks(
@@ -341,7 +376,8 @@ async fn test_concurrent_processing() {
 		})
 	};
 
-	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
 
 	// Process blocks
 	let result = process_new_blocks(
@@ -439,6 +475,7 @@ async fn test_ordered_trigger_handling() {
 
 			tokio::spawn(async move {
 				triggered_blocks.lock().await.push(block_number);
+				TriggerCompletion::completed()
 			})
 		})
 	};
@@ -509,7 +546,8 @@ async fn test_block_storage_enabled() {
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
 
-	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
 
 	let result = process_new_blocks(
 		&network,
@@ -561,7 +599,8 @@ async fn test_max_past_blocks_limit() {
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
 
-	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
 
 	let result = process_new_blocks(
 		&network,
@@ -579,6 +618,70 @@ async fn test_max_past_blocks_limit() {
 	);
 }
 
+#[tokio::test]
+async fn test_max_past_blocks_capped_by_limit() {
+	let mut network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
+	network.max_past_blocks = Some(1_000_000); // Misconfigured backfill window
+	network.max_past_blocks_limit = Some(3);
+
+	let config = MockConfig {
+		last_processed_block: Some(100),
+		latest_block: 110,
+		blocks_to_return: vec![
+			create_test_block(BlockChainType::EVM, 106),
+			create_test_block(BlockChainType::EVM, 107),
+			create_test_block(BlockChainType::EVM, 108),
+			create_test_block(BlockChainType::EVM, 109),
+		],
+		expected_save_block: Some(109),
+		// Should start at 106 (110 - 1 confirmation - 3 limit) instead of 101
+		expected_block_range: Some((106, Some(109))),
+		expected_tracked_blocks: vec![106, 107, 108, 109],
+		store_blocks: false,
+		history_size: 10,
+	};
+
+	let (block_storage, block_tracker, rpc_client) = setup_mocks(config);
+
+	let block_handler = Arc::new(|_: BlockType, network: Network| {
+		Box::pin(async move {
+			ProcessedBlock {
+				block_number: 0,
+				network_slug: network.slug,
+				processing_results: vec![],
+			}
+		}) as BoxFuture<'static, ProcessedBlock>
+	});
+
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
+
+	let backfilled_before = BACKFILL_BLOCKS_TOTAL
+		.with_label_values(&[&network.slug])
+		.get();
+
+	let result = process_new_blocks(
+		&network,
+		&rpc_client,
+		block_storage.clone(),
+		block_handler,
+		trigger_handler,
+		Arc::new(block_tracker),
+	)
+	.await;
+
+	assert!(
+		result.is_ok(),
+		"Block processing should succeed with a capped backfill"
+	);
+	assert_eq!(
+		BACKFILL_BLOCKS_TOTAL
+			.with_label_values(&[&network.slug])
+			.get(),
+		backfilled_before + 4
+	);
+}
+
 #[tokio::test]
 async fn test_max_past_blocks_limit_recommended() {
 	let mut network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
@@ -637,7 +740,8 @@ async fn test_max_past_blocks_limit_recommended() {
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
 
-	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
 
 	// Process blocks without limit
 	let result = process_new_blocks(
@@ -689,7 +793,8 @@ async fn test_confirmation_blocks() {
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
 
-	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
 
 	// Process blocks
 	let result = process_new_blocks(
@@ -705,6 +810,134 @@ async fn test_confirmation_blocks() {
 	assert!(result.is_ok(), "Block processing should succeed");
 }
 
+/// Processes the blocks after block 100 with the latest block at 110 and 2 confirmation blocks,
+/// expecting blocks 101 to `expected_last_block` to be processed
+///
+/// `tagged_block` is the reply of the client to the finality's block tag, `None` when the tag
+/// must not be requested.
+async fn assert_processed_with_finality(
+	finality: Finality,
+	tagged_block: Option<Option<u64>>,
+	expected_last_block: u64,
+) {
+	let mut network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
+	network.confirmation_blocks = 2;
+	network.finality = Some(finality);
+
+	let config = MockConfig {
+		last_processed_block: Some(100),
+		latest_block: 110,
+		blocks_to_return: (101..=expected_last_block)
+			.map(|number| create_test_block(BlockChainType::EVM, number))
+			.collect(),
+		expected_save_block: Some(expected_last_block),
+		expected_block_range: Some((101, Some(expected_last_block))),
+		expected_tracked_blocks: (101..=expected_last_block).collect(),
+		store_blocks: false,
+		history_size: 10,
+	};
+
+	let (block_storage, block_tracker, mut rpc_client) = setup_mocks(config);
+	match tagged_block {
+		Some(tagged_block) => {
+			let expected_tag = finality.block_tag().unwrap();
+			rpc_client
+				.expect_get_block_number_by_tag()
+				.withf(move |tag| tag == expected_tag)
+				.returning(move |_| Ok(tagged_block))
+				.times(1);
+		}
+		None => {
+			rpc_client.expect_get_block_number_by_tag().times(0);
+		}
+	}
+
+	let block_handler = Arc::new(|block: BlockType, network: Network| {
+		Box::pin(async move {
+			ProcessedBlock {
+				block_number: block.number().unwrap_or(0),
+				network_slug: network.slug,
+				processing_results: vec![],
+			}
+		}) as BoxFuture<'static, ProcessedBlock>
+	});
+
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
+
+	let result = process_new_blocks(
+		&network,
+		&rpc_client,
+		block_storage.clone(),
+		block_handler,
+		trigger_handler,
+		Arc::new(block_tracker),
+	)
+	.await;
+
+	assert!(
+		result.is_ok(),
+		"Block processing should succeed with {} finality",
+		finality
+	);
+}
+
+#[tokio::test]
+async fn test_finality_finalized_tag() {
+	// Processing stops at the finalized block rather than 2 blocks before the latest one
+	assert_processed_with_finality(Finality::Finalized, Some(Some(104)), 104).await;
+}
+
+#[tokio::test]
+async fn test_finality_safe_tag() {
+	assert_processed_with_finality(Finality::Safe, Some(Some(106)), 106).await;
+}
+
+#[tokio::test]
+async fn test_finality_tag_bounded_by_latest_block() {
+	// A tagged block reported ahead of the latest block does not extend the processed range
+	assert_processed_with_finality(Finality::Safe, Some(Some(115)), 110).await;
+}
+
+#[tokio::test]
+async fn test_finality_unsupported_tag_falls_back_to_confirmations() {
+	// 110 - 2 confirmation blocks
+	assert_processed_with_finality(Finality::Finalized, Some(None), 108).await;
+}
+
+#[tokio::test]
+async fn test_finality_latest() {
+	assert_processed_with_finality(Finality::Latest, None, 110).await;
+}
+
+#[tokio::test]
+async fn test_finality_confirmations() {
+	// 110 - 5 confirmations, ignoring the confirmation blocks
+	assert_processed_with_finality(Finality::Confirmations(5), None, 105).await;
+}
+
+#[tokio::test]
+async fn test_finality_tag_error() {
+	let mut network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
+	network.finality = Some(Finality::Finalized);
+
+	let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	rpc_client
+		.expect_get_latest_block_number()
+		.returning(|| Ok(110))
+		.times(1);
+	rpc_client
+		.expect_get_block_number_by_tag()
+		.returning(|_| Err(anyhow::anyhow!("connection refused")))
+		.times(1);
+
+	let result = get_latest_confirmed_block(&network, &rpc_client).await;
+	assert!(result
+		.unwrap_err()
+		.to_string()
+		.contains("Failed to get finalized block number"));
+}
+
 #[tokio::test]
 async fn test_process_new_blocks_storage_error() {
 	let network = create_test_network("Ethereum", "ethereum_mainnet", BlockChainType::EVM);
@@ -737,7 +970,8 @@ async fn test_process_new_blocks_storage_error() {
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
 
-	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
 
 	// Process blocks - should fail with storage error
 	let result = process_new_blocks(
@@ -785,7 +1019,8 @@ async fn test_process_new_blocks_network_errors() {
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
 
-	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
 
 	// Process blocks - should fail with network error
 	let result = process_new_blocks(
@@ -837,7 +1072,8 @@ async fn test_process_new_blocks_get_blocks_error() {
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
 
-	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
 
 	let result = process_new_blocks(
 		&network,
@@ -874,6 +1110,7 @@ async fn test_process_new_blocks_storage_save_error() {
 		.expect_save_blocks()
 		.returning(|_, _| Err(anyhow::anyhow!("Failed to save blocks")))
 		.times(1);
+	expect_checkpoints(&mut block_storage, BlockCheckpoints::default());
 	let block_storage = Arc::new(block_storage);
 
 	// Setup block tracker expectations
@@ -905,7 +1142,8 @@ async fn test_process_new_blocks_storage_save_error() {
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
 
-	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
 
 	let result = process_new_blocks(
 		&network,
@@ -937,6 +1175,7 @@ async fn test_process_new_blocks_save_last_processed_error() {
 		.expect_save_last_processed_block()
 		.returning(|_, _| Err(anyhow::anyhow!("Failed to save last processed block")))
 		.times(1);
+	expect_checkpoints(&mut block_storage, BlockCheckpoints::default());
 	let block_storage = Arc::new(block_storage);
 
 	// Setup block tracker expectations
@@ -968,7 +1207,8 @@ async fn test_process_new_blocks_save_last_processed_error() {
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
 
-	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
 
 	let result = process_new_blocks(
 		&network,
@@ -1003,6 +1243,7 @@ async fn test_process_new_blocks_storage_delete_error() {
 		.times(1);
 	// save_blocks should not be called if delete fails
 	block_storage.expect_save_blocks().times(0);
+	expect_checkpoints(&mut block_storage, BlockCheckpoints::default());
 	let block_storage = Arc::new(block_storage);
 
 	// Setup block tracker expectations
@@ -1034,7 +1275,8 @@ async fn test_process_new_blocks_storage_delete_error() {
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
 
-	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
 
 	let result = process_new_blocks(
 		&network,
@@ -1052,82 +1294,1190 @@ async fn test_process_new_blocks_storage_delete_error() {
 	}
 }
 
-#[tokio::test]
-async fn test_network_block_watcher_new() {
-	let network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
-	let block_storage = Arc::new(MockBlockStorage::new());
-	let block_handler = Arc::new(|_: BlockType, network: Network| {
+/// Block handler and trigger handler that record the block numbers they were called with
+#[allow(clippy::type_complexity)]
+fn recording_handlers() -> (
+	Arc<impl Fn(BlockType, Network) -> BoxFuture<'static, ProcessedBlock> + Send + Sync>,
+	Arc<impl Fn(&ProcessedBlock) -> tokio::task::JoinHandle<TriggerCompletion> + Send + Sync>,
+	Arc<Mutex<Vec<u64>>>,
+) {
+	let triggered = Arc::new(Mutex::new(Vec::new()));
+	let block_handler = Arc::new(|block: BlockType, network: Network| {
 		Box::pin(async move {
 			ProcessedBlock {
-				block_number: 0,
+				block_number: block.number().unwrap_or(0),
 				network_slug: network.slug,
 				processing_results: vec![],
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
-	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
-	let block_tracker = Arc::new(BlockTracker::new(10, Some(block_storage.clone())));
-
-	let watcher = NetworkBlockWatcher::<_, _, _, JobScheduler>::new(
-		network,
-		block_storage,
-		block_handler,
-		trigger_handler,
-		block_tracker,
-	)
-	.await;
-
-	assert!(watcher.is_ok());
-
-	// Not expected to be initialized since we haven't started the watcher
-	assert!(!watcher
-		.unwrap()
-		.scheduler
-		.inited
-		.load(std::sync::atomic::Ordering::Relaxed));
+	let trigger_handler = {
+		let triggered = triggered.clone();
+		Arc::new(move |block: &ProcessedBlock| {
+			triggered.lock().unwrap().push(block.block_number);
+			tokio::spawn(async { TriggerCompletion::completed() })
+		})
+	};
+	(block_handler, trigger_handler, triggered)
 }
 
 #[tokio::test]
-async fn test_network_block_watcher_start_stop() {
+async fn test_process_new_blocks_resumes_interrupted_block() {
 	let network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
-	let block_storage = Arc::new(MockBlockStorage::new());
-	let block_handler = Arc::new(|_: BlockType, network: Network| {
-		Box::pin(async move {
-			ProcessedBlock {
-				block_number: 0,
-				network_slug: network.slug,
-				processing_results: vec![],
-			}
-		}) as BoxFuture<'static, ProcessedBlock>
-	});
-	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
-	let block_tracker = Arc::new(BlockTracker::new(10, Some(block_storage.clone())));
+	let temp_dir = tempfile::tempdir().unwrap();
+	let block_storage = Arc::new(FileBlockStorage::new(temp_dir.path().to_path_buf()));
 
-	let watcher = NetworkBlockWatcher::<_, _, _, JobScheduler>::new(
-		network.clone(),
+	// Simulate a crash: block 101 completed, block 102 was interrupted mid-processing and the
+	// last processed block was never advanced past 100
+	block_storage
+		.save_last_processed_block(&network.slug, 100)
+		.await
+		.unwrap();
+	block_storage
+		.mark_in_progress(&network.slug, 101)
+		.await
+		.unwrap();
+	block_storage
+		.mark_in_progress(&network.slug, 102)
+		.await
+		.unwrap();
+	block_storage
+		.mark_complete(&network.slug, 101)
+		.await
+		.unwrap();
+
+	let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	rpc_client
+		.expect_get_latest_block_number()
+		.returning(|| Ok(105))
+		.times(1);
+	rpc_client
+		.expect_get_blocks()
+		.with(predicate::eq(101), predicate::eq(Some(104)))
+		.returning(|_, _| {
+			Ok((101..=104)
+				.map(|number| create_test_block(BlockChainType::EVM, number))
+				.collect())
+		})
+		.times(1);
+
+	let (block_handler, trigger_handler, triggered) = recording_handlers();
+
+	let result = process_new_blocks(
+		&network,
+		&rpc_client,
 		block_storage.clone(),
 		block_handler,
 		trigger_handler,
-		block_tracker,
+		Arc::new(BlockTracker::new(10, Some(block_storage.clone()))),
 	)
 	.await;
+	assert!(result.is_ok());
+
+	// The completed block is not triggered again, the interrupted one is re-processed
+	assert_eq!(*triggered.lock().unwrap(), vec![102, 103, 104]);
+	assert_eq!(
+		block_storage
+			.get_last_processed_block(&network.slug)
+			.await
+			.unwrap(),
+		Some(104)
+	);
+	assert_eq!(
+		block_storage.get_checkpoints(&network.slug).await.unwrap(),
+		BlockCheckpoints::default()
+	);
+}
+
+#[tokio::test]
+async fn test_process_new_blocks_fetches_interrupted_block_outside_range() {
+	let mut network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
+	network.max_past_blocks = Some(2);
+
+	// Block 50 was interrupted, but max_past_blocks moves the start of the range past it
+	let mut block_storage = MockBlockStorage::new();
+	block_storage
+		.expect_get_last_processed_block()
+		.returning(|_| Ok(Some(49)))
+		.times(1);
+	block_storage
+		.expect_save_last_processed_block()
+		.with(predicate::always(), predicate::eq(104))
+		.returning(|_, _| Ok(()))
+		.times(1);
+	expect_checkpoints(
+		&mut block_storage,
+		BlockCheckpoints {
+			in_progress: BTreeSet::from([50]),
+			completed: BTreeSet::new(),
+		},
+	);
+	let block_storage = Arc::new(block_storage);
 
-	// Setup mock RPC client
 	let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
 	rpc_client
 		.expect_get_latest_block_number()
-		.returning(|| Ok(100))
-		.times(0);
+		.returning(|| Ok(105))
+		.times(1);
+	rpc_client
+		.expect_get_blocks()
+		.with(predicate::eq(102), predicate::eq(Some(104)))
+		.returning(|_, _| {
+			Ok((102..=104)
+				.map(|number| create_test_block(BlockChainType::EVM, number))
+				.collect())
+		})
+		.times(1);
+	rpc_client
+		.expect_get_blocks()
+		.with(predicate::eq(50), predicate::eq(None))
+		.returning(|_, _| Ok(vec![create_test_block(BlockChainType::EVM, 50)]))
+		.times(1);
 
-	let mut watcher = watcher.unwrap();
-	// Test start
-	let started_result = watcher.start(rpc_client).await;
-	assert!(started_result.is_ok());
-	assert!(watcher.scheduler.inited().await);
+	let mut block_tracker = MockBlockTracker::default();
+	block_tracker.expect_record_block().returning(|_, _| Ok(()));
 
-	// Test stop
-	let stopped_result = watcher.stop().await;
-	assert!(stopped_result.is_ok());
+	let (block_handler, trigger_handler, triggered) = recording_handlers();
+
+	let result = process_new_blocks(
+		&network,
+		&rpc_client,
+		block_storage,
+		block_handler,
+		trigger_handler,
+		Arc::new(block_tracker),
+	)
+	.await;
+	assert!(result.is_ok());
+	assert_eq!(*triggered.lock().unwrap(), vec![50, 102, 103, 104]);
+}
+
+#[tokio::test]
+async fn test_process_new_blocks_marks_complete_after_triggers_executed() {
+	let network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
+
+	let completed_blocks = Arc::new(Mutex::new(Vec::new()));
+	let mut block_storage = MockBlockStorage::new();
+	block_storage
+		.expect_get_last_processed_block()
+		.returning(|_| Ok(Some(100)))
+		.times(1);
+	block_storage
+		.expect_save_last_processed_block()
+		.returning(|_, _| Ok(()))
+		.times(1);
+	block_storage
+		.expect_get_checkpoints()
+		.returning(|_| Ok(BlockCheckpoints::default()));
+	block_storage
+		.expect_mark_in_progress()
+		.returning(|_, _| Ok(()));
+	block_storage.expect_mark_complete().returning({
+		let completed_blocks = completed_blocks.clone();
+		move |_, block_number| {
+			completed_blocks.lock().unwrap().push(block_number);
+			Ok(())
+		}
+	});
+	let block_storage = Arc::new(block_storage);
+
+	let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	rpc_client
+		.expect_get_latest_block_number()
+		.returning(|| Ok(103))
+		.times(1);
+	rpc_client
+		.expect_get_blocks()
+		.with(predicate::eq(101), predicate::eq(Some(102)))
+		.returning(|_, _| {
+			Ok((101..=102)
+				.map(|number| create_test_block(BlockChainType::EVM, number))
+				.collect())
+		})
+		.times(1);
+
+	let mut block_tracker = MockBlockTracker::default();
+	block_tracker.expect_record_block().returning(|_, _| Ok(()));
+
+	// A stalled trigger queue consumer: blocks are queued but their triggers never run
+	let signals = Arc::new(Mutex::new(Vec::new()));
+	let (block_handler, _, _) = recording_handlers();
+	let trigger_handler = {
+		let signals = signals.clone();
+		Arc::new(move |_: &ProcessedBlock| {
+			let (signal, completion) = TriggerCompletion::pending();
+			signals.lock().unwrap().push(signal);
+			tokio::spawn(async move { completion })
+		})
+	};
+
+	let result = process_new_blocks(
+		&network,
+		&rpc_client,
+		block_storage,
+		block_handler,
+		trigger_handler,
+		Arc::new(block_tracker),
+	)
+	.await;
+	assert!(result.is_ok());
+
+	tokio::time::sleep(Duration::from_millis(50)).await;
+	assert!(
+		completed_blocks.lock().unwrap().is_empty(),
+		"Blocks must not be marked complete before their triggers are executed"
+	);
+
+	// Once the consumer executes the queued blocks, they are marked complete
+	for signal in signals.lock().unwrap().drain(..) {
+		signal.complete();
+	}
+	tokio::time::timeout(Duration::from_secs(5), async {
+		while completed_blocks.lock().unwrap().len() < 2 {
+			tokio::time::sleep(Duration::from_millis(10)).await;
+		}
+	})
+	.await
+	.expect("Executed blocks should be marked complete");
+	let mut completed = completed_blocks.lock().unwrap().clone();
+	completed.sort();
+	assert_eq!(completed, vec![101, 102]);
+}
+
+#[tokio::test]
+async fn test_process_new_blocks_does_not_mark_abandoned_block_complete() {
+	let network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
+
+	let mut block_storage = MockBlockStorage::new();
+	block_storage
+		.expect_get_last_processed_block()
+		.returning(|_| Ok(Some(100)))
+		.times(1);
+	block_storage
+		.expect_save_last_processed_block()
+		.returning(|_, _| Ok(()))
+		.times(1);
+	block_storage
+		.expect_get_checkpoints()
+		.returning(|_| Ok(BlockCheckpoints::default()));
+	block_storage
+		.expect_mark_in_progress()
+		.returning(|_, _| Ok(()));
+	let completed_blocks = Arc::new(Mutex::new(Vec::new()));
+	block_storage.expect_mark_complete().returning({
+		let completed_blocks = completed_blocks.clone();
+		move |_, block_number| {
+			completed_blocks.lock().unwrap().push(block_number);
+			Ok(())
+		}
+	});
+	let block_storage = Arc::new(block_storage);
+
+	let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	rpc_client
+		.expect_get_latest_block_number()
+		.returning(|| Ok(102))
+		.times(1);
+	rpc_client
+		.expect_get_blocks()
+		.with(predicate::eq(101), predicate::eq(Some(101)))
+		.returning(|_, _| Ok(vec![create_test_block(BlockChainType::EVM, 101)]))
+		.times(1);
+
+	let mut block_tracker = MockBlockTracker::default();
+	block_tracker.expect_record_block().returning(|_, _| Ok(()));
+
+	// The trigger handler was interrupted by a shutdown before executing the block's triggers
+	let (block_handler, _, _) = recording_handlers();
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::abandoned() }));
+
+	let result = process_new_blocks(
+		&network,
+		&rpc_client,
+		block_storage,
+		block_handler,
+		trigger_handler,
+		Arc::new(block_tracker),
+	)
+	.await;
+	assert!(result.is_ok());
+
+	tokio::time::sleep(Duration::from_millis(50)).await;
+	assert!(
+		completed_blocks.lock().unwrap().is_empty(),
+		"A block abandoned on shutdown must stay in progress"
+	);
+}
+
+/// Client serving a single latest block number and the blocks requested from it
+fn block_range_client(latest_block: u64) -> MockEvmClientTrait<MockEVMTransportClient> {
+	let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	rpc_client
+		.expect_get_latest_block_number()
+		.returning(move || Ok(latest_block));
+	rpc_client.expect_get_blocks().returning(|start, end| {
+		Ok((start..=end.unwrap_or(start))
+			.map(|number| create_test_block(BlockChainType::EVM, number))
+			.collect())
+	});
+	rpc_client
+}
+
+#[tokio::test]
+async fn test_process_new_blocks_replays_queued_blocks_after_crash() {
+	let network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
+	let temp_dir = tempfile::tempdir().unwrap();
+	let block_storage = Arc::new(FileBlockStorage::new(temp_dir.path().to_path_buf()));
+	block_storage
+		.save_last_processed_block(&network.slug, 100)
+		.await
+		.unwrap();
+	let block_tracker = Arc::new(BlockTracker::new(10, Some(block_storage.clone())));
+
+	// A stalled trigger queue consumer: blocks are queued but their triggers never run
+	let signals = Arc::new(Mutex::new(Vec::new()));
+	let queued = Arc::new(Mutex::new(Vec::new()));
+	let (block_handler, _, _) = recording_handlers();
+	let trigger_handler = {
+		let signals = signals.clone();
+		let queued = queued.clone();
+		Arc::new(move |block: &ProcessedBlock| {
+			queued.lock().unwrap().push(block.block_number);
+			let (signal, completion) = TriggerCompletion::pending();
+			signals.lock().unwrap().push(signal);
+			tokio::spawn(async move { completion })
+		})
+	};
+
+	// Two polls of the same run move the cursor past the queued blocks, without queueing
+	// the first block again
+	let pending_blocks = PendingBlocks::default();
+	for latest_block in [102, 103] {
+		let result = process_new_blocks_with_sinks(
+			&network,
+			&block_range_client(latest_block),
+			block_storage.clone(),
+			block_handler.clone(),
+			trigger_handler.clone(),
+			block_tracker.clone(),
+			Arc::from([]),
+			pending_blocks.clone(),
+		)
+		.await;
+		assert!(result.is_ok());
+	}
+	assert_eq!(*queued.lock().unwrap(), vec![101, 102]);
+	assert_eq!(
+		block_storage
+			.get_last_processed_block(&network.slug)
+			.await
+			.unwrap(),
+		Some(102)
+	);
+	let checkpoints = block_storage.get_checkpoints(&network.slug).await.unwrap();
+	assert_eq!(checkpoints.in_progress, BTreeSet::from([101, 102]));
+
+	// The process crashes before the queued triggers run
+	signals.lock().unwrap().clear();
+
+	// After the restart, the queued blocks are replayed although the cursor is past them
+	let (block_handler, trigger_handler, triggered) = recording_handlers();
+	let result = process_new_blocks(
+		&network,
+		&block_range_client(103),
+		block_storage.clone(),
+		block_handler,
+		trigger_handler,
+		Arc::new(BlockTracker::new(10, Some(block_storage.clone()))),
+	)
+	.await;
+	assert!(result.is_ok());
+	assert_eq!(*triggered.lock().unwrap(), vec![101, 102]);
+	assert_eq!(
+		block_storage.get_checkpoints(&network.slug).await.unwrap(),
+		BlockCheckpoints::default()
+	);
+}
+
+/// Start and optional end block of a `get_blocks` request
+type BlockRange = (u64, Option<u64>);
+
+/// Client serving each request after a delay that shrinks with the block number, so later
+/// blocks arrive first, while recording the requests and the peak number in flight
+#[derive(Clone, Default)]
+struct SlowBlockClient {
+	in_flight: Arc<AtomicUsize>,
+	max_in_flight: Arc<AtomicUsize>,
+	requests: Arc<Mutex<Vec<BlockRange>>>,
+}
+
+#[async_trait::async_trait]
+impl BlockChainClient for SlowBlockClient {
+	async fn get_latest_block_number(&self) -> Result<u64, anyhow::Error> {
+		Ok(111)
+	}
+
+	async fn get_blocks(
+		&self,
+		start_block: u64,
+		end_block: Option<u64>,
+	) -> Result<Vec<BlockType>, anyhow::Error> {
+		self.requests.lock().unwrap().push((start_block, end_block));
+		let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+		self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+
+		tokio::time::sleep(tokio::time::Duration::from_millis(5 * (120 - start_block))).await;
+
+		self.in_flight.fetch_sub(1, Ordering::SeqCst);
+		Ok((start_block..=end_block.unwrap_or(start_block))
+			.map(|number| create_test_block(BlockChainType::EVM, number))
+			.collect())
+	}
+}
+
+#[tokio::test]
+async fn test_process_new_blocks_backfill_concurrency() {
+	let mut network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
+	network.max_past_blocks = Some(50);
+	network.backfill_concurrency = Some(4);
+
+	let temp_dir = tempfile::tempdir().unwrap();
+	let block_storage = Arc::new(FileBlockStorage::new(temp_dir.path().to_path_buf()));
+	block_storage
+		.save_last_processed_block(&network.slug, 100)
+		.await
+		.unwrap();
+
+	let rpc_client = SlowBlockClient::default();
+	let (block_handler, trigger_handler, triggered) = recording_handlers();
+
+	let result = process_new_blocks(
+		&network,
+		&rpc_client,
+		block_storage.clone(),
+		block_handler,
+		trigger_handler,
+		Arc::new(BlockTracker::new(10, Some(block_storage.clone()))),
+	)
+	.await;
+	assert!(result.is_ok());
+
+	// Each of the 10 blocks is fetched on its own, never more than 4 at a time
+	let mut requests = rpc_client.requests.lock().unwrap().clone();
+	requests.sort();
+	assert_eq!(
+		requests,
+		(101..=110).map(|number| (number, None)).collect::<Vec<_>>()
+	);
+	assert_eq!(rpc_client.max_in_flight.load(Ordering::SeqCst), 4);
+
+	// Triggers are still emitted in block order
+	assert_eq!(*triggered.lock().unwrap(), (101..=110).collect::<Vec<_>>());
+	assert_eq!(
+		block_storage
+			.get_last_processed_block(&network.slug)
+			.await
+			.unwrap(),
+		Some(110)
+	);
+}
+
+#[tokio::test]
+async fn test_process_new_blocks_watchdog_skips_slow_block() {
+	let mut network =
+		create_test_network("Watchdog Network", "watchdog-network", BlockChainType::EVM);
+	network.max_block_processing_ms = Some(50);
+	network.block_processing_retries = Some(1);
+	network.store_blocks = Some(true);
+
+	let temp_dir = tempfile::tempdir().unwrap();
+	let block_storage = Arc::new(FileBlockStorage::new(temp_dir.path().to_path_buf()));
+	block_storage
+		.save_last_processed_block(&network.slug, 100)
+		.await
+		.unwrap();
+
+	let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	rpc_client
+		.expect_get_latest_block_number()
+		.returning(|| Ok(104))
+		.times(1);
+	rpc_client
+		.expect_get_blocks()
+		.with(predicate::eq(101), predicate::eq(Some(103)))
+		.returning(|_, _| {
+			Ok((101..=103)
+				.map(|number| create_test_block(BlockChainType::EVM, number))
+				.collect())
+		})
+		.times(1);
+
+	// Block 102 always hangs, block 103 only hangs on its first attempt. Every block that is
+	// processed emits one match.
+	let attempts = Arc::new(Mutex::new(Vec::new()));
+	let block_handler = {
+		let attempts = attempts.clone();
+		Arc::new(move |block: BlockType, network: Network| {
+			let block_number = block.number().unwrap_or(0);
+			let attempt = {
+				let mut attempts = attempts.lock().unwrap();
+				attempts.push(block_number);
+				attempts
+					.iter()
+					.filter(|&&number| number == block_number)
+					.count()
+			};
+			Box::pin(async move {
+				if block_number == 102 || (block_number == 103 && attempt == 1) {
+					tokio::time::sleep(std::time::Duration::from_secs(30)).await;
+				}
+				ProcessedBlock {
+					block_number,
+					network_slug: network.slug,
+					processing_results: vec![MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+						monitor: Monitor::default(),
+						transaction: TransactionBuilder::new().build(),
+						receipt: None,
+						revert_reason: None,
+						logs: None,
+						block_timestamp: None,
+						network_slug: "watchdog-network".to_string(),
+						matched_on: MatchConditions::default(),
+						matched_on_args: None,
+						enrichment: HashMap::new(),
+					}))],
+				}
+			}) as BoxFuture<'static, ProcessedBlock>
+		})
+	};
+
+	let dispatched = Arc::new(Mutex::new(Vec::new()));
+	let trigger_handler = {
+		let dispatched = dispatched.clone();
+		Arc::new(move |block: &ProcessedBlock| {
+			dispatched
+				.lock()
+				.unwrap()
+				.push((block.block_number, block.processing_results.len()));
+			tokio::spawn(async { TriggerCompletion::completed() })
+		})
+	};
+
+	let result = process_new_blocks(
+		&network,
+		&rpc_client,
+		block_storage.clone(),
+		block_handler,
+		trigger_handler,
+		Arc::new(BlockTracker::new(10, Some(block_storage.clone()))),
+	)
+	.await;
+	assert!(result.is_ok());
+
+	// The hung block is skipped without matches and the watcher moves on
+	assert_eq!(
+		*dispatched.lock().unwrap(),
+		vec![(101, 1), (102, 0), (103, 1)]
+	);
+	let mut attempts = attempts.lock().unwrap().clone();
+	attempts.sort();
+	assert_eq!(attempts, vec![101, 102, 102, 103, 103]);
+	assert_eq!(
+		BLOCK_PROCESSING_TIMEOUTS_TOTAL
+			.with_label_values(&["watchdog-network"])
+			.get(),
+		3
+	);
+	assert_eq!(
+		block_storage
+			.get_last_processed_block(&network.slug)
+			.await
+			.unwrap(),
+		Some(103)
+	);
+
+	let missed_blocks =
+		std::fs::read_to_string(temp_dir.path().join("watchdog-network_missed_blocks.txt"))
+			.unwrap();
+	assert_eq!(missed_blocks.trim(), "102");
+}
+
+/// Sink counting the matches it receives, in the order of their block numbers
+#[derive(Default)]
+struct CountingSink {
+	received: Mutex<Vec<String>>,
+}
+
+#[async_trait::async_trait]
+impl MatchSink for CountingSink {
+	async fn on_match(
+		&self,
+		monitor_match: &MonitorMatch,
+	) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+		if let MonitorMatch::EVM(evm_match) = monitor_match {
+			self.received
+				.lock()
+				.unwrap()
+				.push(evm_match.monitor.name.clone());
+		}
+		Ok(())
+	}
+}
+
+/// Sink that always fails
+struct FailingSink;
+
+#[async_trait::async_trait]
+impl MatchSink for FailingSink {
+	async fn on_match(
+		&self,
+		_monitor_match: &MonitorMatch,
+	) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+		Err("sink unavailable".into())
+	}
+}
+
+/// Block handler emitting one match per block, named after the block number
+fn matching_block_handler(
+) -> Arc<impl Fn(BlockType, Network) -> BoxFuture<'static, ProcessedBlock> + Send + Sync> {
+	Arc::new(|block: BlockType, network: Network| {
+		Box::pin(async move {
+			let block_number = block.number().unwrap_or(0);
+			ProcessedBlock {
+				block_number,
+				network_slug: network.slug,
+				processing_results: vec![MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+					monitor: Monitor {
+						name: format!("block_{}", block_number),
+						..Default::default()
+					},
+					transaction: TransactionBuilder::new().build(),
+					receipt: None,
+					revert_reason: None,
+					logs: None,
+					block_timestamp: None,
+					network_slug: "test-network".to_string(),
+					matched_on: MatchConditions::default(),
+					matched_on_args: None,
+					enrichment: HashMap::new(),
+				}))],
+			}
+		}) as BoxFuture<'static, ProcessedBlock>
+	})
+}
+
+#[tokio::test]
+async fn test_process_new_blocks_forwards_matches_to_sinks() {
+	let network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
+	let temp_dir = tempfile::tempdir().unwrap();
+	let block_storage = Arc::new(FileBlockStorage::new(temp_dir.path().to_path_buf()));
+	block_storage
+		.save_last_processed_block(&network.slug, 100)
+		.await
+		.unwrap();
+
+	let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	rpc_client
+		.expect_get_latest_block_number()
+		.returning(|| Ok(104))
+		.times(1);
+	rpc_client
+		.expect_get_blocks()
+		.with(predicate::eq(101), predicate::eq(Some(103)))
+		.returning(|_, _| {
+			Ok((101..=103)
+				.map(|number| create_test_block(BlockChainType::EVM, number))
+				.collect())
+		})
+		.times(1);
+
+	let block_handler = matching_block_handler();
+	let (_, trigger_handler, triggered) = recording_handlers();
+
+	let sink = Arc::new(CountingSink::default());
+	let result = process_new_blocks_with_sinks(
+		&network,
+		&rpc_client,
+		block_storage.clone(),
+		block_handler,
+		trigger_handler,
+		Arc::new(BlockTracker::new(10, Some(block_storage.clone()))),
+		Arc::from([Arc::new(FailingSink) as Arc<dyn MatchSink>, sink.clone()]),
+		PendingBlocks::default(),
+	)
+	.await;
+
+	// The failing sink does not prevent the other sink or the triggers from running
+	assert!(result.is_ok());
+	assert_eq!(
+		*sink.received.lock().unwrap(),
+		vec!["block_101", "block_102", "block_103"]
+	);
+	assert_eq!(*triggered.lock().unwrap(), vec![101, 102, 103]);
+	assert_eq!(
+		block_storage
+			.get_last_processed_block(&network.slug)
+			.await
+			.unwrap(),
+		Some(103)
+	);
+}
+
+#[tokio::test]
+async fn test_process_new_blocks_silences_matches_before_emit_matches_after() {
+	let mut network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
+	network.emit_matches_after = Some(EmitMatchesAfter::Block(103));
+	let temp_dir = tempfile::tempdir().unwrap();
+	let block_storage = Arc::new(FileBlockStorage::new(temp_dir.path().to_path_buf()));
+	block_storage
+		.save_last_processed_block(&network.slug, 100)
+		.await
+		.unwrap();
+
+	let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	rpc_client
+		.expect_get_latest_block_number()
+		.returning(|| Ok(105))
+		.times(1);
+	rpc_client
+		.expect_get_blocks()
+		.with(predicate::eq(101), predicate::eq(Some(104)))
+		.returning(|_, _| {
+			Ok((101..=104)
+				.map(|number| create_test_block(BlockChainType::EVM, number))
+				.collect())
+		})
+		.times(1);
+
+	let (_, trigger_handler, triggered) = recording_handlers();
+	let sink = Arc::new(CountingSink::default());
+	let result = process_new_blocks_with_sinks(
+		&network,
+		&rpc_client,
+		block_storage.clone(),
+		matching_block_handler(),
+		trigger_handler,
+		Arc::new(BlockTracker::new(10, Some(block_storage.clone()))),
+		Arc::from([sink.clone() as Arc<dyn MatchSink>]),
+		PendingBlocks::default(),
+	)
+	.await;
+
+	// Matches before the cutoff neither fire triggers nor reach sinks
+	assert!(result.is_ok());
+	assert_eq!(*triggered.lock().unwrap(), vec![103, 104]);
+	assert_eq!(
+		*sink.received.lock().unwrap(),
+		vec!["block_103", "block_104"]
+	);
+
+	// The silenced blocks still advance the cursor
+	assert_eq!(
+		block_storage
+			.get_last_processed_block(&network.slug)
+			.await
+			.unwrap(),
+		Some(104)
+	);
+	let checkpoints = block_storage.get_checkpoints(&network.slug).await.unwrap();
+	assert!(checkpoints.in_progress.is_empty());
+}
+
+#[tokio::test]
+async fn test_block_watcher_service_add_match_sink() {
+	let block_storage = Arc::new(MockBlockStorage::new());
+	let (block_handler, trigger_handler, _) = recording_handlers();
+	let mut service = BlockWatcherService::<_, _, _, JobScheduler>::new(
+		block_storage.clone(),
+		block_handler,
+		trigger_handler,
+		Arc::new(BlockTracker::new(10, Some(block_storage))),
+	)
+	.await
+	.unwrap();
+	assert!(service.match_sinks.is_empty());
+
+	service.add_match_sink(Arc::new(CountingSink::default()));
+	assert_eq!(service.match_sinks.len(), 1);
+}
+
+#[tokio::test]
+async fn test_block_watcher_service_fail_fast_signals_after_threshold() {
+	let mut network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
+	network.cron_schedule = "@interval:20ms".to_string();
+
+	let mut block_storage = MockBlockStorage::new();
+	block_storage
+		.expect_get_last_processed_block()
+		.returning(|_| Ok(Some(100)));
+	let block_storage = Arc::new(block_storage);
+	let (block_handler, trigger_handler, _) = recording_handlers();
+	let mut service = BlockWatcherService::<_, _, _, JobScheduler>::new(
+		block_storage.clone(),
+		block_handler,
+		trigger_handler,
+		Arc::new(BlockTracker::new(10, Some(block_storage))),
+	)
+	.await
+	.unwrap();
+
+	let (failure_tracker, mut fatal_failures) = WatcherFailureTracker::new(FailFastConfig {
+		max_consecutive_failures: 3,
+	});
+	service.set_failure_tracker(failure_tracker);
+
+	// Every polling run fails to reach the RPC
+	let polls = Arc::new(AtomicUsize::new(0));
+	let polls_clone = polls.clone();
+	let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	rpc_client.expect_clone().returning(move || {
+		let polls = polls_clone.clone();
+		let mut client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+		client.expect_get_latest_block_number().returning(move || {
+			polls.fetch_add(1, Ordering::SeqCst);
+			Err(anyhow::anyhow!("RPC error"))
+		});
+		client
+	});
+
+	service
+		.start_network_watcher(&network, rpc_client)
+		.await
+		.unwrap();
+
+	let failure = tokio::time::timeout(std::time::Duration::from_secs(5), fatal_failures.recv())
+		.await
+		.expect("fatal failure not signaled")
+		.unwrap();
+	assert_eq!(failure.network_slug, network.slug);
+	assert_eq!(failure.consecutive_failures, 3);
+	assert!(failure.error.contains("Failed to process blocks"));
+	assert!(polls.load(Ordering::SeqCst) >= 3);
+
+	service.stop_network_watcher(&network.slug).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_block_watcher_service_without_fail_fast_keeps_running() {
+	let mut network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
+	network.cron_schedule = "@interval:20ms".to_string();
+
+	let mut block_storage = MockBlockStorage::new();
+	block_storage
+		.expect_get_last_processed_block()
+		.returning(|_| Ok(Some(100)));
+	let block_storage = Arc::new(block_storage);
+	let (block_handler, trigger_handler, _) = recording_handlers();
+	let service = BlockWatcherService::<_, _, _, JobScheduler>::new(
+		block_storage.clone(),
+		block_handler,
+		trigger_handler,
+		Arc::new(BlockTracker::new(10, Some(block_storage))),
+	)
+	.await
+	.unwrap();
+	assert!(service.failure_tracker.is_none());
+
+	let polls = Arc::new(AtomicUsize::new(0));
+	let polls_clone = polls.clone();
+	let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	rpc_client.expect_clone().returning(move || {
+		let polls = polls_clone.clone();
+		let mut client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+		client.expect_get_latest_block_number().returning(move || {
+			polls.fetch_add(1, Ordering::SeqCst);
+			Err(anyhow::anyhow!("RPC error"))
+		});
+		client
+	});
+
+	service
+		.start_network_watcher(&network, rpc_client)
+		.await
+		.unwrap();
+
+	// The watcher keeps polling well past the fail-fast threshold
+	tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+	assert!(polls.load(Ordering::SeqCst) > DEFAULT_FAIL_FAST_MAX_FAILURES as usize);
+	assert!(service
+		.active_watchers
+		.read()
+		.await
+		.contains_key(&network.slug));
+
+	service.stop_network_watcher(&network.slug).await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_block_watcher_service_fetch_limiter_bounds_concurrent_polls() {
+	let mut block_storage = MockBlockStorage::new();
+	block_storage
+		.expect_get_last_processed_block()
+		.returning(|_| Ok(Some(100)));
+	let block_storage = Arc::new(block_storage);
+	let (block_handler, trigger_handler, _) = recording_handlers();
+	let mut service = BlockWatcherService::<_, _, _, JobScheduler>::new(
+		block_storage.clone(),
+		block_handler,
+		trigger_handler,
+		Arc::new(BlockTracker::new(10, Some(block_storage))),
+	)
+	.await
+	.unwrap();
+	service.set_fetch_limiter(NetworkFetchLimiter::new(2));
+
+	// Every network polls far more often than its polls complete
+	let in_flight = Arc::new(AtomicUsize::new(0));
+	let max_in_flight = Arc::new(AtomicUsize::new(0));
+	let mut polls = Vec::new();
+	let mut networks = Vec::new();
+	for index in 0..6 {
+		let slug = format!("test-network-{}", index);
+		let mut network = create_test_network(&slug, &slug, BlockChainType::EVM);
+		network.cron_schedule = "@interval:10ms".to_string();
+
+		let network_polls = Arc::new(AtomicUsize::new(0));
+		let (in_flight, max_in_flight, network_polls_clone) = (
+			in_flight.clone(),
+			max_in_flight.clone(),
+			network_polls.clone(),
+		);
+		let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+		rpc_client.expect_clone().returning(move || {
+			let (in_flight, max_in_flight, network_polls) = (
+				in_flight.clone(),
+				max_in_flight.clone(),
+				network_polls_clone.clone(),
+			);
+			let mut client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+			client.expect_get_latest_block_number().returning(move || {
+				let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+				max_in_flight.fetch_max(current, Ordering::SeqCst);
+				std::thread::sleep(std::time::Duration::from_millis(30));
+				in_flight.fetch_sub(1, Ordering::SeqCst);
+				network_polls.fetch_add(1, Ordering::SeqCst);
+				Err(anyhow::anyhow!("RPC error"))
+			});
+			client
+		});
+
+		service
+			.start_network_watcher(&network, rpc_client)
+			.await
+			.unwrap();
+		polls.push(network_polls);
+		networks.push(network);
+	}
+
+	tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+	for network in &networks {
+		service.stop_network_watcher(&network.slug).await.unwrap();
+	}
+
+	assert!(max_in_flight.load(Ordering::SeqCst) <= 2);
+	// Each network keeps being polled on its own schedule
+	for network_polls in &polls {
+		assert!(network_polls.load(Ordering::SeqCst) > 0);
+	}
+}
+
+#[tokio::test]
+async fn test_network_block_watcher_new() {
+	let network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
+	let block_storage = Arc::new(MockBlockStorage::new());
+	let block_handler = Arc::new(|_: BlockType, network: Network| {
+		Box::pin(async move {
+			ProcessedBlock {
+				block_number: 0,
+				network_slug: network.slug,
+				processing_results: vec![],
+			}
+		}) as BoxFuture<'static, ProcessedBlock>
+	});
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
+	let block_tracker = Arc::new(BlockTracker::new(10, Some(block_storage.clone())));
+
+	let watcher = NetworkBlockWatcher::<_, _, _, JobScheduler>::new(
+		network,
+		block_storage,
+		block_handler,
+		trigger_handler,
+		block_tracker,
+	)
+	.await;
+
+	assert!(watcher.is_ok());
+
+	// Not expected to be initialized since we haven't started the watcher
+	assert!(!watcher
+		.unwrap()
+		.scheduler
+		.inited
+		.load(std::sync::atomic::Ordering::Relaxed));
+}
+
+#[tokio::test]
+async fn test_network_block_watcher_start_stop() {
+	let network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
+	let block_storage = Arc::new(MockBlockStorage::new());
+	let block_handler = Arc::new(|_: BlockType, network: Network| {
+		Box::pin(async move {
+			ProcessedBlock {
+				block_number: 0,
+				network_slug: network.slug,
+				processing_results: vec![],
+			}
+		}) as BoxFuture<'static, ProcessedBlock>
+	});
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
+	let block_tracker = Arc::new(BlockTracker::new(10, Some(block_storage.clone())));
+
+	let watcher = NetworkBlockWatcher::<_, _, _, JobScheduler>::new(
+		network.clone(),
+		block_storage.clone(),
+		block_handler,
+		trigger_handler,
+		block_tracker,
+	)
+	.await;
+
+	// Setup mock RPC client
+	let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	rpc_client
+		.expect_get_latest_block_number()
+		.returning(|| Ok(100))
+		.times(0);
+
+	let mut watcher = watcher.unwrap();
+	// Test start
+	let started_result = watcher.start(rpc_client).await;
+	assert!(started_result.is_ok());
+	assert!(watcher.scheduler.inited().await);
+
+	// Test stop
+	let stopped_result = watcher.stop().await;
+	assert!(stopped_result.is_ok());
+}
+
+#[tokio::test]
+async fn test_network_block_watcher_interval_schedule() {
+	let mut network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
+	network.cron_schedule = "@interval:50ms".to_string();
+
+	// Each polling run starts by reading the last processed block; failing here keeps the
+	// run short while letting us count how often the watcher polled.
+	let polls = Arc::new(AtomicUsize::new(0));
+	let polls_clone = polls.clone();
+	let mut block_storage = MockBlockStorage::new();
+	block_storage
+		.expect_get_last_processed_block()
+		.with(predicate::always())
+		.returning(move |_| {
+			polls_clone.fetch_add(1, Ordering::SeqCst);
+			Err(anyhow::anyhow!("storage unavailable"))
+		});
+	let block_storage = Arc::new(block_storage);
+	let block_handler = Arc::new(|_: BlockType, network: Network| {
+		Box::pin(async move {
+			ProcessedBlock {
+				block_number: 0,
+				network_slug: network.slug,
+				processing_results: vec![],
+			}
+		}) as BoxFuture<'static, ProcessedBlock>
+	});
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
+	let block_tracker = Arc::new(BlockTracker::new(10, Some(block_storage.clone())));
+
+	let mut watcher = NetworkBlockWatcher::<_, _, _, JobScheduler>::new(
+		network.clone(),
+		block_storage.clone(),
+		block_handler,
+		trigger_handler,
+		block_tracker,
+	)
+	.await
+	.unwrap();
+
+	let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	rpc_client
+		.expect_clone()
+		.returning(MockEvmClientTrait::<MockEVMTransportClient>::new);
+
+	assert!(watcher.start(rpc_client).await.is_ok());
+	// Interval schedules bypass the cron scheduler entirely
+	assert!(!watcher.scheduler.inited().await);
+
+	tokio::time::sleep(std::time::Duration::from_millis(275)).await;
+	assert!(polls.load(Ordering::SeqCst) >= 3);
+
+	assert!(watcher.stop().await.is_ok());
+	let polls_after_stop = polls.load(Ordering::SeqCst);
+	tokio::time::sleep(std::time::Duration::from_millis(150)).await;
+	assert_eq!(polls.load(Ordering::SeqCst), polls_after_stop);
+}
+
+#[tokio::test]
+async fn test_network_block_watcher_adaptive_schedule() {
+	let mut network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
+	network.cron_schedule = "@adaptive".to_string();
+	network.block_time_ms = 200;
+
+	// Failing runs observe no new block, so polls back off from half a block time
+	let polls = Arc::new(AtomicUsize::new(0));
+	let polls_clone = polls.clone();
+	let mut block_storage = MockBlockStorage::new();
+	block_storage
+		.expect_get_last_processed_block()
+		.with(predicate::always())
+		.returning(move |_| {
+			polls_clone.fetch_add(1, Ordering::SeqCst);
+			Err(anyhow::anyhow!("storage unavailable"))
+		});
+	let block_storage = Arc::new(block_storage);
+	let block_handler = Arc::new(|_: BlockType, network: Network| {
+		Box::pin(async move {
+			ProcessedBlock {
+				block_number: 0,
+				network_slug: network.slug,
+				processing_results: vec![],
+			}
+		}) as BoxFuture<'static, ProcessedBlock>
+	});
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
+	let block_tracker = Arc::new(BlockTracker::new(10, Some(block_storage.clone())));
+
+	let mut watcher = NetworkBlockWatcher::<_, _, _, JobScheduler>::new(
+		network.clone(),
+		block_storage.clone(),
+		block_handler,
+		trigger_handler,
+		block_tracker,
+	)
+	.await
+	.unwrap();
+
+	let mut rpc_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	rpc_client
+		.expect_clone()
+		.returning(MockEvmClientTrait::<MockEVMTransportClient>::new);
+
+	assert!(watcher.start(rpc_client).await.is_ok());
+	assert!(!watcher.scheduler.inited().await);
+
+	// Polls at 0ms, 100ms, 300ms and 700ms, each reading the last processed block twice
+	tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+	assert_eq!(polls.load(Ordering::SeqCst), 6);
+
+	assert!(watcher.stop().await.is_ok());
+	let polls_after_stop = polls.load(Ordering::SeqCst);
+	tokio::time::sleep(std::time::Duration::from_millis(400)).await;
+	assert_eq!(polls.load(Ordering::SeqCst), polls_after_stop);
 }
 
 #[tokio::test]
@@ -1143,7 +2493,8 @@ async fn test_block_watcher_service_start_stop_network() {
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
-	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
 	let block_tracker = Arc::new(BlockTracker::new(10, Some(block_storage.clone())));
 
 	let service = BlockWatcherService::<_, _, _, JobScheduler>::new(
@@ -1213,7 +2564,8 @@ async fn test_block_watcher_service_new() {
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
-	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
 	let block_tracker = Arc::new(BlockTracker::new(10, Some(block_storage.clone())));
 
 	let service = BlockWatcherService::<_, _, _, JobScheduler>::new(
@@ -1262,7 +2614,8 @@ async fn test_process_new_blocks_get_blocks_error_fresh_start() {
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
 
-	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
 
 	let result = process_new_blocks(
 		&network,
@@ -1293,7 +2646,8 @@ async fn test_scheduler_errors() {
 			}
 		}) as BoxFuture<'static, ProcessedBlock>
 	});
-	let trigger_handler = Arc::new(|_: &ProcessedBlock| tokio::spawn(async {}));
+	let trigger_handler =
+		Arc::new(|_: &ProcessedBlock| tokio::spawn(async { TriggerCompletion::completed() }));
 	let block_tracker = Arc::new(BlockTracker::new(10, Some(block_storage.clone())));
 
 	// Test case 1: Scheduler fails to initialize
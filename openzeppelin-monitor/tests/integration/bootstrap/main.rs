@@ -10,24 +10,38 @@ use crate::integration::{
 		MockTriggerRepository,
 	},
 };
+use alloy::primitives::B256;
 use openzeppelin_monitor::{
 	bootstrap::{
-		create_block_handler, create_trigger_handler, get_contract_specs, initialize_services,
-		process_block,
+		create_block_handler, create_block_handler_with_registry, create_trigger_handler,
+		get_contract_specs, initialize_services, load_services_from_config_file, process_block,
+		resolve_ens_names, BlockProcessingContext, BlockProcessorRegistry,
 	},
 	models::{
 		AddressWithSpec, BlockChainType, ContractSpec, EVMContractSpec, EVMMonitorMatch,
 		EVMTransactionReceipt, MatchConditions, Monitor, MonitorMatch, ProcessedBlock,
 		ScriptLanguage, SecretString, SecretValue, StellarBlock, StellarContractSpec,
-		StellarFormattedContractSpec, StellarMonitorMatch, TransactionType, Trigger,
-		TriggerConditions,
+		StellarFormattedContractSpec, StellarMonitorMatch, ThrottleOverflow, TransactionType,
+		Trigger, TriggerConditions,
 	},
+	repositories::{MonitorRepository, NetworkRepository, TriggerRepository},
 	services::{
+		blockwatcher::{BackpressurePolicy, TriggerQueueConfig},
 		filter::{stellar_helpers::are_same_address, FilterService},
-		notification::NotificationService,
-		trigger::{TriggerExecutionService, TriggerExecutionServiceTrait},
+		notification::{
+			CircuitBreakerConfig, DeadLetterStore, NotificationClientPool, NotificationError,
+			NotificationService, Notifier,
+		},
+		trigger::{
+			script_checksum, DispatchedMatchStore, TriggerError, TriggerExecutionService,
+			TriggerExecutionServiceTrait,
+		},
 	},
 	utils::{
+		logging::error::ErrorCode,
+		metrics::{
+			MATCHES_DEDUPLICATED_TOTAL, NOTIFICATIONS_PAUSED_TOTAL, NOTIFICATIONS_THROTTLED_TOTAL,
+		},
 		tests::{
 			evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
 			trigger::TriggerBuilder,
@@ -41,7 +55,13 @@ use stellar_xdr::curr::{
 };
 
 use serde_json::json;
-use std::{collections::HashMap, sync::Arc};
+use std::{
+	collections::HashMap,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+};
 use tokio::sync::watch;
 
 fn create_test_monitor(
@@ -73,9 +93,12 @@ fn create_test_monitor_match(chain: BlockChainType) -> MonitorMatch {
 			transaction: TransactionBuilder::new().build(),
 			network_slug: "ethereum_mainnet".to_string(),
 			receipt: Some(EVMTransactionReceipt::default()),
+			revert_reason: None,
 			logs: Some(vec![]),
+			block_timestamp: None,
 			matched_on: MatchConditions::default(),
 			matched_on_args: None,
+			enrichment: HashMap::new(),
 		})),
 		BlockChainType::Stellar => MonitorMatch::Stellar(Box::new(StellarMonitorMatch {
 			monitor: create_test_monitor("test", vec!["stellar_mainnet"], false, vec![]),
@@ -87,6 +110,7 @@ fn create_test_monitor_match(chain: BlockChainType) -> MonitorMatch {
 			ledger: StellarBlock::default(),
 			matched_on: MatchConditions::default(),
 			matched_on_args: None,
+			enrichment: HashMap::new(),
 		})),
 		_ => panic!("Unsupported chain"),
 	}
@@ -154,8 +178,7 @@ async fn test_initialize_services() {
 	assert!(active_monitors.iter().any(|m| {
 		m.name == "test"
 			&& m.networks.contains(&"ethereum_mainnet".to_string())
-			&& m.triggers
-				.contains(&"evm_large_transfer_usdc_slack".to_string())
+			&& m.triggers.contains(&"evm_large_transfer_usdc_slack".into())
 	}));
 	assert!(networks.contains_key("ethereum_mainnet"));
 
@@ -164,6 +187,71 @@ async fn test_initialize_services() {
 	assert!(Arc::strong_count(&trigger_service) >= 1);
 }
 
+#[tokio::test]
+async fn test_load_services_from_config_file() {
+	let temp_dir = tempfile::tempdir().unwrap();
+	let config_path = temp_dir.path().join("config.json");
+	std::fs::write(
+		&config_path,
+		json!({
+			"networks": [create_test_network("Ethereum", "ethereum_mainnet", BlockChainType::EVM)],
+			"monitors": [create_test_monitor("test", vec!["ethereum_mainnet"], false, vec!["test_trigger"])],
+			"triggers": [create_test_trigger("test_trigger")],
+		})
+		.to_string(),
+	)
+	.unwrap();
+
+	let (monitor_service, network_service, trigger_service) =
+		load_services_from_config_file(&config_path)
+			.await
+			.expect("Failed to load services from config file");
+
+	assert!(network_service.get("ethereum_mainnet").is_some());
+	assert!(trigger_service.get("test_trigger").is_some());
+	assert!(monitor_service.get("test").is_some());
+
+	let (_, _, active_monitors, networks, _, _, _) = initialize_services::<
+		MonitorRepository<NetworkRepository, TriggerRepository>,
+		NetworkRepository,
+		TriggerRepository,
+	>(
+		Some(monitor_service),
+		Some(network_service),
+		Some(trigger_service),
+	)
+	.await
+	.expect("Failed to initialize services");
+
+	assert_eq!(active_monitors.len(), 1);
+	assert!(networks.contains_key("ethereum_mainnet"));
+}
+
+#[tokio::test]
+async fn test_load_services_from_config_file_invalid_references() {
+	let temp_dir = tempfile::tempdir().unwrap();
+	let config_path = temp_dir.path().join("config.json");
+	std::fs::write(
+		&config_path,
+		json!({
+			"networks": [create_test_network("Ethereum", "ethereum_mainnet", BlockChainType::EVM)],
+			"monitors": [create_test_monitor("test", vec!["polygon_mainnet"], false, vec!["missing_trigger"])],
+			"triggers": [create_test_trigger("test_trigger")],
+		})
+		.to_string(),
+	)
+	.unwrap();
+
+	let err = load_services_from_config_file(&config_path)
+		.await
+		.err()
+		.expect("Expected invalid references to be rejected")
+		.to_string();
+
+	assert!(err.contains("non-existent network 'polygon_mainnet'"));
+	assert!(err.contains("non-existent trigger 'missing_trigger'"));
+}
+
 #[tokio::test]
 async fn test_create_block_handler_evm() {
 	let (shutdown_tx, _) = watch::channel(false);
@@ -181,7 +269,7 @@ async fn test_create_block_handler_evm() {
 
 	mock_client
 		.expect_get_logs_for_blocks()
-		.return_once(|_, _, _| Ok(vec![]));
+		.return_once(|_, _, _, _| Ok(vec![]));
 
 	// Create a mock client pool that returns a successful client
 	let mut mock_pool = MockClientPool::new();
@@ -233,6 +321,8 @@ async fn test_create_trigger_handler() {
 		shutdown_tx,
 		Arc::new(trigger_execution_service),
 		HashMap::new(),
+		None,
+		None,
 	);
 
 	assert!(Arc::strong_count(&trigger_handler) == 1);
@@ -261,6 +351,8 @@ async fn test_create_trigger_handler_empty_matches() {
 		shutdown_tx,
 		Arc::new(trigger_execution_service),
 		HashMap::new(),
+		None,
+		None,
 	);
 
 	assert!(Arc::strong_count(&trigger_handler) == 1);
@@ -441,6 +533,206 @@ async fn test_create_block_handler_stellar_client_error() {
 	assert!(result.processing_results.is_empty());
 }
 
+#[tokio::test]
+async fn test_create_block_handler_with_registry_custom_chain() {
+	let (shutdown_tx, _) = watch::channel(false);
+	let monitors = vec![
+		create_test_monitor("solana_monitor", vec!["solana_mainnet"], false, vec![]),
+		create_test_monitor("evm_monitor", vec!["ethereum_mainnet"], false, vec![]),
+	];
+	let network = create_test_network("Solana", "solana_mainnet", BlockChainType::Solana);
+
+	// Register a processor for a chain without a built-in one
+	let processed_monitors = Arc::new(std::sync::Mutex::new(Vec::new()));
+	let mut registry = BlockProcessorRegistry::<MockClientPool>::empty();
+	let recorded_monitors = processed_monitors.clone();
+	registry.register(
+		BlockChainType::Solana,
+		Arc::new(move |context: BlockProcessingContext<MockClientPool>| {
+			let recorded_monitors = recorded_monitors.clone();
+			Box::pin(async move {
+				recorded_monitors
+					.lock()
+					.unwrap()
+					.extend(context.monitors.iter().map(|monitor| monitor.name.clone()));
+				assert_eq!(context.network.slug, "solana_mainnet");
+				Some(vec![create_test_monitor_match(BlockChainType::EVM)])
+			})
+		}),
+	);
+	assert!(registry.supports(&BlockChainType::Solana));
+	assert!(!registry.supports(&BlockChainType::EVM));
+
+	let block_handler = create_block_handler_with_registry(
+		shutdown_tx,
+		Arc::new(FilterService::new()),
+		monitors,
+		Arc::new(MockClientPool::new()),
+		vec![],
+		Arc::new(registry),
+	);
+
+	let result = block_handler(create_test_block(BlockChainType::EVM, 100), network).await;
+	assert_eq!(result.block_number, 100);
+	assert_eq!(result.network_slug, "solana_mainnet");
+	assert_eq!(result.processing_results.len(), 1);
+	// Only the monitors of the network are dispatched to the processor
+	assert_eq!(*processed_monitors.lock().unwrap(), vec!["solana_monitor"]);
+
+	// Networks without a registered processor have no match
+	let (shutdown_tx, _) = watch::channel(false);
+	let block_handler = create_block_handler_with_registry(
+		shutdown_tx,
+		Arc::new(FilterService::new()),
+		vec![create_test_monitor(
+			"evm_monitor",
+			vec!["ethereum_mainnet"],
+			false,
+			vec![],
+		)],
+		Arc::new(MockClientPool::new()),
+		vec![],
+		Arc::new(BlockProcessorRegistry::<MockClientPool>::empty()),
+	);
+	let result = block_handler(
+		create_test_block(BlockChainType::EVM, 100),
+		create_test_network("Ethereum", "ethereum_mainnet", BlockChainType::EVM),
+	)
+	.await;
+	assert!(result.processing_results.is_empty());
+}
+
+#[test]
+fn test_default_block_processor_registry() {
+	let registry = BlockProcessorRegistry::<MockClientPool>::default();
+	assert!(registry.supports(&BlockChainType::EVM));
+	assert!(registry.supports(&BlockChainType::Stellar));
+	assert!(registry.supports(&BlockChainType::Midnight));
+	assert!(!registry.supports(&BlockChainType::Solana));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_create_trigger_handler_queue_backpressure() {
+	let executed = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+	// Set up a slow trigger execution service
+	let ctx = MockTriggerExecutionService::<MockTriggerRepository>::new_context();
+	let executed_clone = executed.clone();
+	ctx.expect()
+		.with(mockall::predicate::always(), mockall::predicate::always())
+		.returning(move |_trigger_service, _notification_service| {
+			let executed = executed_clone.clone();
+			let mut mock = MockTriggerExecutionService::default();
+			mock.expect_execute().times(3).returning(move |_, _, _, _| {
+				std::thread::sleep(std::time::Duration::from_millis(200));
+				executed.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+				Ok(())
+			});
+			mock
+		});
+
+	let trigger_execution_service =
+		setup_trigger_execution_service("tests/integration/fixtures/evm/triggers/trigger.json")
+			.await;
+
+	let (shutdown_tx, _) = watch::channel(false);
+	let trigger_handler = create_trigger_handler(
+		shutdown_tx,
+		Arc::new(trigger_execution_service),
+		HashMap::new(),
+		Some(TriggerQueueConfig {
+			capacity: 1,
+			policy: BackpressurePolicy::Block,
+		}),
+		None,
+	);
+
+	let create_block = |block_number| ProcessedBlock {
+		block_number,
+		network_slug: "ethereum_mainnet".to_string(),
+		processing_results: vec![create_test_monitor_match(BlockChainType::EVM)],
+	};
+
+	// The first block is taken by the consumer and the second one fills the queue
+	let first = trigger_handler(&create_block(100)).await.unwrap();
+	let second = trigger_handler(&create_block(101)).await.unwrap();
+
+	// The third block waits for the slow consumer to free a slot
+	let handle = trigger_handler(&create_block(102));
+	tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+	assert!(!handle.is_finished());
+
+	let third = tokio::time::timeout(std::time::Duration::from_secs(5), handle)
+		.await
+		.expect("Block should be queued once a slot is freed")
+		.unwrap();
+
+	// Completions resolve once the consumer has executed the block's triggers
+	tokio::time::timeout(std::time::Duration::from_secs(5), async {
+		assert!(first.wait().await);
+		assert!(second.wait().await);
+		assert!(third.wait().await);
+	})
+	.await
+	.expect("All queued blocks should be executed");
+	assert_eq!(executed.load(std::sync::atomic::Ordering::SeqCst), 3);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_create_trigger_handler_queue_shutdown_abandons_block() {
+	// Set up a slow trigger execution service
+	let ctx = MockTriggerExecutionService::<MockTriggerRepository>::new_context();
+	ctx.expect()
+		.with(mockall::predicate::always(), mockall::predicate::always())
+		.returning(|_trigger_service, _notification_service| {
+			let mut mock = MockTriggerExecutionService::default();
+			mock.expect_execute().returning(|_, _, _, _| {
+				std::thread::sleep(std::time::Duration::from_millis(300));
+				Ok(())
+			});
+			mock
+		});
+
+	let trigger_execution_service =
+		setup_trigger_execution_service("tests/integration/fixtures/evm/triggers/trigger.json")
+			.await;
+
+	let (shutdown_tx, _) = watch::channel(false);
+	let trigger_handler = create_trigger_handler(
+		shutdown_tx.clone(),
+		Arc::new(trigger_execution_service),
+		HashMap::new(),
+		Some(TriggerQueueConfig {
+			capacity: 1,
+			policy: BackpressurePolicy::Block,
+		}),
+		None,
+	);
+
+	let create_block = |block_number| ProcessedBlock {
+		block_number,
+		network_slug: "ethereum_mainnet".to_string(),
+		processing_results: vec![create_test_monitor_match(BlockChainType::EVM)],
+	};
+
+	trigger_handler(&create_block(100)).await.unwrap();
+	trigger_handler(&create_block(101)).await.unwrap();
+
+	// The third block is still waiting for a free slot when the service shuts down
+	let handle = trigger_handler(&create_block(102));
+	tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+	shutdown_tx.send(true).unwrap();
+
+	let completion = tokio::time::timeout(std::time::Duration::from_secs(5), handle)
+		.await
+		.expect("Trigger handler task should stop on shutdown")
+		.unwrap();
+	assert!(
+		!completion.wait().await,
+		"A block abandoned on shutdown must not be reported as executed"
+	);
+}
+
 #[tokio::test]
 async fn test_create_trigger_handler_with_conditions() {
 	// Set up expectation for the constructor first
@@ -483,6 +775,8 @@ print(True)  # Always return true for test
 		shutdown_tx,
 		Arc::new(trigger_execution_service),
 		trigger_scripts,
+		None,
+		None,
 	);
 
 	assert!(Arc::strong_count(&trigger_handler) == 1);
@@ -493,6 +787,8 @@ print(True)  # Always return true for test
 		script_path: "test_script.py".to_string(),
 		language: ScriptLanguage::Python,
 		timeout_ms: 1000,
+		script_checksum: None,
+		retry_policy: None,
 		arguments: None,
 	}];
 
@@ -503,10 +799,13 @@ print(True)  # Always return true for test
 			monitor,
 			transaction: TransactionBuilder::new().build(),
 			receipt: Some(EVMTransactionReceipt::default()),
+			revert_reason: None,
 			logs: Some(vec![]),
+			block_timestamp: None,
 			network_slug: "ethereum_mainnet".to_string(),
 			matched_on: MatchConditions::default(),
 			matched_on_args: None,
+			enrichment: HashMap::new(),
 		}))],
 	};
 
@@ -540,7 +839,7 @@ async fn test_process_block() {
 
 	mock_client
 		.expect_get_logs_for_blocks()
-		.return_once(|_, _, _| Ok(vec![]));
+		.return_once(|_, _, _, _| Ok(vec![]));
 
 	let result = process_block(
 		&mock_client,
@@ -672,6 +971,109 @@ async fn test_load_scripts_error() {
 	assert!(error.to_string().contains("Failed to read script file"));
 }
 
+#[tokio::test]
+async fn test_load_scripts_from_url() {
+	let mut server = mockito::Server::new_async().await;
+	let content = "print(True)";
+	let mock = server
+		.mock("GET", "/filters/filter.py")
+		.with_status(200)
+		.with_body(content)
+		.expect(1)
+		.create_async()
+		.await;
+	let script_url = format!("{}/filters/filter.py", server.url());
+
+	// Two monitors sharing the remote script
+	let monitors: Vec<Monitor> = ["first_monitor", "second_monitor"]
+		.iter()
+		.map(|name| {
+			let mut monitor = MonitorBuilder::new()
+				.name(name)
+				.trigger_condition(&script_url, 1000, ScriptLanguage::Python, None)
+				.build();
+			monitor.trigger_conditions[0].script_checksum = Some(script_checksum(content));
+			monitor
+		})
+		.collect();
+
+	let trigger_service = setup_trigger_service(HashMap::new());
+	let trigger_execution_service =
+		TriggerExecutionService::new(trigger_service, NotificationService::new());
+
+	let scripts = trigger_execution_service
+		.load_scripts(&monitors)
+		.await
+		.unwrap();
+
+	// The script is fetched once and stored for both monitors
+	mock.assert_async().await;
+	assert_eq!(scripts.len(), 2);
+	let (language, loaded) = &scripts[&format!("first_monitor|{}", script_url)];
+	assert_eq!(*language, ScriptLanguage::Python);
+	assert_eq!(loaded, content);
+	assert_eq!(
+		scripts[&format!("second_monitor|{}", script_url)].1,
+		content
+	);
+}
+
+#[tokio::test]
+async fn test_load_scripts_from_url_checksum_mismatch() {
+	let mut server = mockito::Server::new_async().await;
+	server
+		.mock("GET", "/filters/filter.py")
+		.with_status(200)
+		.with_body("print(False)")
+		.create_async()
+		.await;
+	let script_url = format!("{}/filters/filter.py", server.url());
+
+	let mut monitor = MonitorBuilder::new()
+		.name("test_monitor")
+		.trigger_condition(&script_url, 1000, ScriptLanguage::Python, None)
+		.build();
+	monitor.trigger_conditions[0].script_checksum = Some(script_checksum("print(True)"));
+
+	let trigger_service = setup_trigger_service(HashMap::new());
+	let trigger_execution_service =
+		TriggerExecutionService::new(trigger_service, NotificationService::new());
+
+	let error = trigger_execution_service
+		.load_scripts(&[monitor])
+		.await
+		.unwrap_err();
+	assert!(error.to_string().contains("Checksum mismatch"));
+}
+
+#[tokio::test]
+async fn test_load_scripts_from_url_fetch_error() {
+	let mut server = mockito::Server::new_async().await;
+	server
+		.mock("GET", "/filters/filter.py")
+		.with_status(404)
+		.create_async()
+		.await;
+	let script_url = format!("{}/filters/filter.py", server.url());
+
+	let mut monitor = MonitorBuilder::new()
+		.name("test_monitor")
+		.trigger_condition(&script_url, 1000, ScriptLanguage::Python, None)
+		.build();
+	monitor.trigger_conditions[0].script_checksum = Some(script_checksum("print(True)"));
+
+	let trigger_service = setup_trigger_service(HashMap::new());
+	let trigger_execution_service =
+		TriggerExecutionService::new(trigger_service, NotificationService::new());
+
+	let error = trigger_execution_service
+		.load_scripts(&[monitor])
+		.await
+		.unwrap_err();
+	assert!(error.to_string().contains("Failed to fetch script"));
+	assert!(error.to_string().contains("404"));
+}
+
 #[tokio::test]
 async fn test_load_scripts_empty_conditions() {
 	// Create test monitors with empty trigger conditions
@@ -1196,71 +1598,1133 @@ async fn test_trigger_execution_service_execute_multiple_triggers_partial_succes
 	webhook_mock.assert();
 }
 
-#[tokio::test]
-async fn test_get_contract_specs() {
-	// Test EVM contract specs
-	let mock_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
-	let network = create_test_network("Ethereum", "ethereum_mainnet", BlockChainType::EVM);
-
-	// Create a mock client pool that returns a successful client
-	let mut mock_pool = MockClientPool::new();
-	mock_pool
-		.expect_get_evm_client()
-		.return_once(move |_| Ok(Arc::new(mock_client)));
-	let client_pool = Arc::new(mock_pool);
+fn create_ordered_test_monitor_match(continue_on_error: bool) -> MonitorMatch {
+	MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+		monitor: MonitorBuilder::new()
+			.name("test")
+			.networks(vec!["ethereum_mainnet".to_string()])
+			.trigger_execution(true, continue_on_error)
+			.build(),
+		transaction: TransactionBuilder::new().build(),
+		network_slug: "ethereum_mainnet".to_string(),
+		receipt: Some(EVMTransactionReceipt::default()),
+		revert_reason: None,
+		logs: Some(vec![]),
+		block_timestamp: None,
+		matched_on: MatchConditions::default(),
+		matched_on_args: None,
+		enrichment: HashMap::new(),
+	}))
+}
 
-	// Create a monitor with EVM contract specs
-	let mut monitor = create_test_monitor("test", vec!["ethereum_mainnet"], false, vec![]);
-	monitor.addresses.push(AddressWithSpec {
-		address: "0x1234567890123456789012345678901234567890".to_string(),
-		contract_spec: Some(ContractSpec::EVM(EVMContractSpec::from(
-			serde_json::json!([{
-				"type": "function",
-				"name": "transfer",
-				"inputs": [
-					{
-						"name": "to",
-						"type": "address",
-						"internalType": "address"
-					},
-					{
-						"name": "amount",
-						"type": "uint256",
-						"internalType": "uint256"
-					}
-				],
-				"outputs": [
-					{
-						"name": "",
-						"type": "bool",
-						"internalType": "bool"
-					}
-				],
-				"stateMutability": "nonpayable"
-			}]),
-		))),
-	});
+fn create_webhook_trigger(name: &str, url: &str) -> Trigger {
+	TriggerBuilder::new()
+		.name(name)
+		.webhook(url)
+		.webhook_method("POST")
+		.message("Test Title", "Test Body")
+		.build()
+}
 
-	monitor.addresses.push(AddressWithSpec {
-		address: "0x1234567890123456789012345678901234567890".to_string(),
-		contract_spec: None,
-	});
+#[tokio::test]
+async fn test_trigger_execution_service_execute_ordered_preserves_declared_order() {
+	// Separate servers so that concurrent requests would be handled independently
+	let mut first_server = mockito::Server::new_async().await;
+	let mut second_server = mockito::Server::new_async().await;
+	let calls = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+	// The first trigger responds slowly so that concurrent execution would record it last
+	let first_calls = calls.clone();
+	let first_mock = first_server
+		.mock("POST", "/first")
+		.with_status(200)
+		.with_body_from_request(move |_| {
+			std::thread::sleep(std::time::Duration::from_millis(300));
+			first_calls.lock().unwrap().push("first");
+			vec![]
+		})
+		.create_async()
+		.await;
+	let second_calls = calls.clone();
+	let second_mock = second_server
+		.mock("POST", "/second")
+		.with_status(200)
+		.with_body_from_request(move |_| {
+			second_calls.lock().unwrap().push("second");
+			vec![]
+		})
+		.create_async()
+		.await;
 
-	let monitors = vec![monitor];
+	let mut mocked_triggers = HashMap::new();
+	mocked_triggers.insert(
+		"first".to_string(),
+		create_webhook_trigger("first", &format!("{}/first", first_server.url())),
+	);
+	mocked_triggers.insert(
+		"second".to_string(),
+		create_webhook_trigger("second", &format!("{}/second", second_server.url())),
+	);
 
-	// Create a vector of networks with their associated monitors
-	let network_monitors = vec![(network, monitors)];
+	let trigger_execution_service = TriggerExecutionService::new(
+		setup_trigger_service(mocked_triggers),
+		NotificationService::new(),
+	);
 
-	// Fetch all contract specs for all active monitors
-	let contract_specs = get_contract_specs(&client_pool, &network_monitors).await;
+	let result = trigger_execution_service
+		.execute(
+			&["first".to_string(), "second".to_string()],
+			HashMap::new(),
+			&create_ordered_test_monitor_match(false),
+			&HashMap::new(),
+		)
+		.await;
 
-	// Verify EVM specs and second spec is not added
-	assert_eq!(contract_specs.len(), 1);
+	assert!(result.is_ok());
+	assert_eq!(*calls.lock().unwrap(), vec!["first", "second"]);
+	first_mock.assert();
+	second_mock.assert();
+}
 
-	let (addr, spec) = &contract_specs[0];
-	assert_eq!(addr, "0x1234567890123456789012345678901234567890");
-	match spec {
-		ContractSpec::EVM(evm_spec) => {
+#[tokio::test]
+async fn test_trigger_execution_service_execute_ordered_stops_on_error() {
+	let mut server = mockito::Server::new_async().await;
+	let failing_mock = server
+		.mock("POST", "/first")
+		.with_status(500)
+		.expect(1 + RetryConfig::default().max_retries as usize)
+		.create_async()
+		.await;
+	let skipped_mock = server
+		.mock("POST", "/second")
+		.with_status(200)
+		.expect(0)
+		.create_async()
+		.await;
+
+	let mut mocked_triggers = HashMap::new();
+	mocked_triggers.insert(
+		"first".to_string(),
+		create_webhook_trigger("first", &format!("{}/first", server.url())),
+	);
+	mocked_triggers.insert(
+		"second".to_string(),
+		create_webhook_trigger("second", &format!("{}/second", server.url())),
+	);
+
+	let trigger_execution_service = TriggerExecutionService::new(
+		setup_trigger_service(mocked_triggers),
+		NotificationService::new(),
+	);
+
+	let result = trigger_execution_service
+		.execute(
+			&["first".to_string(), "second".to_string()],
+			HashMap::new(),
+			&create_ordered_test_monitor_match(false),
+			&HashMap::new(),
+		)
+		.await;
+
+	assert!(result.is_err());
+	assert!(result
+		.unwrap_err()
+		.to_string()
+		.contains("Some trigger(s) failed (1 failure(s))"));
+	failing_mock.assert();
+	skipped_mock.assert();
+}
+
+#[tokio::test]
+async fn test_trigger_execution_service_execute_ordered_continue_on_error() {
+	let mut server = mockito::Server::new_async().await;
+	let failing_mock = server
+		.mock("POST", "/first")
+		.with_status(500)
+		.expect(1 + RetryConfig::default().max_retries as usize)
+		.create_async()
+		.await;
+	let second_mock = server
+		.mock("POST", "/second")
+		.with_status(200)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let mut mocked_triggers = HashMap::new();
+	mocked_triggers.insert(
+		"first".to_string(),
+		create_webhook_trigger("first", &format!("{}/first", server.url())),
+	);
+	mocked_triggers.insert(
+		"second".to_string(),
+		create_webhook_trigger("second", &format!("{}/second", server.url())),
+	);
+
+	let trigger_execution_service = TriggerExecutionService::new(
+		setup_trigger_service(mocked_triggers),
+		NotificationService::new(),
+	);
+
+	let result = trigger_execution_service
+		.execute(
+			&["first".to_string(), "second".to_string()],
+			HashMap::new(),
+			&create_ordered_test_monitor_match(true),
+			&HashMap::new(),
+		)
+		.await;
+
+	assert!(result.is_err());
+	assert!(result
+		.unwrap_err()
+		.to_string()
+		.contains("Some trigger(s) failed (1 failure(s))"));
+	failing_mock.assert();
+	second_mock.assert();
+}
+
+/// Notifier recording the maximum number of notifications it was sending at the same time,
+/// failing the notifications of the triggers named `failing_*`
+#[derive(Default)]
+struct ConcurrencyTrackingNotifier {
+	in_flight: AtomicUsize,
+	max_in_flight: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl Notifier for ConcurrencyTrackingNotifier {
+	async fn notify(
+		&self,
+		trigger: &Trigger,
+		_variables: &HashMap<String, String>,
+		_monitor_match: &MonitorMatch,
+	) -> Result<(), NotificationError> {
+		let current = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+		self.max_in_flight.fetch_max(current, Ordering::SeqCst);
+		tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+		self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+		if trigger.name.starts_with("failing_") {
+			return Err(NotificationError::notify_failed(
+				format!("{} failed", trigger.name),
+				None,
+				None,
+			));
+		}
+		Ok(())
+	}
+}
+
+/// Executes the given custom triggers, returning the result and the maximum number of
+/// triggers that were running at the same time
+async fn execute_concurrent_triggers(
+	trigger_names: &[&str],
+	monitor: Monitor,
+) -> (Result<(), TriggerError>, usize) {
+	let notifier = Arc::new(ConcurrencyTrackingNotifier::default());
+	let notification_service = NotificationService::builder()
+		.notifier("tracking", notifier.clone())
+		.build();
+
+	let mocked_triggers = trigger_names
+		.iter()
+		.map(|name| {
+			(
+				name.to_string(),
+				TriggerBuilder::new().name(name).custom("tracking").build(),
+			)
+		})
+		.collect();
+	let trigger_execution_service =
+		TriggerExecutionService::new(setup_trigger_service(mocked_triggers), notification_service);
+
+	let monitor_match = MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+		monitor,
+		transaction: TransactionBuilder::new().build(),
+		network_slug: "ethereum_mainnet".to_string(),
+		receipt: Some(EVMTransactionReceipt::default()),
+		revert_reason: None,
+		logs: Some(vec![]),
+		block_timestamp: None,
+		matched_on: MatchConditions::default(),
+		matched_on_args: None,
+		enrichment: HashMap::new(),
+	}));
+	let trigger_slugs = trigger_names
+		.iter()
+		.map(|name| name.to_string())
+		.collect::<Vec<_>>();
+
+	let result = trigger_execution_service
+		.execute(
+			&trigger_slugs,
+			HashMap::new(),
+			&monitor_match,
+			&HashMap::new(),
+		)
+		.await;
+	(result, notifier.max_in_flight.load(Ordering::SeqCst))
+}
+
+#[tokio::test]
+async fn test_trigger_execution_service_execute_bounded_concurrency() {
+	let monitor = MonitorBuilder::new()
+		.name("test")
+		.networks(vec!["ethereum_mainnet".to_string()])
+		.trigger_concurrency(2)
+		.build();
+
+	let (result, max_in_flight) =
+		execute_concurrent_triggers(&["first", "second", "third", "fourth", "fifth"], monitor)
+			.await;
+
+	assert!(result.is_ok());
+	assert_eq!(max_in_flight, 2);
+}
+
+#[tokio::test]
+async fn test_trigger_execution_service_execute_unbounded_concurrency() {
+	let monitor = MonitorBuilder::new()
+		.name("test")
+		.networks(vec!["ethereum_mainnet".to_string()])
+		.build();
+
+	let (result, max_in_flight) =
+		execute_concurrent_triggers(&["first", "second", "third", "fourth"], monitor).await;
+
+	assert!(result.is_ok());
+	assert_eq!(max_in_flight, 4);
+}
+
+#[tokio::test]
+async fn test_trigger_execution_service_execute_bounded_concurrency_aggregates_failures() {
+	let monitor = MonitorBuilder::new()
+		.name("test")
+		.networks(vec!["ethereum_mainnet".to_string()])
+		.trigger_concurrency(1)
+		.build();
+
+	let (result, max_in_flight) = execute_concurrent_triggers(
+		&["failing_first", "second", "failing_third", "fourth"],
+		monitor,
+	)
+	.await;
+
+	// Failures do not stop the remaining triggers
+	let error = result.unwrap_err().to_string();
+	assert!(error.contains("Some trigger(s) failed (2 failure(s))"));
+	assert_eq!(max_in_flight, 1);
+}
+
+fn create_throttled_test_monitor_match(
+	name: &str,
+	max_notifications_per_window: u32,
+	window_ms: u64,
+	overflow: ThrottleOverflow,
+) -> MonitorMatch {
+	MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+		monitor: MonitorBuilder::new()
+			.name(name)
+			.networks(vec!["ethereum_mainnet".to_string()])
+			.notification_throttle(max_notifications_per_window, window_ms, overflow)
+			.build(),
+		transaction: TransactionBuilder::new().build(),
+		network_slug: "ethereum_mainnet".to_string(),
+		receipt: Some(EVMTransactionReceipt::default()),
+		revert_reason: None,
+		logs: Some(vec![]),
+		block_timestamp: None,
+		matched_on: MatchConditions::default(),
+		matched_on_args: None,
+		enrichment: HashMap::new(),
+	}))
+}
+
+#[tokio::test]
+async fn test_trigger_execution_service_execute_throttled_drops_overflow() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.with_status(200)
+		.expect(2)
+		.create_async()
+		.await;
+
+	let mut mocked_triggers = HashMap::new();
+	mocked_triggers.insert(
+		"webhook".to_string(),
+		create_webhook_trigger("webhook", &server.url()),
+	);
+	let trigger_execution_service = TriggerExecutionService::new(
+		setup_trigger_service(mocked_triggers),
+		NotificationService::new(),
+	);
+
+	let monitor_name = "throttled_drop_monitor";
+	let monitor_match =
+		create_throttled_test_monitor_match(monitor_name, 2, 60000, ThrottleOverflow::Drop);
+	let throttled_before = NOTIFICATIONS_THROTTLED_TOTAL
+		.with_label_values(&[monitor_name])
+		.get();
+
+	// Drive the bucket to empty, the last matches are throttled
+	for _ in 0..5 {
+		let result = trigger_execution_service
+			.execute(
+				&["webhook".to_string()],
+				HashMap::new(),
+				&monitor_match,
+				&HashMap::new(),
+			)
+			.await;
+		assert!(result.is_ok());
+	}
+
+	mock.assert();
+	assert_eq!(
+		NOTIFICATIONS_THROTTLED_TOTAL
+			.with_label_values(&[monitor_name])
+			.get() - throttled_before,
+		3
+	);
+}
+
+#[tokio::test]
+async fn test_trigger_execution_service_execute_throttled_summary() {
+	let mut server = mockito::Server::new_async().await;
+	let first_mock = server
+		.mock("POST", "/")
+		.match_body(mockito::Matcher::Regex("Suppressed 0".to_string()))
+		.with_status(200)
+		.expect(1)
+		.create_async()
+		.await;
+	let summary_mock = server
+		.mock("POST", "/")
+		.match_body(mockito::Matcher::Regex("Suppressed 2".to_string()))
+		.with_status(200)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let mut mocked_triggers = HashMap::new();
+	mocked_triggers.insert(
+		"webhook".to_string(),
+		TriggerBuilder::new()
+			.name("webhook")
+			.webhook(&server.url())
+			.webhook_method("POST")
+			.message("Alert", "Suppressed ${throttle.suppressed_count}")
+			.build(),
+	);
+	let trigger_execution_service = TriggerExecutionService::new(
+		setup_trigger_service(mocked_triggers),
+		NotificationService::new(),
+	);
+
+	let monitor_match = create_throttled_test_monitor_match(
+		"throttled_summary_monitor",
+		1,
+		200,
+		ThrottleOverflow::Summary,
+	);
+	let triggers = ["webhook".to_string()];
+	let trigger_scripts = HashMap::new();
+	let execute = || {
+		trigger_execution_service.execute(
+			&triggers,
+			HashMap::new(),
+			&monitor_match,
+			&trigger_scripts,
+		)
+	};
+
+	assert!(execute().await.is_ok());
+	assert!(execute().await.is_ok());
+	assert!(execute().await.is_ok());
+	first_mock.assert();
+
+	// Once the bucket is refilled, the next notification reports the throttled matches
+	tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+	assert!(execute().await.is_ok());
+	summary_mock.assert();
+}
+
+#[tokio::test]
+async fn test_trigger_execution_service_execute_paused() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.with_status(200)
+		.expect(0)
+		.create_async()
+		.await;
+
+	let mut mocked_triggers = HashMap::new();
+	mocked_triggers.insert(
+		"webhook".to_string(),
+		create_webhook_trigger("webhook", &server.url()),
+	);
+	let trigger_execution_service = TriggerExecutionService::new(
+		setup_trigger_service(mocked_triggers),
+		NotificationService::new(),
+	);
+
+	// Throttle matches to one per window so that suppressed matches consuming the
+	// throttle would prevent the notification after resuming
+	let monitor_name = "paused_monitor";
+	let monitor_match =
+		create_throttled_test_monitor_match(monitor_name, 1, 60000, ThrottleOverflow::Drop);
+	let paused_before = NOTIFICATIONS_PAUSED_TOTAL
+		.with_label_values(&[monitor_name])
+		.get();
+
+	let pause = trigger_execution_service.notification_pause();
+	pause.pause();
+	for _ in 0..3 {
+		let result = trigger_execution_service
+			.execute(
+				&["webhook".to_string()],
+				HashMap::new(),
+				&monitor_match,
+				&HashMap::new(),
+			)
+			.await;
+		assert!(result.is_ok());
+	}
+
+	mock.assert_async().await;
+	assert_eq!(
+		NOTIFICATIONS_PAUSED_TOTAL
+			.with_label_values(&[monitor_name])
+			.get() - paused_before,
+		3
+	);
+
+	// Notifications are sent again once resumed
+	mock.remove_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.with_status(200)
+		.expect(1)
+		.create_async()
+		.await;
+	pause.resume();
+	let result = trigger_execution_service
+		.execute(
+			&["webhook".to_string()],
+			HashMap::new(),
+			&monitor_match,
+			&HashMap::new(),
+		)
+		.await;
+	assert!(result.is_ok());
+	mock.assert_async().await;
+}
+
+fn create_dedup_test_monitor_match(name: &str, tx_hash: u8, window_blocks: u64) -> MonitorMatch {
+	MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+		monitor: MonitorBuilder::new()
+			.name(name)
+			.networks(vec!["ethereum_mainnet".to_string()])
+			.triggers(vec!["webhook".to_string()])
+			.match_dedup(window_blocks)
+			.build(),
+		transaction: TransactionBuilder::new()
+			.hash(B256::with_last_byte(tx_hash))
+			.build(),
+		network_slug: "ethereum_mainnet".to_string(),
+		receipt: Some(EVMTransactionReceipt::default()),
+		revert_reason: None,
+		logs: Some(vec![]),
+		block_timestamp: None,
+		matched_on: MatchConditions::default(),
+		matched_on_args: None,
+		enrichment: HashMap::new(),
+	}))
+}
+
+#[tokio::test]
+async fn test_create_trigger_handler_records_last_match() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.with_status(200)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let mut mocked_triggers = HashMap::new();
+	mocked_triggers.insert(
+		"webhook".to_string(),
+		create_webhook_trigger("webhook", &server.url()),
+	);
+	let trigger_execution_service = Arc::new(TriggerExecutionService::new(
+		setup_trigger_service(mocked_triggers),
+		NotificationService::new(),
+	));
+
+	let (shutdown_tx, _) = watch::channel(false);
+	let trigger_handler = create_trigger_handler(
+		shutdown_tx,
+		trigger_execution_service.clone(),
+		HashMap::new(),
+		None,
+		None,
+	);
+
+	let monitor_name = "staleness_monitor";
+	let monitor = MonitorBuilder::new()
+		.name(monitor_name)
+		.expected_match_window_secs(60)
+		.build();
+	let staleness = trigger_execution_service.staleness();
+
+	// The monitor is stale once its window has elapsed without a match
+	let later = std::time::Instant::now() + std::time::Duration::from_secs(120);
+	assert_eq!(
+		staleness.check_at(std::slice::from_ref(&monitor), later),
+		vec![monitor_name.to_string()]
+	);
+
+	for block_number in [100, 101] {
+		let before = std::time::Instant::now();
+		trigger_handler(&ProcessedBlock {
+			block_number,
+			network_slug: "ethereum_mainnet".to_string(),
+			processing_results: vec![create_dedup_test_monitor_match(
+				monitor_name,
+				block_number as u8,
+				10,
+			)],
+		})
+		.await
+		.expect("Trigger handler task should complete successfully");
+
+		// Duplicate matches are recorded even though they are not notified
+		assert_eq!(
+			staleness.last_match_age_at(monitor_name, before),
+			std::time::Duration::ZERO
+		);
+	}
+
+	mock.assert();
+	let now = std::time::Instant::now();
+	assert!(staleness
+		.check_at(
+			std::slice::from_ref(&monitor),
+			now + std::time::Duration::from_secs(30)
+		)
+		.is_empty());
+}
+
+#[tokio::test]
+async fn test_create_trigger_handler_deduplicates_identical_matches() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.with_status(200)
+		.expect(3)
+		.create_async()
+		.await;
+
+	let mut mocked_triggers = HashMap::new();
+	mocked_triggers.insert(
+		"webhook".to_string(),
+		create_webhook_trigger("webhook", &server.url()),
+	);
+	let trigger_execution_service = TriggerExecutionService::new(
+		setup_trigger_service(mocked_triggers),
+		NotificationService::new(),
+	);
+
+	let (shutdown_tx, _) = watch::channel(false);
+	let trigger_handler = create_trigger_handler(
+		shutdown_tx,
+		Arc::new(trigger_execution_service),
+		HashMap::new(),
+		None,
+		None,
+	);
+
+	let monitor_name = "dedup_heartbeat_monitor";
+	let deduplicated_before = MATCHES_DEDUPLICATED_TOTAL
+		.with_label_values(&[monitor_name])
+		.get();
+
+	// The same event is emitted by a new transaction in each block
+	let blocks = [
+		(
+			100,
+			vec![create_dedup_test_monitor_match(monitor_name, 1, 10)],
+		),
+		(
+			101,
+			vec![
+				create_dedup_test_monitor_match(monitor_name, 2, 10),
+				create_dedup_test_monitor_match("dedup_other_monitor", 3, 10),
+			],
+		),
+		(
+			102,
+			vec![create_dedup_test_monitor_match(monitor_name, 4, 10)],
+		),
+		// The window elapsed since the notified match
+		(
+			111,
+			vec![create_dedup_test_monitor_match(monitor_name, 5, 10)],
+		),
+	];
+	for (block_number, processing_results) in blocks {
+		trigger_handler(&ProcessedBlock {
+			block_number,
+			network_slug: "ethereum_mainnet".to_string(),
+			processing_results,
+		})
+		.await
+		.expect("Trigger handler task should complete successfully");
+	}
+
+	mock.assert();
+	assert_eq!(
+		MATCHES_DEDUPLICATED_TOTAL
+			.with_label_values(&[monitor_name])
+			.get() - deduplicated_before,
+		2
+	);
+}
+
+/// Trigger execution service recording the dispatched transactions, which hangs on the
+/// transaction to interrupt at after requesting a shutdown
+struct InterruptingTriggerExecutionService {
+	dispatched: Arc<std::sync::Mutex<Vec<B256>>>,
+	interrupt_at: Option<(B256, watch::Sender<bool>)>,
+}
+
+#[async_trait::async_trait]
+impl TriggerExecutionServiceTrait for InterruptingTriggerExecutionService {
+	async fn execute(
+		&self,
+		_trigger_slugs: &[String],
+		_variables: HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+		_trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
+	) -> Result<(), TriggerError> {
+		let MonitorMatch::EVM(evm_match) = monitor_match else {
+			return Ok(());
+		};
+		let hash = evm_match.transaction.hash;
+		if let Some((interrupt_hash, shutdown_tx)) = &self.interrupt_at {
+			if *interrupt_hash == hash {
+				shutdown_tx.send(true).unwrap();
+				std::future::pending::<()>().await;
+			}
+		}
+		self.dispatched.lock().unwrap().push(hash);
+		Ok(())
+	}
+
+	async fn load_scripts(
+		&self,
+		_monitors: &[Monitor],
+	) -> Result<HashMap<String, (ScriptLanguage, String)>, TriggerError> {
+		Ok(HashMap::new())
+	}
+}
+
+#[tokio::test]
+async fn test_create_trigger_handler_resumes_interrupted_block() {
+	let temp_dir = tempfile::tempdir().unwrap();
+	let dispatched = Arc::new(std::sync::Mutex::new(Vec::new()));
+	let processed_block = ProcessedBlock {
+		block_number: 100,
+		network_slug: "ethereum_mainnet".to_string(),
+		processing_results: (1..=4)
+			.map(|tx_hash| create_dedup_test_monitor_match("resume_monitor", tx_hash, 10))
+			.collect(),
+	};
+	let run = |interrupt_at: Option<u8>| {
+		let (shutdown_tx, _) = watch::channel(false);
+		let trigger_service = InterruptingTriggerExecutionService {
+			dispatched: dispatched.clone(),
+			interrupt_at: interrupt_at
+				.map(|tx_hash| (B256::with_last_byte(tx_hash), shutdown_tx.clone())),
+		};
+		// Each run uses a new handler, as the service would after a restart
+		let trigger_handler = create_trigger_handler(
+			shutdown_tx,
+			Arc::new(trigger_service),
+			HashMap::new(),
+			None,
+			Some(Arc::new(DispatchedMatchStore::new(
+				temp_dir.path().to_path_buf(),
+			))),
+		);
+		let handle = trigger_handler(&processed_block);
+		// The handler owns the shutdown channel and must outlive its task
+		async move {
+			let result = handle.await;
+			drop(trigger_handler);
+			result
+		}
+	};
+
+	// The service is interrupted while dispatching the third match
+	tokio::time::timeout(std::time::Duration::from_secs(5), run(Some(3)))
+		.await
+		.expect("Interrupted trigger handler task should stop")
+		.unwrap();
+	assert_eq!(
+		*dispatched.lock().unwrap(),
+		vec![B256::with_last_byte(1), B256::with_last_byte(2)]
+	);
+
+	// On resume, only the matches not dispatched yet are dispatched
+	run(None).await.unwrap();
+	assert_eq!(
+		*dispatched.lock().unwrap(),
+		(1..=4).map(B256::with_last_byte).collect::<Vec<_>>()
+	);
+
+	// Processing the block again does not dispatch any match twice
+	run(None).await.unwrap();
+	assert_eq!(dispatched.lock().unwrap().len(), 4);
+}
+
+fn create_digest_trigger(url: &str) -> Trigger {
+	TriggerBuilder::new()
+		.name("digest")
+		.webhook(url)
+		.webhook_method("POST")
+		.message("Digest", "${digest.count} matches: ${digest.summary}")
+		.digest(60000, 2)
+		.build()
+}
+
+fn create_digest_variables(hash: &str) -> HashMap<String, String> {
+	HashMap::from([
+		("monitor.name".to_string(), "test".to_string()),
+		("transaction.hash".to_string(), hash.to_string()),
+	])
+}
+
+#[tokio::test]
+async fn test_trigger_execution_service_execute_digest() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.match_body(mockito::Matcher::Regex(
+			r"3 matches: 1\. test: 0x1\\n2\. test: 0x2\\n\.\.\. and 1 more".to_string(),
+		))
+		.with_status(200)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let mut mocked_triggers = HashMap::new();
+	mocked_triggers.insert("digest".to_string(), create_digest_trigger(&server.url()));
+	let trigger_execution_service = TriggerExecutionService::new(
+		setup_trigger_service(mocked_triggers),
+		NotificationService::new(),
+	);
+
+	let monitor_match = create_test_monitor_match(BlockChainType::EVM);
+	for hash in ["0x1", "0x2", "0x3"] {
+		let result = trigger_execution_service
+			.execute(
+				&["digest".to_string()],
+				create_digest_variables(hash),
+				&monitor_match,
+				&HashMap::new(),
+			)
+			.await;
+		assert!(result.is_ok());
+	}
+
+	// Matches are only accumulated until the digest is sent
+	assert!(!mock.matched_async().await);
+	// The interval has not elapsed yet
+	assert!(trigger_execution_service.flush_digests(false).await.is_ok());
+	assert!(!mock.matched_async().await);
+
+	assert!(trigger_execution_service.flush_digests(true).await.is_ok());
+	mock.assert();
+
+	// The buffer is emptied once the digest is sent
+	assert!(trigger_execution_service.flush_digests(true).await.is_ok());
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_trigger_execution_service_digest_flusher_sends_on_shutdown() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.match_body(mockito::Matcher::Regex("2 matches".to_string()))
+		.with_status(200)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let mut mocked_triggers = HashMap::new();
+	mocked_triggers.insert("digest".to_string(), create_digest_trigger(&server.url()));
+	let trigger_execution_service = Arc::new(TriggerExecutionService::new(
+		setup_trigger_service(mocked_triggers),
+		NotificationService::new(),
+	));
+
+	let (shutdown_tx, shutdown_rx) = watch::channel(false);
+	let flusher = trigger_execution_service
+		.start_digest_flusher(shutdown_rx)
+		.expect("Digest flusher should start for a trigger in digest mode");
+
+	let monitor_match = create_test_monitor_match(BlockChainType::EVM);
+	for hash in ["0x1", "0x2"] {
+		let result = trigger_execution_service
+			.execute(
+				&["digest".to_string()],
+				create_digest_variables(hash),
+				&monitor_match,
+				&HashMap::new(),
+			)
+			.await;
+		assert!(result.is_ok());
+	}
+
+	let _ = shutdown_tx.send(true);
+	flusher.await.unwrap();
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_trigger_execution_service_digest_flusher_without_digest_triggers() {
+	let mut mocked_triggers = HashMap::new();
+	mocked_triggers.insert(
+		"webhook".to_string(),
+		create_webhook_trigger("webhook", "https://api.example.com/webhook"),
+	);
+	let trigger_execution_service = Arc::new(TriggerExecutionService::new(
+		setup_trigger_service(mocked_triggers),
+		NotificationService::new(),
+	));
+
+	let (_shutdown_tx, shutdown_rx) = watch::channel(false);
+	assert!(trigger_execution_service
+		.start_digest_flusher(shutdown_rx)
+		.is_none());
+}
+
+#[tokio::test]
+async fn test_trigger_execution_service_records_dead_letter() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.with_status(400)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let dir = tempfile::TempDir::new().unwrap();
+	let store = Arc::new(DeadLetterStore::new(dir.path().join("dead_letter.jsonl")));
+	let mut mocked_triggers = HashMap::new();
+	mocked_triggers.insert(
+		"webhook".to_string(),
+		create_webhook_trigger("Webhook", &server.url()),
+	);
+	let trigger_execution_service = TriggerExecutionService::new(
+		setup_trigger_service(mocked_triggers),
+		NotificationService::new(),
+	)
+	.with_dead_letter_store(store.clone());
+
+	let monitor_match = create_test_monitor_match(BlockChainType::EVM);
+	let variables = HashMap::from([("monitor.name".to_string(), "test".to_string())]);
+	let result = trigger_execution_service
+		.execute(
+			&["webhook".to_string()],
+			variables.clone(),
+			&monitor_match,
+			&HashMap::new(),
+		)
+		.await;
+	assert!(result.is_err());
+	mock.assert();
+
+	let records = store.load().await.unwrap();
+	assert_eq!(records.len(), 1);
+	assert_eq!(records[0].trigger, "webhook");
+	assert_eq!(records[0].error_code, ErrorCode::Http4xx);
+	assert_eq!(records[0].variables, variables);
+	assert_eq!(
+		records[0].monitor_match.match_id(),
+		monitor_match.match_id()
+	);
+}
+
+#[tokio::test]
+async fn test_trigger_execution_service_records_circuit_breaker_fast_fail() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.with_status(400)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let dir = tempfile::TempDir::new().unwrap();
+	let store = Arc::new(DeadLetterStore::new(dir.path().join("dead_letter.jsonl")));
+	let mut mocked_triggers = HashMap::new();
+	mocked_triggers.insert(
+		"webhook".to_string(),
+		create_webhook_trigger("Webhook", &server.url()),
+	);
+	let client_pool = Arc::new(NotificationClientPool::with_circuit_breaker_config(
+		CircuitBreakerConfig {
+			failure_threshold: Some(1),
+			cooldown: std::time::Duration::from_secs(60),
+		},
+	));
+	let trigger_execution_service = TriggerExecutionService::new(
+		setup_trigger_service(mocked_triggers),
+		NotificationService::with_client_pool(client_pool),
+	)
+	.with_dead_letter_store(store.clone());
+
+	let monitor_match = create_test_monitor_match(BlockChainType::EVM);
+	for _ in 0..2 {
+		let result = trigger_execution_service
+			.execute(
+				&["webhook".to_string()],
+				HashMap::new(),
+				&monitor_match,
+				&HashMap::new(),
+			)
+			.await;
+		assert!(result.is_err());
+	}
+	// The second notification fails fast without reaching the endpoint
+	mock.assert();
+
+	let records = store.load().await.unwrap();
+	assert_eq!(records.len(), 2);
+	assert_eq!(records[0].error_code, ErrorCode::Http4xx);
+	assert!(records[1].error.contains("Circuit breaker is open"));
+}
+
+#[tokio::test]
+async fn test_trigger_execution_service_records_failed_digest() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.with_status(400)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let dir = tempfile::TempDir::new().unwrap();
+	let store = Arc::new(DeadLetterStore::new(dir.path().join("dead_letter.jsonl")));
+	let mut mocked_triggers = HashMap::new();
+	mocked_triggers.insert("digest".to_string(), create_digest_trigger(&server.url()));
+	let trigger_execution_service = TriggerExecutionService::new(
+		setup_trigger_service(mocked_triggers),
+		NotificationService::new(),
+	)
+	.with_dead_letter_store(store.clone());
+
+	let monitor_match = create_test_monitor_match(BlockChainType::EVM);
+	for hash in ["0x1", "0x2"] {
+		let result = trigger_execution_service
+			.execute(
+				&["digest".to_string()],
+				create_digest_variables(hash),
+				&monitor_match,
+				&HashMap::new(),
+			)
+			.await;
+		assert!(result.is_ok());
+	}
+	assert!(trigger_execution_service.flush_digests(true).await.is_err());
+	mock.assert();
+
+	// The digest is recorded with its variables, so that it can be re-sent as is
+	let records = store.load().await.unwrap();
+	assert_eq!(records.len(), 1);
+	assert_eq!(records[0].trigger, "digest");
+	assert_eq!(records[0].variables["digest.count"], "2");
+}
+
+#[tokio::test]
+async fn test_get_contract_specs() {
+	// Test EVM contract specs
+	let mock_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	let network = create_test_network("Ethereum", "ethereum_mainnet", BlockChainType::EVM);
+
+	// Create a mock client pool that returns a successful client
+	let mut mock_pool = MockClientPool::new();
+	mock_pool
+		.expect_get_evm_client()
+		.return_once(move |_| Ok(Arc::new(mock_client)));
+	let client_pool = Arc::new(mock_pool);
+
+	// Create a monitor with EVM contract specs
+	let mut monitor = create_test_monitor("test", vec!["ethereum_mainnet"], false, vec![]);
+	monitor.addresses.push(AddressWithSpec {
+		address: "0x1234567890123456789012345678901234567890".to_string(),
+		contract_spec: Some(ContractSpec::EVM(EVMContractSpec::from(
+			serde_json::json!([{
+				"type": "function",
+				"name": "transfer",
+				"inputs": [
+					{
+						"name": "to",
+						"type": "address",
+						"internalType": "address"
+					},
+					{
+						"name": "amount",
+						"type": "uint256",
+						"internalType": "uint256"
+					}
+				],
+				"outputs": [
+					{
+						"name": "",
+						"type": "bool",
+						"internalType": "bool"
+					}
+				],
+				"stateMutability": "nonpayable"
+			}]),
+		))),
+		contract_spec_path: None,
+		additional_specs: vec![],
+		event_signatures: HashMap::new(),
+	});
+
+	monitor.addresses.push(AddressWithSpec {
+		address: "0x1234567890123456789012345678901234567890".to_string(),
+		contract_spec: None,
+		contract_spec_path: None,
+		additional_specs: vec![],
+		event_signatures: HashMap::new(),
+	});
+
+	let monitors = vec![monitor];
+
+	// Create a vector of networks with their associated monitors
+	let network_monitors = vec![(network, monitors)];
+
+	// Fetch all contract specs for all active monitors
+	let contract_specs = get_contract_specs(&client_pool, &network_monitors).await;
+
+	// Verify EVM specs and second spec is not added
+	assert_eq!(contract_specs.len(), 1);
+
+	let (addr, spec) = &contract_specs[0];
+	assert_eq!(addr, "0x1234567890123456789012345678901234567890");
+	match spec {
+		ContractSpec::EVM(evm_spec) => {
 			let functions: Vec<_> = evm_spec.functions().collect();
 			assert_eq!(functions.len(), 1);
 			assert_eq!(functions[0].name, "transfer");
@@ -1330,12 +2794,18 @@ async fn test_get_contract_specs() {
 				outputs: vec![ScSpecTypeDef::Bool].try_into().unwrap(),
 			}),
 		]) as StellarContractSpec)),
+		contract_spec_path: None,
+		additional_specs: vec![],
+		event_signatures: HashMap::new(),
 	});
 
 	// Add an address without a contract spec to test fetching from chain
 	stellar_monitor.addresses.push(AddressWithSpec {
 		address: "GZYXWVUTSRQPONMLKJIHGFEDCBA0987654321".to_string(),
 		contract_spec: None,
+		contract_spec_path: None,
+		additional_specs: vec![],
+		event_signatures: HashMap::new(),
 	});
 
 	let network_monitors = vec![(network, vec![stellar_monitor])];
@@ -1362,3 +2832,117 @@ async fn test_get_contract_specs() {
 		_ => panic!("Expected Stellar contract spec"),
 	}
 }
+
+#[tokio::test]
+async fn test_resolve_ens_names() {
+	let mut network = create_test_network("Ethereum", "ethereum_mainnet", BlockChainType::EVM);
+	network.ens_registry = Some("0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e".to_string());
+	let networks = HashMap::from([(network.slug.clone(), network)]);
+
+	// The shared name is only resolved once
+	let mut mock_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	mock_client
+		.expect_resolve_ens_name()
+		.withf(|name, registry| {
+			name == "vitalik.eth" && registry == "0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e"
+		})
+		.times(1)
+		.returning(|_, _| {
+			Ok(Some(
+				"0xd8da6bf26964af9d7eed9e03e53415d37aa96045".to_string(),
+			))
+		});
+	let mock_client = Arc::new(mock_client);
+
+	let mut mock_pool = MockClientPool::new();
+	mock_pool
+		.expect_get_evm_client()
+		.returning(move |_| Ok(mock_client.clone()));
+	let client_pool = Arc::new(mock_pool);
+
+	let monitors = vec![
+		MonitorBuilder::new()
+			.name("first")
+			.networks(vec!["ethereum_mainnet".to_string()])
+			.addresses(vec![
+				"Vitalik.eth".to_string(),
+				"0x1234567890123456789012345678901234567890".to_string(),
+			])
+			.build(),
+		MonitorBuilder::new()
+			.name("second")
+			.networks(vec!["ethereum_mainnet".to_string()])
+			.address("vitalik.eth")
+			.build(),
+	];
+
+	let monitors = resolve_ens_names(&client_pool, &networks, monitors)
+		.await
+		.unwrap();
+
+	assert_eq!(
+		monitors[0].addresses[0].address,
+		"0xd8da6bf26964af9d7eed9e03e53415d37aa96045"
+	);
+	assert_eq!(
+		monitors[0].addresses[1].address,
+		"0x1234567890123456789012345678901234567890"
+	);
+	assert_eq!(
+		monitors[1].addresses[0].address,
+		"0xd8da6bf26964af9d7eed9e03e53415d37aa96045"
+	);
+}
+
+#[tokio::test]
+async fn test_resolve_ens_names_unresolved() {
+	let mut network = create_test_network("Ethereum", "ethereum_mainnet", BlockChainType::EVM);
+	network.ens_registry = Some("0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e".to_string());
+	let networks = HashMap::from([(network.slug.clone(), network)]);
+
+	let mut mock_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	mock_client
+		.expect_resolve_ens_name()
+		.returning(|_, _| Ok(None));
+
+	let mut mock_pool = MockClientPool::new();
+	mock_pool
+		.expect_get_evm_client()
+		.return_once(move |_| Ok(Arc::new(mock_client)));
+	let client_pool = Arc::new(mock_pool);
+
+	let monitors = vec![MonitorBuilder::new()
+		.name("test")
+		.networks(vec!["ethereum_mainnet".to_string()])
+		.address("unknown.eth")
+		.build()];
+
+	let result = resolve_ens_names(&client_pool, &networks, monitors).await;
+	assert!(result
+		.unwrap_err()
+		.to_string()
+		.contains("ENS name 'unknown.eth' does not resolve to an address"));
+}
+
+#[tokio::test]
+async fn test_resolve_ens_names_without_registry() {
+	let network = create_test_network("Ethereum", "ethereum_mainnet", BlockChainType::EVM);
+	let networks = HashMap::from([(network.slug.clone(), network)]);
+
+	// No client is requested when no network can resolve the names
+	let mut mock_pool = MockClientPool::new();
+	mock_pool.expect_get_evm_client().never();
+	let client_pool = Arc::new(mock_pool);
+
+	let monitors = vec![MonitorBuilder::new()
+		.name("test")
+		.networks(vec!["ethereum_mainnet".to_string()])
+		.address("vitalik.eth")
+		.build()];
+
+	let result = resolve_ens_names(&client_pool, &networks, monitors).await;
+	assert!(result
+		.unwrap_err()
+		.to_string()
+		.contains("none of its networks has an ens_registry"));
+}
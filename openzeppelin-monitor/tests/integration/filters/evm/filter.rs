@@ -3,20 +3,27 @@
 //! Tests the monitoring functionality for EVM-compatible blockchains,
 //! including event and transaction filtering.
 
-use alloy::primitives::Uint;
-use serde_json::json;
-use std::collections::HashMap;
+use alloy::{
+	primitives::{Address, Uint, B256, U256},
+	sol_types::{Revert, SolError},
+};
+use serde_json::{json, Value};
+use std::{collections::HashMap, str::FromStr};
 
 use openzeppelin_monitor::{
 	models::{
-		BlockType, ContractSpec, EVMReceiptLog, EVMTransactionReceipt, EventCondition,
-		FunctionCondition, Monitor, MonitorMatch, TransactionCondition, TransactionStatus,
+		BlockType, ContractSpec, EVMContractSpec, EVMReceiptLog, EVMTransactionReceipt,
+		EventCondition, EventField, FunctionCondition, MatchConditionType, Monitor, MonitorMatch,
+		MonitorTrigger, PercentageChangeCondition, TransactionCondition, TransactionStatus,
 	},
 	services::{
 		blockchain::{EvmClient, TransportError},
 		filter::{handle_match, FilterError, FilterService},
 	},
-	utils::tests::evm::{receipt::ReceiptBuilder, transaction::TransactionBuilder},
+	utils::{
+		metrics::TRANSACTION_LOGS_TRUNCATED_TOTAL,
+		tests::evm::{receipt::ReceiptBuilder, transaction::TransactionBuilder},
+	},
 };
 
 use crate::integration::{
@@ -235,6 +242,75 @@ async fn test_monitor_events_with_expressions() -> Result<(), Box<FilterError>>
 	Ok(())
 }
 
+#[tokio::test]
+async fn test_monitor_events_with_implementation_spec() -> Result<(), Box<FilterError>> {
+	// Load test data using common utility
+	let test_data = TestDataBuilder::new("evm").build();
+	let filter_service = FilterService::new();
+	// Create mock transport
+	let mock_transport = setup_mock_transport(test_data.clone());
+	let client = EvmClient::new_with_transport(mock_transport);
+
+	// The token is monitored as a proxy: its primary spec only knows the proxy ABI, while
+	// the Transfer event is only defined in the implementation ABI
+	let proxy_spec = ContractSpec::EVM(EVMContractSpec::from(json!([
+		{
+			"anonymous": false,
+			"inputs": [
+				{
+					"indexed": true,
+					"internalType": "address",
+					"name": "implementation",
+					"type": "address"
+				}
+			],
+			"name": "Upgraded",
+			"type": "event"
+		}
+	])));
+	let mut monitor = make_monitor_with_events(test_data.monitor, true);
+	let implementation_spec = monitor.addresses[0].contract_spec.take().unwrap();
+	monitor.addresses[0].contract_spec = Some(proxy_spec);
+	monitor.addresses[0].additional_specs = vec![implementation_spec];
+
+	// Run filter_block with the test data
+	let matches = filter_service
+		.filter_block(
+			&client,
+			&test_data.network,
+			&test_data.blocks[0],
+			&[monitor],
+			None,
+		)
+		.await?;
+
+	assert_eq!(matches.len(), 1, "Expected exactly one match");
+
+	match &matches[0] {
+		MonitorMatch::EVM(evm_match) => {
+			assert_eq!(evm_match.matched_on.events.len(), 1);
+			assert_eq!(
+				evm_match.matched_on.events[0].signature,
+				"Transfer(address,address,uint256)"
+			);
+
+			let matched_on_args = evm_match.matched_on_args.as_ref().unwrap();
+			let args = matched_on_args.events.as_ref().unwrap()[0]
+				.args
+				.as_ref()
+				.unwrap();
+			assert_eq!(args[0].value, "0x58b704065b7aff3ed351052f8560019e05925023");
+			assert_eq!(args[1].value, "0xf423d9c1ffeb6386639d024f3b241dab2331b635");
+			assert_eq!(args[2].value, "8181710000");
+		}
+		_ => {
+			panic!("Expected EVM match");
+		}
+	}
+
+	Ok(())
+}
+
 #[tokio::test]
 async fn test_monitor_functions_with_no_expressions() -> Result<(), Box<FilterError>> {
 	// Load test data using common utility
@@ -576,6 +652,9 @@ async fn test_handle_match() -> Result<(), Box<FilterError>> {
 				&& variables.get("transaction.value") == Some(&"0".to_string())
 				// Monitor metadata
 				&& variables.get("monitor.name") == Some(&"Mint USDC Token".to_string())
+				// Block variables
+				&& variables.get("block.timestamp") == Some(&"1733036783".to_string())
+				&& variables.get("block.timestamp_iso") == Some(&"2024-12-01T07:06:23Z".to_string())
 		})
 		.once()
 		.returning(|_, _, _, _| Ok(()));
@@ -746,7 +825,9 @@ async fn test_handle_match_with_key_collision() -> Result<(), Box<FilterError>>
 		monitor,
 		transaction: TransactionBuilder::new().build(),
 		receipt: Some(create_test_evm_transaction_receipt()),
+		revert_reason: None,
 		logs: Some(create_test_evm_logs()),
+		block_timestamp: None,
 		network_slug: "ethereum_mainnet".to_string(),
 		matched_on: MatchConditions {
 			functions: vec![FunctionCondition {
@@ -755,6 +836,9 @@ async fn test_handle_match_with_key_collision() -> Result<(), Box<FilterError>>
 			}],
 			events: vec![],
 			transactions: vec![],
+			operations: vec![],
+			aggregates: vec![],
+			percentage_changes: vec![],
 		},
 		matched_on_args: Some(EVMMatchArguments {
 			functions: Some(vec![EVMMatchParamsMap {
@@ -777,6 +861,7 @@ async fn test_handle_match_with_key_collision() -> Result<(), Box<FilterError>>
 			}]),
 			events: None,
 		}),
+		enrichment: HashMap::new(),
 	};
 
 	let match_wrapper = MonitorMatch::EVM(Box::new(evm_match));
@@ -834,6 +919,181 @@ async fn test_handle_match_with_key_collision() -> Result<(), Box<FilterError>>
 	Ok(())
 }
 
+#[tokio::test]
+async fn test_handle_match_with_monitor_labels() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("evm").build();
+
+	let data_capture = std::sync::Arc::new(std::sync::Mutex::new(HashMap::new()));
+	let data_capture_clone = data_capture.clone();
+
+	let mut trigger_execution_service =
+		setup_trigger_execution_service("tests/integration/fixtures/evm/triggers/trigger.json")
+			.await;
+	trigger_execution_service
+		.expect_execute()
+		.withf(
+			move |_triggers, variables, _monitor_match, _trigger_scripts| {
+				*data_capture_clone.lock().unwrap() = variables.clone();
+				true
+			},
+		)
+		.returning(|_, _, _, _| Ok(()));
+
+	let mut monitor = test_data.monitor.clone();
+	monitor.labels = HashMap::from([
+		("team".to_string(), "payments".to_string()),
+		("env".to_string(), "prod".to_string()),
+	]);
+
+	let match_wrapper =
+		MonitorMatch::EVM(Box::new(openzeppelin_monitor::models::EVMMonitorMatch {
+			monitor,
+			transaction: TransactionBuilder::new().build(),
+			receipt: Some(ReceiptBuilder::new().build()),
+			revert_reason: None,
+			logs: None,
+			block_timestamp: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: openzeppelin_monitor::models::MatchConditions::default(),
+			matched_on_args: None,
+			enrichment: HashMap::new(),
+		}));
+
+	let result = handle_match(match_wrapper, &trigger_execution_service, &HashMap::new()).await;
+	assert!(result.is_ok(), "Handle match should succeed");
+
+	let captured_data = data_capture.lock().unwrap();
+	assert_eq!(
+		captured_data.get("monitor.labels.team"),
+		Some(&"payments".to_string())
+	);
+	assert_eq!(
+		captured_data.get("monitor.labels.env"),
+		Some(&"prod".to_string())
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_handle_match_with_enrichment() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("evm").build();
+
+	let data_capture = std::sync::Arc::new(std::sync::Mutex::new(HashMap::new()));
+	let data_capture_clone = data_capture.clone();
+
+	let mut trigger_execution_service =
+		setup_trigger_execution_service("tests/integration/fixtures/evm/triggers/trigger.json")
+			.await;
+	trigger_execution_service
+		.expect_execute()
+		.withf(
+			move |_triggers, variables, _monitor_match, _trigger_scripts| {
+				*data_capture_clone.lock().unwrap() = variables.clone();
+				true
+			},
+		)
+		.returning(|_, _, _, _| Ok(()));
+
+	let match_wrapper =
+		MonitorMatch::EVM(Box::new(openzeppelin_monitor::models::EVMMonitorMatch {
+			monitor: test_data.monitor.clone(),
+			transaction: TransactionBuilder::new().build(),
+			receipt: Some(ReceiptBuilder::new().build()),
+			revert_reason: None,
+			logs: None,
+			block_timestamp: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: openzeppelin_monitor::models::MatchConditions::default(),
+			matched_on_args: None,
+			enrichment: HashMap::from([
+				("symbol".to_string(), "USDC".to_string()),
+				("decimals".to_string(), "6".to_string()),
+			]),
+		}));
+
+	let result = handle_match(match_wrapper, &trigger_execution_service, &HashMap::new()).await;
+	assert!(result.is_ok(), "Handle match should succeed");
+
+	let captured_data = data_capture.lock().unwrap();
+	assert_eq!(
+		captured_data.get("enrichment.symbol"),
+		Some(&"USDC".to_string())
+	);
+	assert_eq!(
+		captured_data.get("enrichment.decimals"),
+		Some(&"6".to_string())
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_handle_match_with_conditional_triggers() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("evm").build();
+
+	let triggers_capture = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+	let triggers_capture_clone = triggers_capture.clone();
+
+	let mut trigger_execution_service =
+		setup_trigger_execution_service("tests/integration/fixtures/evm/triggers/trigger.json")
+			.await;
+	trigger_execution_service
+		.expect_execute()
+		.withf(
+			move |triggers, _variables, _monitor_match, _trigger_scripts| {
+				*triggers_capture_clone.lock().unwrap() = triggers.to_vec();
+				true
+			},
+		)
+		.returning(|_, _, _, _| Ok(()));
+
+	let mut monitor = test_data.monitor.clone();
+	monitor.triggers = vec![
+		MonitorTrigger::Conditional {
+			name: "function_pager".to_string(),
+			when: vec![MatchConditionType::Functions],
+		},
+		MonitorTrigger::Conditional {
+			name: "event_log".to_string(),
+			when: vec![MatchConditionType::Events, MatchConditionType::Transactions],
+		},
+		MonitorTrigger::Name("always".to_string()),
+	];
+
+	// Event-only match
+	let match_wrapper =
+		MonitorMatch::EVM(Box::new(openzeppelin_monitor::models::EVMMonitorMatch {
+			monitor,
+			transaction: TransactionBuilder::new().build(),
+			receipt: Some(ReceiptBuilder::new().build()),
+			revert_reason: None,
+			logs: None,
+			block_timestamp: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: openzeppelin_monitor::models::MatchConditions {
+				events: vec![EventCondition {
+					signature: "Transfer(address,address,uint256)".to_string(),
+					expression: None,
+				}],
+				..Default::default()
+			},
+			matched_on_args: None,
+			enrichment: HashMap::new(),
+		}));
+
+	let result = handle_match(match_wrapper, &trigger_execution_service, &HashMap::new()).await;
+	assert!(result.is_ok(), "Handle match should succeed");
+
+	// The function-only trigger is not executed for the event-only match
+	assert_eq!(
+		*triggers_capture.lock().unwrap(),
+		vec!["event_log".to_string(), "always".to_string()]
+	);
+
+	Ok(())
+}
+
 #[tokio::test]
 async fn test_filter_block_with_receipt_and_logs() -> Result<(), Box<FilterError>> {
 	let test_data = TestDataBuilder::new("evm").build();
@@ -1133,6 +1393,155 @@ async fn test_filter_block_needs_receipt_for_status() -> Result<(), Box<FilterEr
 	Ok(())
 }
 
+#[tokio::test]
+async fn test_filter_block_decodes_revert_reason() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("evm").build();
+	let filter_service = FilterService::new();
+
+	// Every transaction in the block failed with Error("Insufficient balance")
+	let receipts: HashMap<String, EVMTransactionReceipt> = test_data
+		.receipts
+		.iter()
+		.map(|receipt| {
+			let mut receipt = receipt.clone();
+			receipt.0.status = Some(Uint::from(0));
+			(format!("{:?}", receipt.transaction_hash), receipt)
+		})
+		.collect();
+	let revert_data = format!(
+		"0x{}",
+		hex::encode(Revert::from("Insufficient balance").abi_encode())
+	);
+
+	let mut mock_transport = MockEVMTransportClient::new();
+	mock_transport
+		.expect_send_raw_request()
+		.returning(move |method, params| match method {
+			"eth_getTransactionReceipt" => {
+				let hash = params.unwrap()[0].as_str().unwrap().to_string();
+				Ok(json!({ "result": receipts.get(&hash) }))
+			}
+			"eth_getLogs" => Ok(json!({ "result": Vec::<EVMReceiptLog>::new() })),
+			"eth_call" => Ok(json!({
+				"error": {
+					"code": 3,
+					"message": "execution reverted",
+					"data": revert_data,
+				}
+			})),
+			_ => Err(TransportError::http(
+				reqwest::StatusCode::METHOD_NOT_ALLOWED,
+				"random.url".to_string(),
+				"Unexpected method call".to_string(),
+				None,
+				None,
+			)),
+		});
+
+	let client = EvmClient::new_with_transport(mock_transport);
+
+	let mut network = test_data.network.clone();
+	network.decode_revert_reasons = Some(true);
+
+	let mut monitor = test_data.monitor;
+	monitor.match_conditions.events = vec![];
+	monitor.match_conditions.functions = vec![];
+	monitor.match_conditions.transactions = vec![TransactionCondition {
+		status: TransactionStatus::Failure,
+		expression: Some("revert_reason == 'Insufficient balance'".to_string()),
+	}];
+
+	let matches = filter_service
+		.filter_block(
+			&client,
+			&network,
+			&test_data.blocks[0],
+			&[monitor.clone()],
+			None,
+		)
+		.await?;
+
+	assert!(!matches.is_empty(), "Should have found matches");
+	for monitor_match in &matches {
+		match monitor_match {
+			MonitorMatch::EVM(evm_match) => {
+				assert_eq!(
+					evm_match.revert_reason.as_deref(),
+					Some("Insufficient balance")
+				);
+				assert_eq!(
+					evm_match.matched_on.transactions[0].status,
+					TransactionStatus::Failure
+				);
+			}
+			_ => panic!("Expected EVM match"),
+		}
+	}
+
+	// The reason is not decoded when the network does not enable it
+	let matches = filter_service
+		.filter_block(
+			&client,
+			&test_data.network,
+			&test_data.blocks[0],
+			&[monitor],
+			None,
+		)
+		.await?;
+	assert!(
+		matches.is_empty(),
+		"Should not match without a revert reason"
+	);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_handle_match_with_revert_reason() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("evm").build();
+
+	let data_capture = std::sync::Arc::new(std::sync::Mutex::new(HashMap::new()));
+	let data_capture_clone = data_capture.clone();
+
+	let mut trigger_execution_service =
+		setup_trigger_execution_service("tests/integration/fixtures/evm/triggers/trigger.json")
+			.await;
+	trigger_execution_service
+		.expect_execute()
+		.withf(
+			move |_triggers, variables, _monitor_match, _trigger_scripts| {
+				*data_capture_clone.lock().unwrap() = variables.clone();
+				true
+			},
+		)
+		.returning(|_, _, _, _| Ok(()));
+
+	let match_wrapper =
+		MonitorMatch::EVM(Box::new(openzeppelin_monitor::models::EVMMonitorMatch {
+			monitor: test_data.monitor.clone(),
+			transaction: TransactionBuilder::new().build(),
+			receipt: Some(ReceiptBuilder::new().status(false).build()),
+			revert_reason: Some("Insufficient balance".to_string()),
+			logs: None,
+			block_timestamp: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: openzeppelin_monitor::models::MatchConditions::default(),
+			matched_on_args: None,
+			enrichment: HashMap::new(),
+		}));
+
+	let result = handle_match(match_wrapper, &trigger_execution_service, &HashMap::new()).await;
+	assert!(result.is_ok(), "Handle match should succeed");
+
+	let captured_data = data_capture.lock().unwrap();
+	assert_eq!(
+		captured_data.get("transaction.revert_reason"),
+		Some(&"Insufficient balance".to_string())
+	);
+
+	Ok(())
+}
+
 #[tokio::test]
 async fn test_filter_block_with_tuples_contains_expression() -> Result<(), Box<FilterError>> {
 	// Load test data using common utility
@@ -1288,3 +1697,706 @@ async fn test_filter_block_with_tuples_expression_equality() -> Result<(), Box<F
 
 	Ok(())
 }
+
+#[tokio::test]
+async fn test_filter_block_matches_raw_selector_without_spec() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("evm").build();
+	let filter_service = FilterService::new();
+	let mock_transport = setup_mock_transport(test_data.clone());
+	let client = EvmClient::new_with_transport(mock_transport);
+
+	let mut monitor = make_monitor_with_functions(test_data.monitor, false);
+	monitor.match_conditions.functions[0].signature = "0xa9059cbb".to_string();
+	for address in monitor.addresses.iter_mut() {
+		address.contract_spec = None;
+	}
+
+	let matches = filter_service
+		.filter_block(
+			&client,
+			&test_data.network,
+			&test_data.blocks[0],
+			&[monitor],
+			None,
+		)
+		.await?;
+
+	assert_eq!(matches.len(), 1, "Expected exactly one match");
+
+	match &matches[0] {
+		MonitorMatch::EVM(evm_match) => {
+			assert_eq!(evm_match.matched_on.functions.len(), 1);
+			assert_eq!(evm_match.matched_on.functions[0].signature, "0xa9059cbb");
+
+			let functions = evm_match
+				.matched_on_args
+				.as_ref()
+				.and_then(|args| args.functions.as_ref())
+				.unwrap();
+			assert_eq!(functions[0].hex_signature.as_deref(), Some("0xa9059cbb"));
+			assert!(functions[0].args.is_none());
+		}
+		_ => panic!("Expected EVM match"),
+	}
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_block_matches_raw_topic_without_spec() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("evm").build();
+	let filter_service = FilterService::new();
+	let mock_transport = setup_mock_transport(test_data.clone());
+	let client = EvmClient::new_with_transport(mock_transport);
+
+	let transfer_topic = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+	let mut monitor = make_monitor_with_events(test_data.monitor, false);
+	monitor.match_conditions.events[0].signature = transfer_topic.to_string();
+	for address in monitor.addresses.iter_mut() {
+		address.contract_spec = None;
+	}
+
+	let matches = filter_service
+		.filter_block(
+			&client,
+			&test_data.network,
+			&test_data.blocks[0],
+			&[monitor],
+			None,
+		)
+		.await?;
+
+	assert_eq!(matches.len(), 1, "Expected exactly one match");
+
+	match &matches[0] {
+		MonitorMatch::EVM(evm_match) => {
+			assert_eq!(evm_match.matched_on.events.len(), 1);
+			assert_eq!(evm_match.matched_on.events[0].signature, transfer_topic);
+		}
+		_ => panic!("Expected EVM match"),
+	}
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_block_with_strict_address_checksum() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("evm").build();
+	let filter_service = FilterService::new();
+
+	let mut network = test_data.network.clone();
+	network.strict_address_checksum = Some(true);
+
+	let transfer_topic = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+	let mut monitor = make_monitor_with_events(test_data.monitor.clone(), false);
+	monitor.match_conditions.events[0].signature = transfer_topic.to_string();
+	for address in monitor.addresses.iter_mut() {
+		address.contract_spec = None;
+	}
+
+	// Checksummed addresses match
+	let mut checksummed = monitor.clone();
+	for address in checksummed.addresses.iter_mut() {
+		address.address = Address::from_str(&address.address)
+			.unwrap()
+			.to_checksum(None);
+	}
+	let matches = filter_service
+		.filter_block(
+			&EvmClient::new_with_transport(setup_mock_transport(test_data.clone())),
+			&network,
+			&test_data.blocks[0],
+			&[checksummed.clone()],
+			None,
+		)
+		.await?;
+	assert_eq!(matches.len(), 1, "Expected exactly one match");
+
+	// Addresses with an invalid checksum are rejected when the monitors are loaded, and match
+	// without strict checksums
+	let mut mistyped = checksummed;
+	for address in mistyped.addresses.iter_mut() {
+		let position = address.address[2..]
+			.find(|c: char| c.is_ascii_alphabetic())
+			.unwrap() + 2;
+		let flipped = match address.address.as_bytes()[position] {
+			c if c.is_ascii_uppercase() => c.to_ascii_lowercase(),
+			c => c.to_ascii_uppercase(),
+		};
+		address
+			.address
+			.replace_range(position..=position, &(flipped as char).to_string());
+	}
+	let matches = filter_service
+		.filter_block(
+			&EvmClient::new_with_transport(setup_mock_transport(test_data.clone())),
+			&test_data.network,
+			&test_data.blocks[0],
+			&[mistyped],
+			None,
+		)
+		.await?;
+	assert_eq!(matches.len(), 1, "Expected exactly one match");
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_block_requests_logs_for_monitored_addresses_and_topics(
+) -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("evm").build();
+	let filter_service = FilterService::new();
+	let receipts = test_data.receipts.clone();
+	let get_logs_params = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+	let mut mock_transport = MockEVMTransportClient::new();
+	let captured_params = get_logs_params.clone();
+	mock_transport
+		.expect_send_raw_request()
+		.returning(move |method, params| match method {
+			"eth_getLogs" => {
+				captured_params
+					.lock()
+					.unwrap()
+					.push(json!(params.unwrap_or_default()));
+				Ok(json!({ "result": &receipts[0].logs }))
+			}
+			_ => Ok(json!({ "result": &receipts[0] })),
+		});
+	let client = EvmClient::new_with_transport(mock_transport);
+
+	let mut monitored_addresses: Vec<String> = test_data
+		.monitor
+		.addresses
+		.iter()
+		.map(|addr| addr.address.to_lowercase())
+		.collect();
+	monitored_addresses.sort();
+
+	let event_monitor = make_monitor_with_events(test_data.monitor.clone(), false);
+	let mut second_monitor = make_monitor_with_events(test_data.monitor.clone(), false);
+	second_monitor.addresses.truncate(1);
+	second_monitor.addresses[0].address = "0x0000000000000000000000000000000000005678".to_string();
+	second_monitor.match_conditions.events[0].signature =
+		"0x8c5be1e5ebec7d5bd14f71427e1e84f3dd0314c0f7b2291e5b200ac8c7c3b925".to_string();
+
+	filter_service
+		.filter_block(
+			&client,
+			&test_data.network,
+			&test_data.blocks[0],
+			&[event_monitor.clone(), second_monitor],
+			None,
+		)
+		.await?;
+
+	// A monitor without event conditions considers every log, so topics are not filtered
+	let function_monitor = make_monitor_with_functions(test_data.monitor, false);
+	filter_service
+		.filter_block(
+			&client,
+			&test_data.network,
+			&test_data.blocks[0],
+			&[event_monitor, function_monitor],
+			None,
+		)
+		.await?;
+
+	let params = get_logs_params.lock().unwrap();
+	assert_eq!(params.len(), 2);
+	let mut expected_addresses = monitored_addresses.clone();
+	expected_addresses.insert(0, "0x0000000000000000000000000000000000005678".to_string());
+	assert_eq!(params[0][0]["address"], json!(expected_addresses));
+	assert_eq!(
+		params[0][0]["topics"],
+		json!([[
+			"0x8c5be1e5ebec7d5bd14f71427e1e84f3dd0314c0f7b2291e5b200ac8c7c3b925",
+			"0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+		]])
+	);
+	assert_eq!(params[1][0]["address"], json!(monitored_addresses));
+	assert!(params[1][0].get("topics").is_none());
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_block_matches_percentage_change_across_transactions(
+) -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("evm").build();
+	let filter_service = FilterService::new();
+
+	// Two USDC transfers in different transactions of the block, the second one 15% larger
+	let transfer_log = serde_json::to_value(&test_data.receipts[0].logs[0]).unwrap();
+	let transfer = |tx_hash: &str, log_index: &str, value: u64| {
+		let mut log = transfer_log.clone();
+		log["transactionHash"] = json!(tx_hash);
+		log["logIndex"] = json!(log_index);
+		log["data"] = json!(format!("0x{:064x}", value));
+		log
+	};
+	let logs = json!([
+		transfer(
+			"0xa39d1b9b3edda74414bd6ffaf6596f8ea12cf0012fd9a930f71ed69df6ff34d0",
+			"0x1",
+			1000
+		),
+		transfer(
+			"0xd5069b22a3a89a36d592d5a1f72a281bc5d11d6d0bac6f0a878c13abb764b6d8",
+			"0x2",
+			1150
+		),
+	]);
+	let mut mock_transport = MockEVMTransportClient::new();
+	mock_transport
+		.expect_send_raw_request()
+		.returning(move |method, _params| match method {
+			"eth_getLogs" => Ok(json!({ "result": logs })),
+			_ => Ok(json!({ "result": "1" })),
+		});
+	let client = EvmClient::new_with_transport(mock_transport);
+
+	let make_monitor = |expression: &str| {
+		let mut monitor = make_monitor_with_events(test_data.monitor.clone(), false);
+		monitor.match_conditions.events = vec![];
+		let transfer_value = EventField {
+			signature: "Transfer(address,address,uint256)".to_string(),
+			field: "value".to_string(),
+			filter: None,
+		};
+		monitor.match_conditions.percentage_changes = vec![PercentageChangeCondition {
+			from: transfer_value.clone(),
+			to: transfer_value,
+			expression: expression.to_string(),
+		}];
+		monitor
+	};
+
+	// The change crosses the threshold
+	let matches = filter_service
+		.filter_block(
+			&client,
+			&test_data.network,
+			&test_data.blocks[0],
+			&[make_monitor("abs_change > 10")],
+			None,
+		)
+		.await?;
+
+	assert_eq!(matches.len(), 1, "Expected exactly one match");
+	match &matches[0] {
+		MonitorMatch::EVM(evm_match) => {
+			// Reported on the transaction that emitted the changed value
+			assert_eq!(
+				evm_match.transaction.hash.to_string(),
+				"0xd5069b22a3a89a36d592d5a1f72a281bc5d11d6d0bac6f0a878c13abb764b6d8"
+			);
+			assert_eq!(evm_match.matched_on.percentage_changes.len(), 1);
+			assert!(evm_match.matched_on.events.is_empty());
+
+			let events = evm_match
+				.matched_on_args
+				.as_ref()
+				.and_then(|args| args.events.as_ref())
+				.unwrap();
+			assert_eq!(events.len(), 2);
+		}
+		_ => panic!("Expected EVM match"),
+	}
+
+	// The change stays under the threshold
+	let matches = filter_service
+		.filter_block(
+			&client,
+			&test_data.network,
+			&test_data.blocks[0],
+			&[make_monitor("abs_change > 20")],
+			None,
+		)
+		.await?;
+	assert!(matches.is_empty());
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_block_with_max_logs_per_tx_skips_excess_logs() -> Result<(), Box<FilterError>>
+{
+	let test_data = TestDataBuilder::new("evm").build();
+	let filter_service = FilterService::new();
+
+	// The first transaction emits 5 transfers, only the last of which is large, while the
+	// second one emits a single large transfer
+	let transfer_log = serde_json::to_value(&test_data.receipts[0].logs[0]).unwrap();
+	let transfer = |tx_hash: &str, log_index: u64, value: u64| {
+		let mut log = transfer_log.clone();
+		log["transactionHash"] = json!(tx_hash);
+		log["logIndex"] = json!(format!("0x{:x}", log_index));
+		log["data"] = json!(format!("0x{:064x}", value));
+		log
+	};
+	let oversized_tx = "0xa39d1b9b3edda74414bd6ffaf6596f8ea12cf0012fd9a930f71ed69df6ff34d0";
+	let regular_tx = "0xd5069b22a3a89a36d592d5a1f72a281bc5d11d6d0bac6f0a878c13abb764b6d8";
+	let mut logs = (1..=4)
+		.map(|index| transfer(oversized_tx, index, 10))
+		.collect::<Vec<_>>();
+	logs.push(transfer(oversized_tx, 5, 5000));
+	logs.push(transfer(regular_tx, 6, 5000));
+	let logs = Value::Array(logs);
+	let mut mock_transport = MockEVMTransportClient::new();
+	mock_transport
+		.expect_send_raw_request()
+		.returning(move |method, _params| match method {
+			"eth_getLogs" => Ok(json!({ "result": logs })),
+			_ => Ok(json!({ "result": "1" })),
+		});
+	let client = EvmClient::new_with_transport(mock_transport);
+
+	let mut monitor = make_monitor_with_events(test_data.monitor.clone(), false);
+	monitor.match_conditions.events = vec![EventCondition {
+		signature: "Transfer(address,address,uint256)".to_string(),
+		expression: Some("value > 1000".to_string()),
+	}];
+	let matched_transactions = |matches: &[MonitorMatch]| {
+		matches
+			.iter()
+			.map(|monitor_match| match monitor_match {
+				MonitorMatch::EVM(evm_match) => evm_match.transaction.hash.to_string(),
+				_ => panic!("Expected EVM match"),
+			})
+			.collect::<Vec<_>>()
+	};
+
+	// Without a limit below the number of logs, both transactions match
+	let matches = filter_service
+		.filter_block(
+			&client,
+			&test_data.network,
+			&test_data.blocks[0],
+			std::slice::from_ref(&monitor),
+			None,
+		)
+		.await?;
+	assert_eq!(
+		matched_transactions(&matches),
+		vec![oversized_tx.to_string(), regular_tx.to_string()]
+	);
+
+	// With the limit, the large transfer of the oversized transaction is skipped, and the
+	// block is still processed
+	let mut network = test_data.network.clone();
+	network.slug = "max_logs_per_tx_network".to_string();
+	network.max_logs_per_tx = Some(3);
+	let truncated = TRANSACTION_LOGS_TRUNCATED_TOTAL.with_label_values(&[network.slug.as_str()]);
+	let truncated_before = truncated.get();
+
+	let matches = filter_service
+		.filter_block(&client, &network, &test_data.blocks[0], &[monitor], None)
+		.await?;
+	assert_eq!(matched_transactions(&matches), vec![regular_tx.to_string()]);
+	assert_eq!(truncated.get() - truncated_before, 1);
+
+	Ok(())
+}
+
+/// Builds a block of `count` USDC transfers, with transfer `i` of value `i` and hash `i + 1`,
+/// along with the same block holding the transaction hashes only
+fn make_transfer_blocks(test_data: &TestData, count: u64) -> (BlockType, BlockType) {
+	let usdc: Address = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+		.parse()
+		.unwrap();
+	let transactions: Vec<_> = (0..count)
+		.map(|i| {
+			TransactionBuilder::new()
+				.hash(B256::from(U256::from(i + 1)))
+				.to(usdc)
+				.value(U256::from(i))
+				.transaction_index(i as usize)
+				.build()
+		})
+		.collect();
+	match &test_data.blocks[0] {
+		BlockType::EVM(block) => {
+			let mut full_block = block.clone();
+			full_block.0.transactions = transactions.clone();
+			let mut hashes_block = block.clone();
+			hashes_block.0.transactions = transactions
+				.iter()
+				.map(|transaction| TransactionBuilder::new().hash(transaction.hash).build())
+				.collect();
+			(BlockType::EVM(full_block), BlockType::EVM(hashes_block))
+		}
+		_ => panic!("Expected EVM block"),
+	}
+}
+
+/// Index of the transfer of a hash built by [`make_transfer_blocks`]
+fn transfer_index(hash: &Value) -> u64 {
+	U256::from_str(hash.as_str().unwrap()).unwrap().to::<u64>() - 1
+}
+
+#[tokio::test]
+async fn test_filter_block_with_tx_chunk_size_matches_whole_block() -> Result<(), Box<FilterError>>
+{
+	let test_data = TestDataBuilder::new("evm").build();
+	let filter_service = FilterService::new();
+	let (full_block, hashes_block) = make_transfer_blocks(&test_data, 2000);
+	let transactions = match &full_block {
+		BlockType::EVM(block) => block.0.transactions.clone(),
+		_ => unreachable!(),
+	};
+
+	// The transactions of the chunked block are fetched in batches, one per chunk
+	let batches = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+	let mut mock_transport = MockEVMTransportClient::new();
+	mock_transport
+		.expect_send_raw_request()
+		.returning(|method, _params| {
+			assert_eq!(method, "eth_getLogs");
+			Ok(json!({ "result": [] }))
+		});
+	mock_transport.expect_send_batch_request().returning({
+		let batches = batches.clone();
+		move |method, params| {
+			assert_eq!(method, "eth_getTransactionByHash");
+			batches.lock().unwrap().push(params.len());
+			Ok(params
+				.iter()
+				.map(|params| {
+					let transaction = &transactions[transfer_index(&params[0]) as usize];
+					json!({ "result": transaction })
+				})
+				.collect())
+		}
+	});
+	let client = EvmClient::new_with_transport(mock_transport);
+
+	let mut monitor = make_monitor_with_transactions(test_data.monitor.clone(), false);
+	monitor.match_conditions.transactions = vec![TransactionCondition {
+		status: TransactionStatus::Any,
+		expression: Some("value > 250".to_string()),
+	}];
+
+	let matches = filter_service
+		.filter_block(
+			&client,
+			&test_data.network,
+			&full_block,
+			&[monitor.clone()],
+			None,
+		)
+		.await?;
+	assert_eq!(matches.len(), 1749);
+	assert!(batches.lock().unwrap().is_empty());
+
+	let mut chunked_network = test_data.network.clone();
+	chunked_network.tx_chunk_size = Some(150);
+	let chunked_matches = filter_service
+		.filter_block(&client, &chunked_network, &hashes_block, &[monitor], None)
+		.await?;
+
+	assert_eq!(
+		serde_json::to_value(&chunked_matches).unwrap(),
+		serde_json::to_value(&matches).unwrap()
+	);
+	let mut expected_batches = vec![150; 13];
+	expected_batches.push(50);
+	assert_eq!(*batches.lock().unwrap(), expected_batches);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_block_with_tx_chunk_size_holds_one_chunk_at_a_time(
+) -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("evm").build();
+	let filter_service = FilterService::new();
+	let (full_block, hashes_block) = make_transfer_blocks(&test_data, 600);
+	let transactions = match &full_block {
+		BlockType::EVM(block) => block.0.transactions.clone(),
+		_ => unreachable!(),
+	};
+
+	/// Request seen by the transport, with the transfer indexes it concerns
+	#[derive(Debug)]
+	enum Request {
+		Batch(String, Vec<u64>),
+		Call(u64),
+	}
+
+	// Every 50th transfer failed, and is replayed with `eth_call` while it is processed
+	let requests = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+	let mut mock_transport = MockEVMTransportClient::new();
+	mock_transport.expect_send_raw_request().returning({
+		let requests = requests.clone();
+		move |method, params| match method {
+			"eth_getLogs" => Ok(json!({ "result": [] })),
+			"eth_call" => {
+				let value = &params.unwrap()[0]["value"];
+				requests.lock().unwrap().push(Request::Call(
+					U256::from_str(value.as_str().unwrap()).unwrap().to(),
+				));
+				Ok(json!({ "result": "0x" }))
+			}
+			_ => panic!("Unexpected request: {}", method),
+		}
+	});
+	mock_transport.expect_send_batch_request().returning({
+		let requests = requests.clone();
+		move |method, params| {
+			let indexes = params
+				.iter()
+				.map(|params| transfer_index(&params[0]))
+				.collect::<Vec<_>>();
+			requests
+				.lock()
+				.unwrap()
+				.push(Request::Batch(method.to_string(), indexes.clone()));
+			Ok(indexes
+				.into_iter()
+				.map(|index| match method {
+					"eth_getTransactionByHash" => {
+						json!({ "result": transactions[index as usize] })
+					}
+					"eth_getTransactionReceipt" => json!({
+						"result": ReceiptBuilder::new()
+							.transaction_hash(B256::from(U256::from(index + 1)))
+							.transaction_index(index as usize)
+							.status(index % 50 != 0)
+							.gas_used(U256::from(21000))
+							.build()
+					}),
+					_ => panic!("Unexpected batch: {}", method),
+				})
+				.collect())
+		}
+	});
+	let client = EvmClient::new_with_transport(mock_transport);
+
+	let mut network = test_data.network.clone();
+	network.tx_chunk_size = Some(150);
+	network.decode_revert_reasons = Some(true);
+	let mut monitor = make_monitor_with_transactions(test_data.monitor.clone(), false);
+	monitor.match_conditions.transactions = vec![TransactionCondition {
+		status: TransactionStatus::Any,
+		expression: Some("gas_used > 0".to_string()),
+	}];
+
+	let matches = filter_service
+		.filter_block(&client, &network, &hashes_block, &[monitor], None)
+		.await?;
+	assert_eq!(matches.len(), 600);
+
+	// Each chunk is fetched, receipts first, then processed before the next chunk is fetched
+	let requests = requests.lock().unwrap();
+	let mut chunk = Vec::new();
+	let mut chunks = 0;
+	let mut calls = 0;
+	for request in requests.iter() {
+		match request {
+			Request::Batch(method, indexes) if method == "eth_getTransactionReceipt" => {
+				chunk = indexes.clone();
+				assert_eq!(chunk.len(), 150);
+				chunks += 1;
+			}
+			Request::Batch(_, indexes) => assert_eq!(*indexes, chunk),
+			Request::Call(index) => {
+				assert!(
+					chunk.contains(index),
+					"{} replayed outside of its chunk",
+					index
+				);
+				calls += 1;
+			}
+		}
+	}
+	assert_eq!(chunks, 4);
+	assert_eq!(calls, 12);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_block_with_sample_rate() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("evm").build();
+	let filter_service = FilterService::new();
+
+	let usdc: Address = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+		.parse()
+		.unwrap();
+	let block = match &test_data.blocks[0] {
+		BlockType::EVM(block) => {
+			let mut block = block.clone();
+			block.0.transactions = (0..2000u64)
+				.map(|i| {
+					TransactionBuilder::new()
+						.hash(B256::from(U256::from(i + 1)))
+						.to(usdc)
+						.build()
+				})
+				.collect();
+			BlockType::EVM(block)
+		}
+		_ => panic!("Expected EVM block"),
+	};
+
+	let mut mock_transport = MockEVMTransportClient::new();
+	mock_transport
+		.expect_send_raw_request()
+		.returning(|method, _params| match method {
+			"eth_getLogs" => Ok(json!({ "result": [] })),
+			_ => Ok(json!({ "result": "1" })),
+		});
+	let client = EvmClient::new_with_transport(mock_transport);
+
+	let mut monitor = make_monitor_with_transactions(test_data.monitor.clone(), false);
+	monitor.match_conditions.transactions[0].status = TransactionStatus::Any;
+	monitor.sample_rate = Some(0.25);
+
+	let matches = filter_service
+		.filter_block(
+			&client,
+			&test_data.network,
+			&block,
+			&[monitor.clone()],
+			None,
+		)
+		.await?;
+
+	// Roughly the sampled fraction of the transactions is evaluated
+	assert!(
+		(400..=600).contains(&matches.len()),
+		"Expected about 500 matches, got {}",
+		matches.len()
+	);
+
+	// The same transactions are sampled on every run
+	let matched_hashes = |matches: &[MonitorMatch]| {
+		matches
+			.iter()
+			.map(|m| match m {
+				MonitorMatch::EVM(evm_match) => evm_match.transaction.hash,
+				_ => panic!("Expected EVM match"),
+			})
+			.collect::<Vec<_>>()
+	};
+	let rerun_matches = filter_service
+		.filter_block(
+			&client,
+			&test_data.network,
+			&block,
+			&[monitor.clone()],
+			None,
+		)
+		.await?;
+	assert_eq!(matched_hashes(&rerun_matches), matched_hashes(&matches));
+
+	// Nothing is evaluated with a zero sample rate
+	monitor.sample_rate = Some(0.0);
+	let matches = filter_service
+		.filter_block(&client, &test_data.network, &block, &[monitor], None)
+		.await?;
+	assert!(matches.is_empty());
+
+	Ok(())
+}
@@ -13,7 +13,10 @@ use openzeppelin_monitor::{
 		StellarMatchParamsMap, StellarMonitorMatch, StellarTransaction, StellarTransactionInfo,
 		TransactionCondition, TransactionStatus, TransactionType,
 	},
-	services::filter::{handle_match, FilterError, FilterService},
+	services::filter::{
+		handle_match, BlockFilter, ContractSpecCache, FilterError, FilterService,
+		StellarBlockFilter,
+	},
 };
 
 use crate::integration::{
@@ -104,7 +107,7 @@ async fn test_monitor_events_with_no_expressions() -> Result<(), Box<FilterError
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	mock_client
 		.expect_get_contract_spec()
@@ -177,7 +180,7 @@ async fn test_monitor_events_with_expressions() -> Result<(), Box<FilterError>>
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	mock_client
 		.expect_get_contract_spec()
@@ -253,6 +256,110 @@ async fn test_monitor_events_with_expressions() -> Result<(), Box<FilterError>>
 	Ok(())
 }
 
+#[tokio::test]
+async fn test_monitor_events_only_fetches_filtered_events_first() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("stellar").build();
+	let filter_service = FilterService::new();
+
+	let monitor = make_monitor_with_events(test_data.monitor, false);
+	let mut expected_contract_ids = monitor
+		.addresses
+		.iter()
+		.map(|address| address.address.clone())
+		.collect::<Vec<String>>();
+	expected_contract_ids.sort();
+
+	let events: Vec<StellarEvent> =
+		read_and_parse_json("tests/integration/fixtures/stellar/events.json");
+	let event_transaction_hashes = events
+		.iter()
+		.map(|event| event.transaction_hash.clone())
+		.collect::<Vec<String>>();
+	let transactions: Vec<StellarTransactionInfo> =
+		read_and_parse_json("tests/integration/fixtures/stellar/transactions.json");
+
+	let mut mock_client = MockStellarClientTrait::<MockStellarTransportClient>::new();
+	let decoded_transactions: Vec<StellarTransaction> = transactions
+		.iter()
+		.map(|tx| StellarTransaction::from(tx.clone()))
+		.collect();
+
+	// Events are requested for the monitored contracts only
+	mock_client
+		.expect_get_events()
+		.withf(move |_, _, filters| {
+			filters
+				.iter()
+				.flat_map(|filter| filter.contract_ids.clone())
+				.collect::<Vec<String>>()
+				== expected_contract_ids
+		})
+		.times(1)
+		.returning(move |_, _, _| Ok(events.clone()));
+
+	mock_client
+		.expect_get_transactions()
+		.times(1)
+		.returning(move |_, _| Ok(decoded_transactions.clone()));
+
+	mock_client
+		.expect_get_contract_spec()
+		.returning(move |_| Ok(test_data.contract_spec.clone().unwrap()));
+
+	let matches = filter_service
+		.filter_block(
+			&mock_client,
+			&test_data.network,
+			&test_data.blocks[0],
+			&[monitor],
+			None,
+		)
+		.await?;
+
+	assert_eq!(matches.len(), 1, "Expected exactly one match");
+	match &matches[0] {
+		MonitorMatch::Stellar(stellar_match) => {
+			assert!(event_transaction_hashes.contains(stellar_match.transaction.hash()));
+			assert_eq!(stellar_match.matched_on.events.len(), 1);
+		}
+		_ => panic!("Expected Stellar match"),
+	}
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_monitor_events_only_skips_transactions_without_events() -> Result<(), Box<FilterError>>
+{
+	let test_data = TestDataBuilder::new("stellar").build();
+	let filter_service = FilterService::new();
+
+	let monitor = make_monitor_with_events(test_data.monitor, false);
+
+	let mut mock_client = MockStellarClientTrait::<MockStellarTransportClient>::new();
+	mock_client
+		.expect_get_events()
+		.times(1)
+		.returning(|_, _, _| Ok(vec![]));
+
+	// Transactions are not fetched when no monitored contract emitted an event
+	mock_client.expect_get_transactions().never();
+
+	let matches = filter_service
+		.filter_block(
+			&mock_client,
+			&test_data.network,
+			&test_data.blocks[0],
+			&[monitor],
+			None,
+		)
+		.await?;
+
+	assert!(matches.is_empty());
+
+	Ok(())
+}
+
 #[tokio::test]
 async fn test_monitor_functions_with_no_expressions() -> Result<(), Box<FilterError>> {
 	// Load test data using common utility
@@ -287,7 +394,7 @@ async fn test_monitor_functions_with_no_expressions() -> Result<(), Box<FilterEr
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	mock_client
 		.expect_get_contract_spec()
@@ -367,7 +474,7 @@ async fn test_monitor_functions_with_expressions() -> Result<(), Box<FilterError
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	mock_client
 		.expect_get_contract_spec()
@@ -461,7 +568,7 @@ async fn test_monitor_transactions_with_expressions() -> Result<(), Box<FilterEr
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	mock_client
 		.expect_get_contract_spec()
@@ -532,7 +639,7 @@ async fn test_monitor_transactions_with_no_expressions() -> Result<(), Box<Filte
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	mock_client
 		.expect_get_contract_spec()
@@ -607,7 +714,7 @@ async fn test_monitor_with_multiple_conditions() -> Result<(), Box<FilterError>>
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	mock_client
 		.expect_get_contract_spec()
@@ -746,7 +853,7 @@ async fn test_handle_match() -> Result<(), Box<FilterError>> {
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	mock_client
 		.expect_get_contract_spec()
@@ -867,7 +974,7 @@ async fn test_handle_match_with_no_args() -> Result<(), Box<FilterError>> {
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	mock_client
 		.expect_get_contract_spec()
@@ -991,6 +1098,9 @@ async fn test_handle_match_with_key_collision() -> Result<(), Box<FilterError>>
 			}],
 			events: vec![],
 			transactions: vec![],
+			operations: vec![],
+			aggregates: vec![],
+			percentage_changes: vec![],
 		},
 		matched_on_args: Some(StellarMatchArguments {
 			functions: Some(vec![StellarMatchParamsMap {
@@ -1012,6 +1122,7 @@ async fn test_handle_match_with_key_collision() -> Result<(), Box<FilterError>>
 			}]),
 			events: None,
 		}),
+		enrichment: HashMap::new(),
 	};
 
 	let match_wrapper = MonitorMatch::Stellar(Box::new(stellar_match));
@@ -1101,7 +1212,7 @@ async fn test_filter_with_contract_spec() -> Result<(), Box<FilterError>> {
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	// Expect contract spec to be called
 	mock_client
@@ -1171,7 +1282,7 @@ async fn test_filter_with_invalid_contract_spec() -> Result<(), Box<FilterError>
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	// Setup mock to return error for contract spec
 	mock_client
@@ -1236,7 +1347,7 @@ async fn test_filter_with_abi_in_config() -> Result<(), Box<FilterError>> {
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	// get_contract_spec should NOT be called since we provide the ABI in config
 	mock_client.expect_get_contract_spec().times(0);
@@ -1254,6 +1365,9 @@ async fn test_filter_with_abi_in_config() -> Result<(), Box<FilterError>> {
 	monitor.addresses = vec![AddressWithSpec {
 		address: contract_with_spec.0.clone(),
 		contract_spec: Some(contract_with_spec.1.clone()),
+		contract_spec_path: None,
+		additional_specs: vec![],
+		event_signatures: HashMap::new(),
 	}];
 
 	// Run filter_block with the test data
@@ -1358,7 +1472,7 @@ async fn test_filter_with_udt_expression() -> Result<(), Box<FilterError>> {
 	mock_client
 		.expect_get_events()
 		.times(1)
-		.returning(move |_, _| Ok(events.clone()));
+		.returning(move |_, _, _| Ok(events.clone()));
 
 	// get_contract_spec should NOT be called since we provide the ABI in config
 	mock_client.expect_get_contract_spec().times(0);
@@ -1379,6 +1493,9 @@ async fn test_filter_with_udt_expression() -> Result<(), Box<FilterError>> {
 	monitor.addresses = vec![AddressWithSpec {
 		address: contract_with_spec.0.clone(),
 		contract_spec: Some(contract_with_spec.1.clone()),
+		contract_spec_path: None,
+		additional_specs: vec![],
+		event_signatures: HashMap::new(),
 	}];
 
 	// Run filter_block with the test data
@@ -1408,3 +1525,216 @@ async fn test_filter_with_udt_expression() -> Result<(), Box<FilterError>> {
 
 	Ok(())
 }
+
+#[tokio::test]
+async fn test_filter_refreshes_stale_contract_spec() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("stellar").build();
+	let contract_spec_cache = ContractSpecCache::default();
+	let filter = StellarBlockFilter::<MockStellarClientTrait<MockStellarTransportClient>>::with_contract_spec_cache(
+		contract_spec_cache.clone(),
+	);
+
+	let monitor = make_monitor_with_functions(test_data.monitor, true);
+
+	let events: Vec<StellarEvent> =
+		read_and_parse_json("tests/integration/fixtures/stellar/events.json");
+	let transactions: Vec<StellarTransactionInfo> =
+		read_and_parse_json("tests/integration/fixtures/stellar/transactions.json");
+	let refreshed_spec = test_data.contract_spec.unwrap();
+
+	// A spec from before the contract was upgraded, without the `transfer` function
+	let stale_spec = ContractSpec::Stellar(StellarContractSpec::from(json!([{
+		"function_v0": {
+			"doc": "",
+			"name": "decimals",
+			"inputs": [],
+			"outputs": ["u32"]
+		}
+	}])));
+	let contract_with_spec: (String, ContractSpec) = (
+		"CBIELTK6YBZJU5UP2WWQEUCYKLPU6AUNZ2BQ4WWFEIE3USCIHMXQDAMA".to_string(),
+		stale_spec,
+	);
+
+	let mut mock_client = MockStellarClientTrait::<MockStellarTransportClient>::new();
+	let decoded_transactions: Vec<StellarTransaction> = transactions
+		.iter()
+		.map(|tx| StellarTransaction::from(tx.clone()))
+		.collect();
+
+	mock_client
+		.expect_get_transactions()
+		.times(2)
+		.returning(move |_, _| Ok(decoded_transactions.clone()));
+
+	mock_client
+		.expect_get_events()
+		.times(2)
+		.returning(move |_, _, _| Ok(events.clone()));
+
+	// The stale spec is refreshed once and reused for the following block
+	mock_client
+		.expect_get_contract_spec()
+		.times(1)
+		.returning(move |_| Ok(refreshed_spec.clone()));
+
+	for _ in 0..2 {
+		let matches = filter
+			.filter_block(
+				&mock_client,
+				&test_data.network,
+				&test_data.blocks[0],
+				&[monitor.clone()],
+				Some(&[contract_with_spec.clone()]),
+			)
+			.await?;
+
+		assert_eq!(matches.len(), 1, "Expected exactly one match");
+		match &matches[0] {
+			MonitorMatch::Stellar(stellar_match) => {
+				assert_eq!(
+					stellar_match.matched_on.functions[0].signature,
+					"transfer(Address,Address,I128)"
+				);
+			}
+			_ => panic!("Expected Stellar match"),
+		}
+	}
+
+	// The refreshed spec is kept in the injected cache
+	assert_eq!(contract_spec_cache.len(), 1);
+	assert!(contract_spec_cache
+		.get("CBIELTK6YBZJU5UP2WWQEUCYKLPU6AUNZ2BQ4WWFEIE3USCIHMXQDAMA")
+		.is_some());
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_stale_contract_spec_refresh_failure() -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("stellar").build();
+	let filter = StellarBlockFilter::<MockStellarClientTrait<MockStellarTransportClient>>::new();
+
+	let monitor = make_monitor_with_functions(test_data.monitor, true);
+
+	let events: Vec<StellarEvent> =
+		read_and_parse_json("tests/integration/fixtures/stellar/events.json");
+	let transactions: Vec<StellarTransactionInfo> =
+		read_and_parse_json("tests/integration/fixtures/stellar/transactions.json");
+
+	let stale_spec = ContractSpec::Stellar(StellarContractSpec::from(json!([{
+		"function_v0": {
+			"doc": "",
+			"name": "decimals",
+			"inputs": [],
+			"outputs": ["u32"]
+		}
+	}])));
+	let contract_with_spec: (String, ContractSpec) = (
+		"CBIELTK6YBZJU5UP2WWQEUCYKLPU6AUNZ2BQ4WWFEIE3USCIHMXQDAMA".to_string(),
+		stale_spec,
+	);
+
+	let mut mock_client = MockStellarClientTrait::<MockStellarTransportClient>::new();
+	let decoded_transactions: Vec<StellarTransaction> = transactions
+		.iter()
+		.map(|tx| StellarTransaction::from(tx.clone()))
+		.collect();
+
+	mock_client
+		.expect_get_transactions()
+		.times(2)
+		.returning(move |_, _| Ok(decoded_transactions.clone()));
+
+	mock_client
+		.expect_get_events()
+		.times(2)
+		.returning(move |_, _, _| Ok(events.clone()));
+
+	// A failed refresh is still rate limited
+	mock_client
+		.expect_get_contract_spec()
+		.times(1)
+		.returning(|_| Err(anyhow::anyhow!("RPC unavailable")));
+
+	for _ in 0..2 {
+		let matches = filter
+			.filter_block(
+				&mock_client,
+				&test_data.network,
+				&test_data.blocks[0],
+				&[monitor.clone()],
+				Some(&[contract_with_spec.clone()]),
+			)
+			.await?;
+
+		assert!(matches.is_empty(), "Stale spec should not produce matches");
+	}
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_refreshes_contract_spec_on_event_decode_failure(
+) -> Result<(), Box<FilterError>> {
+	let test_data = TestDataBuilder::new("stellar").build();
+	let filter = StellarBlockFilter::<MockStellarClientTrait<MockStellarTransportClient>>::new();
+
+	let monitor = make_monitor_with_functions(test_data.monitor, true);
+
+	let mut events: Vec<StellarEvent> =
+		read_and_parse_json("tests/integration/fixtures/stellar/events.json");
+	let transactions: Vec<StellarTransactionInfo> =
+		read_and_parse_json("tests/integration/fixtures/stellar/transactions.json");
+	let contract_spec = test_data.contract_spec.unwrap();
+
+	// The monitored contract emits an event whose value no longer decodes
+	for event in events.iter_mut() {
+		if event.contract_id == "CBIELTK6YBZJU5UP2WWQEUCYKLPU6AUNZ2BQ4WWFEIE3USCIHMXQDAMA" {
+			event.value_xdr = Some("not-base64!".to_string());
+		}
+	}
+
+	let contract_with_spec: (String, ContractSpec) = (
+		"CBIELTK6YBZJU5UP2WWQEUCYKLPU6AUNZ2BQ4WWFEIE3USCIHMXQDAMA".to_string(),
+		contract_spec.clone(),
+	);
+
+	let mut mock_client = MockStellarClientTrait::<MockStellarTransportClient>::new();
+	let decoded_transactions: Vec<StellarTransaction> = transactions
+		.iter()
+		.map(|tx| StellarTransaction::from(tx.clone()))
+		.collect();
+
+	mock_client
+		.expect_get_transactions()
+		.times(2)
+		.returning(move |_, _| Ok(decoded_transactions.clone()));
+
+	mock_client
+		.expect_get_events()
+		.times(2)
+		.returning(move |_, _, _| Ok(events.clone()));
+
+	// The spec is refreshed once, the second block is rate limited
+	mock_client
+		.expect_get_contract_spec()
+		.times(1)
+		.returning(move |_| Ok(contract_spec.clone()));
+
+	for _ in 0..2 {
+		let matches = filter
+			.filter_block(
+				&mock_client,
+				&test_data.network,
+				&test_data.blocks[0],
+				&[monitor.clone()],
+				Some(&[contract_with_spec.clone()]),
+			)
+			.await?;
+
+		assert_eq!(matches.len(), 1, "Expected exactly one match");
+	}
+
+	Ok(())
+}
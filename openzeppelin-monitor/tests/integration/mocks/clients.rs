@@ -13,8 +13,8 @@ use std::{marker::PhantomData, sync::Arc};
 
 use openzeppelin_monitor::{
 	models::{
-		BlockType, ContractSpec, EVMReceiptLog, EVMTransactionReceipt, Network, StellarEvent,
-		StellarTransaction,
+		BlockType, ContractSpec, EVMReceiptLog, EVMTransaction, EVMTransactionReceipt, Network,
+		StellarEvent, StellarEventFilter, StellarTransaction,
 	},
 	services::{
 		blockchain::{
@@ -42,6 +42,7 @@ mock! {
 	#[async_trait]
 	impl<T: Send + Sync + Clone + 'static> BlockChainClient for EvmClientTrait<T> {
 		async fn get_latest_block_number(&self) -> Result<u64, anyhow::Error>;
+		async fn get_block_number_by_tag(&self, tag: &str) -> Result<Option<u64>, anyhow::Error>;
 		async fn get_blocks(
 			&self,
 			start_block: u64,
@@ -61,7 +62,29 @@ mock! {
 			from_block: u64,
 			to_block: u64,
 			addresses: Option<Vec<String>>,
+			topics: Option<Vec<String>>,
 		) -> Result<Vec<EVMReceiptLog>,  anyhow::Error>;
+
+		async fn get_revert_reason(
+			&self,
+			transaction: &EVMTransaction,
+		) -> Result<Option<String>, anyhow::Error>;
+
+		async fn get_transaction_receipts(
+			&self,
+			transaction_hashes: Vec<String>,
+		) -> Result<Vec<EVMTransactionReceipt>, anyhow::Error>;
+
+		async fn get_transactions(
+			&self,
+			transaction_hashes: Vec<String>,
+		) -> Result<Vec<EVMTransaction>, anyhow::Error>;
+
+		async fn resolve_ens_name(
+			&self,
+			name: &str,
+			registry: &str,
+		) -> Result<Option<String>, anyhow::Error>;
 	}
 
 	impl<T: Send + Sync + Clone + 'static> Clone for EvmClientTrait<T> {
@@ -106,6 +129,7 @@ mock! {
 			&self,
 			start_sequence: u32,
 			end_sequence: Option<u32>,
+			filters: &[StellarEventFilter],
 		) -> Result<Vec<StellarEvent>, anyhow::Error>;
 
 
@@ -122,10 +146,8 @@ impl<T: Send + Sync + Clone + 'static> BlockFilterFactory<MockStellarClientTrait
 	for MockStellarClientTrait<T>
 {
 	type Filter = StellarBlockFilter<MockStellarClientTrait<T>>;
-	fn filter() -> Self::Filter {
-		StellarBlockFilter {
-			_client: PhantomData,
-		}
+	fn filter(&self) -> Self::Filter {
+		StellarBlockFilter::new()
 	}
 }
 
@@ -133,7 +155,7 @@ impl<T: Send + Sync + Clone + 'static> BlockFilterFactory<MockEvmClientTrait<T>>
 	for MockEvmClientTrait<T>
 {
 	type Filter = EVMBlockFilter<MockEvmClientTrait<T>>;
-	fn filter() -> Self::Filter {
+	fn filter(&self) -> Self::Filter {
 		EVMBlockFilter {
 			_client: PhantomData,
 		}
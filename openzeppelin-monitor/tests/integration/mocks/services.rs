@@ -7,7 +7,7 @@ use openzeppelin_monitor::{
 	repositories::{TriggerRepositoryTrait, TriggerService},
 	services::{
 		blockchain::BlockFilterFactory,
-		blockwatcher::{BlockStorage, BlockTrackerTrait, JobSchedulerTrait},
+		blockwatcher::{BlockCheckpoints, BlockStorage, BlockTrackerTrait, JobSchedulerTrait},
 		filter::FilterError,
 		notification::NotificationService,
 		trigger::{TriggerError, TriggerExecutionServiceTrait},
@@ -55,6 +55,9 @@ mock! {
 		async fn get_last_processed_block(&self, network_slug: &str) -> Result<Option<u64>, anyhow::Error>;
 		async fn save_blocks(&self, network_slug: &str, blocks: &[BlockType]) -> Result<(), anyhow::Error>;
 		async fn delete_blocks(&self, network_slug: &str) -> Result<(), anyhow::Error>;
+		async fn mark_in_progress(&self, network_slug: &str, block_number: u64) -> Result<(), anyhow::Error>;
+		async fn mark_complete(&self, network_slug: &str, block_number: u64) -> Result<(), anyhow::Error>;
+		async fn get_checkpoints(&self, network_slug: &str) -> Result<BlockCheckpoints, anyhow::Error>;
 	}
 
 	impl Clone for BlockStorage {
@@ -15,6 +15,7 @@ use openzeppelin_monitor::services::blockchain::{
 mock! {
 	pub EVMTransportClient {
 		pub async fn send_raw_request(&self, method: &str, params: Option<Vec<Value>>) -> Result<Value, TransportError>;
+		pub async fn send_batch_request(&self, method: &str, params: Vec<Value>) -> Result<Vec<Value>, TransportError>;
 		pub async fn get_current_url(&self) -> String;
 	}
 
@@ -42,6 +43,14 @@ impl BlockchainTransport for MockEVMTransportClient {
 			.await
 	}
 
+	async fn send_batch_request(
+		&self,
+		method: &str,
+		params: Vec<Value>,
+	) -> Result<Vec<Value>, TransportError> {
+		self.send_batch_request(method, params).await
+	}
+
 	fn update_endpoint_manager_client(
 		&mut self,
 		_: ClientWithMiddleware,
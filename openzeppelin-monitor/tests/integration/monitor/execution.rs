@@ -3,12 +3,15 @@ use crate::integration::{
 		setup_monitor_service, setup_network_service, setup_trigger_service, TestDataBuilder,
 	},
 	mocks::{
-		create_test_network, MockClientPool, MockEvmClientTrait, MockNetworkRepository,
-		MockStellarClientTrait, MockTriggerRepository,
+		create_test_network, MockClientPool, MockEVMTransportClient, MockEvmClientTrait,
+		MockMonitorRepository, MockNetworkRepository, MockStellarClientTrait,
+		MockTriggerRepository,
 	},
 };
+use actix_web::{test as actix_test, web, App};
 use mockall::predicate;
 use openzeppelin_monitor::{
+	models::SecretString,
 	models::{
 		BlockChainType, EVMTransactionReceipt, Monitor, ScriptLanguage, Trigger, TriggerConditions,
 	},
@@ -20,7 +23,8 @@ use openzeppelin_monitor::{
 		filter::FilterService, notification::NotificationService, trigger::TriggerExecutionService,
 	},
 	utils::{
-		monitor::execution::{execute_monitor, MonitorExecutionConfig},
+		metrics::server::{evaluate_handler, EvaluateApiState},
+		monitor::execution::{execute_monitor, BlockSelector, MonitorExecutionConfig},
 		tests::builders::{evm::monitor::MonitorBuilder, trigger::TriggerBuilder},
 	},
 };
@@ -167,7 +171,7 @@ async fn test_execute_monitor_evm() {
 
 	mock_client
 		.expect_get_logs_for_blocks()
-		.return_once(move |_, _, _| {
+		.return_once(move |_, _, _, _| {
 			Ok(test_data
 				.receipts
 				.clone()
@@ -210,7 +214,7 @@ async fn test_execute_monitor_evm() {
 	let result = execute_monitor(MonitorExecutionConfig {
 		path: test_data.monitor.name.clone(),
 		network_slug: Some("ethereum_mainnet".to_string()),
-		block_number: Some(block_number),
+		block: Some(BlockSelector::Number(block_number)),
 		monitor_service: Arc::new(Mutex::new(mock_monitor_service)),
 		network_service: Arc::new(Mutex::new(mock_network_service)),
 		filter_service: Arc::new(FilterService::new()),
@@ -230,6 +234,139 @@ async fn test_execute_monitor_evm() {
 	assert!(matches.len() == 1);
 }
 
+#[actix_web::test]
+async fn test_evaluate_endpoint_returns_matches() {
+	let test_data = TestDataBuilder::new("evm").build();
+	let receipts = test_data.receipts.clone();
+	let mut mocked_monitors = HashMap::new();
+	mocked_monitors.insert("monitor".to_string(), test_data.monitor.clone());
+	let mock_monitor_service = setup_monitor_service(mocked_monitors);
+	let mock_network_service =
+		setup_mocked_network_service("Ethereum", "ethereum_mainnet", BlockChainType::EVM);
+
+	let mut mock_pool = MockClientPool::new();
+	let mut mock_client = MockEvmClientTrait::new();
+
+	mock_client
+		.expect_get_blocks()
+		.with(predicate::eq(21305050u64), predicate::eq(None))
+		.return_once(move |_, _| Ok(test_data.blocks.clone()));
+
+	mock_client
+		.expect_get_logs_for_blocks()
+		.return_once(move |_, _, _, _| {
+			Ok(test_data
+				.receipts
+				.clone()
+				.into_iter()
+				.flat_map(|r| r.logs.clone())
+				.collect())
+		});
+
+	let receipt_map: HashMap<String, EVMTransactionReceipt> = receipts
+		.iter()
+		.map(|r| (format!("0x{:x}", r.transaction_hash), r.clone()))
+		.collect();
+	let receipt_map = Arc::new(receipt_map);
+	mock_client
+		.expect_get_transaction_receipt()
+		.returning(move |hash| {
+			let receipt_map = Arc::clone(&receipt_map);
+			Ok(receipt_map
+				.get(&hash)
+				.cloned()
+				.unwrap_or_else(|| panic!("Receipt not found for hash: {}", hash)))
+		});
+
+	let mock_client = Arc::new(mock_client);
+	mock_pool
+		.expect_get_evm_client()
+		.return_once(move |_| Ok(mock_client));
+
+	let state = EvaluateApiState {
+		token: SecretString::new("test-token".to_string()),
+		monitor_service: Arc::new(Mutex::new(mock_monitor_service)),
+		network_service: Arc::new(Mutex::new(mock_network_service)),
+		filter_service: Arc::new(FilterService::new()),
+		client_pool: Arc::new(mock_pool),
+	};
+
+	let app = actix_test::init_service(App::new().app_data(web::Data::new(state)).route(
+		"/evaluate",
+		web::post().to(evaluate_handler::<
+			MockMonitorRepository<MockNetworkRepository, MockTriggerRepository>,
+			MockNetworkRepository,
+			MockTriggerRepository,
+			MockClientPool,
+		>),
+	))
+	.await;
+
+	let req = actix_test::TestRequest::post()
+		.uri("/evaluate")
+		.insert_header(("Authorization", "Bearer test-token"))
+		.set_json(serde_json::json!({
+			"monitor": test_data.monitor.name,
+			"network": "ethereum_mainnet",
+			"block": 21305050,
+		}))
+		.to_request();
+	let resp = actix_test::call_service(&app, req).await;
+	assert!(resp.status().is_success());
+
+	let matches: Vec<serde_json::Value> = actix_test::read_body_json(resp).await;
+	assert_eq!(matches.len(), 1);
+}
+
+#[actix_web::test]
+async fn test_evaluate_endpoint_refuses_monitor_paths() {
+	let temp_dir = TempDir::new().unwrap();
+	let monitor_path = create_test_monitor_file(
+		temp_dir.path(),
+		"file_monitor",
+		vec![],
+		vec!["ethereum_mainnet"],
+	);
+
+	let mut mocked_monitors = HashMap::new();
+	mocked_monitors.insert(
+		"monitor".to_string(),
+		create_test_monitor("monitor", vec!["ethereum_mainnet"], false, vec![]),
+	);
+	let mock_monitor_service = setup_monitor_service(mocked_monitors);
+	let mock_network_service =
+		setup_mocked_network_service("Ethereum", "ethereum_mainnet", BlockChainType::EVM);
+
+	// Neither monitor is evaluated, so no client is requested
+	let state = EvaluateApiState {
+		token: SecretString::new("test-token".to_string()),
+		monitor_service: Arc::new(Mutex::new(mock_monitor_service)),
+		network_service: Arc::new(Mutex::new(mock_network_service)),
+		filter_service: Arc::new(FilterService::new()),
+		client_pool: Arc::new(MockClientPool::new()),
+	};
+
+	let app = actix_test::init_service(App::new().app_data(web::Data::new(state)).route(
+		"/evaluate",
+		web::post().to(evaluate_handler::<
+			MockMonitorRepository<MockNetworkRepository, MockTriggerRepository>,
+			MockNetworkRepository,
+			MockTriggerRepository,
+			MockClientPool,
+		>),
+	))
+	.await;
+
+	// The file exists, but monitors are only looked up by name among the loaded ones
+	let req = actix_test::TestRequest::post()
+		.uri("/evaluate")
+		.insert_header(("Authorization", "Bearer test-token"))
+		.set_json(serde_json::json!({ "monitor": monitor_path }))
+		.to_request();
+	let resp = actix_test::call_service(&app, req).await;
+	assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+}
+
 #[tokio::test]
 async fn test_execute_monitor_evm_wrong_network() {
 	let test_data = TestDataBuilder::new("evm").build();
@@ -266,7 +403,7 @@ async fn test_execute_monitor_evm_wrong_network() {
 	let result = execute_monitor(MonitorExecutionConfig {
 		path: test_data.monitor.name.clone(),
 		network_slug: Some("ethereum_goerli".to_string()),
-		block_number: Some(block_number),
+		block: Some(BlockSelector::Number(block_number)),
 		monitor_service: Arc::new(Mutex::new(mock_monitor_service)),
 		network_service: Arc::new(Mutex::new(mock_network_service)),
 		filter_service: Arc::new(FilterService::new()),
@@ -319,7 +456,7 @@ async fn test_execute_monitor_evm_wrong_block_number() {
 	let result = execute_monitor(MonitorExecutionConfig {
 		path: test_data.monitor.name.clone(),
 		network_slug: Some("ethereum_mainnet".to_string()),
-		block_number: Some(block_number),
+		block: Some(BlockSelector::Number(block_number)),
 		monitor_service: Arc::new(Mutex::new(mock_monitor_service)),
 		network_service: Arc::new(Mutex::new(mock_network_service)),
 		filter_service: Arc::new(FilterService::new()),
@@ -372,7 +509,7 @@ async fn test_execute_monitor_evm_failed_to_get_block_by_number() {
 	let result = execute_monitor(MonitorExecutionConfig {
 		path: test_data.monitor.name.clone(),
 		network_slug: Some("ethereum_mainnet".to_string()),
-		block_number: Some(block_number),
+		block: Some(BlockSelector::Number(block_number)),
 		monitor_service: Arc::new(Mutex::new(mock_monitor_service)),
 		network_service: Arc::new(Mutex::new(mock_network_service)),
 		filter_service: Arc::new(FilterService::new()),
@@ -417,7 +554,7 @@ async fn test_execute_monitor_evm_failed_to_get_evm_client() {
 	let result = execute_monitor(MonitorExecutionConfig {
 		path: test_data.monitor.name.clone(),
 		network_slug: Some("ethereum_mainnet".to_string()),
-		block_number: Some(block_number),
+		block: Some(BlockSelector::Number(block_number)),
 		monitor_service: Arc::new(Mutex::new(mock_monitor_service)),
 		network_service: Arc::new(Mutex::new(mock_network_service)),
 		filter_service: Arc::new(FilterService::new()),
@@ -462,7 +599,7 @@ async fn test_execute_monitor_stellar() {
 		.return_once(move |_, _| Ok(test_data.stellar_transactions.clone()));
 	mock_client
 		.expect_get_events()
-		.return_once(move |_, _| Ok(test_data.stellar_events.clone()));
+		.return_once(move |_, _, _| Ok(test_data.stellar_events.clone()));
 	mock_client
 		.expect_get_contract_spec()
 		.returning(move |_| Ok(test_data.contract_spec.clone().unwrap()));
@@ -481,7 +618,7 @@ async fn test_execute_monitor_stellar() {
 	let result = execute_monitor(MonitorExecutionConfig {
 		path: test_data.monitor.name.clone(),
 		network_slug: Some("stellar_testnet".to_string()),
-		block_number: Some(block_number),
+		block: Some(BlockSelector::Number(block_number)),
 		monitor_service: Arc::new(Mutex::new(mock_monitor_service)),
 		network_service: Arc::new(Mutex::new(mock_network_service)),
 		filter_service: Arc::new(FilterService::new()),
@@ -546,7 +683,7 @@ async fn test_execute_monitor_failed_to_get_block() {
 	let result = execute_monitor(MonitorExecutionConfig {
 		path: test_data.monitor.name.clone(),
 		network_slug: Some("stellar_testnet".to_string()),
-		block_number: Some(block_number),
+		block: Some(BlockSelector::Number(block_number)),
 		monitor_service: Arc::new(Mutex::new(mock_monitor_service)),
 		network_service: Arc::new(Mutex::new(mock_network_service)),
 		filter_service: Arc::new(FilterService::new()),
@@ -591,7 +728,7 @@ async fn test_execute_monitor_failed_to_get_stellar_client() {
 	let result = execute_monitor(MonitorExecutionConfig {
 		path: test_data.monitor.name.clone(),
 		network_slug: Some("stellar_testnet".to_string()),
-		block_number: Some(block_number),
+		block: Some(BlockSelector::Number(block_number)),
 		monitor_service: Arc::new(Mutex::new(mock_monitor_service)),
 		network_service: Arc::new(Mutex::new(mock_network_service)),
 		filter_service: Arc::new(FilterService::new()),
@@ -649,7 +786,7 @@ async fn test_execute_monitor_failed_to_get_block_by_number() {
 	let result = execute_monitor(MonitorExecutionConfig {
 		path: test_data.monitor.name.clone(),
 		network_slug: Some("stellar_testnet".to_string()),
-		block_number: Some(block_number),
+		block: Some(BlockSelector::Number(block_number)),
 		monitor_service: Arc::new(Mutex::new(mock_monitor_service)),
 		network_service: Arc::new(Mutex::new(mock_network_service)),
 		filter_service: Arc::new(FilterService::new()),
@@ -700,7 +837,7 @@ async fn test_execute_monitor_get_latest_block_number_failed() {
 	let result = execute_monitor(MonitorExecutionConfig {
 		path: test_data.monitor.name.clone(),
 		network_slug: Some("ethereum_mainnet".to_string()),
-		block_number: None,
+		block: None,
 		monitor_service: Arc::new(Mutex::new(mock_monitor_service)),
 		network_service: Arc::new(Mutex::new(mock_network_service)),
 		filter_service: Arc::new(FilterService::new()),
@@ -743,7 +880,7 @@ async fn test_execute_monitor_network_slug_not_defined() {
 
 	mock_client
 		.expect_get_logs_for_blocks()
-		.return_once(move |_, _, _| Ok(vec![]));
+		.return_once(move |_, _, _, _| Ok(vec![]));
 
 	let receipt_map: std::collections::HashMap<String, EVMTransactionReceipt> = receipts
 		.iter()
@@ -777,7 +914,7 @@ async fn test_execute_monitor_network_slug_not_defined() {
 	let result = execute_monitor(MonitorExecutionConfig {
 		path: test_data.monitor.name.clone(),
 		network_slug: None,
-		block_number: None,
+		block: None,
 		monitor_service: Arc::new(Mutex::new(mock_monitor_service)),
 		network_service: Arc::new(Mutex::new(mock_network_service)),
 		filter_service: Arc::new(FilterService::new()),
@@ -817,7 +954,7 @@ async fn test_execute_monitor_midnight() {
 	let result = execute_monitor(MonitorExecutionConfig {
 		path: test_data.monitor.name.clone(),
 		network_slug: Some("midnight_mainnet".to_string()),
-		block_number: None,
+		block: None,
 		monitor_service: Arc::new(Mutex::new(mock_monitor_service)),
 		network_service: Arc::new(Mutex::new(mock_network_service)),
 		filter_service: Arc::new(FilterService::new()),
@@ -857,7 +994,7 @@ async fn test_execute_monitor_solana() {
 	let result = execute_monitor(MonitorExecutionConfig {
 		path: test_data.monitor.name.clone(),
 		network_slug: Some("solana_mainnet".to_string()),
-		block_number: None,
+		block: None,
 		monitor_service: Arc::new(Mutex::new(mock_monitor_service)),
 		network_service: Arc::new(Mutex::new(mock_network_service)),
 		filter_service: Arc::new(FilterService::new()),
@@ -913,7 +1050,7 @@ async fn test_execute_monitor_stellar_get_latest_block_number_failed() {
 	let result = execute_monitor(MonitorExecutionConfig {
 		path: test_data.monitor.name.clone(),
 		network_slug: Some("stellar_mainnet".to_string()),
-		block_number: None,
+		block: None,
 		monitor_service: Arc::new(Mutex::new(mock_monitor_service)),
 		network_service: Arc::new(Mutex::new(mock_network_service)),
 		filter_service: Arc::new(FilterService::new()),
@@ -934,6 +1071,8 @@ async fn test_execute_monitor_evm_with_trigger_scripts() {
 		script_path: "./examples/config/filters/evm_large_transfer_usdc.py".to_string(),
 		language: ScriptLanguage::Python,
 		timeout_ms: 10000,
+		script_checksum: None,
+		retry_policy: None,
 		arguments: None,
 	}];
 	mocked_monitors.insert("monitor".to_string(), test_data.monitor.clone());
@@ -951,7 +1090,7 @@ async fn test_execute_monitor_evm_with_trigger_scripts() {
 
 	mock_client
 		.expect_get_logs_for_blocks()
-		.return_once(move |_, _, _| {
+		.return_once(move |_, _, _, _| {
 			Ok(test_data
 				.receipts
 				.clone()
@@ -1000,7 +1139,7 @@ async fn test_execute_monitor_evm_with_trigger_scripts() {
 	let result = execute_monitor(MonitorExecutionConfig {
 		path: test_data.monitor.name.clone(),
 		network_slug: Some("ethereum_mainnet".to_string()),
-		block_number: Some(block_number),
+		block: Some(BlockSelector::Number(block_number)),
 		monitor_service: Arc::new(Mutex::new(mock_monitor_service)),
 		network_service: Arc::new(Mutex::new(mock_network_service)),
 		filter_service: Arc::new(FilterService::new()),
@@ -1054,7 +1193,7 @@ async fn test_load_from_path() {
 	let monitor = result.unwrap();
 	assert_eq!(monitor.name, "monitor");
 	assert!(monitor.networks.contains(&"ethereum_mainnet".to_string()));
-	assert!(monitor.triggers.contains(&"test-trigger".to_string()));
+	assert!(monitor.triggers.contains(&"test-trigger".into()));
 }
 
 #[tokio::test]
@@ -1100,7 +1239,7 @@ async fn test_load_from_path_with_services() {
 	let monitor = result.unwrap();
 	assert_eq!(monitor.name, "monitor");
 	assert!(monitor.networks.contains(&"ethereum_mainnet".to_string()));
-	assert!(monitor.triggers.contains(&"test-trigger".to_string()));
+	assert!(monitor.triggers.contains(&"test-trigger".into()));
 }
 
 #[tokio::test]
@@ -1152,7 +1291,7 @@ async fn test_load_from_path_trait_implementation() {
 	let monitor = result.unwrap();
 	assert_eq!(monitor.name, "monitor");
 	assert!(monitor.networks.contains(&"ethereum_mainnet".to_string()));
-	assert!(monitor.triggers.contains(&"test-trigger".to_string()));
+	assert!(monitor.triggers.contains(&"test-trigger".into()));
 }
 
 #[tokio::test]
@@ -1284,3 +1423,101 @@ async fn test_load_from_path_with_mixed_services() {
 	std::fs::remove_file(trigger_path).unwrap();
 	std::fs::remove_file(monitor_path).unwrap();
 }
+
+#[tokio::test]
+async fn test_block_selector_resolve() {
+	let mut mock_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	mock_client
+		.expect_get_latest_block_number()
+		.returning(|| Ok(100u64));
+	let network = create_test_network("Ethereum", "ethereum_mainnet", BlockChainType::EVM);
+
+	let resolve = |selector: &str| {
+		let selector = selector.parse::<BlockSelector>().unwrap();
+		let mock_client = &mock_client;
+		let network = &network;
+		async move { selector.resolve(mock_client, network).await }
+	};
+
+	assert_eq!(resolve("42").await.unwrap(), 42);
+	assert_eq!(resolve("latest").await.unwrap(), 100);
+	assert_eq!(resolve("latest-5").await.unwrap(), 95);
+	// The test network requires a single confirmation
+	assert_eq!(resolve("finalized").await.unwrap(), 99);
+
+	let result = resolve("latest-101").await;
+	assert!(result.is_err());
+	assert!(result
+		.unwrap_err()
+		.to_string()
+		.contains("is before the first block"));
+}
+
+#[tokio::test]
+async fn test_execute_monitor_with_relative_block() {
+	let test_data = TestDataBuilder::new("evm").build();
+	let mut mocked_monitors = HashMap::new();
+	mocked_monitors.insert("monitor".to_string(), test_data.monitor.clone());
+	let mock_monitor_service = setup_monitor_service(mocked_monitors);
+
+	let trigger_service = setup_trigger_service(HashMap::new());
+	let notification_service = NotificationService::new();
+	let trigger_execution_service =
+		TriggerExecutionService::new(trigger_service, notification_service);
+
+	let mut mock_pool = MockClientPool::new();
+	let mock_network_service =
+		setup_mocked_network_service("Ethereum", "ethereum_mainnet", BlockChainType::EVM);
+	let mut mock_client = MockEvmClientTrait::new();
+
+	mock_client
+		.expect_get_latest_block_number()
+		.times(1)
+		.returning(|| Ok(100u64));
+
+	// The block 5 blocks before the latest one is fetched
+	mock_client
+		.expect_get_blocks()
+		.with(predicate::eq(95u64), predicate::eq(None))
+		.times(1)
+		.return_once(move |_, _| Ok(test_data.blocks.clone()));
+
+	mock_client
+		.expect_get_logs_for_blocks()
+		.return_once(move |_, _, _, _| Ok(vec![]));
+
+	let receipt_map: HashMap<String, EVMTransactionReceipt> = test_data
+		.receipts
+		.iter()
+		.map(|r| (format!("0x{:x}", r.transaction_hash), r.clone()))
+		.collect();
+	mock_client
+		.expect_get_transaction_receipt()
+		.returning(move |hash| {
+			Ok(receipt_map
+				.get(&hash)
+				.cloned()
+				.unwrap_or_else(|| panic!("Receipt not found for hash: {}", hash)))
+		});
+
+	let mock_client = Arc::new(mock_client);
+	mock_pool
+		.expect_get_evm_client()
+		.times(1)
+		.returning(move |_| Ok(mock_client.clone()));
+
+	let result = execute_monitor(MonitorExecutionConfig {
+		path: test_data.monitor.name.clone(),
+		network_slug: Some("ethereum_mainnet".to_string()),
+		block: Some("latest-5".parse().unwrap()),
+		monitor_service: Arc::new(Mutex::new(mock_monitor_service)),
+		network_service: Arc::new(Mutex::new(mock_network_service)),
+		filter_service: Arc::new(FilterService::new()),
+		trigger_execution_service: Arc::new(trigger_execution_service),
+		active_monitors_trigger_scripts: HashMap::new(),
+		client_pool: Arc::new(mock_pool),
+	})
+	.await;
+
+	assert!(result.is_ok());
+}
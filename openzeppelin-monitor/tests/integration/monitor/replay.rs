@@ -0,0 +1,203 @@
+use crate::integration::{
+	filters::common::{setup_trigger_service, TestData, TestDataBuilder},
+	mocks::{create_test_network, MockClientPool, MockEVMTransportClient, MockEvmClientTrait},
+};
+use mockall::{predicate, Sequence};
+use openzeppelin_monitor::{
+	models::{BlockChainType, EVMTransactionReceipt, ProcessedBlock},
+	services::{
+		filter::FilterService, notification::NotificationService, trigger::TriggerExecutionService,
+	},
+	utils::monitor::{
+		execution::ExecutionResult,
+		replay::{replay_blocks, ReplayConfig},
+	},
+};
+use std::{collections::HashMap, sync::Arc};
+
+/// Mocks an EVM client returning the receipts and the logs of the requested blocks of the EVM
+/// test data
+///
+/// Only the first block of the EVM test data has all of its receipts in the fixtures.
+fn setup_mocked_evm_client(test_data: &TestData) -> MockEvmClientTrait<MockEVMTransportClient> {
+	let mut mock_client = MockEvmClientTrait::new();
+
+	let logs = test_data
+		.receipts
+		.iter()
+		.flat_map(|r| r.logs.clone())
+		.collect::<Vec<_>>();
+	mock_client
+		.expect_get_logs_for_blocks()
+		.returning(move |from_block, to_block, _, _| {
+			Ok(logs
+				.iter()
+				.filter(|log| {
+					log.block_number
+						.is_some_and(|number| (from_block..=to_block).contains(&number.to::<u64>()))
+				})
+				.cloned()
+				.collect())
+		});
+
+	let receipt_map: HashMap<String, EVMTransactionReceipt> = test_data
+		.receipts
+		.iter()
+		.map(|r| (format!("0x{:x}", r.transaction_hash), r.clone()))
+		.collect();
+	mock_client
+		.expect_get_transaction_receipt()
+		.returning(move |hash| {
+			Ok(receipt_map
+				.get(&hash)
+				.cloned()
+				.unwrap_or_else(|| panic!("Receipt not found for hash: {}", hash)))
+		});
+
+	mock_client
+}
+
+async fn replay(
+	test_data: &TestData,
+	mock_client: MockEvmClientTrait<MockEVMTransportClient>,
+	from_block: u64,
+	to_block: u64,
+	fire_triggers: bool,
+) -> ExecutionResult<Vec<ProcessedBlock>> {
+	let mock_client = Arc::new(mock_client);
+	let mut mock_pool = MockClientPool::new();
+	mock_pool
+		.expect_get_evm_client()
+		.returning(move |_| Ok(mock_client.clone()));
+
+	let trigger_execution_service = TriggerExecutionService::new(
+		setup_trigger_service(HashMap::new()),
+		NotificationService::new(),
+	);
+
+	replay_blocks(ReplayConfig {
+		network: create_test_network("Ethereum", "ethereum_mainnet", BlockChainType::EVM),
+		from_block,
+		to_block,
+		monitors: vec![test_data.monitor.clone()],
+		filter_service: Arc::new(FilterService::new()),
+		trigger_execution_service: Arc::new(trigger_execution_service),
+		active_monitors_trigger_scripts: HashMap::new(),
+		fire_triggers,
+		client_pool: Arc::new(mock_pool),
+	})
+	.await
+}
+
+fn count_matches(processed_blocks: &[ProcessedBlock]) -> usize {
+	processed_blocks
+		.iter()
+		.map(|block| block.processing_results.len())
+		.sum()
+}
+
+#[tokio::test]
+async fn test_replay_blocks_evm() {
+	let test_data = TestDataBuilder::new("evm").build();
+	let mut mock_client = setup_mocked_evm_client(&test_data);
+
+	let blocks = vec![test_data.blocks[0].clone()];
+	mock_client
+		.expect_get_blocks()
+		.with(predicate::eq(21305050u64), predicate::eq(Some(21305054u64)))
+		.times(1)
+		.returning(move |_, _| Ok(blocks.clone()));
+
+	let result = replay(&test_data, mock_client, 21305050, 21305054, false).await;
+	assert!(result.is_ok(), "Replay failed: {:?}", result.err());
+
+	let processed_blocks = result.unwrap();
+	assert_eq!(processed_blocks.len(), 1);
+	assert_eq!(count_matches(&processed_blocks), 1);
+	assert_eq!(processed_blocks[0].network_slug, "ethereum_mainnet");
+}
+
+#[tokio::test]
+async fn test_replay_blocks_fetches_range_in_batches() {
+	let test_data = TestDataBuilder::new("evm").build();
+	let mut mock_client = setup_mocked_evm_client(&test_data);
+	let mut seq = Sequence::new();
+
+	let blocks = vec![test_data.blocks[0].clone()];
+	mock_client
+		.expect_get_blocks()
+		.with(predicate::eq(1000u64), predicate::eq(Some(1099u64)))
+		.times(1)
+		.in_sequence(&mut seq)
+		.returning(move |_, _| Ok(blocks.clone()));
+	mock_client
+		.expect_get_blocks()
+		.with(predicate::eq(1100u64), predicate::eq(Some(1149u64)))
+		.times(1)
+		.in_sequence(&mut seq)
+		.returning(|_, _| Ok(vec![]));
+
+	let result = replay(&test_data, mock_client, 1000, 1149, false).await;
+	assert!(result.is_ok(), "Replay failed: {:?}", result.err());
+	assert_eq!(count_matches(&result.unwrap()), 1);
+}
+
+#[tokio::test]
+async fn test_replay_blocks_with_fire_triggers() {
+	let test_data = TestDataBuilder::new("evm").build();
+	let mut mock_client = setup_mocked_evm_client(&test_data);
+
+	let blocks = vec![test_data.blocks[0].clone()];
+	mock_client
+		.expect_get_blocks()
+		.returning(move |_, _| Ok(blocks.clone()));
+
+	let result = replay(&test_data, mock_client, 21305050, 21305054, true).await;
+	assert!(result.is_ok(), "Replay failed: {:?}", result.err());
+	assert_eq!(count_matches(&result.unwrap()), 1);
+}
+
+#[tokio::test]
+async fn test_replay_blocks_invalid_range() {
+	let test_data = TestDataBuilder::new("evm").build();
+	let mut mock_client = MockEvmClientTrait::new();
+	mock_client.expect_get_blocks().never();
+
+	let result = replay(&test_data, mock_client, 21305054, 21305050, false).await;
+	assert!(result.is_err());
+	assert!(result
+		.unwrap_err()
+		.to_string()
+		.contains("Replay start block 21305054 is after end block 21305050"));
+}
+
+#[tokio::test]
+async fn test_replay_blocks_without_monitors_for_network() {
+	let mut test_data = TestDataBuilder::new("evm").build();
+	test_data.monitor.networks = vec!["ethereum_sepolia".to_string()];
+	let mut mock_client = MockEvmClientTrait::new();
+	mock_client.expect_get_blocks().never();
+
+	let result = replay(&test_data, mock_client, 21305050, 21305054, false).await;
+	assert!(result.is_err());
+	assert!(result
+		.unwrap_err()
+		.to_string()
+		.contains("No monitors found for network 'ethereum_mainnet'"));
+}
+
+#[tokio::test]
+async fn test_replay_blocks_failed_to_get_blocks() {
+	let test_data = TestDataBuilder::new("evm").build();
+	let mut mock_client = MockEvmClientTrait::new();
+	mock_client
+		.expect_get_blocks()
+		.returning(|_, _| Err(anyhow::anyhow!("RPC error")));
+
+	let result = replay(&test_data, mock_client, 21305050, 21305054, false).await;
+	assert!(result.is_err());
+	assert!(result
+		.unwrap_err()
+		.to_string()
+		.contains("Failed to get blocks 21305050 to 21305054"));
+}
@@ -6,8 +6,8 @@ use std::collections::HashMap;
 
 use openzeppelin_monitor::{
 	models::{
-		EVMMonitorMatch, MatchConditions, Monitor, MonitorMatch, NotificationMessage, SecretString,
-		SecretValue, TriggerType, TriggerTypeConfig,
+		EVMMonitorMatch, EmailContentType, MatchConditions, Monitor, MonitorMatch,
+		NotificationMessage, SecretString, SecretValue, TriggerType, TriggerTypeConfig,
 	},
 	services::notification::{
 		EmailContent, EmailNotifier, NotificationError, NotificationService, SmtpConfig,
@@ -39,10 +39,13 @@ fn create_test_evm_match(monitor: Monitor) -> MonitorMatch {
 		monitor,
 		transaction,
 		receipt: Some(create_test_evm_transaction_receipt()),
+		revert_reason: None,
 		logs: Some(create_test_evm_logs()),
+		block_timestamp: None,
 		network_slug: "ethereum_mainnet".to_string(),
 		matched_on: MatchConditions::default(),
 		matched_on_args: None,
+		enrichment: HashMap::new(),
 	}))
 }
 
@@ -78,6 +81,11 @@ async fn test_email_notification_success() {
 		body_template: "Test message".to_string(),
 		sender: EmailAddress::new_unchecked("sender@test.com"),
 		recipients: vec![EmailAddress::new_unchecked("recipient@test.com")],
+		cc: vec![],
+		bcc: vec![],
+		reply_to: None,
+		content_type: EmailContentType::default(),
+		attachments: vec![],
 	};
 
 	let stub_transport = AsyncStubTransport::new_ok();
@@ -96,6 +104,11 @@ async fn test_email_notification_failure_after_retries() {
 		body_template: "Test message".to_string(),
 		sender: EmailAddress::new_unchecked("sender@test.com"),
 		recipients: vec![EmailAddress::new_unchecked("recipient@test.com")],
+		cc: vec![],
+		bcc: vec![],
+		reply_to: None,
+		content_type: EmailContentType::default(),
+		attachments: vec![],
 	};
 
 	let stub_transport = AsyncStubTransport::new_error();
@@ -138,6 +151,11 @@ async fn test_notification_service_email_execution_failure() {
 		},
 		sender: "sender@example.com".parse().unwrap(),
 		recipients: vec!["recipient@example.com".parse().unwrap()],
+		cc: vec![],
+		bcc: vec![],
+		reply_to: None,
+		content_type: EmailContentType::default(),
+		attachments: None,
 		retry_policy: RetryConfig::default(),
 	};
 
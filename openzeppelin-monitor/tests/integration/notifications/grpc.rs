@@ -0,0 +1,293 @@
+use openzeppelin_monitor::{
+	models::{
+		EVMMonitorMatch, MatchConditions, Monitor, MonitorMatch, TriggerType, TriggerTypeConfig,
+	},
+	services::notification::{
+		GrpcNotification, GrpcNotificationAck, NotificationError, NotificationService,
+		GRPC_NOTIFY_PATH,
+	},
+	utils::{
+		logging::error::ErrorCode,
+		tests::{
+			evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+			trigger::TriggerBuilder,
+		},
+		RetryConfig,
+	},
+};
+use std::{
+	collections::HashMap,
+	convert::Infallible,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+use tokio::{net::TcpListener, sync::Mutex};
+use tonic::{
+	body::Body,
+	codec::ProstCodec,
+	codegen::{http, BoxFuture, Context, Poll, Service},
+	server::{Grpc, NamedService},
+	transport::{server::TcpIncoming, Server},
+	Status,
+};
+
+use crate::integration::mocks::{create_test_evm_logs, create_test_evm_transaction_receipt};
+
+/// A notification received by the mock gRPC server
+#[derive(Debug, Clone)]
+struct ReceivedNotification {
+	notification: GrpcNotification,
+	metadata: HashMap<String, String>,
+}
+
+/// Minimal implementation of the `NotificationService` of `proto/notification.proto`,
+/// rejecting the first `failures` calls as unavailable.
+#[derive(Clone)]
+struct MockNotificationService {
+	received: Arc<Mutex<Vec<ReceivedNotification>>>,
+	failures: Arc<AtomicUsize>,
+}
+
+impl NamedService for MockNotificationService {
+	const NAME: &'static str = "openzeppelin.monitor.v1.NotificationService";
+}
+
+impl Service<http::Request<Body>> for MockNotificationService {
+	type Response = http::Response<Body>;
+	type Error = Infallible;
+	type Future = BoxFuture<Self::Response, Self::Error>;
+
+	fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+		Poll::Ready(Ok(()))
+	}
+
+	fn call(&mut self, request: http::Request<Body>) -> Self::Future {
+		let service = self.clone();
+		Box::pin(async move {
+			if request.uri().path() != GRPC_NOTIFY_PATH {
+				return Ok(Status::unimplemented("unknown method").into_http());
+			}
+			let mut grpc =
+				Grpc::new(ProstCodec::<GrpcNotificationAck, GrpcNotification>::default());
+			Ok(grpc.unary(NotifyMethod(service), request).await)
+		})
+	}
+}
+
+/// Handler of the `Notify` method
+struct NotifyMethod(MockNotificationService);
+
+impl Service<tonic::Request<GrpcNotification>> for NotifyMethod {
+	type Response = tonic::Response<GrpcNotificationAck>;
+	type Error = Status;
+	type Future = BoxFuture<Self::Response, Self::Error>;
+
+	fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+		Poll::Ready(Ok(()))
+	}
+
+	fn call(&mut self, request: tonic::Request<GrpcNotification>) -> Self::Future {
+		let service = self.0.clone();
+		Box::pin(async move {
+			let remaining_failures =
+				service
+					.failures
+					.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |f| f.checked_sub(1));
+			if remaining_failures.is_ok() {
+				return Err(Status::unavailable("service temporarily unavailable"));
+			}
+
+			let metadata = request
+				.metadata()
+				.iter()
+				.filter_map(|entry| match entry {
+					tonic::metadata::KeyAndValueRef::Ascii(key, value) => Some((
+						key.as_str().to_string(),
+						value.to_str().unwrap_or_default().to_string(),
+					)),
+					tonic::metadata::KeyAndValueRef::Binary(..) => None,
+				})
+				.collect();
+			service.received.lock().await.push(ReceivedNotification {
+				notification: request.into_inner(),
+				metadata,
+			});
+			Ok(tonic::Response::new(GrpcNotificationAck {}))
+		})
+	}
+}
+
+/// Starts the mock gRPC server on a random local port
+async fn start_mock_grpc_server(
+	failures: usize,
+) -> (String, Arc<Mutex<Vec<ReceivedNotification>>>) {
+	let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+	let port = listener.local_addr().unwrap().port();
+	let service = MockNotificationService {
+		received: Arc::new(Mutex::new(Vec::new())),
+		failures: Arc::new(AtomicUsize::new(failures)),
+	};
+	let received = service.received.clone();
+
+	tokio::spawn(
+		Server::builder()
+			.add_service(service)
+			.serve_with_incoming(TcpIncoming::from(listener)),
+	);
+
+	(format!("http://127.0.0.1:{}", port), received)
+}
+
+fn create_test_monitor(name: &str) -> Monitor {
+	MonitorBuilder::new()
+		.name(name)
+		.networks(vec!["ethereum_mainnet".to_string()])
+		.paused(false)
+		.triggers(vec!["test_trigger".to_string()])
+		.build()
+}
+
+fn create_test_evm_match(monitor: Monitor) -> MonitorMatch {
+	MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+		monitor,
+		transaction: TransactionBuilder::new().build(),
+		receipt: Some(create_test_evm_transaction_receipt()),
+		revert_reason: None,
+		logs: Some(create_test_evm_logs()),
+		block_timestamp: None,
+		network_slug: "ethereum_mainnet".to_string(),
+		matched_on: MatchConditions::default(),
+		matched_on_args: None,
+		enrichment: HashMap::new(),
+	}))
+}
+
+#[tokio::test]
+async fn test_notification_service_grpc_execution() {
+	let notification_service = NotificationService::new();
+	let (endpoint, received) = start_mock_grpc_server(0).await;
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.grpc(&endpoint)
+		.grpc_metadata("x-api-key", "secret")
+		.message("Alert for ${monitor.name}", "Value is ${value}")
+		.build();
+
+	let variables = HashMap::from([
+		("monitor.name".to_string(), "test_monitor".to_string()),
+		("value".to_string(), "42".to_string()),
+	]);
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+
+	let result = notification_service
+		.execute(&trigger, &variables, &monitor_match, &HashMap::new())
+		.await;
+
+	assert!(result.is_ok(), "{:?}", result);
+	let received = received.lock().await;
+	assert_eq!(received.len(), 1);
+	let notification = &received[0].notification;
+	assert_eq!(notification.monitor, "test_monitor");
+	assert_eq!(notification.match_id, monitor_match.match_id());
+	assert_eq!(notification.title, "Alert for test_monitor");
+	assert_eq!(notification.body, "Value is 42");
+	assert_eq!(
+		serde_json::from_str::<serde_json::Value>(&notification.monitor_match).unwrap(),
+		serde_json::to_value(&monitor_match).unwrap()
+	);
+	assert_eq!(
+		received[0].metadata.get("x-api-key").map(String::as_str),
+		Some("secret")
+	);
+}
+
+#[tokio::test]
+async fn test_notification_service_grpc_execution_retries_unavailable() {
+	let notification_service = NotificationService::new();
+	let (endpoint, received) = start_mock_grpc_server(1).await;
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.grpc(&endpoint)
+		.build();
+
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+
+	let result = notification_service
+		.execute(&trigger, &HashMap::new(), &monitor_match, &HashMap::new())
+		.await;
+
+	assert!(result.is_ok(), "{:?}", result);
+	let received = received.lock().await;
+	assert_eq!(received.len(), 1);
+	assert!(received[0].notification.title.is_empty());
+	assert!(received[0].notification.body.is_empty());
+}
+
+#[tokio::test]
+async fn test_notification_service_grpc_execution_unreachable_server() {
+	let notification_service = NotificationService::new();
+
+	// Reserve a port and release it so nothing is listening on it
+	let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+	let port = listener.local_addr().unwrap().port();
+	drop(listener);
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.trigger_type(TriggerType::Grpc)
+		.config(TriggerTypeConfig::Grpc {
+			endpoint: format!("http://127.0.0.1:{}", port),
+			metadata: HashMap::new(),
+			message: None,
+			timeout_ms: 1000,
+			retry_policy: RetryConfig {
+				max_retries: 0,
+				..RetryConfig::default()
+			},
+		})
+		.build();
+
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+
+	let result = tokio::time::timeout(
+		Duration::from_secs(15),
+		notification_service.execute(&trigger, &HashMap::new(), &monitor_match, &HashMap::new()),
+	)
+	.await
+	.expect("gRPC notification should fail instead of hanging");
+
+	assert!(matches!(result, Err(NotificationError::NotifyFailed(_))));
+}
+
+#[tokio::test]
+async fn test_notification_service_grpc_execution_invalid_endpoint() {
+	let notification_service = NotificationService::new();
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.trigger_type(TriggerType::Grpc)
+		.config(TriggerTypeConfig::Grpc {
+			endpoint: "http://invalid host:50051".to_string(),
+			metadata: HashMap::new(),
+			message: None,
+			timeout_ms: 1000,
+			retry_policy: RetryConfig::default(),
+		})
+		.build();
+
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+
+	let error = notification_service
+		.execute(&trigger, &HashMap::new(), &monitor_match, &HashMap::new())
+		.await
+		.unwrap_err();
+
+	// Failing to create the pooled channel is not reported as a script failure
+	assert!(matches!(error, NotificationError::ClientPoolError(_)));
+	assert_eq!(error.code(), ErrorCode::ClientPool);
+}
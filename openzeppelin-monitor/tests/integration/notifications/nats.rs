@@ -0,0 +1,301 @@
+use openzeppelin_monitor::{
+	models::{
+		EVMMonitorMatch, MatchConditions, Monitor, MonitorMatch, TriggerType, TriggerTypeConfig,
+	},
+	services::notification::{NotificationError, NotificationService},
+	utils::{
+		logging::error::ErrorCode,
+		tests::{
+			evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+			trigger::TriggerBuilder,
+		},
+		RetryConfig,
+	},
+};
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tokio::{
+	io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+	net::TcpListener,
+	sync::Mutex,
+};
+
+use crate::integration::mocks::{create_test_evm_logs, create_test_evm_transaction_receipt};
+
+/// A message received by the mock NATS server
+#[derive(Debug, Clone)]
+struct PublishedMessage {
+	subject: String,
+	headers: Option<String>,
+	payload: String,
+}
+
+/// Starts a minimal NATS server speaking enough of the client protocol to accept
+/// connections and publishes. Publishes with a reply subject are acknowledged as
+/// JetStream publishes to `stream`.
+async fn start_mock_nats_server(stream: &str) -> (String, Arc<Mutex<Vec<PublishedMessage>>>) {
+	let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+	let port = listener.local_addr().unwrap().port();
+	let messages = Arc::new(Mutex::new(Vec::new()));
+	let stream = stream.to_string();
+
+	let messages_clone = messages.clone();
+	tokio::spawn(async move {
+		loop {
+			let Ok((socket, _)) = listener.accept().await else {
+				break;
+			};
+			let messages = messages_clone.clone();
+			let stream = stream.clone();
+			tokio::spawn(async move {
+				let (read_half, mut write_half) = socket.into_split();
+				let mut reader = BufReader::new(read_half);
+				let info = format!(
+					"INFO {{\"server_id\":\"mock\",\"server_name\":\"mock\",\"version\":\"2.10.0\",\"host\":\"127.0.0.1\",\"port\":{},\"headers\":true,\"max_payload\":1048576,\"proto\":1}}\r\n",
+					port
+				);
+				if write_half.write_all(info.as_bytes()).await.is_err() {
+					return;
+				}
+
+				let mut subscriptions: Vec<(String, String)> = Vec::new();
+				let mut line = String::new();
+				loop {
+					line.clear();
+					if reader.read_line(&mut line).await.unwrap_or(0) == 0 {
+						return;
+					}
+					let parts: Vec<&str> = line.split_whitespace().collect();
+					match parts.first().map(|op| op.to_uppercase()).as_deref() {
+						Some("PING") => {
+							let _ = write_half.write_all(b"PONG\r\n").await;
+						}
+						Some("SUB") => {
+							// SUB <subject> [queue group] <sid>
+							let subject = parts[1].to_string();
+							let sid = parts[parts.len() - 1].to_string();
+							subscriptions.push((subject, sid));
+						}
+						Some(op @ ("PUB" | "HPUB")) => {
+							// PUB <subject> [reply-to] <#bytes>
+							// HPUB <subject> [reply-to] <#header bytes> <#total bytes>
+							let subject = parts[1].to_string();
+							let (reply, header_len, total_len) = if op == "PUB" {
+								let reply = (parts.len() == 4).then(|| parts[2].to_string());
+								(reply, 0, parts[parts.len() - 1].parse::<usize>().unwrap())
+							} else {
+								let reply = (parts.len() == 5).then(|| parts[2].to_string());
+								(
+									reply,
+									parts[parts.len() - 2].parse::<usize>().unwrap(),
+									parts[parts.len() - 1].parse::<usize>().unwrap(),
+								)
+							};
+							let mut body = vec![0u8; total_len + 2];
+							if reader.read_exact(&mut body).await.is_err() {
+								return;
+							}
+							let headers = (header_len > 0)
+								.then(|| String::from_utf8_lossy(&body[..header_len]).to_string());
+							let payload =
+								String::from_utf8_lossy(&body[header_len..total_len]).to_string();
+							messages.lock().await.push(PublishedMessage {
+								subject,
+								headers,
+								payload,
+							});
+
+							// Acknowledge JetStream publishes on the reply subject
+							if let Some(reply) = reply {
+								let sid = subscriptions.iter().find_map(|(subject, sid)| {
+									let matches = match subject.strip_suffix('*') {
+										Some(prefix) => reply.starts_with(prefix),
+										None => subject == &reply,
+									};
+									matches.then(|| sid.clone())
+								});
+								if let Some(sid) = sid {
+									let ack = format!("{{\"stream\":\"{}\",\"seq\":1}}", stream);
+									let msg = format!(
+										"MSG {} {} {}\r\n{}\r\n",
+										reply,
+										sid,
+										ack.len(),
+										ack
+									);
+									let _ = write_half.write_all(msg.as_bytes()).await;
+								}
+							}
+						}
+						_ => {}
+					}
+				}
+			});
+		}
+	});
+
+	(format!("nats://127.0.0.1:{}", port), messages)
+}
+
+/// Waits until the mock server has received `count` messages
+///
+/// Core NATS publishes are fire-and-forget, so the server may still be reading the message
+/// when the publish returns.
+async fn wait_for_messages(messages: &Arc<Mutex<Vec<PublishedMessage>>>, count: usize) {
+	for _ in 0..50 {
+		if messages.lock().await.len() >= count {
+			return;
+		}
+		tokio::time::sleep(Duration::from_millis(20)).await;
+	}
+}
+
+fn create_test_monitor(name: &str) -> Monitor {
+	MonitorBuilder::new()
+		.name(name)
+		.networks(vec!["ethereum_mainnet".to_string()])
+		.paused(false)
+		.triggers(vec!["test_trigger".to_string()])
+		.build()
+}
+
+fn create_test_evm_match(monitor: Monitor) -> MonitorMatch {
+	MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+		monitor,
+		transaction: TransactionBuilder::new().build(),
+		receipt: Some(create_test_evm_transaction_receipt()),
+		revert_reason: None,
+		logs: Some(create_test_evm_logs()),
+		block_timestamp: None,
+		network_slug: "ethereum_mainnet".to_string(),
+		matched_on: MatchConditions::default(),
+		matched_on_args: None,
+		enrichment: HashMap::new(),
+	}))
+}
+
+#[tokio::test]
+async fn test_notification_service_nats_execution() {
+	let notification_service = NotificationService::new();
+	let (server_url, messages) = start_mock_nats_server("ALERTS").await;
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.nats(&server_url, "monitor.alerts")
+		.message("Alert for ${monitor.name}", "Value is ${value}")
+		.build();
+
+	let variables = HashMap::from([
+		("monitor.name".to_string(), "test_monitor".to_string()),
+		("value".to_string(), "42".to_string()),
+	]);
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+
+	let result = notification_service
+		.execute(&trigger, &variables, &monitor_match, &HashMap::new())
+		.await;
+
+	assert!(result.is_ok(), "{:?}", result);
+	wait_for_messages(&messages, 1).await;
+	let messages = messages.lock().await;
+	assert_eq!(messages.len(), 1);
+	assert_eq!(messages[0].subject, "monitor.alerts");
+	assert_eq!(
+		serde_json::from_str::<serde_json::Value>(&messages[0].payload).unwrap(),
+		serde_json::json!({"title": "Alert for test_monitor", "body": "Value is 42"})
+	);
+}
+
+#[tokio::test]
+async fn test_notification_service_nats_jetstream_execution() {
+	let notification_service = NotificationService::new();
+	let (server_url, messages) = start_mock_nats_server("ALERTS").await;
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.nats(&server_url, "monitor.alerts")
+		.nats_stream("ALERTS")
+		.build();
+
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+
+	let result = notification_service
+		.execute(&trigger, &HashMap::new(), &monitor_match, &HashMap::new())
+		.await;
+
+	assert!(result.is_ok(), "{:?}", result);
+	let messages = messages.lock().await;
+	assert_eq!(messages.len(), 1);
+	assert_eq!(messages[0].subject, "monitor.alerts");
+	assert!(messages[0]
+		.headers
+		.as_ref()
+		.is_some_and(|h| h.contains("Nats-Expected-Stream: ALERTS")));
+	assert_eq!(
+		messages[0].payload,
+		serde_json::to_string(&monitor_match).unwrap()
+	);
+}
+
+#[tokio::test]
+async fn test_notification_service_nats_execution_unreachable_server() {
+	let notification_service = NotificationService::new();
+
+	// Reserve a port and release it so nothing is listening on it
+	let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+	let port = listener.local_addr().unwrap().port();
+	drop(listener);
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.trigger_type(TriggerType::Nats)
+		.config(TriggerTypeConfig::Nats {
+			server_url: format!("nats://127.0.0.1:{}", port),
+			subject: "monitor.alerts".to_string(),
+			stream: None,
+			message: None,
+			retry_policy: RetryConfig {
+				max_retries: 0,
+				..RetryConfig::default()
+			},
+		})
+		.build();
+
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+
+	let result = tokio::time::timeout(
+		Duration::from_secs(15),
+		notification_service.execute(&trigger, &HashMap::new(), &monitor_match, &HashMap::new()),
+	)
+	.await
+	.expect("NATS publish should time out instead of hanging");
+
+	assert!(matches!(result, Err(NotificationError::NotifyFailed(_))));
+}
+
+#[tokio::test]
+async fn test_notification_service_nats_execution_invalid_server_url() {
+	let notification_service = NotificationService::new();
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.trigger_type(TriggerType::Nats)
+		.config(TriggerTypeConfig::Nats {
+			server_url: "nats://invalid host:4222".to_string(),
+			subject: "monitor.alerts".to_string(),
+			stream: None,
+			message: None,
+			retry_policy: RetryConfig::default(),
+		})
+		.build();
+
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+
+	let error = notification_service
+		.execute(&trigger, &HashMap::new(), &monitor_match, &HashMap::new())
+		.await
+		.unwrap_err();
+
+	// Failing to create the pooled client is not reported as a script failure
+	assert!(matches!(error, NotificationError::ClientPoolError(_)));
+	assert_eq!(error.code(), ErrorCode::ClientPool);
+}
@@ -1,9 +1,12 @@
 use openzeppelin_monitor::{
 	models::{EVMMonitorMatch, MatchConditions, Monitor, MonitorMatch, ScriptLanguage},
 	services::notification::{NotificationError, NotificationService},
-	utils::tests::{
-		evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
-		trigger::TriggerBuilder,
+	utils::{
+		logging::error::ErrorCode,
+		tests::{
+			evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+			trigger::TriggerBuilder,
+		},
 	},
 };
 use std::collections::HashMap;
@@ -26,10 +29,13 @@ fn create_test_evm_match(monitor: Monitor) -> MonitorMatch {
 		monitor,
 		transaction,
 		receipt: Some(create_test_evm_transaction_receipt()),
+		revert_reason: None,
 		logs: Some(create_test_evm_logs()),
+		block_timestamp: None,
 		network_slug: "ethereum_mainnet".to_string(),
 		matched_on: MatchConditions::default(),
 		matched_on_args: None,
+		enrichment: HashMap::new(),
 	}))
 }
 
@@ -121,3 +127,32 @@ async fn test_notification_service_script_execution_normalized_monitor_name() {
 		.await;
 	assert!(result.is_ok());
 }
+
+#[tokio::test]
+async fn test_notification_service_script_exit_failure_is_script_error() {
+	let notification_service = NotificationService::new();
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.script("test_script.py", ScriptLanguage::Python)
+		.script_timeout_ms(1000)
+		.build();
+
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+	let mut trigger_scripts = HashMap::new();
+	trigger_scripts.insert(
+		"test_monitor|test_script.py".to_string(),
+		(
+			ScriptLanguage::Python,
+			"import sys\nsys.exit(1)".to_string(),
+		),
+	);
+
+	let error = notification_service
+		.execute(&trigger, &HashMap::new(), &monitor_match, &trigger_scripts)
+		.await
+		.unwrap_err();
+
+	assert!(matches!(error, NotificationError::ExecutionError(_)));
+	assert_eq!(error.code(), ErrorCode::Script);
+}
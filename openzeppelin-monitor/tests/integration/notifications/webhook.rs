@@ -1,22 +1,47 @@
+use async_trait::async_trait;
 use mockito::{Matcher, Server};
 use openzeppelin_monitor::{
-	models::{EVMMonitorMatch, MatchConditions, Monitor, MonitorMatch, TriggerType},
+	models::{
+		EVMMatchArguments, EVMMatchParamEntry, EVMMatchParamsMap, EVMMonitorMatch, EventCondition,
+		MatchConditions, Monitor, MonitorMatch, Trigger, TriggerType,
+	},
+	repositories::TriggerService,
+	services::filter::{match_severity, SEVERITY_VARIABLE},
 	services::notification::{
-		GenericWebhookPayloadBuilder, NotificationError, NotificationService, WebhookConfig,
-		WebhookNotifier, WebhookPayloadBuilder,
+		verify_webhook_signature, AuditLog, AuditOutcome, AuditRecord, CircuitBreakerConfig,
+		CircuitState, DeadLetterRecord, DeadLetterStore, FileAuditLog,
+		GenericWebhookPayloadBuilder, NotificationClientPool, NotificationError,
+		NotificationService, WebhookConfig, WebhookNotifier, WebhookPayloadBuilder,
+		WebhookSignatureAlgorithm,
 	},
 	utils::{
+		default_user_agent,
+		metrics::{gather_metrics, NOTIFICATIONS_TOTAL},
 		tests::{
 			evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
 			get_http_client_from_notification_pool,
 			trigger::TriggerBuilder,
 		},
-		RetryConfig,
+		trigger::{drain_dead_letter, test_trigger, DrainSummary},
+		RetryConfig, DEFAULT_REQUEST_ID_HEADER,
+	},
+};
+use std::{
+	collections::{BTreeMap, HashMap},
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
 	},
+	time::Duration,
+};
+use tokio::{
+	io::{AsyncReadExt, AsyncWriteExt},
+	net::TcpListener,
 };
-use std::collections::HashMap;
 
-use crate::integration::mocks::{create_test_evm_logs, create_test_evm_transaction_receipt};
+use crate::integration::mocks::{
+	create_test_evm_logs, create_test_evm_transaction_receipt, MockTriggerRepository,
+};
 
 fn create_test_monitor(name: &str) -> Monitor {
 	MonitorBuilder::new()
@@ -34,17 +59,20 @@ fn create_test_evm_match(monitor: Monitor) -> MonitorMatch {
 		monitor,
 		transaction,
 		receipt: Some(create_test_evm_transaction_receipt()),
+		revert_reason: None,
 		logs: Some(create_test_evm_logs()),
+		block_timestamp: None,
 		network_slug: "ethereum_mainnet".to_string(),
 		matched_on: MatchConditions::default(),
 		matched_on_args: None,
+		enrichment: HashMap::new(),
 	}))
 }
 fn create_test_payload() -> serde_json::Value {
 	let title = "Test Title";
 	let body_template = "Test message with value ${value}";
 	let variables = HashMap::from([("value".to_string(), "42".to_string())]);
-	GenericWebhookPayloadBuilder.build_payload(title, body_template, &variables)
+	GenericWebhookPayloadBuilder::default().build_payload(title, body_template, &variables)
 }
 
 #[tokio::test]
@@ -79,6 +107,88 @@ async fn test_webhook_notification_success() {
 	mock.assert();
 }
 
+#[tokio::test]
+async fn test_webhook_notification_sends_user_agent_and_request_id() {
+	let payload = create_test_payload();
+
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.match_header("user-agent", default_user_agent().as_str())
+		.match_header(
+			DEFAULT_REQUEST_ID_HEADER,
+			Matcher::Regex("^[0-9a-f-]{36}$".to_string()),
+		)
+		.with_status(200)
+		.create_async()
+		.await;
+
+	let config = WebhookConfig {
+		url: server.url(),
+		url_params: None,
+		title: "Test Alert".to_string(),
+		body_template: "Test message with value ${value}".to_string(),
+		method: Some("POST".to_string()),
+		secret: None,
+		headers: None,
+		payload_fields: None,
+	};
+	let http_client = get_http_client_from_notification_pool().await;
+	let notifier = WebhookNotifier::new(config, http_client).unwrap();
+	let result = notifier.notify_json(&payload).await;
+
+	assert!(result.is_ok());
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_webhook_signature_verifies_on_receiver() {
+	let payload = create_test_payload();
+
+	// The receiver only accepts requests whose signature headers verify against the raw body
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.match_request(|request| {
+			let header = |name: &str| {
+				request
+					.header(name)
+					.first()
+					.and_then(|value| value.to_str().ok())
+					.map(|value| value.to_string())
+			};
+			match (header("x-signature"), header("x-timestamp"), request.body()) {
+				(Some(signature), Some(timestamp), Ok(body)) => verify_webhook_signature(
+					body,
+					&signature,
+					&timestamp,
+					"receiver-secret",
+					WebhookSignatureAlgorithm::HmacSha256,
+				),
+				_ => false,
+			}
+		})
+		.with_status(200)
+		.create_async()
+		.await;
+
+	let config = WebhookConfig {
+		url: server.url(),
+		url_params: None,
+		title: "Test Alert".to_string(),
+		body_template: "Test message with value ${value}".to_string(),
+		method: Some("POST".to_string()),
+		secret: Some("receiver-secret".to_string()),
+		headers: None,
+		payload_fields: None,
+	};
+	let http_client = get_http_client_from_notification_pool().await;
+	let notifier = WebhookNotifier::new(config, http_client).unwrap();
+
+	assert!(notifier.notify_json(&payload).await.is_ok());
+	mock.assert();
+}
+
 #[tokio::test]
 async fn test_webhook_notification_failure_retryable_error() {
 	// Setup async mock server to simulate failure
@@ -177,6 +287,269 @@ async fn test_notification_service_webhook_execution() {
 	mock.assert();
 }
 
+#[tokio::test]
+async fn test_notification_service_webhook_templated_url_and_method() {
+	let notification_service = NotificationService::new();
+	let mut server = Server::new_async().await;
+
+	let mock = server
+		.mock("PUT", "/monitors/Large%20Transfer/alerts")
+		.match_query(Matcher::UrlEncoded("tx".into(), "0x12&ab".into()))
+		.match_body(Matcher::PartialJson(serde_json::json!({
+			"title": "Test Alert"
+		})))
+		.with_status(200)
+		.create_async()
+		.await;
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.webhook(&format!(
+			"{}/monitors/${{monitor.name}}/alerts?tx=${{transaction.hash}}",
+			server.url()
+		))
+		.webhook_method("PUT")
+		.message("Test Alert", "Transfer on ${monitor.name}")
+		.build();
+
+	let variables = HashMap::from([
+		("monitor.name".to_string(), "Large Transfer".to_string()),
+		("transaction.hash".to_string(), "0x12&ab".to_string()),
+	]);
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+
+	let result = notification_service
+		.execute(&trigger, &variables, &monitor_match, &HashMap::new())
+		.await;
+
+	assert!(result.is_ok());
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_notification_service_webhook_payload_fields() {
+	let notification_service = NotificationService::new();
+	let mut server = Server::new_async().await;
+
+	let mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::Json(serde_json::json!({
+			"title": "Test Alert",
+			"body": "Transfer on test_monitor",
+			"variables": {
+				"monitor.name": "test_monitor",
+				"transaction.hash": "0x1234"
+			}
+		})))
+		.with_status(200)
+		.create_async()
+		.await;
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.webhook(&server.url())
+		.message("Test Alert", "Transfer on ${monitor.name}")
+		.webhook_payload_fields(vec!["monitor", "transaction"], vec!["transaction.from"])
+		.build();
+
+	let variables = HashMap::from([
+		("monitor.name".to_string(), "test_monitor".to_string()),
+		("transaction.hash".to_string(), "0x1234".to_string()),
+		("transaction.from".to_string(), "0xabcd".to_string()),
+		("events.0.args.value".to_string(), "100".to_string()),
+	]);
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+
+	let result = notification_service
+		.execute(&trigger, &variables, &monitor_match, &HashMap::new())
+		.await;
+
+	assert!(result.is_ok());
+	mock.assert();
+}
+
+fn create_test_transfer_match(monitor: Monitor, value: &str) -> MonitorMatch {
+	let signature = "Transfer(address,address,uint256)".to_string();
+	let mut monitor_match = create_test_evm_match(monitor);
+	if let MonitorMatch::EVM(evm_match) = &mut monitor_match {
+		evm_match.matched_on.events = vec![EventCondition {
+			signature: signature.clone(),
+			expression: None,
+		}];
+		evm_match.matched_on_args = Some(EVMMatchArguments {
+			functions: None,
+			events: Some(vec![EVMMatchParamsMap {
+				signature,
+				args: Some(vec![EVMMatchParamEntry {
+					name: "value".to_string(),
+					value: value.to_string(),
+					kind: "uint256".to_string(),
+					indexed: false,
+				}]),
+				hex_signature: None,
+			}]),
+		});
+	}
+	monitor_match
+}
+
+#[tokio::test]
+async fn test_notification_service_webhook_template_by_severity() {
+	let notification_service = NotificationService::new();
+	let mut server = Server::new_async().await;
+
+	let critical_mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::PartialJson(serde_json::json!({
+			"title": "Critical transfer",
+			"body": "Transfer of 5000 needs attention"
+		})))
+		.with_status(200)
+		.expect(1)
+		.create_async()
+		.await;
+	let default_mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::PartialJson(serde_json::json!({
+			"title": "Transfer",
+			"body": "Transfer of 10"
+		})))
+		.with_status(200)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.webhook(&server.url())
+		.message("Transfer", "Transfer of ${events.0.args.value}")
+		.template(
+			"critical",
+			"Critical transfer",
+			"Transfer of ${events.0.args.value} needs attention",
+		)
+		.build();
+	let monitor = MonitorBuilder::new()
+		.name("test_monitor")
+		.triggers(vec!["test_trigger".to_string()])
+		.severity_rule("critical", None, Some("value > 1000"))
+		.severity_rule("info", None, None)
+		.build();
+
+	// The critical match uses the template, the other one the message of the trigger
+	for value in ["5000", "10"] {
+		let monitor_match = create_test_transfer_match(monitor.clone(), value);
+		let mut variables = HashMap::from([("events.0.args.value".to_string(), value.to_string())]);
+		if let Some(severity) = match_severity(&monitor_match) {
+			variables.insert(SEVERITY_VARIABLE.to_string(), severity);
+		}
+
+		let result = notification_service
+			.execute(&trigger, &variables, &monitor_match, &HashMap::new())
+			.await;
+		assert!(result.is_ok());
+	}
+
+	critical_mock.assert_async().await;
+	default_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_notification_service_webhook_labels() {
+	let notification_service = NotificationService::new();
+	let mut server = Server::new_async().await;
+
+	let mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::PartialJson(serde_json::json!({
+			"body": "Alert for payments in prod"
+		})))
+		.with_status(200)
+		.create_async()
+		.await;
+
+	let trigger = TriggerBuilder::new()
+		.name("labelled_trigger")
+		.webhook(&server.url())
+		.message(
+			"Test Alert",
+			"Alert for ${trigger.labels.team} in ${monitor.labels.env}",
+		)
+		.labels(vec![("team", "payments")])
+		.build();
+
+	let monitor = MonitorBuilder::new()
+		.name("labelled_monitor")
+		.labels(vec![("env", "prod")])
+		.build();
+	// Monitor labels are added to the variables when the match is handled
+	let variables = HashMap::from([("monitor.labels.env".to_string(), "prod".to_string())]);
+	let monitor_match = create_test_evm_match(monitor);
+
+	let result = notification_service
+		.execute(&trigger, &variables, &monitor_match, &HashMap::new())
+		.await;
+
+	assert!(result.is_ok());
+	mock.assert();
+
+	let metric_labels = BTreeMap::from([
+		("monitor".to_string(), "labelled_monitor".to_string()),
+		("trigger".to_string(), "labelled_trigger".to_string()),
+		("status".to_string(), "success".to_string()),
+		("team".to_string(), "payments".to_string()),
+		("env".to_string(), "prod".to_string()),
+	]);
+	assert_eq!(NOTIFICATIONS_TOTAL.get(&metric_labels), 1);
+
+	let metrics = String::from_utf8(gather_metrics().unwrap()).unwrap();
+	assert!(metrics.contains(
+		"notifications_total{env=\"prod\",monitor=\"labelled_monitor\",status=\"success\",team=\"payments\",trigger=\"labelled_trigger\"} 1"
+	));
+}
+
+#[tokio::test]
+async fn test_notification_service_webhook_trigger_timezone() {
+	let notification_service = NotificationService::new();
+	let mut server = Server::new_async().await;
+
+	let mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::PartialJson(serde_json::json!({
+			"body": "Mined at 2023-11-15T00:13:20+02:00 (1700000000)"
+		})))
+		.with_status(200)
+		.create_async()
+		.await;
+
+	let trigger = TriggerBuilder::new()
+		.name("timezone_trigger")
+		.webhook(&server.url())
+		.message(
+			"Test Alert",
+			"Mined at ${block.timestamp_iso} (${block.timestamp})",
+		)
+		.timezone("+02:00")
+		.build();
+
+	// Block timestamp variables are added in the default timezone when the match is handled
+	let variables = HashMap::from([
+		("block.timestamp".to_string(), "1700000000".to_string()),
+		(
+			"block.timestamp_iso".to_string(),
+			"2023-11-14T22:13:20Z".to_string(),
+		),
+	]);
+	let monitor_match = create_test_evm_match(MonitorBuilder::new().build());
+
+	let result = notification_service
+		.execute(&trigger, &variables, &monitor_match, &HashMap::new())
+		.await;
+
+	assert!(result.is_ok());
+	mock.assert();
+}
+
 #[tokio::test]
 async fn test_notification_service_webhook_execution_failure() {
 	let notification_service = NotificationService::new();
@@ -213,6 +586,238 @@ async fn test_notification_service_webhook_execution_failure() {
 	mock.assert();
 }
 
+#[tokio::test]
+async fn test_notification_service_audit_log_records_each_send() {
+	let temp_dir = tempfile::TempDir::new().unwrap();
+	let audit_log = Arc::new(FileAuditLog::new(temp_dir.path().join("audit.jsonl")));
+	let notification_service = NotificationService::builder()
+		.audit_log(audit_log.clone())
+		.build();
+	let mut server = Server::new_async().await;
+	let success_mock = server
+		.mock("POST", "/ok")
+		.with_status(200)
+		.expect(1)
+		.create_async()
+		.await;
+	let failure_mock = server
+		.mock("POST", "/down")
+		.with_status(400)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let create_trigger = |name: &str, path: &str| {
+		TriggerBuilder::new()
+			.name(name)
+			.webhook(&format!("{}{}", server.url(), path))
+			.webhook_method("POST")
+			.message("Test Alert", "Test message")
+			.build()
+	};
+	let monitor_match = create_test_evm_match(create_test_monitor("audited_monitor"));
+
+	let result = notification_service
+		.execute(
+			&create_trigger("healthy_trigger", "/ok"),
+			&HashMap::new(),
+			&monitor_match,
+			&HashMap::new(),
+		)
+		.await;
+	assert!(result.is_ok());
+	let result = notification_service
+		.execute(
+			&create_trigger("failing_trigger", "/down"),
+			&HashMap::new(),
+			&monitor_match,
+			&HashMap::new(),
+		)
+		.await;
+	assert!(result.is_err());
+	success_mock.assert();
+	failure_mock.assert();
+
+	let content = std::fs::read_to_string(audit_log.path()).unwrap();
+	let records: Vec<AuditRecord> = content
+		.lines()
+		.map(|line| serde_json::from_str(line).unwrap())
+		.collect();
+	assert_eq!(records.len(), 2);
+
+	assert_eq!(records[0].monitor, "audited_monitor");
+	assert_eq!(records[0].trigger, "healthy_trigger");
+	assert_eq!(records[0].trigger_type, "webhook");
+	assert_eq!(records[0].match_id, monitor_match.match_id());
+	assert_eq!(records[0].outcome, AuditOutcome::Success);
+	assert!(records[0].error.is_none());
+
+	assert_eq!(records[1].trigger, "failing_trigger");
+	assert_eq!(records[1].match_id, monitor_match.match_id());
+	assert_eq!(records[1].outcome, AuditOutcome::Failure);
+	assert!(records[1].error.is_some());
+}
+
+struct FailingAuditLog;
+
+#[async_trait]
+impl AuditLog for FailingAuditLog {
+	async fn record(&self, _record: &AuditRecord) -> Result<(), anyhow::Error> {
+		Err(anyhow::anyhow!("disk full"))
+	}
+}
+
+#[tokio::test]
+async fn test_notification_service_audit_log_failure_is_not_fatal() {
+	let notification_service = NotificationService::builder()
+		.audit_log(Arc::new(FailingAuditLog))
+		.build();
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.with_status(200)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.webhook(&server.url())
+		.webhook_method("POST")
+		.message("Test Alert", "Test message")
+		.build();
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+
+	let result = notification_service
+		.execute(&trigger, &HashMap::new(), &monitor_match, &HashMap::new())
+		.await;
+	assert!(result.is_ok());
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_notification_service_monitor_retry_overrides_trigger_retry() {
+	let notification_service = NotificationService::new();
+	let mut server = Server::new_async().await;
+	let fast_retry_policy = |max_retries| RetryConfig {
+		max_retries,
+		initial_backoff: Duration::from_millis(1),
+		max_backoff: Duration::from_millis(10),
+		..RetryConfig::default()
+	};
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.webhook(&server.url())
+		.webhook_method("GET")
+		.message("Test Alert", "Test message")
+		.retry_policy(fast_retry_policy(0))
+		.build();
+
+	// The trigger's retry policy applies without monitor override
+	let mock = server
+		.mock("GET", "/")
+		.with_status(500)
+		.expect(1)
+		.create_async()
+		.await;
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+	let result = notification_service
+		.execute(&trigger, &HashMap::new(), &monitor_match, &HashMap::new())
+		.await;
+	assert!(result.is_err());
+	mock.assert_async().await;
+	mock.remove_async().await;
+
+	// The monitor's retry policy takes precedence
+	let mock = server
+		.mock("GET", "/")
+		.with_status(500)
+		.expect(3)
+		.create_async()
+		.await;
+	let monitor_match = create_test_evm_match(
+		MonitorBuilder::new()
+			.name("critical_monitor")
+			.triggers(vec!["test_trigger".to_string()])
+			.notification_retry(fast_retry_policy(2))
+			.build(),
+	);
+	let result = notification_service
+		.execute(&trigger, &HashMap::new(), &monitor_match, &HashMap::new())
+		.await;
+	assert!(result.is_err());
+	mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_notification_service_circuit_breaker_opens_and_recovers() {
+	let client_pool = Arc::new(NotificationClientPool::with_circuit_breaker_config(
+		CircuitBreakerConfig {
+			failure_threshold: Some(1),
+			cooldown: Duration::from_millis(200),
+		},
+	));
+	let notification_service = NotificationService::with_client_pool(client_pool.clone());
+	let mut server = Server::new_async().await;
+	let default_retries_count = RetryConfig::default().max_retries as usize;
+
+	let failing_mock = server
+		.mock("GET", "/")
+		.with_status(500)
+		.expect(1 + default_retries_count)
+		.create_async()
+		.await;
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.webhook(&server.url())
+		.webhook_method("GET")
+		.message("Test Alert", "Test message")
+		.build();
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+
+	// The first failure runs the full retry policy and opens the circuit
+	let result = notification_service
+		.execute(&trigger, &HashMap::new(), &monitor_match, &HashMap::new())
+		.await;
+	assert!(result.is_err());
+	let breaker = client_pool
+		.get_or_create_circuit_breaker(&server.url())
+		.await
+		.unwrap();
+	assert_eq!(breaker.state(), CircuitState::Open);
+
+	// While open, notifications fail fast without reaching the endpoint
+	let result = notification_service
+		.execute(&trigger, &HashMap::new(), &monitor_match, &HashMap::new())
+		.await;
+	let error = result.unwrap_err();
+	assert!(matches!(error, NotificationError::NotifyFailed(_)));
+	assert!(error.to_string().contains("Circuit breaker is open"));
+	failing_mock.assert_async().await;
+	failing_mock.remove_async().await;
+
+	// After the cooldown a successful probe closes the circuit
+	let healthy_mock = server
+		.mock("GET", "/")
+		.with_status(200)
+		.expect(2)
+		.create_async()
+		.await;
+	tokio::time::sleep(Duration::from_millis(250)).await;
+	assert_eq!(breaker.state(), CircuitState::HalfOpen);
+
+	for _ in 0..2 {
+		let result = notification_service
+			.execute(&trigger, &HashMap::new(), &monitor_match, &HashMap::new())
+			.await;
+		assert!(result.is_ok());
+	}
+	assert_eq!(breaker.state(), CircuitState::Closed);
+	healthy_mock.assert_async().await;
+}
+
 #[tokio::test]
 async fn test_notification_service_webhook_execution_invalid_url() {
 	let notification_service = NotificationService::new();
@@ -274,3 +879,330 @@ async fn test_notify_json_with_url_params() {
 	assert!(result.is_ok());
 	mock.assert();
 }
+
+/// Starts an HTTP server that holds each request for `delay` and records the
+/// maximum number of requests it was handling at the same time.
+async fn start_concurrency_tracking_server(delay: Duration) -> (String, Arc<AtomicUsize>) {
+	let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+	let url = format!("http://{}", listener.local_addr().unwrap());
+	let in_flight = Arc::new(AtomicUsize::new(0));
+	let max_in_flight = Arc::new(AtomicUsize::new(0));
+
+	let max_in_flight_clone = max_in_flight.clone();
+	tokio::spawn(async move {
+		loop {
+			let Ok((mut stream, _)) = listener.accept().await else {
+				break;
+			};
+			let in_flight = in_flight.clone();
+			let max_in_flight = max_in_flight_clone.clone();
+			tokio::spawn(async move {
+				// Read the request headers and body before responding
+				let mut request = Vec::new();
+				let mut buf = [0u8; 1024];
+				loop {
+					let n = stream.read(&mut buf).await.unwrap_or(0);
+					if n == 0 {
+						return;
+					}
+					request.extend_from_slice(&buf[..n]);
+					let text = String::from_utf8_lossy(&request);
+					if let Some(header_end) = text.find("\r\n\r\n") {
+						let content_length = text[..header_end]
+							.lines()
+							.find_map(|line| {
+								let (name, value) = line.split_once(':')?;
+								name.eq_ignore_ascii_case("content-length")
+									.then(|| value.trim().parse::<usize>().ok())?
+							})
+							.unwrap_or(0);
+						if request.len() >= header_end + 4 + content_length {
+							break;
+						}
+					}
+				}
+
+				let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+				max_in_flight.fetch_max(current, Ordering::SeqCst);
+				tokio::time::sleep(delay).await;
+				in_flight.fetch_sub(1, Ordering::SeqCst);
+
+				let _ = stream
+					.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+					.await;
+			});
+		}
+	});
+
+	(url, max_in_flight)
+}
+
+#[tokio::test]
+async fn test_notification_service_webhook_max_concurrent_notifications() {
+	let notification_service = NotificationService::new();
+	let (url, max_in_flight) = start_concurrency_tracking_server(Duration::from_millis(200)).await;
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.webhook(&url)
+		.message("Test Alert", "Test message")
+		.max_concurrent_notifications(1)
+		.build();
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+	let variables = HashMap::new();
+	let scripts = HashMap::new();
+
+	let (first, second) = tokio::join!(
+		notification_service.execute(&trigger, &variables, &monitor_match, &scripts),
+		notification_service.execute(&trigger, &variables, &monitor_match, &scripts),
+	);
+
+	// Both notifications are queued and delivered, one at a time
+	assert!(first.is_ok());
+	assert!(second.is_ok());
+	assert_eq!(max_in_flight.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_notification_service_webhook_without_concurrency_limit() {
+	let notification_service = NotificationService::new();
+	let (url, max_in_flight) = start_concurrency_tracking_server(Duration::from_millis(200)).await;
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.webhook(&url)
+		.message("Test Alert", "Test message")
+		.build();
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+	let variables = HashMap::new();
+	let scripts = HashMap::new();
+
+	let (first, second) = tokio::join!(
+		notification_service.execute(&trigger, &variables, &monitor_match, &scripts),
+		notification_service.execute(&trigger, &variables, &monitor_match, &scripts),
+	);
+
+	assert!(first.is_ok());
+	assert!(second.is_ok());
+	assert_eq!(max_in_flight.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_test_trigger_sends_sample_notification() {
+	let notification_service = NotificationService::new();
+	let mut server = Server::new_async().await;
+
+	let mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::PartialJson(serde_json::json!({
+			"body": "Transfer of 1000000 on Test Trigger"
+		})))
+		.with_status(200)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let trigger = TriggerBuilder::new()
+		.name("sample_trigger")
+		.webhook(&server.url())
+		.message(
+			"Test Alert",
+			"Transfer of ${events.0.args.value} on ${monitor.name}",
+		)
+		.build();
+
+	let mut trigger_repository = MockTriggerRepository::default();
+	trigger_repository
+		.expect_get()
+		.with(mockall::predicate::eq("sample_trigger"))
+		.returning(move |_| Some(trigger.clone()));
+	let trigger_service = TriggerService::new_with_repository(trigger_repository).unwrap();
+
+	let result = test_trigger("sample_trigger", &trigger_service, &notification_service).await;
+
+	assert!(result.is_ok());
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_test_trigger_not_found() {
+	let notification_service = NotificationService::new();
+
+	let mut trigger_repository = MockTriggerRepository::default();
+	trigger_repository.expect_get().returning(|_| None);
+	let trigger_service = TriggerService::new_with_repository(trigger_repository).unwrap();
+
+	let result = test_trigger("missing_trigger", &trigger_service, &notification_service).await;
+
+	assert!(result
+		.unwrap_err()
+		.to_string()
+		.contains("Trigger 'missing_trigger' not found"));
+}
+
+async fn seed_dead_letter_store(store: &DeadLetterStore, records: &[(&str, &str)]) {
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+	let error = NotificationError::network_error("Connection refused", None, None);
+	for (trigger, value) in records {
+		let variables = HashMap::from([("value".to_string(), value.to_string())]);
+		let record = DeadLetterRecord::new(*trigger, &monitor_match, &variables, &error);
+		store.append(&record).await.unwrap();
+	}
+}
+
+fn create_dead_letter_trigger_service(
+	triggers: HashMap<String, Trigger>,
+) -> TriggerService<MockTriggerRepository> {
+	let mut trigger_repository = MockTriggerRepository::default();
+	trigger_repository
+		.expect_get()
+		.returning(move |slug| triggers.get(slug).cloned());
+	TriggerService::new_with_repository(trigger_repository).unwrap()
+}
+
+fn create_dead_letter_trigger(url: &str) -> Trigger {
+	TriggerBuilder::new()
+		.name("Healthy Webhook")
+		.webhook(url)
+		.webhook_method("POST")
+		.message("Recovered Alert", "Value ${value}")
+		.build()
+}
+
+#[tokio::test]
+async fn test_drain_dead_letter_resends_to_healthy_endpoint() {
+	let mut server = Server::new_async().await;
+	let first_mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::PartialJson(serde_json::json!({"body": "Value 1"})))
+		.with_status(200)
+		.expect(1)
+		.create_async()
+		.await;
+	let second_mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::PartialJson(serde_json::json!({"body": "Value 2"})))
+		.with_status(200)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let dir = tempfile::TempDir::new().unwrap();
+	let store = DeadLetterStore::new(dir.path().join("dead_letter.jsonl"));
+	seed_dead_letter_store(
+		&store,
+		&[("healthy", "1"), ("other", "3"), ("healthy", "2")],
+	)
+	.await;
+	let trigger_service = create_dead_letter_trigger_service(HashMap::from([(
+		"healthy".to_string(),
+		create_dead_letter_trigger(&server.url()),
+	)]));
+
+	let summary = drain_dead_letter(
+		&store,
+		&trigger_service,
+		&NotificationService::new(),
+		Some("healthy"),
+		None,
+	)
+	.await
+	.unwrap();
+
+	assert_eq!(
+		summary,
+		DrainSummary {
+			delivered: 2,
+			failed: 0,
+			skipped: 0,
+			remaining: 1,
+		}
+	);
+	first_mock.assert();
+	second_mock.assert();
+	let remaining = store.load().await.unwrap();
+	assert_eq!(remaining.len(), 1);
+	assert_eq!(remaining[0].trigger, "other");
+}
+
+#[tokio::test]
+async fn test_drain_dead_letter_max() {
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.with_status(200)
+		.expect(2)
+		.create_async()
+		.await;
+
+	let dir = tempfile::TempDir::new().unwrap();
+	let store = DeadLetterStore::new(dir.path().join("dead_letter.jsonl"));
+	seed_dead_letter_store(
+		&store,
+		&[("healthy", "1"), ("healthy", "2"), ("healthy", "3")],
+	)
+	.await;
+	let trigger_service = create_dead_letter_trigger_service(HashMap::from([(
+		"healthy".to_string(),
+		create_dead_letter_trigger(&server.url()),
+	)]));
+
+	let summary = drain_dead_letter(
+		&store,
+		&trigger_service,
+		&NotificationService::new(),
+		None,
+		Some(2),
+	)
+	.await
+	.unwrap();
+
+	assert_eq!(summary.delivered, 2);
+	assert_eq!(summary.remaining, 1);
+	mock.assert();
+	// The oldest records are re-sent first
+	let remaining = store.load().await.unwrap();
+	assert_eq!(remaining.len(), 1);
+	assert_eq!(remaining[0].variables["value"], "3");
+}
+
+#[tokio::test]
+async fn test_drain_dead_letter_keeps_failed_and_unknown_triggers() {
+	let mut server = Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.with_status(400)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let dir = tempfile::TempDir::new().unwrap();
+	let store = DeadLetterStore::new(dir.path().join("dead_letter.jsonl"));
+	seed_dead_letter_store(&store, &[("healthy", "1"), ("removed", "2")]).await;
+	let trigger_service = create_dead_letter_trigger_service(HashMap::from([(
+		"healthy".to_string(),
+		create_dead_letter_trigger(&server.url()),
+	)]));
+
+	let summary = drain_dead_letter(
+		&store,
+		&trigger_service,
+		&NotificationService::new(),
+		None,
+		None,
+	)
+	.await
+	.unwrap();
+
+	assert_eq!(
+		summary,
+		DrainSummary {
+			delivered: 0,
+			failed: 1,
+			skipped: 1,
+			remaining: 2,
+		}
+	);
+	mock.assert();
+	assert_eq!(store.load().await.unwrap().len(), 2);
+}
@@ -0,0 +1,177 @@
+use crate::integration::mocks::{
+	create_test_network, MockClientPool, MockEVMTransportClient, MockEvmClientTrait,
+	MockStellarClientTrait, MockStellarTransportClient,
+};
+use openzeppelin_monitor::{
+	models::{BlockChainType, Network, Trigger},
+	utils::{
+		selftest::{run_selftest, SelfTestComponent, SelfTestReport, SelfTestStatus},
+		tests::builders::trigger::TriggerBuilder,
+	},
+};
+use std::sync::Arc;
+
+/// Mocks a client pool whose EVM client is healthy and whose Stellar client cannot fetch
+/// its latest block
+fn setup_mocked_client_pool() -> MockClientPool {
+	let mut evm_client = MockEvmClientTrait::<MockEVMTransportClient>::new();
+	evm_client
+		.expect_get_latest_block_number()
+		.returning(|| Ok(21305050));
+	let evm_client = Arc::new(evm_client);
+
+	let mut stellar_client = MockStellarClientTrait::<MockStellarTransportClient>::new();
+	stellar_client
+		.expect_get_latest_block_number()
+		.returning(|| Err(anyhow::anyhow!("RPC error")));
+	let stellar_client = Arc::new(stellar_client);
+
+	let mut mock_pool = MockClientPool::new();
+	mock_pool
+		.expect_get_evm_client()
+		.returning(move |_| Ok(evm_client.clone()));
+	mock_pool
+		.expect_get_stellar_client()
+		.returning(move |_| Ok(stellar_client.clone()));
+	mock_pool
+}
+
+fn status_of(report: &SelfTestReport, name: &str) -> SelfTestStatus {
+	report
+		.checks
+		.iter()
+		.find(|check| check.name == name)
+		.unwrap_or_else(|| panic!("Check not found for {}", name))
+		.status
+}
+
+#[tokio::test]
+async fn test_selftest_mixed_health() {
+	let mut server = mockito::Server::new_async().await;
+	let healthy_webhook = server
+		.mock("HEAD", "/healthy")
+		.with_status(200)
+		.create_async()
+		.await;
+	let missing_webhook = server
+		.mock("HEAD", "/missing")
+		.with_status(404)
+		.create_async()
+		.await;
+	let telegram = server
+		.mock("GET", "/bottest-token/getMe")
+		.with_status(200)
+		.with_body(r#"{"ok":true}"#)
+		.create_async()
+		.await;
+
+	let networks: Vec<Network> = vec![
+		create_test_network("Ethereum", "ethereum_mainnet", BlockChainType::EVM),
+		create_test_network("Stellar", "stellar_mainnet", BlockChainType::Stellar),
+	];
+	let triggers: Vec<Trigger> = vec![
+		TriggerBuilder::new()
+			.name("healthy_webhook")
+			.webhook(&format!("{}/healthy", server.url()))
+			.build(),
+		TriggerBuilder::new()
+			.name("missing_slack")
+			.slack(&format!("{}/missing", server.url()))
+			.build(),
+		TriggerBuilder::new()
+			.name("telegram")
+			.telegram("test-token", "1730223038", false)
+			.build(),
+		TriggerBuilder::new()
+			.name("custom")
+			.custom("pagerduty")
+			.build(),
+	];
+
+	let report = run_selftest(
+		&networks,
+		&triggers,
+		&setup_mocked_client_pool(),
+		&server.url(),
+	)
+	.await;
+
+	assert!(!report.passed());
+	assert_eq!(report.checks.len(), 6);
+	assert_eq!(report.checks[0].component, SelfTestComponent::Network);
+	assert_eq!(status_of(&report, "ethereum_mainnet"), SelfTestStatus::Pass);
+	assert_eq!(report.checks[0].detail, "latest block 21305050");
+	assert_eq!(status_of(&report, "stellar_mainnet"), SelfTestStatus::Fail);
+	assert_eq!(status_of(&report, "healthy_webhook"), SelfTestStatus::Pass);
+	assert_eq!(status_of(&report, "missing_slack"), SelfTestStatus::Fail);
+	assert_eq!(status_of(&report, "telegram"), SelfTestStatus::Pass);
+	assert_eq!(status_of(&report, "custom"), SelfTestStatus::Skip);
+	assert_eq!(
+		report
+			.failures()
+			.map(|check| check.name.as_str())
+			.collect::<Vec<_>>(),
+		vec!["stellar_mainnet", "missing_slack"]
+	);
+	assert!(report.to_string().ends_with("6 check(s), 2 failed"));
+
+	healthy_webhook.assert_async().await;
+	missing_webhook.assert_async().await;
+	telegram.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_selftest_all_healthy() {
+	let mut server = mockito::Server::new_async().await;
+	let webhook = server
+		.mock("HEAD", "/webhook")
+		.with_status(405)
+		.create_async()
+		.await;
+
+	let networks = vec![create_test_network(
+		"Ethereum",
+		"ethereum_mainnet",
+		BlockChainType::EVM,
+	)];
+	let triggers = vec![TriggerBuilder::new()
+		.name("webhook")
+		.webhook(&format!("{}/webhook", server.url()))
+		.build()];
+
+	let report = run_selftest(
+		&networks,
+		&triggers,
+		&setup_mocked_client_pool(),
+		&server.url(),
+	)
+	.await;
+
+	// Endpoints rejecting the HEAD method are still reachable
+	assert!(report.passed());
+	assert_eq!(report.checks.len(), 2);
+	webhook.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_selftest_invalid_telegram_token() {
+	let mut server = mockito::Server::new_async().await;
+	let telegram = server
+		.mock("GET", "/botinvalid-token/getMe")
+		.with_status(401)
+		.with_body(r#"{"ok":false,"error_code":401,"description":"Unauthorized"}"#)
+		.create_async()
+		.await;
+
+	let triggers = vec![TriggerBuilder::new()
+		.name("telegram")
+		.telegram("invalid-token", "1730223038", false)
+		.build()];
+
+	let report = run_selftest(&[], &triggers, &MockClientPool::new(), &server.url()).await;
+
+	assert!(!report.passed());
+	assert_eq!(report.checks[0].status, SelfTestStatus::Fail);
+	assert_eq!(report.checks[0].detail, "bot token rejected with HTTP 401");
+	telegram.assert_async().await;
+}
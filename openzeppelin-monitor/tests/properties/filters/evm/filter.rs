@@ -1093,9 +1093,10 @@ proptest! {
 				&status,
 				&tx,
 				&Some(ReceiptBuilder::new().build()),
+				None,
 				&monitor,
 				&mut matched_transactions
-			);
+			, &[]);
 
 			// Verify matches based on monitor conditions and transaction status
 			let value = tx.value.to::<u128>();
@@ -1140,9 +1141,10 @@ proptest! {
 			&TransactionStatus::Success,
 			&tx,
 			&Some(ReceiptBuilder::new().build()),
+			None,
 			&monitor,
 			&mut matched_transactions
-		);
+		, &[]);
 
 		prop_assert_eq!(matched_transactions.len(), 1);
 		prop_assert!(matched_transactions[0].expression.is_none());
@@ -1207,7 +1209,7 @@ proptest! {
 			&monitor,
 			&mut matched_functions,
 			&mut matched_args
-		);
+		, &[]);
 
 		let should_match = monitor.match_conditions.functions.iter().any(|f|
 			f.signature == "store(uint256)"
@@ -1250,7 +1252,7 @@ proptest! {
 			&mut matched_events,
 			&mut matched_args,
 			&mut monitor.addresses.iter().map(|a| a.address.clone()).collect()
-		);
+		, &[]);
 
 
 		let should_match = monitor.match_conditions.events.iter().any(|e|
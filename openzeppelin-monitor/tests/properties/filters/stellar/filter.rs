@@ -22,7 +22,7 @@ use openzeppelin_monitor::{
 };
 use proptest::{prelude::*, test_runner::Config};
 use serde_json::{json, Value as JsonValue};
-use std::{marker::PhantomData, str::FromStr};
+use std::str::FromStr;
 use stellar_strkey::Contract;
 use stellar_xdr::curr::{
 	AccountId, Hash, HostFunction, Int128Parts, InvokeContractArgs, InvokeHostFunctionOp, Memo,
@@ -384,9 +384,7 @@ proptest! {
 			indexed: false,
 		}];
 
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
 		// Test address comparison based on normalized form
@@ -416,9 +414,7 @@ proptest! {
 			indexed: false,
 		}];
 
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
 		let expected = match operator {
@@ -454,9 +450,7 @@ proptest! {
 			indexed: false,
 		}];
 
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
@@ -499,9 +493,7 @@ proptest! {
 			indexed: false,
 		}];
 
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
 		let expected = match operator {
@@ -537,9 +529,7 @@ proptest! {
 			indexed: false,
 		}];
 
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
 		let expected = match operator {
@@ -575,9 +565,7 @@ proptest! {
 			indexed: false,
 		}];
 
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
 		let expected = match operator {
@@ -613,9 +601,7 @@ proptest! {
 			indexed: false,
 		}];
 
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
 		let expected = match operator {
@@ -651,9 +637,7 @@ proptest! {
 			indexed: false,
 		}];
 
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
 		let expected = match operator {
@@ -689,9 +673,7 @@ proptest! {
 			indexed: false,
 		}];
 
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
 		let expected = match operator {
@@ -730,9 +712,7 @@ proptest! {
 			indexed: false,
 		}];
 
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
 		// Handle different vector operations: contains checks for membership,
@@ -765,9 +745,7 @@ proptest! {
 					indexed: false,
 			}];
 
-			let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-					_client: PhantomData,
-			};
+			let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 			let result = filter.evaluate_expression(&expr, &params).unwrap();
 
 			let expected = values.contains(&target);
@@ -796,9 +774,7 @@ proptest! {
 					indexed: false,
 			}];
 
-			let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-					_client: PhantomData,
-			};
+			let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 			let result = filter.evaluate_expression(&expr, &params).unwrap();
 			// Normalize the target for comparison
 			let target_lowercase = target.to_lowercase();
@@ -843,9 +819,7 @@ proptest! {
 					indexed: false,
 			}];
 
-			let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-					_client: PhantomData,
-			};
+			let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 			let result = filter.evaluate_expression(&expr, &params).unwrap();
 
 			// Manually check for presence in original values
@@ -880,9 +854,7 @@ proptest! {
 			indexed: false,
 		}];
 
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
 		let expected = value_str1.eq_ignore_ascii_case(&value_str2);
@@ -915,9 +887,7 @@ proptest! {
 					indexed: false,
 			}];
 
-			let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-					_client: PhantomData,
-			};
+			let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 			let result = filter.evaluate_expression(&expr, &params).unwrap();
 
 			// Check if target exists in any nested array
@@ -955,9 +925,7 @@ proptest! {
 			indexed: false,
 		}];
 
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
 		let expected = match operator {
@@ -998,9 +966,7 @@ proptest! {
 			indexed: false,
 		}];
 
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 		let lhs_json_val = serde_json::from_str::<JsonValue>(&lhs_json_map_str).unwrap();
 		let rhs_json_val = serde_json::from_str::<JsonValue>(&rhs_json_map_str).unwrap();
@@ -1038,9 +1004,7 @@ proptest! {
 			}
 		];
 
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
 		let expected = amount >= threshold && are_same_address(&addr, &addr);
@@ -1064,9 +1028,7 @@ proptest! {
 			indexed: false,
 		}];
 
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
 		let expected = amount < threshold1 || amount > threshold2;
@@ -1116,9 +1078,7 @@ proptest! {
 			}
 		];
 
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 		let result = filter.evaluate_expression(&expr, &params).unwrap();
 
 		// Expected result combines numeric threshold checks with address equality checks
@@ -1149,9 +1109,7 @@ proptest! {
 			}
 		];
 
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 
 		// Test cases for expression validation:
 		// 1. Invalid operator syntax
@@ -1178,9 +1136,7 @@ proptest! {
 		monitor in generate_monitor_with_transaction(),
 	) {
 		let mut matched_transactions = Vec::new();
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 
 		filter.find_matching_transaction(&tx, &monitor, &mut matched_transactions);
 
@@ -1228,9 +1184,7 @@ proptest! {
 		tx in generate_transaction()
 
 	) {
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 		let mut matched_transactions = Vec::new();
 
 		// Create monitor with empty conditions
@@ -1276,9 +1230,7 @@ proptest! {
 		// Generate a threshold for expression testing
 		threshold in 0u128..1000000u128,
 	) {
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 
 		// Create the function signature
 		let function_signature = format!("{}({})", function_name, param_type);
@@ -1430,9 +1382,7 @@ proptest! {
 		bool_value in any::<bool>(),
 		string_value in "[a-zA-Z0-9]*",
 	) {
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 
 		// Create array of JSON values with explicit types
 		let arguments = vec![
@@ -1478,9 +1428,7 @@ proptest! {
 	fn test_convert_array_arguments(
 		values in prop::collection::vec(any::<i64>(), 1..5),
 	) {
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 		let arguments = vec![json!(values)];
 
 		let function_spec = StellarContractFunction::default();
@@ -1502,9 +1450,7 @@ proptest! {
 		key in "[a-zA-Z][a-zA-Z0-9_]*",
 		value in any::<i64>(),
 	) {
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 
 		// Test regular object to parameter conversion
 		let map = json!({
@@ -1543,9 +1489,7 @@ proptest! {
 	// Verifies proper handling of empty argument lists
 	#[test]
 	fn test_convert_empty_arguments(_ in prop::collection::vec(any::<i64>(), 0..1)) {
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 		let arguments = Vec::new();
 
 		let function_spec = StellarContractFunction::default();
@@ -1578,9 +1522,7 @@ proptest! {
 		// Generate a threshold for expression testing
 		threshold in 0u128..1000000u128,
 	) {
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 
 		// Create the event signature
 		let event_signature = format!("{}({})", event_name, param_type);
@@ -1683,9 +1625,7 @@ proptest! {
 		// Generate a value for expression testing
 		value in 0u64..u64::MAX,
 	) {
-		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>> {
-			_client: PhantomData,
-		};
+		let filter = StellarBlockFilter::<StellarClient<StellarTransportClient>>::new();
 
 		// Create a buffer for event name encoding (8 byte prefix + name)
 		let mut event_name_buffer = vec![0u8; 8];
@@ -1,7 +1,7 @@
 use crate::properties::strategies::{monitor_strategy, network_strategy, trigger_strategy};
 
 use openzeppelin_monitor::{
-	models::{ConfigLoader, ScriptLanguage},
+	models::{BlockChainType, ConfigLoader, ScriptLanguage},
 	repositories::{
 		MonitorRepository, MonitorRepositoryTrait, NetworkRepository, TriggerRepository,
 	},
@@ -53,7 +53,7 @@ proptest! {
 		let trigger_names: Vec<String> = triggers.keys().cloned().collect();
 
 		// Generate monitors with valid references
-		let monitors = proptest::collection::hash_map(
+		let mut monitors = proptest::collection::hash_map(
 			"[a-zA-Z0-9_]{1,10}",
 			monitor_strategy(network_names, trigger_names),
 			MIN_TEST_CASES..MAX_TEST_CASES
@@ -62,6 +62,15 @@ proptest! {
 		.unwrap()
 		.current();
 
+		// Stellar networks only accept valid account or contract addresses
+		for monitor in monitors.values_mut() {
+			if monitor.networks.iter().any(|slug| networks[slug].network_type == BlockChainType::Stellar) {
+				for address in monitor.addresses.iter_mut() {
+					address.address = "GCXKG6RN4ONIEPCMNFB732A436Z5PNDSRLGWK7GBLCMQLIFO4S7EYWVU".to_string();
+				}
+			}
+		}
+
 		// Test valid references
 		let result = MonitorRepository::<NetworkRepository, TriggerRepository>::validate_monitor_references(
 			&monitors,
@@ -73,7 +82,7 @@ proptest! {
 		// Test invalid references
 		let mut invalid_monitors = monitors.clone();
 		for monitor in invalid_monitors.values_mut() {
-			monitor.triggers.push("non_existent_trigger".to_string());
+			monitor.triggers.push("non_existent_trigger".into());
 			monitor.networks.push("non_existent_network".to_string());
 		}
 
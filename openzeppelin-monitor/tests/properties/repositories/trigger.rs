@@ -93,7 +93,7 @@ proptest! {
 			// Test invalid cases
 			match &trigger.trigger_type {
 				TriggerType::Slack => {
-					if let TriggerTypeConfig::Slack { slack_url: _, message: _, retry_policy: _ } = &trigger.config {
+					if let TriggerTypeConfig::Slack { slack_url: _, message: _, retry_policy: _, max_concurrent_notifications: _, max_message_length: _ } = &trigger.config {
 						invalid_trigger = trigger.clone();
 						if let TriggerTypeConfig::Slack { slack_url, .. } = &mut invalid_trigger.config {
 							*slack_url = SecretValue::Plain(SecretString::new("not-a-url".to_string())); // Invalid URL format
@@ -123,7 +123,7 @@ proptest! {
 					}
 				}
 				TriggerType::Email => {
-					if let TriggerTypeConfig::Email { host: _, port: _, username: _, password: _, message: _, sender: _, recipients: _, retry_policy: _ } = &trigger.config {
+					if let TriggerTypeConfig::Email { host: _, port: _, username: _, password: _, message: _, sender: _, recipients: _, cc: _, bcc: _, reply_to: _, content_type: _, attachments: _, retry_policy: _ } = &trigger.config {
 						// Test empty recipients
 						invalid_trigger = trigger.clone();
 						if let TriggerTypeConfig::Email { recipients: r, .. } = &mut invalid_trigger.config {
@@ -150,7 +150,7 @@ proptest! {
 					}
 				}
 				TriggerType::Webhook => {
-					if let TriggerTypeConfig::Webhook { url: _, method: _, headers: _, secret: _, message: _, retry_policy: _ } = &trigger.config {
+					if let TriggerTypeConfig::Webhook { url: _, method: _, headers: _, secret: _, message: _, retry_policy: _, max_concurrent_notifications: _, payload_fields: _ } = &trigger.config {
 						// Test invalid method
 						invalid_trigger = trigger.clone();
 						if let TriggerTypeConfig::Webhook { method: m, .. } = &mut invalid_trigger.config {
@@ -187,7 +187,7 @@ proptest! {
 					}
 				}
 				TriggerType::Discord => {
-					if let TriggerTypeConfig::Discord { discord_url: _, message: _, retry_policy: _ } = &trigger.config {
+					if let TriggerTypeConfig::Discord { discord_url: _, message: _, retry_policy: _, max_concurrent_notifications: _, max_message_length: _, embed: _ } = &trigger.config {
 						// Test invalid URL
 						invalid_trigger = trigger.clone();
 						if let TriggerTypeConfig::Discord { discord_url: u, .. } = &mut invalid_trigger.config {
@@ -217,7 +217,7 @@ proptest! {
 					}
 				}
 				TriggerType::Telegram => {
-					if let TriggerTypeConfig::Telegram { token: _, chat_id: _, disable_web_preview: _, message: _, retry_policy: _ } = &trigger.config {
+					if let TriggerTypeConfig::Telegram { token: _, chat_id: _, disable_web_preview: _, message: _, retry_policy: _, max_concurrent_notifications: _, max_message_length: _ } = &trigger.config {
 						// Test invalid token
 						invalid_trigger = trigger.clone();
 						if let TriggerTypeConfig::Telegram { token: t, .. } = &mut invalid_trigger.config {
@@ -253,8 +253,45 @@ proptest! {
 						prop_assert!(invalid_trigger.validate().is_err());
 					}
 				}
+				TriggerType::Kafka => {
+					if let TriggerTypeConfig::Kafka { brokers: _, topic: _, key_template: _, message: _, retry_policy: _ } = &trigger.config {
+						// Test empty brokers
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::Kafka { brokers: b, .. } = &mut invalid_trigger.config {
+							*b = vec![];
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+
+						// Test invalid topic
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::Kafka { topic: t, .. } = &mut invalid_trigger.config {
+							*t = "invalid topic".to_string();
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+					}
+				}
+				TriggerType::Nats => {
+					if let TriggerTypeConfig::Nats { server_url: _, subject: _, stream: _, message: _, retry_policy: _ } = &trigger.config {
+						// Test invalid subject
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::Nats { subject: s, .. } = &mut invalid_trigger.config {
+							*s = "invalid subject".to_string();
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+					}
+				}
+				TriggerType::Grpc => {
+					if let TriggerTypeConfig::Grpc { endpoint: _, metadata: _, message: _, timeout_ms: _, retry_policy: _ } = &trigger.config {
+						// Test invalid endpoint
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::Grpc { endpoint: e, .. } = &mut invalid_trigger.config {
+							*e = "not-a-url".to_string();
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+					}
+				}
 				TriggerType::Script => {
-					if let TriggerTypeConfig::Script { script_path: _, arguments: _, language: _, timeout_ms: _ } = &trigger.config {
+					if let TriggerTypeConfig::Script { script_path: _, arguments: _, language: _, timeout_ms: _, script_checksum: _, retry_policy: _ } = &trigger.config {
 						// Test invalid path
 						invalid_trigger = trigger.clone();
 						if let TriggerTypeConfig::Script { script_path: p, .. } = &mut invalid_trigger.config {
@@ -270,6 +307,12 @@ proptest! {
 						prop_assert!(invalid_trigger.validate().is_err());
 					}
 				}
+				TriggerType::Custom(_) => {
+					// Test empty notifier name
+					invalid_trigger = trigger.clone();
+					invalid_trigger.trigger_type = TriggerType::Custom("".to_string());
+					prop_assert!(invalid_trigger.validate().is_err());
+				}
 			}
 		}
 	}
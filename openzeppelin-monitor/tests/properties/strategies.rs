@@ -1,10 +1,10 @@
 use email_address::EmailAddress;
 use openzeppelin_monitor::{
 	models::{
-		AddressWithSpec, BlockChainType, EventCondition, FunctionCondition, MatchConditions,
-		Monitor, Network, NotificationMessage, RpcUrl, ScriptLanguage, SecretString, SecretValue,
-		TransactionCondition, TransactionStatus, Trigger, TriggerConditions, TriggerType,
-		TriggerTypeConfig,
+		AddressWithSpec, BlockChainType, EmailContentType, EventCondition, FunctionCondition,
+		MatchConditions, Monitor, Network, NotificationMessage, RpcUrl, ScriptLanguage,
+		SecretString, SecretValue, TransactionCondition, TransactionStatus, Trigger,
+		TriggerConditions, TriggerType, TriggerTypeConfig,
 	},
 	utils::{
 		tests::{evm::monitor::MonitorBuilder, network::NetworkBuilder, trigger::TriggerBuilder},
@@ -12,6 +12,7 @@ use openzeppelin_monitor::{
 	},
 };
 use proptest::{option, prelude::*};
+use std::collections::HashMap;
 use std::os::unix::prelude::ExitStatusExt;
 
 const MIN_COLLECTION_SIZE: usize = 0;
@@ -38,6 +39,9 @@ pub fn monitor_strategy(
 				AddressWithSpec {
 					address,
 					contract_spec: None,
+					contract_spec_path: None,
+					additional_specs: vec![],
+					event_signatures: HashMap::new(),
 				}
 			}),
 			MIN_COLLECTION_SIZE..MAX_ADDRESSES,
@@ -98,6 +102,8 @@ pub fn trigger_strategy() -> impl Strategy<Value = Trigger> {
 					slack_url: SecretValue::Plain(SecretString::new(slack_url)),
 					message,
 					retry_policy: RetryConfig::default(),
+					max_concurrent_notifications: None,
+					max_message_length: None,
 				})
 		)
 			.prop_map(|(name, trigger_type, config)| TriggerBuilder::new()
@@ -131,6 +137,11 @@ pub fn trigger_strategy() -> impl Strategy<Value = Trigger> {
 							message,
 							sender,
 							recipients,
+							cc: vec![],
+							bcc: vec![],
+							reply_to: None,
+							content_type: EmailContentType::default(),
+							attachments: None,
 							retry_policy: RetryConfig::default(),
 						}
 					}
@@ -164,6 +175,8 @@ pub fn trigger_strategy() -> impl Strategy<Value = Trigger> {
 						secret: secret.map(|s| SecretValue::Plain(SecretString::new(s))),
 						message,
 						retry_policy: RetryConfig::default(),
+						max_concurrent_notifications: None,
+						payload_fields: None,
 					}
 				})
 		)
@@ -185,6 +198,7 @@ pub fn rpc_url_strategy() -> impl Strategy<Value = RpcUrl> {
 			type_,
 			url: SecretValue::Plain(SecretString::new(url)),
 			weight,
+			headers: HashMap::new(),
 		})
 }
 
@@ -282,6 +296,9 @@ pub fn match_conditions_strategy() -> impl Strategy<Value = MatchConditions> {
 			functions,
 			events,
 			transactions,
+			operations: vec![],
+			aggregates: vec![],
+			percentage_changes: vec![],
 		})
 }
 
@@ -313,6 +330,8 @@ pub fn trigger_conditions_strategy() -> impl Strategy<Value = Vec<TriggerConditi
 				arguments: Some(arguments.split(',').map(|s| s.to_string()).collect()),
 				language,
 				timeout_ms,
+				script_checksum: None,
+				retry_policy: None,
 			}]
 		})
 }